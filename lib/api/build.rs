@@ -128,6 +128,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("CreateCollection.replication_factor", "range(min = 1)"),
             ("CreateCollection.write_consistency_factor", "range(min = 1)"),
             ("CreateCollection.strict_mode_config", ""),
+            ("CreateCollection.sparse_vectors_config", ""),
             ("UpdateCollection.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("UpdateCollection.optimizers_config", ""),
             ("UpdateCollection.params", ""),
@@ -136,6 +137,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpdateCollection.vectors_config", ""),
             ("UpdateCollection.quantization_config", ""),
             ("UpdateCollection.strict_mode_config", ""),
+            ("UpdateCollection.sparse_vectors_config", ""),
             ("CollectionParamsDiff.replication_factor", "range(min = 1)"),
             ("CollectionParamsDiff.write_consistency_factor", "range(min = 1)"),
             ("DeleteCollection.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
@@ -144,6 +146,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("ChangeAliases.timeout", "range(min = 1)"),
             ("ListCollectionAliasesRequest.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("HnswConfigDiff.ef_construct", "range(min = 4)"),
+            ("HnswConfigDiff.matryoshka_dim", "range(min = 1)"),
             ("WalConfigDiff.wal_capacity_mb", "range(min = 1)"),
             ("WalConfigDiff.wal_retain_closed", "range(min = 1)"),
             ("OptimizersConfigDiff.deleted_threshold", "range(min = 0.0, max = 1.0)"),
@@ -154,6 +157,13 @@ fn configure_validation(builder: Builder) -> Builder {
             ("VectorParams.size", "range(min = 1, max = 65536)"),
             ("VectorParams.hnsw_config", ""),
             ("VectorParams.quantization_config", ""),
+            ("VectorParams.multivector_config", ""),
+            ("MultiVectorConfig.token_pooling", ""),
+            ("TokenPooling.target_count", "range(min = 1)"),
+            ("SparseVectorConfig.map", ""),
+            ("SparseVectorParams.bm25_params", ""),
+            ("Bm25Params.k1", "range(min = 0.0)"),
+            ("Bm25Params.b", "range(min = 0.0, max = 1.0)"),
             ("VectorParamsMap.map", ""),
             ("VectorParamsDiff.hnsw_config", ""),
             ("VectorParamsDiff.quantization_config", ""),
@@ -181,6 +191,7 @@ fn configure_validation(builder: Builder) -> Builder {
             "UpdateCollectionClusterSetupRequest",
             "ProductQuantization",
             "BinaryQuantization",
+            "RabitqQuantization",
             "Disabled",
             "QuantizationConfigDiff",
             "quantization_config_diff::Quantization",
@@ -240,6 +251,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchPointGroups.limit", "range(min = 1)"),
             ("SearchPointGroups.timeout", "range(min = 1)"),
             ("SearchParams.quantization", ""),
+            ("SearchParams.ivf_nprobe", "range(min = 1)"),
+            ("SearchParams.matryoshka_dim", "range(min = 1)"),
             ("QuantizationSearchParams.oversampling", "range(min = 1.0)"),
             ("ScrollPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("ScrollPoints.filter", ""),
@@ -311,6 +324,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("Mmr.diversity", "range(min = 0.0, max = 1.0)"),
             ("Mmr.candidates_limit", "range(max = 16_384)"),
             ("Rrf.k", "range(min = 1)"),
+            ("TextQuery.query", "length(min = 1)"),
             ("Query.variant", ""),
             ("PrefetchQuery.prefetch", ""),
             ("PrefetchQuery.query", ""),
@@ -1,7 +1,8 @@
 use itertools::Itertools;
 use segment::common::operation_error::OperationError;
 use segment::data_types::vectors::{
-    DenseVector, MultiDenseVectorInternal, NamedVectorStruct, VectorInternal, VectorStructInternal,
+    DenseVector, MultiDenseVectorInternal, NamedVectorStruct, VectorElementType, VectorInternal,
+    VectorStructInternal,
 };
 use sparse::common::sparse_vector::SparseVector;
 use tonic::Status;
@@ -176,8 +177,7 @@ impl TryFrom<grpc::Vectors> for rest::VectorStruct {
                     if let Some(vector) = vector {
                         return match vector {
                             grpc::vector::Vector::Dense(dense) => {
-                                let grpc::DenseVector { data } = dense;
-                                Ok(rest::VectorStruct::Single(data))
+                                Ok(rest::VectorStruct::Single(DenseVector::from(dense)))
                             }
                             grpc::vector::Vector::Sparse(_sparse) => {
                                 return Err(Status::invalid_argument(
@@ -185,10 +185,7 @@ impl TryFrom<grpc::Vectors> for rest::VectorStruct {
                                 ));
                             }
                             grpc::vector::Vector::MultiDense(multi) => {
-                                let grpc::MultiDenseVector { vectors } = multi;
-                                Ok(rest::VectorStruct::MultiDense(
-                                    vectors.into_iter().map(|v| v.data).collect(),
-                                ))
+                                Ok(rest::VectorStruct::MultiDense(multi.into_matrix()))
                             }
                             grpc::vector::Vector::Document(document) => Ok(
                                 rest::VectorStruct::Document(rest::Document::try_from(document)?),
@@ -249,17 +246,13 @@ impl TryFrom<grpc::Vector> for rest::Vector {
         if let Some(vector) = vector {
             return match vector {
                 grpc::vector::Vector::Dense(dense) => {
-                    let grpc::DenseVector { data } = dense;
-                    Ok(rest::Vector::Dense(data))
+                    Ok(rest::Vector::Dense(DenseVector::from(dense)))
                 }
                 grpc::vector::Vector::Sparse(sparse) => Ok(rest::Vector::Sparse(
                     sparse::common::sparse_vector::SparseVector::from(sparse),
                 )),
                 grpc::vector::Vector::MultiDense(multi) => {
-                    let grpc::MultiDenseVector { vectors } = multi;
-                    Ok(rest::Vector::MultiDense(
-                        vectors.into_iter().map(|v| v.data).collect(),
-                    ))
+                    Ok(rest::Vector::MultiDense(multi.into_matrix()))
                 }
                 grpc::vector::Vector::Document(document) => {
                     Ok(rest::Vector::Document(rest::Document::try_from(document)?))
@@ -297,7 +290,7 @@ impl TryFrom<grpc::Vector> for rest::Vector {
 
 impl grpc::MultiDenseVector {
     pub fn into_matrix(self) -> Vec<Vec<f32>> {
-        self.vectors.into_iter().map(|v| v.data).collect()
+        self.vectors.into_iter().map(DenseVector::from).collect()
     }
 }
 
@@ -315,8 +308,7 @@ impl TryFrom<grpc::VectorOutput> for VectorInternal {
         if let Some(vector) = vector {
             return match vector {
                 grpc::vector_output::Vector::Dense(dense) => {
-                    let grpc::DenseVector { data } = dense;
-                    Ok(VectorInternal::Dense(data))
+                    Ok(VectorInternal::Dense(DenseVector::from(dense)))
                 }
                 grpc::vector_output::Vector::Sparse(sparse) => Ok(VectorInternal::Sparse(
                     sparse::common::sparse_vector::SparseVector::from(sparse),
@@ -362,8 +354,7 @@ impl TryFrom<grpc::VectorsOutput> for VectorStructInternal {
                     if let Some(vector) = vector {
                         return match vector {
                             grpc::vector_output::Vector::Dense(dense) => {
-                                let grpc::DenseVector { data } = dense;
-                                Ok(VectorStructInternal::Single(data))
+                                Ok(VectorStructInternal::Single(DenseVector::from(dense)))
                             }
                             grpc::vector_output::Vector::Sparse(_sparse) => {
                                 return Err(OperationError::ValidationError {
@@ -490,10 +481,7 @@ impl TryFrom<grpc::Vector> for VectorInternal {
 
         if let Some(vector) = vector {
             return match vector {
-                Vector::Dense(dense) => {
-                    let grpc::DenseVector { data } = dense;
-                    Ok(VectorInternal::Dense(data))
-                }
+                Vector::Dense(dense) => Ok(VectorInternal::Dense(DenseVector::from(dense))),
                 Vector::Sparse(sparse) => Ok(VectorInternal::Sparse(
                     sparse::common::sparse_vector::SparseVector::from(sparse),
                 )),
@@ -545,14 +533,20 @@ impl TryFrom<grpc::Vector> for VectorInternal {
 
 impl From<grpc::DenseVector> for DenseVector {
     fn from(value: grpc::DenseVector) -> Self {
-        let grpc::DenseVector { data } = value;
-        data
+        let grpc::DenseVector { data, binary } = value;
+        match binary {
+            Some(binary) => binary.into_iter().map(VectorElementType::from).collect(),
+            None => data,
+        }
     }
 }
 
 impl From<DenseVector> for grpc::DenseVector {
     fn from(value: DenseVector) -> Self {
-        Self { data: value }
+        Self {
+            data: value,
+            binary: None,
+        }
     }
 }
 
@@ -593,7 +587,10 @@ impl From<grpc::MultiDenseVector> for MultiDenseVectorInternal {
     /// Uses the equivalent of [`MultiDenseVectorInternal::new_unchecked`], but rewritten to avoid collecting twice
     fn from(value: grpc::MultiDenseVector) -> Self {
         let grpc::MultiDenseVector { vectors } = value;
-        let dim = vectors[0].data.len();
+        let dim = match &vectors[0].binary {
+            Some(binary) => binary.len(),
+            None => vectors[0].data.len(),
+        };
         let inner_vector = vectors.into_iter().flat_map(DenseVector::from).collect();
         Self {
             flattened_vectors: inner_vector,
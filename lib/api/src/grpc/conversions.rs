@@ -10,8 +10,8 @@ use common::types::ScoreType;
 use itertools::Itertools;
 use segment::common::operation_error::OperationError;
 use segment::data_types::index::{
-    BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, IntegerIndexType,
-    KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
+    BoolIndexType, DEFAULT_NGRAM_SIZE, DatetimeIndexType, FloatIndexType, GeoIndexType,
+    IntegerIndexType, KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
 };
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedMultiDenseVector, VectorInternal};
 use segment::data_types::{facets as segment_facets, vectors as segment_vectors};
@@ -26,13 +26,16 @@ use uuid::Uuid;
 
 use super::qdrant::{
     BinaryQuantization, BoolIndexParams, CompressionRatio, DatetimeIndexParams, DatetimeRange,
-    Direction, FacetHit, FacetHitInternal, FacetValue, FacetValueInternal, FieldType,
-    FloatIndexParams, GeoIndexParams, GeoLineString, GroupId, HardwareUsage, HasVectorCondition,
-    KeywordIndexParams, LookupLocation, MaxOptimizationThreads, MultiVectorComparator,
-    MultiVectorConfig, OrderBy, OrderValue, Range, RawVector, RecommendStrategy, RetrievedPoint,
-    SearchMatrixPair, SearchPointGroups, SearchPoints, ShardKeySelector, StartFrom,
-    StrictModeMultivector, StrictModeMultivectorConfig, StrictModeSparse, StrictModeSparseConfig,
-    UuidIndexParams, VectorsOutput, WithLookup, raw_query, start_from,
+    Direction, DiskAnnConfig, FacetHit, FacetHitInternal, FacetValue, FacetValueInternal,
+    FieldType, FloatIndexParams, GeoIndexParams, GeoLineString, GroupId, HardwareUsage,
+    HasVectorCondition, HighlightParams, IvfConfig, IvfResiduals, KeywordIndexParams,
+    LookupLocation, MaxOptimizationThreads, MultiVectorComparator, MultiVectorConfig, OrderBy,
+    OrderValue, RabitqQuantization, Range, RawVector, RecommendStrategy, RetrievedPoint,
+    SearchMatrixPair, SearchPointGroups, SearchPoints, ShardKeySelector, SparseSearchAlgorithm,
+    StartFrom, StrictModeMultivector, StrictModeMultivectorConfig, StrictModeSparse,
+    StrictModeSparseConfig, TextHighlight, TextHighlights, TextMatchOffsets, TextQuery,
+    TokenPooling, UuidIndexParams, VdeConfig, VdeIndexType, VdeStorageType, VectorsOutput,
+    WithLookup, raw_query, start_from,
 };
 use super::stemming_algorithm::StemmingParams;
 use super::{Expression, Formula, RecoQuery, SnowballParams, StemmingAlgorithm, Usage};
@@ -43,17 +46,18 @@ use crate::grpc::qdrant::payload_index_params::IndexParams;
 use crate::grpc::qdrant::point_id::PointIdOptions;
 use crate::grpc::qdrant::with_payload_selector::SelectorOptions;
 use crate::grpc::qdrant::{
-    CollectionDescription, CollectionOperationResponse, Condition, Distance, FieldCondition,
-    Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, HasIdCondition, HealthCheckReply,
-    HnswConfigDiff, IntegerIndexParams, IsEmptyCondition, IsNullCondition, ListCollectionsResponse,
-    Match, MinShould, NamedVectors, NestedCondition, PayloadExcludeSelector,
-    PayloadIncludeSelector, PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId,
-    PointStruct, PointsOperationResponse, PointsOperationResponseInternal, ProductQuantization,
+    BuiltinCharFilter, CharFilter, CollectionDescription, CollectionOperationResponse, Condition,
+    Distance, FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius,
+    HasIdCondition, HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition,
+    IsNullCondition, ListCollectionsResponse, MappingCharFilter, Match, MatchFuzzy, MinShould,
+    NamedVectors, NestedCondition, NgramParams, PayloadExcludeSelector, PayloadIncludeSelector,
+    PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId, PointStruct,
+    PointsOperationResponse, PointsOperationResponseInternal, ProductQuantization,
     QuantizationConfig, QuantizationSearchParams, QuantizationType, RepeatedIntegers,
     RepeatedStrings, ScalarQuantization, ScoredPoint, SearchParams, ShardKey, StopwordsSet,
-    StrictModeConfig, TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal,
-    ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector, shard_key,
-    with_vectors_selector,
+    StrictModeConfig, SynonymGroup, Synonyms, TextIndexParams, TokenizerType, UpdateResult,
+    UpdateResultInternal, ValuesCount, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
+    char_filter, shard_key, with_vectors_selector,
 };
 use crate::grpc::{
     self, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding, DecayParamsExpression,
@@ -73,6 +77,39 @@ pub fn convert_shard_key_to_grpc(value: segment::types::ShardKey) -> ShardKey {
     }
 }
 
+pub fn convert_highlight_to_grpc(
+    highlight: segment::data_types::highlight::PointHighlight,
+) -> HashMap<String, TextHighlights> {
+    highlight
+        .into_iter()
+        .map(|(key, values)| {
+            let values = values.into_iter().map(TextHighlight::from).collect();
+            (key, TextHighlights { values })
+        })
+        .collect()
+}
+
+/// Empty highlight is converted into `None`, as gRPC does not distinguish them
+pub fn convert_highlight_from_grpc(
+    highlight: HashMap<String, TextHighlights>,
+) -> Option<segment::data_types::highlight::PointHighlight> {
+    if highlight.is_empty() {
+        return None;
+    }
+
+    let highlight = highlight
+        .into_iter()
+        .map(|(key, TextHighlights { values })| {
+            let values = values
+                .into_iter()
+                .map(segment::data_types::highlight::TextHighlight::from)
+                .collect();
+            (key, values)
+        })
+        .collect();
+    Some(highlight)
+}
+
 pub fn convert_shard_key_from_grpc(value: ShardKey) -> Option<segment::types::ShardKey> {
     let ShardKey { key } = value;
     key.map(|key| match key {
@@ -142,6 +179,8 @@ impl From<segment::data_types::index::TokenizerType> for TokenizerType {
             segment::data_types::index::TokenizerType::Whitespace => TokenizerType::Whitespace,
             segment::data_types::index::TokenizerType::Multilingual => TokenizerType::Multilingual,
             segment::data_types::index::TokenizerType::Word => TokenizerType::Word,
+            segment::data_types::index::TokenizerType::Ngram { .. } => TokenizerType::Ngram,
+            segment::data_types::index::TokenizerType::EdgeNgram => TokenizerType::EdgeNgram,
         }
     }
 }
@@ -219,7 +258,16 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
             on_disk,
             stopwords,
             stemmer,
+            char_filters,
+            synonyms,
         } = params;
+        let ngram = match tokenizer {
+            segment::data_types::index::TokenizerType::Ngram { min, max } => Some(NgramParams {
+                min: Some(min as u64),
+                max: Some(max as u64),
+            }),
+            _ => None,
+        };
         let tokenizer = TokenizerType::from(tokenizer);
 
         // Convert stopwords if present
@@ -227,6 +275,14 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
 
         let stemming_algo = stemmer.map(StemmingAlgorithm::from);
 
+        let char_filters = char_filters
+            .into_iter()
+            .flatten()
+            .map(CharFilter::from)
+            .collect();
+
+        let synonyms = synonyms.map(Synonyms::from);
+
         PayloadIndexParams {
             index_params: Some(IndexParams::TextIndexParams(TextIndexParams {
                 tokenizer: tokenizer as i32,
@@ -237,6 +293,9 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
                 on_disk,
                 stopwords: stopwords_set,
                 stemmer: stemming_algo,
+                char_filters,
+                synonyms,
+                ngram,
             })),
         }
     }
@@ -358,6 +417,58 @@ impl From<segment::data_types::index::StopwordsInterface> for StopwordsSet {
     }
 }
 
+impl From<segment::data_types::index::CharFilter> for CharFilter {
+    fn from(value: segment::data_types::index::CharFilter) -> Self {
+        let char_filter = match value {
+            segment::data_types::index::CharFilter::Builtin(builtin) => {
+                let builtin = match builtin {
+                    segment::data_types::index::BuiltinCharFilter::RemoveDiacritics => {
+                        BuiltinCharFilter::RemoveDiacritics
+                    }
+                    segment::data_types::index::BuiltinCharFilter::AsciiFolding => {
+                        BuiltinCharFilter::AsciiFolding
+                    }
+                };
+                char_filter::CharFilter::Builtin(builtin as i32)
+            }
+            segment::data_types::index::CharFilter::Mapping(mapping) => {
+                let segment::data_types::index::MappingCharFilter {
+                    r#type: _,
+                    mappings,
+                } = mapping;
+                char_filter::CharFilter::Mapping(MappingCharFilter {
+                    mappings: mappings.into_iter().collect(),
+                })
+            }
+        };
+
+        CharFilter {
+            char_filter: Some(char_filter),
+        }
+    }
+}
+
+impl From<segment::data_types::index::SynonymsInterface> for Synonyms {
+    fn from(synonyms: segment::data_types::index::SynonymsInterface) -> Self {
+        let (groups, file) = match synonyms {
+            segment::data_types::index::SynonymsInterface::Groups(groups) => (Some(groups), None),
+            segment::data_types::index::SynonymsInterface::Set(set) => {
+                let segment::data_types::index::SynonymsSet { groups, file } = set;
+                (groups, file)
+            }
+        };
+
+        Synonyms {
+            groups: groups
+                .into_iter()
+                .flatten()
+                .map(|tokens| SynonymGroup { tokens })
+                .collect(),
+            file,
+        }
+    }
+}
+
 impl From<segment::data_types::index::StemmingAlgorithm> for StemmingAlgorithm {
     fn from(value: segment::data_types::index::StemmingAlgorithm) -> Self {
         let stemming_params = match value {
@@ -388,6 +499,11 @@ impl TryFrom<TokenizerType> for segment::data_types::index::TokenizerType {
             }
             TokenizerType::Whitespace => Ok(segment::data_types::index::TokenizerType::Whitespace),
             TokenizerType::Word => Ok(segment::data_types::index::TokenizerType::Word),
+            TokenizerType::Ngram => Ok(segment::data_types::index::TokenizerType::Ngram {
+                min: DEFAULT_NGRAM_SIZE,
+                max: DEFAULT_NGRAM_SIZE,
+            }),
+            TokenizerType::EdgeNgram => Ok(segment::data_types::index::TokenizerType::EdgeNgram),
         }
     }
 }
@@ -509,8 +625,28 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             on_disk,
             stopwords,
             stemmer,
+            char_filters,
+            synonyms,
+            ngram,
         } = params;
 
+        let tokenizer = TokenizerType::try_from(tokenizer)
+            .map(|x| x.try_into())
+            .unwrap_or_else(|_| Err(Status::invalid_argument("unknown tokenizer type")))?;
+        let tokenizer = match tokenizer {
+            segment::data_types::index::TokenizerType::Ngram { min, max } => {
+                let NgramParams {
+                    min: ngram_min,
+                    max: ngram_max,
+                } = ngram.unwrap_or_default();
+                segment::data_types::index::TokenizerType::Ngram {
+                    min: ngram_min.map_or(min, |x| x as usize),
+                    max: ngram_max.map_or(max, |x| x as usize),
+                }
+            }
+            tokenizer => tokenizer,
+        };
+
         // Convert stopwords if present
         let stopwords_converted = if let Some(set) = stopwords {
             Some(segment::data_types::index::StopwordsInterface::try_from(
@@ -525,11 +661,22 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             .map(segment::data_types::index::StemmingAlgorithm::try_from)
             .transpose()?;
 
+        let char_filters = if char_filters.is_empty() {
+            None
+        } else {
+            Some(
+                char_filters
+                    .into_iter()
+                    .map(segment::data_types::index::CharFilter::try_from)
+                    .collect::<Result<_, _>>()?,
+            )
+        };
+
+        let synonyms = synonyms.map(segment::data_types::index::SynonymsInterface::from);
+
         Ok(segment::data_types::index::TextIndexParams {
             r#type: TextIndexType::Text,
-            tokenizer: TokenizerType::try_from(tokenizer)
-                .map(|x| x.try_into())
-                .unwrap_or_else(|_| Err(Status::invalid_argument("unknown tokenizer type")))?,
+            tokenizer,
             lowercase,
             min_token_len: min_token_len.map(|x| x as usize),
             max_token_len: max_token_len.map(|x| x as usize),
@@ -537,10 +684,63 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             on_disk,
             stopwords: stopwords_converted,
             stemmer,
+            char_filters,
+            synonyms,
         })
     }
 }
 
+impl TryFrom<CharFilter> for segment::data_types::index::CharFilter {
+    type Error = Status;
+
+    fn try_from(value: CharFilter) -> Result<Self, Self::Error> {
+        let CharFilter { char_filter } = value;
+        match char_filter {
+            Some(char_filter::CharFilter::Builtin(builtin)) => {
+                let builtin = match BuiltinCharFilter::try_from(builtin) {
+                    Ok(BuiltinCharFilter::RemoveDiacritics) => {
+                        segment::data_types::index::BuiltinCharFilter::RemoveDiacritics
+                    }
+                    Ok(BuiltinCharFilter::AsciiFolding) => {
+                        segment::data_types::index::BuiltinCharFilter::AsciiFolding
+                    }
+                    Err(_) => {
+                        return Err(Status::invalid_argument(format!(
+                            "unknown char filter: {builtin}"
+                        )));
+                    }
+                };
+                Ok(segment::data_types::index::CharFilter::Builtin(builtin))
+            }
+            Some(char_filter::CharFilter::Mapping(MappingCharFilter { mappings })) => {
+                Ok(segment::data_types::index::CharFilter::Mapping(
+                    segment::data_types::index::MappingCharFilter {
+                        r#type: segment::data_types::index::Mapping::Mapping,
+                        mappings: mappings.into_iter().collect(),
+                    },
+                ))
+            }
+            None => Err(Status::invalid_argument("char filter is not specified")),
+        }
+    }
+}
+
+impl From<Synonyms> for segment::data_types::index::SynonymsInterface {
+    fn from(value: Synonyms) -> Self {
+        let Synonyms { groups, file } = value;
+
+        let groups = if groups.is_empty() {
+            None
+        } else {
+            Some(groups.into_iter().map(|group| group.tokens).collect())
+        };
+
+        segment::data_types::index::SynonymsInterface::Set(
+            segment::data_types::index::SynonymsSet { groups, file },
+        )
+    }
+}
+
 impl TryFrom<StemmingParams> for segment::data_types::index::StemmingAlgorithm {
     type Error = Status;
 
@@ -782,20 +982,35 @@ impl From<segment::types::QuantizationSearchParams> for QuantizationSearchParams
     }
 }
 
-impl From<SearchParams> for segment::types::SearchParams {
-    fn from(params: SearchParams) -> Self {
+impl TryFrom<SearchParams> for segment::types::SearchParams {
+    type Error = Status;
+
+    fn try_from(params: SearchParams) -> Result<Self, Self::Error> {
         let SearchParams {
             hnsw_ef,
             exact,
             quantization,
             indexed_only,
+            ivf_nprobe,
+            matryoshka_dim,
+            sparse_algorithm,
         } = params;
-        Self {
+        let sparse_algorithm = sparse_algorithm
+            .map(|x| {
+                SparseSearchAlgorithm::try_from(x).map_err(|_| {
+                    Status::invalid_argument(format!("Unknown sparse search algorithm: {x}"))
+                })
+            })
+            .transpose()?;
+        Ok(Self {
             hnsw_ef: hnsw_ef.map(|x| x as usize),
             exact: exact.unwrap_or(false),
             quantization: quantization.map(|q| q.into()),
             indexed_only: indexed_only.unwrap_or(false),
-        }
+            ivf_nprobe: ivf_nprobe.map(|x| x as usize),
+            matryoshka_dim: matryoshka_dim.map(|x| x as usize),
+            sparse_algorithm: sparse_algorithm.map(Into::into),
+        })
     }
 }
 
@@ -806,12 +1021,42 @@ impl From<segment::types::SearchParams> for SearchParams {
             exact,
             quantization,
             indexed_only,
+            ivf_nprobe,
+            matryoshka_dim,
+            sparse_algorithm,
         } = params;
         Self {
             hnsw_ef: hnsw_ef.map(|x| x as u64),
             exact: Some(exact),
             quantization: quantization.map(|q| q.into()),
             indexed_only: Some(indexed_only),
+            ivf_nprobe: ivf_nprobe.map(|x| x as u64),
+            matryoshka_dim: matryoshka_dim.map(|x| x as u64),
+            sparse_algorithm: sparse_algorithm.map(|x| SparseSearchAlgorithm::from(x) as i32),
+        }
+    }
+}
+
+impl From<SparseSearchAlgorithm> for segment::types::SparseSearchAlgorithm {
+    fn from(value: SparseSearchAlgorithm) -> Self {
+        match value {
+            SparseSearchAlgorithm::Batch => segment::types::SparseSearchAlgorithm::Batch,
+            SparseSearchAlgorithm::BlockMaxWand => {
+                segment::types::SparseSearchAlgorithm::BlockMaxWand
+            }
+            SparseSearchAlgorithm::MaxScore => segment::types::SparseSearchAlgorithm::MaxScore,
+        }
+    }
+}
+
+impl From<segment::types::SparseSearchAlgorithm> for SparseSearchAlgorithm {
+    fn from(value: segment::types::SparseSearchAlgorithm) -> Self {
+        match value {
+            segment::types::SparseSearchAlgorithm::Batch => SparseSearchAlgorithm::Batch,
+            segment::types::SparseSearchAlgorithm::BlockMaxWand => {
+                SparseSearchAlgorithm::BlockMaxWand
+            }
+            segment::types::SparseSearchAlgorithm::MaxScore => SparseSearchAlgorithm::MaxScore,
         }
     }
 }
@@ -868,6 +1113,7 @@ impl TryFrom<rest::Record> for RetrievedPoint {
             vector,
             shard_key,
             order_value,
+            highlight,
         } = record;
         let retrieved_point = Self {
             id: Some(PointId::from(id)),
@@ -875,6 +1121,7 @@ impl TryFrom<rest::Record> for RetrievedPoint {
             vectors: vector.map(VectorsOutput::try_from).transpose()?,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(From::from),
+            highlight: highlight.map(convert_highlight_to_grpc).unwrap_or_default(),
         };
         Ok(retrieved_point)
     }
@@ -920,6 +1167,79 @@ impl TryFrom<OrderValue> for segment::data_types::order_by::OrderValue {
     }
 }
 
+impl From<segment::data_types::highlight::TextHighlight> for TextHighlight {
+    fn from(value: segment::data_types::highlight::TextHighlight) -> Self {
+        let segment::data_types::highlight::TextHighlight {
+            value_index,
+            offsets,
+            snippets,
+        } = value;
+        Self {
+            value_index: value_index as u64,
+            offsets: offsets
+                .into_iter()
+                .map(|[start, end]| TextMatchOffsets {
+                    start: start as u64,
+                    end: end as u64,
+                })
+                .collect(),
+            snippets: snippets.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<TextHighlight> for segment::data_types::highlight::TextHighlight {
+    fn from(value: TextHighlight) -> Self {
+        let TextHighlight {
+            value_index,
+            offsets,
+            snippets,
+        } = value;
+        Self {
+            value_index: value_index as usize,
+            offsets: offsets
+                .into_iter()
+                .map(|TextMatchOffsets { start, end }| [start as usize, end as usize])
+                .collect(),
+            snippets: (!snippets.is_empty()).then_some(snippets),
+        }
+    }
+}
+
+impl From<segment::data_types::highlight::HighlightParams> for HighlightParams {
+    fn from(value: segment::data_types::highlight::HighlightParams) -> Self {
+        let segment::data_types::highlight::HighlightParams {
+            snippets,
+            pre_tag,
+            post_tag,
+            context,
+        } = value;
+        Self {
+            snippets,
+            pre_tag,
+            post_tag,
+            context: context.map(|context| context as u64),
+        }
+    }
+}
+
+impl From<HighlightParams> for segment::data_types::highlight::HighlightParams {
+    fn from(value: HighlightParams) -> Self {
+        let HighlightParams {
+            snippets,
+            pre_tag,
+            post_tag,
+            context,
+        } = value;
+        Self {
+            snippets,
+            pre_tag,
+            post_tag,
+            context: context.map(|context| context as usize),
+        }
+    }
+}
+
 impl From<segment::types::ScoredPoint> for ScoredPoint {
     fn from(point: segment::types::ScoredPoint) -> Self {
         let segment::types::ScoredPoint {
@@ -930,6 +1250,7 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            highlight,
         } = point;
         Self {
             id: Some(PointId::from(id)),
@@ -939,6 +1260,7 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::from),
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            highlight: highlight.map(convert_highlight_to_grpc).unwrap_or_default(),
         }
     }
 }
@@ -954,6 +1276,7 @@ impl TryFrom<rest::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            highlight,
         } = point;
         Ok(Self {
             id: Some(PointId::from(id)),
@@ -963,6 +1286,7 @@ impl TryFrom<rest::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::try_from).transpose()?,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            highlight: highlight.map(convert_highlight_to_grpc).unwrap_or_default(),
         })
     }
 }
@@ -1055,6 +1379,7 @@ impl From<segment::types::ScalarQuantization> for ScalarQuantization {
         ScalarQuantization {
             r#type: match config.r#type {
                 segment::types::ScalarType::Int8 => QuantizationType::Int8 as i32,
+                segment::types::ScalarType::Int4 => QuantizationType::Int4 as i32,
             },
             quantile: config.quantile,
             always_ram: config.always_ram,
@@ -1075,6 +1400,7 @@ impl TryFrom<ScalarQuantization> for segment::types::ScalarQuantization {
             scalar: segment::types::ScalarQuantizationConfig {
                 r#type: match QuantizationType::try_from(r#type).ok() {
                     Some(QuantizationType::Int8) => segment::types::ScalarType::Int8,
+                    Some(QuantizationType::Int4) => segment::types::ScalarType::Int4,
                     Some(QuantizationType::UnknownQuantization) | None => {
                         return Err(Status::invalid_argument("Unknown quantization type"));
                     }
@@ -1092,6 +1418,7 @@ impl From<segment::types::ProductQuantization> for ProductQuantization {
         let segment::types::ProductQuantizationConfig {
             compression,
             always_ram,
+            opq,
         } = product;
         ProductQuantization {
             compression: match compression {
@@ -1102,6 +1429,7 @@ impl From<segment::types::ProductQuantization> for ProductQuantization {
                 segment::types::CompressionRatio::X64 => CompressionRatio::X64 as i32,
             },
             always_ram,
+            opq,
         }
     }
 }
@@ -1113,6 +1441,7 @@ impl TryFrom<ProductQuantization> for segment::types::ProductQuantization {
         let ProductQuantization {
             compression,
             always_ram,
+            opq,
         } = value;
         Ok(segment::types::ProductQuantization {
             product: segment::types::ProductQuantizationConfig {
@@ -1129,6 +1458,7 @@ impl TryFrom<ProductQuantization> for segment::types::ProductQuantization {
                     Ok(CompressionRatio::X64) => segment::types::CompressionRatio::X64,
                 },
                 always_ram,
+                opq,
             },
         })
     }
@@ -1211,6 +1541,23 @@ impl TryFrom<BinaryQuantization> for segment::types::BinaryQuantization {
     }
 }
 
+impl From<segment::types::RabitqQuantization> for RabitqQuantization {
+    fn from(value: segment::types::RabitqQuantization) -> Self {
+        let segment::types::RabitqQuantization { rabitq } = value;
+        let segment::types::RabitqQuantizationConfig { always_ram } = rabitq;
+        RabitqQuantization { always_ram }
+    }
+}
+
+impl From<RabitqQuantization> for segment::types::RabitqQuantization {
+    fn from(value: RabitqQuantization) -> Self {
+        let RabitqQuantization { always_ram } = value;
+        segment::types::RabitqQuantization {
+            rabitq: segment::types::RabitqQuantizationConfig { always_ram },
+        }
+    }
+}
+
 impl From<segment::types::QuantizationConfig> for QuantizationConfig {
     fn from(value: segment::types::QuantizationConfig) -> Self {
         match value {
@@ -1229,6 +1576,11 @@ impl From<segment::types::QuantizationConfig> for QuantizationConfig {
                     binary.into(),
                 )),
             },
+            segment::types::QuantizationConfig::Rabitq(rabitq) => Self {
+                quantization: Some(super::qdrant::quantization_config::Quantization::Rabitq(
+                    rabitq.into(),
+                )),
+            },
         }
     }
 }
@@ -1250,6 +1602,9 @@ impl TryFrom<QuantizationConfig> for segment::types::QuantizationConfig {
             super::qdrant::quantization_config::Quantization::Binary(config) => Ok(
                 segment::types::QuantizationConfig::Binary(config.try_into()?),
             ),
+            super::qdrant::quantization_config::Quantization::Rabitq(config) => {
+                Ok(segment::types::QuantizationConfig::Rabitq(config.into()))
+            }
         }
     }
 }
@@ -1315,9 +1670,13 @@ impl From<segment::types::BinaryQuantizationQueryEncoding> for BinaryQuantizatio
 
 impl From<segment::types::MultiVectorConfig> for MultiVectorConfig {
     fn from(value: segment::types::MultiVectorConfig) -> Self {
-        let segment::types::MultiVectorConfig { comparator } = value;
+        let segment::types::MultiVectorConfig {
+            comparator,
+            token_pooling,
+        } = value;
         Self {
             comparator: MultiVectorComparator::from(comparator) as i32,
+            token_pooling: token_pooling.map(TokenPooling::from),
         }
     }
 }
@@ -1326,6 +1685,28 @@ impl From<segment::types::MultiVectorComparator> for MultiVectorComparator {
     fn from(value: segment::types::MultiVectorComparator) -> Self {
         match value {
             segment::types::MultiVectorComparator::MaxSim => MultiVectorComparator::MaxSim,
+            segment::types::MultiVectorComparator::MaxSimNormalized => {
+                MultiVectorComparator::MaxSimNormalized
+            }
+            segment::types::MultiVectorComparator::Chamfer => MultiVectorComparator::Chamfer,
+        }
+    }
+}
+
+impl From<segment::types::TokenPoolingConfig> for TokenPooling {
+    fn from(value: segment::types::TokenPoolingConfig) -> Self {
+        let segment::types::TokenPoolingConfig { target_count } = value;
+        Self {
+            target_count: target_count as u64,
+        }
+    }
+}
+
+impl From<TokenPooling> for segment::types::TokenPoolingConfig {
+    fn from(value: TokenPooling) -> Self {
+        let TokenPooling { target_count } = value;
+        Self {
+            target_count: target_count as usize,
         }
     }
 }
@@ -1334,11 +1715,15 @@ impl TryFrom<MultiVectorConfig> for segment::types::MultiVectorConfig {
     type Error = Status;
 
     fn try_from(value: MultiVectorConfig) -> Result<Self, Self::Error> {
-        let MultiVectorConfig { comparator } = value;
+        let MultiVectorConfig {
+            comparator,
+            token_pooling,
+        } = value;
         let comparator = MultiVectorComparator::try_from(comparator)
             .map_err(|_| Status::invalid_argument("Unknown multi vector comparator"))?;
         Ok(segment::types::MultiVectorConfig {
             comparator: segment::types::MultiVectorComparator::from(comparator),
+            token_pooling: token_pooling.map(segment::types::TokenPoolingConfig::from),
         })
     }
 }
@@ -1347,6 +1732,246 @@ impl From<MultiVectorComparator> for segment::types::MultiVectorComparator {
     fn from(value: MultiVectorComparator) -> Self {
         match value {
             MultiVectorComparator::MaxSim => segment::types::MultiVectorComparator::MaxSim,
+            MultiVectorComparator::MaxSimNormalized => {
+                segment::types::MultiVectorComparator::MaxSimNormalized
+            }
+            MultiVectorComparator::Chamfer => segment::types::MultiVectorComparator::Chamfer,
+        }
+    }
+}
+
+impl From<segment::types::VdeIndexConfig> for VdeConfig {
+    fn from(value: segment::types::VdeIndexConfig) -> Self {
+        let segment::types::VdeIndexConfig {
+            index_type,
+            storage_type,
+            config_json,
+            full_scan_threshold,
+        } = value;
+        Self {
+            index_type: Some(VdeIndexType::from(index_type) as i32),
+            storage_type: Some(VdeStorageType::from(storage_type) as i32),
+            config_json,
+            full_scan_threshold: full_scan_threshold.map(|x| x as u64),
+        }
+    }
+}
+
+impl From<segment::types::VdeIndexType> for VdeIndexType {
+    fn from(value: segment::types::VdeIndexType) -> Self {
+        match value {
+            segment::types::VdeIndexType::VsagHnsw => VdeIndexType::VsagHnsw,
+        }
+    }
+}
+
+impl From<segment::types::VdeStorageType> for VdeStorageType {
+    fn from(value: segment::types::VdeStorageType) -> Self {
+        match value {
+            segment::types::VdeStorageType::Zendb => VdeStorageType::Zendb,
+            segment::types::VdeStorageType::Memory => VdeStorageType::Memory,
+        }
+    }
+}
+
+impl TryFrom<VdeConfig> for segment::types::VdeIndexConfig {
+    type Error = Status;
+
+    fn try_from(value: VdeConfig) -> Result<Self, Self::Error> {
+        let VdeConfig {
+            index_type,
+            storage_type,
+            config_json,
+            full_scan_threshold,
+        } = value;
+        let index_type = index_type
+            .map(|index_type| {
+                VdeIndexType::try_from(index_type)
+                    .map_err(|_| Status::invalid_argument("Unknown VDE index type"))
+            })
+            .transpose()?;
+        let storage_type = storage_type
+            .map(|storage_type| {
+                VdeStorageType::try_from(storage_type)
+                    .map_err(|_| Status::invalid_argument("Unknown VDE storage type"))
+            })
+            .transpose()?;
+        Ok(segment::types::VdeIndexConfig {
+            index_type: index_type.map(Into::into).unwrap_or_default(),
+            storage_type: storage_type.map(Into::into).unwrap_or_default(),
+            config_json,
+            full_scan_threshold: full_scan_threshold.map(|x| x as usize),
+        })
+    }
+}
+
+impl From<VdeIndexType> for segment::types::VdeIndexType {
+    fn from(value: VdeIndexType) -> Self {
+        match value {
+            VdeIndexType::VsagHnsw => segment::types::VdeIndexType::VsagHnsw,
+        }
+    }
+}
+
+impl From<VdeStorageType> for segment::types::VdeStorageType {
+    fn from(value: VdeStorageType) -> Self {
+        match value {
+            VdeStorageType::Zendb => segment::types::VdeStorageType::Zendb,
+            VdeStorageType::Memory => segment::types::VdeStorageType::Memory,
+        }
+    }
+}
+
+impl From<segment::types::DiskAnnConfig> for DiskAnnConfig {
+    fn from(value: segment::types::DiskAnnConfig) -> Self {
+        let segment::types::DiskAnnConfig {
+            max_degree,
+            build_list_size,
+            search_list_size,
+            beam_width,
+            pq_compression,
+            full_scan_threshold,
+            on_disk,
+        } = value;
+        Self {
+            max_degree: Some(max_degree as u64),
+            build_list_size: Some(build_list_size as u64),
+            search_list_size: Some(search_list_size as u64),
+            beam_width: Some(beam_width as u64),
+            pq_compression: Some(CompressionRatio::from(pq_compression) as i32),
+            full_scan_threshold: full_scan_threshold.map(|x| x as u64),
+            on_disk,
+        }
+    }
+}
+
+impl TryFrom<DiskAnnConfig> for segment::types::DiskAnnConfig {
+    type Error = Status;
+
+    fn try_from(value: DiskAnnConfig) -> Result<Self, Self::Error> {
+        let DiskAnnConfig {
+            max_degree,
+            build_list_size,
+            search_list_size,
+            beam_width,
+            pq_compression,
+            full_scan_threshold,
+            on_disk,
+        } = value;
+        let default = segment::types::DiskAnnConfig::default();
+        let pq_compression = pq_compression
+            .map(|compression| {
+                CompressionRatio::try_from(compression)
+                    .map_err(|_| Status::invalid_argument("Unknown compression ratio"))
+            })
+            .transpose()?;
+        Ok(segment::types::DiskAnnConfig {
+            max_degree: max_degree.map_or(default.max_degree, |x| x as usize),
+            build_list_size: build_list_size.map_or(default.build_list_size, |x| x as usize),
+            search_list_size: search_list_size.map_or(default.search_list_size, |x| x as usize),
+            beam_width: beam_width.map_or(default.beam_width, |x| x as usize),
+            pq_compression: pq_compression.map_or(default.pq_compression, Into::into),
+            full_scan_threshold: full_scan_threshold.map(|x| x as usize),
+            on_disk,
+        })
+    }
+}
+
+impl From<segment::types::IvfConfig> for IvfConfig {
+    fn from(value: segment::types::IvfConfig) -> Self {
+        let segment::types::IvfConfig {
+            nlist,
+            nprobe,
+            residuals,
+            pq_compression,
+            full_scan_threshold,
+            on_disk,
+        } = value;
+        Self {
+            nlist: nlist.map(|x| x as u64),
+            nprobe: Some(nprobe as u64),
+            residuals: residuals.map(|residuals| IvfResiduals::from(residuals) as i32),
+            pq_compression: Some(CompressionRatio::from(pq_compression) as i32),
+            full_scan_threshold: full_scan_threshold.map(|x| x as u64),
+            on_disk,
+        }
+    }
+}
+
+impl TryFrom<IvfConfig> for segment::types::IvfConfig {
+    type Error = Status;
+
+    fn try_from(value: IvfConfig) -> Result<Self, Self::Error> {
+        let IvfConfig {
+            nlist,
+            nprobe,
+            residuals,
+            pq_compression,
+            full_scan_threshold,
+            on_disk,
+        } = value;
+        let default = segment::types::IvfConfig::default();
+        let residuals = residuals
+            .map(|residuals| {
+                IvfResiduals::try_from(residuals)
+                    .map_err(|_| Status::invalid_argument("Unknown IVF residuals quantization"))
+            })
+            .transpose()?;
+        let pq_compression = pq_compression
+            .map(|compression| {
+                CompressionRatio::try_from(compression)
+                    .map_err(|_| Status::invalid_argument("Unknown compression ratio"))
+            })
+            .transpose()?;
+        Ok(segment::types::IvfConfig {
+            nlist: nlist.map(|x| x as usize),
+            nprobe: nprobe.map_or(default.nprobe, |x| x as usize),
+            residuals: residuals.map(Into::into),
+            pq_compression: pq_compression.map_or(default.pq_compression, Into::into),
+            full_scan_threshold: full_scan_threshold.map(|x| x as usize),
+            on_disk,
+        })
+    }
+}
+
+impl From<segment::types::IvfResiduals> for IvfResiduals {
+    fn from(value: segment::types::IvfResiduals) -> Self {
+        match value {
+            segment::types::IvfResiduals::Scalar => IvfResiduals::Scalar,
+            segment::types::IvfResiduals::Product => IvfResiduals::Product,
+        }
+    }
+}
+
+impl From<IvfResiduals> for segment::types::IvfResiduals {
+    fn from(value: IvfResiduals) -> Self {
+        match value {
+            IvfResiduals::Scalar => segment::types::IvfResiduals::Scalar,
+            IvfResiduals::Product => segment::types::IvfResiduals::Product,
+        }
+    }
+}
+
+impl From<segment::types::CompressionRatio> for CompressionRatio {
+    fn from(value: segment::types::CompressionRatio) -> Self {
+        match value {
+            segment::types::CompressionRatio::X4 => CompressionRatio::X4,
+            segment::types::CompressionRatio::X8 => CompressionRatio::X8,
+            segment::types::CompressionRatio::X16 => CompressionRatio::X16,
+            segment::types::CompressionRatio::X32 => CompressionRatio::X32,
+            segment::types::CompressionRatio::X64 => CompressionRatio::X64,
+        }
+    }
+}
+
+impl From<CompressionRatio> for segment::types::CompressionRatio {
+    fn from(value: CompressionRatio) -> Self {
+        match value {
+            CompressionRatio::X4 => segment::types::CompressionRatio::X4,
+            CompressionRatio::X8 => segment::types::CompressionRatio::X8,
+            CompressionRatio::X16 => segment::types::CompressionRatio::X16,
+            CompressionRatio::X32 => segment::types::CompressionRatio::X32,
+            CompressionRatio::X64 => segment::types::CompressionRatio::X64,
         }
     }
 }
@@ -1899,12 +2524,43 @@ impl TryFrom<Match> for segment::types::Match {
                 MatchValue::TextAny(text_any) => {
                     segment::types::Match::TextAny(segment::types::MatchTextAny { text_any })
                 }
+                MatchValue::Fuzzy(fuzzy) => segment::types::Match::Fuzzy(fuzzy.into()),
             }),
             _ => Err(Status::invalid_argument("Malformed Match condition")),
         }
     }
 }
 
+impl From<MatchFuzzy> for segment::types::MatchFuzzy {
+    fn from(value: MatchFuzzy) -> Self {
+        let MatchFuzzy {
+            text,
+            max_edits,
+            prefix_len,
+        } = value;
+        Self {
+            text,
+            max_edits: max_edits as usize,
+            prefix_len: prefix_len.map(|prefix_len| prefix_len as usize),
+        }
+    }
+}
+
+impl From<segment::types::MatchFuzzy> for MatchFuzzy {
+    fn from(value: segment::types::MatchFuzzy) -> Self {
+        let segment::types::MatchFuzzy {
+            text,
+            max_edits,
+            prefix_len,
+        } = value;
+        Self {
+            text,
+            max_edits: max_edits as u32,
+            prefix_len: prefix_len.map(|prefix_len| prefix_len as u32),
+        }
+    }
+}
+
 impl From<segment::types::Match> for Match {
     fn from(value: segment::types::Match) -> Self {
         let match_value = match value {
@@ -1942,6 +2598,7 @@ impl From<segment::types::Match> for Match {
             segment::types::Match::TextAny(segment::types::MatchTextAny { text_any }) => {
                 MatchValue::TextAny(text_any)
             }
+            segment::types::Match::Fuzzy(fuzzy) => MatchValue::Fuzzy(fuzzy.into()),
         };
         Self {
             match_value: Some(match_value),
@@ -2028,6 +2685,28 @@ impl From<segment::data_types::order_by::OrderBy> for OrderBy {
     }
 }
 
+impl TryFrom<TextQuery> for segment::data_types::text_query::TextQuery {
+    type Error = Status;
+
+    fn try_from(value: TextQuery) -> Result<Self, Self::Error> {
+        let TextQuery { key, query } = value;
+        Ok(Self {
+            key: json::json_path_from_proto(&key)?,
+            query,
+        })
+    }
+}
+
+impl From<segment::data_types::text_query::TextQuery> for TextQuery {
+    fn from(value: segment::data_types::text_query::TextQuery) -> Self {
+        let segment::data_types::text_query::TextQuery { key, query } = value;
+        Self {
+            key: key.to_string(),
+            query,
+        }
+    }
+}
+
 impl From<segment::data_types::order_by::StartFrom> for StartFrom {
     fn from(value: segment::data_types::order_by::StartFrom) -> Self {
         Self {
@@ -2127,6 +2806,7 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
             on_disk,
             payload_m,
             copy_vectors,
+            matryoshka_dim,
         } = hnsw_config;
         Self {
             m: m.unwrap_or_default() as usize,
@@ -2136,6 +2816,7 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
             on_disk,
             payload_m: payload_m.map(|x| x as usize),
             copy_vectors,
+            matryoshka_dim: matryoshka_dim.map(|x| x as usize),
         }
     }
 }
@@ -2464,6 +3145,8 @@ impl TryFrom<Distance> for segment::types::Distance {
             Distance::Euclid => segment::types::Distance::Euclid,
             Distance::Dot => segment::types::Distance::Dot,
             Distance::Manhattan => segment::types::Distance::Manhattan,
+            Distance::Hamming => segment::types::Distance::Hamming,
+            Distance::Jaccard => segment::types::Distance::Jaccard,
         })
     }
 }
@@ -2801,7 +3484,7 @@ impl TryFrom<SearchPoints> for rest::SearchRequestInternal {
         Ok(Self {
             vector,
             filter: filter.map(|f| f.try_into()).transpose()?,
-            params: params.map(|p| p.into()),
+            params: params.map(TryInto::try_into).transpose()?,
             limit: limit as usize,
             offset: offset.map(|x| x as usize),
             with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
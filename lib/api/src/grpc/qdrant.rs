@@ -127,7 +127,17 @@ pub struct VectorParams {
     pub datatype: ::core::option::Option<i32>,
     /// Configuration for multi-vector search
     #[prost(message, optional, tag = "7")]
+    #[validate(nested)]
     pub multivector_config: ::core::option::Option<MultiVectorConfig>,
+    /// Configuration of index managed by VDE. If set - VDE index is built instead of HNSW
+    #[prost(message, optional, tag = "8")]
+    pub vde_config: ::core::option::Option<VdeConfig>,
+    /// Configuration of on-disk DiskANN graph index. If set - DiskANN index is built instead of HNSW
+    #[prost(message, optional, tag = "9")]
+    pub diskann_config: ::core::option::Option<DiskAnnConfig>,
+    /// Configuration of IVF index. If set - IVF index is built instead of HNSW
+    #[prost(message, optional, tag = "10")]
+    pub ivf_config: ::core::option::Option<IvfConfig>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -145,6 +155,15 @@ pub struct VectorParamsDiff {
     /// If true - serve vectors from disk. If set to false, the vectors will be loaded in RAM.
     #[prost(bool, optional, tag = "3")]
     pub on_disk: ::core::option::Option<bool>,
+    /// Update params for VDE index. If none - it is left unchanged.
+    #[prost(message, optional, tag = "4")]
+    pub vde_config: ::core::option::Option<VdeConfigDiff>,
+    /// Update params for DiskANN index. If none - it is left unchanged.
+    #[prost(message, optional, tag = "5")]
+    pub diskann_config: ::core::option::Option<DiskAnnConfigDiff>,
+    /// Update params for IVF index. If none - it is left unchanged.
+    #[prost(message, optional, tag = "6")]
+    pub ivf_config: ::core::option::Option<IvfConfigDiff>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -209,6 +228,21 @@ pub mod vectors_config_diff {
         ParamsMap(super::VectorParamsDiffMap),
     }
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Bm25Params {
+    /// Term frequency saturation. Default: 1.2
+    #[prost(float, optional, tag = "1")]
+    #[validate(range(min = 0.0))]
+    pub k1: ::core::option::Option<f32>,
+    /// Document length normalization, in range \[0, 1\]. Default: 0.75
+    #[prost(float, optional, tag = "2")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub b: ::core::option::Option<f32>,
+}
+#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -219,17 +253,34 @@ pub struct SparseVectorParams {
     /// If set - apply modifier to the vector values
     #[prost(enumeration = "Modifier", optional, tag = "2")]
     pub modifier: ::core::option::Option<i32>,
+    /// Parameters of `Bm25` modifier
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub bm25_params: ::core::option::Option<Bm25Params>,
 }
+#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SparseVectorConfig {
     #[prost(map = "string, message", tag = "1")]
+    #[validate(nested)]
     pub map: ::std::collections::HashMap<
         ::prost::alloc::string::String,
         SparseVectorParams,
     >,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenPooling {
+    /// Max count of vectors stored for each multivector
+    #[prost(uint64, tag = "1")]
+    #[validate(range(min = 1))]
+    pub target_count: u64,
+}
+#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -237,6 +288,133 @@ pub struct MultiVectorConfig {
     /// Comparator for multi-vector search
     #[prost(enumeration = "MultiVectorComparator", tag = "1")]
     pub comparator: i32,
+    /// Reduce count of vectors in each multivector at ingest time by hierarchical clustering
+    #[prost(message, optional, tag = "2")]
+    #[validate(nested)]
+    pub token_pooling: ::core::option::Option<TokenPooling>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VdeConfig {
+    /// Type of index built by VDE. Default: VsagHnsw
+    #[prost(enumeration = "VdeIndexType", optional, tag = "1")]
+    pub index_type: ::core::option::Option<i32>,
+    /// Storage VDE keeps indexed vectors in. Default: Zendb
+    #[prost(enumeration = "VdeStorageType", optional, tag = "2")]
+    pub storage_type: ::core::option::Option<i32>,
+    /// Engine specific tuning parameters, a JSON object passed to VDE as-is
+    #[prost(string, optional, tag = "3")]
+    pub config_json: ::core::option::Option<::prost::alloc::string::String>,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over VDE search
+    #[prost(uint64, optional, tag = "4")]
+    pub full_scan_threshold: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VdeConfigDiff {
+    #[prost(oneof = "vde_config_diff::Config", tags = "1, 2")]
+    pub config: ::core::option::Option<vde_config_diff::Config>,
+}
+/// Nested message and enum types in `VdeConfigDiff`.
+pub mod vde_config_diff {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Config {
+        #[prost(message, tag = "1")]
+        Params(super::VdeConfig),
+        #[prost(message, tag = "2")]
+        Disabled(super::Disabled),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiskAnnConfig {
+    /// Maximal number of neighbours of a graph node. Default: 64
+    #[prost(uint64, optional, tag = "1")]
+    pub max_degree: ::core::option::Option<u64>,
+    /// Size of the search list used to find neighbours during the build. Default: 100
+    #[prost(uint64, optional, tag = "2")]
+    pub build_list_size: ::core::option::Option<u64>,
+    /// Size of the search list used during the search. Default: 100
+    #[prost(uint64, optional, tag = "3")]
+    pub search_list_size: ::core::option::Option<u64>,
+    /// Number of graph nodes read from disk at once during the search. Default: 4
+    #[prost(uint64, optional, tag = "4")]
+    pub beam_width: ::core::option::Option<u64>,
+    /// Compression ratio of PQ codes kept in RAM to navigate the graph. Default: x16
+    #[prost(enumeration = "CompressionRatio", optional, tag = "5")]
+    pub pq_compression: ::core::option::Option<i32>,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over graph search
+    #[prost(uint64, optional, tag = "6")]
+    pub full_scan_threshold: ::core::option::Option<u64>,
+    /// If true - graph is read from disk on search. If false - it is loaded into RAM. Default: true
+    #[prost(bool, optional, tag = "7")]
+    pub on_disk: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiskAnnConfigDiff {
+    #[prost(oneof = "disk_ann_config_diff::Config", tags = "1, 2")]
+    pub config: ::core::option::Option<disk_ann_config_diff::Config>,
+}
+/// Nested message and enum types in `DiskAnnConfigDiff`.
+pub mod disk_ann_config_diff {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Config {
+        #[prost(message, tag = "1")]
+        Params(super::DiskAnnConfig),
+        #[prost(message, tag = "2")]
+        Disabled(super::Disabled),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IvfConfig {
+    /// Number of inverted lists. If not set - 4 * sqrt(N) lists for N indexed vectors
+    #[prost(uint64, optional, tag = "1")]
+    pub nlist: ::core::option::Option<u64>,
+    /// Number of inverted lists probed during the search. Default: 16
+    #[prost(uint64, optional, tag = "2")]
+    pub nprobe: ::core::option::Option<u64>,
+    /// Quantization of vector residuals. If not set - probed vectors are scored with original vectors
+    #[prost(enumeration = "IvfResiduals", optional, tag = "3")]
+    pub residuals: ::core::option::Option<i32>,
+    /// Compression ratio of product quantized residuals. Default: x16
+    #[prost(enumeration = "CompressionRatio", optional, tag = "4")]
+    pub pq_compression: ::core::option::Option<i32>,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over IVF search
+    #[prost(uint64, optional, tag = "5")]
+    pub full_scan_threshold: ::core::option::Option<u64>,
+    /// If true - inverted lists are read from disk on search. If false - they are loaded into RAM. Default: false
+    #[prost(bool, optional, tag = "6")]
+    pub on_disk: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IvfConfigDiff {
+    #[prost(oneof = "ivf_config_diff::Config", tags = "1, 2")]
+    pub config: ::core::option::Option<ivf_config_diff::Config>,
+}
+/// Nested message and enum types in `IvfConfigDiff`.
+pub mod ivf_config_diff {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Config {
+        #[prost(message, tag = "1")]
+        Params(super::IvfConfig),
+        #[prost(message, tag = "2")]
+        Disabled(super::Disabled),
+    }
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -413,6 +591,12 @@ pub struct HnswConfigDiff {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[prost(bool, optional, tag = "7")]
     pub copy_vectors: ::core::option::Option<bool>,
+    /// Build the graph on the first N dimensions of dense vectors, for Matryoshka embeddings.
+    /// Searches traverse the graph with the same dimensions and rescore found points with all dimensions.
+    /// Ignored for quantized vectors. If not set, all dimensions are used.
+    #[prost(uint64, optional, tag = "8")]
+    #[validate(range(min = 1))]
+    pub matryoshka_dim: ::core::option::Option<u64>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -541,6 +725,9 @@ pub struct ProductQuantization {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[prost(bool, optional, tag = "2")]
     pub always_ram: ::core::option::Option<bool>,
+    /// If true - learn OPQ rotation together with the codebooks. Ignored for Manhattan distance
+    #[prost(bool, optional, tag = "3")]
+    pub opq: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -622,8 +809,17 @@ pub struct BinaryQuantization {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RabitqQuantization {
+    /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
+    #[prost(bool, optional, tag = "1")]
+    pub always_ram: ::core::option::Option<bool>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuantizationConfig {
-    #[prost(oneof = "quantization_config::Quantization", tags = "1, 2, 3")]
+    #[prost(oneof = "quantization_config::Quantization", tags = "1, 2, 3, 4")]
     #[validate(nested)]
     pub quantization: ::core::option::Option<quantization_config::Quantization>,
 }
@@ -639,6 +835,8 @@ pub mod quantization_config {
         Product(super::ProductQuantization),
         #[prost(message, tag = "3")]
         Binary(super::BinaryQuantization),
+        #[prost(message, tag = "4")]
+        Rabitq(super::RabitqQuantization),
     }
 }
 #[derive(validator::Validate)]
@@ -651,7 +849,7 @@ pub struct Disabled {}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QuantizationConfigDiff {
-    #[prost(oneof = "quantization_config_diff::Quantization", tags = "1, 2, 3, 4")]
+    #[prost(oneof = "quantization_config_diff::Quantization", tags = "1, 2, 3, 4, 5")]
     #[validate(nested)]
     pub quantization: ::core::option::Option<quantization_config_diff::Quantization>,
 }
@@ -669,6 +867,8 @@ pub mod quantization_config_diff {
         Disabled(super::Disabled),
         #[prost(message, tag = "4")]
         Binary(super::BinaryQuantization),
+        #[prost(message, tag = "5")]
+        Rabitq(super::RabitqQuantization),
     }
 }
 #[derive(validator::Validate)]
@@ -843,6 +1043,7 @@ pub struct CreateCollection {
     pub sharding_method: ::core::option::Option<i32>,
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "16")]
+    #[validate(nested)]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
     /// Configuration for strict mode
     #[prost(message, optional, tag = "17")]
@@ -890,6 +1091,7 @@ pub struct UpdateCollection {
     pub quantization_config: ::core::option::Option<QuantizationConfigDiff>,
     /// New sparse vector parameters
     #[prost(message, optional, tag = "8")]
+    #[validate(nested)]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
     /// New strict mode configuration
     #[prost(message, optional, tag = "9")]
@@ -1065,6 +1267,17 @@ pub struct StopwordsSet {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NgramParams {
+    /// Minimal size of n-grams, in characters. Default is 3.
+    #[prost(uint64, optional, tag = "1")]
+    pub min: ::core::option::Option<u64>,
+    /// Maximal size of n-grams, in characters. Default is 3.
+    #[prost(uint64, optional, tag = "2")]
+    pub max: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TextIndexParams {
     /// Tokenizer type
     #[prost(enumeration = "TokenizerType", tag = "1")]
@@ -1090,6 +1303,66 @@ pub struct TextIndexParams {
     /// Set an algorithm for stemming.
     #[prost(message, optional, tag = "8")]
     pub stemmer: ::core::option::Option<StemmingAlgorithm>,
+    /// Character filters, applied to each token in order
+    #[prost(message, repeated, tag = "9")]
+    pub char_filters: ::prost::alloc::vec::Vec<CharFilter>,
+    /// Synonyms, expanded at query time
+    #[prost(message, optional, tag = "10")]
+    pub synonyms: ::core::option::Option<Synonyms>,
+    /// Sizes of n-grams, used by the Ngram tokenizer
+    #[prost(message, optional, tag = "11")]
+    pub ngram: ::core::option::Option<NgramParams>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MappingCharFilter {
+    /// Replace all occurrences of each key with its value
+    #[prost(map = "string, string", tag = "1")]
+    pub mappings: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CharFilter {
+    #[prost(oneof = "char_filter::CharFilter", tags = "1, 2")]
+    pub char_filter: ::core::option::Option<char_filter::CharFilter>,
+}
+/// Nested message and enum types in `CharFilter`.
+pub mod char_filter {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum CharFilter {
+        /// Built-in character filter
+        #[prost(enumeration = "super::BuiltinCharFilter", tag = "1")]
+        Builtin(i32),
+        /// Custom mapping of character sequences
+        #[prost(message, tag = "2")]
+        Mapping(super::MappingCharFilter),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SynonymGroup {
+    /// Interchangeable tokens
+    #[prost(string, repeated, tag = "1")]
+    pub tokens: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Synonyms {
+    /// Groups of interchangeable tokens
+    #[prost(message, repeated, tag = "1")]
+    pub groups: ::prost::alloc::vec::Vec<SynonymGroup>,
+    /// Content of a synonyms file in Solr format
+    #[prost(string, optional, tag = "2")]
+    pub file: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1651,6 +1924,8 @@ pub enum Datatype {
     Float32 = 1,
     Uint8 = 2,
     Float16 = 3,
+    Binary = 4,
+    BFloat16 = 5,
 }
 impl Datatype {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1663,6 +1938,8 @@ impl Datatype {
             Datatype::Float32 => "Float32",
             Datatype::Uint8 => "Uint8",
             Datatype::Float16 => "Float16",
+            Datatype::Binary => "Binary",
+            Datatype::BFloat16 => "BFloat16",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1672,6 +1949,8 @@ impl Datatype {
             "Float32" => Some(Self::Float32),
             "Uint8" => Some(Self::Uint8),
             "Float16" => Some(Self::Float16),
+            "Binary" => Some(Self::Binary),
+            "BFloat16" => Some(Self::BFloat16),
             _ => None,
         }
     }
@@ -1683,6 +1962,8 @@ pub enum Modifier {
     None = 0,
     /// Apply Inverse Document Frequency
     Idf = 1,
+    /// Apply Inverse Document Frequency and BM25 saturation of the vector values
+    Bm25 = 2,
 }
 impl Modifier {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1693,6 +1974,7 @@ impl Modifier {
         match self {
             Modifier::None => "None",
             Modifier::Idf => "Idf",
+            Modifier::Bm25 => "Bm25",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1700,6 +1982,7 @@ impl Modifier {
         match value {
             "None" => Some(Self::None),
             "Idf" => Some(Self::Idf),
+            "Bm25" => Some(Self::Bm25),
             _ => None,
         }
     }
@@ -1709,6 +1992,10 @@ impl Modifier {
 #[repr(i32)]
 pub enum MultiVectorComparator {
     MaxSim = 0,
+    /// MaxSim divided by count of query vectors
+    MaxSimNormalized = 1,
+    /// Symmetric Chamfer similarity, average of normalized MaxSim in both directions
+    Chamfer = 2,
 }
 impl MultiVectorComparator {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1718,12 +2005,99 @@ impl MultiVectorComparator {
     pub fn as_str_name(&self) -> &'static str {
         match self {
             MultiVectorComparator::MaxSim => "MaxSim",
+            MultiVectorComparator::MaxSimNormalized => "MaxSimNormalized",
+            MultiVectorComparator::Chamfer => "Chamfer",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
             "MaxSim" => Some(Self::MaxSim),
+            "MaxSimNormalized" => Some(Self::MaxSimNormalized),
+            "Chamfer" => Some(Self::Chamfer),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VdeIndexType {
+    /// HNSW index of the VSAG library
+    VsagHnsw = 0,
+}
+impl VdeIndexType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VdeIndexType::VsagHnsw => "VsagHnsw",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VsagHnsw" => Some(Self::VsagHnsw),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VdeStorageType {
+    /// Persistent ZenDB (Btrieve2) storage
+    Zendb = 0,
+    /// Storage in memory (RAM)
+    Memory = 1,
+}
+impl VdeStorageType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            VdeStorageType::Zendb => "Zendb",
+            VdeStorageType::Memory => "Memory",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Zendb" => Some(Self::Zendb),
+            "Memory" => Some(Self::Memory),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IvfResiduals {
+    /// Scalar quantization of residuals into int8
+    Scalar = 0,
+    /// Product quantization of residuals
+    Product = 1,
+}
+impl IvfResiduals {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            IvfResiduals::Scalar => "Scalar",
+            IvfResiduals::Product => "Product",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Scalar" => Some(Self::Scalar),
+            "Product" => Some(Self::Product),
             _ => None,
         }
     }
@@ -1737,6 +2111,8 @@ pub enum Distance {
     Euclid = 2,
     Dot = 3,
     Manhattan = 4,
+    Hamming = 5,
+    Jaccard = 6,
 }
 impl Distance {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1750,6 +2126,8 @@ impl Distance {
             Distance::Euclid => "Euclid",
             Distance::Dot => "Dot",
             Distance::Manhattan => "Manhattan",
+            Distance::Hamming => "Hamming",
+            Distance::Jaccard => "Jaccard",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1760,6 +2138,8 @@ impl Distance {
             "Euclid" => Some(Self::Euclid),
             "Dot" => Some(Self::Dot),
             "Manhattan" => Some(Self::Manhattan),
+            "Hamming" => Some(Self::Hamming),
+            "Jaccard" => Some(Self::Jaccard),
             _ => None,
         }
     }
@@ -1858,6 +2238,7 @@ impl PayloadSchemaType {
 pub enum QuantizationType {
     UnknownQuantization = 0,
     Int8 = 1,
+    Int4 = 2,
 }
 impl QuantizationType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1868,6 +2249,7 @@ impl QuantizationType {
         match self {
             QuantizationType::UnknownQuantization => "UnknownQuantization",
             QuantizationType::Int8 => "Int8",
+            QuantizationType::Int4 => "Int4",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1875,6 +2257,7 @@ impl QuantizationType {
         match value {
             "UnknownQuantization" => Some(Self::UnknownQuantization),
             "Int8" => Some(Self::Int8),
+            "Int4" => Some(Self::Int4),
             _ => None,
         }
     }
@@ -1983,6 +2366,8 @@ pub enum TokenizerType {
     Whitespace = 2,
     Word = 3,
     Multilingual = 4,
+    Ngram = 5,
+    EdgeNgram = 6,
 }
 impl TokenizerType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1996,6 +2381,8 @@ impl TokenizerType {
             TokenizerType::Whitespace => "Whitespace",
             TokenizerType::Word => "Word",
             TokenizerType::Multilingual => "Multilingual",
+            TokenizerType::Ngram => "Ngram",
+            TokenizerType::EdgeNgram => "EdgeNgram",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2006,6 +2393,36 @@ impl TokenizerType {
             "Whitespace" => Some(Self::Whitespace),
             "Word" => Some(Self::Word),
             "Multilingual" => Some(Self::Multilingual),
+            "Ngram" => Some(Self::Ngram),
+            "EdgeNgram" => Some(Self::EdgeNgram),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BuiltinCharFilter {
+    /// Remove diacritical marks, e.g. `é` -> `e`
+    RemoveDiacritics = 0,
+    /// Replace latin letters with their closest ASCII equivalents, e.g. `ß` -> `ss`
+    AsciiFolding = 1,
+}
+impl BuiltinCharFilter {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BuiltinCharFilter::RemoveDiacritics => "RemoveDiacritics",
+            BuiltinCharFilter::AsciiFolding => "AsciiFolding",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RemoveDiacritics" => Some(Self::RemoveDiacritics),
+            "AsciiFolding" => Some(Self::AsciiFolding),
             _ => None,
         }
     }
@@ -4331,6 +4748,9 @@ pub mod vector_output {
 pub struct DenseVector {
     #[prost(float, repeated, tag = "1")]
     pub data: ::prost::alloc::vec::Vec<f32>,
+    /// Bit-packed binary vector, one byte per dimension. Used instead of `data`
+    #[prost(bytes = "vec", optional, tag = "2")]
+    pub binary: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -4854,6 +5274,21 @@ pub struct SearchParams {
     /// guarantee that all uploaded vectors will be included in search results
     #[prost(bool, optional, tag = "4")]
     pub indexed_only: ::core::option::Option<bool>,
+    /// Params relevant to IVF index. Number of inverted lists probed during the search.
+    /// Larger the value - more accurate the result, more time required for search.
+    #[prost(uint64, optional, tag = "5")]
+    #[validate(range(min = 1))]
+    pub ivf_nprobe: ::core::option::Option<u64>,
+    /// Params relevant to HNSW index. Number of first dimensions of dense vectors used to traverse the graph,
+    /// for Matryoshka embeddings. Found points are rescored with all dimensions.
+    /// If not set, the dimensions the graph was built with are used. Ignored for graphs built with all dimensions.
+    #[prost(uint64, optional, tag = "6")]
+    #[validate(range(min = 1))]
+    pub matryoshka_dim: ::core::option::Option<u64>,
+    /// Params relevant to sparse index. Algorithm used to traverse posting lists of the query.
+    /// Default: Batch
+    #[prost(enumeration = "SparseSearchAlgorithm", optional, tag = "7")]
+    pub sparse_algorithm: ::core::option::Option<i32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5087,6 +5522,9 @@ pub struct ScrollPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "11")]
     pub timeout: ::core::option::Option<u64>,
+    /// Highlight the text matched by full-text conditions of the filter
+    #[prost(message, optional, tag = "12")]
+    pub highlight: ::core::option::Option<HighlightParams>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5689,12 +6127,26 @@ pub struct Rrf {
     #[validate(range(min = 1))]
     pub k: ::core::option::Option<u32>,
 }
+/// Relevance-ranked full-text query, scored with BM25 over the full-text index of a payload field
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextQuery {
+    /// Payload key with a full-text index
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Text to search for
+    #[prost(string, tag = "2")]
+    #[validate(length(min = 1))]
+    pub query: ::prost::alloc::string::String,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Query {
-    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
+    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
     #[validate(nested)]
     pub variant: ::core::option::Option<query::Variant>,
 }
@@ -5734,6 +6186,9 @@ pub mod query {
         /// Parameterized reciprocal rank fusion
         #[prost(message, tag = "10")]
         Rrf(super::Rrf),
+        /// Rank points by relevance to a text query, using the full-text index of a payload field.
+        #[prost(message, tag = "11")]
+        Text(super::TextQuery),
     }
 }
 #[derive(validator::Validate)]
@@ -5831,6 +6286,9 @@ pub struct QueryPoints {
     #[prost(uint64, optional, tag = "15")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    /// Highlight the text matched by full-text conditions of the filter, of the prefetch filters and by the text queries, including the ones of the prefetches
+    #[prost(message, optional, tag = "16")]
+    pub highlight: ::core::option::Option<HighlightParams>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6292,6 +6750,55 @@ pub mod order_value {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HighlightParams {
+    /// If true, return fragments of the matched texts with the matches marked up. Default: false
+    #[prost(bool, optional, tag = "1")]
+    pub snippets: ::core::option::Option<bool>,
+    /// Markup inserted before each match in snippets. Default: `<em>`
+    #[prost(string, optional, tag = "2")]
+    pub pre_tag: ::core::option::Option<::prost::alloc::string::String>,
+    /// Markup inserted after each match in snippets. Default: `</em>`
+    #[prost(string, optional, tag = "3")]
+    pub post_tag: ::core::option::Option<::prost::alloc::string::String>,
+    /// Number of characters around the matches to include into snippets. Default: 50
+    #[prost(uint64, optional, tag = "4")]
+    pub context: ::core::option::Option<u64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextMatchOffsets {
+    /// Character offset of the start of the match
+    #[prost(uint64, tag = "1")]
+    pub start: u64,
+    /// Character offset after the end of the match
+    #[prost(uint64, tag = "2")]
+    pub end: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextHighlight {
+    /// Index of the value, if the field contains an array of texts
+    #[prost(uint64, tag = "1")]
+    pub value_index: u64,
+    /// Offsets of the matched words in the value
+    #[prost(message, repeated, tag = "2")]
+    pub offsets: ::prost::alloc::vec::Vec<TextMatchOffsets>,
+    /// Fragments of the value with the matches marked up, if requested
+    #[prost(string, repeated, tag = "3")]
+    pub snippets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TextHighlights {
+    #[prost(message, repeated, tag = "1")]
+    pub values: ::prost::alloc::vec::Vec<TextHighlight>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScoredPoint {
     /// Point id
     #[prost(message, optional, tag = "1")]
@@ -6314,6 +6821,12 @@ pub struct ScoredPoint {
     /// Order by value
     #[prost(message, optional, tag = "8")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Text matches by payload key, if highlighting is requested
+    #[prost(map = "string, message", tag = "9")]
+    pub highlight: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        TextHighlights,
+    >,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6490,6 +7003,12 @@ pub struct RetrievedPoint {
     /// Order-by value
     #[prost(message, optional, tag = "6")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Text matches by payload key, if highlighting is requested
+    #[prost(map = "string, message", tag = "7")]
+    pub highlight: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        TextHighlights,
+    >,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6754,7 +7273,7 @@ pub struct FieldCondition {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Match {
-    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
+    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
     pub match_value: ::core::option::Option<r#match::MatchValue>,
 }
 /// Nested message and enum types in `Match`.
@@ -6793,11 +7312,28 @@ pub mod r#match {
         /// Match any word in the text
         #[prost(string, tag = "10")]
         TextAny(::prost::alloc::string::String),
+        /// Match text, tolerating typos
+        #[prost(message, tag = "11")]
+        Fuzzy(super::MatchFuzzy),
     }
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MatchFuzzy {
+    /// Text to match
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+    /// Maximal number of edits per token, at most 2
+    #[prost(uint32, tag = "2")]
+    pub max_edits: u32,
+    /// Number of leading characters of each token which must match exactly. Default: 0
+    #[prost(uint32, optional, tag = "3")]
+    pub prefix_len: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RepeatedStrings {
     #[prost(string, repeated, tag = "1")]
     pub strings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
@@ -7114,6 +7650,38 @@ impl FieldType {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum SparseSearchAlgorithm {
+    /// Score all posting lists in batches of ids, pruning only the longest posting list
+    Batch = 0,
+    /// Skip ids and blocks of posting lists that can't make it to the top results
+    BlockMaxWand = 1,
+    /// Only consider ids from posting lists with large upper bounds of the weights as candidates
+    MaxScore = 2,
+}
+impl SparseSearchAlgorithm {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SparseSearchAlgorithm::Batch => "Batch",
+            SparseSearchAlgorithm::BlockMaxWand => "BlockMaxWand",
+            SparseSearchAlgorithm::MaxScore => "MaxScore",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Batch" => Some(Self::Batch),
+            "BlockMaxWand" => Some(Self::BlockMaxWand),
+            "MaxScore" => Some(Self::MaxScore),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum Direction {
     Asc = 0,
     Desc = 1,
@@ -10235,7 +10803,7 @@ pub mod query_shard_points {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Query {
-        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7")]
+        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
         pub score: ::core::option::Option<query::Score>,
     }
     /// Nested message and enum types in `Query`.
@@ -10265,6 +10833,9 @@ pub mod query_shard_points {
             /// Parameterized RRF fusion
             #[prost(message, tag = "7")]
             Rrf(super::super::Rrf),
+            /// Rank by BM25 relevance to a text query
+            #[prost(message, tag = "8")]
+            Text(super::super::TextQuery),
         }
     }
     #[derive(serde::Serialize)]
@@ -2,7 +2,9 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 
 use common::validation::{validate_range_generic, validate_shard_different_peers};
-use segment::data_types::index::validate_integer_index_params;
+use segment::data_types::index::{
+    DEFAULT_NGRAM_SIZE, validate_integer_index_params, validate_ngram_range,
+};
 use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::qdrant as grpc;
@@ -81,6 +83,7 @@ impl Validate for grpc::quantization_config::Quantization {
             Quantization::Scalar(scalar) => scalar.validate(),
             Quantization::Product(product) => product.validate(),
             Quantization::Binary(binary) => binary.validate(),
+            Quantization::Rabitq(rabitq) => rabitq.validate(),
         }
     }
 }
@@ -92,6 +95,7 @@ impl Validate for grpc::quantization_config_diff::Quantization {
             Quantization::Scalar(scalar) => scalar.validate(),
             Quantization::Product(product) => product.validate(),
             Quantization::Binary(binary) => binary.validate(),
+            Quantization::Rabitq(rabitq) => rabitq.validate(),
             Quantization::Disabled(_) => Ok(()),
         }
     }
@@ -345,6 +349,7 @@ impl Validate for super::qdrant::query::Variant {
             grpc::query::Variant::Context(q) => q.validate(),
             grpc::query::Variant::Formula(q) => q.validate(),
             grpc::query::Variant::Rrf(q) => q.validate(),
+            grpc::query::Variant::Text(q) => q.validate(),
             grpc::query::Variant::Sample(_)
             | grpc::query::Variant::Fusion(_)
             | grpc::query::Variant::OrderBy(_) => Ok(()),
@@ -458,7 +463,9 @@ impl Validate for super::qdrant::payload_index_params::IndexParams {
             }
             grpc::payload_index_params::IndexParams::FloatIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::GeoIndexParams(_) => Ok(()),
-            grpc::payload_index_params::IndexParams::TextIndexParams(_) => Ok(()),
+            grpc::payload_index_params::IndexParams::TextIndexParams(text_index_params) => {
+                text_index_params.validate()
+            }
             grpc::payload_index_params::IndexParams::BoolIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::DatetimeIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::UuidIndexParams(_) => Ok(()),
@@ -478,6 +485,20 @@ impl Validate for super::qdrant::IntegerIndexParams {
     }
 }
 
+impl Validate for super::qdrant::TextIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        if self.tokenizer() != grpc::TokenizerType::Ngram {
+            return Ok(());
+        }
+        let ngram = self.ngram.as_ref();
+        let size = |size: Option<u64>| size.map_or(DEFAULT_NGRAM_SIZE, |size| size as usize);
+        validate_ngram_range(
+            size(ngram.and_then(|ngram| ngram.min)),
+            size(ngram.and_then(|ngram| ngram.max)),
+        )
+    }
+}
+
 impl Validate for super::qdrant::points_selector::PointsSelectorOneOf {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
@@ -493,7 +514,7 @@ mod tests {
 
     use crate::grpc::qdrant::{
         CreateCollection, CreateFieldIndexCollection, GeoLineString, GeoPoint, GeoPolygon,
-        SearchPoints, UpdateCollection,
+        NgramParams, SearchPoints, TextIndexParams, TokenizerType, UpdateCollection,
     };
 
     #[test]
@@ -585,6 +606,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bad_ngram_tokenizer() {
+        let text_index_params = |ngram| TextIndexParams {
+            tokenizer: TokenizerType::Ngram as i32,
+            ngram,
+            ..Default::default()
+        };
+        assert!(text_index_params(None).validate().is_ok());
+        assert!(
+            text_index_params(Some(NgramParams {
+                min: Some(2),
+                max: Some(4),
+            }))
+            .validate()
+            .is_ok()
+        );
+        assert!(
+            text_index_params(Some(NgramParams {
+                min: Some(0),
+                max: None,
+            }))
+            .validate()
+            .is_err()
+        );
+        assert!(
+            text_index_params(Some(NgramParams {
+                min: Some(4),
+                max: None,
+            }))
+            .validate()
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_bad_search_request() {
         let bad_request = SearchPoints {
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::text_query::TextQuery;
 use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
 use uuid::Uuid;
 
 use super::schema::{ScoredPoint, Vector};
 use super::{
     FacetRequestInternal, FacetResponse, FacetValue, FacetValueHit, NearestQuery, OrderByInterface,
-    Query, QueryInterface, VectorOutput, VectorStructOutput,
+    Query, QueryInterface, TextInput, VectorOutput, VectorStructOutput,
 };
 use crate::grpc;
 use crate::rest::models::InferenceUsage;
@@ -86,6 +87,7 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            highlight,
         } = value;
         ScoredPoint {
             id,
@@ -95,6 +97,7 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector: vector.map(VectorStructOutput::from),
             shard_key,
             order_value,
+            highlight,
         }
     }
 }
@@ -140,6 +143,13 @@ impl From<OrderByInterface> for OrderBy {
     }
 }
 
+impl From<TextInput> for TextQuery {
+    fn from(text: TextInput) -> Self {
+        let TextInput { key, query } = text;
+        TextQuery { key, query }
+    }
+}
+
 impl From<QueryInterface> for Query {
     fn from(value: QueryInterface) -> Self {
         match value {
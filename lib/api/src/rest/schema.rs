@@ -2,13 +2,19 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
+use base64::Engine as _;
+use base64::prelude::BASE64_STANDARD;
 use common::types::ScoreType;
 use common::validation::validate_multi_vector;
 use ordered_float::NotNan;
 use schemars::JsonSchema;
 use segment::common::utils::MaybeOneOrMany;
-use segment::data_types::index::{StemmingAlgorithm, StopwordsInterface, TokenizerType};
+use segment::data_types::highlight::{HighlightParams, PointHighlight};
+use segment::data_types::index::{
+    CharFilter, StemmingAlgorithm, StopwordsInterface, SynonymsInterface, TokenizerType,
+};
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::vectors::VectorElementType;
 use segment::json_path::JsonPath;
 use segment::types::{
     Condition, Filter, GeoPoint, IntPayloadType, Payload, PointIdType, SearchParams, ShardKey,
@@ -20,17 +26,62 @@ use sparse::common::sparse_vector::SparseVector;
 use validator::{Validate, ValidationErrors};
 
 /// Type for dense vector
-pub type DenseVector = Vec<segment::data_types::vectors::VectorElementType>;
+pub type DenseVector = Vec<VectorElementType>;
 
 /// Type for multi dense vector
 pub type MultiDenseVector = Vec<DenseVector>;
 
+/// Dense vector as given in requests: an array of numbers, or a base64 string of bytes.
+///
+/// Base64 is a compact input for binary vectors, each decoded byte is one dimension.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum DenseVectorInput {
+    Values(DenseVector),
+    /// Base64 encoded bytes, one byte per dimension
+    Base64(String),
+}
+
+impl DenseVectorInput {
+    fn into_vector<E: serde::de::Error>(self) -> Result<DenseVector, E> {
+        match self {
+            DenseVectorInput::Values(vector) => Ok(vector),
+            DenseVectorInput::Base64(encoded) => decode_base64_vector(&encoded)
+                .map_err(|err| E::custom(format!("Invalid base64 encoded vector: {err}"))),
+        }
+    }
+}
+
+fn decode_base64_vector(encoded: &str) -> Result<DenseVector, base64::DecodeError> {
+    let bytes = BASE64_STANDARD.decode(encoded)?;
+    Ok(bytes.into_iter().map(VectorElementType::from).collect())
+}
+
+fn deserialize_dense_vector<'de, D>(deserializer: D) -> Result<DenseVector, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    DenseVectorInput::deserialize(deserializer)?.into_vector()
+}
+
+fn deserialize_dense_vectors<'de, D>(deserializer: D) -> Result<Vec<DenseVector>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Vec::<DenseVectorInput>::deserialize(deserializer)?
+        .into_iter()
+        .map(DenseVectorInput::into_vector)
+        .collect()
+}
+
 /// Vector Data
 /// Vectors can be described directly with values
 /// Or specified with source "objects" for inference
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum Vector {
+    #[serde(deserialize_with = "deserialize_dense_vector")]
+    #[schemars(with = "DenseVectorInput")]
     Dense(DenseVector),
     Sparse(SparseVector),
     MultiDense(MultiDenseVector),
@@ -86,7 +137,8 @@ fn named_vector_example() -> HashMap<VectorNameBuf, Vector> {
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum VectorStruct {
-    #[schemars(example = "vector_example")]
+    #[serde(deserialize_with = "deserialize_dense_vector")]
+    #[schemars(with = "DenseVectorInput", example = "vector_example")]
     Single(DenseVector),
     #[schemars(example = "multi_dense_vector_example")]
     MultiDense(MultiDenseVector),
@@ -239,6 +291,15 @@ pub struct TextPreprocessingConfig {
     /// Default is `None`, which means no maximum length.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_token_len: Option<usize>,
+    /// Character filters, applied to each token in order, before any other processing.
+    /// Should match the character filters of the text index, if it is used for the same field.
+    /// Default is `None`, which means no character filters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub char_filters: Option<Vec<CharFilter>>,
+    /// Synonyms, added to the query vector along with the original tokens.
+    /// Default is `None`, which means no synonyms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synonyms: Option<SynonymsInterface>,
 }
 
 impl Bm25Config {
@@ -378,6 +439,8 @@ pub struct InferenceObject {
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum BatchVectorStruct {
+    #[serde(deserialize_with = "deserialize_dense_vectors")]
+    #[schemars(with = "Vec<DenseVectorInput>")]
     Single(Vec<DenseVector>),
     MultiDense(Vec<MultiDenseVector>),
     Named(HashMap<VectorNameBuf, Vec<Vector>>),
@@ -450,6 +513,9 @@ pub struct ScoredPoint {
     /// Order-by value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_value: Option<segment::data_types::order_by::OrderValue>,
+    /// Text matches by payload key, if highlighting is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<PointHighlight>,
 }
 
 /// Point data
@@ -469,6 +535,9 @@ pub struct Record {
     pub shard_key: Option<segment::types::ShardKey>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_value: Option<segment::data_types::order_by::OrderValue>,
+    /// Text matches by payload key, if highlighting is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<PointHighlight>,
 }
 
 /// Vector data separator for named and unnamed modes
@@ -527,18 +596,58 @@ pub struct Rrf {
     pub k: Option<usize>,
 }
 
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum VectorInput {
+    Id(segment::types::PointIdType),
+    #[schemars(with = "DenseVectorInput")]
     DenseVector(DenseVector),
     SparseVector(SparseVector),
     MultiDenseVector(MultiDenseVector),
+    Document(Document),
+    Image(Image),
+    Object(InferenceObject),
+}
+
+/// [`VectorInput`] as given in requests
+///
+/// Strings are point ids first, only other strings are decoded as base64 vectors.
+#[derive(Deserialize)]
+#[serde(untagged, rename = "VectorInput")]
+enum VectorInputRepr {
     Id(segment::types::PointIdType),
+    Base64(String),
+    DenseVector(DenseVector),
+    SparseVector(SparseVector),
+    MultiDenseVector(MultiDenseVector),
     Document(Document),
     Image(Image),
     Object(InferenceObject),
 }
 
+impl<'de> Deserialize<'de> for VectorInput {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let input = match VectorInputRepr::deserialize(deserializer)? {
+            VectorInputRepr::Id(id) => VectorInput::Id(id),
+            VectorInputRepr::Base64(encoded) => {
+                let vector = decode_base64_vector(&encoded).map_err(|err| {
+                    serde::de::Error::custom(format!(
+                        "Invalid vector input \"{encoded}\": expected a point id (unsigned integer or UUID) or a base64 encoded vector ({err})"
+                    ))
+                })?;
+                VectorInput::DenseVector(vector)
+            }
+            VectorInputRepr::DenseVector(vector) => VectorInput::DenseVector(vector),
+            VectorInputRepr::SparseVector(vector) => VectorInput::SparseVector(vector),
+            VectorInputRepr::MultiDenseVector(vector) => VectorInput::MultiDenseVector(vector),
+            VectorInputRepr::Document(document) => VectorInput::Document(document),
+            VectorInputRepr::Image(image) => VectorInput::Image(image),
+            VectorInputRepr::Object(object) => VectorInput::Object(object),
+        };
+        Ok(input)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct QueryRequestInternal {
     /// Sub-requests to perform first. If present, the query will be performed on the results of the prefetch(es).
@@ -583,6 +692,11 @@ pub struct QueryRequestInternal {
     /// Note: the other collection vectors should have the same vector size as the 'using' vector in the current collection
     #[serde(default)]
     pub lookup_from: Option<LookupLocation>,
+
+    /// Highlight the text matched by full-text conditions of the filter, of the prefetch filters
+    /// and by the text queries, including the ones of the prefetches.
+    #[validate(nested)]
+    pub highlight: Option<HighlightParams>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -641,6 +755,9 @@ pub enum Query {
 
     /// Sample points from the collection, non-deterministically.
     Sample(SampleQuery),
+
+    /// Rank points by relevance to a text query, using the full-text index of a payload field.
+    Text(TextQuery),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -713,6 +830,25 @@ pub struct SampleQuery {
     pub sample: Sample,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct TextQuery {
+    #[validate(nested)]
+    pub text: TextInput,
+}
+
+/// Relevance-ranked full-text query, scored with BM25 over the full-text index of a payload field.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct TextInput {
+    /// Payload key with a full-text index
+    pub key: JsonPath,
+
+    /// Text to search for
+    #[validate(length(min = 1))]
+    pub query: String,
+}
+
 /// Maximal Marginal Relevance (MMR) algorithm for re-ranking the points.
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
@@ -154,6 +154,11 @@ impl Collection {
                         .quantization_config
                         .replace(QuantizationConfig::Binary(binary));
                 }
+                QuantizationConfigDiff::Rabitq(rabitq) => {
+                    config
+                        .quantization_config
+                        .replace(QuantizationConfig::Rabitq(rabitq));
+                }
                 QuantizationConfigDiff::Disabled(_) => {
                     config.quantization_config = None;
                 }
@@ -235,6 +235,7 @@ impl Collection {
                 with_vector: WithVector::Bool(false),
                 with_payload: WithPayloadInterface::Bool(false),
                 lookup_from: None,
+                highlight: None,
             };
 
             queries.push((query_request, shard_selection.clone()));
@@ -294,6 +295,7 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
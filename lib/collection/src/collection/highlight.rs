@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+
+use segment::data_types::highlight::{HighlightParams, PointHighlight, TextHighlight};
+use segment::data_types::text_query::TextQuery;
+use segment::index::field_index::full_text_index::highlight::{
+    TextHighlighter, char_offsets, snippets,
+};
+use segment::json_path::JsonPath;
+use segment::types::{
+    Condition, FieldCondition, Filter, Payload, PayloadContainer, PayloadSchemaParams,
+    PayloadSelector, WithPayload, WithPayloadInterface,
+};
+use serde_json::Value;
+
+use super::Collection;
+use crate::operations::universal_query::collection_query::{CollectionPrefetch, Query};
+
+/// Highlights the text matches in the payload of the response points.
+///
+/// Texts are matched with the same tokenizer, as the full-text index of the field is configured with.
+pub(crate) struct PointsHighlighter {
+    params: HighlightParams,
+    highlighters: Vec<(JsonPath, TextHighlighter)>,
+    /// Payload requested by the user, it is stripped to this selection after highlighting
+    with_payload: WithPayloadInterface,
+}
+
+impl PointsHighlighter {
+    /// Payload to retrieve for the points: the payload requested by the user,
+    /// extended with the highlighted fields
+    pub fn with_payload(&self) -> WithPayloadInterface {
+        if self.highlighters.is_empty() {
+            return self.with_payload.clone();
+        }
+
+        let mut keys: Vec<JsonPath> = self
+            .highlighters
+            .iter()
+            .map(|(key, _)| key.clone())
+            .collect();
+        let mut include_keys = |fields: &[JsonPath]| {
+            keys.retain(|key| !fields.contains(key));
+            fields.iter().cloned().chain(keys.drain(..)).collect()
+        };
+
+        match &self.with_payload {
+            WithPayloadInterface::Bool(true) => WithPayloadInterface::Bool(true),
+            WithPayloadInterface::Bool(false) => WithPayloadInterface::Fields(include_keys(&[])),
+            WithPayloadInterface::Fields(fields) => {
+                WithPayloadInterface::Fields(include_keys(fields))
+            }
+            WithPayloadInterface::Selector(PayloadSelector::Include(selector)) => {
+                WithPayloadInterface::Selector(PayloadSelector::new_include(include_keys(
+                    &selector.include,
+                )))
+            }
+            WithPayloadInterface::Selector(PayloadSelector::Exclude(selector)) => {
+                // Keep excluding only the fields, which do not overlap with the highlighted ones
+                let exclude: Vec<_> = selector
+                    .exclude
+                    .iter()
+                    .filter(|pattern| !keys.iter().any(|key| key.compatible(pattern)))
+                    .cloned()
+                    .collect();
+                if exclude.is_empty() {
+                    WithPayloadInterface::Bool(true)
+                } else {
+                    WithPayloadInterface::Selector(PayloadSelector::new_exclude(exclude))
+                }
+            }
+        }
+    }
+
+    /// Highlight the matches in the payload, and reduce it to the payload requested by the user
+    pub fn highlight(&self, payload: Option<Payload>) -> (Option<Payload>, PointHighlight) {
+        let mut highlight = PointHighlight::new();
+
+        if let Some(payload) = &payload {
+            for (key, highlighter) in &self.highlighters {
+                let values = self.highlight_values(payload, key, highlighter);
+                if !values.is_empty() {
+                    highlight.entry(key.to_string()).or_default().extend(values);
+                }
+            }
+        }
+
+        if self.highlighters.is_empty() {
+            return (payload, highlight);
+        }
+
+        let WithPayload {
+            enable,
+            payload_selector,
+        } = WithPayload::from(&self.with_payload);
+        let payload = match payload_selector {
+            _ if !enable => None,
+            Some(selector) => payload.map(|payload| selector.process(payload)),
+            None => payload,
+        };
+
+        (payload, highlight)
+    }
+
+    fn highlight_values(
+        &self,
+        payload: &Payload,
+        key: &JsonPath,
+        highlighter: &TextHighlighter,
+    ) -> Vec<TextHighlight> {
+        let texts = payload
+            .get_value(key)
+            .into_iter()
+            .flat_map(|value| match value {
+                Value::String(text) => vec![text.as_str()],
+                Value::Array(values) => values.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            });
+
+        texts
+            .enumerate()
+            .filter_map(|(value_index, text)| {
+                let ranges = highlighter.highlight(text);
+                if ranges.is_empty() {
+                    return None;
+                }
+
+                let snippets = self.params.snippets_enabled().then(|| {
+                    snippets(
+                        text,
+                        &ranges,
+                        self.params.pre_tag(),
+                        self.params.post_tag(),
+                        self.params.context(),
+                    )
+                });
+
+                Some(TextHighlight {
+                    value_index,
+                    offsets: char_offsets(text, &ranges),
+                    snippets,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Collection {
+    /// Highlighter of the full-text conditions of the `filters` and of the `text_queries`.
+    ///
+    /// Conditions of `must_not` and of nested filters are not highlighted.
+    pub(crate) fn points_highlighter<'a>(
+        &self,
+        params: Option<&HighlightParams>,
+        filters: impl IntoIterator<Item = &'a Filter>,
+        text_queries: impl IntoIterator<Item = &'a TextQuery>,
+        with_payload: &WithPayloadInterface,
+    ) -> Option<PointsHighlighter> {
+        let params = params?;
+
+        let mut highlighters: HashMap<JsonPath, TextHighlighter> = HashMap::new();
+        let mut conditions = Vec::new();
+        for filter in filters {
+            collect_field_conditions(filter, &mut conditions);
+        }
+        for FieldCondition { key, r#match, .. } in conditions {
+            if let Some(condition) = r#match {
+                highlighters
+                    .entry(key.clone())
+                    .or_insert_with(|| self.text_highlighter(key))
+                    .add_condition(condition);
+            }
+        }
+
+        for TextQuery { key, query } in text_queries {
+            highlighters
+                .entry(key.clone())
+                .or_insert_with(|| self.text_highlighter(key))
+                .add_text_query(query);
+        }
+
+        let highlighters = highlighters
+            .into_iter()
+            .filter(|(_, highlighter)| !highlighter.is_empty())
+            .collect();
+
+        Some(PointsHighlighter {
+            params: params.clone(),
+            highlighters,
+            with_payload: with_payload.clone(),
+        })
+    }
+
+    /// Highlighter with the parameters of the full-text index of the field,
+    /// or with the default ones if the field is not indexed
+    fn text_highlighter(&self, key: &JsonPath) -> TextHighlighter {
+        let params = self
+            .payload_key_index_schema(key)
+            .and_then(|schema| match schema.expand().into_owned() {
+                PayloadSchemaParams::Text(params) => Some(params),
+                _ => None,
+            })
+            .unwrap_or_default();
+        TextHighlighter::new(&params)
+    }
+}
+
+/// Filters and text queries of the prefetches, including the nested ones
+pub(crate) fn collect_prefetch_conditions<'a>(
+    prefetches: &'a [CollectionPrefetch],
+    filters: &mut Vec<&'a Filter>,
+    text_queries: &mut Vec<&'a TextQuery>,
+) {
+    for prefetch in prefetches {
+        filters.extend(&prefetch.filter);
+        if let Some(Query::Text(text_query)) = &prefetch.query {
+            text_queries.push(text_query);
+        }
+        collect_prefetch_conditions(&prefetch.prefetch, filters, text_queries);
+    }
+}
+
+fn collect_field_conditions<'a>(filter: &'a Filter, conditions: &mut Vec<&'a FieldCondition>) {
+    let positive_conditions = filter
+        .must
+        .iter()
+        .flatten()
+        .chain(filter.should.iter().flatten())
+        .chain(filter.min_should.iter().flat_map(|i| &i.conditions));
+
+    for condition in positive_conditions {
+        match condition {
+            Condition::Field(field_condition) => conditions.push(field_condition),
+            Condition::Filter(filter) => collect_field_conditions(filter, conditions),
+            Condition::IsEmpty(_)
+            | Condition::IsNull(_)
+            | Condition::HasId(_)
+            | Condition::HasVector(_)
+            | Condition::Nested(_)
+            | Condition::CustomIdChecker(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use segment::data_types::index::TextIndexParams;
+    use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+    use segment::types::Match;
+
+    use super::*;
+
+    fn highlighter(with_payload: WithPayloadInterface) -> PointsHighlighter {
+        PointsHighlighter {
+            params: HighlightParams::default(),
+            highlighters: vec![(
+                JsonPath::from_str("title").unwrap(),
+                TextHighlighter::new(&TextIndexParams::default()),
+            )],
+            with_payload,
+        }
+    }
+
+    fn paths(keys: &[&str]) -> Vec<JsonPath> {
+        keys.iter()
+            .map(|key| JsonPath::from_str(key).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_with_payload_includes_highlighted_fields() {
+        assert_eq!(
+            highlighter(WithPayloadInterface::Bool(true)).with_payload(),
+            WithPayloadInterface::Bool(true),
+        );
+        assert_eq!(
+            highlighter(WithPayloadInterface::Bool(false)).with_payload(),
+            WithPayloadInterface::Fields(paths(&["title"])),
+        );
+        assert_eq!(
+            highlighter(WithPayloadInterface::Fields(paths(&["author", "title"]))).with_payload(),
+            WithPayloadInterface::Fields(paths(&["author", "title"])),
+        );
+        assert_eq!(
+            highlighter(WithPayloadInterface::Selector(
+                PayloadSelector::new_include(paths(&["author"]))
+            ))
+            .with_payload(),
+            WithPayloadInterface::Selector(PayloadSelector::new_include(paths(&[
+                "author", "title"
+            ]))),
+        );
+        assert_eq!(
+            highlighter(WithPayloadInterface::Selector(
+                PayloadSelector::new_exclude(paths(&["author", "title.text"]))
+            ))
+            .with_payload(),
+            WithPayloadInterface::Selector(PayloadSelector::new_exclude(paths(&["author"]))),
+        );
+        assert_eq!(
+            highlighter(WithPayloadInterface::Selector(
+                PayloadSelector::new_exclude(paths(&["title"]))
+            ))
+            .with_payload(),
+            WithPayloadInterface::Bool(true),
+        );
+    }
+
+    fn prefetch(
+        prefetch: Vec<CollectionPrefetch>,
+        query: Option<Query>,
+        filter: Option<Filter>,
+    ) -> CollectionPrefetch {
+        CollectionPrefetch {
+            prefetch,
+            query,
+            using: DEFAULT_VECTOR_NAME.to_owned(),
+            filter,
+            score_threshold: None,
+            limit: 10,
+            params: None,
+            lookup_from: None,
+        }
+    }
+
+    #[test]
+    fn test_collect_nested_prefetch_conditions() {
+        let key = JsonPath::from_str("title").unwrap();
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            key.clone(),
+            Match::new_text("robot"),
+        )));
+        let text_query = TextQuery {
+            key,
+            query: "dreams".to_string(),
+        };
+
+        let prefetches = vec![prefetch(
+            vec![prefetch(
+                Vec::new(),
+                Some(Query::Text(text_query.clone())),
+                Some(filter.clone()),
+            )],
+            None,
+            None,
+        )];
+
+        let mut filters = Vec::new();
+        let mut text_queries = Vec::new();
+        collect_prefetch_conditions(&prefetches, &mut filters, &mut text_queries);
+        assert_eq!(filters, vec![&filter]);
+        assert_eq!(text_queries, vec![&text_query]);
+    }
+}
@@ -349,6 +349,7 @@ mod tests {
             vector: Some(VectorStructInternal::Named(vectors)),
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
@@ -362,6 +363,7 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
@@ -387,6 +389,7 @@ mod tests {
             vector: Some(VectorStructInternal::Named(vectors)),
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
@@ -411,6 +414,7 @@ mod tests {
             vector: Some(VectorStructInternal::Named(vector_map)),
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
@@ -423,6 +427,7 @@ mod tests {
         let mut vectors_map = BTreeMap::new();
         let multi_config = MultiVectorConfig {
             comparator: MultiVectorComparator::MaxSim,
+            token_pooling: None,
         };
         vectors_map.insert(
             name.to_string(),
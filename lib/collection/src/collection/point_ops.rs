@@ -269,12 +269,23 @@ impl Collection {
         let mut limit = request
             .limit
             .unwrap_or_else(|| default_request.limit.unwrap());
-        let with_payload_interface = request
+        let mut with_payload_interface = request
             .with_payload
             .clone()
             .unwrap_or_else(|| default_request.with_payload.clone().unwrap());
         let with_vector = request.with_vector;
 
+        // Highlighted fields are retrieved along with the requested payload
+        let highlighter = self.points_highlighter(
+            request.highlight.as_ref(),
+            &request.filter,
+            None,
+            &with_payload_interface,
+        );
+        if let Some(highlighter) = &highlighter {
+            with_payload_interface = highlighter.with_payload();
+        }
+
         let order_by = request.order_by.map(OrderBy::from);
 
         // Validate user did not try to use an id offset with order_by
@@ -362,6 +373,15 @@ impl Collection {
             // remove extra point, it would be a first point of the next page
             Some(points.pop().unwrap().id)
         };
+
+        if let Some(highlighter) = &highlighter {
+            for point in &mut points {
+                let (payload, highlight) = highlighter.highlight(point.payload.take());
+                point.payload = payload;
+                point.highlight = Some(highlight);
+            }
+        }
+
         Ok(ScrollResult {
             points,
             next_page_offset,
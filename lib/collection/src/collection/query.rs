@@ -15,6 +15,7 @@ use tokio::sync::RwLockReadGuard;
 use tokio::time::Instant;
 
 use super::Collection;
+use crate::collection::highlight::collect_prefetch_conditions;
 use crate::collection::mmr::mmr_from_points_with_vector;
 use crate::collection_manager::probabilistic_search_sampling::find_search_sampling_over_point_distribution;
 use crate::common::batching::batch_requests;
@@ -26,7 +27,7 @@ use crate::common::transpose_iterator::transposed_iter;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{CollectionError, CollectionResult};
-use crate::operations::universal_query::collection_query::CollectionQueryRequest;
+use crate::operations::universal_query::collection_query::{CollectionQueryRequest, Query};
 use crate::operations::universal_query::shard_query::{
     FusionInternal, MmrInternal, ScoringQuery, ShardQueryRequest, ShardQueryResponse,
 };
@@ -427,7 +428,7 @@ impl Collection {
     /// This function is used to query the collection. It will return a list of scored points.
     pub async fn query_batch<'a, F, Fut>(
         &self,
-        requests_batch: Vec<(CollectionQueryRequest, ShardSelectorInternal)>,
+        mut requests_batch: Vec<(CollectionQueryRequest, ShardSelectorInternal)>,
         collection_by_name: F,
         read_consistency: Option<ReadConsistency>,
         timeout: Option<Duration>,
@@ -439,6 +440,29 @@ impl Collection {
     {
         let start = Instant::now();
 
+        // Highlighted fields are retrieved along with the requested payload
+        let highlighters: Vec<_> = requests_batch
+            .iter_mut()
+            .map(|(request, _)| {
+                let mut filters: Vec<_> = request.filter.iter().collect();
+                let mut text_queries: Vec<_> = match &request.query {
+                    Some(Query::Text(text_query)) => vec![text_query],
+                    _ => Vec::new(),
+                };
+                collect_prefetch_conditions(&request.prefetch, &mut filters, &mut text_queries);
+                let highlighter = self.points_highlighter(
+                    request.highlight.as_ref(),
+                    filters,
+                    text_queries,
+                    &request.with_payload,
+                );
+                if let Some(highlighter) = &highlighter {
+                    request.with_payload = highlighter.with_payload();
+                }
+                highlighter
+            })
+            .collect();
+
         // Lift nested prefetches to root queries for vector resolution
         let resolver_requests = build_vector_resolver_queries(&requests_batch);
 
@@ -499,12 +523,23 @@ impl Collection {
             },
         )?;
 
-        let results = future::try_join_all(futures)
+        let mut results: Vec<Vec<ScoredPoint>> = future::try_join_all(futures)
             .await?
             .into_iter()
             .flatten()
             .collect();
 
+        for (points, highlighter) in results.iter_mut().zip(&highlighters) {
+            let Some(highlighter) = highlighter else {
+                continue;
+            };
+            for point in points {
+                let (payload, highlight) = highlighter.highlight(point.payload.take());
+                point.payload = payload;
+                point.highlight = Some(highlight);
+            }
+        }
+
         Ok(results)
     }
 
@@ -718,7 +753,8 @@ fn intermediate_query_infos(request: &ShardQueryRequest) -> Vec<IntermediateQuer
         | Some(ScoringQuery::Vector(_))
         | Some(ScoringQuery::OrderBy(_))
         | Some(ScoringQuery::Formula(_))
-        | Some(ScoringQuery::Sample(_)) => {
+        | Some(ScoringQuery::Sample(_))
+        | Some(ScoringQuery::Text(_)) => {
             // Otherwise, we expect the root result
             vec![IntermediateQueryInfo {
                 scoring_query: request.query.as_ref(),
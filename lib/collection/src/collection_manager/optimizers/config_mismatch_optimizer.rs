@@ -1,4 +1,3 @@
-use std::borrow::Cow;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -7,7 +6,8 @@ use parking_lot::Mutex;
 use segment::common::operation_time_statistics::OperationDurationsAggregator;
 use segment::index::sparse_index::sparse_index_config::SparseIndexType;
 use segment::types::{
-    HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, SegmentType, VectorName,
+    DiskAnnConfig, HnswConfig, HnswGlobalConfig, Indexes, IvfConfig, QuantizationConfig,
+    SegmentType, VdeIndexConfig, VectorName,
 };
 
 use crate::collection_manager::holders::segment_holder::{LockedSegmentHolder, SegmentId};
@@ -15,7 +15,6 @@ use crate::collection_manager::optimizers::segment_optimizer::{
     OptimizerThresholds, SegmentOptimizer,
 };
 use crate::config::CollectionParams;
-use crate::operations::config_diff::DiffConfig;
 
 /// Looks for segments having a mismatch between configured and actual parameters
 ///
@@ -73,32 +72,28 @@ impl ConfigMismatchOptimizer {
             .and_then(|index| index.on_disk)
     }
 
-    /// Calculates and HNSW config that should be used for a given vector
-    /// with current configuration.
-    ///
-    /// Takes vector-specific HNSW config (if any) and merges it with the collection-wide config.
-    fn get_required_hnsw_config(&self, vector_name: &VectorName) -> Cow<'_, HnswConfig> {
-        let target_hnsw_collection = &self.hnsw_config;
-        // Select vector specific target HNSW config
-        let target_hnsw_vector = self
-            .collection_params
+    /// Returns the VDE index config that should be used for a given vector, if any
+    fn get_required_vde_config(&self, vector_name: &VectorName) -> Option<&VdeIndexConfig> {
+        self.collection_params
             .vectors
             .get_params(vector_name)
-            .and_then(|vector_params| vector_params.hnsw_config)
-            .map(|vector_hnsw| vector_hnsw.update(target_hnsw_collection))
-            .and_then(|hnsw| match hnsw {
-                Ok(hnsw) => Some(hnsw),
-                Err(err) => {
-                    log::warn!(
-                        "Failed to merge collection and vector HNSW config, ignoring: {err}"
-                    );
-                    None
-                }
-            });
-        match target_hnsw_vector {
-            Some(target_hnsw) => Cow::Owned(target_hnsw),
-            None => Cow::Borrowed(target_hnsw_collection),
-        }
+            .and_then(|vector_params| vector_params.vde_config.as_ref())
+    }
+
+    /// Returns the DiskANN index config that should be used for a given vector, if any
+    fn get_required_diskann_config(&self, vector_name: &VectorName) -> Option<&DiskAnnConfig> {
+        self.collection_params
+            .vectors
+            .get_params(vector_name)
+            .and_then(|vector_params| vector_params.diskann_config.as_ref())
+    }
+
+    /// Returns the IVF index config that should be used for a given vector, if any
+    fn get_required_ivf_config(&self, vector_name: &VectorName) -> Option<&IvfConfig> {
+        self.collection_params
+            .vectors
+            .get_params(vector_name)
+            .and_then(|vector_params| vector_params.ivf_config.as_ref())
     }
 
     fn worst_segment(
@@ -136,16 +131,60 @@ impl ConfigMismatchOptimizer {
                         .vector_data
                         .iter()
                         .any(|(vector_name, vector_data)| {
-                            // Check HNSW mismatch
+                            // Check HNSW, VDE, DiskANN and IVF mismatch
                             match &vector_data.index {
                                 Indexes::Plain {} => {}
                                 Indexes::Hnsw(effective_hnsw) => {
+                                    // Select segment if it should be indexed by VDE, DiskANN or IVF instead
+                                    if self.get_required_vde_config(vector_name).is_some()
+                                        || self.get_required_diskann_config(vector_name).is_some()
+                                        || self.get_required_ivf_config(vector_name).is_some()
+                                    {
+                                        return true;
+                                    }
+
                                     // Select segment if we have an HNSW mismatch that requires rebuild
-                                    let target_hnsw = self.get_required_hnsw_config(vector_name);
+                                    let target_hnsw = self
+                                        .collection_params
+                                        .get_vector_hnsw_config(vector_name, &self.hnsw_config);
                                     if effective_hnsw.mismatch_requires_rebuild(&target_hnsw) {
                                         return true;
                                     }
                                 }
+                                Indexes::Vde(effective_vde) => {
+                                    // Select segment if VDE config changed or VDE index is disabled
+                                    let target_vde = self.get_required_vde_config(vector_name);
+                                    if target_vde != Some(effective_vde) {
+                                        return true;
+                                    }
+                                }
+                                Indexes::DiskAnn(effective_diskann) => {
+                                    // Select segment if it should be indexed by VDE instead
+                                    if self.get_required_vde_config(vector_name).is_some() {
+                                        return true;
+                                    }
+
+                                    // Select segment if DiskANN config changed or DiskANN index is disabled
+                                    let target_diskann =
+                                        self.get_required_diskann_config(vector_name);
+                                    if target_diskann != Some(effective_diskann) {
+                                        return true;
+                                    }
+                                }
+                                Indexes::Ivf(effective_ivf) => {
+                                    // Select segment if it should be indexed by VDE or DiskANN instead
+                                    if self.get_required_vde_config(vector_name).is_some()
+                                        || self.get_required_diskann_config(vector_name).is_some()
+                                    {
+                                        return true;
+                                    }
+
+                                    // Select segment if IVF config changed or IVF index is disabled
+                                    let target_ivf = self.get_required_ivf_config(vector_name);
+                                    if target_ivf != Some(effective_ivf) {
+                                        return true;
+                                    }
+                                }
                             }
 
                             if let Some(is_required_on_disk) =
@@ -285,7 +324,7 @@ mod tests {
     use segment::entry::entry_point::SegmentEntry;
     use segment::index::hnsw_index::num_rayon_threads;
     use segment::types::{
-        CompressionRatio, Distance, ProductQuantization, ProductQuantizationConfig,
+        CompressionRatio, Distance, IvfResiduals, ProductQuantization, ProductQuantizationConfig,
         ScalarQuantizationConfig, ScalarType,
     };
     use tempfile::Builder;
@@ -294,7 +333,7 @@ mod tests {
     use crate::collection_manager::fixtures::{random_multi_vec_segment, random_segment};
     use crate::collection_manager::holders::segment_holder::{LockedSegment, SegmentHolder};
     use crate::collection_manager::optimizers::indexing_optimizer::IndexingOptimizer;
-    use crate::operations::config_diff::HnswConfigDiff;
+    use crate::operations::config_diff::{DiffConfig, HnswConfigDiff};
     use crate::operations::types::VectorsConfig;
     use crate::operations::vector_params_builder::VectorParamsBuilder;
 
@@ -349,6 +388,7 @@ mod tests {
             on_disk: None,
             payload_m: None,
             copy_vectors: None,
+            matryoshka_dim: None,
         };
 
         // Optimizers used in test
@@ -510,6 +550,7 @@ mod tests {
             on_disk: None,
             payload_m: None,
             copy_vectors: None,
+            matryoshka_dim: None,
         };
 
         let permit_cpu_count = num_rayon_threads(hnsw_config_collection.max_indexing_threads);
@@ -739,6 +780,7 @@ mod tests {
             product: ProductQuantizationConfig {
                 compression: CompressionRatio::X32,
                 always_ram: Some(true),
+                opq: None,
             },
         });
         match config_mismatch_optimizer.collection_params.vectors {
@@ -789,4 +831,429 @@ mod tests {
                 );
             });
     }
+
+    /// This test the config mismatch optimizer for a changed VDE index config
+    ///
+    /// It tests whether:
+    /// - enabling VDE index on a HNSW indexed segment triggers a rebuild with VDE index
+    /// - changing VDE tuning parameters triggers a rebuild
+    /// - disabling VDE index triggers a rebuild with HNSW index
+    #[cfg(feature = "vde")]
+    #[test]
+    fn test_vde_config_mismatch() {
+        // Collection configuration
+        let (point_count, dim) = (1000, 10);
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size_kb: usize::MAX,
+            memmap_threshold_kb: usize::MAX,
+            indexing_threshold_kb: 10,
+        };
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(
+                VectorParamsBuilder::new(dim as u64, Distance::Dot).build(),
+            ),
+            ..CollectionParams::empty()
+        };
+
+        // Base segment
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let mut holder = SegmentHolder::default();
+
+        let segment = random_segment(dir.path(), 100, point_count, dim as usize);
+
+        let segment_id = holder.add_new(segment);
+        let locked_holder: Arc<RwLock<_>> = Arc::new(RwLock::new(holder));
+
+        let hnsw_config = HnswConfig {
+            m: 16,
+            ef_construct: 100,
+            full_scan_threshold: 10,
+            max_indexing_threads: 0,
+            on_disk: None,
+            payload_m: None,
+            copy_vectors: None,
+            matryoshka_dim: None,
+        };
+
+        // Optimizers used in test
+        let index_optimizer = IndexingOptimizer::new(
+            2,
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            hnsw_config.clone(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+        let mut config_mismatch_optimizer = ConfigMismatchOptimizer::new(
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params,
+            hnsw_config.clone(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+
+        let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+        let budget = ResourceBudget::new(permit_cpu_count, permit_cpu_count);
+
+        // Use indexing optimizer to build HNSW index
+        let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+        let changed = index_optimizer
+            .optimize(
+                locked_holder.clone(),
+                vec![segment_id],
+                permit,
+                budget.clone(),
+                &false.into(),
+            )
+            .unwrap();
+        assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+        let vde_config = VdeIndexConfig {
+            storage_type: segment::types::VdeStorageType::Memory,
+            ..Default::default()
+        };
+        let tuned_vde_config = VdeIndexConfig {
+            config_json: Some(r#"{"ef_construction": 200}"#.to_string()),
+            ..vde_config.clone()
+        };
+
+        for target_vde in [Some(vde_config), Some(tuned_vde_config), None] {
+            config_mismatch_optimizer
+                .collection_params
+                .vectors
+                .get_params_mut(DEFAULT_VECTOR_NAME)
+                .unwrap()
+                .vde_config
+                .clone_from(&target_vde);
+
+            // Run mismatch optimizer, make sure it optimizes due to the changed VDE config
+            let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+            let suggested_to_optimize = config_mismatch_optimizer
+                .check_condition(locked_holder.clone(), &Default::default());
+            assert_eq!(suggested_to_optimize.len(), 1);
+            let changed = config_mismatch_optimizer
+                .optimize(
+                    locked_holder.clone(),
+                    suggested_to_optimize,
+                    permit,
+                    budget.clone(),
+                    &false.into(),
+                )
+                .unwrap();
+            assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+            // No mismatch is left after optimization
+            let suggested_to_optimize = config_mismatch_optimizer
+                .check_condition(locked_holder.clone(), &Default::default());
+            assert_eq!(suggested_to_optimize.len(), 0);
+
+            let expected_index = match &target_vde {
+                Some(target_vde) => Indexes::Vde(target_vde.clone()),
+                None => Indexes::Hnsw(hnsw_config.clone()),
+            };
+            locked_holder
+                .read()
+                .iter()
+                .map(|(_, segment)| match segment {
+                    LockedSegment::Original(s) => s.read(),
+                    LockedSegment::Proxy(_) => unreachable!(),
+                })
+                .filter(|segment| segment.total_point_count() > 0)
+                .for_each(|segment| {
+                    assert_eq!(
+                        segment.config().vector_data[DEFAULT_VECTOR_NAME].index,
+                        expected_index,
+                        "segment must be optimized with target index config",
+                    );
+                });
+        }
+    }
+
+    /// This test the config mismatch optimizer for a changed DiskANN index config
+    ///
+    /// It tests whether:
+    /// - enabling DiskANN index on a HNSW indexed segment triggers a rebuild with DiskANN index
+    /// - changing DiskANN parameters triggers a rebuild
+    /// - disabling DiskANN index triggers a rebuild with HNSW index
+    #[test]
+    fn test_diskann_config_mismatch() {
+        // Collection configuration
+        let (point_count, dim) = (1000, 10);
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size_kb: usize::MAX,
+            memmap_threshold_kb: usize::MAX,
+            indexing_threshold_kb: 10,
+        };
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(
+                VectorParamsBuilder::new(dim as u64, Distance::Dot).build(),
+            ),
+            ..CollectionParams::empty()
+        };
+
+        // Base segment
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let mut holder = SegmentHolder::default();
+
+        let segment = random_segment(dir.path(), 100, point_count, dim as usize);
+
+        let segment_id = holder.add_new(segment);
+        let locked_holder: Arc<RwLock<_>> = Arc::new(RwLock::new(holder));
+
+        let hnsw_config = HnswConfig {
+            m: 16,
+            ef_construct: 100,
+            full_scan_threshold: 10,
+            max_indexing_threads: 0,
+            on_disk: None,
+            payload_m: None,
+            copy_vectors: None,
+            matryoshka_dim: None,
+        };
+
+        // Optimizers used in test
+        let index_optimizer = IndexingOptimizer::new(
+            2,
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            hnsw_config.clone(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+        let mut config_mismatch_optimizer = ConfigMismatchOptimizer::new(
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params,
+            hnsw_config.clone(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+
+        let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+        let budget = ResourceBudget::new(permit_cpu_count, permit_cpu_count);
+
+        // Use indexing optimizer to build HNSW index
+        let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+        let changed = index_optimizer
+            .optimize(
+                locked_holder.clone(),
+                vec![segment_id],
+                permit,
+                budget.clone(),
+                &false.into(),
+            )
+            .unwrap();
+        assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+        let diskann_config = DiskAnnConfig {
+            max_degree: 16,
+            build_list_size: 32,
+            ..Default::default()
+        };
+        let tuned_diskann_config = DiskAnnConfig {
+            search_list_size: 200,
+            ..diskann_config.clone()
+        };
+
+        for target_diskann in [Some(diskann_config), Some(tuned_diskann_config), None] {
+            config_mismatch_optimizer
+                .collection_params
+                .vectors
+                .get_params_mut(DEFAULT_VECTOR_NAME)
+                .unwrap()
+                .diskann_config
+                .clone_from(&target_diskann);
+
+            // Run mismatch optimizer, make sure it optimizes due to the changed DiskANN config
+            let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+            let suggested_to_optimize = config_mismatch_optimizer
+                .check_condition(locked_holder.clone(), &Default::default());
+            assert_eq!(suggested_to_optimize.len(), 1);
+            let changed = config_mismatch_optimizer
+                .optimize(
+                    locked_holder.clone(),
+                    suggested_to_optimize,
+                    permit,
+                    budget.clone(),
+                    &false.into(),
+                )
+                .unwrap();
+            assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+            // No mismatch is left after optimization
+            let suggested_to_optimize = config_mismatch_optimizer
+                .check_condition(locked_holder.clone(), &Default::default());
+            assert_eq!(suggested_to_optimize.len(), 0);
+
+            let expected_index = match &target_diskann {
+                Some(target_diskann) => Indexes::DiskAnn(target_diskann.clone()),
+                None => Indexes::Hnsw(hnsw_config.clone()),
+            };
+            locked_holder
+                .read()
+                .iter()
+                .map(|(_, segment)| match segment {
+                    LockedSegment::Original(s) => s.read(),
+                    LockedSegment::Proxy(_) => unreachable!(),
+                })
+                .filter(|segment| segment.total_point_count() > 0)
+                .for_each(|segment| {
+                    assert_eq!(
+                        segment.config().vector_data[DEFAULT_VECTOR_NAME].index,
+                        expected_index,
+                        "segment must be optimized with target index config",
+                    );
+                });
+        }
+    }
+
+    /// This test the config mismatch optimizer for a changed IVF index config
+    ///
+    /// It tests whether:
+    /// - enabling IVF index on a HNSW indexed segment triggers a rebuild with IVF index
+    /// - changing IVF parameters triggers a rebuild
+    /// - disabling IVF index triggers a rebuild with HNSW index
+    #[test]
+    fn test_ivf_config_mismatch() {
+        // Collection configuration
+        let (point_count, dim) = (1000, 10);
+        let thresholds_config = OptimizerThresholds {
+            max_segment_size_kb: usize::MAX,
+            memmap_threshold_kb: usize::MAX,
+            indexing_threshold_kb: 10,
+        };
+        let collection_params = CollectionParams {
+            vectors: VectorsConfig::Single(
+                VectorParamsBuilder::new(dim as u64, Distance::Dot).build(),
+            ),
+            ..CollectionParams::empty()
+        };
+
+        // Base segment
+        let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+        let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+        let mut holder = SegmentHolder::default();
+
+        let segment = random_segment(dir.path(), 100, point_count, dim as usize);
+
+        let segment_id = holder.add_new(segment);
+        let locked_holder: Arc<RwLock<_>> = Arc::new(RwLock::new(holder));
+
+        let hnsw_config = HnswConfig {
+            m: 16,
+            ef_construct: 100,
+            full_scan_threshold: 10,
+            max_indexing_threads: 0,
+            on_disk: None,
+            payload_m: None,
+            copy_vectors: None,
+            matryoshka_dim: None,
+        };
+
+        // Optimizers used in test
+        let index_optimizer = IndexingOptimizer::new(
+            2,
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params.clone(),
+            hnsw_config.clone(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+        let mut config_mismatch_optimizer = ConfigMismatchOptimizer::new(
+            thresholds_config,
+            dir.path().to_owned(),
+            temp_dir.path().to_owned(),
+            collection_params,
+            hnsw_config.clone(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+
+        let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+        let budget = ResourceBudget::new(permit_cpu_count, permit_cpu_count);
+
+        // Use indexing optimizer to build HNSW index
+        let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+        let changed = index_optimizer
+            .optimize(
+                locked_holder.clone(),
+                vec![segment_id],
+                permit,
+                budget.clone(),
+                &false.into(),
+            )
+            .unwrap();
+        assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+        let ivf_config = IvfConfig {
+            nlist: Some(8),
+            ..Default::default()
+        };
+        let tuned_ivf_config = IvfConfig {
+            residuals: Some(IvfResiduals::Scalar),
+            ..ivf_config.clone()
+        };
+
+        for target_ivf in [Some(ivf_config), Some(tuned_ivf_config), None] {
+            config_mismatch_optimizer
+                .collection_params
+                .vectors
+                .get_params_mut(DEFAULT_VECTOR_NAME)
+                .unwrap()
+                .ivf_config
+                .clone_from(&target_ivf);
+
+            // Run mismatch optimizer, make sure it optimizes due to the changed IVF config
+            let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+            let suggested_to_optimize = config_mismatch_optimizer
+                .check_condition(locked_holder.clone(), &Default::default());
+            assert_eq!(suggested_to_optimize.len(), 1);
+            let changed = config_mismatch_optimizer
+                .optimize(
+                    locked_holder.clone(),
+                    suggested_to_optimize,
+                    permit,
+                    budget.clone(),
+                    &false.into(),
+                )
+                .unwrap();
+            assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+            // No mismatch is left after optimization
+            let suggested_to_optimize = config_mismatch_optimizer
+                .check_condition(locked_holder.clone(), &Default::default());
+            assert_eq!(suggested_to_optimize.len(), 0);
+
+            let expected_index = match &target_ivf {
+                Some(target_ivf) => Indexes::Ivf(target_ivf.clone()),
+                None => Indexes::Hnsw(hnsw_config.clone()),
+            };
+            locked_holder
+                .read()
+                .iter()
+                .map(|(_, segment)| match segment {
+                    LockedSegment::Original(s) => s.read(),
+                    LockedSegment::Proxy(_) => unreachable!(),
+                })
+                .filter(|segment| segment.total_point_count() > 0)
+                .for_each(|segment| {
+                    assert_eq!(
+                        segment.config().vector_data[DEFAULT_VECTOR_NAME].index,
+                        expected_index,
+                        "segment must be optimized with target index config",
+                    );
+                });
+        }
+    }
 }
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use parking_lot::Mutex;
 use segment::common::operation_time_statistics::OperationDurationsAggregator;
-use segment::types::{HnswConfig, HnswGlobalConfig, QuantizationConfig, SegmentType};
+use segment::types::{HnswConfig, HnswGlobalConfig, Indexes, QuantizationConfig, SegmentType};
 
 use crate::collection_manager::holders::segment_holder::{
     LockedSegmentHolder, SegmentHolder, SegmentId,
@@ -16,11 +16,22 @@ use crate::config::CollectionParams;
 
 const BYTES_IN_KB: usize = 1024;
 
+/// HNSW graphs of a segment are only extended with new points if the segment is at most this many
+/// times larger than the new points
+///
+/// Extending the graphs rewrites the whole segment, this avoids rewriting a large segment on every
+/// small batch of new points.
+const MAX_HNSW_EXTENSION_RATIO: usize = 10;
+
 /// Looks for the segments, which require to be indexed.
 ///
 /// If segment is too large, but still does not have indexes - it is time to create some indexes.
 /// The process of index creation is slow and CPU-bounded, so it is convenient to perform
 /// index building in a same way as segment re-creation.
+///
+/// If incremental HNSW building is enabled, the segment is preferably optimized together with the
+/// largest HNSW indexed segment not much larger than itself, so its points are inserted into the
+/// existing graph.
 pub struct IndexingOptimizer {
     default_segments_number: usize,
     thresholds_config: OptimizerThresholds,
@@ -91,6 +102,61 @@ impl IndexingOptimizer {
             .map(|(idx, size)| (*idx, size))
     }
 
+    /// Find the largest segment with HNSW graphs on all dense vectors, which still fits into
+    /// `max_size_bytes`
+    ///
+    /// New points can be inserted into the graphs of such segment incrementally, instead of
+    /// building the graphs from scratch. Graphs built with other `m` or `ef_construct` than
+    /// the current config are not extended, as the result would mix both configs.
+    fn largest_hnsw_indexed_segment(
+        &self,
+        segments: &SegmentHolder,
+        excluded_ids: &HashSet<SegmentId>,
+        max_size_bytes: usize,
+    ) -> Option<(SegmentId, usize)> {
+        segments
+            .iter()
+            // Excluded externally, might already be scheduled for optimization
+            .filter(|(idx, _)| !excluded_ids.contains(idx))
+            .filter_map(|(idx, segment)| {
+                let segment_entry = segment.get();
+                let read_segment = segment_entry.read();
+                let vector_size = read_segment
+                    .max_available_vectors_size_in_bytes()
+                    .unwrap_or_default();
+
+                if read_segment.segment_type() == SegmentType::Special {
+                    return None; // Never optimize already optimized segment
+                }
+
+                if vector_size >= max_size_bytes {
+                    return None;
+                }
+
+                let segment_config = read_segment.config();
+                let mut dense_vectors = self.collection_params.vectors.params_iter().peekable();
+                // Sparse-only collections have no graphs to extend
+                let has_dense_vectors = dense_vectors.peek().is_some();
+                let is_hnsw_indexed = has_dense_vectors
+                    && dense_vectors.all(|(vector_name, _)| {
+                        let Some(vector_data) = segment_config.vector_data.get(vector_name) else {
+                            return false;
+                        };
+                        let Indexes::Hnsw(graph_hnsw) = &vector_data.index else {
+                            return false;
+                        };
+                        let target_hnsw = self
+                            .collection_params
+                            .get_vector_hnsw_config(vector_name, &self.hnsw_config);
+                        graph_hnsw.m == target_hnsw.m
+                            && graph_hnsw.ef_construct == target_hnsw.ef_construct
+                    });
+
+                is_hnsw_indexed.then_some((*idx, vector_size))
+            })
+            .max_by_key(|(_, vector_size_bytes)| *vector_size_bytes)
+    }
+
     fn worst_segment(
         &self,
         segments: LockedSegmentHolder,
@@ -219,15 +285,31 @@ impl IndexingOptimizer {
             return vec![selected_segment_id, *idx];
         }
 
+        let max_segment_size_bytes = self
+            .thresholds_config
+            .max_segment_size_kb
+            .saturating_mul(BYTES_IN_KB);
+
+        // Find the largest HNSW indexed segment to extend its graphs with the selected segment
+        let max_extended_size_bytes = max_segment_size_bytes
+            .saturating_sub(selected_segment_size)
+            .min(selected_segment_size.saturating_mul(MAX_HNSW_EXTENSION_RATIO));
+        if common::flags::feature_flags().incremental_hnsw_building
+            && let Some((idx, _)) = self.largest_hnsw_indexed_segment(
+                &segments_read_guard,
+                excluded_ids,
+                max_extended_size_bytes,
+            )
+            && idx != selected_segment_id
+        {
+            return vec![selected_segment_id, idx];
+        }
+
         // Find smallest indexed to check if we can reindex together
         let smallest_indexed = Self::smallest_indexed_segment(&segments_read_guard, excluded_ids);
         if let Some((idx, size)) = smallest_indexed
             && idx != selected_segment_id
-            && selected_segment_size + size
-                < self
-                    .thresholds_config
-                    .max_segment_size_kb
-                    .saturating_mul(BYTES_IN_KB)
+            && selected_segment_size + size < max_segment_size_bytes
         {
             return vec![selected_segment_id, idx];
         }
@@ -284,7 +366,7 @@ impl SegmentOptimizer for IndexingOptimizer {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::BTreeMap;
+    use std::collections::{BTreeMap, HashMap};
     use std::ops::Deref;
     use std::sync::Arc;
     use std::sync::atomic::AtomicBool;
@@ -297,6 +379,7 @@ mod tests {
     use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
     use segment::entry::entry_point::SegmentEntry;
     use segment::fixtures::index_fixtures::random_vector;
+    use segment::index::VectorIndexEnum;
     use segment::index::hnsw_index::num_rayon_threads;
     use segment::json_path::JsonPath;
     use segment::payload_json;
@@ -825,6 +908,192 @@ mod tests {
         assert_eq!(locked_holder.read().len(), number_of_segments);
     }
 
+    /// Test that indexing optimizer extends the largest HNSW indexed segment, which fits into
+    /// the max segment size, with new unindexed points
+    #[test]
+    fn test_indexing_optimizer_extends_largest_hnsw_segment() {
+        init();
+
+        let mut holder = SegmentHolder::default();
+
+        let stopped = AtomicBool::new(false);
+        let dim = 256;
+
+        let segments_dir = Builder::new().prefix("segments_dir").tempdir().unwrap();
+        let segments_temp_dir = Builder::new()
+            .prefix("segments_temp_dir")
+            .tempdir()
+            .unwrap();
+        let mut opnum = 101..1000000;
+
+        let small_segment = random_segment(segments_dir.path(), opnum.next().unwrap(), 50, dim);
+        let middle_segment = random_segment(segments_dir.path(), opnum.next().unwrap(), 150, dim);
+        let large_segment = random_segment(segments_dir.path(), opnum.next().unwrap(), 300, dim);
+        let segment_config = small_segment.segment_config.clone();
+
+        holder.add_new(small_segment);
+        holder.add_new(middle_segment);
+        holder.add_new(large_segment);
+
+        let locked_holder: Arc<RwLock<_, _>> = Arc::new(RwLock::new(holder));
+
+        let mut index_optimizer = IndexingOptimizer::new(
+            10, // Index every segment on its own
+            OptimizerThresholds {
+                max_segment_size_kb: 300,
+                memmap_threshold_kb: 1000,
+                indexing_threshold_kb: 10,
+            },
+            segments_dir.path().to_owned(),
+            segments_temp_dir.path().to_owned(),
+            CollectionParams {
+                vectors: VectorsConfig::Single(
+                    VectorParamsBuilder::new(
+                        segment_config.vector_data[DEFAULT_VECTOR_NAME].size as u64,
+                        segment_config.vector_data[DEFAULT_VECTOR_NAME].distance,
+                    )
+                    .build(),
+                ),
+                ..CollectionParams::empty()
+            },
+            Default::default(),
+            HnswGlobalConfig::default(),
+            Default::default(),
+        );
+
+        let permit_cpu_count = num_rayon_threads(0);
+        let budget = ResourceBudget::new(permit_cpu_count, permit_cpu_count);
+
+        let optimize = |index_optimizer: &IndexingOptimizer, segment_ids: Vec<SegmentId>| {
+            let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+            index_optimizer
+                .optimize(
+                    locked_holder.clone(),
+                    segment_ids,
+                    permit,
+                    budget.clone(),
+                    &stopped,
+                )
+                .unwrap();
+        };
+
+        // Index all segments on their own
+        loop {
+            let suggested_to_optimize =
+                index_optimizer.check_condition(locked_holder.clone(), &Default::default());
+            if suggested_to_optimize.is_empty() {
+                break;
+            }
+            assert_eq!(suggested_to_optimize.len(), 1);
+            optimize(&index_optimizer, suggested_to_optimize);
+        }
+
+        let segment_sizes = |locked_holder: &LockedSegmentHolder| {
+            locked_holder
+                .read()
+                .iter()
+                .map(|(idx, segment)| (*idx, segment.get().read().available_point_count()))
+                .collect::<HashMap<_, _>>()
+        };
+        let indexed_sizes = segment_sizes(&locked_holder);
+        let find_segment = |points: usize| {
+            indexed_sizes
+                .iter()
+                .find(|(_, size)| **size == points)
+                .map(|(idx, _)| *idx)
+                .unwrap()
+        };
+        let small_segment_id = find_segment(50);
+        let middle_segment_id = find_segment(150);
+
+        // Level of each point in the HNSW graph of a segment, by point ID
+        let point_levels = |segment_id: SegmentId| {
+            let holder = locked_holder.read();
+            let LockedSegment::Original(segment) = holder.get(segment_id).unwrap() else {
+                unreachable!("optimized segment can't be a proxy");
+            };
+            let segment = segment.read();
+            let vector_index = segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_index
+                .borrow();
+            let VectorIndexEnum::Hnsw(hnsw_index) = &*vector_index else {
+                panic!("segment is not HNSW indexed");
+            };
+            segment
+                .iter_points()
+                .map(|point_id| {
+                    let internal_id = segment.get_internal_id(point_id).unwrap();
+                    (point_id, hnsw_index.graph().point_level(internal_id))
+                })
+                .collect::<HashMap<_, _>>()
+        };
+        let middle_point_levels = point_levels(middle_segment_id);
+        // Levels are random, a graph built from scratch would almost surely assign other ones
+        assert!(middle_point_levels.values().any(|level| *level > 0));
+
+        // New points arrive into a new unindexed segment
+        let new_segment = random_segment(segments_dir.path(), opnum.next().unwrap(), 100, dim);
+        let new_segment_id = locked_holder.write().add_new(new_segment);
+
+        index_optimizer.default_segments_number = 1;
+
+        // Graphs built with another config are not extended, the smallest indexed segment is
+        // optimized together with new points instead
+        let hnsw_config = index_optimizer.hnsw_config.clone();
+        index_optimizer.hnsw_config.m *= 2;
+        let suggested_to_optimize =
+            index_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert_eq!(
+            suggested_to_optimize,
+            vec![new_segment_id, small_segment_id]
+        );
+        index_optimizer.hnsw_config = hnsw_config;
+
+        // The large segment doesn't fit together with new points, so they extend the middle one
+        let suggested_to_optimize =
+            index_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert_eq!(
+            suggested_to_optimize,
+            vec![new_segment_id, middle_segment_id]
+        );
+        optimize(&index_optimizer, suggested_to_optimize);
+
+        let sizes = segment_sizes(&locked_holder);
+        assert_eq!(sizes.values().filter(|size| **size > 0).count(), 3);
+        assert_eq!(sizes[&small_segment_id], 50);
+        let extended_segment_id = *sizes.iter().find(|(_, size)| **size == 250).unwrap().0;
+
+        // The graph of the middle segment is extended, its points keep their levels
+        let extended_point_levels = point_levels(extended_segment_id);
+        for (point_id, level) in &middle_point_levels {
+            assert_eq!(extended_point_levels[point_id], *level);
+        }
+
+        // A small batch of new points doesn't rewrite the much larger extended segment, the
+        // small segment is extended instead
+        let new_segment = random_segment(segments_dir.path(), opnum.next().unwrap(), 20, dim);
+        let new_segment_id = locked_holder.write().add_new(new_segment);
+        let suggested_to_optimize =
+            index_optimizer.check_condition(locked_holder.clone(), &Default::default());
+        assert_eq!(
+            suggested_to_optimize,
+            vec![new_segment_id, small_segment_id]
+        );
+        optimize(&index_optimizer, suggested_to_optimize);
+        assert!(
+            segment_sizes(&locked_holder)
+                .values()
+                .any(|size| *size == 70)
+        );
+        for (_, segment) in locked_holder.read().iter() {
+            let segment = segment.get();
+            let segment = segment.read();
+            if segment.available_point_count() > 0 {
+                assert_eq!(segment.segment_type(), SegmentType::Indexed);
+            }
+        }
+    }
+
     /// This tests things are as we expect when we define both `on_disk: false` and `memmap_threshold`
     ///
     /// Before this PR (<https://github.com/qdrant/qdrant/pull/3167>) such configuration would create an infinite optimization loop.
@@ -878,6 +1147,7 @@ mod tests {
             on_disk: None,
             payload_m: None,
             copy_vectors: None,
+            matryoshka_dim: None,
         };
 
         {
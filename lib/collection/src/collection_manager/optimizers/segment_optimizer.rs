@@ -30,7 +30,6 @@ use crate::collection_manager::holders::segment_holder::{
     LockedSegment, LockedSegmentHolder, SegmentHolder, SegmentId,
 };
 use crate::config::CollectionParams;
-use crate::operations::config_diff::DiffConfig;
 use crate::operations::types::{CollectionError, CollectionResult};
 
 const BYTES_IN_KB: usize = 1024;
@@ -244,19 +243,25 @@ pub trait SegmentOptimizer {
             collection_params.to_base_vector_data(collection_quantization.as_ref())?;
         let mut sparse_vector_data = collection_params.to_sparse_vector_data()?;
 
-        // If indexing, change to HNSW index and quantization
+        // If indexing, change to HNSW (or VDE, DiskANN, IVF) index and quantization
         if threshold_is_indexed {
             let collection_hnsw = self.hnsw_config();
             vector_data.iter_mut().for_each(|(vector_name, config)| {
-                // Assign HNSW index
-                let param_hnsw = collection_params
-                    .vectors
-                    .get_params(vector_name)
-                    .and_then(|params| params.hnsw_config);
-                let vector_hnsw = param_hnsw
-                    .and_then(|c| c.update(collection_hnsw).ok())
-                    .unwrap_or_else(|| collection_hnsw.clone());
-                config.index = Indexes::Hnsw(vector_hnsw);
+                // Assign VDE, DiskANN or IVF index if configured, HNSW index otherwise
+                let vector_params = collection_params.vectors.get_params(vector_name);
+                let param_vde = vector_params.and_then(|params| params.vde_config.clone());
+                let param_diskann = vector_params.and_then(|params| params.diskann_config.clone());
+                let param_ivf = vector_params.and_then(|params| params.ivf_config.clone());
+                config.index = match (param_vde, param_diskann, param_ivf) {
+                    (Some(vector_vde), _, _) => Indexes::Vde(vector_vde),
+                    (None, Some(vector_diskann), _) => Indexes::DiskAnn(vector_diskann),
+                    (None, None, Some(vector_ivf)) => Indexes::Ivf(vector_ivf),
+                    (None, None, None) => Indexes::Hnsw(
+                        collection_params
+                            .get_vector_hnsw_config(vector_name, collection_hnsw)
+                            .into_owned(),
+                    ),
+                };
 
                 // Assign quantization config
                 let param_quantization = collection_params
@@ -469,6 +469,7 @@ mod tests {
             on_disk: None,
             payload_m: None,
             copy_vectors: None,
+            matryoshka_dim: None,
         };
 
         let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
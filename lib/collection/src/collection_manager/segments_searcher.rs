@@ -183,18 +183,30 @@ impl SegmentsSearcher {
 
         const DEFAULT_CAPACITY: usize = 3;
         let mut idf_vectors: TinyVec<[&VectorName; DEFAULT_CAPACITY]> = Default::default();
+        // BM25 vectors with `k1` and `b` parameters
+        let mut bm25_vectors: TinyVec<[(&VectorName, f32, f32); DEFAULT_CAPACITY]> =
+            Default::default();
 
         // check vector names existing
         for req in &batch_request.searches {
             let vector_name = req.query.get_vector_name();
             collection_config.params.get_distance(vector_name)?;
-            if let Some(sparse_vector_params) = collection_config
+            let Some(sparse_vector_params) = collection_config
                 .params
                 .get_sparse_vector_params_opt(vector_name)
-                && sparse_vector_params.modifier == Some(Modifier::Idf)
-                && !idf_vectors.contains(&vector_name)
-            {
-                idf_vectors.push(vector_name);
+            else {
+                continue;
+            };
+            match sparse_vector_params.modifier {
+                Some(Modifier::Idf) if !idf_vectors.contains(&vector_name) => {
+                    idf_vectors.push(vector_name);
+                }
+                Some(Modifier::Bm25 { k1, b })
+                    if !bm25_vectors.iter().any(|(name, ..)| *name == vector_name) =>
+                {
+                    bm25_vectors.push((vector_name, k1.0, b.0));
+                }
+                _ => {}
             }
         }
 
@@ -210,6 +222,10 @@ impl SegmentsSearcher {
                 .iterate_sparse(|vector_name, sparse_vector| {
                     if idf_vectors.contains(&vector_name) {
                         query_context.init_idf(vector_name, &sparse_vector.indices);
+                    } else if let Some((_, k1, b)) =
+                        bm25_vectors.iter().find(|(name, ..)| *name == vector_name)
+                    {
+                        query_context.init_bm25(vector_name, *k1, *b, &sparse_vector.indices);
                     }
                 })
         }
@@ -761,6 +777,9 @@ fn get_hnsw_ef_construct(config: &SegmentConfig, vector_name: &VectorName) -> Op
         .and_then(|config| match &config.index {
             Indexes::Plain {} => None,
             Indexes::Hnsw(hnsw) => Some(hnsw),
+            Indexes::Vde(_) => None,
+            Indexes::DiskAnn(_) => None,
+            Indexes::Ivf(_) => None,
         })
         .map(|hnsw| hnsw.ef_construct)
 }
@@ -12,6 +12,7 @@ fn score_point(id: usize, score: ScoreType, version: SeqNumberType) -> ScoredPoi
         vector: None,
         shard_key: None,
         order_value: None,
+        highlight: None,
     }
 }
 
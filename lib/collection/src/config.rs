@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
 use std::io::{Read, Write};
@@ -20,7 +21,9 @@ use uuid::Uuid;
 use validator::Validate;
 use wal::WalOptions;
 
-use crate::operations::config_diff::{DiffConfig, QuantizationConfigDiff};
+use crate::operations::config_diff::{
+    DiffConfig, DiskAnnConfigDiff, IvfConfigDiff, QuantizationConfigDiff, VdeConfigDiff,
+};
 use crate::operations::types::{
     CollectionError, CollectionResult, SparseVectorParams, SparseVectorsConfig, VectorParams,
     VectorParamsDiff, VectorsConfig, VectorsConfigDiff,
@@ -358,6 +361,33 @@ impl CollectionParams {
         }
     }
 
+    /// HNSW config the graphs of the given vector are built with
+    ///
+    /// Takes vector-specific HNSW config (if any) and merges it with the collection-wide config.
+    pub fn get_vector_hnsw_config<'a>(
+        &self,
+        vector_name: &VectorName,
+        collection_hnsw: &'a HnswConfig,
+    ) -> Cow<'a, HnswConfig> {
+        let vector_hnsw = self
+            .vectors
+            .get_params(vector_name)
+            .and_then(|vector_params| vector_params.hnsw_config)
+            .and_then(|vector_hnsw| match vector_hnsw.update(collection_hnsw) {
+                Ok(hnsw) => Some(hnsw),
+                Err(err) => {
+                    log::warn!(
+                        "Failed to merge collection and vector HNSW config, ignoring: {err}"
+                    );
+                    None
+                }
+            });
+        match vector_hnsw {
+            Some(vector_hnsw) => Cow::Owned(vector_hnsw),
+            None => Cow::Borrowed(collection_hnsw),
+        }
+    }
+
     fn get_vector_params_mut(
         &mut self,
         vector_name: &VectorName,
@@ -412,6 +442,9 @@ impl CollectionParams {
                 hnsw_config,
                 quantization_config,
                 on_disk,
+                vde_config,
+                diskann_config,
+                ivf_config,
             } = update_params.clone();
 
             if let Some(hnsw_diff) = hnsw_config {
@@ -433,6 +466,9 @@ impl CollectionParams {
                     QuantizationConfigDiff::Binary(binary) => {
                         Some(QuantizationConfig::Binary(binary))
                     }
+                    QuantizationConfigDiff::Rabitq(rabitq) => {
+                        Some(QuantizationConfig::Rabitq(rabitq))
+                    }
                     QuantizationConfigDiff::Disabled(_) => None,
                 }
             }
@@ -440,6 +476,27 @@ impl CollectionParams {
             if let Some(on_disk) = on_disk {
                 vector_params.on_disk = Some(on_disk);
             }
+
+            if let Some(vde_diff) = vde_config {
+                vector_params.vde_config = match vde_diff {
+                    VdeConfigDiff::Config(vde) => Some(vde),
+                    VdeConfigDiff::Disabled(_) => None,
+                }
+            }
+
+            if let Some(diskann_diff) = diskann_config {
+                vector_params.diskann_config = match diskann_diff {
+                    DiskAnnConfigDiff::Config(diskann) => Some(diskann),
+                    DiskAnnConfigDiff::Disabled(_) => None,
+                }
+            }
+
+            if let Some(ivf_diff) = ivf_config {
+                vector_params.ivf_config = match ivf_diff {
+                    IvfConfigDiff::Config(ivf) => Some(ivf),
+                    IvfConfigDiff::Disabled(_) => None,
+                }
+            }
         }
         Ok(())
     }
@@ -214,6 +214,7 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
@@ -226,6 +227,7 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
@@ -296,6 +296,7 @@ impl From<CollectionQueryGroupsRequest> for GroupRequest {
             with_vector,
             with_payload,
             lookup_from,
+            highlight: None,
         };
 
         GroupRequest {
@@ -569,6 +570,7 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
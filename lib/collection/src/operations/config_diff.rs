@@ -6,7 +6,8 @@ use api::rest::MaxOptimizationThreads;
 use merge::Merge;
 use schemars::JsonSchema;
 use segment::types::{
-    BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization, StrictModeConfig,
+    BinaryQuantization, DiskAnnConfig, HnswConfig, IvfConfig, ProductQuantization,
+    RabitqQuantization, ScalarQuantization, StrictModeConfig, VdeIndexConfig,
 };
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -96,6 +97,12 @@ pub struct HnswConfigDiff {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub copy_vectors: Option<bool>,
+    /// Build the graph on the first N dimensions of dense vectors, for Matryoshka embeddings.
+    /// Searches traverse the graph with the same dimensions and rescore found points with all
+    /// dimensions. Ignored for quantized vectors. If not set, all dimensions are used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub matryoshka_dim: Option<usize>,
 }
 
 #[derive(
@@ -374,6 +381,7 @@ pub enum QuantizationConfigDiff {
     Scalar(ScalarQuantization),
     Product(ProductQuantization),
     Binary(BinaryQuantization),
+    Rabitq(RabitqQuantization),
     Disabled(Disabled),
 }
 
@@ -383,12 +391,73 @@ impl QuantizationConfigDiff {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[serde(untagged)]
+pub enum VdeConfigDiff {
+    Config(VdeIndexConfig),
+    Disabled(Disabled),
+}
+
+impl VdeConfigDiff {
+    pub fn new_disabled() -> Self {
+        VdeConfigDiff::Disabled(Disabled::Disabled)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[serde(untagged)]
+pub enum DiskAnnConfigDiff {
+    Config(DiskAnnConfig),
+    Disabled(Disabled),
+}
+
+impl DiskAnnConfigDiff {
+    pub fn new_disabled() -> Self {
+        DiskAnnConfigDiff::Disabled(Disabled::Disabled)
+    }
+}
+
+impl Validate for DiskAnnConfigDiff {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            DiskAnnConfigDiff::Config(config) => config.validate(),
+            DiskAnnConfigDiff::Disabled(_) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[serde(untagged)]
+pub enum IvfConfigDiff {
+    Config(IvfConfig),
+    Disabled(Disabled),
+}
+
+impl IvfConfigDiff {
+    pub fn new_disabled() -> Self {
+        IvfConfigDiff::Disabled(Disabled::Disabled)
+    }
+}
+
+impl Validate for IvfConfigDiff {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            IvfConfigDiff::Config(config) => config.validate(),
+            IvfConfigDiff::Disabled(_) => Ok(()),
+        }
+    }
+}
+
 impl Validate for QuantizationConfigDiff {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
             QuantizationConfigDiff::Scalar(scalar) => scalar.validate(),
             QuantizationConfigDiff::Product(product) => product.validate(),
             QuantizationConfigDiff::Binary(binary) => binary.validate(),
+            QuantizationConfigDiff::Rabitq(rabitq) => rabitq.validate(),
             QuantizationConfigDiff::Disabled(_) => Ok(()),
         }
     }
@@ -434,6 +503,18 @@ mod tests {
         assert_eq!(new_config.m, 32)
     }
 
+    #[test]
+    fn test_hnsw_matryoshka_update() {
+        let base_config = HnswConfig::default();
+        let update: HnswConfigDiff = serde_json::from_str(r#"{ "matryoshka_dim": 256 }"#).unwrap();
+        let new_config = update.update(&base_config).unwrap();
+        assert_eq!(new_config.matryoshka_dim, Some(256));
+        assert!(new_config.mismatch_requires_rebuild(&base_config));
+
+        let invalid: HnswConfigDiff = serde_json::from_str(r#"{ "matryoshka_dim": 0 }"#).unwrap();
+        assert!(invalid.validate().is_err());
+    }
+
     #[test]
     fn test_optimizer_update() {
         let base_config = OptimizersConfig {
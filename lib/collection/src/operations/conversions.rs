@@ -7,28 +7,34 @@ use api::grpc::conversions::{
     convert_shard_key_from_grpc, convert_shard_key_from_grpc_opt, convert_shard_key_to_grpc,
     from_grpc_dist,
 };
+use api::grpc::qdrant::disk_ann_config_diff::Config as DiskAnnConfigDiffPb;
+use api::grpc::qdrant::ivf_config_diff::Config as IvfConfigDiffPb;
 use api::grpc::qdrant::quantization_config_diff::Quantization;
 use api::grpc::qdrant::update_collection_cluster_setup_request::{
     Operation as ClusterOperationsPb, Operation,
 };
+use api::grpc::qdrant::vde_config_diff::Config as VdeConfigDiffPb;
 use api::rest::schema::ShardKeySelector;
 use api::rest::{BaseGroupRequest, MaxOptimizationThreads};
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 use segment::common::operation_error::OperationError;
 use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
 use segment::types::{
-    Distance, HnswConfig, MultiVectorConfig, QuantizationConfig, StrictModeConfigOutput,
-    WithPayloadInterface,
+    DiskAnnConfig, Distance, HnswConfig, IvfConfig, MultiVectorConfig, QuantizationConfig,
+    StrictModeConfigOutput, VdeIndexConfig, WithPayloadInterface,
 };
 use tonic::Status;
+use validator::Validate;
 
 use super::cluster_ops::ReshardingDirection;
 use super::consistency_params::ReadConsistency;
 use super::types::{
-    CollectionConfig, ContextExamplePair, CoreSearchRequest, Datatype, DiscoverRequestInternal,
-    GroupsResult, Modifier, PointGroup, RecommendExample, RecommendGroupsRequestInternal,
-    ReshardingInfo, SparseIndexParams, SparseVectorParams, SparseVectorsConfig, VectorParamsDiff,
-    VectorsConfigDiff,
+    CollectionConfig, ContextExamplePair, CoreSearchRequest, DEFAULT_BM25_B, DEFAULT_BM25_K1,
+    Datatype, DiscoverRequestInternal, GroupsResult, Modifier, PointGroup, RecommendExample,
+    RecommendGroupsRequestInternal, ReshardingInfo, SparseIndexParams, SparseVectorParams,
+    SparseVectorsConfig, VectorParamsDiff, VectorsConfigDiff, validate_sparse_modifier,
+    validate_vde_index_config,
 };
 use crate::config::{
     CollectionParams, ShardingMethod, WalConfig, default_replication_factor,
@@ -44,8 +50,8 @@ use crate::operations::cluster_ops::{
     RestartTransfer, RestartTransferOperation,
 };
 use crate::operations::config_diff::{
-    CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
-    WalConfigDiff,
+    CollectionParamsDiff, DiskAnnConfigDiff, HnswConfigDiff, IvfConfigDiff, OptimizersConfigDiff,
+    QuantizationConfigDiff, VdeConfigDiff, WalConfigDiff,
 };
 use crate::operations::point_ops::{FilterSelector, PointIdsList, PointsSelector, WriteOrdering};
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
@@ -123,6 +129,7 @@ pub fn try_record_from_grpc(
         vectors,
         shard_key,
         order_value,
+        highlight: _,
     } = point;
     let id = id
         .ok_or_else(|| Status::invalid_argument("retrieved point does not have an ID"))?
@@ -205,7 +212,7 @@ pub fn try_discover_request_from_grpc(
         target,
         context: Some(context),
         filter: filter.map(|f| f.try_into()).transpose()?,
-        params: params.map(|p| p.into()),
+        params: params.map(|p| p.try_into()).transpose()?,
         limit: limit as usize,
         offset: offset.map(|x| x as usize),
         with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
@@ -241,6 +248,7 @@ impl From<api::grpc::qdrant::HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m,
             copy_vectors,
+            matryoshka_dim,
         } = value;
         Self {
             m: m.map(|v| v as usize),
@@ -250,6 +258,7 @@ impl From<api::grpc::qdrant::HnswConfigDiff> for HnswConfigDiff {
             on_disk,
             payload_m: payload_m.map(|v| v as usize),
             copy_vectors,
+            matryoshka_dim: matryoshka_dim.map(|v| v as usize),
         }
     }
 }
@@ -264,6 +273,7 @@ impl From<HnswConfigDiff> for api::grpc::qdrant::HnswConfigDiff {
             on_disk,
             payload_m,
             copy_vectors,
+            matryoshka_dim,
         } = value;
         Self {
             m: m.map(|v| v as u64),
@@ -273,6 +283,7 @@ impl From<HnswConfigDiff> for api::grpc::qdrant::HnswConfigDiff {
             on_disk,
             payload_m: payload_m.map(|v| v as u64),
             copy_vectors,
+            matryoshka_dim: matryoshka_dim.map(|v| v as u64),
         }
     }
 }
@@ -369,6 +380,7 @@ impl TryFrom<api::grpc::qdrant::QuantizationConfigDiff> for QuantizationConfigDi
                 Quantization::Scalar(scalar) => Ok(Self::Scalar(scalar.try_into()?)),
                 Quantization::Product(product) => Ok(Self::Product(product.try_into()?)),
                 Quantization::Binary(binary) => Ok(Self::Binary(binary.try_into()?)),
+                Quantization::Rabitq(rabitq) => Ok(Self::Rabitq(rabitq.into())),
                 Quantization::Disabled(_) => Ok(Self::new_disabled()),
             },
         }
@@ -417,6 +429,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             on_disk,
             payload_m,
             copy_vectors,
+            matryoshka_dim,
         } = hnsw_config;
 
         let CollectionParams {
@@ -499,6 +512,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                     on_disk,
                     payload_m: payload_m.map(|v| v as u64),
                     copy_vectors,
+                    matryoshka_dim: matryoshka_dim.map(|v| v as u64),
                 }),
                 optimizer_config: Some(api::grpc::qdrant::OptimizersConfigDiff {
                     deleted_threshold: Some(deleted_threshold),
@@ -554,6 +568,7 @@ impl From<RecordInternal> for api::grpc::qdrant::RetrievedPoint {
             vectors: vector.map(api::grpc::qdrant::VectorsOutput::from),
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(From::from),
+            highlight: Default::default(),
         }
     }
 }
@@ -687,6 +702,9 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             on_disk,
             datatype,
             multivector_config,
+            vde_config,
+            diskann_config,
+            ivf_config,
         } = vector_params;
         Ok(Self {
             size: NonZeroU64::new(size).ok_or_else(|| {
@@ -699,6 +717,11 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
                 .transpose()?,
             on_disk,
             storage_type: None, // gRPC doesn't support storage_type yet
+            vde_config: vde_config.map(convert_vde_config_from_proto).transpose()?,
+            diskann_config: diskann_config
+                .map(convert_diskann_config_from_proto)
+                .transpose()?,
+            ivf_config: ivf_config.map(convert_ivf_config_from_proto).transpose()?,
             datatype: convert_datatype_from_proto(datatype)?,
             multivector_config: multivector_config
                 .map(MultiVectorConfig::try_from)
@@ -715,6 +738,8 @@ fn convert_datatype_from_proto(datatype: Option<i32>) -> Result<Option<Datatype>
                 api::grpc::qdrant::Datatype::Uint8 => Ok(Some(Datatype::Uint8)),
                 api::grpc::qdrant::Datatype::Float32 => Ok(Some(Datatype::Float32)),
                 api::grpc::qdrant::Datatype::Float16 => Ok(Some(Datatype::Float16)),
+                api::grpc::qdrant::Datatype::Binary => Ok(Some(Datatype::Binary)),
+                api::grpc::qdrant::Datatype::BFloat16 => Ok(Some(Datatype::BFloat16)),
                 api::grpc::qdrant::Datatype::Default => Ok(None),
             }
         } else {
@@ -727,6 +752,86 @@ fn convert_datatype_from_proto(datatype: Option<i32>) -> Result<Option<Datatype>
     }
 }
 
+fn convert_vde_config_from_proto(
+    vde_config: api::grpc::qdrant::VdeConfig,
+) -> Result<VdeIndexConfig, Status> {
+    let vde_config = VdeIndexConfig::try_from(vde_config)?;
+    validate_vde_index_config(&vde_config)
+        .map_err(|err| Status::invalid_argument(err.to_string()))?;
+    Ok(vde_config)
+}
+
+impl TryFrom<api::grpc::qdrant::VdeConfigDiff> for VdeConfigDiff {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::VdeConfigDiff) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::VdeConfigDiff { config } = value;
+        match config {
+            None => Err(Status::invalid_argument("VDE config is not specified")),
+            Some(config) => match config {
+                VdeConfigDiffPb::Params(params) => {
+                    Ok(Self::Config(convert_vde_config_from_proto(params)?))
+                }
+                VdeConfigDiffPb::Disabled(_) => Ok(Self::new_disabled()),
+            },
+        }
+    }
+}
+
+fn convert_diskann_config_from_proto(
+    diskann_config: api::grpc::qdrant::DiskAnnConfig,
+) -> Result<DiskAnnConfig, Status> {
+    let diskann_config = DiskAnnConfig::try_from(diskann_config)?;
+    diskann_config
+        .validate()
+        .map_err(|err| Status::invalid_argument(err.to_string()))?;
+    Ok(diskann_config)
+}
+
+impl TryFrom<api::grpc::qdrant::DiskAnnConfigDiff> for DiskAnnConfigDiff {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::DiskAnnConfigDiff) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::DiskAnnConfigDiff { config } = value;
+        match config {
+            None => Err(Status::invalid_argument("DiskANN config is not specified")),
+            Some(config) => match config {
+                DiskAnnConfigDiffPb::Params(params) => {
+                    Ok(Self::Config(convert_diskann_config_from_proto(params)?))
+                }
+                DiskAnnConfigDiffPb::Disabled(_) => Ok(Self::new_disabled()),
+            },
+        }
+    }
+}
+
+fn convert_ivf_config_from_proto(
+    ivf_config: api::grpc::qdrant::IvfConfig,
+) -> Result<IvfConfig, Status> {
+    let ivf_config = IvfConfig::try_from(ivf_config)?;
+    ivf_config
+        .validate()
+        .map_err(|err| Status::invalid_argument(err.to_string()))?;
+    Ok(ivf_config)
+}
+
+impl TryFrom<api::grpc::qdrant::IvfConfigDiff> for IvfConfigDiff {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::IvfConfigDiff) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::IvfConfigDiff { config } = value;
+        match config {
+            None => Err(Status::invalid_argument("IVF config is not specified")),
+            Some(config) => match config {
+                IvfConfigDiffPb::Params(params) => {
+                    Ok(Self::Config(convert_ivf_config_from_proto(params)?))
+                }
+                IvfConfigDiffPb::Disabled(_) => Ok(Self::new_disabled()),
+            },
+        }
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::VectorParamsDiff> for VectorParamsDiff {
     type Error = Status;
 
@@ -735,22 +840,38 @@ impl TryFrom<api::grpc::qdrant::VectorParamsDiff> for VectorParamsDiff {
             hnsw_config,
             quantization_config,
             on_disk,
+            vde_config,
+            diskann_config,
+            ivf_config,
         } = vector_params;
         Ok(Self {
             hnsw_config: hnsw_config.map(Into::into),
             quantization_config: quantization_config.map(TryInto::try_into).transpose()?,
             on_disk,
+            vde_config: vde_config.map(TryInto::try_into).transpose()?,
+            diskann_config: diskann_config.map(TryInto::try_into).transpose()?,
+            ivf_config: ivf_config.map(TryInto::try_into).transpose()?,
         })
     }
 }
 
-impl From<api::grpc::qdrant::Modifier> for Modifier {
-    fn from(value: api::grpc::qdrant::Modifier) -> Self {
-        match value {
-            api::grpc::qdrant::Modifier::None => Modifier::None,
-            api::grpc::qdrant::Modifier::Idf => Modifier::Idf,
+fn modifier_from_grpc(
+    modifier: api::grpc::qdrant::Modifier,
+    bm25_params: Option<api::grpc::qdrant::Bm25Params>,
+) -> Result<Modifier, Status> {
+    let modifier = match modifier {
+        api::grpc::qdrant::Modifier::None => Modifier::None,
+        api::grpc::qdrant::Modifier::Idf => Modifier::Idf,
+        api::grpc::qdrant::Modifier::Bm25 => {
+            let api::grpc::qdrant::Bm25Params { k1, b } = bm25_params.unwrap_or_default();
+            Modifier::Bm25 {
+                k1: OrderedFloat(k1.unwrap_or(DEFAULT_BM25_K1)),
+                b: OrderedFloat(b.unwrap_or(DEFAULT_BM25_B)),
+            }
         }
-    }
+    };
+    validate_sparse_modifier(&modifier).map_err(|err| Status::invalid_argument(err.to_string()))?;
+    Ok(modifier)
 }
 
 impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
@@ -759,7 +880,11 @@ impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
     fn try_from(
         sparse_vector_params: api::grpc::qdrant::SparseVectorParams,
     ) -> Result<Self, Self::Error> {
-        let api::grpc::qdrant::SparseVectorParams { index, modifier } = sparse_vector_params;
+        let api::grpc::qdrant::SparseVectorParams {
+            index,
+            modifier,
+            bm25_params,
+        } = sparse_vector_params;
         Ok(Self {
             index: index
                 .map(|index_config| -> Result<_, Status> {
@@ -774,7 +899,8 @@ impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
                 .and_then(|x|
                     // XXX: Invalid values silently converted to None
                     api::grpc::qdrant::Modifier::try_from(x).ok())
-                .map(Modifier::from),
+                .map(|modifier| modifier_from_grpc(modifier, bm25_params))
+                .transpose()?,
         })
     }
 }
@@ -784,6 +910,7 @@ impl From<Modifier> for api::grpc::qdrant::Modifier {
         match value {
             Modifier::None => api::grpc::qdrant::Modifier::None,
             Modifier::Idf => api::grpc::qdrant::Modifier::Idf,
+            Modifier::Bm25 { .. } => api::grpc::qdrant::Modifier::Bm25,
         }
     }
 }
@@ -804,6 +931,13 @@ impl From<SparseVectorParams> for api::grpc::qdrant::SparseVectorParams {
                     datatype: datatype.map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
                 }
             }),
+            bm25_params: match modifier {
+                Some(Modifier::Bm25 { k1, b }) => Some(api::grpc::qdrant::Bm25Params {
+                    k1: Some(k1.0),
+                    b: Some(b.0),
+                }),
+                Some(Modifier::None | Modifier::Idf) | None => None,
+            },
             modifier: modifier.map(|modifier| api::grpc::qdrant::Modifier::from(modifier) as i32),
         }
     }
@@ -825,6 +959,9 @@ fn grpc_to_segment_quantization_config(
         api::grpc::qdrant::quantization_config::Quantization::Binary(config) => {
             Ok(QuantizationConfig::Binary(config.try_into()?))
         }
+        api::grpc::qdrant::quantization_config::Quantization::Rabitq(config) => {
+            Ok(QuantizationConfig::Rabitq(config.into()))
+        }
     }
 }
 
@@ -1240,7 +1377,7 @@ impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
             negative,
             strategy: strategy.map(|s| s.try_into()).transpose()?,
             filter: filter.map(|f| f.try_into()).transpose()?,
-            params: params.map(|p| p.into()),
+            params: params.map(|p| p.try_into()).transpose()?,
             limit: limit as usize,
             offset: offset.map(|x| x as usize),
             with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
@@ -1340,6 +1477,9 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             quantization_config,
             on_disk,
             storage_type: _, // Ignore storage_type for gRPC (not supported yet)
+            vde_config,
+            diskann_config,
+            ivf_config,
             datatype,
             multivector_config,
         } = value;
@@ -1350,6 +1490,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
                 Distance::Euclid => api::grpc::qdrant::Distance::Euclid,
                 Distance::Dot => api::grpc::qdrant::Distance::Dot,
                 Distance::Manhattan => api::grpc::qdrant::Distance::Manhattan,
+                Distance::Hamming => api::grpc::qdrant::Distance::Hamming,
+                Distance::Jaccard => api::grpc::qdrant::Distance::Jaccard,
             }
             .into(),
             hnsw_config: hnsw_config.map(Into::into),
@@ -1357,6 +1499,9 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             on_disk,
             datatype: datatype.map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
             multivector_config: multivector_config.map(api::grpc::qdrant::MultiVectorConfig::from),
+            vde_config: vde_config.map(api::grpc::qdrant::VdeConfig::from),
+            diskann_config: diskann_config.map(api::grpc::qdrant::DiskAnnConfig::from),
+            ivf_config: ivf_config.map(api::grpc::qdrant::IvfConfig::from),
         }
     }
 }
@@ -1367,6 +1512,8 @@ impl From<Datatype> for api::grpc::qdrant::Datatype {
             Datatype::Float32 => api::grpc::qdrant::Datatype::Float32,
             Datatype::Uint8 => api::grpc::qdrant::Datatype::Uint8,
             Datatype::Float16 => api::grpc::qdrant::Datatype::Float16,
+            Datatype::Binary => api::grpc::qdrant::Datatype::Binary,
+            Datatype::BFloat16 => api::grpc::qdrant::Datatype::BFloat16,
         }
     }
 }
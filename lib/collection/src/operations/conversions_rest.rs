@@ -15,6 +15,7 @@ impl From<RecordInternal> for api::rest::Record {
             vector: vector.map(api::rest::VectorStructOutput::from),
             shard_key,
             order_value,
+            highlight: None,
         }
     }
 }
@@ -10,6 +10,7 @@ impl Generalizer for ScrollRequestInternal {
             with_payload,
             with_vector,
             order_by,
+            highlight,
         } = self;
 
         Self {
@@ -19,6 +20,7 @@ impl Generalizer for ScrollRequestInternal {
             with_payload: with_payload.clone(),
             with_vector: with_vector.clone(),
             order_by: order_by.clone(),
+            highlight: highlight.clone(),
         }
     }
 }
@@ -74,6 +74,7 @@ impl Generalizer for ScoringQuery {
             ScoringQuery::OrderBy(_) => self.clone(),
             ScoringQuery::Formula(_) => self.clone(),
             ScoringQuery::Sample(_) => self.clone(),
+            ScoringQuery::Text(_) => self.clone(),
             ScoringQuery::Mmr(mmr) => ScoringQuery::Mmr(mmr.remove_details()),
         }
     }
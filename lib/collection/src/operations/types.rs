@@ -21,19 +21,23 @@ use issues::IssueRecord;
 use merge::Merge;
 use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::Schema;
 use segment::common::anonymize::Anonymize;
 use segment::common::operation_error::{CancelledError, OperationError};
 use segment::data_types::groups::GroupId;
+use segment::data_types::highlight::HighlightParams;
 use segment::data_types::order_by::{OrderBy, OrderValue};
+use segment::data_types::text_query::TextQuery;
 use segment::data_types::vectors::{
     DEFAULT_VECTOR_NAME, DenseVector, NamedQuery, NamedVectorStruct, VectorRef,
     VectorStructInternal,
 };
 use segment::types::{
-    Distance, Filter, HnswConfig, MultiVectorConfig, Payload, PayloadIndexInfo, PayloadKeyType,
-    PointIdType, QuantizationConfig, SearchParams, SeqNumberType, ShardKey,
-    SparseVectorStorageType, StrictModeConfigOutput, VectorName, VectorNameBuf,
-    VectorStorageDatatype, WithPayloadInterface, WithVector,
+    DiskAnnConfig, Distance, Filter, HnswConfig, IvfConfig, MultiVectorConfig, Payload,
+    PayloadIndexInfo, PayloadKeyType, PointIdType, QuantizationConfig, SearchParams, SeqNumberType,
+    ShardKey, SparseVectorStorageType, StrictModeConfigOutput, VdeIndexConfig, VectorName,
+    VectorNameBuf, VectorStorageDatatype, WithPayloadInterface, WithVector,
 };
 use semver::Version;
 use serde;
@@ -53,7 +57,9 @@ use validator::{Validate, ValidationError, ValidationErrors};
 use super::{ClockTag, config_diff};
 use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
 use crate::operations::cluster_ops::ReshardingDirection;
-use crate::operations::config_diff::{HnswConfigDiff, QuantizationConfigDiff};
+use crate::operations::config_diff::{
+    DiskAnnConfigDiff, HnswConfigDiff, IvfConfigDiff, QuantizationConfigDiff, VdeConfigDiff,
+};
 use crate::operations::point_ops::{PointStructPersisted, VectorStructPersisted};
 use crate::operations::query_enum::QueryEnum;
 use crate::operations::universal_query::shard_query::{ScoringQuery, ShardQueryRequest};
@@ -457,6 +463,10 @@ pub struct ScrollRequestInternal {
 
     /// Order the records by a payload field.
     pub order_by: Option<OrderByInterface>,
+
+    /// Highlight the text matched by full-text conditions of the filter.
+    #[validate(nested)]
+    pub highlight: Option<HighlightParams>,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -465,6 +475,11 @@ pub enum ScrollOrder {
     ById,
     ByField(OrderBy),
     Random,
+    /// Rank by BM25 relevance to a text query
+    ByText {
+        query: TextQuery,
+        score_threshold: Option<ScoreType>,
+    },
 }
 
 /// Scroll request, used as a part of query request
@@ -509,6 +524,7 @@ impl Default for ScrollRequestInternal {
             with_payload: Some(Self::default_with_payload()),
             with_vector: Self::default_with_vector(),
             order_by: None,
+            highlight: None,
         }
     }
 }
@@ -529,6 +545,7 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.875, 0.140625, 0.897_6])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            highlight: None,
         },
         api::rest::Record {
             id: PointIdType::NumId(41),
@@ -536,6 +553,7 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.75, 0.640625, 0.8945])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            highlight: None,
         },
     ]
 }
@@ -1209,6 +1227,9 @@ impl From<OperationError> for CollectionError {
             },
             OperationError::MissingRangeIndexForOrderBy { .. } => Self::bad_input(format!("{err}")),
             OperationError::MissingMapIndexForFacet { .. } => Self::bad_input(format!("{err}")),
+            OperationError::MissingFullTextIndexForTextQuery { .. } => {
+                Self::bad_input(format!("{err}"))
+            }
             OperationError::VariableTypeError { .. } => Self::bad_input(format!("{err}")),
             OperationError::NonFiniteNumber { .. } => Self::bad_input(format!("{err}")),
             OperationError::RocksDbColumnFamilyNotFound { .. } => Self::ServiceError {
@@ -1453,6 +1474,12 @@ pub enum Datatype {
     Float32,
     Uint8,
     Float16,
+    /// Bit-packed binary vector, each dimension is a byte holding 8 bits.
+    /// Requires `Hamming` or `Jaccard` distance.
+    Binary,
+    /// Brain floating point, 16-bit with the exponent range of `float32`.
+    #[serde(rename = "bfloat16")]
+    BFloat16,
 }
 
 impl From<Datatype> for VectorStorageDatatype {
@@ -1461,6 +1488,8 @@ impl From<Datatype> for VectorStorageDatatype {
             Datatype::Float32 => VectorStorageDatatype::Float32,
             Datatype::Uint8 => VectorStorageDatatype::Uint8,
             Datatype::Float16 => VectorStorageDatatype::Float16,
+            Datatype::Binary => VectorStorageDatatype::Binary,
+            Datatype::BFloat16 => VectorStorageDatatype::BFloat16,
         }
     }
 }
@@ -1469,6 +1498,7 @@ impl From<Datatype> for VectorStorageDatatype {
 #[derive(
     Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq,
 )]
+#[validate(schema(function = "validate_vector_index_params"))]
 #[serde(rename_all = "snake_case")]
 #[anonymize(false)]
 pub struct VectorParams {
@@ -1498,10 +1528,29 @@ pub struct VectorParams {
     /// If specified, overrides `on_disk` setting.
     /// - "memory" - store in RAM (fast, high memory usage)
     /// - "mmap" - memory-mapped files (balanced)
-    /// - "vde" - Vector Data Engine with integrated HNSW index
+    /// - "vde" - Vector Data Engine with integrated HNSW index, requires a build with VDE support
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(custom(function = "validate_vector_storage_type"))]
     pub storage_type: Option<segment::types::VectorStorageType>,
 
+    /// Custom params for index managed by VDE (Vector Data Engine).
+    /// If specified, VDE index is built instead of HNSW. Requires a build with VDE support.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(custom(function = "validate_vde_index_config"))]
+    pub vde_config: Option<VdeIndexConfig>,
+
+    /// Custom params for on-disk DiskANN graph index.
+    /// If specified, DiskANN index is built instead of HNSW.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub diskann_config: Option<DiskAnnConfig>,
+
+    /// Custom params for IVF index.
+    /// If specified, IVF index is built instead of HNSW.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub ivf_config: Option<IvfConfig>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     /// Defines which datatype should be used to represent vectors in the storage.
     /// Choosing different datatypes allows to optimize memory usage and performance vs accuracy.
@@ -1525,6 +1574,95 @@ pub fn validate_nonzerou64_range_min_1_max_65536(
     validate_range_generic(value.get(), Some(1), Some(65536))
 }
 
+/// Validate the storage type is supported by this build.
+pub fn validate_vector_storage_type(
+    value: &segment::types::VectorStorageType,
+) -> Result<(), ValidationError> {
+    if *value == segment::types::VectorStorageType::Vde && !cfg!(feature = "vde") {
+        return Err(ValidationError::new("vde_not_supported")
+            .with_message(segment::types::VDE_NOT_SUPPORTED_MESSAGE.into()));
+    }
+    Ok(())
+}
+
+/// Validate the VDE index is supported by this build and has a valid tuning config.
+pub fn validate_vde_index_config(value: &VdeIndexConfig) -> Result<(), ValidationError> {
+    if !cfg!(feature = "vde") {
+        return Err(ValidationError::new("vde_not_supported")
+            .with_message(segment::types::VDE_NOT_SUPPORTED_MESSAGE.into()));
+    }
+    if let Some(config_json) = &value.config_json
+        && !matches!(serde_json::from_str(config_json), Ok(Value::Object(_)))
+    {
+        return Err(ValidationError::new("invalid_vde_config_json")
+            .with_message("VDE config_json must be a JSON object".into()));
+    }
+    Ok(())
+}
+
+/// Validate DiskANN and IVF indexes are only configured for single dense vectors.
+pub fn validate_vector_index_params(params: &VectorParams) -> Result<(), ValidationError> {
+    if params.diskann_config.is_some() && params.multivector_config.is_some() {
+        return Err(ValidationError::new("diskann_multivector_not_supported")
+            .with_message("DiskANN index does not support multi-vectors".into()));
+    }
+    if params.ivf_config.is_some() && params.multivector_config.is_some() {
+        return Err(ValidationError::new("ivf_multivector_not_supported")
+            .with_message("IVF index does not support multi-vectors".into()));
+    }
+    if let Some(token_pooling) = params
+        .multivector_config
+        .and_then(|config| config.token_pooling)
+    {
+        if token_pooling.target_count == 0 {
+            return Err(ValidationError::new("token_pooling_target_count")
+                .with_message("Token pooling target count must be positive".into()));
+        }
+        if params.distance.is_binary() {
+            return Err(ValidationError::new("token_pooling_binary_not_supported")
+                .with_message("Token pooling is not supported for binary vectors".into()));
+        }
+    }
+    let is_binary_datatype = params.datatype == Some(Datatype::Binary);
+    if is_binary_datatype != params.distance.is_binary() {
+        return Err(
+            ValidationError::new("binary_datatype_distance_mismatch").with_message(
+                "Binary datatype requires Hamming or Jaccard distance, and vice versa".into(),
+            ),
+        );
+    }
+    if is_binary_datatype
+        && (params.quantization_config.is_some()
+            || params.vde_config.is_some()
+            || params.diskann_config.is_some()
+            || params.ivf_config.is_some())
+    {
+        return Err(
+            ValidationError::new("binary_datatype_index_not_supported").with_message(
+                "Binary vectors only support HNSW index and do not support quantization".into(),
+            ),
+        );
+    }
+    if params.distance == Distance::Manhattan
+        && matches!(
+            params.quantization_config,
+            Some(QuantizationConfig::Rabitq(_))
+        )
+    {
+        return Err(ValidationError::new("rabitq_manhattan_not_supported")
+            .with_message("RaBitQ quantization does not support Manhattan distance".into()));
+    }
+    Ok(())
+}
+
+/// Validate the VDE index config diff, if it enables the VDE index.
+pub fn validate_vde_config_diff(value: &VdeConfigDiff) -> Result<(), ValidationError> {
+    match value {
+        VdeConfigDiff::Config(config) => validate_vde_index_config(config),
+        VdeConfigDiff::Disabled(_) => Ok(()),
+    }
+}
+
 /// Is considered empty if `None` or if diff has no field specified
 fn is_hnsw_diff_empty(hnsw_config: &Option<HnswConfigDiff>) -> bool {
     hnsw_config
@@ -1533,17 +1671,111 @@ fn is_hnsw_diff_empty(hnsw_config: &Option<HnswConfigDiff>) -> bool {
         .unwrap_or(true)
 }
 
+pub const DEFAULT_BM25_K1: f32 = 1.2;
+pub const DEFAULT_BM25_B: f32 = 0.75;
+
+const fn default_bm25_k1() -> f32 {
+    DEFAULT_BM25_K1
+}
+
+const fn default_bm25_b() -> f32 {
+    DEFAULT_BM25_B
+}
+
+/// Weight modification, which will be applied to sparse vectors at query time
+///
+/// Deserialized, and described in the API schema, as `ModifierInput`.
+#[derive(Debug, Deserialize, Serialize, Anonymize, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case", from = "ModifierInput")]
+pub enum Modifier {
+    #[default]
+    None,
+    Idf,
+    #[anonymize(false)]
+    Bm25 {
+        /// Term frequency saturation. Default: 1.2
+        k1: OrderedFloat<f32>,
+        /// Document length normalization, in range [0, 1]. Default: 0.75
+        b: OrderedFloat<f32>,
+    },
+}
+
+impl Modifier {
+    /// BM25 modifier with default parameters
+    pub fn bm25() -> Self {
+        Modifier::Bm25 {
+            k1: OrderedFloat(DEFAULT_BM25_K1),
+            b: OrderedFloat(DEFAULT_BM25_B),
+        }
+    }
+}
+
+impl JsonSchema for Modifier {
+    fn schema_name() -> String {
+        "Modifier".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        ModifierInput::json_schema(generator)
+    }
+}
+
 /// If used, include weight modification, which will be applied to sparse vectors at query time:
 /// None - no modification (default)
 /// Idf - inverse document frequency, based on statistics of the collection
-#[derive(
-    Debug, Hash, Deserialize, Serialize, JsonSchema, Anonymize, Clone, PartialEq, Eq, Default,
-)]
+/// Bm25 - inverse document frequency, and BM25 saturation of stored values as term frequencies,
+/// normalized by the average length of vectors in the collection
+///
+/// Bm25 is accepted either as `"bm25"`, with default parameters, or as `{"bm25": {"k1": .., "b": ..}}`.
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum ModifierInput {
+    Name(ModifierName),
+    Bm25 { bm25: Bm25Params },
+}
+
+#[derive(Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
-pub enum Modifier {
-    #[default]
+enum ModifierName {
     None,
     Idf,
+    Bm25,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct Bm25Params {
+    /// Term frequency saturation. Default: 1.2
+    #[serde(default = "default_bm25_k1")]
+    k1: f32,
+    /// Document length normalization, in range [0, 1]. Default: 0.75
+    #[serde(default = "default_bm25_b")]
+    b: f32,
+}
+
+impl From<ModifierInput> for Modifier {
+    fn from(input: ModifierInput) -> Self {
+        match input {
+            ModifierInput::Name(ModifierName::None) => Modifier::None,
+            ModifierInput::Name(ModifierName::Idf) => Modifier::Idf,
+            ModifierInput::Name(ModifierName::Bm25) => Modifier::bm25(),
+            ModifierInput::Bm25 {
+                bm25: Bm25Params { k1, b },
+            } => Modifier::Bm25 {
+                k1: OrderedFloat(k1),
+                b: OrderedFloat(b),
+            },
+        }
+    }
+}
+
+pub fn validate_sparse_modifier(modifier: &Modifier) -> Result<(), ValidationError> {
+    match modifier {
+        Modifier::None | Modifier::Idf => Ok(()),
+        Modifier::Bm25 { k1, b } => {
+            validate_range_generic(k1.0, Some(0.0), None)?;
+            validate_range_generic(b.0, Some(0.0), Some(1.0))
+        }
+    }
 }
 
 /// Params of single sparse vector data storage
@@ -1559,6 +1791,7 @@ pub struct SparseVectorParams {
     /// Configures addition value modifications for sparse vectors.
     /// Default: none
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(custom(function = "validate_sparse_modifier"))]
     pub modifier: Option<Modifier>,
 }
 
@@ -1627,6 +1860,7 @@ impl SparseIndexParams {
 /// }
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Anonymize, Clone, PartialEq, Hash, Eq)]
 #[serde(rename_all = "snake_case", untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum VectorsConfig {
     Single(VectorParams),
     Multi(BTreeMap<VectorNameBuf, VectorParams>),
@@ -1842,6 +2076,9 @@ impl From<&VectorParams> for VectorParamsBase {
             quantization_config: _,
             on_disk: _,
             storage_type: _,
+            vde_config: _,
+            diskann_config: _,
+            ivf_config: _,
             datatype: _,
             multivector_config: _,
         } = params;
@@ -1887,6 +2124,21 @@ pub struct VectorParamsDiff {
     /// If true, vectors are served from disk, improving RAM usage at the cost of latency
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
+    /// Update params for VDE index. If `Disabled` - HNSW index is used again.
+    /// If none - it is left unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(custom(function = "validate_vde_config_diff"))]
+    pub vde_config: Option<VdeConfigDiff>,
+    /// Update params for DiskANN index. If `Disabled` - HNSW index is used again.
+    /// If none - it is left unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub diskann_config: Option<DiskAnnConfigDiff>,
+    /// Update params for IVF index. If `Disabled` - HNSW index is used again.
+    /// If none - it is left unchanged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub ivf_config: Option<IvfConfigDiff>,
 }
 
 /// Vector update params for multiple vectors
@@ -3,7 +3,9 @@ use api::rest::LookupLocation;
 use common::types::ScoreType;
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
+use segment::data_types::highlight::HighlightParams;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::text_query::TextQuery;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal, VectorRef};
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::json_path::JsonPath;
@@ -42,6 +44,8 @@ pub struct CollectionQueryRequest {
     pub with_vector: WithVector,
     pub with_payload: WithPayloadInterface,
     pub lookup_from: Option<LookupLocation>,
+    /// Highlight the text matched by full-text conditions of the filter and by the text query
+    pub highlight: Option<HighlightParams>,
 }
 
 impl CollectionQueryRequest {
@@ -100,6 +104,9 @@ pub enum Query {
 
     /// Sample points
     Sample(SampleInternal),
+
+    /// Rank by BM25 relevance to a text query
+    Text(TextQuery),
 }
 
 impl Query {
@@ -124,6 +131,7 @@ impl Query {
             Query::OrderBy(order_by) => ScoringQuery::OrderBy(order_by),
             Query::Formula(formula) => ScoringQuery::Formula(ParsedFormula::try_from(formula)?),
             Query::Sample(sample) => ScoringQuery::Sample(sample),
+            Query::Text(text_query) => ScoringQuery::Text(text_query),
         };
 
         Ok(scoring_query)
@@ -136,7 +144,11 @@ impl Query {
                 .into_iter()
                 .copied()
                 .collect(),
-            Self::Fusion(_) | Self::OrderBy(_) | Self::Formula(_) | Self::Sample(_) => Vec::new(),
+            Self::Fusion(_)
+            | Self::OrderBy(_)
+            | Self::Formula(_)
+            | Self::Sample(_)
+            | Self::Text(_) => Vec::new(),
         }
     }
 }
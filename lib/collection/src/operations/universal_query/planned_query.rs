@@ -115,7 +115,8 @@ impl PlannedQuery {
             | Some(ScoringQuery::Fusion(_))
             | Some(ScoringQuery::OrderBy(_))
             | Some(ScoringQuery::Formula(_))
-            | Some(ScoringQuery::Sample(_)) => with_vector,
+            | Some(ScoringQuery::Sample(_))
+            | Some(ScoringQuery::Text(_)) => with_vector,
             Some(ScoringQuery::Mmr(mmr)) => with_vector.merge(&WithVector::from(mmr.using.clone())),
         };
 
@@ -221,7 +222,8 @@ impl PlannedQuery {
             rescore @ (ScoringQuery::Vector(_)
             | ScoringQuery::OrderBy(_)
             | ScoringQuery::Formula(_)
-            | ScoringQuery::Sample(_)) => Some(RescoreParams {
+            | ScoringQuery::Sample(_)
+            | ScoringQuery::Text(_)) => Some(RescoreParams {
                 rescore,
                 limit,
                 score_threshold: score_threshold.map(OrderedFloat),
@@ -359,6 +361,23 @@ fn leaf_source_from_scoring_query(
                 "cannot apply Formula without prefetches".to_string(),
             ));
         }
+        Some(ScoringQuery::Text(query)) => {
+            let scroll = QueryScrollRequestInternal {
+                scroll_order: ScrollOrder::ByText {
+                    query,
+                    score_threshold,
+                },
+                filter,
+                with_vector: WithVector::from(false),
+                with_payload: WithPayloadInterface::from(false),
+                limit,
+            };
+
+            let idx = scrolls.len();
+            scrolls.push(scroll);
+
+            Source::ScrollsIdx(idx)
+        }
         Some(ScoringQuery::Sample(SampleInternal::Random)) => {
             let scroll = QueryScrollRequestInternal {
                 scroll_order: ScrollOrder::Random,
@@ -432,6 +451,7 @@ impl TryFrom<Vec<ShardQueryRequest>> for PlannedQuery {
 mod tests {
     use ahash::AHashSet;
     use segment::common::reciprocal_rank_fusion::DEFAULT_RRF_K;
+    use segment::data_types::text_query::TextQuery;
     use segment::data_types::vectors::{MultiDenseVectorInternal, NamedQuery, VectorInternal};
     use segment::json_path::JsonPath;
     use segment::types::{
@@ -720,6 +740,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_text_and_dense_fusion() {
+        let dummy_vector = vec![1.0, 2.0, 3.0];
+        let text_query = TextQuery {
+            key: "description".try_into().unwrap(),
+            query: "red apples".to_string(),
+        };
+        let filter_outer = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            "country".try_into().unwrap(),
+            "Germany".to_string().into(),
+        )));
+
+        let request = ShardQueryRequest {
+            prefetches: vec![
+                ShardPrefetch {
+                    prefetches: Vec::new(),
+                    query: Some(ScoringQuery::Vector(QueryEnum::Nearest(
+                        NamedQuery::new_from_vector(
+                            VectorInternal::Dense(dummy_vector.clone()),
+                            "dense",
+                        ),
+                    ))),
+                    limit: 100,
+                    params: None,
+                    filter: None,
+                    score_threshold: None,
+                },
+                ShardPrefetch {
+                    prefetches: Vec::new(),
+                    query: Some(ScoringQuery::Text(text_query.clone())),
+                    limit: 100,
+                    params: None,
+                    filter: None,
+                    score_threshold: Some(OrderedFloat(1.5)),
+                },
+            ],
+            query: Some(ScoringQuery::Fusion(FusionInternal::RrfK(DEFAULT_RRF_K))),
+            filter: Some(filter_outer.clone()),
+            score_threshold: None,
+            limit: 50,
+            offset: 0,
+            params: None,
+            with_payload: WithPayloadInterface::Bool(false),
+            with_vector: WithVector::Bool(false),
+        };
+
+        let planned_query = PlannedQuery::try_from(vec![request]).unwrap();
+
+        assert_eq!(planned_query.searches.len(), 1);
+        assert_eq!(
+            planned_query.scrolls,
+            vec![QueryScrollRequestInternal {
+                scroll_order: ScrollOrder::ByText {
+                    query: text_query,
+                    score_threshold: Some(1.5),
+                },
+                filter: Some(filter_outer),
+                with_vector: WithVector::Bool(false),
+                with_payload: WithPayloadInterface::Bool(false),
+                limit: 100,
+            }]
+        );
+
+        assert_eq!(
+            planned_query.root_plans,
+            vec![RootPlan {
+                with_payload: WithPayloadInterface::Bool(false),
+                with_vector: WithVector::Bool(false),
+                merge_plan: MergePlan {
+                    sources: vec![Source::SearchesIdx(0), Source::ScrollsIdx(0)],
+                    rescore_params: None,
+                },
+            }]
+        );
+    }
+
     #[test]
     fn test_try_from_rrf_without_source() {
         let request = ShardQueryRequest {
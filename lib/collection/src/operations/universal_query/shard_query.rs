@@ -6,6 +6,7 @@ use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use segment::common::reciprocal_rank_fusion::DEFAULT_RRF_K;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::text_query::TextQuery;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
 use segment::index::query_optimization::rescore_formula::parsed_formula::{
     DecayKind, ParsedFormula,
@@ -113,6 +114,9 @@ pub enum ScoringQuery {
     ///   1. Performs search all the way down to segments.
     ///   2. MMR gets calculated once results reach collection level.
     Mmr(MmrInternal),
+
+    /// Rank by BM25 relevance to a text query, using the full-text index of a payload field
+    Text(TextQuery),
 }
 
 impl ScoringQuery {
@@ -129,7 +133,11 @@ impl ScoringQuery {
             },
             // MMR is a nearest neighbors search before computing diversity at collection level
             Self::Mmr(_) => false,
-            Self::Vector(_) | Self::OrderBy(_) | Self::Formula(_) | Self::Sample(_) => false,
+            Self::Vector(_)
+            | Self::OrderBy(_)
+            | Self::Formula(_)
+            | Self::Sample(_)
+            | Self::Text(_) => false,
         }
     }
 
@@ -167,6 +175,8 @@ impl ScoringQuery {
                 // Euclidean scores can be negated within the formula
                 ScoringQuery::Formula(_formula) => Some(Order::LargeBetter),
                 ScoringQuery::OrderBy(order_by) => Some(Order::from(order_by.direction())),
+                // More relevant documents have higher BM25 scores
+                ScoringQuery::Text(_) => Some(Order::LargeBetter),
                 // Random sample does not require ordering
                 ScoringQuery::Sample(SampleInternal::Random) => None,
                 // MMR cannot be reordered
@@ -261,7 +271,7 @@ impl TryFrom<grpc::QueryShardPoints> for ShardQueryRequest {
             score_threshold: score_threshold.map(OrderedFloat),
             limit: limit as usize,
             offset: offset as usize,
-            params: params.map(SearchParams::from),
+            params: params.map(SearchParams::try_from).transpose()?,
             with_vector: with_vectors
                 .map(WithVector::from)
                 .unwrap_or(WithVector::Bool(false)),
@@ -298,7 +308,7 @@ impl TryFrom<grpc::query_shard_points::Prefetch> for ShardPrefetch {
                 .map(|query| ScoringQuery::try_from_grpc_query(query, using))
                 .transpose()?,
             limit: limit as usize,
-            params: params.map(SearchParams::from),
+            params: params.map(SearchParams::try_from).transpose()?,
             filter: filter.map(Filter::try_from).transpose()?,
             score_threshold: score_threshold.map(OrderedFloat),
         };
@@ -642,6 +652,9 @@ impl ScoringQuery {
             grpc::query_shard_points::query::Score::Sample(sample) => {
                 ScoringQuery::Sample(SampleInternal::try_from(sample)?)
             }
+            grpc::query_shard_points::query::Score::Text(text_query) => {
+                ScoringQuery::Text(TextQuery::try_from(text_query)?)
+            }
             grpc::query_shard_points::query::Score::Formula(formula) => ScoringQuery::Formula(
                 ParsedFormula::try_from(FormulaInternal::try_from(formula)?).map_err(|e| {
                     Status::invalid_argument(format!("failed to parse formula: {e}"))
@@ -708,6 +721,9 @@ impl From<ScoringQuery> for grpc::query_shard_points::Query {
             ScoringQuery::Sample(sample) => Self {
                 score: Some(Score::Sample(api::grpc::qdrant::Sample::from(sample) as i32)),
             },
+            ScoringQuery::Text(text_query) => Self {
+                score: Some(Score::Text(grpc::TextQuery::from(text_query))),
+            },
             ScoringQuery::Mmr(MmrInternal {
                 vector,
                 using: _,
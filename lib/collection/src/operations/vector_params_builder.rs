@@ -1,6 +1,9 @@
 use std::num::NonZeroU64;
 
-use segment::types::{Distance, MultiVectorConfig, QuantizationConfig};
+use segment::types::{
+    DiskAnnConfig, Distance, IvfConfig, MultiVectorConfig, QuantizationConfig, VdeIndexConfig,
+    VectorStorageType,
+};
 
 use crate::operations::config_diff::HnswConfigDiff;
 use crate::operations::types::{Datatype, VectorParams};
@@ -19,6 +22,9 @@ impl VectorParamsBuilder {
                 quantization_config: None,
                 on_disk: None,
                 storage_type: None,
+                vde_config: None,
+                diskann_config: None,
+                ivf_config: None,
                 datatype: None,
                 multivector_config: None,
             },
@@ -40,6 +46,26 @@ impl VectorParamsBuilder {
         self
     }
 
+    pub fn with_storage_type(mut self, storage_type: VectorStorageType) -> Self {
+        self.vector_params.storage_type = Some(storage_type);
+        self
+    }
+
+    pub fn with_vde_config(mut self, vde_config: VdeIndexConfig) -> Self {
+        self.vector_params.vde_config = Some(vde_config);
+        self
+    }
+
+    pub fn with_diskann_config(mut self, diskann_config: DiskAnnConfig) -> Self {
+        self.vector_params.diskann_config = Some(diskann_config);
+        self
+    }
+
+    pub fn with_ivf_config(mut self, ivf_config: IvfConfig) -> Self {
+        self.vector_params.ivf_config = Some(ivf_config);
+        self
+    }
+
     pub fn with_datatype(mut self, datatype: Datatype) -> Self {
         self.vector_params.datatype = Some(datatype);
         self
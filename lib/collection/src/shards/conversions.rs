@@ -1,5 +1,5 @@
 use api::conversions::json::payload_to_proto;
-use api::grpc::conversions::convert_shard_key_from_grpc_opt;
+use api::grpc::conversions::{convert_highlight_from_grpc, convert_shard_key_from_grpc_opt};
 use api::grpc::qdrant::points_selector::PointsSelectorOneOf;
 use api::grpc::qdrant::{
     ClearPayloadPoints, ClearPayloadPointsInternal, CreateFieldIndexCollection,
@@ -446,6 +446,7 @@ pub fn try_scored_point_from_grpc(
         vectors,
         shard_key,
         order_value,
+        highlight,
     } = point;
     let id = id
         .ok_or_else(|| Status::invalid_argument("scored point does not have an ID"))?
@@ -471,5 +472,6 @@ pub fn try_scored_point_from_grpc(
         vector,
         shard_key: convert_shard_key_from_grpc_opt(shard_key),
         order_value: order_value.map(TryFrom::try_from).transpose()?,
+        highlight: convert_highlight_from_grpc(highlight),
     })
 }
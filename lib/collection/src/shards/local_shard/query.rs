@@ -360,6 +360,35 @@ impl LocalShard {
                 self.rescore_with_formula(formula, sources, limit, timeout, hw_counter_acc)
                     .await
             }
+            ScoringQuery::Text(query) => {
+                // create single scroll request for rescoring query
+                let filter = filter_with_sources_ids(sources.into_iter());
+
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload: false.into(),
+                    with_vector: false.into(),
+                    scroll_order: ScrollOrder::ByText {
+                        query,
+                        score_threshold: score_threshold.map(OrderedFloat::into_inner),
+                    },
+                };
+
+                self.query_scroll_batch(
+                    Arc::new(vec![scroll_request]),
+                    search_runtime_handle,
+                    timeout,
+                    hw_counter_acc.clone(),
+                )
+                .await?
+                .pop()
+                .ok_or_else(|| {
+                    CollectionError::service_error(
+                        "Rescoring with text query didn't return expected batch of results",
+                    )
+                })
+            }
             ScoringQuery::Sample(sample) => match sample {
                 SampleInternal::Random => {
                     // create single scroll request for rescoring query
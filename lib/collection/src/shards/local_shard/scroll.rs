@@ -4,12 +4,14 @@ use std::time::{Duration, Instant};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::ScoreType;
 use futures::future::try_join_all;
 use itertools::Itertools as _;
 use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use segment::data_types::order_by::{Direction, OrderBy};
+use segment::data_types::text_query::{TextQuery, TextQueryStatistics};
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
 };
@@ -80,6 +82,25 @@ impl LocalShard {
         let offset_id = None;
 
         let record_results = match scroll_order {
+            ScrollOrder::ByText {
+                query,
+                score_threshold,
+            } => {
+                // Text relevance carries real scores, no need to convert records
+                return self
+                    .scroll_by_text(
+                        limit,
+                        with_payload,
+                        with_vector,
+                        filter.as_ref(),
+                        search_runtime_handle,
+                        query,
+                        *score_threshold,
+                        timeout,
+                        hw_measurement_acc,
+                    )
+                    .await;
+            }
             ScrollOrder::ById => {
                 self.scroll_by_id(
                     offset_id,
@@ -130,6 +151,7 @@ impl LocalShard {
                 vector: record.vector,
                 shard_key: record.shard_key,
                 order_value: record.order_value,
+                highlight: None,
             })
             .collect();
 
@@ -321,6 +343,148 @@ impl LocalShard {
         Ok(ordered_records)
     }
 
+    /// Rank points by BM25 relevance of the text query.
+    ///
+    /// Corpus statistics are collected over all segments of the shard first,
+    /// so that the scores of different segments are comparable.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn scroll_by_text(
+        &self,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        search_runtime_handle: &Handle,
+        query: &TextQuery,
+        score_threshold: Option<ScoreType>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let start = Instant::now();
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+        let stopping_guard = StoppingGuard::new();
+        let segments = self.segments.clone();
+
+        let update_operation_lock = self.update_operation_lock.read().await;
+        let (non_appendable, appendable) = segments.read().split_segments();
+        let all_segments = non_appendable.into_iter().chain(appendable).collect_vec();
+
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+
+        let statistics = {
+            let all_segments = all_segments.clone();
+            let query = query.clone();
+            let hw_counter = hw_counter.fork();
+            let collect_statistics = search_runtime_handle.spawn_blocking(move || {
+                let mut statistics = TextQueryStatistics::default();
+                for segment in all_segments {
+                    segment.get().read().fill_text_query_statistics(
+                        &query,
+                        &[],
+                        &mut statistics,
+                        &hw_counter,
+                    )?;
+                }
+                Ok::<_, CollectionError>(statistics)
+            });
+            tokio::time::timeout(timeout, collect_statistics)
+                .await
+                .map_err(|_: Elapsed| {
+                    CollectionError::timeout(timeout.as_secs() as usize, "scroll_by_text")
+                })???
+        };
+        let statistics = Arc::new(statistics);
+
+        let read_text_scored = |segment: LockedSegment, hw_counter: &HardwareCounterCell| {
+            let is_stopped = stopping_guard.get_is_stopped();
+            let filter = filter.cloned();
+            let query = query.clone();
+            let statistics = statistics.clone();
+
+            let hw_counter = hw_counter.fork();
+            search_runtime_handle.spawn_blocking(move || {
+                segment.get().read().read_text_scored_filtered(
+                    Some(limit),
+                    filter.as_ref(),
+                    &query,
+                    &statistics,
+                    &is_stopped,
+                    &hw_counter,
+                )
+            })
+        };
+
+        // update timeout
+        let timeout = timeout.saturating_sub(start.elapsed());
+        let all_reads = tokio::time::timeout(
+            timeout,
+            try_join_all(
+                all_segments
+                    .into_iter()
+                    .map(|segment| read_text_scored(segment, &hw_counter)),
+            ),
+        )
+        .await
+        .map_err(|_: Elapsed| {
+            CollectionError::timeout(timeout.as_secs() as usize, "scroll_by_text")
+        })??;
+
+        let all_reads = all_reads.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        // The same point may be present in multiple segments, keep the best score only
+        let mut seen_ids = HashSet::with_capacity(limit);
+        let (scores, point_ids): (Vec<_>, Vec<_>) = all_reads
+            .into_iter()
+            .kmerge_by(|(score_a, _), (score_b, _)| score_a >= score_b)
+            .take_while(|(score, _)| score_threshold.is_none_or(|threshold| *score >= threshold))
+            .filter(|(_, point_id)| seen_ids.insert(*point_id))
+            .take(limit)
+            .unzip();
+
+        let with_payload = WithPayload::from(with_payload_interface);
+
+        // update timeout
+        let timeout = timeout.saturating_sub(start.elapsed());
+
+        // Fetch with the requested vector and payload
+        let mut records_map = tokio::time::timeout(
+            timeout,
+            SegmentsSearcher::retrieve(
+                segments,
+                &point_ids,
+                &with_payload,
+                with_vector,
+                search_runtime_handle,
+                hw_measurement_acc,
+            ),
+        )
+        .await
+        .map_err(|_: Elapsed| CollectionError::timeout(timeout.as_secs() as usize, "retrieve"))??;
+
+        drop(update_operation_lock);
+
+        let scored_points = point_ids
+            .iter()
+            .zip(scores)
+            // Use remove to avoid cloning, we take each point ID only once
+            .filter_map(|(point_id, score)| {
+                let record = records_map.remove(point_id)?;
+                Some(ScoredPoint {
+                    id: record.id,
+                    version: 0,
+                    score,
+                    payload: record.payload,
+                    vector: record.vector,
+                    shard_key: record.shard_key,
+                    order_value: None,
+                    highlight: None,
+                })
+            })
+            .collect();
+
+        Ok(scored_points)
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn scroll_randomly(
         &self,
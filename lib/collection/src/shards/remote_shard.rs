@@ -965,6 +965,7 @@ impl ShardOperation for RemoteShard {
             shard_key_selector: None,
             order_by: order_by.map(|o| o.clone().into()),
             timeout: processed_timeout.map(|t| t.as_secs()),
+            highlight: None,
         };
         let scroll_request = &ScrollPointsInternal {
             scroll_points: Some(scroll_points),
@@ -547,6 +547,7 @@ mod test {
             vector: None,
             shard_key: None,
             order_value: None,
+            highlight: None,
         }
     }
 
@@ -0,0 +1,111 @@
+use api::rest::schema::{Vector, VectorInput, VectorStruct};
+use segment::data_types::vectors::DenseVector;
+use segment::types::{
+    Distance, QuantizationConfig, ScalarQuantization, ScalarQuantizationConfig, VdeIndexConfig,
+};
+use validator::Validate;
+
+use crate::operations::types::{Datatype, VectorParams};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+
+fn binary_vector_params(distance: Distance) -> VectorParamsBuilder {
+    VectorParamsBuilder::new(32, distance)
+}
+
+#[test]
+fn validate_binary_vector_params() {
+    for distance in [Distance::Hamming, Distance::Jaccard] {
+        assert!(
+            binary_vector_params(distance)
+                .with_datatype(Datatype::Binary)
+                .build()
+                .validate()
+                .is_ok()
+        );
+        assert!(binary_vector_params(distance).build().validate().is_err());
+        assert!(
+            binary_vector_params(distance)
+                .with_datatype(Datatype::Uint8)
+                .build()
+                .validate()
+                .is_err()
+        );
+    }
+
+    let err = binary_vector_params(Distance::Cosine)
+        .with_datatype(Datatype::Binary)
+        .build()
+        .validate()
+        .unwrap_err();
+    assert!(err.to_string().contains("requires Hamming or Jaccard"));
+
+    let quantized = binary_vector_params(Distance::Hamming)
+        .with_datatype(Datatype::Binary)
+        .with_quantization_config(QuantizationConfig::Scalar(ScalarQuantization {
+            scalar: ScalarQuantizationConfig {
+                r#type: Default::default(),
+                quantile: None,
+                always_ram: None,
+            },
+        }))
+        .build();
+    assert!(quantized.validate().is_err());
+
+    let vde = binary_vector_params(Distance::Jaccard)
+        .with_datatype(Datatype::Binary)
+        .with_vde_config(VdeIndexConfig::default())
+        .build();
+    assert!(vde.validate().is_err());
+}
+
+#[test]
+fn parse_base64_vectors() {
+    // 0x00, 0x0F, 0xFF
+    let encoded = r#""AA//""#;
+    let expected: DenseVector = vec![0.0, 15.0, 255.0];
+
+    let vector: VectorStruct = serde_json::from_str(encoded).unwrap();
+    assert_eq!(vector, VectorStruct::Single(expected.clone()));
+
+    let vector: VectorStruct = serde_json::from_str(r#"{"fingerprint": "AA//"}"#).unwrap();
+    let VectorStruct::Named(vectors) = vector else {
+        panic!("expected named vectors");
+    };
+    assert_eq!(vectors["fingerprint"], Vector::Dense(expected.clone()));
+
+    // Arrays of numbers are still accepted
+    let vector: VectorStruct = serde_json::from_str("[0, 15, 255]").unwrap();
+    assert_eq!(vector, VectorStruct::Single(expected.clone()));
+
+    let input: VectorInput = serde_json::from_str(encoded).unwrap();
+    assert!(matches!(input, VectorInput::DenseVector(vector) if vector == expected));
+
+    // UUIDs are point ids, not base64 vectors
+    let input: VectorInput =
+        serde_json::from_str(r#""5c56c793-69f3-4fbf-87e6-c4bf54c28c26""#).unwrap();
+    assert!(matches!(input, VectorInput::Id(_)));
+
+    // Strings that are neither ids nor base64 are reported as such
+    let err = serde_json::from_str::<VectorInput>(r#""5c56c793-69f3-4fbf-87e6-c4bf54c28c2""#)
+        .unwrap_err();
+    assert!(err.to_string().contains("expected a point id"), "{err}");
+
+    assert!(serde_json::from_str::<VectorStruct>(r#""not base64!""#).is_err());
+}
+
+#[test]
+fn convert_binary_vectors_grpc() {
+    let grpc_vector = api::grpc::qdrant::DenseVector {
+        data: vec![],
+        binary: Some(vec![0, 15, 255]),
+    };
+    assert_eq!(DenseVector::from(grpc_vector), vec![0.0, 15.0, 255.0]);
+
+    let params = binary_vector_params(Distance::Jaccard)
+        .with_datatype(Datatype::Binary)
+        .build();
+    let grpc_params = api::grpc::qdrant::VectorParams::from(params);
+    let converted = VectorParams::try_from(grpc_params).unwrap();
+    assert_eq!(converted.distance, Distance::Jaccard);
+    assert_eq!(converted.datatype, Some(Datatype::Binary));
+}
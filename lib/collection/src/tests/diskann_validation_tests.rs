@@ -0,0 +1,102 @@
+use segment::types::{CompressionRatio, DiskAnnConfig, Distance, MultiVectorConfig};
+use validator::Validate;
+
+use crate::operations::config_diff::DiskAnnConfigDiff;
+use crate::operations::types::{VectorParams, VectorParamsDiff};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+
+fn diskann_vector_params(config: DiskAnnConfig) -> VectorParams {
+    VectorParamsBuilder::new(4, Distance::Cosine)
+        .with_diskann_config(config)
+        .build()
+}
+
+#[test]
+fn validate_diskann_config() {
+    assert!(
+        diskann_vector_params(DiskAnnConfig::default())
+            .validate()
+            .is_ok()
+    );
+
+    let small_degree = diskann_vector_params(DiskAnnConfig {
+        max_degree: 2,
+        ..Default::default()
+    });
+    assert!(small_degree.validate().is_err());
+
+    let wide_beam = diskann_vector_params(DiskAnnConfig {
+        beam_width: 64,
+        ..Default::default()
+    });
+    assert!(wide_beam.validate().is_err());
+
+    let multivector_params = VectorParamsBuilder::new(4, Distance::Cosine)
+        .with_diskann_config(DiskAnnConfig::default())
+        .with_multivector_config(MultiVectorConfig::default())
+        .build();
+    let err = multivector_params.validate().unwrap_err();
+    assert!(err.to_string().contains("does not support multi-vectors"));
+}
+
+#[test]
+fn parse_diskann_config() {
+    let params: VectorParams = serde_json::from_str(
+        r#"{
+            "size": 4,
+            "distance": "Cosine",
+            "diskann_config": {
+                "max_degree": 32,
+                "pq_compression": "x8"
+            }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        params.diskann_config,
+        Some(DiskAnnConfig {
+            max_degree: 32,
+            pq_compression: CompressionRatio::X8,
+            ..Default::default()
+        }),
+    );
+
+    let diff: VectorParamsDiff = serde_json::from_str(r#"{"diskann_config": "Disabled"}"#).unwrap();
+    assert_eq!(diff.diskann_config, Some(DiskAnnConfigDiff::new_disabled()));
+
+    let diff: VectorParamsDiff = serde_json::from_str(r#"{"diskann_config": {}}"#).unwrap();
+    assert_eq!(
+        diff.diskann_config,
+        Some(DiskAnnConfigDiff::Config(DiskAnnConfig::default())),
+    );
+}
+
+#[test]
+fn convert_diskann_config_grpc() {
+    let params = diskann_vector_params(DiskAnnConfig {
+        search_list_size: 200,
+        full_scan_threshold: Some(1000),
+        on_disk: Some(false),
+        ..Default::default()
+    });
+    let grpc_params = api::grpc::qdrant::VectorParams::from(params.clone());
+    let converted = VectorParams::try_from(grpc_params).unwrap();
+    assert_eq!(converted.diskann_config, params.diskann_config);
+
+    let grpc_params = api::grpc::qdrant::VectorParams::from(diskann_vector_params(DiskAnnConfig {
+        max_degree: 1,
+        ..Default::default()
+    }));
+    assert!(VectorParams::try_from(grpc_params).is_err());
+
+    let grpc_diff = api::grpc::qdrant::VectorParamsDiff {
+        diskann_config: Some(api::grpc::qdrant::DiskAnnConfigDiff {
+            config: Some(api::grpc::qdrant::disk_ann_config_diff::Config::Disabled(
+                api::grpc::qdrant::Disabled {},
+            )),
+        }),
+        ..Default::default()
+    };
+    let diff = VectorParamsDiff::try_from(grpc_diff).unwrap();
+    assert_eq!(diff.diskann_config, Some(DiskAnnConfigDiff::new_disabled()));
+}
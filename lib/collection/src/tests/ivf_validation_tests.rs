@@ -0,0 +1,102 @@
+use segment::types::{CompressionRatio, Distance, IvfConfig, IvfResiduals, MultiVectorConfig};
+use validator::Validate;
+
+use crate::operations::config_diff::IvfConfigDiff;
+use crate::operations::types::{VectorParams, VectorParamsDiff};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+
+fn ivf_vector_params(config: IvfConfig) -> VectorParams {
+    VectorParamsBuilder::new(4, Distance::Cosine)
+        .with_ivf_config(config)
+        .build()
+}
+
+#[test]
+fn validate_ivf_config() {
+    assert!(ivf_vector_params(IvfConfig::default()).validate().is_ok());
+
+    let no_lists = ivf_vector_params(IvfConfig {
+        nlist: Some(0),
+        ..Default::default()
+    });
+    assert!(no_lists.validate().is_err());
+
+    let no_probes = ivf_vector_params(IvfConfig {
+        nprobe: 0,
+        ..Default::default()
+    });
+    assert!(no_probes.validate().is_err());
+
+    let multivector_params = VectorParamsBuilder::new(4, Distance::Cosine)
+        .with_ivf_config(IvfConfig::default())
+        .with_multivector_config(MultiVectorConfig::default())
+        .build();
+    let err = multivector_params.validate().unwrap_err();
+    assert!(err.to_string().contains("does not support multi-vectors"));
+}
+
+#[test]
+fn parse_ivf_config() {
+    let params: VectorParams = serde_json::from_str(
+        r#"{
+            "size": 4,
+            "distance": "Cosine",
+            "ivf_config": {
+                "nlist": 1024,
+                "residuals": "product",
+                "pq_compression": "x8"
+            }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        params.ivf_config,
+        Some(IvfConfig {
+            nlist: Some(1024),
+            residuals: Some(IvfResiduals::Product),
+            pq_compression: CompressionRatio::X8,
+            ..Default::default()
+        }),
+    );
+
+    let diff: VectorParamsDiff = serde_json::from_str(r#"{"ivf_config": "Disabled"}"#).unwrap();
+    assert_eq!(diff.ivf_config, Some(IvfConfigDiff::new_disabled()));
+
+    let diff: VectorParamsDiff = serde_json::from_str(r#"{"ivf_config": {}}"#).unwrap();
+    assert_eq!(
+        diff.ivf_config,
+        Some(IvfConfigDiff::Config(IvfConfig::default())),
+    );
+}
+
+#[test]
+fn convert_ivf_config_grpc() {
+    let params = ivf_vector_params(IvfConfig {
+        nlist: Some(256),
+        nprobe: 32,
+        residuals: Some(IvfResiduals::Scalar),
+        full_scan_threshold: Some(1000),
+        on_disk: Some(true),
+        ..Default::default()
+    });
+    let grpc_params = api::grpc::qdrant::VectorParams::from(params.clone());
+    let converted = VectorParams::try_from(grpc_params).unwrap();
+    assert_eq!(converted.ivf_config, params.ivf_config);
+
+    let grpc_params = api::grpc::qdrant::VectorParams::from(ivf_vector_params(IvfConfig {
+        nprobe: 0,
+        ..Default::default()
+    }));
+    assert!(VectorParams::try_from(grpc_params).is_err());
+
+    let grpc_diff = api::grpc::qdrant::VectorParamsDiff {
+        ivf_config: Some(api::grpc::qdrant::IvfConfigDiff {
+            config: Some(api::grpc::qdrant::ivf_config_diff::Config::Disabled(
+                api::grpc::qdrant::Disabled {},
+            )),
+        }),
+        ..Default::default()
+    };
+    let diff = VectorParamsDiff::try_from(grpc_diff).unwrap();
+    assert_eq!(diff.ivf_config, Some(IvfConfigDiff::new_disabled()));
+}
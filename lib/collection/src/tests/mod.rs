@@ -1,13 +1,18 @@
+mod binary_vectors_tests;
+mod diskann_validation_tests;
 mod fix_payload_indices;
 pub mod fixtures;
 mod hw_metrics;
+mod ivf_validation_tests;
 mod payload;
 mod points_dedup;
 mod query_prefetch_offset_limit;
+mod rabitq_validation_tests;
 mod sha_256_test;
 mod shard_query;
 mod snapshot_test;
 mod sparse_vectors_validation_tests;
+mod vde_validation_tests;
 mod wal_recovery_test;
 
 use std::sync::Arc;
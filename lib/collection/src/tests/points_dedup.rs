@@ -166,6 +166,7 @@ async fn test_scroll_dedup() {
                 with_payload: Some(false.into()),
                 with_vector: false.into(),
                 order_by: None,
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -194,6 +195,7 @@ async fn test_scroll_dedup() {
                 with_payload: Some(false.into()),
                 with_vector: false.into(),
                 order_by: Some(OrderByInterface::Key("num".parse().unwrap())),
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use common::budget::ResourceBudget;
+use parking_lot::RwLock;
+use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
+use segment::entry::entry_point::SegmentEntry;
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::types::{
+    Distance, HnswGlobalConfig, QuantizationConfig, RabitqQuantization, RabitqQuantizationConfig,
+};
+use tempfile::Builder;
+use validator::Validate;
+
+use crate::collection_manager::fixtures::random_segment;
+use crate::collection_manager::holders::segment_holder::{LockedSegment, SegmentHolder};
+use crate::collection_manager::optimizers::indexing_optimizer::IndexingOptimizer;
+use crate::collection_manager::optimizers::segment_optimizer::{
+    OptimizerThresholds, SegmentOptimizer,
+};
+use crate::config::CollectionParams;
+use crate::operations::types::{VectorParams, VectorsConfig};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+
+fn rabitq_config() -> QuantizationConfig {
+    QuantizationConfig::Rabitq(RabitqQuantization {
+        rabitq: RabitqQuantizationConfig { always_ram: None },
+    })
+}
+
+fn rabitq_vector_params(distance: Distance) -> VectorParams {
+    VectorParamsBuilder::new(8, distance)
+        .with_quantization_config(rabitq_config())
+        .build()
+}
+
+#[test]
+fn validate_rabitq_distance() {
+    for distance in [Distance::Cosine, Distance::Euclid, Distance::Dot] {
+        assert!(rabitq_vector_params(distance).validate().is_ok());
+    }
+
+    let err = rabitq_vector_params(Distance::Manhattan)
+        .validate()
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("does not support Manhattan distance")
+    );
+}
+
+/// RaBitQ configured for the whole collection is not checked by vector params validation, so
+/// vectors with Manhattan distance are indexed without quantization
+#[test]
+fn collection_rabitq_skips_manhattan() {
+    let (point_count, dim) = (1000, 8);
+    let collection_params = CollectionParams {
+        vectors: VectorsConfig::Single(
+            VectorParamsBuilder::new(dim as u64, Distance::Manhattan).build(),
+        ),
+        ..CollectionParams::empty()
+    };
+
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut holder = SegmentHolder::default();
+    let segment_id = holder.add_new(random_segment(dir.path(), 100, point_count, dim));
+    let locked_holder = Arc::new(RwLock::new(holder));
+
+    let index_optimizer = IndexingOptimizer::new(
+        2,
+        OptimizerThresholds {
+            max_segment_size_kb: usize::MAX,
+            memmap_threshold_kb: usize::MAX,
+            indexing_threshold_kb: 10,
+        },
+        dir.path().to_owned(),
+        temp_dir.path().to_owned(),
+        collection_params,
+        Default::default(),
+        HnswGlobalConfig::default(),
+        Some(rabitq_config()),
+    );
+
+    let permit_cpu_count = num_rayon_threads(0);
+    let budget = ResourceBudget::new(permit_cpu_count, permit_cpu_count);
+    let permit = budget.try_acquire(0, permit_cpu_count).unwrap();
+    let changed = index_optimizer
+        .optimize(
+            locked_holder.clone(),
+            vec![segment_id],
+            permit,
+            budget,
+            &false.into(),
+        )
+        .unwrap();
+    assert!(changed > 0, "optimizer should have rebuilt this segment");
+
+    let holder = locked_holder.read();
+    let segment = holder
+        .iter()
+        .map(|(_, segment)| match segment {
+            LockedSegment::Original(s) => s.read(),
+            LockedSegment::Proxy(_) => unreachable!(),
+        })
+        .find(|segment| segment.total_point_count() > 0)
+        .unwrap();
+    assert_eq!(
+        segment.config().vector_data[DEFAULT_VECTOR_NAME].quantization_config,
+        Some(rabitq_config()),
+    );
+    assert!(
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .borrow()
+            .is_none()
+    );
+}
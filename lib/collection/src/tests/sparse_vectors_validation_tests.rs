@@ -4,12 +4,14 @@ use api::rest::{
     BaseGroupRequest, Batch, BatchVectorStruct, PointStruct, PointVectors, PointsList,
     SearchGroupsRequestInternal, SearchRequestInternal, Vector, VectorStruct,
 };
+use ordered_float::OrderedFloat;
 use segment::types::VectorNameBuf;
 use sparse::common::sparse_vector::SparseVector;
 use validator::Validate;
 
 use crate::operations::types::{
-    ContextExamplePair, DiscoverRequestInternal, RecommendExample, RecommendRequestInternal,
+    ContextExamplePair, DEFAULT_BM25_B, DEFAULT_BM25_K1, DiscoverRequestInternal, Modifier,
+    RecommendExample, RecommendRequestInternal, SparseVectorParams,
 };
 
 fn wrong_sparse_vector() -> SparseVector {
@@ -167,3 +169,68 @@ fn validate_error_sparse_vector_point_vectors() {
         vector: vector_struct,
     });
 }
+
+#[test]
+fn validate_sparse_vector_params_bm25_modifier() {
+    let params: SparseVectorParams = serde_json::from_str(r#"{"modifier": {"bm25": {}}}"#).unwrap();
+    assert_eq!(
+        params.modifier,
+        Some(Modifier::Bm25 {
+            k1: OrderedFloat(DEFAULT_BM25_K1),
+            b: OrderedFloat(DEFAULT_BM25_B),
+        }),
+    );
+    assert!(params.validate().is_ok());
+
+    // Plain name uses the default parameters
+    let plain: SparseVectorParams = serde_json::from_str(r#"{"modifier": "bm25"}"#).unwrap();
+    assert_eq!(plain, params);
+
+    let params: SparseVectorParams =
+        serde_json::from_str(r#"{"modifier": {"bm25": {"k1": 2.0, "b": 0.5}}}"#).unwrap();
+    assert_eq!(
+        serde_json::to_value(&params).unwrap(),
+        serde_json::json!({"modifier": {"bm25": {"k1": 2.0, "b": 0.5}}}),
+    );
+
+    let params: SparseVectorParams = serde_json::from_str(r#"{"modifier": "idf"}"#).unwrap();
+    assert_eq!(params.modifier, Some(Modifier::Idf));
+
+    let params: SparseVectorParams =
+        serde_json::from_str(r#"{"modifier": {"bm25": {"k1": 2.0, "b": 1.5}}}"#).unwrap();
+    assert!(params.validate().is_err());
+
+    let params: SparseVectorParams =
+        serde_json::from_str(r#"{"modifier": {"bm25": {"k1": -1.0}}}"#).unwrap();
+    assert!(params.validate().is_err());
+}
+
+#[test]
+fn validate_grpc_sparse_vector_params_bm25_modifier() {
+    let grpc_params = |k1, b| api::grpc::qdrant::SparseVectorParams {
+        index: None,
+        modifier: Some(api::grpc::qdrant::Modifier::Bm25 as i32),
+        bm25_params: Some(api::grpc::qdrant::Bm25Params { k1, b }),
+    };
+
+    let params = SparseVectorParams::try_from(grpc_params(Some(2.0), None)).unwrap();
+    assert_eq!(
+        params.modifier,
+        Some(Modifier::Bm25 {
+            k1: OrderedFloat(2.0),
+            b: OrderedFloat(DEFAULT_BM25_B),
+        }),
+    );
+
+    assert!(SparseVectorParams::try_from(grpc_params(Some(-1.0), None)).is_err());
+    assert!(SparseVectorParams::try_from(grpc_params(None, Some(1.5))).is_err());
+}
+
+#[test]
+fn sparse_modifier_schema_lists_plain_bm25() {
+    let schema = serde_json::to_value(schemars::schema_for!(Modifier)).unwrap();
+    assert_eq!(
+        schema["definitions"]["ModifierName"]["enum"],
+        serde_json::json!(["none", "idf", "bm25"]),
+    );
+}
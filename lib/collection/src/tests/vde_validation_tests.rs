@@ -0,0 +1,122 @@
+use segment::types::{
+    Distance, MultiVectorConfig, VdeIndexConfig, VdeStorageType, VectorStorageType,
+};
+use validator::Validate;
+
+use crate::operations::config_diff::VdeConfigDiff;
+use crate::operations::types::{VectorParams, VectorParamsDiff};
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+
+fn vde_config(config_json: Option<&str>) -> VdeIndexConfig {
+    VdeIndexConfig {
+        storage_type: VdeStorageType::Memory,
+        config_json: config_json.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+fn vde_vector_params(config_json: Option<&str>) -> VectorParamsBuilder {
+    VectorParamsBuilder::new(4, Distance::Dot).with_vde_config(vde_config(config_json))
+}
+
+#[test]
+fn validate_vde_storage_type() {
+    let vector_params = || VectorParamsBuilder::new(4, Distance::Dot);
+    assert!(vector_params().build().validate().is_ok());
+    assert!(
+        vector_params()
+            .with_storage_type(VectorStorageType::Mmap)
+            .build()
+            .validate()
+            .is_ok()
+    );
+
+    let result = vector_params()
+        .with_storage_type(VectorStorageType::Vde)
+        .build()
+        .validate();
+    if cfg!(feature = "vde") {
+        assert!(result.is_ok());
+    } else {
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("built without VDE support"));
+    }
+}
+
+#[test]
+fn validate_vde_index_config() {
+    let result = vde_vector_params(Some(r#"{"ef_search": 100}"#))
+        .build()
+        .validate();
+    if !cfg!(feature = "vde") {
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("built without VDE support"));
+        return;
+    }
+    assert!(result.is_ok());
+    assert!(vde_vector_params(None).build().validate().is_ok());
+
+    for invalid_json in ["not json", "[1, 2]", "42"] {
+        let err = vde_vector_params(Some(invalid_json))
+            .build()
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("must be a JSON object"));
+    }
+
+    let multivector_params = vde_vector_params(None)
+        .with_multivector_config(MultiVectorConfig::default())
+        .build();
+    assert!(multivector_params.validate().is_ok());
+}
+
+#[test]
+fn parse_vde_config() {
+    let params: VectorParams = serde_json::from_str(
+        r#"{
+            "size": 4,
+            "distance": "Dot",
+            "vde_config": {
+                "storage_type": "memory",
+                "config_json": "{\"ef_search\": 100}"
+            }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        params.vde_config,
+        Some(vde_config(Some(r#"{"ef_search": 100}"#)))
+    );
+
+    let diff: VectorParamsDiff = serde_json::from_str(r#"{"vde_config": "Disabled"}"#).unwrap();
+    assert_eq!(diff.vde_config, Some(VdeConfigDiff::new_disabled()));
+
+    let diff: VectorParamsDiff = serde_json::from_str(r#"{"vde_config": {}}"#).unwrap();
+    assert_eq!(
+        diff.vde_config,
+        Some(VdeConfigDiff::Config(VdeIndexConfig::default())),
+    );
+}
+
+#[cfg(feature = "vde")]
+#[test]
+fn convert_vde_config_grpc() {
+    let params = vde_vector_params(Some(r#"{"ef_search": 100}"#)).build();
+    let grpc_params = api::grpc::qdrant::VectorParams::from(params.clone());
+    let converted = VectorParams::try_from(grpc_params).unwrap();
+    assert_eq!(converted.vde_config, params.vde_config);
+
+    let grpc_params = api::grpc::qdrant::VectorParams::from(vde_vector_params(Some("[]")).build());
+    assert!(VectorParams::try_from(grpc_params).is_err());
+
+    let grpc_diff = api::grpc::qdrant::VectorParamsDiff {
+        vde_config: Some(api::grpc::qdrant::VdeConfigDiff {
+            config: Some(api::grpc::qdrant::vde_config_diff::Config::Disabled(
+                api::grpc::qdrant::Disabled {},
+            )),
+        }),
+        ..Default::default()
+    };
+    let diff = VectorParamsDiff::try_from(grpc_diff).unwrap();
+    assert_eq!(diff.vde_config, Some(VdeConfigDiff::new_disabled()));
+}
@@ -112,6 +112,7 @@ async fn test_collection_payload_reloading_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: true.into(),
                 order_by: None,
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -195,6 +196,7 @@ async fn test_collection_payload_custom_payload_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Fields(vec![JsonPath::new("k2")])),
                 with_vector: true.into(),
                 order_by: None,
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -234,6 +236,7 @@ async fn test_collection_payload_custom_payload_with_shards(shard_number: u32) {
                 with_payload: Some(PayloadSelectorExclude::new(vec![JsonPath::new("k1")]).into()),
                 with_vector: false.into(),
                 order_by: None,
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
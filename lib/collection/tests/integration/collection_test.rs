@@ -19,13 +19,14 @@ use collection::recommendations::recommend_by;
 use collection::shards::replica_set::{ReplicaSetState, ReplicaState};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
+use segment::data_types::highlight::{HighlightParams, TextHighlight};
 use segment::data_types::order_by::{Direction, OrderBy};
 use segment::data_types::vectors::VectorStructInternal;
 use segment::types::{
-    Condition, ExtendedPointId, FieldCondition, Filter, HasIdCondition, Payload,
-    PayloadFieldSchema, PayloadSchemaType, PointIdType, WithPayloadInterface,
+    Condition, ExtendedPointId, FieldCondition, Filter, HasIdCondition, Match, MatchTextAny,
+    Payload, PayloadFieldSchema, PayloadSchemaType, PointIdType, WithPayloadInterface,
 };
-use serde_json::Map;
+use serde_json::{Map, json};
 use tempfile::Builder;
 
 use crate::common::{N_SHARDS, load_local_collection, simple_collection_fixture};
@@ -458,6 +459,7 @@ async fn test_read_api_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
                 order_by: None,
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -471,6 +473,119 @@ async fn test_read_api_with_shards(shard_number: u32) {
     assert_eq!(result.points.len(), 2);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_highlight_scroll() {
+    test_highlight_scroll_with_shards(1).await;
+    test_highlight_scroll_with_shards(N_SHARDS).await;
+}
+
+async fn test_highlight_scroll_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+
+    let payloads = vec![
+        json!({"text": "The quick brown fox", "other": 0}),
+        json!({"text": ["A brown dog", "lazy fox and brown hen"], "other": 1}),
+        json!({"text": "Nothing here", "other": 2}),
+    ];
+
+    let batch = BatchPersisted {
+        ids: vec![0, 1, 2].into_iter().map(|x| x.into()).collect_vec(),
+        vectors: BatchVectorStructPersisted::Single(vec![
+            vec![0.0, 0.0, 1.0, 1.0],
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0],
+        ]),
+        payloads: Some(
+            payloads
+                .into_iter()
+                .map(|payload| Some(serde_json::from_value(payload).unwrap()))
+                .collect(),
+        ),
+    };
+
+    let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::from(batch),
+    ));
+
+    let hw_counter = HwMeasurementAcc::new();
+    collection
+        .update_from_client_simple(
+            insert_points,
+            true,
+            WriteOrdering::default(),
+            hw_counter.clone(),
+        )
+        .await
+        .unwrap();
+
+    collection
+        .create_payload_index_with_wait(
+            "text".parse().unwrap(),
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Text),
+            true,
+            hw_counter,
+        )
+        .await
+        .unwrap();
+
+    let result = collection
+        .scroll_by(
+            ScrollRequestInternal {
+                offset: None,
+                limit: Some(10),
+                filter: Some(Filter::new_must(Condition::Field(
+                    FieldCondition::new_match(
+                        "text".parse().unwrap(),
+                        Match::TextAny(MatchTextAny {
+                            text_any: "brown fox".to_string(),
+                        }),
+                    ),
+                ))),
+                with_payload: Some(WithPayloadInterface::Fields(vec!["other".parse().unwrap()])),
+                with_vector: false.into(),
+                order_by: None,
+                highlight: Some(HighlightParams {
+                    snippets: Some(true),
+                    context: Some(5),
+                    ..Default::default()
+                }),
+            },
+            None,
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.points.len(), 2);
+
+    // Highlighted field is not returned, if it is not requested
+    let payload = result.points[0].payload.as_ref().unwrap();
+    assert_eq!(
+        payload,
+        &serde_json::from_value(json!({"other": 0})).unwrap()
+    );
+
+    let highlight = result.points[0].highlight.as_ref().unwrap();
+    assert_eq!(
+        highlight["text"],
+        [TextHighlight {
+            value_index: 0,
+            offsets: vec![[10, 15], [16, 19]],
+            snippets: Some(vec!["uick <em>brown</em> <em>fox</em>".to_string()]),
+        }],
+    );
+
+    let highlight = result.points[1].highlight.as_ref().unwrap();
+    let offsets = highlight["text"]
+        .iter()
+        .map(|value| (value.value_index, value.offsets.clone()))
+        .collect_vec();
+    assert_eq!(offsets, [(0, vec![[2, 7]]), (1, vec![[5, 8], [13, 18]])],);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_ordered_read_api() {
     test_ordered_scroll_api_with_shards(1).await;
@@ -597,6 +712,7 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Asc),
                         start_from: None,
                     })),
+                    highlight: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -629,6 +745,7 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Desc),
                         start_from: None,
                     })),
+                    highlight: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -670,6 +787,7 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Asc),
                         start_from: None,
                     })),
+                    highlight: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -710,6 +828,7 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         direction: Some(Direction::Desc),
                         start_from: None,
                     })),
+                    highlight: None,
                 },
                 None,
                 &ShardSelectorInternal::All,
@@ -747,6 +866,7 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vector: false.into(),
                 order_by: Some(OrderByInterface::Key(MULTI_VALUE_KEY.parse().unwrap())),
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
@@ -849,6 +969,7 @@ async fn test_collection_delete_points_by_filter_with_shards(shard_number: u32)
                 with_payload: Some(WithPayloadInterface::Bool(false)),
                 with_vector: false.into(),
                 order_by: None,
+                highlight: None,
             },
             None,
             &ShardSelectorInternal::All,
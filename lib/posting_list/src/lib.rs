@@ -8,6 +8,7 @@ mod view;
 mod visitor;
 
 use bitpacking::BitPacker;
+use zerocopy::little_endian::U32;
 
 type BitPackerImpl = bitpacking::BitPacker4x;
 
@@ -20,6 +21,7 @@ pub trait SizedValue: Sized + Copy + std::fmt::Debug {}
 impl SizedValue for () {}
 impl SizedValue for u32 {}
 impl SizedValue for u64 {}
+impl SizedValue for U32 {}
 
 pub trait UnsizedValue {
     /// Returns the length of the serialized value in bytes.
@@ -22,6 +22,9 @@ impl PostingValue for u32 {
 impl PostingValue for u64 {
     type Handler = SizedHandler<u64>;
 }
+impl PostingValue for U32 {
+    type Handler = SizedHandler<U32>;
+}
 
 /// Trait to abstract the handling of values in PostingList
 ///
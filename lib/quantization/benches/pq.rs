@@ -56,10 +56,77 @@ fn encode_bench(c: &mut Criterion) {
     println!("total: {total}");
 }
 
+fn opq_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("opq");
+
+    let vectors_count = 10_000;
+    let vector_dim = 128;
+    let chunk_size = 4;
+    let mut rng = rand::rng();
+    let mut list: Vec<f32> = Vec::new();
+    for _ in 0..vectors_count {
+        let vector: Vec<f32> = (0..vector_dim).map(|_| rng.random()).collect();
+        list.extend_from_slice(&vector);
+    }
+
+    let vector_parameters = VectorParameters {
+        dim: vector_dim,
+        deprecated_count: None,
+        distance_type: DistanceType::Dot,
+        invert: false,
+    };
+    let quantized_vector_size = EncodedVectorsPQ::<TestEncodedStorage>::get_quantized_vector_size(
+        &vector_parameters,
+        chunk_size,
+    );
+    let encode = |rotation: bool| {
+        EncodedVectorsPQ::encode_with_rotation(
+            (0..vectors_count).map(|i| &list[i * vector_dim..(i + 1) * vector_dim]),
+            TestEncodedStorageBuilder::new(None, quantized_vector_size),
+            &vector_parameters,
+            vectors_count,
+            chunk_size,
+            rotation,
+            4,
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap()
+    };
+
+    group.bench_function("encode pq", |b| {
+        b.iter(|| encode(false));
+    });
+
+    group.bench_function("encode opq", |b| {
+        b.iter(|| encode(true));
+    });
+
+    let opq_encoded = encode(true);
+    let query: Vec<f32> = (0..vector_dim).map(|_| rng.random()).collect();
+
+    group.bench_function("encode query opq", |b| {
+        b.iter(|| opq_encoded.encode_query(&query));
+    });
+
+    let encoded_query = opq_encoded.encode_query(&query);
+    let mut total = 0.0;
+    let hardware_counter = HardwareCounterCell::new();
+
+    group.bench_function("score random access opq", |b| {
+        b.iter(|| {
+            let random_idx = rand::random::<u32>() % vectors_count as u32;
+            total += opq_encoded.score_point(&encoded_query, random_idx, &hardware_counter);
+        });
+    });
+
+    println!("total: {total}");
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = encode_bench
+    targets = encode_bench, opq_bench
 }
 
 criterion_main!(benches);
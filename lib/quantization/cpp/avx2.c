@@ -203,3 +203,65 @@ EXPORT uint32_t impl_xor_popcnt_scalar4_avx_uint128(
     HSUM256_PS(result_mm256, mul_scalar);
     return (uint32_t)mul_scalar;
 }
+
+EXPORT float impl_score_dot_int4_avx(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    uint32_t dim
+) {
+    const __m128i* v_ptr = (const __m128i*)vector_ptr;
+    const __m256i* q_ptr = (const __m256i*)query_ptr;
+
+    __m256i mask = _mm256_set1_epi8(0x0F);
+    __m256i ones = _mm256_set1_epi16(1);
+    __m256i sum = _mm256_setzero_si256();
+    // the vector sizes are assumed to be multiples of 32, each 32 dimensions are packed into 16 bytes
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        __m128i packed = _mm_loadu_si128(v_ptr);
+        __m256i q = _mm256_loadu_si256(q_ptr);
+        v_ptr++;
+        q_ptr++;
+
+        // low nibbles hold the first 16 dimensions, high nibbles hold the next 16
+        __m256i v = _mm256_inserti128_si256(
+            _mm256_castsi128_si256(packed), _mm_srli_epi16(packed, 4), 1);
+        v = _mm256_and_si256(v, mask);
+
+        __m256i s = _mm256_maddubs_epi16(v, q);
+        sum = _mm256_add_epi32(sum, _mm256_madd_epi16(s, ones));
+    }
+
+    HSUM256_EPI32(sum, sum_scalar);
+    return (float) sum_scalar;
+}
+
+EXPORT float impl_score_l1_int4_avx(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    uint32_t dim
+) {
+    const __m128i* v_ptr = (const __m128i*)vector_ptr;
+    const __m256i* q_ptr = (const __m256i*)query_ptr;
+
+    __m256i mask = _mm256_set1_epi8(0x0F);
+    __m256i sum = _mm256_setzero_si256();
+    // the vector sizes are assumed to be multiples of 32, each 32 dimensions are packed into 16 bytes
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        __m128i packed = _mm_loadu_si128(v_ptr);
+        __m256i q = _mm256_loadu_si256(q_ptr);
+        v_ptr++;
+        q_ptr++;
+
+        // low nibbles hold the first 16 dimensions, high nibbles hold the next 16
+        __m256i v = _mm256_inserti128_si256(
+            _mm256_castsi128_si256(packed), _mm_srli_epi16(packed, 4), 1);
+        v = _mm256_and_si256(v, mask);
+
+        __m256i abs_diff = _mm256_max_epu8(_mm256_subs_epu8(v, q), _mm256_subs_epu8(q, v));
+        sum = _mm256_add_epi64(sum, _mm256_sad_epu8(abs_diff, _mm256_setzero_si256()));
+    }
+
+    // 64-bit sums fit into their lower 32 bits
+    HSUM256_EPI32(sum, sum_scalar);
+    return (float) sum_scalar;
+}
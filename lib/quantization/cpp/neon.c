@@ -458,3 +458,56 @@ EXPORT float impl_score_l1_neon(
 
     return (float) sum;
 }
+
+EXPORT float impl_score_dot_int4_neon(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    uint32_t dim
+) {
+    uint8x16_t mask = vdupq_n_u8(0x0F);
+    uint32x4_t mul1 = vdupq_n_u32(0);
+    uint32x4_t mul2 = vdupq_n_u32(0);
+    // the vector sizes are assumed to be multiples of 32, each 32 dimensions are packed into 16 bytes
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        uint8x16_t packed = vld1q_u8(vector_ptr);
+        uint8x16_t q_low = vld1q_u8(query_ptr);
+        uint8x16_t q_high = vld1q_u8(query_ptr + 16);
+        vector_ptr += 16;
+        query_ptr += 32;
+
+        // low nibbles hold the first 16 dimensions, high nibbles hold the next 16
+        uint8x16_t v_low = vandq_u8(packed, mask);
+        uint8x16_t v_high = vshrq_n_u8(packed, 4);
+
+        mul1 = vpadalq_u16(mul1, vmull_u8(vget_low_u8(q_low), vget_low_u8(v_low)));
+        mul2 = vpadalq_u16(mul2, vmull_u8(vget_high_u8(q_low), vget_high_u8(v_low)));
+        mul1 = vpadalq_u16(mul1, vmull_u8(vget_low_u8(q_high), vget_low_u8(v_high)));
+        mul2 = vpadalq_u16(mul2, vmull_u8(vget_high_u8(q_high), vget_high_u8(v_high)));
+    }
+    return (float)vaddvq_u32(vaddq_u32(mul1, mul2));
+}
+
+EXPORT float impl_score_l1_int4_neon(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    uint32_t dim
+) {
+    uint8x16_t mask = vdupq_n_u8(0x0F);
+    uint32x4_t sum = vdupq_n_u32(0);
+    // the vector sizes are assumed to be multiples of 32, each 32 dimensions are packed into 16 bytes
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        uint8x16_t packed = vld1q_u8(vector_ptr);
+        uint8x16_t q_low = vld1q_u8(query_ptr);
+        uint8x16_t q_high = vld1q_u8(query_ptr + 16);
+        vector_ptr += 16;
+        query_ptr += 32;
+
+        // low nibbles hold the first 16 dimensions, high nibbles hold the next 16
+        uint8x16_t v_low = vandq_u8(packed, mask);
+        uint8x16_t v_high = vshrq_n_u8(packed, 4);
+
+        sum = vpadalq_u16(sum, vpaddlq_u8(vabdq_u8(q_low, v_low)));
+        sum = vpadalq_u16(sum, vpaddlq_u8(vabdq_u8(q_high, v_high)));
+    }
+    return (float)vaddvq_u32(sum);
+}
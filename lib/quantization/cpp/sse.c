@@ -511,3 +511,72 @@ EXPORT float impl_score_l1_sse(
 
     return (float) sum;
 }
+
+EXPORT float impl_score_dot_int4_sse(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    uint32_t dim
+) {
+    const __m128i* v_ptr = (const __m128i*)vector_ptr;
+    const __m128i* q_ptr = (const __m128i*)query_ptr;
+
+    __m128i mask = _mm_set1_epi8(0x0F);
+    __m128i ones = _mm_set1_epi16(1);
+    __m128i mul = _mm_setzero_si128();
+    // the vector sizes are assumed to be multiples of 32, each 32 dimensions are packed into 16 bytes
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        __m128i packed = _mm_loadu_si128(v_ptr);
+        __m128i q_low = _mm_loadu_si128(q_ptr);
+        __m128i q_high = _mm_loadu_si128(q_ptr + 1);
+        v_ptr++;
+        q_ptr += 2;
+
+        // low nibbles hold the first 16 dimensions, high nibbles hold the next 16
+        __m128i v_low = _mm_and_si128(packed, mask);
+        __m128i v_high = _mm_and_si128(_mm_srli_epi16(packed, 4), mask);
+
+        __m128i s = _mm_add_epi16(
+            _mm_maddubs_epi16(v_low, q_low),
+            _mm_maddubs_epi16(v_high, q_high));
+        mul = _mm_add_epi32(mul, _mm_madd_epi16(s, ones));
+    }
+    __m128 mul_ps = _mm_cvtepi32_ps(mul);
+    HSUM128_PS(mul_ps, mul_scalar);
+    return mul_scalar;
+}
+
+EXPORT float impl_score_l1_int4_sse(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    uint32_t dim
+) {
+    const __m128i* v_ptr = (const __m128i*)vector_ptr;
+    const __m128i* q_ptr = (const __m128i*)query_ptr;
+
+    __m128i mask = _mm_set1_epi8(0x0F);
+    __m128i sum = _mm_setzero_si128();
+    // the vector sizes are assumed to be multiples of 32, each 32 dimensions are packed into 16 bytes
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        __m128i packed = _mm_loadu_si128(v_ptr);
+        __m128i q_low = _mm_loadu_si128(q_ptr);
+        __m128i q_high = _mm_loadu_si128(q_ptr + 1);
+        v_ptr++;
+        q_ptr += 2;
+
+        // low nibbles hold the first 16 dimensions, high nibbles hold the next 16
+        __m128i v_low = _mm_and_si128(packed, mask);
+        __m128i v_high = _mm_and_si128(_mm_srli_epi16(packed, 4), mask);
+
+        __m128i abs_diff_low = _mm_max_epu8(
+            _mm_subs_epu8(v_low, q_low), _mm_subs_epu8(q_low, v_low));
+        __m128i abs_diff_high = _mm_max_epu8(
+            _mm_subs_epu8(v_high, q_high), _mm_subs_epu8(q_high, v_high));
+
+        sum = _mm_add_epi64(sum, _mm_sad_epu8(abs_diff_low, _mm_setzero_si128()));
+        sum = _mm_add_epi64(sum, _mm_sad_epu8(abs_diff_high, _mm_setzero_si128()));
+    }
+
+    // 64-bit sums fit into their lower 32 bits
+    sum = _mm_add_epi64(sum, _mm_srli_si128(sum, 8));
+    return (float) _mm_cvtsi128_si32(sum);
+}
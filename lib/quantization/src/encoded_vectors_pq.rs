@@ -18,9 +18,11 @@ use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
 use crate::encoded_storage::{EncodedStorage, EncodedStorageBuilder};
-use crate::encoded_vectors::{EncodedVectors, VectorParameters, validate_vector_parameters};
+use crate::encoded_vectors::{
+    DistanceType, EncodedVectors, VectorParameters, validate_vector_parameters,
+};
 use crate::kmeans::kmeans;
-use crate::{ConditionalVariable, EncodingError};
+use crate::{ConditionalVariable, EncodingError, opq};
 
 pub const KMEANS_SAMPLE_SIZE: usize = 10_000;
 pub const KMEANS_MAX_ITERATIONS: usize = 100;
@@ -45,6 +47,9 @@ pub struct Metadata {
     pub centroids: Vec<Vec<f32>>,
     pub vector_division: Vec<Range<usize>>,
     pub vector_parameters: VectorParameters,
+    /// Learned OPQ rotation, applied to vectors before chunking. Row-major `dim x dim` matrix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<Vec<f32>>,
 }
 
 impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
@@ -63,30 +68,111 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
     /// * `stopped` - Atomic bool that indicates if encoding should be stopped
     #[allow(clippy::too_many_arguments)]
     pub fn encode<'a>(
+        data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone + Send,
+        storage_builder: impl EncodedStorageBuilder<Storage = TStorage> + Send,
+        vector_parameters: &VectorParameters,
+        count: usize,
+        chunk_size: usize,
+        max_kmeans_threads: usize,
+        meta_path: Option<&Path>,
+        stopped: &AtomicBool,
+    ) -> Result<Self, EncodingError> {
+        Self::encode_with_rotation(
+            data,
+            storage_builder,
+            vector_parameters,
+            count,
+            chunk_size,
+            false,
+            max_kmeans_threads,
+            meta_path,
+            stopped,
+        )
+    }
+
+    /// Encode vector data using product quantization with optional OPQ rotation.
+    ///
+    /// If `rotation` is enabled, an orthogonal rotation of the vector space is learned
+    /// together with the codebooks, so that correlated dimensions are spread between chunks.
+    /// The rotation is stored in metadata and applied to each query.
+    /// Rotation is not supported for L1 distance, since it does not preserve L1 distances.
+    ///
+    /// # Arguments
+    /// * `data` - iterator over original vector data
+    /// * `storage_builder` - encoding result storage builder
+    /// * `vector_parameters` - parameters of original vector data (dimension, distance, etc)
+    /// * `chunk_size` - Max size of f32 chunk that replaced by centroid index (in original vector dimension)
+    /// * `rotation` - Learn OPQ rotation before encoding
+    /// * `max_threads` - Max allowed threads for kmeans and encodind process
+    /// * `stopped` - Atomic bool that indicates if encoding should be stopped
+    #[allow(clippy::too_many_arguments)]
+    pub fn encode_with_rotation<'a>(
         data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone + Send,
         mut storage_builder: impl EncodedStorageBuilder<Storage = TStorage> + Send,
         vector_parameters: &VectorParameters,
         count: usize,
         chunk_size: usize,
+        rotation: bool,
         max_kmeans_threads: usize,
         meta_path: Option<&Path>,
         stopped: &AtomicBool,
     ) -> Result<Self, EncodingError> {
         debug_assert!(validate_vector_parameters(data.clone(), vector_parameters).is_ok());
 
+        if rotation && vector_parameters.distance_type == DistanceType::L1 {
+            return Err(EncodingError::ArgumentsError(
+                "OPQ rotation does not support L1 distance".to_string(),
+            ));
+        }
+
         // first, divide vector into chunks
         let vector_division = Self::get_vector_division(vector_parameters.dim, chunk_size);
 
-        // then, find flattened centroid positions
-        let centroids = Self::find_centroids(
-            data.clone(),
-            &vector_division,
-            vector_parameters,
-            count,
-            CENTROIDS_COUNT,
-            max_kmeans_threads,
-            stopped,
-        )?;
+        // then, find rotation and flattened centroid positions
+        // If there are not enough vectors, centroids are exact point positions and rotation is useless
+        let (rotation, centroids) = if rotation && count > CENTROIDS_COUNT {
+            let sample = Self::sample_vectors(
+                data.clone(),
+                vector_parameters.dim,
+                count,
+                KMEANS_SAMPLE_SIZE,
+                stopped,
+            )?;
+            let rotation = opq::train_rotation(
+                &sample,
+                vector_parameters.dim,
+                &vector_division,
+                CENTROIDS_COUNT,
+                max_kmeans_threads,
+                KMEANS_ACCURACY,
+                stopped,
+            )?;
+            let rotated_sample: Vec<Vec<f32>> = sample
+                .chunks_exact(vector_parameters.dim)
+                .map(|vector| opq::rotate(&rotation, vector))
+                .collect();
+            let centroids = Self::find_centroids(
+                rotated_sample.iter(),
+                &vector_division,
+                vector_parameters,
+                rotated_sample.len(),
+                CENTROIDS_COUNT,
+                max_kmeans_threads,
+                stopped,
+            )?;
+            (Some(rotation), centroids)
+        } else {
+            let centroids = Self::find_centroids(
+                data.clone(),
+                &vector_division,
+                vector_parameters,
+                count,
+                CENTROIDS_COUNT,
+                max_kmeans_threads,
+                stopped,
+            )?;
+            (None, centroids)
+        };
 
         // finally, encode data
         Self::encode_storage(
@@ -94,6 +180,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
             &mut storage_builder,
             &vector_division,
             &centroids,
+            rotation.as_deref(),
             max_kmeans_threads,
             stopped,
         )?;
@@ -106,6 +193,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
             centroids,
             vector_division,
             vector_parameters: vector_parameters.clone(),
+            rotation,
         };
         if let Some(meta_path) = meta_path {
             meta_path
@@ -170,6 +258,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
     /// * `storage_builder` - Builder of encoded data container
     /// * `vector_division` - Division of original vector into chunks
     /// * `centroids` - Centroid positions (flattened by chunks; for similarity to vector data format)
+    /// * `rotation` - Optional OPQ rotation, applied to each vector before encoding
     /// * `max_threads` - Max allowed threads for encoding process
     /// * `stopped` - Atomic bool that indicates if encoding should be stopped
     ///
@@ -181,6 +270,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
         storage_builder: &'b mut (impl EncodedStorageBuilder<Storage = TStorage> + Send),
         vector_division: &'b [Range<usize>],
         centroids: &'b [Vec<f32>],
+        rotation: Option<&'b [f32]>,
         max_threads: usize,
         stopped: &AtomicBool,
     ) -> Result<(), EncodingError> {
@@ -200,6 +290,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
                     storage_builder,
                     vector_division,
                     centroids,
+                    rotation,
                     max_threads,
                     stopped,
                 )
@@ -208,12 +299,14 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
 
     /// Encode whole storage inside rayon context
     /// This function should be called inside `rayon::scope`
+    #[allow(clippy::too_many_arguments)]
     fn encode_storage_rayon<'a: 'b, 'b>(
         scope: &rayon::Scope<'b>,
         data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone + Send + 'b,
         storage_builder: &'b mut (impl EncodedStorageBuilder<Storage = TStorage> + Send),
         vector_division: &'b [Range<usize>],
         centroids: &'b [Vec<f32>],
+        rotation: Option<&'b [f32]>,
         max_threads: usize,
         stopped: &'b AtomicBool,
     ) -> Result<(), EncodingError> {
@@ -244,12 +337,15 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
                         return;
                     }
 
-                    Self::encode_vector(
-                        vector.as_ref(),
-                        vector_division,
-                        centroids,
-                        &mut encoded_vector,
-                    );
+                    let rotated;
+                    let vector = match rotation {
+                        Some(rotation) => {
+                            rotated = opq::rotate(rotation, vector.as_ref());
+                            &rotated
+                        }
+                        None => vector.as_ref(),
+                    };
+                    Self::encode_vector(vector, vector_division, centroids, &mut encoded_vector);
                     // wait for permission from prev thread to use storage
                     let is_disconnected = condvar.wait();
                     // push encoded vector to storage
@@ -401,6 +497,38 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
         Ok(result)
     }
 
+    /// Take random subset of data as a flattened vector
+    fn sample_vectors<'a>(
+        data: impl Iterator<Item = impl AsRef<[f32]> + 'a>,
+        dim: usize,
+        count: usize,
+        sample_size: usize,
+        stopped: &AtomicBool,
+    ) -> Result<Vec<f32>, EncodingError> {
+        let sample_size = sample_size.min(count);
+        let permutor = permutation_iterator::Permutor::new(count as u64);
+        let mut selected_vectors: Vec<usize> =
+            permutor.map(|i| i as usize).take(sample_size).collect();
+        if stopped.load(Ordering::Relaxed) {
+            return Err(EncodingError::Stopped);
+        }
+        selected_vectors.sort_unstable();
+
+        let mut sample = Vec::with_capacity(sample_size * dim);
+        let mut selected = selected_vectors.into_iter().peekable();
+        for (vector_index, vector_data) in data.enumerate() {
+            match selected.peek() {
+                Some(&selected_index) if selected_index == vector_index => {
+                    sample.extend_from_slice(vector_data.as_ref());
+                    selected.next();
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+        Ok(sample)
+    }
+
     #[cfg(target_arch = "x86_64")]
     #[target_feature(enable = "sse4.1")]
     unsafe fn score_point_sse(&self, query: &EncodedQueryPQ, centroids: &[u8]) -> f32 {
@@ -508,6 +636,14 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsPQ<TStorage> {
     }
 
     fn encode_query(&self, query: &[f32]) -> EncodedQueryPQ {
+        let rotated;
+        let query = match &self.metadata.rotation {
+            Some(rotation) => {
+                rotated = opq::rotate(rotation, query);
+                &rotated
+            }
+            None => query,
+        };
         let lut_capacity = self.metadata.vector_division.len() * self.metadata.centroids.len();
         let mut lut = Vec::with_capacity(lut_capacity);
         for range in &self.metadata.vector_division {
@@ -0,0 +1,628 @@
+use std::alloc::Layout;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::typelevel::True;
+use common::types::PointOffsetType;
+use io::file_operations::atomic_save_json;
+use memory::mmap_type::MmapFlusher;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::EncodingError;
+use crate::encoded_storage::{EncodedStorage, EncodedStorageBuilder};
+use crate::encoded_vectors::{
+    DistanceType, EncodedVectors, VectorParameters, validate_vector_parameters,
+};
+use crate::vector_stats::VectorStats;
+
+/// Bits per dimension of the quantized query.
+pub const QUERY_BITS: usize = 4;
+
+/// Multiplier of the estimation error bound, in standard deviations of the estimator.
+/// The estimation error is close to normally distributed, so the true score falls
+/// within the bound for about 99.7% of the points.
+pub const DEFAULT_ERROR_BOUND_CONFIDENCE: f32 = 3.0;
+
+/// Number of random sign flip and Hadamard transform rounds of the rotation.
+const ROTATION_ROUNDS: usize = 3;
+
+/// Per-vector correction factors, stored in front of the binary code as `f32` values.
+const FACTORS_COUNT: usize = 4;
+
+const FACTORS_SIZE: usize = FACTORS_COUNT * size_of::<f32>();
+
+/// RaBitQ quantization.
+///
+/// Vectors are shifted by the centroid of the data and randomly rotated.
+/// Each rotated residual is stored as one bit per dimension, along with correction
+/// factors which allow to build an unbiased estimation of the score together with
+/// a bound of the estimation error.
+pub struct EncodedVectorsRabitq<TStorage: EncodedStorage> {
+    encoded_vectors: TStorage,
+    metadata: Metadata,
+    metadata_path: Option<PathBuf>,
+    rotation: Rotation,
+    centroid_norm_sq: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Metadata {
+    pub vector_parameters: VectorParameters,
+    /// Mean of the encoded vectors, residuals are taken relatively to it
+    pub centroid: Vec<f32>,
+    /// Seed of the random rotation
+    pub rotation_seed: u64,
+}
+
+/// Query rotated the same way as the stored vectors and quantized into `QUERY_BITS` bits.
+/// Quantized values are split into bit planes, so inner product with a binary code
+/// is a sum of bit plane popcounts.
+pub struct EncodedQueryRabitq {
+    bit_planes: Vec<u64>,
+    lower: f32,
+    step: f32,
+    codes_sum: f32,
+    residual_norm: f32,
+    centroid_ip: f32,
+}
+
+/// Correction factors of a single encoded vector.
+#[derive(Debug, Clone, Copy)]
+struct VectorFactors {
+    /// Norm of the residual to the centroid
+    residual_norm: f32,
+    /// Inner product of the quantized and the original unit residuals
+    quantized_ip: f32,
+    /// Inner product of the residual and the centroid
+    centroid_ip: f32,
+    /// Count of set bits in the binary code
+    bits_count: f32,
+}
+
+impl VectorFactors {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let value = |i: usize| {
+            f32::from_ne_bytes(
+                bytes[i * size_of::<f32>()..(i + 1) * size_of::<f32>()]
+                    .try_into()
+                    .unwrap(),
+            )
+        };
+        Self {
+            residual_norm: value(0),
+            quantized_ip: value(1),
+            centroid_ip: value(2),
+            bits_count: value(3),
+        }
+    }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        for value in [
+            self.residual_norm,
+            self.quantized_ip,
+            self.centroid_ip,
+            self.bits_count,
+        ] {
+            bytes.extend_from_slice(&value.to_ne_bytes());
+        }
+    }
+}
+
+/// Random orthogonal transformation: rounds of random sign flips followed by
+/// normalized Walsh-Hadamard transform. Vectors are zero-padded to a power of two.
+struct Rotation {
+    dim: usize,
+    padded_dim: usize,
+    signs: Vec<f32>,
+}
+
+impl Rotation {
+    fn new(dim: usize, seed: u64) -> Self {
+        let padded_dim = Self::padded_dim(dim);
+        // Use own generator, so the rotation never changes with dependency updates
+        let mut state = seed;
+        let signs = (0..ROTATION_ROUNDS * padded_dim)
+            .map(|_| {
+                if splitmix64(&mut state) & 1 == 0 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            })
+            .collect();
+        Self {
+            dim,
+            padded_dim,
+            signs,
+        }
+    }
+
+    fn padded_dim(dim: usize) -> usize {
+        dim.next_power_of_two().max(u64::BITS as usize)
+    }
+
+    fn rotate(&self, vector: &[f32]) -> Vec<f32> {
+        debug_assert_eq!(vector.len(), self.dim);
+        let mut result = vec![0.0; self.padded_dim];
+        result[..self.dim].copy_from_slice(vector);
+        let scale = 1.0 / (self.padded_dim as f32).sqrt();
+        for round_signs in self.signs.chunks_exact(self.padded_dim) {
+            result
+                .iter_mut()
+                .zip(round_signs)
+                .for_each(|(value, sign)| *value *= sign * scale);
+            walsh_hadamard_transform(&mut result);
+        }
+        result
+    }
+}
+
+impl<TStorage: EncodedStorage> EncodedVectorsRabitq<TStorage> {
+    pub fn storage(&self) -> &TStorage {
+        &self.encoded_vectors
+    }
+
+    /// Encode vector data using RaBitQ quantization.
+    ///
+    /// # Arguments
+    /// * `orig_data` - iterator over original vector data
+    /// * `storage_builder` - encoding result storage builder
+    /// * `vector_parameters` - parameters of original vector data (dimension, distance, etc)
+    /// * `meta_path` - path to save metadata
+    /// * `stopped` - Atomic bool that indicates if encoding should be stopped
+    pub fn encode<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        mut storage_builder: impl EncodedStorageBuilder<Storage = TStorage>,
+        vector_parameters: &VectorParameters,
+        meta_path: Option<&Path>,
+        stopped: &AtomicBool,
+    ) -> Result<Self, EncodingError> {
+        debug_assert!(validate_vector_parameters(orig_data.clone(), vector_parameters).is_ok());
+
+        if vector_parameters.distance_type == DistanceType::L1 {
+            return Err(EncodingError::ArgumentsError(
+                "RaBitQ quantization does not support L1 distance".to_string(),
+            ));
+        }
+
+        let centroid = if vector_parameters.dim > 0 {
+            VectorStats::build(orig_data.clone(), vector_parameters)
+                .elements_stats
+                .iter()
+                .map(|stats| stats.mean)
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let metadata = Metadata {
+            vector_parameters: vector_parameters.clone(),
+            centroid,
+            rotation_seed: rand::rng().random(),
+        };
+        let rotation = Rotation::new(vector_parameters.dim, metadata.rotation_seed);
+
+        for vector in orig_data {
+            if stopped.load(Ordering::Relaxed) {
+                return Err(EncodingError::Stopped);
+            }
+
+            let encoded_vector = Self::encode_vector(vector.as_ref(), &metadata, &rotation);
+            storage_builder
+                .push_vector_data(&encoded_vector)
+                .map_err(|e| {
+                    EncodingError::EncodingError(format!("Failed to push encoded vector: {e}",))
+                })?;
+        }
+
+        let encoded_vectors = storage_builder
+            .build()
+            .map_err(|e| EncodingError::EncodingError(format!("Failed to build storage: {e}",)))?;
+
+        if let Some(meta_path) = meta_path {
+            meta_path
+                .parent()
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "Path must have a parent directory",
+                    )
+                })
+                .and_then(std::fs::create_dir_all)
+                .map_err(|e| {
+                    EncodingError::EncodingError(format!(
+                        "Failed to create metadata directory: {e}",
+                    ))
+                })?;
+            atomic_save_json(meta_path, &metadata).map_err(|e| {
+                EncodingError::EncodingError(format!("Failed to save metadata: {e}",))
+            })?;
+        }
+
+        Ok(Self::new(
+            encoded_vectors,
+            metadata,
+            meta_path.map(PathBuf::from),
+        ))
+    }
+
+    pub fn load(encoded_vectors: TStorage, meta_path: &Path) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(meta_path)?;
+        let metadata: Metadata = serde_json::from_str(&contents)?;
+        Ok(Self::new(
+            encoded_vectors,
+            metadata,
+            Some(meta_path.to_path_buf()),
+        ))
+    }
+
+    fn new(encoded_vectors: TStorage, metadata: Metadata, metadata_path: Option<PathBuf>) -> Self {
+        let rotation = Rotation::new(metadata.vector_parameters.dim, metadata.rotation_seed);
+        let centroid_norm_sq = metadata.centroid.iter().map(|x| x * x).sum();
+        Self {
+            encoded_vectors,
+            metadata,
+            metadata_path,
+            rotation,
+            centroid_norm_sq,
+        }
+    }
+
+    pub fn get_quantized_vector_size(vector_parameters: &VectorParameters) -> usize {
+        FACTORS_SIZE + Rotation::padded_dim(vector_parameters.dim) / u8::BITS as usize
+    }
+
+    pub fn get_quantized_vector(&self, i: PointOffsetType) -> &[u8] {
+        self.encoded_vectors.get_vector_data(i)
+    }
+
+    pub fn layout(&self) -> Layout {
+        Layout::from_size_align(
+            Self::get_quantized_vector_size(&self.metadata.vector_parameters),
+            align_of::<u64>(),
+        )
+        .unwrap()
+    }
+
+    pub fn get_metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Estimate the score of the point `i` along with the bound of the estimation error.
+    ///
+    /// `confidence` is the width of the bound in standard deviations of the estimator,
+    /// use [`DEFAULT_ERROR_BOUND_CONFIDENCE`] if not sure.
+    pub fn score_point_with_error_bound(
+        &self,
+        query: &EncodedQueryRabitq,
+        i: PointOffsetType,
+        confidence: f32,
+        hw_counter: &HardwareCounterCell,
+    ) -> (f32, f32) {
+        let bytes = self.encoded_vectors.get_vector_data(i);
+        hw_counter.cpu_counter().incr_delta(bytes.len());
+        self.score_bytes_with_error_bound(query, bytes, confidence)
+    }
+
+    fn encode_vector(vector: &[f32], metadata: &Metadata, rotation: &Rotation) -> Vec<u8> {
+        let residual: Vec<f32> = vector
+            .iter()
+            .zip(&metadata.centroid)
+            .map(|(value, center)| value - center)
+            .collect();
+        let residual_norm = norm(&residual);
+        let centroid_ip = dot(&residual, &metadata.centroid);
+
+        let rotated = rotation.rotate(&residual);
+        let padded_dim = rotated.len();
+
+        let mut code = vec![0u64; padded_dim / u64::BITS as usize];
+        for (i, value) in rotated.iter().enumerate() {
+            if *value > 0.0 {
+                code[i / u64::BITS as usize] |= 1 << (i % u64::BITS as usize);
+            }
+        }
+
+        // Inner product of the unit residual `u` and its quantization `(2 * bits - 1) / sqrt(D)`
+        let quantized_ip = if residual_norm > f32::EPSILON {
+            rotated.iter().map(|x| x.abs()).sum::<f32>()
+                / (residual_norm * (padded_dim as f32).sqrt())
+        } else {
+            1.0
+        };
+
+        let factors = VectorFactors {
+            residual_norm,
+            quantized_ip,
+            centroid_ip,
+            bits_count: code.iter().map(|word| word.count_ones()).sum::<u32>() as f32,
+        };
+
+        let mut encoded = Vec::with_capacity(FACTORS_SIZE + code.len() * size_of::<u64>());
+        factors.write(&mut encoded);
+        for word in code {
+            encoded.extend_from_slice(&word.to_ne_bytes());
+        }
+        encoded
+    }
+
+    /// Estimate inner product of the unit query and the unit stored residuals,
+    /// returns the estimation and its error bound.
+    fn estimate_unit_ip(
+        &self,
+        query: &EncodedQueryRabitq,
+        factors: &VectorFactors,
+        code: &[u8],
+        confidence: f32,
+    ) -> (f32, f32) {
+        let words_count = code.len() / size_of::<u64>();
+        let mut selected_codes_sum = 0u32;
+        for (word_index, word_bytes) in code.chunks_exact(size_of::<u64>()).enumerate() {
+            let word = u64::from_ne_bytes(word_bytes.try_into().unwrap());
+            for bit in 0..QUERY_BITS {
+                let plane = query.bit_planes[bit * words_count + word_index];
+                selected_codes_sum += (word & plane).count_ones() << bit;
+            }
+        }
+
+        let padded_dim = self.rotation.padded_dim as f32;
+        // Sum of query values at dimensions with set bits, and over all dimensions
+        let selected_sum =
+            query.step * selected_codes_sum as f32 + query.lower * factors.bits_count;
+        let total_sum = query.step * query.codes_sum + query.lower * padded_dim;
+        let quantized_query_ip = (2.0 * selected_sum - total_sum) / padded_dim.sqrt();
+
+        let quantized_ip = factors.quantized_ip.max(f32::EPSILON);
+        let estimation = quantized_query_ip / quantized_ip;
+        let error = ((1.0 - quantized_ip * quantized_ip).max(0.0) / (quantized_ip * quantized_ip))
+            .sqrt()
+            * confidence
+            / (padded_dim - 1.0).max(1.0).sqrt();
+        (estimation, error)
+    }
+
+    fn score_bytes_with_error_bound(
+        &self,
+        query: &EncodedQueryRabitq,
+        bytes: &[u8],
+        confidence: f32,
+    ) -> (f32, f32) {
+        let (factors_bytes, code) = bytes.split_at(FACTORS_SIZE);
+        let factors = VectorFactors::from_bytes(factors_bytes);
+        let (unit_ip, unit_ip_error) = self.estimate_unit_ip(query, &factors, code, confidence);
+        let norms_product = factors.residual_norm * query.residual_norm;
+
+        let (score, error) = match self.metadata.vector_parameters.distance_type {
+            DistanceType::Dot => (
+                norms_product * unit_ip + factors.centroid_ip + query.centroid_ip,
+                norms_product * unit_ip_error,
+            ),
+            // L1 is rejected on encoding
+            DistanceType::L2 | DistanceType::L1 => (
+                factors.residual_norm * factors.residual_norm
+                    + query.residual_norm * query.residual_norm
+                    - 2.0 * norms_product * unit_ip,
+                2.0 * norms_product * unit_ip_error,
+            ),
+        };
+
+        if self.metadata.vector_parameters.invert {
+            (-score, error)
+        } else {
+            (score, error)
+        }
+    }
+}
+
+impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsRabitq<TStorage> {
+    type EncodedQuery = EncodedQueryRabitq;
+
+    fn is_on_disk(&self) -> bool {
+        self.encoded_vectors.is_on_disk()
+    }
+
+    fn encode_query(&self, query: &[f32]) -> EncodedQueryRabitq {
+        debug_assert_eq!(query.len(), self.metadata.vector_parameters.dim);
+        let residual: Vec<f32> = query
+            .iter()
+            .zip(&self.metadata.centroid)
+            .map(|(value, center)| value - center)
+            .collect();
+        let residual_norm = norm(&residual);
+        let centroid_ip = dot(query, &self.metadata.centroid);
+
+        let mut rotated = self.rotation.rotate(&residual);
+        if residual_norm > f32::EPSILON {
+            rotated.iter_mut().for_each(|x| *x /= residual_norm);
+        }
+
+        let lower = rotated.iter().copied().fold(f32::INFINITY, f32::min);
+        let upper = rotated.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let max_code = ((1 << QUERY_BITS) - 1) as f32;
+        let step = if upper > lower {
+            (upper - lower) / max_code
+        } else {
+            1.0
+        };
+
+        let words_count = rotated.len() / u64::BITS as usize;
+        let mut bit_planes = vec![0u64; QUERY_BITS * words_count];
+        let mut codes_sum = 0.0;
+        for (i, value) in rotated.iter().enumerate() {
+            let code = ((value - lower) / step).round().clamp(0.0, max_code) as u32;
+            codes_sum += code as f32;
+            for bit in 0..QUERY_BITS {
+                if code & (1 << bit) != 0 {
+                    bit_planes[bit * words_count + i / u64::BITS as usize] |=
+                        1 << (i % u64::BITS as usize);
+                }
+            }
+        }
+
+        EncodedQueryRabitq {
+            bit_planes,
+            lower,
+            step,
+            codes_sum,
+            residual_norm,
+            centroid_ip,
+        }
+    }
+
+    fn score_point(
+        &self,
+        query: &EncodedQueryRabitq,
+        i: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> f32 {
+        let bytes = self.encoded_vectors.get_vector_data(i);
+        self.score_bytes(True, query, bytes, hw_counter)
+    }
+
+    /// Score two points inside encoded data by their indexes.
+    /// Inner product of unit residuals is estimated from the hamming distance of binary codes.
+    fn score_internal(
+        &self,
+        i: PointOffsetType,
+        j: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> f32 {
+        let bytes_i = self.encoded_vectors.get_vector_data(i);
+        let bytes_j = self.encoded_vectors.get_vector_data(j);
+
+        hw_counter
+            .vector_io_read()
+            .incr_delta(bytes_i.len() + bytes_j.len());
+        hw_counter.cpu_counter().incr_delta(bytes_i.len());
+
+        let (factors_i, code_i) = bytes_i.split_at(FACTORS_SIZE);
+        let (factors_j, code_j) = bytes_j.split_at(FACTORS_SIZE);
+        let factors_i = VectorFactors::from_bytes(factors_i);
+        let factors_j = VectorFactors::from_bytes(factors_j);
+
+        let different_bits: u32 = code_i
+            .chunks_exact(size_of::<u64>())
+            .zip(code_j.chunks_exact(size_of::<u64>()))
+            .map(|(a, b)| {
+                let a = u64::from_ne_bytes(a.try_into().unwrap());
+                let b = u64::from_ne_bytes(b.try_into().unwrap());
+                (a ^ b).count_ones()
+            })
+            .sum();
+        let padded_dim = self.rotation.padded_dim as f32;
+        let codes_ip = (padded_dim - 2.0 * different_bits as f32) / padded_dim;
+        // Both sides are quantized, so the estimation may leave the valid range of unit vectors
+        let unit_ip = (codes_ip
+            / (factors_i.quantized_ip * factors_j.quantized_ip).max(f32::EPSILON))
+        .clamp(-1.0, 1.0);
+        let residuals_ip = factors_i.residual_norm * factors_j.residual_norm * unit_ip;
+
+        let score = match self.metadata.vector_parameters.distance_type {
+            DistanceType::Dot => {
+                residuals_ip + factors_i.centroid_ip + factors_j.centroid_ip + self.centroid_norm_sq
+            }
+            DistanceType::L2 | DistanceType::L1 => {
+                factors_i.residual_norm * factors_i.residual_norm
+                    + factors_j.residual_norm * factors_j.residual_norm
+                    - 2.0 * residuals_ip
+            }
+        };
+
+        if self.metadata.vector_parameters.invert {
+            -score
+        } else {
+            score
+        }
+    }
+
+    fn quantized_vector_size(&self) -> usize {
+        Self::get_quantized_vector_size(&self.metadata.vector_parameters)
+    }
+
+    fn encode_internal_vector(&self, _id: PointOffsetType) -> Option<EncodedQueryRabitq> {
+        // Stored vector can't be restored from the binary code without accuracy loss
+        None
+    }
+
+    fn upsert_vector(
+        &mut self,
+        id: PointOffsetType,
+        vector: &[f32],
+        hw_counter: &HardwareCounterCell,
+    ) -> std::io::Result<()> {
+        let encoded_vector = Self::encode_vector(vector, &self.metadata, &self.rotation);
+        self.encoded_vectors
+            .upsert_vector(id, &encoded_vector, hw_counter)
+    }
+
+    fn vectors_count(&self) -> usize {
+        self.encoded_vectors.vectors_count()
+    }
+
+    fn flusher(&self) -> MmapFlusher {
+        self.encoded_vectors.flusher()
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.encoded_vectors.files();
+        if let Some(meta_path) = &self.metadata_path {
+            files.push(meta_path.clone());
+        }
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        let mut files = self.encoded_vectors.immutable_files();
+        if let Some(meta_path) = &self.metadata_path {
+            files.push(meta_path.clone());
+        }
+        files
+    }
+
+    type SupportsBytes = True;
+    fn score_bytes(
+        &self,
+        _: Self::SupportsBytes,
+        query: &Self::EncodedQuery,
+        bytes: &[u8],
+        hw_counter: &HardwareCounterCell,
+    ) -> f32 {
+        hw_counter.cpu_counter().incr_delta(bytes.len());
+        self.score_bytes_with_error_bound(query, bytes, DEFAULT_ERROR_BOUND_CONFIDENCE)
+            .0
+    }
+}
+
+fn walsh_hadamard_transform(values: &mut [f32]) {
+    debug_assert!(values.len().is_power_of_two());
+    let mut half = 1;
+    while half < values.len() {
+        for block in values.chunks_exact_mut(2 * half) {
+            let (left, right) = block.split_at_mut(half);
+            for (a, b) in left.iter_mut().zip(right.iter_mut()) {
+                let (x, y) = (*a, *b);
+                *a = x + y;
+                *b = x - y;
+            }
+        }
+        half *= 2;
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn norm(a: &[f32]) -> f32 {
+    dot(a, a).sqrt()
+}
@@ -18,6 +18,47 @@ use crate::quantile::{find_min_max_from_iter, find_quantile_interval};
 
 pub const ALIGNMENT: usize = 16;
 
+/// Alignment of 4-bit vectors, each aligned block is packed into `ALIGNMENT` bytes
+pub const ALIGNMENT_INT4: usize = 2 * ALIGNMENT;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScalarQuantizationMethod {
+    /// Each dimension is encoded into one byte
+    #[default]
+    Int8,
+    /// Each dimension is encoded into 4 bits, two dimensions per byte
+    ///
+    /// Within each block of `ALIGNMENT_INT4` dimensions, byte `i` holds dimension `i` in the
+    /// low nibble and dimension `i + ALIGNMENT` in the high nibble.
+    Int4,
+}
+
+impl ScalarQuantizationMethod {
+    fn alignment(self) -> usize {
+        match self {
+            ScalarQuantizationMethod::Int8 => ALIGNMENT,
+            ScalarQuantizationMethod::Int4 => ALIGNMENT_INT4,
+        }
+    }
+
+    /// Maximal encoded value
+    fn max_value(self) -> f32 {
+        match self {
+            ScalarQuantizationMethod::Int8 => 127.0,
+            ScalarQuantizationMethod::Int4 => 15.0,
+        }
+    }
+
+    /// Size of encoded dimensions in bytes, without the vector offset
+    fn code_size(self, actual_dim: usize) -> usize {
+        match self {
+            ScalarQuantizationMethod::Int8 => actual_dim,
+            ScalarQuantizationMethod::Int4 => actual_dim / 2,
+        }
+    }
+}
+
 pub struct EncodedVectorsU8<TStorage: EncodedStorage> {
     encoded_vectors: TStorage,
     metadata: Metadata,
@@ -36,6 +77,8 @@ struct Metadata {
     offset: f32,
     multiplier: f32,
     vector_parameters: VectorParameters,
+    #[serde(default)]
+    method: ScalarQuantizationMethod,
 }
 
 impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
@@ -44,15 +87,38 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
     }
 
     pub fn encode<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        storage_builder: impl EncodedStorageBuilder<Storage = TStorage>,
+        vector_parameters: &VectorParameters,
+        count: usize,
+        quantile: Option<f32>,
+        meta_path: Option<&Path>,
+        stopped: &AtomicBool,
+    ) -> Result<Self, EncodingError> {
+        Self::encode_with_method(
+            orig_data,
+            storage_builder,
+            vector_parameters,
+            ScalarQuantizationMethod::Int8,
+            count,
+            quantile,
+            meta_path,
+            stopped,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn encode_with_method<'a>(
         orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
         mut storage_builder: impl EncodedStorageBuilder<Storage = TStorage>,
         vector_parameters: &VectorParameters,
+        method: ScalarQuantizationMethod,
         count: usize,
         quantile: Option<f32>,
         meta_path: Option<&Path>,
         stopped: &AtomicBool,
     ) -> Result<Self, EncodingError> {
-        let actual_dim = Self::get_actual_dim(vector_parameters);
+        let actual_dim = Self::get_actual_dim_for_method(vector_parameters.dim, method);
 
         if count == 0 {
             let metadata = Metadata {
@@ -61,6 +127,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                 offset: 0.0,
                 multiplier: 0.0,
                 vector_parameters: vector_parameters.clone(),
+                method,
             };
             if let Some(meta_path) = meta_path {
                 meta_path
@@ -91,12 +158,12 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
         }
 
         debug_assert!(validate_vector_parameters(orig_data.clone(), vector_parameters).is_ok());
-        let (alpha, offset) = Self::find_alpha_offset_size_dim(orig_data.clone());
+        let (alpha, offset) = Self::find_alpha_offset_size_dim(orig_data.clone(), method);
         let (alpha, offset) = if let Some(quantile) = quantile {
             if let Some((min, max)) =
                 find_quantile_interval(orig_data.clone(), vector_parameters.dim, count, quantile)
             {
-                Self::alpha_offset_from_min_max(min, max)
+                Self::alpha_offset_from_min_max(min, max, method)
             } else {
                 (alpha, offset)
             }
@@ -112,16 +179,16 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
             let mut encoded_vector = Vec::with_capacity(actual_dim + std::mem::size_of::<f32>());
             encoded_vector.extend_from_slice(&f32::default().to_ne_bytes());
             for &value in vector.as_ref() {
-                let encoded = Self::f32_to_u8(value, alpha, offset);
+                let encoded = Self::f32_to_u8(value, alpha, offset, method);
                 encoded_vector.push(encoded);
             }
-            if !vector_parameters.dim.is_multiple_of(ALIGNMENT) {
-                for _ in 0..(ALIGNMENT - vector_parameters.dim % ALIGNMENT) {
+            if !vector_parameters.dim.is_multiple_of(method.alignment()) {
+                for _ in 0..(method.alignment() - vector_parameters.dim % method.alignment()) {
                     let placeholder = match vector_parameters.distance_type {
                         DistanceType::Dot => 0.0,
                         DistanceType::L1 | DistanceType::L2 => offset,
                     };
-                    let encoded = Self::f32_to_u8(placeholder, alpha, offset);
+                    let encoded = Self::f32_to_u8(placeholder, alpha, offset, method);
                     encoded_vector.push(encoded);
                 }
             }
@@ -148,6 +215,9 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
             };
             encoded_vector[0..std::mem::size_of::<f32>()]
                 .copy_from_slice(&vector_offset.to_ne_bytes());
+            if method == ScalarQuantizationMethod::Int4 {
+                pack_int4(&mut encoded_vector, std::mem::size_of::<f32>());
+            }
             storage_builder
                 .push_vector_data(&encoded_vector)
                 .map_err(|e| {
@@ -175,6 +245,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
             offset,
             multiplier,
             vector_parameters: vector_parameters.clone(),
+            method,
         };
         if let Some(meta_path) = meta_path {
             meta_path
@@ -217,13 +288,30 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
     pub fn score_point_simple(&self, query: &EncodedQueryU8, i: u32) -> f32 {
         let (vector_offset, v_ptr) = self.get_vec_ptr(i);
 
-        let score = match self.metadata.vector_parameters.distance_type {
-            DistanceType::Dot | DistanceType::L2 => impl_score_dot(
+        let score = match (
+            self.metadata.method,
+            self.metadata.vector_parameters.distance_type,
+        ) {
+            (ScalarQuantizationMethod::Int8, DistanceType::Dot | DistanceType::L2) => {
+                impl_score_dot(
+                    query.encoded_query.as_ptr(),
+                    v_ptr,
+                    self.metadata.actual_dim,
+                )
+            }
+            (ScalarQuantizationMethod::Int8, DistanceType::L1) => impl_score_l1(
                 query.encoded_query.as_ptr(),
                 v_ptr,
                 self.metadata.actual_dim,
             ),
-            DistanceType::L1 => impl_score_l1(
+            (ScalarQuantizationMethod::Int4, DistanceType::Dot | DistanceType::L2) => {
+                impl_score_dot_int4(
+                    query.encoded_query.as_ptr(),
+                    v_ptr,
+                    self.metadata.actual_dim,
+                )
+            }
+            (ScalarQuantizationMethod::Int4, DistanceType::L1) => impl_score_l1_int4(
                 query.encoded_query.as_ptr(),
                 v_ptr,
                 self.metadata.actual_dim,
@@ -237,17 +325,24 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
     pub fn score_point_neon(&self, query: &EncodedQueryU8, i: u32) -> f32 {
         unsafe {
             let (vector_offset, v_ptr) = self.get_vec_ptr(i);
-            let score = match self.metadata.vector_parameters.distance_type {
-                DistanceType::Dot | DistanceType::L2 => impl_score_dot_neon(
-                    query.encoded_query.as_ptr(),
-                    v_ptr,
-                    self.metadata.actual_dim as u32,
-                ),
-                DistanceType::L1 => impl_score_l1_neon(
-                    query.encoded_query.as_ptr(),
-                    v_ptr,
-                    self.metadata.actual_dim as u32,
-                ),
+            let q_ptr = query.encoded_query.as_ptr();
+            let dim = self.metadata.actual_dim as u32;
+            let score = match (
+                self.metadata.method,
+                self.metadata.vector_parameters.distance_type,
+            ) {
+                (ScalarQuantizationMethod::Int8, DistanceType::Dot | DistanceType::L2) => {
+                    impl_score_dot_neon(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int8, DistanceType::L1) => {
+                    impl_score_l1_neon(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int4, DistanceType::Dot | DistanceType::L2) => {
+                    impl_score_dot_int4_neon(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int4, DistanceType::L1) => {
+                    impl_score_l1_int4_neon(q_ptr, v_ptr, dim)
+                }
             };
             self.metadata.multiplier * score + query.offset + vector_offset
         }
@@ -257,17 +352,24 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
     pub fn score_point_sse(&self, query: &EncodedQueryU8, i: u32) -> f32 {
         unsafe {
             let (vector_offset, v_ptr) = self.get_vec_ptr(i);
-            let score = match self.metadata.vector_parameters.distance_type {
-                DistanceType::Dot | DistanceType::L2 => impl_score_dot_sse(
-                    query.encoded_query.as_ptr(),
-                    v_ptr,
-                    self.metadata.actual_dim as u32,
-                ),
-                DistanceType::L1 => impl_score_l1_sse(
-                    query.encoded_query.as_ptr(),
-                    v_ptr,
-                    self.metadata.actual_dim as u32,
-                ),
+            let q_ptr = query.encoded_query.as_ptr();
+            let dim = self.metadata.actual_dim as u32;
+            let score = match (
+                self.metadata.method,
+                self.metadata.vector_parameters.distance_type,
+            ) {
+                (ScalarQuantizationMethod::Int8, DistanceType::Dot | DistanceType::L2) => {
+                    impl_score_dot_sse(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int8, DistanceType::L1) => {
+                    impl_score_l1_sse(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int4, DistanceType::Dot | DistanceType::L2) => {
+                    impl_score_dot_int4_sse(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int4, DistanceType::L1) => {
+                    impl_score_l1_int4_sse(q_ptr, v_ptr, dim)
+                }
             };
             self.metadata.multiplier * score + query.offset + vector_offset
         }
@@ -277,38 +379,103 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
     pub fn score_point_avx(&self, query: &EncodedQueryU8, i: u32) -> f32 {
         unsafe {
             let (vector_offset, v_ptr) = self.get_vec_ptr(i);
-            let score = match self.metadata.vector_parameters.distance_type {
-                DistanceType::Dot | DistanceType::L2 => impl_score_dot_avx(
-                    query.encoded_query.as_ptr(),
-                    v_ptr,
-                    self.metadata.actual_dim as u32,
-                ),
-                DistanceType::L1 => impl_score_l1_avx(
-                    query.encoded_query.as_ptr(),
-                    v_ptr,
-                    self.metadata.actual_dim as u32,
-                ),
+            let q_ptr = query.encoded_query.as_ptr();
+            let dim = self.metadata.actual_dim as u32;
+            let score = match (
+                self.metadata.method,
+                self.metadata.vector_parameters.distance_type,
+            ) {
+                (ScalarQuantizationMethod::Int8, DistanceType::Dot | DistanceType::L2) => {
+                    impl_score_dot_avx(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int8, DistanceType::L1) => {
+                    impl_score_l1_avx(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int4, DistanceType::Dot | DistanceType::L2) => {
+                    impl_score_dot_int4_avx(q_ptr, v_ptr, dim)
+                }
+                (ScalarQuantizationMethod::Int4, DistanceType::L1) => {
+                    impl_score_l1_int4_avx(q_ptr, v_ptr, dim)
+                }
             };
             self.metadata.multiplier * score + query.offset + vector_offset
         }
     }
 
+    /// Scores an unpacked 4-bit query against a packed 4-bit vector, without offsets
+    fn score_int4(&self, q_ptr: *const u8, v_ptr: *const u8) -> f32 {
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx2") {
+            unsafe {
+                return match self.metadata.vector_parameters.distance_type {
+                    DistanceType::Dot | DistanceType::L2 => {
+                        impl_score_dot_int4_avx(q_ptr, v_ptr, self.metadata.actual_dim as u32)
+                    }
+                    DistanceType::L1 => {
+                        impl_score_l1_int4_avx(q_ptr, v_ptr, self.metadata.actual_dim as u32)
+                    }
+                };
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if is_x86_feature_detected!("sse4.1") {
+            unsafe {
+                return match self.metadata.vector_parameters.distance_type {
+                    DistanceType::Dot | DistanceType::L2 => {
+                        impl_score_dot_int4_sse(q_ptr, v_ptr, self.metadata.actual_dim as u32)
+                    }
+                    DistanceType::L1 => {
+                        impl_score_l1_int4_sse(q_ptr, v_ptr, self.metadata.actual_dim as u32)
+                    }
+                };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe {
+                return match self.metadata.vector_parameters.distance_type {
+                    DistanceType::Dot | DistanceType::L2 => {
+                        impl_score_dot_int4_neon(q_ptr, v_ptr, self.metadata.actual_dim as u32)
+                    }
+                    DistanceType::L1 => {
+                        impl_score_l1_int4_neon(q_ptr, v_ptr, self.metadata.actual_dim as u32)
+                    }
+                };
+            }
+        }
+
+        let score = match self.metadata.vector_parameters.distance_type {
+            DistanceType::Dot | DistanceType::L2 => {
+                impl_score_dot_int4(q_ptr, v_ptr, self.metadata.actual_dim)
+            }
+            DistanceType::L1 => impl_score_l1_int4(q_ptr, v_ptr, self.metadata.actual_dim),
+        };
+        score as f32
+    }
+
     fn find_alpha_offset_size_dim<'a>(
         orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        method: ScalarQuantizationMethod,
     ) -> (f32, f32) {
         let (min, max) = find_min_max_from_iter(orig_data);
-        Self::alpha_offset_from_min_max(min, max)
+        Self::alpha_offset_from_min_max(min, max, method)
     }
 
-    fn alpha_offset_from_min_max(min: f32, max: f32) -> (f32, f32) {
-        let alpha = (max - min) / 127.0;
+    fn alpha_offset_from_min_max(
+        min: f32,
+        max: f32,
+        method: ScalarQuantizationMethod,
+    ) -> (f32, f32) {
+        let alpha = (max - min) / method.max_value();
         let offset = min;
         (alpha, offset)
     }
 
-    fn f32_to_u8(i: f32, alpha: f32, offset: f32) -> u8 {
+    fn f32_to_u8(i: f32, alpha: f32, offset: f32, method: ScalarQuantizationMethod) -> u8 {
         let i = (i - offset) / alpha;
-        i.clamp(0.0, 127.0) as u8
+        i.clamp(0.0, method.max_value()) as u8
     }
 
     #[inline]
@@ -337,14 +504,28 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
 
     pub fn get_quantized_vector_offset_and_code(&self, i: PointOffsetType) -> (f32, &[u8]) {
         let (offset, v_ptr) = self.get_vec_ptr(i);
-        let vector_data_size = self.metadata.actual_dim;
+        let vector_data_size = self.metadata.method.code_size(self.metadata.actual_dim);
         let code = unsafe { std::slice::from_raw_parts(v_ptr, vector_data_size) };
         (offset, code)
     }
 
     pub fn get_quantized_vector_size(vector_parameters: &VectorParameters) -> usize {
-        let actual_dim = Self::get_actual_dim(vector_parameters);
-        actual_dim + std::mem::size_of::<f32>()
+        Self::get_quantized_vector_size_from_params(
+            vector_parameters.dim,
+            ScalarQuantizationMethod::Int8,
+        )
+    }
+
+    pub fn get_quantized_vector_size_from_params(
+        dim: usize,
+        method: ScalarQuantizationMethod,
+    ) -> usize {
+        let actual_dim = Self::get_actual_dim_for_method(dim, method);
+        method.code_size(actual_dim) + std::mem::size_of::<f32>()
+    }
+
+    pub fn get_method(&self) -> ScalarQuantizationMethod {
+        self.metadata.method
     }
 
     pub fn get_multiplier(&self) -> f32 {
@@ -361,7 +542,12 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
     }
 
     pub fn get_actual_dim(vector_parameters: &VectorParameters) -> usize {
-        vector_parameters.dim + (ALIGNMENT - vector_parameters.dim % ALIGNMENT) % ALIGNMENT
+        Self::get_actual_dim_for_method(vector_parameters.dim, ScalarQuantizationMethod::Int8)
+    }
+
+    pub fn get_actual_dim_for_method(dim: usize, method: ScalarQuantizationMethod) -> usize {
+        let alignment = method.alignment();
+        dim + (alignment - dim % alignment) % alignment
     }
 }
 
@@ -373,19 +559,24 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
     }
 
     fn encode_query(&self, query: &[f32]) -> EncodedQueryU8 {
+        let method = self.metadata.method;
         let dim = query.len();
         let mut query: Vec<_> = query
             .iter()
-            .map(|&v| Self::f32_to_u8(v, self.metadata.alpha, self.metadata.offset))
+            .map(|&v| Self::f32_to_u8(v, self.metadata.alpha, self.metadata.offset, method))
             .collect();
-        if !dim.is_multiple_of(ALIGNMENT) {
-            for _ in 0..(ALIGNMENT - dim % ALIGNMENT) {
+        if !dim.is_multiple_of(method.alignment()) {
+            for _ in 0..(method.alignment() - dim % method.alignment()) {
                 let placeholder = match self.metadata.vector_parameters.distance_type {
                     DistanceType::Dot => 0.0,
                     DistanceType::L1 | DistanceType::L2 => self.metadata.offset,
                 };
-                let encoded =
-                    Self::f32_to_u8(placeholder, self.metadata.alpha, self.metadata.offset);
+                let encoded = Self::f32_to_u8(
+                    placeholder,
+                    self.metadata.alpha,
+                    self.metadata.offset,
+                    method,
+                );
                 query.push(encoded);
             }
         }
@@ -450,6 +641,18 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
         };
         let offset = query_offset + vector_offset - diff;
 
+        if self.metadata.method == ScalarQuantizationMethod::Int4 {
+            let score = match self.metadata.vector_parameters.distance_type {
+                DistanceType::Dot | DistanceType::L2 => {
+                    impl_score_dot_packed_int4(q_ptr, v_ptr, self.metadata.actual_dim)
+                }
+                DistanceType::L1 => {
+                    impl_score_l1_packed_int4(q_ptr, v_ptr, self.metadata.actual_dim)
+                }
+            };
+            return self.metadata.multiplier * score as f32 + offset;
+        }
+
         #[cfg(target_arch = "x86_64")]
         if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
             unsafe {
@@ -509,9 +712,9 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
     }
 
     fn quantized_vector_size(&self) -> usize {
-        // actual_dim rounds up vector_dimension to the next multiple of ALIGNMENT
+        // actual_dim rounds up vector_dimension to the next multiple of the alignment
         // also add scaling factor to the tally
-        self.metadata.actual_dim + std::mem::size_of::<f32>()
+        self.metadata.method.code_size(self.metadata.actual_dim) + std::mem::size_of::<f32>()
     }
 
     fn encode_internal_vector(&self, id: PointOffsetType) -> Option<EncodedQueryU8> {
@@ -531,11 +734,15 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
         } else {
             query_offset - offset_difference
         };
+        let code_size = self.metadata.method.code_size(self.metadata.actual_dim);
+        let code = unsafe { std::slice::from_raw_parts(q_ptr, code_size) };
+        let encoded_query = match self.metadata.method {
+            ScalarQuantizationMethod::Int8 => code.to_vec(),
+            ScalarQuantizationMethod::Int4 => unpack_int4(code),
+        };
         Some(EncodedQueryU8 {
             offset: query_offset,
-            encoded_query: unsafe {
-                std::slice::from_raw_parts(q_ptr, self.metadata.actual_dim).to_vec()
-            },
+            encoded_query,
         })
     }
 
@@ -588,11 +795,20 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
             .cpu_counter()
             .incr_delta(self.metadata.vector_parameters.dim);
 
-        debug_assert!(bytes.len() >= std::mem::size_of::<f32>() + self.metadata.actual_dim);
+        debug_assert!(
+            bytes.len()
+                >= std::mem::size_of::<f32>()
+                    + self.metadata.method.code_size(self.metadata.actual_dim)
+        );
 
         let (vector_offset, v_ptr) = Self::parse_vec_data(bytes);
         let q_ptr = query.encoded_query.as_ptr();
 
+        if self.metadata.method == ScalarQuantizationMethod::Int4 {
+            let score = self.score_int4(q_ptr, v_ptr);
+            return self.metadata.multiplier * score + query.offset + vector_offset;
+        }
+
         #[cfg(target_arch = "x86_64")]
         if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
             unsafe {
@@ -672,6 +888,97 @@ fn impl_score_l1(q_ptr: *const u8, v_ptr: *const u8, actual_dim: usize) -> i32 {
     }
 }
 
+/// Packs 4-bit values, stored one per byte after `start`, two per byte in place
+///
+/// See [`ScalarQuantizationMethod::Int4`] for the layout.
+fn pack_int4(data: &mut Vec<u8>, start: usize) {
+    let dim = data.len() - start;
+    debug_assert!(dim.is_multiple_of(ALIGNMENT_INT4));
+    // Packed block is written before the next unpacked block, so it is safe to pack in place
+    for block in 0..dim / ALIGNMENT_INT4 {
+        let src = start + block * ALIGNMENT_INT4;
+        let dst = start + block * ALIGNMENT;
+        for i in 0..ALIGNMENT {
+            data[dst + i] = data[src + i] | (data[src + i + ALIGNMENT] << 4);
+        }
+    }
+    data.truncate(start + dim / 2);
+}
+
+fn unpack_int4(code: &[u8]) -> Vec<u8> {
+    let mut unpacked = vec![0; code.len() * 2];
+    for (block, packed) in code.chunks_exact(ALIGNMENT).enumerate() {
+        let unpacked = &mut unpacked[block * ALIGNMENT_INT4..(block + 1) * ALIGNMENT_INT4];
+        for (i, &value) in packed.iter().enumerate() {
+            unpacked[i] = value & 0x0F;
+            unpacked[i + ALIGNMENT] = value >> 4;
+        }
+    }
+    unpacked
+}
+
+/// Scores an unpacked 4-bit query against a packed 4-bit vector
+fn impl_score_dot_int4(q_ptr: *const u8, v_ptr: *const u8, actual_dim: usize) -> i32 {
+    unsafe {
+        let mut score = 0i32;
+        for block in 0..actual_dim / ALIGNMENT_INT4 {
+            let q_ptr = q_ptr.add(block * ALIGNMENT_INT4);
+            let v_ptr = v_ptr.add(block * ALIGNMENT);
+            for i in 0..ALIGNMENT {
+                let v = *v_ptr.add(i);
+                score += i32::from(*q_ptr.add(i)) * i32::from(v & 0x0F);
+                score += i32::from(*q_ptr.add(i + ALIGNMENT)) * i32::from(v >> 4);
+            }
+        }
+        score
+    }
+}
+
+/// Scores an unpacked 4-bit query against a packed 4-bit vector
+fn impl_score_l1_int4(q_ptr: *const u8, v_ptr: *const u8, actual_dim: usize) -> i32 {
+    unsafe {
+        let mut score = 0i32;
+        for block in 0..actual_dim / ALIGNMENT_INT4 {
+            let q_ptr = q_ptr.add(block * ALIGNMENT_INT4);
+            let v_ptr = v_ptr.add(block * ALIGNMENT);
+            for i in 0..ALIGNMENT {
+                let v = *v_ptr.add(i);
+                score += i32::from((*q_ptr.add(i)).abs_diff(v & 0x0F));
+                score += i32::from((*q_ptr.add(i + ALIGNMENT)).abs_diff(v >> 4));
+            }
+        }
+        score
+    }
+}
+
+/// Scores two packed 4-bit vectors
+fn impl_score_dot_packed_int4(a_ptr: *const u8, b_ptr: *const u8, actual_dim: usize) -> i32 {
+    unsafe {
+        let mut score = 0i32;
+        for i in 0..actual_dim / 2 {
+            let a = *a_ptr.add(i);
+            let b = *b_ptr.add(i);
+            score += i32::from(a & 0x0F) * i32::from(b & 0x0F);
+            score += i32::from(a >> 4) * i32::from(b >> 4);
+        }
+        score
+    }
+}
+
+/// Scores two packed 4-bit vectors
+fn impl_score_l1_packed_int4(a_ptr: *const u8, b_ptr: *const u8, actual_dim: usize) -> i32 {
+    unsafe {
+        let mut score = 0i32;
+        for i in 0..actual_dim / 2 {
+            let a = *a_ptr.add(i);
+            let b = *b_ptr.add(i);
+            score += i32::from((a & 0x0F).abs_diff(b & 0x0F));
+            score += i32::from((a >> 4).abs_diff(b >> 4));
+        }
+        score
+    }
+}
+
 #[cfg(target_arch = "x86_64")]
 unsafe extern "C" {
     fn impl_score_dot_avx(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
@@ -679,10 +986,19 @@ unsafe extern "C" {
 
     fn impl_score_dot_sse(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
     fn impl_score_l1_sse(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+
+    fn impl_score_dot_int4_avx(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+    fn impl_score_l1_int4_avx(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+
+    fn impl_score_dot_int4_sse(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+    fn impl_score_l1_int4_sse(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 unsafe extern "C" {
     fn impl_score_dot_neon(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
     fn impl_score_l1_neon(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+
+    fn impl_score_dot_int4_neon(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+    fn impl_score_l1_int4_neon(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
 }
@@ -0,0 +1,301 @@
+//! Optimized product quantization (OPQ).
+//!
+//! Product quantization splits vectors into contiguous chunks, which is far from optimal
+//! if dimensions are correlated. OPQ learns an orthogonal rotation of the vector space
+//! which minimizes the product quantization error. Rotation is trained by alternating
+//! between training of chunk codebooks on rotated data and solving the orthogonal
+//! Procrustes problem for the reconstructed data.
+
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::ThreadPool;
+use rayon::prelude::*;
+
+use crate::EncodingError;
+use crate::kmeans::kmeans;
+
+/// Count of alternations between codebooks and rotation training.
+pub const OPQ_ITERATIONS: usize = 4;
+/// Max kmeans iterations of the intermediate codebooks.
+pub const OPQ_KMEANS_MAX_ITERATIONS: usize = 10;
+/// Max iterations of the polar decomposition.
+const POLAR_MAX_ITERATIONS: usize = 30;
+const POLAR_ACCURACY: f32 = 1e-5;
+/// Seed of the initial random rotation, fixed for reproducible encoding.
+const INITIAL_ROTATION_SEED: u64 = 42;
+
+/// Learn an orthogonal rotation for product quantization.
+///
+/// # Arguments
+/// * `sample` - flattened training vectors
+/// * `dim` - dimension of vectors
+/// * `vector_division` - division of vectors into chunks
+/// * `centroids_count` - count of centroids for each chunk
+/// * `max_threads` - max allowed threads for training
+/// * `accuracy` - kmeans accuracy of the intermediate codebooks
+/// * `stopped` - Atomic bool that indicates if training should be stopped
+///
+/// Returns rotation matrix in row-major order.
+pub fn train_rotation(
+    sample: &[f32],
+    dim: usize,
+    vector_division: &[Range<usize>],
+    centroids_count: usize,
+    max_threads: usize,
+    accuracy: f32,
+    stopped: &AtomicBool,
+) -> Result<Vec<f32>, EncodingError> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .thread_name(|idx| format!("opq-{idx}"))
+        .num_threads(std::cmp::max(1, max_threads))
+        .build()
+        .map_err(|e| {
+            EncodingError::EncodingError(format!("Failed OPQ training while thread pool init: {e}"))
+        })?;
+
+    let count = sample.len() / dim;
+    if count < centroids_count {
+        // Not enough data to train codebooks, keep the original space
+        return Ok(identity(dim));
+    }
+
+    // Start from random rotation. Identity is a stationary point of the alternation,
+    // if the covariance is already diagonal.
+    let mut rng = StdRng::seed_from_u64(INITIAL_ROTATION_SEED);
+    let mut rotation: Vec<f32> = (0..dim * dim)
+        .map(|_| rng.random_range(-1.0..1.0))
+        .collect();
+    orthonormalize(&mut rotation, dim);
+
+    for _ in 0..OPQ_ITERATIONS {
+        if stopped.load(Ordering::Relaxed) {
+            return Err(EncodingError::Stopped);
+        }
+
+        let rotated = rotate_all(&pool, sample, &rotation, dim);
+
+        // Reconstruct rotated vectors by the nearest centroids of each chunk
+        let mut reconstructed = vec![0.0; rotated.len()];
+        for range in vector_division {
+            let chunk: Vec<f32> = rotated
+                .chunks_exact(dim)
+                .flat_map(|vector| vector[range.clone()].iter().copied())
+                .collect();
+            let centroids = kmeans(
+                &chunk,
+                centroids_count,
+                range.len(),
+                OPQ_KMEANS_MAX_ITERATIONS,
+                max_threads,
+                accuracy,
+                stopped,
+            )?;
+            pool.install(|| {
+                reconstructed
+                    .par_chunks_exact_mut(dim)
+                    .zip(chunk.par_chunks_exact(range.len()))
+                    .for_each(|(reconstructed, subvector)| {
+                        let nearest = nearest_centroid(subvector, &centroids);
+                        reconstructed[range.clone()].copy_from_slice(nearest);
+                    });
+            });
+        }
+
+        // Orthogonal Procrustes: the best rotation of `x` into `y` is the orthogonal factor
+        // of the polar decomposition of the cross-covariance `Y^T X`
+        let cross_covariance = transposed_product(&pool, &reconstructed, sample, dim);
+        rotation = orthogonal_factor(&pool, cross_covariance, dim, stopped)?;
+    }
+
+    Ok(rotation)
+}
+
+/// Apply rotation to a single vector.
+pub fn rotate(rotation: &[f32], vector: &[f32]) -> Vec<f32> {
+    rotation
+        .chunks_exact(vector.len())
+        .map(|row| row.iter().zip(vector).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+fn rotate_all(pool: &ThreadPool, data: &[f32], rotation: &[f32], dim: usize) -> Vec<f32> {
+    let mut result = vec![0.0; data.len()];
+    pool.install(|| {
+        result
+            .par_chunks_exact_mut(dim)
+            .zip(data.par_chunks_exact(dim))
+            .for_each(|(result, vector)| {
+                for (value, row) in result.iter_mut().zip(rotation.chunks_exact(dim)) {
+                    *value = row.iter().zip(vector).map(|(a, b)| a * b).sum();
+                }
+            });
+    });
+    result
+}
+
+fn nearest_centroid<'a>(subvector: &[f32], centroids: &'a [f32]) -> &'a [f32] {
+    centroids
+        .chunks_exact(subvector.len())
+        .map(|centroid| {
+            let distance: f32 = subvector
+                .iter()
+                .zip(centroid)
+                .map(|(a, b)| (a - b).powi(2))
+                .sum();
+            (distance, centroid)
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, centroid)| centroid)
+        .unwrap_or_default()
+}
+
+fn identity(dim: usize) -> Vec<f32> {
+    let mut matrix = vec![0.0; dim * dim];
+    for i in 0..dim {
+        matrix[i * dim + i] = 1.0;
+    }
+    matrix
+}
+
+fn transpose(matrix: &[f32], dim: usize) -> Vec<f32> {
+    let mut result = vec![0.0; matrix.len()];
+    for i in 0..dim {
+        for j in 0..dim {
+            result[j * dim + i] = matrix[i * dim + j];
+        }
+    }
+    result
+}
+
+/// Product `A^T B` of two row-major matrices with `dim` columns each.
+fn transposed_product(pool: &ThreadPool, a: &[f32], b: &[f32], dim: usize) -> Vec<f32> {
+    let a_transposed: Vec<Vec<f32>> = (0..dim)
+        .map(|column| a.chunks_exact(dim).map(|row| row[column]).collect())
+        .collect();
+    let mut result = vec![0.0; dim * dim];
+    pool.install(|| {
+        result
+            .par_chunks_exact_mut(dim)
+            .zip(a_transposed.par_iter())
+            .for_each(|(result_row, a_column)| {
+                for (&a_value, b_row) in a_column.iter().zip(b.chunks_exact(dim)) {
+                    for (result, b_value) in result_row.iter_mut().zip(b_row) {
+                        *result += a_value * b_value;
+                    }
+                }
+            });
+    });
+    result
+}
+
+/// Product of two square row-major matrices.
+fn product(pool: &ThreadPool, a: &[f32], b: &[f32], dim: usize) -> Vec<f32> {
+    let mut result = vec![0.0; dim * dim];
+    pool.install(|| {
+        result
+            .par_chunks_exact_mut(dim)
+            .zip(a.par_chunks_exact(dim))
+            .for_each(|(result_row, a_row)| {
+                for (&a_value, b_row) in a_row.iter().zip(b.chunks_exact(dim)) {
+                    for (result, b_value) in result_row.iter_mut().zip(b_row) {
+                        *result += a_value * b_value;
+                    }
+                }
+            });
+    });
+    result
+}
+
+/// Orthonormalize rows of a square matrix by modified Gram-Schmidt process.
+/// Degenerate rows are replaced by basis vectors.
+fn orthonormalize(matrix: &mut [f32], dim: usize) {
+    for i in 0..dim {
+        let (done, rest) = matrix.split_at_mut(i * dim);
+        let row = &mut rest[..dim];
+        let mut basis = 0..dim;
+        loop {
+            for prev in done.chunks_exact(dim) {
+                let projection: f32 = prev.iter().zip(row.iter()).map(|(a, b)| a * b).sum();
+                row.iter_mut()
+                    .zip(prev)
+                    .for_each(|(value, prev)| *value -= projection * prev);
+            }
+            let norm = row.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm > POLAR_ACCURACY {
+                row.iter_mut().for_each(|x| *x /= norm);
+                break;
+            }
+            let Some(basis_index) = basis.next() else {
+                break;
+            };
+            row.fill(0.0);
+            row[basis_index] = 1.0;
+        }
+    }
+}
+
+/// Orthogonal factor of the polar decomposition, found by Newton-Schulz iterations.
+/// Directions with negligible singular values may not converge, so the result is
+/// orthonormalized at the end.
+fn orthogonal_factor(
+    pool: &ThreadPool,
+    mut matrix: Vec<f32>,
+    dim: usize,
+    stopped: &AtomicBool,
+) -> Result<Vec<f32>, EncodingError> {
+    // Scale singular values into the convergence region
+    let norm = matrix.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm <= f32::EPSILON {
+        return Ok(identity(dim));
+    }
+    matrix.iter_mut().for_each(|x| *x /= norm);
+
+    for _ in 0..POLAR_MAX_ITERATIONS {
+        if stopped.load(Ordering::Relaxed) {
+            return Err(EncodingError::Stopped);
+        }
+
+        let gram = product(pool, &transpose(&matrix, dim), &matrix, dim);
+        let correction = product(pool, &matrix, &gram, dim);
+        let mut change = 0.0;
+        for (value, correction) in matrix.iter_mut().zip(correction) {
+            let new_value = 1.5 * *value - 0.5 * correction;
+            change += (new_value - *value).powi(2);
+            *value = new_value;
+        }
+        if change.sqrt() < POLAR_ACCURACY * dim as f32 {
+            break;
+        }
+    }
+    orthonormalize(&mut matrix, dim);
+    Ok(matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orthogonal_factor() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let dim = 3;
+        // Rotation by 90 degrees around z axis, stretched along x
+        let matrix = vec![0.0, -1.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.5];
+        let orthogonal = orthogonal_factor(&pool, matrix, dim, &AtomicBool::new(false)).unwrap();
+        let expected = [0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+        for (value, expected) in orthogonal.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-3, "{orthogonal:?}");
+        }
+
+        let gram = product(&pool, &transpose(&orthogonal, dim), &orthogonal, dim);
+        for (value, expected) in gram.iter().zip(identity(dim)) {
+            assert!((value - expected).abs() < 1e-3);
+        }
+    }
+}
@@ -11,10 +11,14 @@ pub mod test_binary;
 #[cfg(test)]
 pub mod test_binary_encodings;
 #[cfg(test)]
+pub mod test_int4;
+#[cfg(test)]
 pub mod test_neon;
 #[cfg(test)]
 pub mod test_pq;
 #[cfg(test)]
+pub mod test_rabitq;
+#[cfg(test)]
 pub mod test_simple;
 #[cfg(test)]
 pub mod test_sse;
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use common::counter::hardware_counter::HardwareCounterCell;
+    use quantization::encoded_storage::{TestEncodedStorage, TestEncodedStorageBuilder};
+    use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
+    use quantization::encoded_vectors_u8::{EncodedVectorsU8, ScalarQuantizationMethod};
+    use rand::{Rng, SeedableRng};
+
+    use crate::metrics::{dot_similarity, l1_similarity, l2_similarity};
+
+    fn encode_int4(
+        vector_data: &[Vec<f32>],
+        vector_parameters: &VectorParameters,
+    ) -> EncodedVectorsU8<TestEncodedStorage> {
+        let quantized_vector_size =
+            EncodedVectorsU8::<TestEncodedStorage>::get_quantized_vector_size_from_params(
+                vector_parameters.dim,
+                ScalarQuantizationMethod::Int4,
+            );
+        EncodedVectorsU8::encode_with_method(
+            vector_data.iter(),
+            TestEncodedStorageBuilder::new(None, quantized_vector_size),
+            vector_parameters,
+            ScalarQuantizationMethod::Int4,
+            vector_data.len(),
+            None,
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap()
+    }
+
+    fn test_int4_impl(distance_type: DistanceType, invert: bool) {
+        let vectors_count = 129;
+        let vector_dim = 65;
+        let error = vector_dim as f32 * 0.2;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let mut vector_data: Vec<Vec<f32>> = Vec::new();
+        for _ in 0..vectors_count {
+            let vector: Vec<f32> = (0..vector_dim).map(|_| rng.random()).collect();
+            vector_data.push(vector);
+        }
+        let query: Vec<f32> = (0..vector_dim).map(|_| rng.random()).collect();
+
+        let vector_parameters = VectorParameters {
+            dim: vector_dim,
+            deprecated_count: None,
+            distance_type,
+            invert,
+        };
+        let encoded = encode_int4(&vector_data, &vector_parameters);
+
+        // 65 dimensions are aligned to 96, which are packed into 48 bytes
+        assert_eq!(encoded.quantized_vector_size(), 48 + size_of::<f32>());
+        assert_eq!(encoded.get_method(), ScalarQuantizationMethod::Int4);
+
+        let hw_counter = HardwareCounterCell::new();
+        let query_u8 = encoded.encode_query(&query);
+        for (index, vector) in vector_data.iter().enumerate() {
+            let score = encoded.score_point(&query_u8, index as u32, &hw_counter);
+            let simple_score = encoded.score_point_simple(&query_u8, index as u32);
+            assert!((score - simple_score).abs() < 1e-3);
+
+            let original_score = match distance_type {
+                DistanceType::Dot => dot_similarity(&query, vector),
+                DistanceType::L1 => l1_similarity(&query, vector),
+                DistanceType::L2 => l2_similarity(&query, vector),
+            };
+            let original_score = if invert {
+                -original_score
+            } else {
+                original_score
+            };
+            assert!((score - original_score).abs() < error);
+
+            #[cfg(target_arch = "x86_64")]
+            if is_x86_feature_detected!("avx2") {
+                let avx_score = encoded.score_point_avx(&query_u8, index as u32);
+                assert!((avx_score - simple_score).abs() < 1e-3);
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            if is_x86_feature_detected!("sse4.1") {
+                let sse_score = encoded.score_point_sse(&query_u8, index as u32);
+                assert!((sse_score - simple_score).abs() < 1e-3);
+            }
+
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+            {
+                let neon_score = encoded.score_point_neon(&query_u8, index as u32);
+                assert!((neon_score - simple_score).abs() < 1e-3);
+            }
+        }
+
+        // Scoring stored points against each other matches scoring an internal query
+        for i in 0..10 {
+            let internal_query = encoded.encode_internal_vector(i).unwrap();
+            for j in 0..vectors_count as u32 {
+                let score = encoded.score_internal(i, j, &hw_counter);
+                let query_score = encoded.score_point(&internal_query, j, &hw_counter);
+                assert!((score - query_score).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dot_int4() {
+        test_int4_impl(DistanceType::Dot, false);
+        test_int4_impl(DistanceType::Dot, true);
+    }
+
+    #[test]
+    fn test_l2_int4() {
+        test_int4_impl(DistanceType::L2, false);
+        test_int4_impl(DistanceType::L2, true);
+    }
+
+    #[test]
+    fn test_l1_int4() {
+        test_int4_impl(DistanceType::L1, false);
+        test_int4_impl(DistanceType::L1, true);
+    }
+}
@@ -4,6 +4,7 @@ mod tests {
     use std::time::Duration;
 
     use common::counter::hardware_counter::HardwareCounterCell;
+    use quantization::EncodingError;
     use quantization::encoded_storage::{TestEncodedStorage, TestEncodedStorageBuilder};
     use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
     use quantization::encoded_vectors_pq::EncodedVectorsPQ;
@@ -339,6 +340,142 @@ mod tests {
         }
     }
 
+    /// Vectors with a few high-variance correlated dimensions,
+    /// which are packed into the first chunks by plain PQ
+    fn correlated_vectors(rng: &mut impl Rng, count: usize) -> Vec<Vec<f32>> {
+        const LATENT_DIM: usize = 16;
+        let mixing: Vec<Vec<f32>> = (0..LATENT_DIM)
+            .map(|_| {
+                (0..LATENT_DIM)
+                    .map(|_| rng.random_range(-1.0..1.0))
+                    .collect()
+            })
+            .collect();
+        (0..count)
+            .map(|_| {
+                let latent: Vec<f32> = (0..LATENT_DIM)
+                    .map(|_| rng.random_range(-1.0..1.0))
+                    .collect();
+                (0..VECTOR_DIM)
+                    .map(|i| match mixing.get(i) {
+                        Some(row) => row.iter().zip(&latent).map(|(a, b)| a * b).sum(),
+                        None => rng.random_range(-0.01..0.01),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn mean_dot_error(
+        encoded: &EncodedVectorsPQ<TestEncodedStorage>,
+        vector_data: &[Vec<f32>],
+        query: &[f32],
+    ) -> f32 {
+        let query_u8 = encoded.encode_query(query);
+        let counter = HardwareCounterCell::new();
+        let total_error: f32 = vector_data
+            .iter()
+            .enumerate()
+            .map(|(index, vector)| {
+                let score = encoded.score_point(&query_u8, index as u32, &counter);
+                (score - dot_similarity(query, vector)).abs()
+            })
+            .sum();
+        total_error / vector_data.len() as f32
+    }
+
+    #[test]
+    fn test_pq_opq_dot() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vectors_count = 2 * VECTORS_COUNT;
+        let vector_data = correlated_vectors(&mut rng, vectors_count);
+        let query = correlated_vectors(&mut rng, 1).pop().unwrap();
+
+        let vector_parameters = VectorParameters {
+            dim: VECTOR_DIM,
+            deprecated_count: None,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+        let chunk_size = 8;
+        let quantized_vector_size =
+            EncodedVectorsPQ::<TestEncodedStorage>::get_quantized_vector_size(
+                &vector_parameters,
+                chunk_size,
+            );
+
+        let dir = tempfile::Builder::new().prefix("opq").tempdir().unwrap();
+        let data_path = dir.path().join("data.bin");
+        let meta_path = dir.path().join("meta.json");
+        let encode = |rotation: bool, data_path: Option<&std::path::Path>, meta_path| {
+            EncodedVectorsPQ::encode_with_rotation(
+                vector_data.iter(),
+                TestEncodedStorageBuilder::new(data_path, quantized_vector_size),
+                &vector_parameters,
+                vectors_count,
+                chunk_size,
+                rotation,
+                1,
+                meta_path,
+                &AtomicBool::new(false),
+            )
+            .unwrap()
+        };
+        let plain = encode(false, None, None);
+        let rotated = encode(true, Some(&data_path), Some(&meta_path));
+        assert!(plain.get_metadata().rotation.is_none());
+        assert!(rotated.get_metadata().rotation.is_some());
+
+        let plain_error = mean_dot_error(&plain, &vector_data, &query);
+        let rotated_error = mean_dot_error(&rotated, &vector_data, &query);
+        assert!(
+            rotated_error < plain_error,
+            "OPQ error {rotated_error} is not less than PQ error {plain_error}",
+        );
+
+        // Rotation is stored together with quantized data
+        let loaded = EncodedVectorsPQ::load(
+            TestEncodedStorage::from_file(&data_path, quantized_vector_size).unwrap(),
+            &meta_path,
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.get_metadata().rotation,
+            rotated.get_metadata().rotation,
+        );
+        assert_eq!(mean_dot_error(&loaded, &vector_data, &query), rotated_error);
+    }
+
+    #[test]
+    fn test_pq_opq_l1() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data = correlated_vectors(&mut rng, VECTORS_COUNT);
+
+        let vector_parameters = VectorParameters {
+            dim: VECTOR_DIM,
+            deprecated_count: None,
+            distance_type: DistanceType::L1,
+            invert: false,
+        };
+        let quantized_vector_size =
+            EncodedVectorsPQ::<TestEncodedStorage>::get_quantized_vector_size(
+                &vector_parameters,
+                1,
+            );
+        let result = EncodedVectorsPQ::encode_with_rotation(
+            vector_data.iter(),
+            TestEncodedStorageBuilder::new(None, quantized_vector_size),
+            &vector_parameters,
+            VECTORS_COUNT,
+            1,
+            true,
+            1,
+            None,
+            &AtomicBool::new(false),
+        );
+        assert!(matches!(result, Err(EncodingError::ArgumentsError(_))));
+    }
+
     // ignore this test because it requires long time
     // this test should be started separately of with `--test-threads=1` flag
     // because `num_threads::num_threads()` is used to check that all encode threads finished
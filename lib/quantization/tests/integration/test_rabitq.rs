@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use common::counter::hardware_counter::HardwareCounterCell;
+    use quantization::EncodingError;
+    use quantization::encoded_storage::{TestEncodedStorage, TestEncodedStorageBuilder};
+    use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
+    use quantization::encoded_vectors_rabitq::{
+        DEFAULT_ERROR_BOUND_CONFIDENCE, EncodedVectorsRabitq,
+    };
+    use rand::{Rng, SeedableRng};
+
+    use crate::metrics::{dot_similarity, l2_similarity};
+
+    /// Generate non-centred vectors: all of them share a common offset.
+    fn generate_vectors(count: usize, dim: usize, rng: &mut rand::rngs::StdRng) -> Vec<Vec<f32>> {
+        let offset: Vec<f32> = (0..dim).map(|_| rng.random_range(0.5..1.0)).collect();
+        (0..count)
+            .map(|_| {
+                offset
+                    .iter()
+                    .map(|x| x + rng.random_range(-0.5..0.5))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn encode_rabitq(
+        vector_data: &[Vec<f32>],
+        vector_parameters: &VectorParameters,
+    ) -> Result<EncodedVectorsRabitq<TestEncodedStorage>, EncodingError> {
+        let quantized_vector_size =
+            EncodedVectorsRabitq::<TestEncodedStorage>::get_quantized_vector_size(
+                vector_parameters,
+            );
+        EncodedVectorsRabitq::encode(
+            vector_data.iter(),
+            TestEncodedStorageBuilder::new(None, quantized_vector_size),
+            vector_parameters,
+            None,
+            &AtomicBool::new(false),
+        )
+    }
+
+    fn test_rabitq_impl(distance_type: DistanceType, invert: bool, vector_dim: usize) {
+        let vectors_count = 256;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data = generate_vectors(vectors_count, vector_dim, &mut rng);
+        let query = generate_vectors(1, vector_dim, &mut rng).pop().unwrap();
+
+        let vector_parameters = VectorParameters {
+            dim: vector_dim,
+            deprecated_count: None,
+            distance_type,
+            invert,
+        };
+        let encoded = encode_rabitq(&vector_data, &vector_parameters).unwrap();
+
+        let hw_counter = HardwareCounterCell::new();
+        let query_encoded = encoded.encode_query(&query);
+        let mut within_bound = 0;
+        for (index, vector) in vector_data.iter().enumerate() {
+            let (score, error) = encoded.score_point_with_error_bound(
+                &query_encoded,
+                index as u32,
+                DEFAULT_ERROR_BOUND_CONFIDENCE,
+                &hw_counter,
+            );
+            assert!(error >= 0.0);
+            assert_eq!(
+                score,
+                encoded.score_point(&query_encoded, index as u32, &hw_counter),
+            );
+
+            let original_score = match distance_type {
+                DistanceType::Dot => dot_similarity(&query, vector),
+                DistanceType::L2 => l2_similarity(&query, vector),
+                DistanceType::L1 => unreachable!(),
+            };
+            let original_score = if invert {
+                -original_score
+            } else {
+                original_score
+            };
+            if (score - original_score).abs() <= error {
+                within_bound += 1;
+            }
+        }
+        // Error bound is probabilistic and query is quantized as well, allow a few misses
+        assert!(
+            within_bound as f32 >= 0.95 * vectors_count as f32,
+            "only {within_bound} of {vectors_count} scores are within the error bound",
+        );
+
+        // Scoring stored points against each other approximates original scores
+        for i in 0..10 {
+            for j in 0..vectors_count as u32 {
+                let score = encoded.score_internal(i, j, &hw_counter);
+                let (query_i, vector_j) = (&vector_data[i as usize], &vector_data[j as usize]);
+                let original_score = match distance_type {
+                    DistanceType::Dot => dot_similarity(query_i, vector_j),
+                    DistanceType::L2 => l2_similarity(query_i, vector_j),
+                    DistanceType::L1 => unreachable!(),
+                };
+                let original_score = if invert {
+                    -original_score
+                } else {
+                    original_score
+                };
+                assert!((score - original_score).abs() < vector_dim as f32 * 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rabitq_dot() {
+        test_rabitq_impl(DistanceType::Dot, false, 128);
+        test_rabitq_impl(DistanceType::Dot, true, 128);
+        test_rabitq_impl(DistanceType::Dot, false, 65);
+    }
+
+    #[test]
+    fn test_rabitq_l2() {
+        test_rabitq_impl(DistanceType::L2, false, 128);
+        test_rabitq_impl(DistanceType::L2, true, 128);
+        test_rabitq_impl(DistanceType::L2, false, 65);
+    }
+
+    #[test]
+    fn test_rabitq_l1_not_supported() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data = generate_vectors(16, 32, &mut rng);
+        let vector_parameters = VectorParameters {
+            dim: 32,
+            deprecated_count: None,
+            distance_type: DistanceType::L1,
+            invert: true,
+        };
+        assert!(matches!(
+            encode_rabitq(&vector_data, &vector_parameters),
+            Err(EncodingError::ArgumentsError(_)),
+        ));
+    }
+
+    #[test]
+    fn test_rabitq_upsert() {
+        let vector_dim = 64;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data = generate_vectors(16, vector_dim, &mut rng);
+        let vector_parameters = VectorParameters {
+            dim: vector_dim,
+            deprecated_count: None,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+        let mut encoded = encode_rabitq(&vector_data, &vector_parameters).unwrap();
+
+        let hw_counter = HardwareCounterCell::new();
+        let new_vector = generate_vectors(1, vector_dim, &mut rng).pop().unwrap();
+        encoded.upsert_vector(3, &new_vector, &hw_counter).unwrap();
+
+        let query_encoded = encoded.encode_query(&new_vector);
+        let best = (0..vector_data.len() as u32)
+            .max_by(|&a, &b| {
+                let score_a = encoded.score_point(&query_encoded, a, &hw_counter);
+                let score_b = encoded.score_point(&query_encoded, b, &hw_counter);
+                score_a.total_cmp(&score_b)
+            })
+            .unwrap();
+        assert_eq!(best, 3);
+    }
+}
@@ -364,6 +364,7 @@ fn build_hnsw_index<R: Rng + ?Sized>(
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     let open_args = HnswIndexOpenArgs {
@@ -0,0 +1,109 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::prelude::StdRng;
+use segment::fixtures::index_fixtures::random_vector;
+use segment::fixtures::vde_fixtures::{VdeHnswFixture, random_vde_filter, recall};
+use segment::index::VectorIndex;
+use segment::types::{Distance, HnswConfig, SearchParams};
+use tempfile::Builder;
+
+#[cfg(not(target_os = "windows"))]
+mod prof;
+
+const NUM_VECTORS: u64 = 50_000;
+const DIM: usize = 64;
+const TOP: usize = 10;
+const EF: usize = 64;
+const RECALL_SAMPLE_SIZE: usize = 100;
+
+// intent: compare search latency of the VDE index and native HNSW on the same data
+fn vde_search_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vde-search-group");
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("vde_search").tempdir().unwrap();
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 100,
+        full_scan_threshold: 10, // low value to trigger index usage by default
+        max_indexing_threads: 0,
+        on_disk: Some(false),
+        payload_m: None,
+        copy_vectors: None,
+        matryoshka_dim: None,
+    };
+    let fixture = VdeHnswFixture::new(
+        dir.path(),
+        NUM_VECTORS,
+        DIM,
+        Distance::Cosine,
+        hnsw_config,
+        &mut rng,
+    );
+
+    let params = SearchParams {
+        hnsw_ef: Some(EF),
+        ..Default::default()
+    };
+
+    for (name, index) in [
+        ("hnsw", &fixture.hnsw_index as &dyn VectorIndex),
+        ("vde", &fixture.vde_index),
+    ] {
+        for filtered in [false, true] {
+            let mut rng = StdRng::seed_from_u64(42);
+
+            let mut total_recall = 0.0;
+            for _ in 0..RECALL_SAMPLE_SIZE {
+                let query = random_vector(&mut rng, DIM).into();
+                let filter = filtered.then(|| random_vde_filter(&mut rng));
+                let exact = fixture.search_exact(&query, filter.as_ref(), TOP);
+                let found = index
+                    .search(
+                        &[&query],
+                        filter.as_ref(),
+                        TOP,
+                        Some(&params),
+                        &Default::default(),
+                    )
+                    .unwrap();
+                total_recall += recall(&exact, &found[0]);
+            }
+            eprintln!(
+                "{name} filtered: {filtered}, recall@{TOP} = {:.3}",
+                total_recall / RECALL_SAMPLE_SIZE as f64,
+            );
+
+            let bench_name = if filtered {
+                format!("{name}-filtered-search")
+            } else {
+                format!("{name}-search")
+            };
+            group.bench_function(bench_name, |b| {
+                b.iter(|| {
+                    let query = random_vector(&mut rng, DIM).into();
+                    let filter = filtered.then(|| random_vde_filter(&mut rng));
+                    index
+                        .search(
+                            &[&query],
+                            filter.as_ref(),
+                            TOP,
+                            Some(&params),
+                            &Default::default(),
+                        )
+                        .unwrap()
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().with_profiler(prof::FlamegraphProfiler::new(100));
+    targets = vde_search_benchmark
+}
+
+criterion_main!(benches);
@@ -62,6 +62,10 @@ pub enum OperationError {
         "No appropriate index for faceting: `{key}`. Please create one to facet on this field. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Match conditions"
     )]
     MissingMapIndexForFacet { key: String },
+    #[error(
+        "No full-text index for text query key: `{key}`. Please create one to use text queries. Check https://qdrant.tech/documentation/concepts/indexing/#full-text-index"
+    )]
+    MissingFullTextIndexForTextQuery { key: String },
     #[error(
         "Expected {expected_type} value for {field_name} in the payload and/or in the formula defaults. Error: {description}"
     )]
@@ -157,6 +157,7 @@ mod tests {
                             on_disk: None,
                             payload_m: Some(10),
                             copy_vectors: None,
+                            matryoshka_dim: None,
                         }),
                         quantization_config: None,
                         on_disk: None,
@@ -189,6 +190,7 @@ mod tests {
                 on_disk: None,
                 payload_m: None,
                 copy_vectors: None,
+                matryoshka_dim: None,
             }),
             storage_type: StorageTypeV5::InMemory,
             payload_storage_type: PayloadStorageType::default(),
@@ -200,14 +202,18 @@ mod tests {
         eprintln!("new = {new_segment:#?}");
 
         match &new_segment.vector_data.get("vec1").unwrap().index {
-            Indexes::Plain { .. } => panic!("expected HNSW index"),
+            Indexes::Plain { .. } | Indexes::Vde(_) | Indexes::DiskAnn(_) | Indexes::Ivf(_) => {
+                panic!("expected HNSW index")
+            }
             Indexes::Hnsw(hnsw) => {
                 assert_eq!(hnsw.m, 20);
             }
         }
 
         match &new_segment.vector_data.get("vec2").unwrap().index {
-            Indexes::Plain { .. } => panic!("expected HNSW index"),
+            Indexes::Plain { .. } | Indexes::Vde(_) | Indexes::DiskAnn(_) | Indexes::Ivf(_) => {
+                panic!("expected HNSW index")
+            }
             Indexes::Hnsw(hnsw) => {
                 assert_eq!(hnsw.m, 25);
             }
@@ -265,6 +271,7 @@ mod tests {
                 on_disk: None,
                 payload_m: None,
                 copy_vectors: None,
+                matryoshka_dim: None,
             }),
             storage_type: StorageTypeV5::InMemory,
             payload_storage_type: PayloadStorageType::default(),
@@ -307,6 +314,9 @@ mod tests {
                 QuantizationConfig::Binary(_) => {
                     panic!("expected scalar quantization")
                 }
+                QuantizationConfig::Rabitq(_) => {
+                    panic!("expected scalar quantization")
+                }
             },
             _ => {
                 panic!("expected quantization")
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Default markup inserted before a match in snippets
+pub const DEFAULT_HIGHLIGHT_PRE_TAG: &str = "<em>";
+/// Default markup inserted after a match in snippets
+pub const DEFAULT_HIGHLIGHT_POST_TAG: &str = "</em>";
+/// Default number of characters of context around the matches in snippets
+pub const DEFAULT_HIGHLIGHT_CONTEXT: usize = 50;
+
+/// Highlighting of the text matched by full-text conditions of the filter and by the text query.
+/// Conditions of the prefetch filters and the prefetch text queries are highlighted as well.
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct HighlightParams {
+    /// If true, return fragments of the matched texts with the matches marked up. Default: false
+    pub snippets: Option<bool>,
+
+    /// Markup inserted before each match in snippets. Default: `<em>`
+    pub pre_tag: Option<String>,
+
+    /// Markup inserted after each match in snippets. Default: `</em>`
+    pub post_tag: Option<String>,
+
+    /// Number of characters around the matches to include into snippets. Default: 50
+    #[validate(range(max = 10000))]
+    pub context: Option<usize>,
+}
+
+impl HighlightParams {
+    pub fn snippets_enabled(&self) -> bool {
+        self.snippets.unwrap_or(false)
+    }
+
+    pub fn pre_tag(&self) -> &str {
+        self.pre_tag.as_deref().unwrap_or(DEFAULT_HIGHLIGHT_PRE_TAG)
+    }
+
+    pub fn post_tag(&self) -> &str {
+        self.post_tag
+            .as_deref()
+            .unwrap_or(DEFAULT_HIGHLIGHT_POST_TAG)
+    }
+
+    pub fn context(&self) -> usize {
+        self.context.unwrap_or(DEFAULT_HIGHLIGHT_CONTEXT)
+    }
+}
+
+/// Matches in a single text value of a payload field
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct TextHighlight {
+    /// Index of the value, if the field contains an array of texts. 0 for a single text.
+    pub value_index: usize,
+
+    /// Character offsets of the matched words in the value, as `[start, end)` pairs
+    pub offsets: Vec<[usize; 2]>,
+
+    /// Fragments of the value with the matches marked up, if requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippets: Option<Vec<String>>,
+}
+
+/// Matched text values of a point, by payload key
+pub type PointHighlight = BTreeMap<String, Vec<TextHighlight>>;
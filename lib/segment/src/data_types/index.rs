@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::str::FromStr;
 
@@ -176,6 +176,38 @@ pub enum TokenizerType {
     #[default]
     Word,
     Multilingual,
+    /// All substrings of whitespace separated words with `min..=max` characters, for substring matching.
+    Ngram {
+        /// Minimal size of n-grams, in characters. Default: 3.
+        #[serde(default = "default_ngram_size")]
+        min: usize,
+        /// Maximal size of n-grams, in characters. Default: 3.
+        #[serde(default = "default_ngram_size")]
+        max: usize,
+    },
+    /// Prefixes of whitespace separated words, same as `prefix` but keeping punctuation inside of words.
+    /// Sizes of prefixes are given by `min_token_len` and `max_token_len`.
+    EdgeNgram,
+}
+
+/// Size of n-grams if not configured, trigrams are a common choice for substring search.
+pub const DEFAULT_NGRAM_SIZE: usize = 3;
+
+const fn default_ngram_size() -> usize {
+    DEFAULT_NGRAM_SIZE
+}
+
+pub fn validate_ngram_range(min: usize, max: usize) -> Result<(), ValidationErrors> {
+    let message = if min == 0 {
+        "the minimal n-gram size must be at least 1"
+    } else if min > max {
+        "the minimal n-gram size can't be larger than the maximal size"
+    } else {
+        return Ok(());
+    };
+    let mut errors = ValidationErrors::new();
+    errors.add("tokenizer", ValidationError::new(message));
+    Err(errors)
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
@@ -214,6 +246,28 @@ pub struct TextIndexParams {
     /// Algorithm for stemming. Default: disabled.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stemmer: Option<StemmingAlgorithm>,
+
+    /// Character filters, applied to each token in order, before any other processing.
+    /// Default: none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub char_filters: Option<Vec<CharFilter>>,
+
+    /// Synonyms, expanded at query time. Default: none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synonyms: Option<SynonymsInterface>,
+}
+
+impl Validate for TextIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self.tokenizer {
+            TokenizerType::Ngram { min, max } => validate_ngram_range(min, max),
+            TokenizerType::Prefix
+            | TokenizerType::Whitespace
+            | TokenizerType::Word
+            | TokenizerType::Multilingual
+            | TokenizerType::EdgeNgram => Ok(()),
+        }
+    }
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
@@ -447,6 +501,64 @@ pub struct StopwordsSet {
     pub custom: Option<BTreeSet<String>>,
 }
 
+/// Normalization of the characters of a token.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(untagged)]
+pub enum CharFilter {
+    Builtin(BuiltinCharFilter),
+    Mapping(MappingCharFilter),
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BuiltinCharFilter {
+    /// Remove diacritical marks, e.g. `é` -> `e`.
+    RemoveDiacritics,
+    /// Replace latin letters with their closest ASCII equivalents, e.g. `é` -> `e`, `ß` -> `ss`.
+    /// Other characters are kept as is.
+    AsciiFolding,
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Mapping {
+    #[default]
+    Mapping,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+pub struct MappingCharFilter {
+    pub r#type: Mapping,
+    /// Replace all occurrences of each key with its value.
+    /// Longer keys take precedence over the shorter ones.
+    pub mappings: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(untagged)]
+pub enum SynonymsInterface {
+    /// Groups of interchangeable tokens, e.g. `[["tv", "television"]]`.
+    Groups(Vec<Vec<String>>),
+    Set(SynonymsSet),
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+pub struct SynonymsSet {
+    /// Groups of interchangeable tokens, e.g. `[["tv", "television"]]`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<Vec<String>>>,
+
+    /// Content of a synonyms file in Solr format. Will be merged with the groups.
+    ///
+    /// Each line is either a group of interchangeable tokens, separated by commas: `tv, television`,
+    /// or a one-way mapping: `tv => television, telly`.
+    /// Empty lines and lines starting with `#` are ignored.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
 // Bool
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
@@ -495,6 +607,32 @@ pub struct DatetimeIndexParams {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ngram_tokenizer_params() {
+        let params: TextIndexParams = serde_json::from_str(
+            r#"{"type": "text", "tokenizer": {"ngram": {"min": 2, "max": 4}}}"#,
+        )
+        .unwrap();
+        assert_eq!(params.tokenizer, TokenizerType::Ngram { min: 2, max: 4 });
+        assert!(params.validate().is_ok());
+
+        let params: TextIndexParams =
+            serde_json::from_str(r#"{"type": "text", "tokenizer": {"ngram": {}}}"#).unwrap();
+        assert_eq!(params.tokenizer, TokenizerType::Ngram { min: 3, max: 3 });
+
+        let params: TextIndexParams =
+            serde_json::from_str(r#"{"type": "text", "tokenizer": "word"}"#).unwrap();
+        assert_eq!(params.tokenizer, TokenizerType::Word);
+
+        for (min, max) in [(0, 3), (4, 3)] {
+            let params = TextIndexParams {
+                tokenizer: TokenizerType::Ngram { min, max },
+                ..Default::default()
+            };
+            assert!(params.validate().is_err());
+        }
+    }
+
     #[test]
     fn test_stopwords_option_language_serialization() {
         let stopwords = StopwordsInterface::Language(Language::English);
@@ -634,4 +772,49 @@ mod tests {
 
         assert_eq!(stopwords_multiple, expected_set);
     }
+
+    #[test]
+    fn test_deserialize_char_filters_and_synonyms() {
+        let json = r#"{
+            "type": "text",
+            "char_filters": [
+                "ascii_folding",
+                {"type": "mapping", "mappings": {"&": "and"}}
+            ],
+            "synonyms": [["tv", "television"]]
+        }"#;
+        let params: TextIndexParams = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            params.char_filters,
+            Some(vec![
+                CharFilter::Builtin(BuiltinCharFilter::AsciiFolding),
+                CharFilter::Mapping(MappingCharFilter {
+                    r#type: Mapping::Mapping,
+                    mappings: BTreeMap::from([("&".to_string(), "and".to_string())]),
+                }),
+            ]),
+        );
+        assert_eq!(
+            params.synonyms,
+            Some(SynonymsInterface::Groups(vec![vec![
+                "tv".to_string(),
+                "television".to_string(),
+            ]])),
+        );
+
+        let json = r#"{"file": "tv, television\nlaptop => notebook"}"#;
+        let synonyms: SynonymsInterface = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            synonyms,
+            SynonymsInterface::Set(SynonymsSet {
+                groups: None,
+                file: Some("tv, television\nlaptop => notebook".to_string()),
+            }),
+        );
+
+        let serialized = serde_json::to_string(&params).unwrap();
+        let deserialized: TextIndexParams = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, params);
+    }
 }
@@ -2,11 +2,13 @@ pub mod build_index_result;
 pub mod collection_defaults;
 pub mod facets;
 pub mod groups;
+pub mod highlight;
 pub mod index;
 pub mod manifest;
 pub mod named_vectors;
 pub mod order_by;
 pub mod primitive;
 pub mod query_context;
+pub mod text_query;
 pub mod tiny_map;
 pub mod vectors;
@@ -7,10 +7,12 @@ use super::primitive::PrimitiveVectorElement;
 use super::tiny_map;
 use super::vectors::{
     DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
-    VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorInternal, VectorRef,
+    VectorElementType, VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf,
+    VectorInternal, VectorRef,
 };
 use crate::common::operation_error::OperationError;
 use crate::types::{VectorDataConfig, VectorName, VectorNameBuf, VectorStorageDatatype};
+use crate::vector_storage::multi_dense::token_pooling::pool_tokens;
 
 type CowKey<'a> = Cow<'a, VectorName>;
 
@@ -335,12 +337,36 @@ impl<'a> NamedVectors<'a> {
                         // replace dense vector with preprocessed vector
                         dense_vector.copy_from_slice(&preprocessed_vector);
                     }
+                    if let Some(mut pooled_multi_vector) =
+                        Self::pool_multi_vector(&owned_multi_vector, config)
+                    {
+                        // mean vectors of clusters have to be preprocessed again, e.g. normalized
+                        for dense_vector in pooled_multi_vector.multi_vectors_mut() {
+                            let preprocessed_vector =
+                                Self::preprocess_dense_vector(dense_vector.to_vec(), config);
+                            dense_vector.copy_from_slice(&preprocessed_vector);
+                        }
+                        owned_multi_vector = pooled_multi_vector;
+                    }
                     *multi_vector = CowMultiVector::Owned(owned_multi_vector);
                 }
             }
         }
     }
 
+    /// Apply token pooling, if configured for the multivector.
+    /// Bit-packed binary vectors can't be averaged, so they are never pooled.
+    fn pool_multi_vector(
+        multi_vector: &MultiDenseVectorInternal,
+        config: &VectorDataConfig,
+    ) -> Option<MultiDenseVectorInternal> {
+        let token_pooling = config.multivector_config?.token_pooling?;
+        if config.distance.is_binary() {
+            return None;
+        }
+        pool_tokens(multi_vector, token_pooling.target_count)
+    }
+
     fn preprocess_dense_vector(
         dense_vector: DenseVector,
         config: &VectorDataConfig,
@@ -349,12 +375,17 @@ impl<'a> NamedVectors<'a> {
             Some(VectorStorageDatatype::Float32) | None => config
                 .distance
                 .preprocess_vector::<VectorElementType>(dense_vector),
-            Some(VectorStorageDatatype::Uint8) => config
-                .distance
-                .preprocess_vector::<VectorElementTypeByte>(dense_vector),
+            Some(VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary) => {
+                config
+                    .distance
+                    .preprocess_vector::<VectorElementTypeByte>(dense_vector)
+            }
             Some(VectorStorageDatatype::Float16) => config
                 .distance
                 .preprocess_vector::<VectorElementTypeHalf>(dense_vector),
+            Some(VectorStorageDatatype::BFloat16) => config
+                .distance
+                .preprocess_vector::<VectorElementTypeBFloat16>(dense_vector),
         }
     }
 }
@@ -7,6 +7,7 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::cow::SimpleCow;
 use sparse::common::types::{DimId, DimWeight};
+use sparse::index::search_context::Bm25Params;
 
 use crate::data_types::tiny_map;
 use crate::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
@@ -21,6 +22,11 @@ pub struct QueryIdfStats {
 
     /// Number of indexed vectors per vector name.
     pub indexed_vectors: tiny_map::TinyMap<VectorNameBuf, usize>,
+
+    /// Total length of indexed sparse vectors per vector name,
+    /// collected over all segments.
+    /// Required for processing sparse vector search with BM25 modifier.
+    pub document_lengths: tiny_map::TinyMap<VectorNameBuf, f64>,
 }
 
 #[derive(Debug)]
@@ -41,6 +47,10 @@ pub struct QueryContext {
     /// Required for processing sparse vector search with `idf-dot` similarity.
     idf_stats: QueryIdfStats,
 
+    /// BM25 parameters of sparse vectors, which use BM25 modifier.
+    /// Average document length is computed from `idf_stats` per segment query.
+    bm25: tiny_map::TinyMap<VectorNameBuf, Bm25Params>,
+
     /// Structure to accumulate and report hardware usage.
     /// Holds reference to the shared drain, which is used to accumulate the values.
     hardware_usage_accumulator: HwMeasurementAcc,
@@ -56,6 +66,7 @@ impl QueryContext {
             search_optimized_threshold_kb,
             is_stopped: Arc::new(AtomicBool::new(false)),
             idf_stats: QueryIdfStats::default(),
+            bm25: tiny_map::TinyMap::new(),
             hardware_usage_accumulator,
         }
     }
@@ -103,6 +114,29 @@ impl QueryContext {
         }
     }
 
+    /// Same as [`Self::init_idf`], and additionally enable BM25 saturation of document weights,
+    /// so the statistics of document lengths can be collected.
+    pub fn init_bm25(
+        &mut self,
+        vector_name: &VectorName,
+        k1: DimWeight,
+        b: DimWeight,
+        indices: &[DimId],
+    ) {
+        self.init_idf(vector_name, indices);
+        self.idf_stats
+            .document_lengths
+            .insert(vector_name.to_owned(), 0.0);
+        self.bm25.insert(
+            vector_name.to_owned(),
+            Bm25Params {
+                k1,
+                b,
+                avg_doc_length: 0.0,
+            },
+        );
+    }
+
     pub fn mut_idf_stats(&mut self) -> &mut QueryIdfStats {
         &mut self.idf_stats
     }
@@ -141,16 +175,29 @@ impl<'a> SegmentQueryContext<'a> {
     }
 
     pub fn get_vector_context(&self, vector_name: &VectorName) -> VectorQueryContext<'_> {
+        let idf_stats = &self.query_context.idf_stats;
+        let indexed_vectors = idf_stats.indexed_vectors.get(vector_name).copied();
+        let bm25 = self.query_context.bm25.get(vector_name).map(|bm25| {
+            let total_length = idf_stats
+                .document_lengths
+                .get(vector_name)
+                .copied()
+                .unwrap_or(0.0);
+            let avg_doc_length = match indexed_vectors {
+                Some(count) if count > 0 => (total_length / count as f64) as DimWeight,
+                _ => 0.0,
+            };
+            Bm25Params {
+                avg_doc_length,
+                ..*bm25
+            }
+        });
         VectorQueryContext {
             search_optimized_threshold_kb: self.query_context.search_optimized_threshold_kb,
             is_stopped: Some(&self.query_context.is_stopped),
-            idf: self.query_context.idf_stats.idf.get(vector_name),
-            indexed_vectors: self
-                .query_context
-                .idf_stats
-                .indexed_vectors
-                .get(vector_name)
-                .copied(),
+            idf: idf_stats.idf.get(vector_name),
+            indexed_vectors,
+            bm25,
             deleted_points: self.deleted_points,
             hardware_counter: self.hardware_counter.fork(),
         }
@@ -183,6 +230,8 @@ pub struct VectorQueryContext<'a> {
 
     indexed_vectors: Option<usize>,
 
+    bm25: Option<Bm25Params>,
+
     deleted_points: Option<&'a BitSlice>,
 
     hardware_counter: HardwareCounterCell,
@@ -236,6 +285,11 @@ impl VectorQueryContext<'_> {
     pub fn is_require_idf(&self) -> bool {
         self.idf.is_some() && self.indexed_vectors.is_some()
     }
+
+    /// BM25 saturation of document weights, if the vector uses BM25 modifier
+    pub fn bm25(&self) -> Option<Bm25Params> {
+        self.bm25
+    }
 }
 
 #[cfg(feature = "testing")]
@@ -246,6 +300,7 @@ impl Default for VectorQueryContext<'_> {
             is_stopped: None,
             idf: None,
             indexed_vectors: None,
+            bm25: None,
             deleted_points: None,
             hardware_counter: HardwareCounterCell::new(),
         }
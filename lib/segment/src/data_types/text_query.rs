@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use common::types::ScoreType;
+use serde::{Deserialize, Serialize};
+use sparse::index::search_context::Bm25Params;
+
+use crate::json_path::JsonPath;
+
+/// BM25 term frequency saturation
+const BM25_K1: ScoreType = 1.2;
+/// BM25 document length normalization
+const BM25_B: ScoreType = 0.75;
+
+/// Relevance-ranked full-text query.
+///
+/// Documents are scored with BM25 using the full-text index of the `key` field.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct TextQuery {
+    /// Payload key with a full-text index
+    pub key: JsonPath,
+
+    /// Text to search for
+    pub query: String,
+}
+
+/// Corpus statistics required to compute BM25 scores.
+///
+/// Statistics are collected over all segments before scoring,
+/// so that the scores are comparable between segments.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextQueryStatistics {
+    /// Number of indexed documents
+    pub points_count: usize,
+    /// Sum of lengths of all indexed documents, in tokens
+    pub total_document_length: usize,
+    /// Number of documents containing each query token
+    pub document_frequencies: HashMap<String, usize>,
+}
+
+impl TextQueryStatistics {
+    pub fn bm25_params(&self) -> Bm25Params {
+        let avg_doc_length = if self.points_count > 0 {
+            self.total_document_length as ScoreType / self.points_count as ScoreType
+        } else {
+            0.0
+        };
+        Bm25Params {
+            k1: BM25_K1,
+            b: BM25_B,
+            avg_doc_length,
+        }
+    }
+
+    /// Inverse document frequency of the token.
+    ///
+    /// Uses the same smoothed formula as the IDF modifier of sparse vectors,
+    /// which stays positive for tokens present in most documents.
+    pub fn idf(&self, token: &str) -> ScoreType {
+        let n = self.points_count as ScoreType;
+        let df = self.document_frequencies.get(token).copied().unwrap_or(0) as ScoreType;
+        ((n - df + 0.5) / (df + 0.5) + 1.0).ln()
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::TelemetryDetail;
+use common::types::{ScoreType, TelemetryDetail};
 
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
@@ -12,6 +12,7 @@ use crate::data_types::facets::{FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
 use crate::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
+use crate::data_types::text_query::{TextQuery, TextQueryStatistics};
 use crate::data_types::vectors::{QueryVector, VectorInternal};
 use crate::entry::snapshot_entry::SnapshotEntry;
 use crate::index::field_index::{CardinalityEstimation, FieldIndex};
@@ -170,6 +171,35 @@ pub trait SegmentEntry: SnapshotEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<(OrderValue, PointIdType)>>;
 
+    /// Accumulate statistics of the full-text index of the `query.key` field,
+    /// which are required for BM25 scoring of the query.
+    ///
+    /// Points in `excluded_points` are left out of the statistics.
+    /// Will fail if there is no full-text index for the key.
+    fn fill_text_query_statistics(
+        &self,
+        query: &TextQuery,
+        excluded_points: &[PointIdType],
+        statistics: &mut TextQueryStatistics,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()>;
+
+    /// Return points which satisfies filtering condition and contain at least one of the query
+    /// tokens, ordered by BM25 score descending.
+    ///
+    /// Scores are computed with the corpus `statistics` of all searched segments.
+    /// Will fail if there is no full-text index for the key.
+    /// Cancelled by `is_stopped` flag.
+    fn read_text_scored_filtered<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        query: &'a TextQuery,
+        statistics: &TextQueryStatistics,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>>;
+
     /// Return random points which satisfies filtering condition.
     ///
     /// Cancelled by `is_stopped` flag.
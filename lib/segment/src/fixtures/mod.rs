@@ -4,3 +4,5 @@ pub mod payload_fixtures;
 pub mod query_fixtures;
 pub mod segment_fixtures;
 pub mod sparse_fixtures;
+#[cfg(feature = "vde")]
+pub mod vde_fixtures;
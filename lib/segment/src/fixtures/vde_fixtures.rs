@@ -0,0 +1,190 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::types::ScoredPointOffset;
+use rand::Rng;
+
+use crate::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
+use crate::entry::entry_point::SegmentEntry;
+use crate::fixtures::index_fixtures::random_vector;
+use crate::fixtures::payload_fixtures::{random_adj, random_int_payload};
+use crate::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use crate::index::vde_index::{VDEVectorIndex, VdeIndexOpenArgs};
+use crate::index::{PayloadIndex, VectorIndex};
+use crate::json_path::JsonPath;
+use crate::payload_json;
+use crate::segment::Segment;
+use crate::segment_constructor::VectorIndexBuildArgs;
+use crate::segment_constructor::simple_segment_constructor::build_simple_segment;
+use crate::types::{
+    Condition, Distance, FieldCondition, Filter, HnswConfig, HnswGlobalConfig, PayloadSchemaType,
+    Range, SearchParams, SeqNumberType, VdeIndexConfig, VdeStorageType,
+};
+
+pub const INT_KEY: &str = "int";
+pub const KEYWORD_KEY: &str = "keyword";
+pub const UNINDEXED_KEY: &str = "unindexed";
+
+/// Native HNSW and VDE indices built over the same segment
+pub struct VdeHnswFixture {
+    pub segment: Segment,
+    pub hnsw_index: HNSWIndex,
+    pub vde_index: VDEVectorIndex,
+}
+
+impl VdeHnswFixture {
+    /// Build a segment with random vectors and payloads in `path`, and index it with both indices
+    pub fn new<R: Rng + ?Sized>(
+        path: &Path,
+        num_vectors: u64,
+        dim: usize,
+        distance: Distance,
+        hnsw_config: HnswConfig,
+        rng: &mut R,
+    ) -> Self {
+        let stopped = AtomicBool::new(false);
+        let hw_counter = HardwareCounterCell::new();
+
+        let mut segment = build_simple_segment(&path.join("segment"), dim, distance).unwrap();
+        for n in 0..num_vectors {
+            let idx = n.into();
+            let vector = random_vector(rng, dim);
+            let payload = payload_json! {
+                INT_KEY: random_int_payload(rng, 1..=1),
+                KEYWORD_KEY: random_adj(rng),
+                UNINDEXED_KEY: random_int_payload(rng, 1..=2),
+            };
+            segment
+                .upsert_point(
+                    n as SeqNumberType,
+                    idx,
+                    only_default_vector(&vector),
+                    &hw_counter,
+                )
+                .unwrap();
+            segment
+                .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+                .unwrap();
+        }
+
+        let payload_index = segment.payload_index.clone();
+        for (key, schema) in [
+            (INT_KEY, PayloadSchemaType::Integer),
+            (KEYWORD_KEY, PayloadSchemaType::Keyword),
+        ] {
+            payload_index
+                .borrow_mut()
+                .set_indexed(&JsonPath::new(key), schema, &hw_counter)
+                .unwrap();
+        }
+
+        let vector_data = &segment.vector_data[DEFAULT_VECTOR_NAME];
+        let hnsw_index = HNSWIndex::build(
+            HnswIndexOpenArgs {
+                path: &path.join("hnsw"),
+                id_tracker: segment.id_tracker.clone(),
+                vector_storage: vector_data.vector_storage.clone(),
+                quantized_vectors: vector_data.quantized_vectors.clone(),
+                payload_index: payload_index.clone(),
+                hnsw_config,
+            },
+            VectorIndexBuildArgs {
+                permit: Arc::new(ResourcePermit::dummy(1)),
+                old_indices: &[],
+                gpu_device: None,
+                rng,
+                stopped: &stopped,
+                hnsw_global_config: &HnswGlobalConfig::default(),
+                feature_flags: FeatureFlags::default(),
+            },
+        )
+        .unwrap();
+
+        let vde_index = VDEVectorIndex::build(
+            VdeIndexOpenArgs {
+                path: &path.join("vde"),
+                id_tracker: segment.id_tracker.clone(),
+                vector_storage: vector_data.vector_storage.clone(),
+                payload_index,
+                dimension: dim,
+                distance,
+                config: VdeIndexConfig {
+                    storage_type: VdeStorageType::Memory,
+                    ..Default::default()
+                },
+            },
+            &stopped,
+        )
+        .unwrap();
+
+        Self {
+            segment,
+            hnsw_index,
+            vde_index,
+        }
+    }
+
+    /// Exact search, the ground truth for recall of both indices
+    pub fn search_exact(
+        &self,
+        query: &QueryVector,
+        filter: Option<&Filter>,
+        top: usize,
+    ) -> Vec<ScoredPointOffset> {
+        let params = SearchParams {
+            exact: true,
+            ..Default::default()
+        };
+        self.hnsw_index
+            .search(&[query], filter, top, Some(&params), &Default::default())
+            .unwrap()
+            .pop()
+            .unwrap()
+    }
+}
+
+/// Filter on indexed fields, optionally combined with a condition VDE can't evaluate
+pub fn random_vde_filter<R: Rng + ?Sized>(rng: &mut R) -> Filter {
+    let int_range = |key: &str, left: i64, size: i64| {
+        Condition::Field(FieldCondition::new_range(
+            JsonPath::new(key),
+            Range {
+                lt: None,
+                gt: None,
+                gte: Some(left as f64),
+                lte: Some((left + size) as f64),
+            },
+        ))
+    };
+    let keyword = Condition::Field(FieldCondition::new_match(
+        JsonPath::new(KEYWORD_KEY),
+        random_adj(rng).into(),
+    ));
+
+    match rng.random_range(0..3) {
+        0 => Filter::new_must(keyword),
+        1 => Filter::new_must(int_range(INT_KEY, rng.random_range(0..400), 100)),
+        _ => Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![keyword, int_range(UNINDEXED_KEY, 0, 250)]),
+            must_not: None,
+        },
+    }
+}
+
+/// Fraction of the `exact` points found
+pub fn recall(exact: &[ScoredPointOffset], found: &[ScoredPointOffset]) -> f64 {
+    if exact.is_empty() {
+        return 1.0;
+    }
+    let hits = exact
+        .iter()
+        .filter(|point| found.iter().any(|found| found.idx == point.idx))
+        .count();
+    hits as f64 / exact.len() as f64
+}
@@ -0,0 +1,41 @@
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+use io::file_operations::{atomic_save_json, read_json};
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+
+pub const DISKANN_INDEX_CONFIG_FILE: &str = "diskann_config.json";
+
+/// Parameters the DiskANN graph was built with
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub struct DiskAnnGraphConfig {
+    /// Dimension of vectors stored in graph nodes
+    pub dim: usize,
+    /// Maximal number of neighbours of a node
+    pub max_degree: usize,
+    /// Size of the candidate list used to build the graph
+    pub build_list_size: usize,
+    /// Number of vector dimensions replaced by a single PQ centroid
+    pub pq_bucket_size: usize,
+    /// Node every search starts from, the medoid of indexed vectors.
+    /// `None` if there are no indexed vectors.
+    pub entry_point: Option<PointOffsetType>,
+    /// Number of vectors linked into the graph
+    pub indexed_vector_count: usize,
+}
+
+impl DiskAnnGraphConfig {
+    pub fn get_config_path(path: &Path) -> PathBuf {
+        path.join(DISKANN_INDEX_CONFIG_FILE)
+    }
+
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        Ok(read_json(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_json(path, self)?)
+    }
+}
@@ -0,0 +1,211 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+use io::file_operations::atomic_save;
+use memmap2::Mmap;
+use memory::fadvise::clear_disk_cache;
+use memory::madvise::{Advice, AdviceSetting, Madviseable};
+use memory::mmap_ops::open_read_mmap;
+#[cfg(target_os = "linux")]
+use parking_lot::Mutex;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::vectors::VectorElementType;
+#[cfg(target_os = "linux")]
+use crate::vector_storage::async_io::UringReader;
+
+pub const DISKANN_GRAPH_FILE: &str = "diskann_graph.bin";
+
+const ELEMENT_SIZE: usize = size_of::<u32>();
+
+/// Vamana graph stored on disk
+///
+/// Every node is a fixed size record holding the full vector of the point, followed by the
+/// number of neighbours and the neighbours themselves, padded to `max_degree`. A single read of
+/// a node yields everything the beam search needs to rescore the point and continue traversal.
+#[derive(Debug)]
+pub struct DiskGraph {
+    dim: usize,
+    max_degree: usize,
+    mmap: Mmap,
+    /// Context for io_uring-based reads of nodes, only used if the graph is on disk
+    #[cfg(target_os = "linux")]
+    uring_reader: Option<Box<Mutex<UringReader<u8>>>>,
+}
+
+/// Node record of [`DiskGraph`]
+pub struct GraphNode<'a> {
+    dim: usize,
+    bytes: &'a [u8],
+}
+
+impl GraphNode<'_> {
+    /// Copy the vector of the node into `buffer`
+    pub fn read_vector(&self, buffer: &mut Vec<VectorElementType>) {
+        buffer.clear();
+        buffer.extend(
+            self.bytes[..self.dim * ELEMENT_SIZE]
+                .chunks_exact(ELEMENT_SIZE)
+                .map(|chunk| VectorElementType::from_le_bytes(chunk.try_into().unwrap())),
+        );
+    }
+
+    pub fn neighbours(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
+        let degree_offset = self.dim * ELEMENT_SIZE;
+        let degree = read_u32(&self.bytes[degree_offset..]) as usize;
+        self.bytes[degree_offset + ELEMENT_SIZE..]
+            .chunks_exact(ELEMENT_SIZE)
+            .take(degree)
+            .map(read_u32)
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes[..ELEMENT_SIZE].try_into().unwrap())
+}
+
+impl DiskGraph {
+    pub fn get_path(dir: &Path) -> PathBuf {
+        dir.join(DISKANN_GRAPH_FILE)
+    }
+
+    fn record_size(dim: usize, max_degree: usize) -> usize {
+        (dim + 1 + max_degree) * ELEMENT_SIZE
+    }
+
+    /// Write graph nodes into `dir`
+    ///
+    /// Nodes are written in order of point offsets, each one with its vector and neighbours.
+    pub fn save<'a>(
+        dir: &Path,
+        dim: usize,
+        max_degree: usize,
+        nodes: impl Iterator<
+            Item = OperationResult<(impl AsRef<[VectorElementType]>, &'a [PointOffsetType])>,
+        >,
+    ) -> OperationResult<()> {
+        atomic_save(&Self::get_path(dir), |writer| {
+            for node in nodes {
+                let (vector, neighbours) = node?;
+                let vector = vector.as_ref();
+                if vector.len() != dim || neighbours.len() > max_degree {
+                    return Err(OperationError::service_error(format!(
+                        "DiskANN node doesn't fit the record: {} dimensions and {} neighbours",
+                        vector.len(),
+                        neighbours.len(),
+                    )));
+                }
+                for value in vector {
+                    writer.write_all(&value.to_le_bytes())?;
+                }
+                writer.write_all(&(neighbours.len() as u32).to_le_bytes())?;
+                for neighbour in neighbours {
+                    writer.write_all(&neighbour.to_le_bytes())?;
+                }
+                for _ in neighbours.len()..max_degree {
+                    writer.write_all(&0u32.to_le_bytes())?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    pub fn open(dir: &Path, dim: usize, max_degree: usize, on_disk: bool) -> OperationResult<Self> {
+        let path = Self::get_path(dir);
+        let populate = !on_disk;
+        let mmap = open_read_mmap(&path, AdviceSetting::Advice(Advice::Random), populate)?;
+
+        let record_size = Self::record_size(dim, max_degree);
+        if !mmap.len().is_multiple_of(record_size) {
+            return Err(OperationError::service_error(format!(
+                "DiskANN graph file {} is corrupted, size {} is not a multiple of {record_size}",
+                path.display(),
+                mmap.len(),
+            )));
+        }
+
+        #[cfg(target_os = "linux")]
+        let uring_reader = if on_disk {
+            let file = std::fs::File::open(&path)?;
+            Some(Box::new(Mutex::new(UringReader::new(
+                file,
+                record_size,
+                0,
+            )?)))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            dim,
+            max_degree,
+            mmap,
+            #[cfg(target_os = "linux")]
+            uring_reader,
+        })
+    }
+
+    pub fn num_points(&self) -> usize {
+        self.mmap.len() / Self::record_size(self.dim, self.max_degree)
+    }
+
+    /// Size of a single node on disk in bytes
+    pub fn node_size(&self) -> usize {
+        Self::record_size(self.dim, self.max_degree)
+    }
+
+    pub fn node(&self, point_id: PointOffsetType) -> GraphNode<'_> {
+        let record_size = self.node_size();
+        let start = point_id as usize * record_size;
+        GraphNode {
+            dim: self.dim,
+            bytes: &self.mmap[start..start + record_size],
+        }
+    }
+
+    /// Read the given nodes and call `callback` for each of them, in any order
+    ///
+    /// If the graph is on disk, nodes are read in parallel with io_uring on Linux.
+    pub fn read_nodes(
+        &self,
+        point_ids: &[PointOffsetType],
+        mut callback: impl FnMut(PointOffsetType, GraphNode<'_>),
+    ) -> OperationResult<()> {
+        #[cfg(target_os = "linux")]
+        if let Some(uring_reader) = &self.uring_reader {
+            return uring_reader.lock().read_stream(
+                point_ids.iter().copied(),
+                |_, point_id, bytes: &[u8]| {
+                    callback(
+                        point_id,
+                        GraphNode {
+                            dim: self.dim,
+                            bytes,
+                        },
+                    );
+                },
+            );
+        }
+
+        for &point_id in point_ids {
+            callback(point_id, self.node(point_id));
+        }
+        Ok(())
+    }
+
+    /// Read underlying data from disk into disk cache.
+    pub fn populate(&self) {
+        self.mmap.populate();
+    }
+
+    /// Drop disk cache.
+    pub fn clear_cache(&self, dir: &Path) -> OperationResult<()> {
+        clear_disk_cache(&Self::get_path(dir))?;
+        Ok(())
+    }
+
+    pub fn files(&self, dir: &Path) -> Vec<PathBuf> {
+        vec![Self::get_path(dir)]
+    }
+}
@@ -0,0 +1,773 @@
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use atomic_refcell::AtomicRefCell;
+use bitvec::slice::BitSlice;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset, TelemetryDetail};
+use log::debug;
+use parking_lot::Mutex;
+use rand::Rng;
+
+use super::config::DiskAnnGraphConfig;
+use super::disk_graph::DiskGraph;
+use super::pq_vectors::GraphPqVectors;
+use super::search_list::SearchList;
+use super::vamana_builder::VamanaBuilder;
+use crate::common::BYTES_IN_KB;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use crate::data_types::named_vectors::CowVector;
+use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::vectors::{QueryVector, VectorElementType, VectorInternal, VectorRef};
+use crate::id_tracker::IdTrackerSS;
+use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::sample_estimation::sample_check_cardinality;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::visited_pool::VisitedPool;
+use crate::index::{PayloadIndex, VectorIndex};
+use crate::segment_constructor::VectorIndexBuildArgs;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
+use crate::telemetry::VectorIndexSearchesTelemetry;
+use crate::types::{DEFAULT_FULL_SCAN_THRESHOLD, DiskAnnConfig, Distance, Filter, SearchParams};
+use crate::vector_storage::{Sequential, VectorStorage, VectorStorageEnum, new_raw_scorer};
+
+/// Score of a graph node, used to order search candidates
+type CandidateScorer<'a> = Box<dyn Fn(PointOffsetType) -> ScoreType + 'a>;
+
+/// Score of a full vector read from a graph node
+type VectorRescorer<'a> = dyn Fn(&[VectorElementType]) -> ScoreType + 'a;
+
+#[derive(Debug)]
+struct DiskAnnSearchesTelemetry {
+    unfiltered_plain: Arc<Mutex<OperationDurationsAggregator>>,
+    unfiltered_graph: Arc<Mutex<OperationDurationsAggregator>>,
+    small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    large_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl DiskAnnSearchesTelemetry {
+    fn new() -> Self {
+        Self {
+            unfiltered_plain: OperationDurationsAggregator::new(),
+            unfiltered_graph: OperationDurationsAggregator::new(),
+            small_cardinality: OperationDurationsAggregator::new(),
+            large_cardinality: OperationDurationsAggregator::new(),
+            exact_filtered: OperationDurationsAggregator::new(),
+            exact_unfiltered: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+pub struct DiskAnnIndexOpenArgs<'a> {
+    pub path: &'a Path,
+    pub id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    pub dimension: usize,
+    pub distance: Distance,
+    pub config: DiskAnnConfig,
+}
+
+/// DiskANN vector index
+///
+/// A single layer Vamana graph is stored on disk along with full vectors of the points, see
+/// [`DiskGraph`]. Only PQ codes of the vectors are kept in RAM. The beam search navigates the
+/// graph by PQ scores, reading a few nodes from disk at once, and rescores read nodes with their
+/// full vectors.
+#[derive(Debug)]
+pub struct DiskAnnIndex {
+    id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    distance: Distance,
+    config: DiskAnnConfig,
+    graph_config: DiskAnnGraphConfig,
+    graph: DiskGraph,
+    /// PQ codes of graph nodes, only present if the graph has an entry point
+    pq_vectors: Option<GraphPqVectors>,
+    visited_pool: VisitedPool,
+    searches_telemetry: DiskAnnSearchesTelemetry,
+    path: PathBuf,
+}
+
+impl DiskAnnIndex {
+    pub fn open(args: DiskAnnIndexOpenArgs<'_>) -> OperationResult<Self> {
+        Self::check_dense_vectors(&args.vector_storage.borrow())?;
+
+        let graph_config =
+            DiskAnnGraphConfig::load(&DiskAnnGraphConfig::get_config_path(args.path))?;
+        let graph = DiskGraph::open(
+            args.path,
+            graph_config.dim,
+            graph_config.max_degree,
+            args.config.on_disk.unwrap_or(true),
+        )?;
+        let pq_vectors = graph_config
+            .entry_point
+            .map(|_| {
+                GraphPqVectors::load(
+                    args.path,
+                    graph_config.dim,
+                    args.distance,
+                    graph_config.pq_bucket_size,
+                )
+            })
+            .transpose()?;
+
+        Ok(Self::new(args, graph_config, graph, pq_vectors))
+    }
+
+    pub fn build<R: Rng + ?Sized>(
+        open_args: DiskAnnIndexOpenArgs<'_>,
+        build_args: VectorIndexBuildArgs<'_, R>,
+    ) -> OperationResult<Self> {
+        let DiskAnnIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            payload_index: _,
+            dimension,
+            distance,
+            config,
+        } = &open_args;
+        let VectorIndexBuildArgs {
+            permit,
+            old_indices: _,
+            gpu_device: _,
+            rng,
+            stopped,
+            hnsw_global_config: _,
+            feature_flags: _,
+        } = build_args;
+
+        create_dir_all(path)?;
+
+        let id_tracker = id_tracker.borrow();
+        let vector_storage = vector_storage.borrow();
+        Self::check_dense_vectors(&vector_storage)?;
+
+        let dim = *dimension;
+        let total_vector_count = vector_storage.total_vector_count();
+        let deleted_points = id_tracker.deleted_point_bitslice();
+        let points: Vec<_> = id_tracker
+            .iter_ids_excluding(vector_storage.deleted_vector_bitslice())
+            .collect();
+
+        debug!(
+            "building DiskANN for {} vectors with {} CPUs",
+            points.len(),
+            permit.num_cpus,
+        );
+
+        let entry_point =
+            Self::find_medoid(&vector_storage, dim, deleted_points, &points, stopped)?;
+
+        let mut builder = VamanaBuilder::new(
+            total_vector_count,
+            config.max_degree,
+            config.build_list_size,
+            *distance,
+        );
+        if let Some(entry_point) = entry_point {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .thread_name(|idx| format!("diskann-build-{idx}"))
+                .num_threads(permit.num_cpus as usize)
+                .build()?;
+            let points_scorer_builder = |point_id| {
+                // No need to accumulate hardware, since this is an internal operation
+                FilteredScorer::new_internal(
+                    point_id,
+                    &vector_storage,
+                    None,
+                    None,
+                    deleted_points,
+                    HardwareCounterCell::disposable(),
+                )
+            };
+            builder.build(
+                &points,
+                entry_point,
+                &points_scorer_builder,
+                &pool,
+                rng,
+                stopped,
+            )?;
+        }
+
+        let links = builder.links();
+        let nodes = (0..total_vector_count).map(|point_id| {
+            let CowVector::Dense(vector) =
+                vector_storage.get_vector::<Sequential>(point_id as PointOffsetType)
+            else {
+                return Err(OperationError::service_error(
+                    "DiskANN index only supports dense vectors",
+                ));
+            };
+            Ok((vector, links[point_id].as_slice()))
+        });
+        DiskGraph::save(path, dim, config.max_degree, nodes)?;
+
+        let on_disk = config.on_disk.unwrap_or(true);
+        let graph = DiskGraph::open(path, dim, config.max_degree, on_disk)?;
+
+        let pq_bucket_size = GraphPqVectors::bucket_size(config.pq_compression);
+        let pq_vectors = entry_point
+            .map(|_| {
+                // Encode vectors read back from the graph, they are stored as full precision floats
+                let vectors = (0..total_vector_count as PointOffsetType).map(|point_id| {
+                    let mut vector = Vec::with_capacity(dim);
+                    graph.node(point_id).read_vector(&mut vector);
+                    vector
+                });
+                GraphPqVectors::encode(
+                    path,
+                    vectors,
+                    total_vector_count,
+                    dim,
+                    *distance,
+                    pq_bucket_size,
+                    permit.num_cpus as usize,
+                    stopped,
+                )
+            })
+            .transpose()?;
+
+        let graph_config = DiskAnnGraphConfig {
+            dim,
+            max_degree: config.max_degree,
+            build_list_size: config.build_list_size,
+            pq_bucket_size,
+            entry_point,
+            indexed_vector_count: points.len(),
+        };
+        graph_config.save(&DiskAnnGraphConfig::get_config_path(path))?;
+
+        drop(vector_storage);
+        drop(id_tracker);
+        Ok(Self::new(open_args, graph_config, graph, pq_vectors))
+    }
+
+    fn new(
+        args: DiskAnnIndexOpenArgs<'_>,
+        graph_config: DiskAnnGraphConfig,
+        graph: DiskGraph,
+        pq_vectors: Option<GraphPqVectors>,
+    ) -> Self {
+        let DiskAnnIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            payload_index,
+            dimension: _,
+            distance,
+            config,
+        } = args;
+        Self {
+            id_tracker,
+            vector_storage,
+            payload_index,
+            distance,
+            config,
+            graph_config,
+            graph,
+            pq_vectors,
+            visited_pool: VisitedPool::new(),
+            searches_telemetry: DiskAnnSearchesTelemetry::new(),
+            path: path.to_path_buf(),
+        }
+    }
+
+    fn check_dense_vectors(vector_storage: &VectorStorageEnum) -> OperationResult<()> {
+        if vector_storage.try_multi_vector_config().is_some() {
+            return Err(OperationError::validation_error(
+                "DiskANN index does not support multi-vectors",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Find the point closest to the mean of all points, used as the entry point of the graph
+    fn find_medoid(
+        vector_storage: &VectorStorageEnum,
+        dim: usize,
+        deleted_points: &BitSlice,
+        points: &[PointOffsetType],
+        stopped: &AtomicBool,
+    ) -> OperationResult<Option<PointOffsetType>> {
+        if points.is_empty() {
+            return Ok(None);
+        }
+
+        let mut mean = vec![0.0; dim];
+        for &point_id in points {
+            check_process_stopped(stopped)?;
+            if let CowVector::Dense(vector) = vector_storage.get_vector::<Sequential>(point_id) {
+                for (sum, value) in mean.iter_mut().zip(vector.iter()) {
+                    *sum += value;
+                }
+            }
+        }
+        let count = points.len() as VectorElementType;
+        mean.iter_mut().for_each(|sum| *sum /= count);
+
+        let scorer = FilteredScorer::new(
+            QueryVector::Nearest(mean.into()),
+            vector_storage,
+            None,
+            None,
+            deleted_points,
+            HardwareCounterCell::disposable(),
+        )?;
+        let medoid = scorer.peek_top_iter(points.iter().copied(), 1, stopped)?;
+        Ok(medoid.first().map(|point| point.idx))
+    }
+
+    pub fn config(&self) -> &DiskAnnConfig {
+        &self.config
+    }
+
+    pub fn is_on_disk(&self) -> bool {
+        self.config.on_disk.unwrap_or(true)
+    }
+
+    /// Full scan threshold in number of vectors
+    fn full_scan_threshold(&self, vector_storage: &VectorStorageEnum) -> usize {
+        let full_scan_threshold_kb = self
+            .config
+            .full_scan_threshold
+            .unwrap_or(DEFAULT_FULL_SCAN_THRESHOLD);
+        vector_storage
+            .size_of_available_vectors_in_bytes()
+            .checked_div(vector_storage.available_vector_count())
+            .and_then(|avg_vector_size| {
+                full_scan_threshold_kb
+                    .saturating_mul(BYTES_IN_KB)
+                    .checked_div(avg_vector_size)
+            })
+            .unwrap_or(1)
+    }
+
+    /// Score all given points with the vectors of the segment vector storage
+    fn search_plain(
+        &self,
+        vector: &QueryVector,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        top: usize,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let deleted_points = query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+        let scorer = FilteredScorer::new(
+            vector.to_owned(),
+            &vector_storage,
+            None,
+            None,
+            deleted_points,
+            query_context.hardware_counter(),
+        )?;
+        let is_stopped = query_context.is_stopped();
+        Ok(scorer.peek_top_iter(points, top, &is_stopped)?)
+    }
+
+    fn search_vectors_plain(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        match filter {
+            Some(filter) => {
+                let filtered_points = self
+                    .payload_index
+                    .borrow()
+                    .query_points(filter, &query_context.hardware_counter());
+                vectors
+                    .iter()
+                    .map(|vector| {
+                        self.search_plain(
+                            vector,
+                            &mut filtered_points.iter().copied(),
+                            top,
+                            query_context,
+                        )
+                    })
+                    .collect()
+            }
+            None => {
+                let id_tracker = self.id_tracker.borrow();
+                vectors
+                    .iter()
+                    .map(|vector| {
+                        self.search_plain(
+                            vector,
+                            &mut id_tracker.iter_internal(),
+                            top,
+                            query_context,
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn search_vectors_with_graph(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        vectors
+            .iter()
+            .map(|vector| self.search_with_graph(vector, filter, top, params, query_context))
+            .collect()
+    }
+
+    /// Search the graph for `top` points accepted by the filter
+    ///
+    /// The filter is checked on found points only. If the search list was too short to find
+    /// enough of them, the search is repeated with a longer list.
+    fn search_with_graph(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let (Some(entry_point), Some(pq_vectors)) =
+            (self.graph_config.entry_point, self.pq_vectors.as_ref())
+        else {
+            return Ok(Vec::new());
+        };
+        if top == 0 {
+            return Ok(Vec::new());
+        }
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let payload_index = self.payload_index.borrow();
+
+        let hw_counter = query_context.hardware_counter();
+        let is_stopped = query_context.is_stopped();
+        let deleted_points = query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+        let deleted_vectors = vector_storage.deleted_vector_bitslice();
+        let filter_context = filter.map(|filter| payload_index.filter_context(filter, &hw_counter));
+        let check = |point_id: PointOffsetType| {
+            let is_deleted = deleted_points
+                .get(point_id as usize)
+                .is_some_and(|deleted| *deleted)
+                || deleted_vectors
+                    .get(point_id as usize)
+                    .is_some_and(|deleted| *deleted);
+            !is_deleted
+                && filter_context
+                    .as_ref()
+                    .is_none_or(|filter_context| filter_context.check(point_id))
+        };
+
+        // Nearest queries navigate by PQ codes and are rescored with vectors read from the graph,
+        // other queries are scored with the vectors of the segment vector storage
+        let (score_candidate, rescore): (CandidateScorer, Option<Box<VectorRescorer>>) =
+            match vector {
+                QueryVector::Nearest(VectorInternal::Dense(dense)) => {
+                    let query = self
+                        .distance
+                        .preprocess_vector::<VectorElementType>(dense.clone());
+                    let encoded_query = pq_vectors.encode_query(&query);
+                    let similarity = similarity_function(self.distance);
+                    let cpu_counter = hw_counter.cpu_counter().clone();
+                    let dim = self.graph_config.dim;
+                    (
+                        Box::new(move |point_id| {
+                            pq_vectors.score_point(&encoded_query, point_id, &hw_counter)
+                        }),
+                        Some(Box::new(move |stored| {
+                            cpu_counter.incr_delta(dim);
+                            similarity(&query, stored)
+                        })),
+                    )
+                }
+                _ => {
+                    let raw_scorer = new_raw_scorer(
+                        vector.to_owned(),
+                        &vector_storage,
+                        query_context.hardware_counter(),
+                    )?;
+                    (
+                        Box::new(move |point_id| raw_scorer.score_point(point_id)),
+                        None,
+                    )
+                }
+            };
+
+        let available_vector_count = vector_storage.available_vector_count();
+        let mut list_size = params
+            .and_then(|params| params.hnsw_ef)
+            .unwrap_or(self.config.search_list_size)
+            .max(top);
+        loop {
+            let (found, is_list_full) = self.beam_search(
+                entry_point,
+                list_size,
+                top,
+                score_candidate.as_ref(),
+                rescore.as_deref(),
+                &check,
+                &is_stopped,
+                query_context,
+            )?;
+            if found.len() >= top || !is_list_full || list_size >= available_vector_count {
+                return Ok(found);
+            }
+            list_size = list_size.saturating_mul(2);
+        }
+    }
+
+    /// Beam search over the graph
+    ///
+    /// Candidates are ordered by `score_candidate`. The best `beam_width` unexpanded candidates
+    /// are read from disk at once, read nodes accepted by `check` are scored with `rescore` if
+    /// given, or with their candidate score otherwise.
+    ///
+    /// Returns found points and whether the search list has dropped candidates.
+    #[allow(clippy::too_many_arguments)]
+    fn beam_search(
+        &self,
+        entry_point: PointOffsetType,
+        list_size: usize,
+        top: usize,
+        score_candidate: &dyn Fn(PointOffsetType) -> ScoreType,
+        rescore: Option<&VectorRescorer>,
+        check: &dyn Fn(PointOffsetType) -> bool,
+        is_stopped: &AtomicBool,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<(Vec<ScoredPointOffset>, bool)> {
+        let hw_counter = query_context.hardware_counter();
+        let mut visited = self.visited_pool.get(self.graph.num_points());
+        let mut search_list = SearchList::new(list_size);
+        let mut found = FixedLengthPriorityQueue::new(top);
+
+        visited.check_and_update_visited(entry_point);
+        search_list.insert(ScoredPointOffset {
+            idx: entry_point,
+            score: score_candidate(entry_point),
+        });
+
+        let mut vector_buffer = Vec::with_capacity(self.graph_config.dim);
+        let mut neighbours =
+            Vec::with_capacity(self.config.beam_width * self.graph_config.max_degree);
+        loop {
+            check_process_stopped(is_stopped)?;
+
+            let beam = search_list.expand_next(self.config.beam_width);
+            if beam.is_empty() {
+                break;
+            }
+            let beam_ids: Vec<_> = beam.iter().map(|point| point.idx).collect();
+
+            neighbours.clear();
+            self.graph.read_nodes(&beam_ids, |point_id, node| {
+                if check(point_id) {
+                    let score = match rescore {
+                        Some(rescore) => {
+                            node.read_vector(&mut vector_buffer);
+                            rescore(&vector_buffer)
+                        }
+                        None => beam
+                            .iter()
+                            .find(|point| point.idx == point_id)
+                            .map_or_else(|| score_candidate(point_id), |point| point.score),
+                    };
+                    found.push(ScoredPointOffset {
+                        idx: point_id,
+                        score,
+                    });
+                }
+                neighbours.extend(node.neighbours());
+            })?;
+            hw_counter
+                .vector_io_read()
+                .incr_delta(beam_ids.len() * self.graph.node_size());
+
+            for &neighbour in &neighbours {
+                if !visited.check_and_update_visited(neighbour) {
+                    search_list.insert(ScoredPointOffset {
+                        idx: neighbour,
+                        score: score_candidate(neighbour),
+                    });
+                }
+            }
+        }
+
+        Ok((found.into_sorted_vec(), search_list.is_full()))
+    }
+
+    /// Read underlying data from disk into disk cache.
+    pub fn populate(&self) -> OperationResult<()> {
+        self.graph.populate();
+        Ok(())
+    }
+
+    /// Drop disk cache.
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.graph.clear_cache(&self.path)
+    }
+}
+
+fn similarity_function(
+    distance: Distance,
+) -> fn(&[VectorElementType], &[VectorElementType]) -> ScoreType {
+    match distance {
+        Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::similarity,
+        Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::similarity,
+        Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::similarity,
+        Distance::Manhattan => <ManhattanMetric as Metric<VectorElementType>>::similarity,
+        Distance::Hamming => <HammingMetric as Metric<VectorElementType>>::similarity,
+        Distance::Jaccard => <JaccardMetric as Metric<VectorElementType>>::similarity,
+    }
+}
+
+impl VectorIndex for DiskAnnIndex {
+    fn search(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let exact = params.is_some_and(|params| params.exact);
+        let telemetry = &self.searches_telemetry;
+
+        let Some(query_filter) = filter else {
+            let plain_search = exact || {
+                let vector_storage = self.vector_storage.borrow();
+                vector_storage.available_vector_count() < self.full_scan_threshold(&vector_storage)
+            };
+            if plain_search {
+                let _timer = ScopeDurationMeasurer::new(if exact {
+                    &telemetry.exact_unfiltered
+                } else {
+                    &telemetry.unfiltered_plain
+                });
+                return self.search_vectors_plain(vectors, None, top, query_context);
+            }
+            let _timer = ScopeDurationMeasurer::new(&telemetry.unfiltered_graph);
+            return self.search_vectors_with_graph(vectors, None, top, params, query_context);
+        };
+
+        if exact {
+            let _timer = ScopeDurationMeasurer::new(&telemetry.exact_filtered);
+            return self.search_vectors_plain(vectors, filter, top, query_context);
+        }
+
+        // Like for HNSW, small cardinality filters are served by scoring the filtered points
+        // directly, the graph is searched otherwise
+        let use_graph = {
+            let id_tracker = self.id_tracker.borrow();
+            let vector_storage = self.vector_storage.borrow();
+            let payload_index = self.payload_index.borrow();
+            let hw_counter = query_context.hardware_counter();
+
+            let full_scan_threshold = self.full_scan_threshold(&vector_storage);
+            let available_vector_count = vector_storage.available_vector_count();
+            let query_cardinality = adjust_to_available_vectors(
+                payload_index.estimate_cardinality(query_filter, &hw_counter),
+                available_vector_count,
+                id_tracker.available_point_count(),
+            );
+
+            if query_cardinality.max < full_scan_threshold {
+                false
+            } else if query_cardinality.min > full_scan_threshold {
+                true
+            } else {
+                // Fast cardinality estimation is not enough, do sample estimation of cardinality
+                let filter_context = payload_index.filter_context(query_filter, &hw_counter);
+                sample_check_cardinality(
+                    id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
+                    |idx| filter_context.check(idx),
+                    full_scan_threshold,
+                    available_vector_count,
+                )
+            }
+        };
+
+        if use_graph {
+            let _timer = ScopeDurationMeasurer::new(&telemetry.large_cardinality);
+            self.search_vectors_with_graph(vectors, filter, top, params, query_context)
+        } else {
+            let _timer = ScopeDurationMeasurer::new(&telemetry.small_cardinality);
+            self.search_vectors_plain(vectors, filter, top, query_context)
+        }
+    }
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
+        VectorIndexSearchesTelemetry {
+            index_name: None,
+            unfiltered_plain: tm.unfiltered_plain.lock().get_statistics(detail),
+            filtered_plain: Default::default(),
+            unfiltered_hnsw: tm.unfiltered_graph.lock().get_statistics(detail),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
+            filtered_two_hop: Default::default(),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
+            filtered_sparse: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
+            unfiltered_sparse: Default::default(),
+        }
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.graph.files(&self.path);
+        if let Some(pq_vectors) = &self.pq_vectors {
+            files.extend(pq_vectors.files());
+        }
+        files.push(DiskAnnGraphConfig::get_config_path(&self.path));
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        self.files()
+    }
+
+    fn indexed_vector_count(&self) -> usize {
+        self.graph_config.indexed_vector_count
+    }
+
+    fn size_of_searchable_vectors_in_bytes(&self) -> usize {
+        self.vector_storage
+            .borrow()
+            .size_of_available_vectors_in_bytes()
+    }
+
+    fn update_vector(
+        &mut self,
+        _id: PointOffsetType,
+        _vector: Option<VectorRef>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        Err(OperationError::service_error("Cannot update DiskANN index"))
+    }
+}
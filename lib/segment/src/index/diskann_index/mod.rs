@@ -0,0 +1,8 @@
+pub mod config;
+pub mod disk_graph;
+pub mod diskann_vector_index;
+mod pq_vectors;
+mod search_list;
+mod vamana_builder;
+
+pub use diskann_vector_index::{DiskAnnIndex, DiskAnnIndexOpenArgs};
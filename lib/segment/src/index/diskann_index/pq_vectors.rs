@@ -0,0 +1,121 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::{PointOffsetType, ScoreType};
+use quantization::{EncodedQueryPQ, EncodedVectors, EncodedVectorsPQ};
+
+use crate::common::operation_error::OperationResult;
+use crate::data_types::vectors::VectorElementType;
+use crate::types::{CompressionRatio, Distance};
+use crate::vector_storage::quantized::quantized_ram_storage::{
+    QuantizedRamStorage, QuantizedRamStorageBuilder,
+};
+use crate::vector_storage::quantized::quantized_vectors::{
+    QuantizedVectors, QuantizedVectorsStorageType,
+};
+
+const DISKANN_PQ_DATA_FILE: &str = "diskann_pq.data";
+const DISKANN_PQ_META_FILE: &str = "diskann_pq.json";
+
+/// Product quantized vectors, kept in RAM to navigate the DiskANN graph
+pub struct GraphPqVectors {
+    encoded: EncodedVectorsPQ<QuantizedRamStorage>,
+}
+
+impl fmt::Debug for GraphPqVectors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GraphPqVectors").finish_non_exhaustive()
+    }
+}
+
+impl GraphPqVectors {
+    #[allow(clippy::too_many_arguments)]
+    pub fn encode<'a>(
+        dir: &Path,
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
+        count: usize,
+        dim: usize,
+        distance: Distance,
+        bucket_size: usize,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let vector_parameters = Self::vector_parameters(dim, distance, count);
+        let quantized_vector_size =
+            EncodedVectorsPQ::<QuantizedRamStorage>::get_quantized_vector_size(
+                &vector_parameters,
+                bucket_size,
+            );
+        let storage_builder = QuantizedRamStorageBuilder::new(
+            &dir.join(DISKANN_PQ_DATA_FILE),
+            count,
+            quantized_vector_size,
+        )?;
+        let encoded = EncodedVectorsPQ::encode(
+            vectors,
+            storage_builder,
+            &vector_parameters,
+            count,
+            bucket_size,
+            max_threads,
+            Some(&dir.join(DISKANN_PQ_META_FILE)),
+            stopped,
+        )?;
+        Ok(Self { encoded })
+    }
+
+    pub fn load(
+        dir: &Path,
+        dim: usize,
+        distance: Distance,
+        bucket_size: usize,
+    ) -> OperationResult<Self> {
+        let vector_parameters = Self::vector_parameters(dim, distance, 0);
+        let quantized_vector_size =
+            EncodedVectorsPQ::<QuantizedRamStorage>::get_quantized_vector_size(
+                &vector_parameters,
+                bucket_size,
+            );
+        let storage =
+            QuantizedRamStorage::from_file(&dir.join(DISKANN_PQ_DATA_FILE), quantized_vector_size)?;
+        let encoded = EncodedVectorsPQ::load(storage, &dir.join(DISKANN_PQ_META_FILE))?;
+        Ok(Self { encoded })
+    }
+
+    pub fn bucket_size(compression: CompressionRatio) -> usize {
+        QuantizedVectors::get_bucket_size(compression)
+    }
+
+    fn vector_parameters(
+        dim: usize,
+        distance: Distance,
+        count: usize,
+    ) -> quantization::VectorParameters {
+        QuantizedVectors::construct_vector_parameters(
+            distance,
+            dim,
+            count,
+            QuantizedVectorsStorageType::Immutable,
+        )
+    }
+
+    /// Encode a preprocessed query
+    pub fn encode_query(&self, query: &[VectorElementType]) -> EncodedQueryPQ {
+        self.encoded.encode_query(query)
+    }
+
+    pub fn score_point(
+        &self,
+        query: &EncodedQueryPQ,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        self.encoded.score_point(query, point_id, hw_counter)
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.encoded.files()
+    }
+}
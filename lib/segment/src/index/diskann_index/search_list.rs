@@ -0,0 +1,92 @@
+use common::types::ScoredPointOffset;
+
+struct Candidate {
+    point: ScoredPointOffset,
+    expanded: bool,
+}
+
+/// Bounded list of the best candidates found by the greedy search, ordered by score
+///
+/// The search repeatedly expands the best candidates which were not expanded yet, until all
+/// candidates in the list are expanded.
+pub struct SearchList {
+    capacity: usize,
+    candidates: Vec<Candidate>,
+}
+
+impl SearchList {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            candidates: Vec::with_capacity(capacity + 1),
+        }
+    }
+
+    /// Insert a candidate, it is dropped if the list is full of better candidates
+    pub fn insert(&mut self, point: ScoredPointOffset) {
+        let position = self
+            .candidates
+            .partition_point(|candidate| candidate.point.score >= point.score);
+        if position >= self.capacity {
+            return;
+        }
+        self.candidates.insert(
+            position,
+            Candidate {
+                point,
+                expanded: false,
+            },
+        );
+        self.candidates.truncate(self.capacity);
+    }
+
+    /// Take up to `count` best candidates which were not expanded yet, marking them expanded
+    pub fn expand_next(&mut self, count: usize) -> Vec<ScoredPointOffset> {
+        self.candidates
+            .iter_mut()
+            .filter(|candidate| !candidate.expanded)
+            .take(count)
+            .map(|candidate| {
+                candidate.expanded = true;
+                candidate.point
+            })
+            .collect()
+    }
+
+    /// Whether the list has dropped any candidate, so a larger list could find more points
+    pub fn is_full(&self) -> bool {
+        self.candidates.len() >= self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::PointOffsetType;
+
+    use super::*;
+
+    fn point(idx: PointOffsetType, score: f32) -> ScoredPointOffset {
+        ScoredPointOffset { idx, score }
+    }
+
+    #[test]
+    fn test_search_list_keeps_best_candidates() {
+        let mut list = SearchList::new(3);
+        for (idx, score) in [(0, 0.5), (1, 0.1), (2, 0.9), (3, 0.3), (4, 0.7)] {
+            list.insert(point(idx, score));
+        }
+        assert!(list.is_full());
+
+        let expanded = list.expand_next(2);
+        assert_eq!(
+            expanded.iter().map(|p| p.idx).collect::<Vec<_>>(),
+            vec![2, 4],
+        );
+
+        // A better candidate pushes the worst one out of the full list
+        list.insert(point(5, 0.8));
+        let expanded = list.expand_next(2);
+        assert_eq!(expanded.iter().map(|p| p.idx).collect::<Vec<_>>(), vec![5]);
+        assert!(list.expand_next(2).is_empty());
+    }
+}
@@ -0,0 +1,255 @@
+use std::sync::atomic::AtomicBool;
+
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
+use rand::Rng;
+use rand::seq::SliceRandom;
+use rayon::ThreadPool;
+use rayon::prelude::*;
+
+use super::search_list::SearchList;
+use crate::common::operation_error::{OperationResult, check_process_stopped};
+use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::visited_pool::VisitedPool;
+use crate::types::Distance;
+
+/// Pruning factor of the second build pass, keeps long range edges in the graph
+const VAMANA_ALPHA: f32 = 1.2;
+
+/// Maximal number of points inserted in parallel against the same state of the graph
+const MAX_BATCH_SIZE: usize = 1024;
+
+/// Builder of a Vamana graph in RAM
+///
+/// Points are inserted in two passes over a random order: the first one with `alpha = 1` builds
+/// a sparse graph of close neighbours, the second one with `alpha > 1` adds long range edges.
+/// Each pass inserts batches of growing size in parallel, batches see the graph as it was
+/// before the batch started.
+pub struct VamanaBuilder {
+    max_degree: usize,
+    build_list_size: usize,
+    distance: Distance,
+    links: Vec<Vec<PointOffsetType>>,
+    visited_pool: VisitedPool,
+}
+
+impl VamanaBuilder {
+    pub fn new(
+        num_points: usize,
+        max_degree: usize,
+        build_list_size: usize,
+        distance: Distance,
+    ) -> Self {
+        Self {
+            max_degree,
+            build_list_size,
+            distance,
+            links: vec![Vec::new(); num_points],
+            visited_pool: VisitedPool::new(),
+        }
+    }
+
+    pub fn links(&self) -> &[Vec<PointOffsetType>] {
+        &self.links
+    }
+
+    /// Link `points` into the graph, the search for neighbours starts from `entry_point`
+    ///
+    /// `points_scorer_builder` creates a scorer against the vector of the given point.
+    pub fn build<'a, R: Rng + ?Sized>(
+        &mut self,
+        points: &[PointOffsetType],
+        entry_point: PointOffsetType,
+        points_scorer_builder: &(impl Fn(PointOffsetType) -> OperationResult<FilteredScorer<'a>> + Sync),
+        pool: &ThreadPool,
+        rng: &mut R,
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        let mut order = points.to_vec();
+        for alpha in [1.0, VAMANA_ALPHA] {
+            order.shuffle(rng);
+
+            let mut start = 0;
+            while start < order.len() {
+                check_process_stopped(stopped)?;
+
+                // Early points have to be linked to each other, grow batches with the graph
+                let batch_size = start.clamp(1, MAX_BATCH_SIZE);
+                let batch = &order[start..order.len().min(start + batch_size)];
+                start += batch.len();
+
+                let new_links = pool.install(|| {
+                    batch
+                        .par_iter()
+                        .map(|&point| {
+                            let mut scorer = points_scorer_builder(point)?;
+                            let links =
+                                self.search_and_prune(point, entry_point, &mut scorer, alpha);
+                            Ok((point, links))
+                        })
+                        .collect::<OperationResult<Vec<_>>>()
+                })?;
+
+                let mut back_links = Vec::new();
+                for (point, links) in new_links {
+                    back_links.extend(links.iter().map(|&neighbour| (neighbour, point)));
+                    self.links[point as usize] = links;
+                }
+                back_links.sort_unstable();
+                let back_links: Vec<_> = back_links
+                    .chunk_by(|(a, _), (b, _)| a == b)
+                    .map(|chunk| (chunk[0].0, chunk.iter().map(|&(_, point)| point).collect()))
+                    .collect();
+
+                let updated_links = pool.install(|| {
+                    back_links
+                        .into_par_iter()
+                        .map(|(neighbour, incoming)| {
+                            let links =
+                                self.add_links(neighbour, incoming, points_scorer_builder, alpha)?;
+                            Ok((neighbour, links))
+                        })
+                        .collect::<OperationResult<Vec<_>>>()
+                })?;
+                for (neighbour, links) in updated_links {
+                    self.links[neighbour as usize] = links;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Find neighbours of `point` among the points visited by the greedy search and its current
+    /// neighbours
+    fn search_and_prune(
+        &self,
+        point: PointOffsetType,
+        entry_point: PointOffsetType,
+        scorer: &mut FilteredScorer,
+        alpha: f32,
+    ) -> Vec<PointOffsetType> {
+        let mut candidates = self.greedy_search(entry_point, scorer);
+        let mut current_links = self.links[point as usize].clone();
+        candidates.extend(scorer.score_points(&mut current_links, 0));
+        self.robust_prune(point, candidates, scorer, alpha)
+    }
+
+    /// Add `incoming` links to the neighbours of `point`, pruning them if there are too many
+    fn add_links<'a>(
+        &self,
+        point: PointOffsetType,
+        incoming: Vec<PointOffsetType>,
+        points_scorer_builder: &impl Fn(PointOffsetType) -> OperationResult<FilteredScorer<'a>>,
+        alpha: f32,
+    ) -> OperationResult<Vec<PointOffsetType>> {
+        let mut links = self.links[point as usize].clone();
+        for incoming_point in incoming {
+            if !links.contains(&incoming_point) {
+                links.push(incoming_point);
+            }
+        }
+        if links.len() <= self.max_degree {
+            return Ok(links);
+        }
+        let mut scorer = points_scorer_builder(point)?;
+        let candidates = scorer.score_points(&mut links, 0).collect();
+        Ok(self.robust_prune(point, candidates, &scorer, alpha))
+    }
+
+    /// Greedy search of the point `scorer` is built for, returns all expanded points
+    fn greedy_search(
+        &self,
+        entry_point: PointOffsetType,
+        scorer: &mut FilteredScorer,
+    ) -> Vec<ScoredPointOffset> {
+        let mut visited = self.visited_pool.get(self.links.len());
+        let mut search_list = SearchList::new(self.build_list_size);
+        let mut expanded = Vec::new();
+
+        visited.check_and_update_visited(entry_point);
+        search_list.insert(ScoredPointOffset {
+            idx: entry_point,
+            score: scorer.score_point(entry_point),
+        });
+
+        let mut neighbours = Vec::with_capacity(self.max_degree);
+        while let Some(&point) = search_list.expand_next(1).first() {
+            expanded.push(point);
+            neighbours.clear();
+            neighbours.extend(
+                self.links[point.idx as usize]
+                    .iter()
+                    .copied()
+                    .filter(|&neighbour| !visited.check_and_update_visited(neighbour)),
+            );
+            for neighbour in scorer.score_points(&mut neighbours, 0) {
+                search_list.insert(neighbour);
+            }
+        }
+        expanded
+    }
+
+    /// Select at most `max_degree` neighbours of `point` out of `candidates`
+    ///
+    /// Closest candidates are selected first, a candidate is skipped if it is occluded by an
+    /// already selected one, i.e. it is `alpha` times closer to the selected neighbour than to
+    /// `point`.
+    fn robust_prune(
+        &self,
+        point: PointOffsetType,
+        mut candidates: Vec<ScoredPointOffset>,
+        scorer: &FilteredScorer,
+        alpha: f32,
+    ) -> Vec<PointOffsetType> {
+        candidates.retain(|candidate| candidate.idx != point);
+        candidates.sort_unstable_by_key(|candidate| candidate.idx);
+        candidates.dedup_by_key(|candidate| candidate.idx);
+
+        let mut candidates: Vec<_> = candidates
+            .into_iter()
+            .map(|candidate| {
+                let dissimilarity =
+                    self.dissimilarity(scorer, point, candidate.idx, candidate.score);
+                (dissimilarity, candidate.idx)
+            })
+            .collect();
+        candidates.sort_unstable_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let mut selected: Vec<PointOffsetType> = Vec::with_capacity(self.max_degree);
+        for (to_point, candidate) in candidates {
+            if selected.len() >= self.max_degree {
+                break;
+            }
+            let is_occluded = selected.iter().any(|&selected_point| {
+                let score = scorer.score_internal(selected_point, candidate);
+                alpha * self.dissimilarity(scorer, selected_point, candidate, score) <= to_point
+            });
+            if !is_occluded {
+                selected.push(candidate);
+            }
+        }
+        selected
+    }
+
+    /// Dissimilarity of points `a` and `b` scored `score` against each other, grows with the
+    /// distance between them
+    fn dissimilarity(
+        &self,
+        scorer: &FilteredScorer,
+        a: PointOffsetType,
+        b: PointOffsetType,
+        score: ScoreType,
+    ) -> ScoreType {
+        match self.distance {
+            // Vectors are normalized, `1 - cos` is a proper dissimilarity
+            Distance::Cosine => 1.0 - score,
+            // Scores are negated distances
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming | Distance::Jaccard => {
+                -score
+            }
+            // Dot product is not a distance, use the squared euclidean distance instead
+            Distance::Dot => {
+                scorer.score_internal(a, a) + scorer.score_internal(b, b) - 2.0 * score
+            }
+        }
+    }
+}
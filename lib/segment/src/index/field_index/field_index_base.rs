@@ -189,6 +189,9 @@ impl FieldIndex {
                 Some(Match::Phrase(MatchPhrase { phrase })) => Some(
                     full_text_index.check_payload_match::<true>(payload_value, phrase, hw_counter),
                 ),
+                Some(Match::Fuzzy(fuzzy)) => {
+                    Some(full_text_index.check_payload_fuzzy_match(payload_value, fuzzy))
+                }
                 _ => None,
             },
             FieldIndex::UuidIndex(_) => None,
@@ -394,6 +397,22 @@ impl FieldIndex {
         }
     }
 
+    pub fn as_full_text(&self) -> Option<&FullTextIndex> {
+        match self {
+            FieldIndex::FullTextIndex(index) => Some(index),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::IntMapIndex(_)
+            | FieldIndex::KeywordIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::BoolIndex(_)
+            | FieldIndex::UuidMapIndex(_)
+            | FieldIndex::UuidIndex(_)
+            | FieldIndex::NullIndex(_) => None,
+        }
+    }
+
     pub fn as_facet_index(&self) -> Option<FacetIndexEnum<'_>> {
         match self {
             FieldIndex::KeywordIndex(index) => Some(FacetIndexEnum::Keyword(index)),
@@ -0,0 +1,227 @@
+//! Edit-distance matching of query tokens against the vocabulary of the text index.
+
+use common::counter::hardware_counter::HardwareCounterCell;
+
+use super::inverted_index::{InvertedIndex, TokenId};
+
+/// Maximal number of edits supported by fuzzy matching.
+///
+/// Larger distances match too many unrelated tokens to be useful, and make the lookup expensive.
+pub const MAX_FUZZY_EDITS: usize = 2;
+
+/// Matches tokens within a bounded Levenshtein distance of a query token.
+///
+/// Candidates must share the first `prefix_len` characters with the query token,
+/// which is cheap to check and prunes most of the vocabulary.
+#[derive(Debug, Clone)]
+pub struct FuzzyMatcher {
+    /// Part of the query token after the prefix
+    suffix: Vec<char>,
+    prefix: String,
+    max_edits: usize,
+}
+
+impl FuzzyMatcher {
+    pub fn new(query: &str, max_edits: usize, prefix_len: usize) -> Self {
+        let prefix: String = query.chars().take(prefix_len).collect();
+        let suffix = query[prefix.len()..].chars().collect();
+        Self {
+            suffix,
+            prefix,
+            max_edits: max_edits.min(MAX_FUZZY_EDITS),
+        }
+    }
+
+    /// Characters every matching token starts with
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn is_match(&self, candidate: &str) -> bool {
+        self.distance(candidate).is_some()
+    }
+
+    /// Edit distance to the `candidate`, if it matches
+    pub fn distance(&self, candidate: &str) -> Option<usize> {
+        let suffix = candidate.strip_prefix(self.prefix.as_str())?;
+
+        // Length difference is a lower bound of the distance
+        if suffix.chars().count().abs_diff(self.suffix.len()) > self.max_edits {
+            return None;
+        }
+
+        bounded_levenshtein(&self.suffix, suffix, self.max_edits)
+    }
+
+    /// All matching tokens among the `candidates`
+    ///
+    /// The expansion is not limited, so that a query matches the same points with and without
+    /// a full-text index.
+    pub fn expand<'a>(&self, candidates: impl Iterator<Item = (&'a str, TokenId)>) -> Vec<TokenId> {
+        candidates
+            .filter(|(token, _)| self.is_match(token))
+            .map(|(_, token_id)| token_id)
+            .collect()
+    }
+}
+
+/// Vocabulary of an index in lexicographic order.
+///
+/// Tokens sharing a prefix form a contiguous range, which is found with a binary search
+/// instead of scanning the whole vocabulary.
+#[derive(Debug, Default)]
+pub struct SortedVocab {
+    tokens: Vec<(Box<str>, TokenId)>,
+}
+
+impl SortedVocab {
+    pub fn new<'a>(tokens: impl Iterator<Item = (&'a str, TokenId)>) -> Self {
+        let mut tokens: Vec<_> = tokens
+            .map(|(token, token_id)| (Box::from(token), token_id))
+            .collect();
+        tokens.sort_unstable();
+        Self { tokens }
+    }
+
+    /// Tokens of the `index` vocabulary which have any postings
+    pub fn from_index(index: &impl InvertedIndex, hw_counter: &HardwareCounterCell) -> Self {
+        Self::new(
+            index
+                .vocab_with_postings_len_iter()
+                .filter(|(_, postings_len)| *postings_len > 0)
+                .filter_map(|(token, _)| {
+                    let token_id = index.get_token_id(token, hw_counter)?;
+                    Some((token, token_id))
+                }),
+        )
+    }
+
+    /// Tokens starting with `prefix`
+    pub fn with_prefix(&self, prefix: &str) -> impl Iterator<Item = (&str, TokenId)> + '_ {
+        let start = self
+            .tokens
+            .partition_point(|(token, _)| token.as_ref() < prefix);
+        let len = self.tokens[start..].partition_point(|(token, _)| token.starts_with(prefix));
+        self.tokens[start..start + len]
+            .iter()
+            .map(|(token, token_id)| (token.as_ref(), *token_id))
+    }
+}
+
+/// Levenshtein distance between `a` and `b`, if it does not exceed `max_distance`.
+///
+/// Computation stops as soon as all of the cells of a row exceed `max_distance`,
+/// as the distance can only grow from there.
+fn bounded_levenshtein(a: &[char], b: &str, max_distance: usize) -> Option<usize> {
+    let mut prev_row: Vec<usize> = (0..=a.len()).collect();
+    let mut row = vec![0; a.len() + 1];
+
+    for (i, b_char) in b.chars().enumerate() {
+        row[0] = i + 1;
+        let mut row_min = row[0];
+
+        for (j, &a_char) in a.iter().enumerate() {
+            let substitution = prev_row[j] + usize::from(a_char != b_char);
+            let insertion = row[j] + 1;
+            let deletion = prev_row[j + 1] + 1;
+            row[j + 1] = substitution.min(insertion).min(deletion);
+            row_min = row_min.min(row[j + 1]);
+        }
+
+        if row_min > max_distance {
+            return None;
+        }
+
+        std::mem::swap(&mut prev_row, &mut row);
+    }
+
+    let distance = prev_row[a.len()];
+    (distance <= max_distance).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_levenshtein() {
+        let distance =
+            |a: &str, b: &str, max| bounded_levenshtein(&a.chars().collect::<Vec<_>>(), b, max);
+
+        assert_eq!(distance("kitten", "sitting", 3), Some(3));
+        assert_eq!(distance("kitten", "sitting", 2), None);
+        assert_eq!(distance("", "ab", 2), Some(2));
+        assert_eq!(distance("ab", "", 1), None);
+        assert_eq!(distance("мир", "мор", 1), Some(1));
+        assert_eq!(distance("same", "same", 0), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_matcher() {
+        let matcher = FuzzyMatcher::new("iphone", 1, 0);
+        assert!(matcher.is_match("iphone"));
+        assert!(matcher.is_match("iphane"));
+        assert!(matcher.is_match("phone"));
+        assert!(matcher.is_match("iphones"));
+        assert!(!matcher.is_match("iphonexs"));
+        assert!(!matcher.is_match("ifone"));
+
+        // The first characters must match exactly
+        let matcher = FuzzyMatcher::new("iphone", 2, 2);
+        assert!(matcher.is_match("ipone"));
+        assert!(!matcher.is_match("phone"));
+        assert!(!matcher.is_match("aphone"));
+
+        // Prefix longer than the query
+        let matcher = FuzzyMatcher::new("ip", 1, 5);
+        assert!(matcher.is_match("ip"));
+        assert!(matcher.is_match("ipa"));
+        assert!(!matcher.is_match("i"));
+
+        // Number of edits is limited
+        let matcher = FuzzyMatcher::new("abc", 10, 0);
+        assert!(matcher.is_match("a"));
+        assert!(!matcher.is_match("xyz"));
+    }
+
+    #[test]
+    fn test_sorted_vocab() {
+        let vocab = SortedVocab::new(
+            ["iphone", "ipad", "phone", "ip", "iphones", "i", "j"]
+                .into_iter()
+                .zip(0..),
+        );
+
+        let with_prefix = |prefix| {
+            vocab
+                .with_prefix(prefix)
+                .map(|(token, _)| token)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(with_prefix("ip"), ["ip", "ipad", "iphone", "iphones"]);
+        assert_eq!(with_prefix("iphone"), ["iphone", "iphones"]);
+        assert_eq!(with_prefix("x"), Vec::<&str>::new());
+        assert_eq!(with_prefix("").len(), 7);
+    }
+
+    #[test]
+    fn test_fuzzy_expand() {
+        let tokens: Vec<_> = (0..1000).map(|i| format!("ab{i:03}")).collect();
+        let candidates = tokens
+            .iter()
+            .enumerate()
+            .map(|(token_id, token)| (token.as_str(), token_id as TokenId));
+
+        // Every token differing in at most two of the digits matches, there is no limit
+        let matcher = FuzzyMatcher::new("ab000", 2, 0);
+        let expanded = matcher.expand(candidates);
+        let expected: Vec<_> = tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| token.bytes().filter(|&c| c != b'0').count() <= 4)
+            .map(|(token_id, _)| token_id as TokenId)
+            .collect();
+        assert_eq!(expected.len(), 1 + 3 * 9 + 3 * 81);
+        assert_eq!(expanded, expected);
+    }
+}
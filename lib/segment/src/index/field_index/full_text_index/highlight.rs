@@ -0,0 +1,355 @@
+//! Highlighting of the text matched by full-text conditions and queries.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use ahash::AHashSet;
+
+use super::fuzzy::FuzzyMatcher;
+use super::tokenizers::Tokenizer;
+use crate::data_types::index::TextIndexParams;
+use crate::types::{Match, MatchFuzzy, MatchPhrase, MatchText, MatchTextAny};
+
+/// Finds the words of a text, which are matched by the text conditions of a field.
+///
+/// The text is split into words the same way the tokenizer does, and each word is tokenized separately,
+/// so that the matched tokens can be mapped back to the original text.
+#[derive(Debug, Clone)]
+pub struct TextHighlighter {
+    tokenizer: Tokenizer,
+    matchers: Vec<TokensMatcher>,
+}
+
+#[derive(Debug, Clone)]
+enum TokensMatcher {
+    /// Word matches, if any of its tokens is in the set
+    Tokens(AHashSet<String>),
+    /// Word matches, if it contains all tokens of any of the query words.
+    /// Each query token is given along with its synonyms, any of which may be present.
+    ///
+    /// A query word can have many tokens, e.g. n-grams, all of which must be matched.
+    AllTokens(Vec<Vec<AHashSet<String>>>),
+    /// Consecutive words match, if their tokens contain the sequence
+    Phrase(Vec<String>),
+    /// Word matches, if any of its tokens is similar to a query token
+    Fuzzy(Vec<FuzzyMatcher>),
+}
+
+impl TextHighlighter {
+    pub fn new(params: &TextIndexParams) -> Self {
+        Self {
+            tokenizer: Tokenizer::new_from_text_index_params(params),
+            matchers: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.matchers.is_empty()
+    }
+
+    /// Highlight the matches of a full-text condition, other conditions are ignored
+    pub fn add_condition(&mut self, condition: &Match) {
+        match condition {
+            Match::Text(MatchText { text }) => {
+                let query_words: Vec<_> = self
+                    .tokenizer
+                    .split_words(text)
+                    .into_iter()
+                    .filter_map(|(_, word)| {
+                        let mut tokens = Vec::new();
+                        self.tokenizer
+                            .tokenize_query(word, |token| tokens.push(self.with_synonyms(token)));
+                        (!tokens.is_empty()).then_some(tokens)
+                    })
+                    .collect();
+                if !query_words.is_empty() {
+                    self.matchers.push(TokensMatcher::AllTokens(query_words));
+                }
+            }
+            Match::TextAny(MatchTextAny { text_any }) => self.add_text_query(text_any),
+            Match::Phrase(MatchPhrase { phrase }) => {
+                let mut tokens = Vec::new();
+                self.tokenizer
+                    .tokenize_doc(phrase, |token| tokens.push(token.into_owned()));
+                if !tokens.is_empty() {
+                    self.matchers.push(TokensMatcher::Phrase(tokens));
+                }
+            }
+            Match::Fuzzy(MatchFuzzy {
+                text,
+                max_edits,
+                prefix_len,
+            }) => {
+                let mut matchers = Vec::new();
+                self.tokenizer.tokenize_query(text, |token| {
+                    matchers.push(FuzzyMatcher::new(
+                        &token,
+                        *max_edits,
+                        prefix_len.unwrap_or(0),
+                    ));
+                });
+                if !matchers.is_empty() {
+                    self.matchers.push(TokensMatcher::Fuzzy(matchers));
+                }
+            }
+            Match::Value(_) | Match::Any(_) | Match::Except(_) => {}
+        }
+    }
+
+    /// Highlight the words, containing any of the query tokens or their synonyms
+    pub fn add_text_query(&mut self, text: &str) {
+        let mut tokens = AHashSet::new();
+        self.tokenizer
+            .tokenize_query(text, |token| tokens.extend(self.with_synonyms(token)));
+        if !tokens.is_empty() {
+            self.matchers.push(TokensMatcher::Tokens(tokens));
+        }
+    }
+
+    /// Query token along with its synonyms
+    fn with_synonyms(&self, token: Cow<str>) -> AHashSet<String> {
+        let mut tokens: AHashSet<_> = self.tokenizer.synonyms(&token).iter().cloned().collect();
+        tokens.insert(token.into_owned());
+        tokens
+    }
+
+    /// Byte ranges of the matched words in the text, in order of their appearance
+    pub fn highlight(&self, text: &str) -> Vec<Range<usize>> {
+        let words = self.tokenizer.split_words(text);
+        let words_tokens: Vec<Vec<String>> = words
+            .iter()
+            .map(|(_, word)| {
+                let mut tokens = Vec::new();
+                self.tokenizer
+                    .tokenize_doc(word, |token| tokens.push(token.into_owned()));
+                tokens
+            })
+            .collect();
+
+        let mut matched = vec![false; words.len()];
+        for matcher in &self.matchers {
+            match matcher {
+                TokensMatcher::Tokens(query_tokens) => {
+                    for (is_matched, tokens) in matched.iter_mut().zip(&words_tokens) {
+                        *is_matched |= tokens.iter().any(|token| query_tokens.contains(token));
+                    }
+                }
+                TokensMatcher::AllTokens(query_words) => {
+                    for (is_matched, tokens) in matched.iter_mut().zip(&words_tokens) {
+                        *is_matched |= query_words.iter().any(|query_tokens| {
+                            query_tokens.iter().all(|alternatives| {
+                                tokens.iter().any(|token| alternatives.contains(token))
+                            })
+                        });
+                    }
+                }
+                TokensMatcher::Fuzzy(fuzzy_matchers) => {
+                    for (is_matched, tokens) in matched.iter_mut().zip(&words_tokens) {
+                        *is_matched |= tokens.iter().any(|token| {
+                            fuzzy_matchers.iter().any(|matcher| matcher.is_match(token))
+                        });
+                    }
+                }
+                TokensMatcher::Phrase(phrase) => {
+                    // Tokens of the whole text, along with the index of their word
+                    let text_tokens: Vec<(usize, &str)> = words_tokens
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(word_idx, tokens)| {
+                            tokens.iter().map(move |token| (word_idx, token.as_str()))
+                        })
+                        .collect();
+
+                    for window in text_tokens.windows(phrase.len()) {
+                        let is_phrase = window
+                            .iter()
+                            .zip(phrase)
+                            .all(|((_, token), phrase_token)| token == phrase_token);
+                        if is_phrase {
+                            for (word_idx, _) in window {
+                                matched[*word_idx] = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        words
+            .into_iter()
+            .zip(matched)
+            .filter(|(_, is_matched)| *is_matched)
+            .map(|((offset, word), _)| offset..offset + word.len())
+            .collect()
+    }
+}
+
+/// Converts ordered byte ranges of the text into `[start, end)` character offsets
+pub fn char_offsets(text: &str, ranges: &[Range<usize>]) -> Vec<[usize; 2]> {
+    let mut byte_pos = 0;
+    let mut char_pos = 0;
+    let mut to_char_pos = |byte_offset: usize| {
+        char_pos += text[byte_pos..byte_offset].chars().count();
+        byte_pos = byte_offset;
+        char_pos
+    };
+
+    ranges
+        .iter()
+        .map(|range| [to_char_pos(range.start), to_char_pos(range.end)])
+        .collect()
+}
+
+/// Fragments of the text around the ordered byte ranges, with the ranges wrapped into the tags.
+///
+/// Ranges, which are closer than `2 * context` characters to each other, share a fragment.
+pub fn snippets(
+    text: &str,
+    ranges: &[Range<usize>],
+    pre_tag: &str,
+    post_tag: &str,
+    context: usize,
+) -> Vec<String> {
+    let mut snippets = Vec::new();
+
+    let mut ranges = ranges.iter().peekable();
+    while let Some(first) = ranges.next() {
+        let mut snippet = String::new();
+        let mut cursor = chars_before(text, first.start, context);
+        let mut range = first;
+        loop {
+            snippet.push_str(&text[cursor..range.start]);
+            snippet.push_str(pre_tag);
+            snippet.push_str(&text[range.clone()]);
+            snippet.push_str(post_tag);
+            cursor = range.end;
+
+            let fragment_end = chars_after(text, range.end, context);
+            match ranges.next_if(|next| chars_before(text, next.start, context) <= fragment_end) {
+                Some(next) => range = next,
+                None => {
+                    snippet.push_str(&text[cursor..fragment_end]);
+                    break;
+                }
+            }
+        }
+        snippets.push(snippet);
+    }
+
+    snippets
+}
+
+/// Byte position `count` characters before `pos`, or the start of the text
+fn chars_before(text: &str, pos: usize, count: usize) -> usize {
+    if count == 0 {
+        return pos;
+    }
+    text[..pos]
+        .char_indices()
+        .nth_back(count - 1)
+        .map_or(0, |(idx, _)| idx)
+}
+
+/// Byte position `count` characters after `pos`, or the end of the text
+fn chars_after(text: &str, pos: usize, count: usize) -> usize {
+    text[pos..]
+        .char_indices()
+        .nth(count)
+        .map_or(text.len(), |(idx, _)| pos + idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::index::{TextIndexType, TokenizerType};
+
+    fn highlighter(tokenizer: TokenizerType) -> TextHighlighter {
+        TextHighlighter::new(&TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer,
+            ..Default::default()
+        })
+    }
+
+    fn highlighted<'a>(text: &'a str, highlighter: &TextHighlighter) -> Vec<&'a str> {
+        highlighter
+            .highlight(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_highlight_conditions() {
+        let text = "The quick brown fox, and the lazy dog. Quick!";
+
+        let mut text_highlighter = highlighter(TokenizerType::Word);
+        text_highlighter.add_condition(&Match::Text("quick dog".into()));
+        assert_eq!(
+            highlighted(text, &text_highlighter),
+            ["quick", "dog", "Quick"]
+        );
+
+        let mut phrase_highlighter = highlighter(TokenizerType::Word);
+        phrase_highlighter.add_condition(&Match::Phrase("the lazy".into()));
+        assert_eq!(highlighted(text, &phrase_highlighter), ["the", "lazy"]);
+
+        let mut fuzzy_highlighter = highlighter(TokenizerType::Word);
+        fuzzy_highlighter.add_condition(&Match::Fuzzy(MatchFuzzy {
+            text: "brwn".to_string(),
+            max_edits: 1,
+            prefix_len: None,
+        }));
+        assert_eq!(highlighted(text, &fuzzy_highlighter), ["brown"]);
+
+        // Punctuation stays in the words of the whitespace tokenizer
+        let mut whitespace_highlighter = highlighter(TokenizerType::Whitespace);
+        whitespace_highlighter.add_condition(&Match::Text("quick!".into()));
+        assert_eq!(highlighted(text, &whitespace_highlighter), ["Quick!"]);
+
+        let mut prefix_highlighter = highlighter(TokenizerType::Prefix);
+        prefix_highlighter.add_text_query("qui la");
+        assert_eq!(
+            highlighted(text, &prefix_highlighter),
+            ["quick", "lazy", "Quick"]
+        );
+
+        // Words containing only some of the n-grams of a query word are not matched
+        let mut ngram_highlighter = highlighter(TokenizerType::Ngram { min: 3, max: 3 });
+        ngram_highlighter.add_condition(&Match::Text("ello".into()));
+        assert_eq!(
+            highlighted("shell allow hello yellow", &ngram_highlighter),
+            ["hello", "yellow"]
+        );
+        let mut ngram_highlighter = highlighter(TokenizerType::Ngram { min: 3, max: 3 });
+        ngram_highlighter.add_condition(&Match::TextAny("ello".into()));
+        assert_eq!(
+            highlighted("shell allow hello yellow", &ngram_highlighter),
+            ["shell", "allow", "hello", "yellow"]
+        );
+
+        let mut empty_highlighter = highlighter(TokenizerType::Word);
+        empty_highlighter.add_condition(&Match::Text("cat".into()));
+        assert!(empty_highlighter.highlight(text).is_empty());
+    }
+
+    #[test]
+    fn test_offsets_and_snippets() {
+        let text = "Ünïcode text: the café is open, the café is cozy";
+        let mut text_highlighter = highlighter(TokenizerType::Word);
+        text_highlighter.add_text_query("café");
+        let ranges = text_highlighter.highlight(text);
+
+        assert_eq!(char_offsets(text, &ranges), [[18, 22], [36, 40]]);
+
+        assert_eq!(
+            snippets(text, &ranges, "<b>", "</b>", 4),
+            ["the <b>café</b> is ", "the <b>café</b> is "]
+        );
+        assert_eq!(
+            snippets(text, &ranges, "<b>", "</b>", 10),
+            ["text: the <b>café</b> is open, the <b>café</b> is cozy"]
+        );
+        assert_eq!(snippets(text, &ranges, "[", "]", 0), ["[café]", "[café]"]);
+    }
+}
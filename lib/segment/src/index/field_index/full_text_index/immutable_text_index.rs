@@ -1,7 +1,9 @@
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use common::types::PointOffsetType;
 
+use super::fuzzy::SortedVocab;
 use super::inverted_index::InvertedIndex;
 use super::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
 #[cfg(feature = "rocksdb")]
@@ -25,6 +27,8 @@ pub struct ImmutableFullTextIndex {
     pub(super) tokenizer: Tokenizer,
     // Backing storage, source of state, persists deletions
     pub(super) storage: Storage,
+    // Built on the first fuzzy query, vocabulary does not change after loading
+    pub(super) sorted_vocab: OnceLock<SortedVocab>,
 }
 
 pub(super) enum Storage {
@@ -61,6 +65,7 @@ impl ImmutableFullTextIndex {
             inverted_index: ImmutableInvertedIndex::from(mutable),
             tokenizer,
             storage: Storage::RocksDb(db_wrapper),
+            sorted_vocab: OnceLock::new(),
         }))
     }
 
@@ -81,6 +86,7 @@ impl ImmutableFullTextIndex {
             inverted_index,
             storage: Storage::Mmap(Box::new(index)),
             tokenizer,
+            sorted_vocab: OnceLock::new(),
         }
     }
 
@@ -168,6 +174,7 @@ impl ImmutableFullTextIndex {
             inverted_index: ImmutableInvertedIndex::from(inverted_index),
             tokenizer,
             storage: Storage::RocksDb(db),
+            sorted_vocab: OnceLock::new(),
         }
     }
 
@@ -5,7 +5,8 @@ use ahash::AHashMap;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use itertools::Either;
-use posting_list::{PostingBuilder, PostingList, PostingListView, PostingValue};
+use posting_list::{PostingList, PostingListView, PostingValue};
+use zerocopy::little_endian::U32;
 
 use super::immutable_postings_enum::ImmutablePostings;
 use super::mmap_inverted_index::MmapInvertedIndex;
@@ -15,6 +16,7 @@ use super::positions::Positions;
 use super::postings_iterator::{
     intersect_compressed_postings_iterator, merge_compressed_postings_iterator,
 };
+use super::term_frequencies::TermFrequencies;
 use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::full_text_index::inverted_index::postings_iterator::{
@@ -27,7 +29,11 @@ pub struct ImmutableInvertedIndex {
     pub(in crate::index::field_index::full_text_index) postings: ImmutablePostings,
     pub(in crate::index::field_index::full_text_index) vocab: HashMap<String, TokenId>,
     pub(in crate::index::field_index::full_text_index) point_to_tokens_count: Vec<usize>,
+    /// Number of tokens in each document, including repeated ones
+    pub(in crate::index::field_index::full_text_index) point_to_doc_length: Vec<u32>,
     pub(in crate::index::field_index::full_text_index) points_count: usize,
+    /// Sum of document lengths of all active points
+    pub(in crate::index::field_index::full_text_index) total_document_length: usize,
 }
 
 impl ImmutableInvertedIndex {
@@ -35,7 +41,7 @@ impl ImmutableInvertedIndex {
     fn filter_has_all<'a>(
         &'a self,
         tokens: TokenSet,
-    ) -> impl Iterator<Item = PointOffsetType> + 'a {
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         // in case of immutable index, deleted documents are still in the postings
         let filter = move |idx| {
             self.point_to_tokens_count
@@ -68,11 +74,12 @@ impl ImmutableInvertedIndex {
         }
 
         match &self.postings {
-            ImmutablePostings::Ids(postings) => {
-                Either::Left(intersection(postings, tokens, filter))
+            ImmutablePostings::Ids(postings) => Box::new(intersection(postings, tokens, filter)),
+            ImmutablePostings::WithFrequencies(postings) => {
+                Box::new(intersection(postings, tokens, filter))
             }
             ImmutablePostings::WithPositions(postings) => {
-                Either::Right(intersection(postings, tokens, filter))
+                Box::new(intersection(postings, tokens, filter))
             }
         }
     }
@@ -81,7 +88,7 @@ impl ImmutableInvertedIndex {
     fn filter_has_any<'a>(
         &'a self,
         tokens: TokenSet,
-    ) -> impl Iterator<Item = PointOffsetType> + 'a {
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         // in case of immutable index, deleted documents are still in the postings
         let is_active = move |idx| {
             self.point_to_tokens_count
@@ -109,9 +116,12 @@ impl ImmutableInvertedIndex {
         }
 
         match &self.postings {
-            ImmutablePostings::Ids(postings) => Either::Left(merge(postings, tokens, is_active)),
+            ImmutablePostings::Ids(postings) => Box::new(merge(postings, tokens, is_active)),
+            ImmutablePostings::WithFrequencies(postings) => {
+                Box::new(merge(postings, tokens, is_active))
+            }
             ImmutablePostings::WithPositions(postings) => {
-                Either::Right(merge(postings, tokens, is_active))
+                Box::new(merge(postings, tokens, is_active))
             }
         }
     }
@@ -140,6 +150,9 @@ impl ImmutableInvertedIndex {
 
         match &self.postings {
             ImmutablePostings::Ids(postings) => check_intersection(postings, tokens, point_id),
+            ImmutablePostings::WithFrequencies(postings) => {
+                check_intersection(postings, tokens, point_id)
+            }
             ImmutablePostings::WithPositions(postings) => {
                 check_intersection(postings, tokens, point_id)
             }
@@ -170,6 +183,7 @@ impl ImmutableInvertedIndex {
 
         match &self.postings {
             ImmutablePostings::Ids(postings) => check_any(postings, tokens, point_id),
+            ImmutablePostings::WithFrequencies(postings) => check_any(postings, tokens, point_id),
             ImmutablePostings::WithPositions(postings) => check_any(postings, tokens, point_id),
         }
     }
@@ -195,7 +209,9 @@ impl ImmutableInvertedIndex {
                 ))
             }
             // cannot do phrase matching if there's no positional information
-            ImmutablePostings::Ids(_postings) => Either::Left(std::iter::empty()),
+            ImmutablePostings::Ids(_) | ImmutablePostings::WithFrequencies(_) => {
+                Either::Left(std::iter::empty())
+            }
         }
     }
 
@@ -217,7 +233,7 @@ impl ImmutableInvertedIndex {
                 })
             }
             // cannot do phrase matching if there's no positional information
-            ImmutablePostings::Ids(_postings) => false,
+            ImmutablePostings::Ids(_) | ImmutablePostings::WithFrequencies(_) => false,
         }
     }
 }
@@ -231,6 +247,7 @@ impl InvertedIndex for ImmutableInvertedIndex {
         &mut self,
         _idx: PointOffsetType,
         _tokens: super::TokenSet,
+        _frequencies: TermFrequencies,
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         Err(OperationError::service_error(
@@ -255,18 +272,25 @@ impl InvertedIndex for ImmutableInvertedIndex {
         }
         self.point_to_tokens_count[idx as usize] = 0;
         self.points_count -= 1;
+        if let Some(doc_length) = self.point_to_doc_length.get_mut(idx as usize) {
+            self.total_document_length -= *doc_length as usize;
+            *doc_length = 0;
+        }
         true
     }
 
     fn filter<'a>(
         &'a self,
         query: ParsedQuery,
-        _hw_counter: &'a HardwareCounterCell,
+        hw_counter: &'a HardwareCounterCell,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         match query {
             ParsedQuery::AllTokens(tokens) => Box::new(self.filter_has_all(tokens)),
             ParsedQuery::Phrase(tokens) => Box::new(self.filter_has_phrase(tokens)),
-            ParsedQuery::AnyTokens(tokens) => Box::new(self.filter_has_any(tokens)),
+            ParsedQuery::AnyTokens(tokens) => self.filter_has_any(tokens),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.filter_token_alternatives(token_sets, hw_counter)
+            }
         }
     }
 
@@ -274,6 +298,28 @@ impl InvertedIndex for ImmutableInvertedIndex {
         self.postings.posting_len(token_id)
     }
 
+    fn term_frequencies<'a>(
+        &'a self,
+        token_id: TokenId,
+        _hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = (PointOffsetType, u32)> + 'a> {
+        let Some(frequencies) = self.postings.iter_frequencies(token_id) else {
+            return Box::new(std::iter::empty());
+        };
+        // in case of immutable index, deleted documents are still in the postings
+        Box::new(frequencies.filter(|(point_id, _)| !self.values_is_empty(*point_id)))
+    }
+
+    fn document_length(&self, point_id: PointOffsetType) -> usize {
+        self.point_to_doc_length
+            .get(point_id as usize)
+            .map_or(0, |length| *length as usize)
+    }
+
+    fn total_document_length(&self) -> usize {
+        self.total_document_length
+    }
+
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
         self.vocab.iter().filter_map(|(token, &token_id)| {
             self.postings
@@ -287,6 +333,12 @@ impl InvertedIndex for ImmutableInvertedIndex {
             ParsedQuery::AllTokens(tokens) => self.check_has_subset(tokens, point_id),
             ParsedQuery::Phrase(phrase) => self.check_has_phrase(phrase, point_id),
             ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, point_id),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                !token_sets.is_empty()
+                    && token_sets
+                        .iter()
+                        .all(|tokens| self.check_has_any(tokens, point_id))
+            }
         }
     }
 
@@ -318,14 +370,32 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
             postings,
             vocab,
             point_to_tokens,
+            point_to_frequencies,
             point_to_doc,
             points_count,
+            total_document_length,
         } = index;
 
         let (postings, vocab, orig_to_new_token) = optimized_postings_and_vocab(postings, vocab);
 
+        let point_to_doc_length = point_to_frequencies
+            .iter()
+            .map(|frequencies| {
+                frequencies
+                    .as_ref()
+                    .map_or(0, TermFrequencies::document_length)
+            })
+            .collect();
+
         let postings = match point_to_doc {
-            None => ImmutablePostings::Ids(create_compressed_postings(postings)),
+            None => {
+                ImmutablePostings::WithFrequencies(create_compressed_postings_with_frequencies(
+                    postings,
+                    &point_to_tokens,
+                    &point_to_frequencies,
+                    &orig_to_new_token,
+                ))
+            }
             Some(point_to_doc) => {
                 ImmutablePostings::WithPositions(create_compressed_postings_with_positions(
                     postings,
@@ -347,7 +417,9 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
                         .unwrap_or(0)
                 })
                 .collect(),
+            point_to_doc_length,
             points_count,
+            total_document_length,
         }
     }
 }
@@ -386,17 +458,36 @@ fn optimized_postings_and_vocab(
     (postings, vocab, orig_to_new_token)
 }
 
-fn create_compressed_postings(
+fn create_compressed_postings_with_frequencies(
     postings: Vec<super::posting_list::PostingList>,
-) -> Vec<PostingList<()>> {
+    point_to_tokens: &[Option<TokenSet>],
+    point_to_frequencies: &[Option<TermFrequencies>],
+    orig_to_new_token: &AHashMap<TokenId, TokenId>,
+) -> Vec<PostingList<U32>> {
+    // frequencies are aligned with the original token ids of the documents
+    let mut new_to_orig_token = vec![0; postings.len()];
+    for (&orig_token, &new_token) in orig_to_new_token {
+        new_to_orig_token[new_token as usize] = orig_token;
+    }
+
     postings
         .into_iter()
-        .map(|posting| {
-            let mut builder = PostingBuilder::new();
-            for id in posting.iter() {
-                builder.add_id(id);
-            }
-            builder.build()
+        .zip(new_to_orig_token)
+        .map(|(posting, orig_token)| {
+            posting
+                .iter()
+                .map(|id| {
+                    let tokens = point_to_tokens[id as usize].as_ref();
+                    let frequencies = point_to_frequencies[id as usize].as_ref();
+                    let frequency = tokens
+                        .zip(frequencies)
+                        .and_then(|(tokens, frequencies)| frequencies.get(tokens, orig_token))
+                        .expect(
+                            "If id is in this token's posting list, the document should contain the token",
+                        );
+                    (id, U32::new(frequency))
+                })
+                .collect()
         })
         .collect()
 }
@@ -455,6 +546,12 @@ impl From<&MmapInvertedIndex> for ImmutableInvertedIndex {
                     .map(PostingListView::to_owned)
                     .collect(),
             ),
+            MmapPostingsEnum::WithFrequencies(postings) => ImmutablePostings::WithFrequencies(
+                postings
+                    .iter_postings()
+                    .map(PostingListView::to_owned)
+                    .collect(),
+            ),
             MmapPostingsEnum::WithPositions(postings) => ImmutablePostings::WithPositions(
                 postings
                     .iter_postings()
@@ -475,11 +572,24 @@ impl From<&MmapInvertedIndex> for ImmutableInvertedIndex {
             "postings and vocab must be the same size",
         );
 
+        let point_to_tokens_count = index.storage.point_to_tokens_count.to_vec();
+
+        // Legacy indexes without stored document lengths count each token once
+        let point_to_doc_length: Vec<u32> = match &index.storage.point_to_doc_length {
+            Some(point_to_doc_length) => point_to_doc_length.to_vec(),
+            None => point_to_tokens_count
+                .iter()
+                .map(|count| *count as u32)
+                .collect(),
+        };
+
         ImmutableInvertedIndex {
             postings,
             vocab,
-            point_to_tokens_count: index.storage.point_to_tokens_count.to_vec(),
+            point_to_tokens_count,
+            point_to_doc_length,
             points_count: index.points_count(),
+            total_document_length: index.total_document_length(),
         }
     }
 }
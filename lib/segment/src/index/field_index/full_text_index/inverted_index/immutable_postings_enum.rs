@@ -1,6 +1,6 @@
-#[cfg(test)]
 use common::types::PointOffsetType;
 use posting_list::PostingList;
+use zerocopy::little_endian::U32;
 
 use super::positions::Positions;
 use crate::index::field_index::full_text_index::inverted_index::TokenId;
@@ -8,7 +8,11 @@ use crate::index::field_index::full_text_index::inverted_index::TokenId;
 #[cfg_attr(test, derive(Clone))]
 #[derive(Debug)]
 pub enum ImmutablePostings {
+    /// Legacy format, without term frequencies. Each token is counted once per document.
     Ids(Vec<PostingList<()>>),
+    /// Number of occurrences of the token in each document.
+    /// Stored as little-endian, so that the postings can be read directly from mmap.
+    WithFrequencies(Vec<PostingList<U32>>),
     WithPositions(Vec<PostingList<Positions>>),
 }
 
@@ -16,6 +20,7 @@ impl ImmutablePostings {
     pub fn len(&self) -> usize {
         match self {
             ImmutablePostings::Ids(lists) => lists.len(),
+            ImmutablePostings::WithFrequencies(lists) => lists.len(),
             ImmutablePostings::WithPositions(lists) => lists.len(),
         }
     }
@@ -25,28 +30,49 @@ impl ImmutablePostings {
             ImmutablePostings::Ids(postings) => {
                 postings.get(token as usize).map(|posting| posting.len())
             }
+            ImmutablePostings::WithFrequencies(postings) => {
+                postings.get(token as usize).map(|posting| posting.len())
+            }
             ImmutablePostings::WithPositions(postings) => {
                 postings.get(token as usize).map(|posting| posting.len())
             }
         }
     }
 
-    #[cfg(test)]
-    pub fn iter_ids(
+    /// Iterate over the points in the posting list of the token, along with the token frequency
+    pub fn iter_frequencies(
         &self,
         token_id: TokenId,
-    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
+    ) -> Option<Box<dyn Iterator<Item = (PointOffsetType, u32)> + '_>> {
         match self {
             ImmutablePostings::Ids(postings) => postings.get(token_id as usize).map(|posting| {
-                Box::new(posting.iter().map(|elem| elem.id))
-                    as Box<dyn Iterator<Item = PointOffsetType>>
+                Box::new(posting.iter().map(|elem| (elem.id, 1u32)))
+                    as Box<dyn Iterator<Item = (PointOffsetType, u32)>>
             }),
+            ImmutablePostings::WithFrequencies(postings) => {
+                postings.get(token_id as usize).map(|posting| {
+                    Box::new(posting.iter().map(|elem| (elem.id, elem.value.get())))
+                        as Box<dyn Iterator<Item = (PointOffsetType, u32)>>
+                })
+            }
             ImmutablePostings::WithPositions(postings) => {
                 postings.get(token_id as usize).map(|posting| {
-                    Box::new(posting.iter().map(|elem| elem.id))
-                        as Box<dyn Iterator<Item = PointOffsetType>>
+                    Box::new(
+                        posting
+                            .iter()
+                            .map(|elem| (elem.id, elem.value.len() as u32)),
+                    ) as Box<dyn Iterator<Item = (PointOffsetType, u32)>>
                 })
             }
         }
     }
+
+    #[cfg(test)]
+    pub fn iter_ids(
+        &self,
+        token_id: TokenId,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
+        self.iter_frequencies(token_id)
+            .map(|iter| Box::new(iter.map(|(id, _)| id)) as Box<dyn Iterator<Item = _>>)
+    }
 }
@@ -13,6 +13,7 @@ use posting_list::{
     PostingChunk, PostingList, PostingListComponents, PostingListView, PostingValue,
     RemainderPosting, SizedTypeFor, ValueHandler,
 };
+use zerocopy::little_endian::U32;
 use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
 
 use crate::index::field_index::full_text_index::inverted_index::TokenId;
@@ -32,6 +33,8 @@ pub(in crate::index::field_index::full_text_index) trait MmapPostingValue:
 
 impl MmapPostingValue for () {}
 
+impl MmapPostingValue for U32 {}
+
 impl MmapPostingValue for Positions {}
 
 #[derive(Debug, Default, Clone, FromBytes, Immutable, IntoBytes, KnownLayout)]
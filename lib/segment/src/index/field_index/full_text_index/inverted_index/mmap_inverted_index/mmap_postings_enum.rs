@@ -1,12 +1,14 @@
-#[cfg(test)]
 use common::types::PointOffsetType;
+use zerocopy::little_endian::U32;
 
 use crate::index::field_index::full_text_index::inverted_index::TokenId;
 use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::mmap_postings::MmapPostings;
 use super::super::positions::Positions;
 
 pub enum MmapPostingsEnum {
+    /// Legacy format, without term frequencies. Each token is counted once per document.
     Ids(MmapPostings<()>),
+    WithFrequencies(MmapPostings<U32>),
     WithPositions(MmapPostings<Positions>),
 }
 
@@ -14,6 +16,7 @@ impl MmapPostingsEnum {
     pub fn populate(&self) {
         match self {
             MmapPostingsEnum::Ids(postings) => postings.populate(),
+            MmapPostingsEnum::WithFrequencies(postings) => postings.populate(),
             MmapPostingsEnum::WithPositions(postings) => postings.populate(),
         }
     }
@@ -21,26 +24,44 @@ impl MmapPostingsEnum {
     pub fn posting_len(&self, token_id: TokenId) -> Option<usize> {
         match self {
             MmapPostingsEnum::Ids(postings) => postings.get(token_id).map(|view| view.len()),
+            MmapPostingsEnum::WithFrequencies(postings) => {
+                postings.get(token_id).map(|view| view.len())
+            }
             MmapPostingsEnum::WithPositions(postings) => {
                 postings.get(token_id).map(|view| view.len())
             }
         }
     }
 
-    #[cfg(test)]
-    pub fn iter_ids<'a>(
+    /// Iterate over the points in the posting list of the token, along with the token frequency
+    pub fn iter_frequencies<'a>(
         &'a self,
         token_id: TokenId,
-    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+    ) -> Option<Box<dyn Iterator<Item = (PointOffsetType, u32)> + 'a>> {
         match self {
             MmapPostingsEnum::Ids(postings) => postings.get(token_id).map(|view| {
-                Box::new(view.into_iter().map(|elem| elem.id))
-                    as Box<dyn Iterator<Item = PointOffsetType>>
+                Box::new(view.into_iter().map(|elem| (elem.id, 1u32)))
+                    as Box<dyn Iterator<Item = (PointOffsetType, u32)>>
+            }),
+            MmapPostingsEnum::WithFrequencies(postings) => postings.get(token_id).map(|view| {
+                Box::new(view.into_iter().map(|elem| (elem.id, elem.value.get())))
+                    as Box<dyn Iterator<Item = (PointOffsetType, u32)>>
             }),
             MmapPostingsEnum::WithPositions(postings) => postings.get(token_id).map(|view| {
-                Box::new(view.into_iter().map(|elem| elem.id))
-                    as Box<dyn Iterator<Item = PointOffsetType>>
+                Box::new(
+                    view.into_iter()
+                        .map(|elem| (elem.id, elem.value.len() as u32)),
+                ) as Box<dyn Iterator<Item = (PointOffsetType, u32)>>
             }),
         }
     }
+
+    #[cfg(test)]
+    pub fn iter_ids<'a>(
+        &'a self,
+        token_id: TokenId,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        self.iter_frequencies(token_id)
+            .map(|iter| Box::new(iter.map(|(id, _)| id)) as Box<dyn Iterator<Item = _>>)
+    }
 }
@@ -11,6 +11,7 @@ use memory::madvise::AdviceSetting;
 use memory::mmap_ops;
 use memory::mmap_type::{MmapBitSlice, MmapSlice};
 use mmap_postings::{MmapPostingValue, MmapPostings};
+use zerocopy::little_endian::U32;
 
 use super::immutable_inverted_index::ImmutableInvertedIndex;
 use super::immutable_postings_enum::ImmutablePostings;
@@ -19,6 +20,7 @@ use super::positions::Positions;
 use super::postings_iterator::{
     intersect_compressed_postings_iterator, merge_compressed_postings_iterator,
 };
+use super::term_frequencies::TermFrequencies;
 use super::{InvertedIndex, ParsedQuery, TokenId, TokenSet};
 use crate::common::Flusher;
 use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUpdateWrapper;
@@ -35,12 +37,15 @@ const POSTINGS_FILE: &str = "postings.dat";
 const VOCAB_FILE: &str = "vocab.dat";
 const POINT_TO_TOKENS_COUNT_FILE: &str = "point_to_tokens_count.dat";
 const DELETED_POINTS_FILE: &str = "deleted_points.dat";
+const POINT_TO_DOC_LENGTH_FILE: &str = "point_to_doc_length.dat";
 
 pub struct MmapInvertedIndex {
     pub(in crate::index::field_index::full_text_index) path: PathBuf,
     pub(in crate::index::field_index::full_text_index) storage: Storage,
     /// Number of points which are not deleted
     pub(in crate::index::field_index::full_text_index) active_points_count: usize,
+    /// Sum of document lengths of all active points
+    total_document_length: usize,
     is_on_disk: bool,
 }
 
@@ -50,6 +55,10 @@ pub(in crate::index::field_index::full_text_index) struct Storage {
     pub(in crate::index::field_index::full_text_index) point_to_tokens_count: MmapSlice<usize>,
     pub(in crate::index::field_index::full_text_index) deleted_points:
         MmapBitSliceBufferedUpdateWrapper,
+    /// Number of tokens in each document, including repeated ones.
+    ///
+    /// Not present in indexes created before term frequencies were stored.
+    pub(in crate::index::field_index::full_text_index) point_to_doc_length: Option<MmapSlice<u32>>,
 }
 
 impl MmapInvertedIndex {
@@ -58,7 +67,9 @@ impl MmapInvertedIndex {
             postings,
             vocab,
             point_to_tokens_count,
+            point_to_doc_length,
             points_count: _,
+            total_document_length: _,
         } = inverted_index;
 
         debug_assert_eq!(vocab.len(), postings.len());
@@ -67,9 +78,13 @@ impl MmapInvertedIndex {
         let vocab_path = path.join(VOCAB_FILE);
         let point_to_tokens_count_path = path.join(POINT_TO_TOKENS_COUNT_FILE);
         let deleted_points_path = path.join(DELETED_POINTS_FILE);
+        let point_to_doc_length_path = path.join(POINT_TO_DOC_LENGTH_FILE);
 
         match postings {
             ImmutablePostings::Ids(postings) => MmapPostings::create(postings_path, postings)?,
+            ImmutablePostings::WithFrequencies(postings) => {
+                MmapPostings::create(postings_path, postings)?
+            }
             ImmutablePostings::WithPositions(postings) => {
                 MmapPostings::create(postings_path, postings)?
             }
@@ -96,6 +111,11 @@ impl MmapInvertedIndex {
 
         MmapSlice::create(&point_to_tokens_count_path, point_to_tokens_count_iter)?;
 
+        MmapSlice::create(
+            &point_to_doc_length_path,
+            point_to_doc_length.iter().copied(),
+        )?;
+
         Ok(())
     }
 
@@ -108,18 +128,25 @@ impl MmapInvertedIndex {
         let vocab_path = path.join(VOCAB_FILE);
         let point_to_tokens_count_path = path.join(POINT_TO_TOKENS_COUNT_FILE);
         let deleted_points_path = path.join(DELETED_POINTS_FILE);
+        let point_to_doc_length_path = path.join(POINT_TO_DOC_LENGTH_FILE);
 
         // If postings don't exist, assume the index doesn't exist on disk
         if !postings_path.is_file() {
             return Ok(None);
         }
 
-        let postings = match has_positions {
-            false => MmapPostingsEnum::Ids(MmapPostings::<()>::open(&postings_path, populate)?),
-            true => MmapPostingsEnum::WithPositions(MmapPostings::<Positions>::open(
+        // Legacy indexes have neither document lengths nor term frequencies in the postings
+        let has_frequencies = point_to_doc_length_path.is_file();
+
+        let postings = if has_positions {
+            MmapPostingsEnum::WithPositions(MmapPostings::<Positions>::open(
                 &postings_path,
                 populate,
-            )?),
+            )?)
+        } else if has_frequencies {
+            MmapPostingsEnum::WithFrequencies(MmapPostings::<U32>::open(&postings_path, populate)?)
+        } else {
+            MmapPostingsEnum::Ids(MmapPostings::<()>::open(&postings_path, populate)?)
         };
         let vocab = MmapHashMap::<str, TokenId>::open(&vocab_path, false)?;
 
@@ -139,6 +166,26 @@ impl MmapInvertedIndex {
         let deleted_points = MmapBitSliceBufferedUpdateWrapper::new(deleted);
         let points_count = point_to_tokens_count.len() - num_deleted_points;
 
+        let point_to_doc_length: Option<MmapSlice<u32>> = if has_frequencies {
+            Some(unsafe {
+                MmapSlice::try_from(mmap_ops::open_write_mmap(
+                    &point_to_doc_length_path,
+                    AdviceSetting::Global,
+                    populate,
+                )?)?
+            })
+        } else {
+            None
+        };
+
+        let total_document_length = (0..point_to_tokens_count.len())
+            .filter(|&idx| !deleted_points.get(idx).unwrap_or(true))
+            .map(|idx| match &point_to_doc_length {
+                Some(point_to_doc_length) => point_to_doc_length[idx] as usize,
+                None => point_to_tokens_count[idx],
+            })
+            .sum();
+
         Ok(Some(Self {
             path,
             storage: Storage {
@@ -146,8 +193,10 @@ impl MmapInvertedIndex {
                 vocab,
                 point_to_tokens_count,
                 deleted_points,
+                point_to_doc_length,
             },
             active_points_count: points_count,
+            total_document_length,
             is_on_disk: !populate,
         }))
     }
@@ -207,6 +256,7 @@ impl MmapInvertedIndex {
 
         match &self.storage.postings {
             MmapPostingsEnum::Ids(postings) => intersection(postings, tokens, filter),
+            MmapPostingsEnum::WithFrequencies(postings) => intersection(postings, tokens, filter),
             MmapPostingsEnum::WithPositions(postings) => intersection(postings, tokens, filter),
         }
     }
@@ -215,7 +265,7 @@ impl MmapInvertedIndex {
     fn filter_has_any<'a>(
         &'a self,
         tokens: TokenSet,
-    ) -> impl Iterator<Item = PointOffsetType> + 'a {
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         // in case of immutable index, deleted documents are still in the postings
         let is_active = move |idx| self.is_active(idx);
 
@@ -239,9 +289,12 @@ impl MmapInvertedIndex {
         }
 
         match &self.storage.postings {
-            MmapPostingsEnum::Ids(postings) => Either::Left(merge(postings, tokens, is_active)),
+            MmapPostingsEnum::Ids(postings) => Box::new(merge(postings, tokens, is_active)),
+            MmapPostingsEnum::WithFrequencies(postings) => {
+                Box::new(merge(postings, tokens, is_active))
+            }
             MmapPostingsEnum::WithPositions(postings) => {
-                Either::Right(merge(postings, tokens, is_active))
+                Box::new(merge(postings, tokens, is_active))
             }
         }
     }
@@ -275,6 +328,9 @@ impl MmapInvertedIndex {
 
         match &self.storage.postings {
             MmapPostingsEnum::Ids(postings) => check_intersection(postings, tokens, point_id),
+            MmapPostingsEnum::WithFrequencies(postings) => {
+                check_intersection(postings, tokens, point_id)
+            }
             MmapPostingsEnum::WithPositions(postings) => {
                 check_intersection(postings, tokens, point_id)
             }
@@ -305,6 +361,7 @@ impl MmapInvertedIndex {
 
         match &self.storage.postings {
             MmapPostingsEnum::Ids(postings) => check_any(postings, tokens, point_id),
+            MmapPostingsEnum::WithFrequencies(postings) => check_any(postings, tokens, point_id),
             MmapPostingsEnum::WithPositions(postings) => check_any(postings, tokens, point_id),
         }
     }
@@ -326,7 +383,9 @@ impl MmapInvertedIndex {
                 ))
             }
             // cannot do phrase matching if there's no positional information
-            MmapPostingsEnum::Ids(_postings) => Either::Left(std::iter::empty()),
+            MmapPostingsEnum::Ids(_) | MmapPostingsEnum::WithFrequencies(_) => {
+                Either::Left(std::iter::empty())
+            }
         }
     }
 
@@ -343,17 +402,21 @@ impl MmapInvertedIndex {
                 })
             }
             // cannot do phrase matching if there's no positional information
-            MmapPostingsEnum::Ids(_postings) => false,
+            MmapPostingsEnum::Ids(_) | MmapPostingsEnum::WithFrequencies(_) => false,
         }
     }
 
     pub fn files(&self) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             self.path.join(POSTINGS_FILE),
             self.path.join(VOCAB_FILE),
             self.path.join(POINT_TO_TOKENS_COUNT_FILE),
             self.path.join(DELETED_POINTS_FILE),
-        ]
+        ];
+        if self.storage.point_to_doc_length.is_some() {
+            files.push(self.path.join(POINT_TO_DOC_LENGTH_FILE));
+        }
+        files
     }
 
     pub fn immutable_files(&self) -> Vec<PathBuf> {
@@ -378,6 +441,9 @@ impl MmapInvertedIndex {
         self.storage.postings.populate();
         self.storage.vocab.populate()?;
         self.storage.point_to_tokens_count.populate()?;
+        if let Some(point_to_doc_length) = &self.storage.point_to_doc_length {
+            point_to_doc_length.populate()?;
+        }
         Ok(())
     }
 
@@ -401,6 +467,7 @@ impl InvertedIndex for MmapInvertedIndex {
         &mut self,
         _idx: PointOffsetType,
         _tokens: super::TokenSet,
+        _frequencies: TermFrequencies,
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         Err(OperationError::service_error(
@@ -428,10 +495,22 @@ impl InvertedIndex for MmapInvertedIndex {
             return false; // Already removed
         }
 
+        let doc_length = self.document_length(idx);
+
         self.storage.deleted_points.set(idx as usize, true);
         if let Some(count) = self.storage.point_to_tokens_count.get_mut(idx as usize) {
             *count = 0;
 
+            if let Some(length) = self
+                .storage
+                .point_to_doc_length
+                .as_mut()
+                .and_then(|point_to_doc_length| point_to_doc_length.get_mut(idx as usize))
+            {
+                *length = 0;
+            }
+            self.total_document_length -= doc_length;
+
             // `deleted_points`'s length can be larger than `point_to_tokens_count`'s length.
             // Only if the index is within bounds of `point_to_tokens_count`, we decrement the active points count.
             self.active_points_count -= 1;
@@ -443,12 +522,15 @@ impl InvertedIndex for MmapInvertedIndex {
     fn filter<'a>(
         &'a self,
         query: ParsedQuery,
-        _hw_counter: &HardwareCounterCell,
+        hw_counter: &'a HardwareCounterCell,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         match query {
             ParsedQuery::AllTokens(tokens) => self.filter_has_all(tokens),
             ParsedQuery::Phrase(phrase) => Box::new(self.filter_has_phrase(phrase)),
-            ParsedQuery::AnyTokens(tokens) => Box::new(self.filter_has_any(tokens)),
+            ParsedQuery::AnyTokens(tokens) => self.filter_has_any(tokens),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.filter_token_alternatives(token_sets, hw_counter)
+            }
         }
     }
 
@@ -460,6 +542,35 @@ impl InvertedIndex for MmapInvertedIndex {
         self.storage.postings.posting_len(token_id)
     }
 
+    fn term_frequencies<'a>(
+        &'a self,
+        token_id: TokenId,
+        _hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = (PointOffsetType, u32)> + 'a> {
+        match self.storage.postings.iter_frequencies(token_id) {
+            // in case of mmap immutable index, deleted points are still in the postings
+            Some(iter) => Box::new(iter.filter(|(idx, _)| self.is_active(*idx))),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    fn document_length(&self, point_id: PointOffsetType) -> usize {
+        if !self.is_active(point_id) {
+            return 0;
+        }
+        match &self.storage.point_to_doc_length {
+            Some(point_to_doc_length) => point_to_doc_length
+                .get(point_id as usize)
+                .map_or(0, |length| *length as usize),
+            // legacy index, each token is counted once
+            None => self.values_count(point_id),
+        }
+    }
+
+    fn total_document_length(&self) -> usize {
+        self.total_document_length
+    }
+
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
         self.iter_vocab().filter_map(move |(token, &token_id)| {
             self.storage
@@ -474,6 +585,12 @@ impl InvertedIndex for MmapInvertedIndex {
             ParsedQuery::AllTokens(tokens) => self.check_has_subset(tokens, point_id),
             ParsedQuery::Phrase(phrase) => self.check_has_phrase(phrase, point_id),
             ParsedQuery::AnyTokens(tokens) => self.check_has_any(tokens, point_id),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                !token_sets.is_empty()
+                    && token_sets
+                        .iter()
+                        .all(|tokens| self.check_has_any(tokens, point_id))
+            }
         }
     }
 
@@ -6,6 +6,7 @@ pub(super) mod mutable_inverted_index_builder;
 mod positions;
 mod posting_list;
 mod postings_iterator;
+pub(super) mod term_frequencies;
 
 use std::cmp::min;
 use std::collections::HashMap;
@@ -14,10 +15,11 @@ use ahash::AHashSet;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use itertools::Itertools;
+use term_frequencies::TermFrequencies;
 
 use crate::common::operation_error::OperationResult;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition, PrimaryCondition};
-use crate::index::query_estimator::expected_should_estimation;
+use crate::index::query_estimator::{combine_must_estimations, expected_should_estimation};
 use crate::types::{FieldCondition, Match, PayloadKeyType};
 
 pub type TokenId = u32;
@@ -161,6 +163,12 @@ pub enum ParsedQuery {
 
     /// All these tokens must be present in the document, in the same order as this query.
     Phrase(Document),
+
+    /// At least one token of each of these sets must be present in the document.
+    ///
+    /// Each set contains the alternatives of one query token: either its synonyms,
+    /// or the vocabulary tokens similar to it in a fuzzy query.
+    TokenAlternatives(Vec<TokenSet>),
 }
 
 pub trait InvertedIndex {
@@ -198,6 +206,7 @@ pub trait InvertedIndex {
         &mut self,
         idx: PointOffsetType,
         tokens: TokenSet,
+        frequencies: TermFrequencies,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()>;
 
@@ -216,9 +225,63 @@ pub trait InvertedIndex {
         hw_counter: &'a HardwareCounterCell,
     ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a>;
 
+    /// Iterate over points which contain at least one token of each of the sets.
+    ///
+    /// Iteration is driven by the set with the shortest postings,
+    /// the other sets are checked for each candidate point.
+    fn filter_token_alternatives<'a>(
+        &'a self,
+        mut token_sets: Vec<TokenSet>,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        if token_sets.is_empty() || token_sets.iter().any(TokenSet::is_empty) {
+            // Empty request or unmatched token -> no matches
+            return Box::new(std::iter::empty());
+        }
+
+        let postings_len = |tokens: &TokenSet| -> usize {
+            tokens
+                .tokens()
+                .iter()
+                .filter_map(|&token_id| self.get_posting_len(token_id, hw_counter))
+                .sum()
+        };
+        let (driving_idx, _) = token_sets
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, tokens)| postings_len(tokens))
+            .unwrap();
+        let driving_tokens = token_sets.swap_remove(driving_idx);
+
+        let other_queries: Vec<_> = token_sets.into_iter().map(ParsedQuery::AnyTokens).collect();
+
+        Box::new(
+            self.filter(ParsedQuery::AnyTokens(driving_tokens), hw_counter)
+                .filter(move |&point_id| {
+                    other_queries
+                        .iter()
+                        .all(|query| self.check_match(query, point_id))
+                }),
+        )
+    }
+
     fn get_posting_len(&self, token_id: TokenId, hw_counter: &HardwareCounterCell)
     -> Option<usize>;
 
+    /// Iterate over the points which contain the token,
+    /// along with the number of occurrences of the token in each of them.
+    fn term_frequencies<'a>(
+        &'a self,
+        token_id: TokenId,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = (PointOffsetType, u32)> + 'a>;
+
+    /// Number of tokens in the document of the point, including repeated ones
+    fn document_length(&self, point_id: PointOffsetType) -> usize;
+
+    /// Sum of [`Self::document_length`] over all indexed points
+    fn total_document_length(&self) -> usize;
+
     fn estimate_cardinality(
         &self,
         query: &ParsedQuery,
@@ -235,6 +298,9 @@ pub trait InvertedIndex {
             ParsedQuery::AnyTokens(tokens) => {
                 self.estimate_has_any_cardinality(tokens, condition, hw_counter)
             }
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.estimate_token_alternatives_cardinality(token_sets, condition, hw_counter)
+            }
         }
     }
 
@@ -323,6 +389,28 @@ pub trait InvertedIndex {
         }
     }
 
+    fn estimate_token_alternatives_cardinality(
+        &self,
+        token_sets: &[TokenSet],
+        condition: &FieldCondition,
+        hw_counter: &HardwareCounterCell,
+    ) -> CardinalityEstimation {
+        let points_count = self.points_count();
+
+        if token_sets.is_empty() || points_count == 0 {
+            return CardinalityEstimation::exact(0)
+                .with_primary_clause(PrimaryCondition::Condition(Box::new(condition.clone())));
+        }
+
+        // Each set is matched independently, like a `must` of `should` conditions
+        let estimations: Vec<_> = token_sets
+            .iter()
+            .map(|tokens| self.estimate_has_any_cardinality(tokens, condition, hw_counter))
+            .collect();
+
+        combine_must_estimations(&estimations, points_count)
+    }
+
     fn estimate_has_phrase_cardinality(
         &self,
         phrase: &Document,
@@ -393,7 +481,7 @@ mod tests {
     use rand::seq::SliceRandom;
     use rstest::rstest;
 
-    use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
+    use super::{Document, InvertedIndex, ParsedQuery, TermFrequencies, TokenId, TokenSet};
     use crate::index::field_index::full_text_index::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
     use crate::index::field_index::full_text_index::inverted_index::mmap_inverted_index::MmapInvertedIndex;
     use crate::index::field_index::full_text_index::inverted_index::mutable_inverted_index::MutableInvertedIndex;
@@ -457,8 +545,11 @@ mod tests {
                     .index_document(idx, Document(token_ids.clone()), &hw_counter)
                     .unwrap();
             }
-            let token_set = TokenSet::from_iter(token_ids);
-            index.index_tokens(idx, token_set, &hw_counter).unwrap();
+            let token_set = token_ids.iter().copied().collect::<TokenSet>();
+            let frequencies = TermFrequencies::new(&token_set, &token_ids);
+            index
+                .index_tokens(idx, token_set, frequencies, &hw_counter)
+                .unwrap();
         }
 
         // Remove some points
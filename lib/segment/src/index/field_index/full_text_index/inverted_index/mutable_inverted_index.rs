@@ -6,6 +6,7 @@ use itertools::Either;
 
 use super::posting_list::PostingList;
 use super::postings_iterator::{intersect_postings_iterator, merge_postings_iterator};
+use super::term_frequencies::TermFrequencies;
 use super::{Document, InvertedIndex, ParsedQuery, TokenId, TokenSet};
 use crate::common::operation_error::OperationResult;
 
@@ -15,11 +16,17 @@ pub struct MutableInvertedIndex {
     pub vocab: HashMap<String, TokenId>,
     pub(super) point_to_tokens: Vec<Option<TokenSet>>,
 
+    /// Number of occurrences of each token of `point_to_tokens` in the documents
+    pub(super) point_to_frequencies: Vec<Option<TermFrequencies>>,
+
     /// Optional additional structure to store positional information of tokens in the documents.
     ///
     /// Must be enabled explicitly.
     pub point_to_doc: Option<Vec<Option<Document>>>,
     pub(super) points_count: usize,
+
+    /// Sum of document lengths of all indexed points
+    pub(super) total_document_length: usize,
 }
 
 impl MutableInvertedIndex {
@@ -29,8 +36,10 @@ impl MutableInvertedIndex {
             postings: Vec::new(),
             vocab: HashMap::new(),
             point_to_tokens: Vec::new(),
+            point_to_frequencies: Vec::new(),
             point_to_doc: with_positions.then_some(Vec::new()),
             points_count: 0,
+            total_document_length: 0,
         }
     }
 
@@ -50,6 +59,10 @@ impl MutableInvertedIndex {
         self.point_to_tokens.get(idx as usize)?.as_ref()
     }
 
+    fn get_frequencies(&self, idx: PointOffsetType) -> Option<&TermFrequencies> {
+        self.point_to_frequencies.get(idx as usize)?.as_ref()
+    }
+
     fn get_document(&self, idx: PointOffsetType) -> Option<&Document> {
         self.point_to_doc.as_ref()?.get(idx as usize)?.as_ref()
     }
@@ -130,9 +143,11 @@ impl InvertedIndex for MutableInvertedIndex {
         &mut self,
         point_id: PointOffsetType,
         tokens: TokenSet,
+        frequencies: TermFrequencies,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         self.points_count += 1;
+        self.total_document_length += frequencies.document_length() as usize;
 
         let mut hw_cell_wb = hw_counter
             .payload_index_io_write_counter()
@@ -148,6 +163,17 @@ impl InvertedIndex for MutableInvertedIndex {
             self.point_to_tokens.resize_with(new_len, Default::default);
         }
 
+        if self.point_to_frequencies.len() <= point_id as usize {
+            let new_len = point_id as usize + 1;
+
+            hw_cell_wb.incr_delta(
+                (new_len - self.point_to_frequencies.len()) * size_of::<Option<TermFrequencies>>(),
+            );
+
+            self.point_to_frequencies
+                .resize_with(new_len, Default::default);
+        }
+
         for token_id in tokens.tokens() {
             let token_idx_usize = *token_id as usize;
 
@@ -164,6 +190,7 @@ impl InvertedIndex for MutableInvertedIndex {
                 .insert(point_id);
         }
         self.point_to_tokens[point_id as usize] = Some(tokens);
+        self.point_to_frequencies[point_id as usize] = Some(frequencies);
 
         Ok(())
     }
@@ -211,6 +238,14 @@ impl InvertedIndex for MutableInvertedIndex {
             point_to_doc[point_id as usize] = None;
         }
 
+        if let Some(frequencies) = self
+            .point_to_frequencies
+            .get_mut(point_id as usize)
+            .and_then(Option::take)
+        {
+            self.total_document_length -= frequencies.document_length() as usize;
+        }
+
         self.points_count -= 1;
 
         for removed_token in removed_token_set.tokens() {
@@ -222,15 +257,18 @@ impl InvertedIndex for MutableInvertedIndex {
         true
     }
 
-    fn filter(
-        &self,
+    fn filter<'a>(
+        &'a self,
         query: ParsedQuery,
-        _hw_counter: &HardwareCounterCell,
-    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
         match query {
             ParsedQuery::AllTokens(tokens) => Box::new(self.filter_has_all(tokens)),
             ParsedQuery::Phrase(phrase) => self.filter_has_phrase(phrase),
             ParsedQuery::AnyTokens(tokens) => Box::new(self.filter_has_any(tokens)),
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                self.filter_token_alternatives(token_sets, hw_counter)
+            }
         }
     }
 
@@ -238,6 +276,31 @@ impl InvertedIndex for MutableInvertedIndex {
         self.postings.get(token_id as usize).map(|x| x.len())
     }
 
+    fn term_frequencies<'a>(
+        &'a self,
+        token_id: TokenId,
+        _hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = (PointOffsetType, u32)> + 'a> {
+        let Some(posting) = self.postings.get(token_id as usize) else {
+            return Box::new(std::iter::empty());
+        };
+
+        Box::new(posting.iter().filter_map(move |point_id| {
+            let tokens = self.get_tokens(point_id)?;
+            let frequency = self.get_frequencies(point_id)?.get(tokens, token_id)?;
+            Some((point_id, frequency))
+        }))
+    }
+
+    fn document_length(&self, point_id: PointOffsetType) -> usize {
+        self.get_frequencies(point_id)
+            .map_or(0, |frequencies| frequencies.document_length() as usize)
+    }
+
+    fn total_document_length(&self) -> usize {
+        self.total_document_length
+    }
+
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
         self.vocab.iter().filter_map(|(token, &posting_idx)| {
             self.postings
@@ -272,6 +335,14 @@ impl InvertedIndex for MutableInvertedIndex {
                 // Check that at least one token is in document
                 doc.has_any(query)
             }
+            ParsedQuery::TokenAlternatives(token_sets) => {
+                let Some(doc) = self.get_tokens(point_id) else {
+                    return false;
+                };
+
+                // Check that at least one token of each set is in document
+                !token_sets.is_empty() && token_sets.iter().all(|tokens| doc.has_any(tokens))
+            }
         }
     }
 
@@ -2,6 +2,7 @@ use common::types::PointOffsetType;
 
 use super::InvertedIndex;
 use super::mutable_inverted_index::MutableInvertedIndex;
+use super::term_frequencies::TermFrequencies;
 #[cfg(feature = "rocksdb")]
 use crate::common::operation_error::OperationResult;
 use crate::index::field_index::full_text_index::inverted_index::{Document, TokenSet};
@@ -25,6 +26,9 @@ impl MutableInvertedIndexBuilder {
             self.index
                 .point_to_tokens
                 .resize_with(idx as usize + 1, Default::default);
+            self.index
+                .point_to_frequencies
+                .resize_with(idx as usize + 1, Default::default);
 
             if let Some(point_to_doc) = self.index.point_to_doc.as_mut() {
                 point_to_doc.resize_with(idx as usize + 1, Default::default);
@@ -33,14 +37,17 @@ impl MutableInvertedIndexBuilder {
 
         let tokens = self.index.register_tokens(str_tokens);
 
+        // insert as tokenset, along with the number of occurrences of each token
+        let tokens_set = tokens.iter().copied().collect::<TokenSet>();
+        let frequencies = TermFrequencies::new(&tokens_set, &tokens);
+        self.index.total_document_length += frequencies.document_length() as usize;
+        self.index.point_to_tokens[idx as usize] = Some(tokens_set);
+        self.index.point_to_frequencies[idx as usize] = Some(frequencies);
+
         // insert as whole document
         if let Some(point_to_doc) = self.index.point_to_doc.as_mut() {
-            point_to_doc[idx as usize] = Some(Document::new(tokens.clone()));
+            point_to_doc[idx as usize] = Some(Document::new(tokens));
         }
-
-        // insert as tokenset
-        let tokens_set = TokenSet::from_iter(tokens);
-        self.index.point_to_tokens[idx as usize] = Some(tokens_set);
     }
 
     #[cfg(feature = "rocksdb")]
@@ -8,6 +8,10 @@ use crate::index::field_index::full_text_index::inverted_index::{Document, Token
 pub struct Positions(Vec<u32>);
 
 impl Positions {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -0,0 +1,32 @@
+use crate::index::field_index::full_text_index::inverted_index::{TokenId, TokenSet};
+
+/// Number of occurrences of each token in a document.
+///
+/// Frequencies are aligned with the sorted tokens of the document's [`TokenSet`], so that the
+/// token ids are not stored twice.
+#[derive(Default, Clone, Debug)]
+pub struct TermFrequencies(Vec<u32>);
+
+impl TermFrequencies {
+    /// Count how many times each token of `token_set` appears in the document tokens
+    pub fn new(token_set: &TokenSet, document_tokens: &[TokenId]) -> Self {
+        let mut frequencies = vec![0; token_set.len()];
+        for token in document_tokens {
+            if let Ok(idx) = token_set.tokens().binary_search(token) {
+                frequencies[idx] += 1;
+            }
+        }
+        Self(frequencies)
+    }
+
+    /// Frequency of the `token` in the document, which is represented by the `token_set`
+    pub fn get(&self, token_set: &TokenSet, token: TokenId) -> Option<u32> {
+        let idx = token_set.tokens().binary_search(&token).ok()?;
+        self.0.get(idx).copied()
+    }
+
+    /// Total number of tokens in the document, including repeated ones
+    pub fn document_length(&self) -> u32 {
+        self.0.iter().sum()
+    }
+}
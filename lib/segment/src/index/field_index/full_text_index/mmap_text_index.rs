@@ -1,13 +1,16 @@
 use std::fs::{create_dir_all, remove_dir};
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use serde_json::Value;
 
+use super::fuzzy::SortedVocab;
 use super::inverted_index::immutable_inverted_index::ImmutableInvertedIndex;
 use super::inverted_index::mmap_inverted_index::MmapInvertedIndex;
 use super::inverted_index::mutable_inverted_index::MutableInvertedIndex;
+use super::inverted_index::term_frequencies::TermFrequencies;
 use super::inverted_index::{Document, InvertedIndex, TokenSet};
 use super::text_index::FullTextIndex;
 use super::tokenizers::Tokenizer;
@@ -22,6 +25,8 @@ use crate::index::field_index::{FieldIndexBuilderTrait, ValueIndexer};
 pub struct MmapFullTextIndex {
     pub(super) inverted_index: MmapInvertedIndex,
     pub(super) tokenizer: Tokenizer,
+    // Built on the first fuzzy query, vocabulary of an mmap index never changes
+    pub(super) sorted_vocab: OnceLock<SortedVocab>,
 }
 
 impl MmapFullTextIndex {
@@ -39,6 +44,7 @@ impl MmapFullTextIndex {
         Ok(inverted_index.map(|inverted_index| Self {
             inverted_index,
             tokenizer,
+            sorted_vocab: OnceLock::new(),
         }))
     }
 
@@ -148,8 +154,10 @@ impl ValueIndexer for FullTextMmapIndexBuilder {
                 .index_document(id, document, hw_counter)?;
         }
 
-        let token_set = TokenSet::from_iter(tokens);
-        self.mutable_index.index_tokens(id, token_set, hw_counter)?;
+        let token_set = tokens.iter().copied().collect::<TokenSet>();
+        let frequencies = TermFrequencies::new(&token_set, &tokens);
+        self.mutable_index
+            .index_tokens(id, token_set, frequencies, hw_counter)?;
 
         Ok(())
     }
@@ -204,6 +212,7 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
         let mmap_index = MmapFullTextIndex {
             inverted_index,
             tokenizer: tokenizer.clone(),
+            sorted_vocab: OnceLock::new(),
         };
 
         let text_index = if is_on_disk {
@@ -213,6 +222,7 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
                 inverted_index: immutable,
                 tokenizer,
                 storage: Storage::Mmap(Box::new(mmap_index)),
+                sorted_vocab: OnceLock::new(),
             })
         };
 
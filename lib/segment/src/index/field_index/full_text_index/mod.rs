@@ -1,8 +1,11 @@
+pub mod fuzzy;
+pub mod highlight;
 mod immutable_text_index;
 mod inverted_index;
 pub mod mmap_text_index;
 mod mutable_text_index;
 pub mod stop_words;
+pub mod synonyms;
 pub mod text_index;
 pub mod tokenizers;
 
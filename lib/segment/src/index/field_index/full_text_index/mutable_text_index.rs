@@ -11,6 +11,7 @@ use parking_lot::RwLock;
 
 use super::inverted_index::mutable_inverted_index::MutableInvertedIndex;
 use super::inverted_index::mutable_inverted_index_builder::MutableInvertedIndexBuilder;
+use super::inverted_index::term_frequencies::TermFrequencies;
 use super::inverted_index::{Document, InvertedIndex, TokenSet};
 use super::text_index::FullTextIndex;
 use super::tokenizers::Tokenizer;
@@ -246,16 +247,17 @@ impl MutableFullTextIndex {
                 .index_document(idx, document, hw_counter)?;
         }
 
-        let token_set = TokenSet::from_iter(tokens);
+        let token_set = tokens.iter().copied().collect::<TokenSet>();
+        let frequencies = TermFrequencies::new(&token_set, &tokens);
         self.inverted_index
-            .index_tokens(idx, token_set, hw_counter)?;
+            .index_tokens(idx, token_set, frequencies, hw_counter)?;
 
         let tokens_to_store = if phrase_matching {
             // store ordered tokens
             str_tokens
         } else {
-            // store sorted, unique tokens
-            str_tokens.into_iter().sorted().dedup().collect()
+            // store sorted tokens, keeping repeated ones for term frequencies
+            str_tokens.into_iter().sorted().collect()
         };
 
         let db_document = FullTextIndex::serialize_document(tokens_to_store)?;
@@ -413,6 +415,8 @@ mod tests {
             on_disk: None,
             stopwords: None,
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         {
@@ -0,0 +1,155 @@
+use ahash::AHashMap;
+use itertools::Itertools as _;
+
+use super::tokenizers::TokensProcessor;
+use crate::data_types::index::{SynonymsInterface, SynonymsSet};
+
+/// Query-time expansion of tokens into their synonyms.
+#[derive(Debug, Clone, Default)]
+pub struct Synonyms {
+    /// Processed token -> processed tokens it expands to, excluding itself
+    expansions: AHashMap<String, Vec<String>>,
+}
+
+impl Synonyms {
+    /// Synonyms are processed the same way as the tokens of the documents.
+    /// Entries, which are removed by the processing (e.g. stopwords) or consist of multiple words, are ignored.
+    pub fn new(option: &Option<SynonymsInterface>, tokens_processor: &TokensProcessor) -> Self {
+        let mut this = Self::default();
+
+        let Some(option) = option else {
+            return this;
+        };
+
+        let (groups, file) = match option {
+            SynonymsInterface::Groups(groups) => (Some(groups), None),
+            SynonymsInterface::Set(SynonymsSet { groups, file }) => {
+                (groups.as_ref(), file.as_ref())
+            }
+        };
+
+        for group in groups.into_iter().flatten() {
+            let group = Self::process_tokens(group.iter().map(String::as_str), tokens_processor);
+            this.add_equivalent(&group);
+        }
+
+        for line in file.into_iter().flat_map(|file| file.lines()) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match line.split_once("=>") {
+                Some((from, to)) => {
+                    let from = Self::process_tokens(from.split(','), tokens_processor);
+                    let to = Self::process_tokens(to.split(','), tokens_processor);
+                    for token in &from {
+                        this.add_expansions(token, &to);
+                    }
+                }
+                None => {
+                    let group = Self::process_tokens(line.split(','), tokens_processor);
+                    this.add_equivalent(&group);
+                }
+            }
+        }
+
+        this
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expansions.is_empty()
+    }
+
+    /// Synonyms of a processed token, excluding the token itself
+    pub fn expand(&self, token: &str) -> &[String] {
+        self.expansions
+            .get(token)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn process_tokens<'a>(
+        entries: impl Iterator<Item = &'a str>,
+        tokens_processor: &TokensProcessor,
+    ) -> Vec<String> {
+        entries
+            .map(str::trim)
+            .filter(|entry| !entry.contains(char::is_whitespace))
+            .filter_map(|entry| tokens_processor.process_token(entry, false))
+            .map(|token| token.into_owned())
+            .unique()
+            .collect()
+    }
+
+    fn add_equivalent(&mut self, group: &[String]) {
+        for token in group {
+            self.add_expansions(token, group);
+        }
+    }
+
+    fn add_expansions(&mut self, token: &str, synonyms: &[String]) {
+        let synonyms = synonyms.iter().filter(|synonym| *synonym != token);
+        let mut synonyms = synonyms.peekable();
+        if synonyms.peek().is_none() {
+            return;
+        }
+
+        let expansions = self.expansions.entry(token.to_string()).or_default();
+        for synonym in synonyms {
+            if !expansions.contains(synonym) {
+                expansions.push(synonym.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::data_types::index::StopwordsInterface;
+    use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
+
+    #[test]
+    fn test_synonyms() {
+        let stopwords = Some(StopwordsInterface::new_custom(&["the"]));
+        let tokens_processor = TokensProcessor::new(
+            true,
+            Arc::new(StopwordsFilter::new(&stopwords, true)),
+            Default::default(),
+            None,
+            None,
+            None,
+        );
+
+        let config = SynonymsInterface::Set(SynonymsSet {
+            groups: Some(vec![vec!["TV".to_string(), "television".to_string()]]),
+            file: Some(
+                "# comment\n\
+                 \n\
+                 telly, tv\n\
+                 laptop => notebook, computer\n\
+                 the, couch, sofa, big screen\n"
+                    .to_string(),
+            ),
+        });
+        let synonyms = Synonyms::new(&Some(config), &tokens_processor);
+
+        assert_eq!(synonyms.expand("tv"), ["television", "telly"]);
+        assert_eq!(synonyms.expand("television"), ["tv"]);
+        assert_eq!(synonyms.expand("telly"), ["tv"]);
+
+        // Mapping is one-way
+        assert_eq!(synonyms.expand("laptop"), ["notebook", "computer"]);
+        assert!(synonyms.expand("notebook").is_empty());
+
+        // Stopwords and multi-word entries are ignored
+        assert_eq!(synonyms.expand("couch"), ["sofa"]);
+        assert!(synonyms.expand("the").is_empty());
+        assert!(synonyms.expand("big screen").is_empty());
+
+        assert!(Synonyms::new(&None, &tokens_processor).is_empty());
+    }
+}
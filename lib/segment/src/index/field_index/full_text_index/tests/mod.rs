@@ -4,9 +4,15 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use tempfile::Builder;
 
-use crate::data_types::index::{TextIndexParams, TextIndexType, TokenizerType};
+use crate::data_types::index::{
+    BuiltinCharFilter, CharFilter, SynonymsInterface, SynonymsSet, TextIndexParams, TextIndexType,
+    TokenizerType,
+};
+use crate::data_types::text_query::TextQueryStatistics;
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
-use crate::index::field_index::{FieldIndexBuilderTrait as _, ValueIndexer};
+use crate::index::field_index::{FieldIndexBuilderTrait as _, PayloadFieldIndex, ValueIndexer};
+use crate::json_path::JsonPath;
+use crate::types::{FieldCondition, Match, MatchFuzzy};
 
 fn movie_titles() -> Vec<String> {
     vec![
@@ -164,6 +170,8 @@ fn test_prefix_search() {
         stopwords: None,
         on_disk: None,
         stemmer: None,
+        char_filters: None,
+        synonyms: None,
     };
 
     let mut index =
@@ -197,6 +205,286 @@ fn test_prefix_search() {
     assert!(index.parse_text_query("q231", &hw_counter).is_none());
 }
 
+#[test]
+fn test_ngram_search() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Ngram { min: 3, max: 3 },
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        phrase_matching: None,
+        stopwords: None,
+        on_disk: None,
+        stemmer: None,
+        char_filters: None,
+        synonyms: None,
+    };
+
+    let mutable_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut mutable_index =
+        FullTextIndex::new_gridstore(mutable_dir.path().to_path_buf(), config.clone(), true)
+            .unwrap()
+            .unwrap();
+
+    let mmap_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(mmap_dir.path().to_path_buf(), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    let immutable_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut immutable_builder =
+        FullTextIndex::builder_mmap(immutable_dir.path().to_path_buf(), config.clone(), false);
+    immutable_builder.init().unwrap();
+
+    for (i, text) in movie_titles().into_iter().enumerate() {
+        let values = vec![text];
+        mutable_index
+            .add_many(i as PointOffsetType, values.clone(), &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(i as PointOffsetType, values.clone(), &hw_counter)
+            .unwrap();
+        immutable_builder
+            .add_many(i as PointOffsetType, values, &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+    let immutable_index = immutable_builder.finalize().unwrap();
+
+    for index in [mutable_index, mmap_index, immutable_index] {
+        // "Little Lost Robot", "Nobody Here But—", "Robot AL-76 Goes Astray", "Robot Dreams"
+        let res: Vec<_> = index.query("OBO", &hw_counter).collect();
+        assert_eq!(res.len(), 4);
+
+        // Longer words are matched by all of their n-grams
+        let res: Vec<_> = index.query("bicentenn", &hw_counter).collect();
+        assert_eq!(res.len(), 1);
+
+        // Punctuation is kept inside of words
+        let query = index.parse_text_query("AL-7", &hw_counter).unwrap();
+        let res: Vec<_> = index.query("AL-7", &hw_counter).collect();
+        assert_eq!(res.len(), 1);
+        assert!(index.check_match(&query, res[0]));
+
+        assert!(index.parse_text_query("xyz", &hw_counter).is_none());
+    }
+}
+
+#[test]
+fn test_fuzzy_search() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        phrase_matching: None,
+        stopwords: None,
+        on_disk: None,
+        stemmer: None,
+        char_filters: None,
+        synonyms: None,
+    };
+
+    let mutable_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut mutable_index =
+        FullTextIndex::new_gridstore(mutable_dir.path().to_path_buf(), config.clone(), true)
+            .unwrap()
+            .unwrap();
+
+    let mmap_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(mmap_dir.path().to_path_buf(), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    let immutable_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut immutable_builder =
+        FullTextIndex::builder_mmap(immutable_dir.path().to_path_buf(), config.clone(), false);
+    immutable_builder.init().unwrap();
+
+    for (i, text) in movie_titles().into_iter().enumerate() {
+        let values = vec![text];
+        mutable_index
+            .add_many(i as PointOffsetType, values.clone(), &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(i as PointOffsetType, values.clone(), &hw_counter)
+            .unwrap();
+        immutable_builder
+            .add_many(i as PointOffsetType, values, &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+    let immutable_index = immutable_builder.finalize().unwrap();
+
+    let fuzzy_condition = |text: &str, max_edits, prefix_len| {
+        FieldCondition::new_match(
+            JsonPath::new("text"),
+            Match::Fuzzy(MatchFuzzy {
+                text: text.to_string(),
+                max_edits,
+                prefix_len,
+            }),
+        )
+    };
+
+    for index in [mutable_index, mmap_index, immutable_index] {
+        let search = |condition: &FieldCondition| -> Vec<_> {
+            index.filter(condition, &hw_counter).unwrap().collect()
+        };
+
+        // "Little Lost Robot", "Robot AL-76 Goes Astray", "Robot Dreams"
+        let condition = fuzzy_condition("robt", 1, None);
+        let res = search(&condition);
+        assert_eq!(res.len(), 3);
+        let estimation = index.estimate_cardinality(&condition, &hw_counter).unwrap();
+        assert!(estimation.min <= 3 && 3 <= estimation.max);
+
+        // All tokens must match
+        let res = search(&fuzzy_condition("robt dreems", 1, None));
+        assert_eq!(res.len(), 1);
+        let query = index
+            .parse_fuzzy_query(
+                &MatchFuzzy {
+                    text: "robt dreems".to_string(),
+                    max_edits: 1,
+                    prefix_len: None,
+                },
+                &hw_counter,
+            )
+            .unwrap();
+        assert!(index.check_match(&query, res[0]));
+
+        // Payload values are matched without the vocabulary
+        let fuzzy = MatchFuzzy {
+            text: "robt dreems".to_string(),
+            max_edits: 1,
+            prefix_len: None,
+        };
+        assert!(index.check_payload_fuzzy_match(&serde_json::json!("Robot Dreams"), &fuzzy));
+        assert!(
+            index.check_payload_fuzzy_match(&serde_json::json!(["Foo", "robot dreams"]), &fuzzy)
+        );
+        assert!(!index.check_payload_fuzzy_match(&serde_json::json!("Robot Visions"), &fuzzy));
+
+        // "First Law", "The Last Answer", "The Last Question", "The Last Trump",
+        // "Liar!", "Rain, Rain, Go Away"
+        let res = search(&fuzzy_condition("lair", 2, None));
+        assert_eq!(res.len(), 6);
+
+        // Excludes "Liar!" and "Rain, Rain, Go Away"
+        let res = search(&fuzzy_condition("lair", 2, Some(2)));
+        assert_eq!(res.len(), 4);
+
+        // Too far from anything in the vocabulary
+        let condition = fuzzy_condition("xyzzy", 2, None);
+        assert!(search(&condition).is_empty());
+        let estimation = index.estimate_cardinality(&condition, &hw_counter).unwrap();
+        assert_eq!(estimation.max, 0);
+    }
+}
+
+#[test]
+fn test_synonyms_and_char_filters() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        phrase_matching: None,
+        stopwords: None,
+        on_disk: None,
+        stemmer: None,
+        char_filters: Some(vec![CharFilter::Builtin(BuiltinCharFilter::AsciiFolding)]),
+        synonyms: Some(SynonymsInterface::Set(SynonymsSet {
+            groups: Some(vec![vec!["TV".to_string(), "télévision".to_string()]]),
+            file: Some("laptop => notebook".to_string()),
+        })),
+    };
+
+    let texts = [
+        "Café with a télévision",
+        "Smart TV",
+        "Cheap notebook",
+        "Laptop stand",
+    ];
+
+    let mutable_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut mutable_index =
+        FullTextIndex::new_gridstore(mutable_dir.path().to_path_buf(), config.clone(), true)
+            .unwrap()
+            .unwrap();
+
+    let mmap_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(mmap_dir.path().to_path_buf(), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    let immutable_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let mut immutable_builder =
+        FullTextIndex::builder_mmap(immutable_dir.path().to_path_buf(), config.clone(), false);
+    immutable_builder.init().unwrap();
+
+    for (i, text) in texts.into_iter().enumerate() {
+        let values = vec![text.to_string()];
+        mutable_index
+            .add_many(i as PointOffsetType, values.clone(), &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(i as PointOffsetType, values.clone(), &hw_counter)
+            .unwrap();
+        immutable_builder
+            .add_many(i as PointOffsetType, values, &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+    let immutable_index = immutable_builder.finalize().unwrap();
+
+    for index in [mutable_index, mmap_index, immutable_index] {
+        let search = |query: &str| -> Vec<_> {
+            let mut res: Vec<_> = index.query(query, &hw_counter).collect();
+            res.sort_unstable();
+            res
+        };
+
+        // Char filters are applied to both documents and queries
+        assert_eq!(search("cafe"), vec![0]);
+        assert_eq!(search("CAFÉ"), vec![0]);
+
+        // Synonyms are expanded at query time
+        assert_eq!(search("tv"), vec![0, 1]);
+        assert_eq!(search("television"), vec![0, 1]);
+        assert_eq!(search("smart television"), vec![1]);
+        assert!(search("smart cafe").is_empty());
+
+        // One-way mapping
+        assert_eq!(search("laptop"), vec![2, 3]);
+        assert_eq!(search("notebook"), vec![2]);
+
+        let query = index.parse_text_query("laptop", &hw_counter).unwrap();
+        assert!(index.check_match(&query, 2));
+        assert!(!index.check_match(&query, 1));
+
+        let query = index
+            .parse_text_any_query("tv laptop", &hw_counter)
+            .unwrap();
+        let mut res: Vec<_> = index.filter_query(query, &hw_counter).collect();
+        res.sort_unstable();
+        assert_eq!(res, vec![0, 1, 2, 3]);
+    }
+}
+
 #[test]
 fn test_phrase_matching() {
     let hw_counter = HardwareCounterCell::default();
@@ -213,6 +501,8 @@ fn test_phrase_matching() {
         phrase_matching: Some(true), // Enable phrase matching
         stopwords: None,
         stemmer: None,
+        char_filters: None,
+        synonyms: None,
     };
 
     let mut mutable_index =
@@ -305,3 +595,81 @@ fn test_phrase_matching() {
     check_matching(mutable_index);
     check_matching(mmap_index);
 }
+
+#[test]
+fn test_bm25_scores() {
+    let hw_counter = HardwareCounterCell::default();
+
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::default(),
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: Some(true),
+        on_disk: None,
+        phrase_matching: None,
+        stopwords: None,
+        stemmer: None,
+        char_filters: None,
+        synonyms: None,
+    };
+
+    let mut mutable_index =
+        FullTextIndex::builder_gridstore(temp_dir.path().to_path_buf(), config.clone())
+            .make_empty()
+            .unwrap();
+
+    let mut mmap_builder =
+        FullTextIndex::builder_mmap(temp_dir.path().to_path_buf(), config.clone(), true);
+    mmap_builder.init().unwrap();
+
+    let documents = vec![
+        (0, "the quick brown fox jumps over the lazy dog".to_string()),
+        (1, "fox fox fox".to_string()),
+        (2, "a fox".to_string()),
+        (3, "the lazy dog sleeps peacefully".to_string()),
+        (4, "the brown dog".to_string()),
+    ];
+
+    for (point_id, text) in documents {
+        mutable_index
+            .add_many(point_id, vec![text.clone()], &hw_counter)
+            .unwrap();
+        mmap_builder
+            .add_many(point_id, vec![text], &hw_counter)
+            .unwrap();
+    }
+
+    let mmap_index = mmap_builder.finalize().unwrap();
+
+    let check_scores = |index: FullTextIndex| {
+        let tokens = index.parse_scored_query_tokens("Fox unknown");
+        assert_eq!(tokens.len(), 2);
+
+        let mut statistics = TextQueryStatistics::default();
+        index.fill_text_query_statistics(&tokens, &[], &mut statistics, &hw_counter);
+        assert_eq!(statistics.points_count, 5);
+        assert_eq!(statistics.total_document_length, 9 + 3 + 2 + 5 + 3);
+        assert_eq!(statistics.document_frequencies["fox"], 3);
+        assert_eq!(statistics.document_frequencies["unknown"], 0);
+
+        let mut excluded_statistics = TextQueryStatistics::default();
+        index.fill_text_query_statistics(&tokens, &[1, 3], &mut excluded_statistics, &hw_counter);
+        assert_eq!(excluded_statistics.points_count, 3);
+        assert_eq!(excluded_statistics.total_document_length, 9 + 2 + 3);
+        assert_eq!(excluded_statistics.document_frequencies["fox"], 2);
+        assert_eq!(excluded_statistics.document_frequencies["unknown"], 0);
+
+        let scores = index.bm25_scores(&tokens, &statistics, &hw_counter);
+        assert_eq!(scores.len(), 3);
+
+        // Repeated term ranks first, then the shorter document
+        assert!(scores[&1] > scores[&2]);
+        assert!(scores[&2] > scores[&0]);
+        assert!(scores[&0] > 0.0);
+    };
+
+    check_scores(mutable_index);
+    check_scores(mmap_index);
+}
@@ -12,6 +12,7 @@ use crate::common::operation_error::OperationResult;
 #[cfg(feature = "rocksdb")]
 use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
 use crate::data_types::index::TextIndexParams;
+use crate::data_types::text_query::TextQueryStatistics;
 use crate::fixtures::payload_fixtures::random_full_text_payload;
 use crate::index::field_index::field_index_base::PayloadFieldIndex;
 use crate::index::field_index::full_text_index::inverted_index::{
@@ -411,6 +412,20 @@ fn test_congruence(
                     .filter_query(parsed_query_b, &hw_counter)
                     .collect::<HashSet<_>>(),
             );
+
+            let mut statistics_a = TextQueryStatistics::default();
+            index_a.fill_text_query_statistics(keywords, &[], &mut statistics_a, &hw_counter);
+            let mut statistics_b = TextQueryStatistics::default();
+            index_b.fill_text_query_statistics(keywords, &[], &mut statistics_b, &hw_counter);
+
+            // Deleted points are not counted, even if they are still in the postings
+            assert_eq!(statistics_a, statistics_b);
+
+            // With the same statistics, scores must be identical
+            assert_eq!(
+                index_a.bm25_scores(keywords, &statistics_a, &hw_counter),
+                index_b.bm25_scores(keywords, &statistics_a, &hw_counter),
+            );
         }
 
         if phrase_matching {
@@ -3,9 +3,9 @@ use std::path::PathBuf;
 #[cfg(feature = "rocksdb")]
 use std::sync::Arc;
 
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::PointOffsetType;
+use common::types::{PointOffsetType, ScoreType};
 #[cfg(feature = "rocksdb")]
 use parking_lot::RwLock;
 #[cfg(feature = "rocksdb")]
@@ -13,6 +13,7 @@ use rocksdb::DB;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::fuzzy::{FuzzyMatcher, SortedVocab};
 use super::immutable_text_index::ImmutableFullTextIndex;
 use super::inverted_index::{InvertedIndex, ParsedQuery, TokenId, TokenSet};
 use super::mmap_text_index::{FullTextMmapIndexBuilder, MmapFullTextIndex};
@@ -25,6 +26,7 @@ use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDelet
 #[cfg(feature = "rocksdb")]
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::data_types::index::TextIndexParams;
+use crate::data_types::text_query::TextQueryStatistics;
 use crate::index::field_index::full_text_index::inverted_index::Document;
 use crate::index::field_index::{
     CardinalityEstimation, FieldIndexBuilderTrait, PayloadBlockCondition, PayloadFieldIndex,
@@ -32,7 +34,7 @@ use crate::index::field_index::{
 };
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, Match, MatchPhrase, MatchText, PayloadKeyType};
+use crate::types::{FieldCondition, Match, MatchFuzzy, MatchPhrase, MatchText, PayloadKeyType};
 
 pub enum FullTextIndex {
     Mutable(MutableFullTextIndex),
@@ -168,6 +170,69 @@ impl FullTextIndex {
         }
     }
 
+    fn term_frequencies<'a>(
+        &'a self,
+        token_id: TokenId,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = (PointOffsetType, u32)> + 'a> {
+        match self {
+            Self::Mutable(index) => index.inverted_index.term_frequencies(token_id, hw_counter),
+            Self::Immutable(index) => index.inverted_index.term_frequencies(token_id, hw_counter),
+            Self::Mmap(index) => index.inverted_index.term_frequencies(token_id, hw_counter),
+        }
+    }
+
+    fn document_length(&self, point_id: PointOffsetType) -> usize {
+        match self {
+            Self::Mutable(index) => index.inverted_index.document_length(point_id),
+            Self::Immutable(index) => index.inverted_index.document_length(point_id),
+            Self::Mmap(index) => index.inverted_index.document_length(point_id),
+        }
+    }
+
+    fn total_document_length(&self) -> usize {
+        match self {
+            Self::Mutable(index) => index.inverted_index.total_document_length(),
+            Self::Immutable(index) => index.inverted_index.total_document_length(),
+            Self::Mmap(index) => index.inverted_index.total_document_length(),
+        }
+    }
+
+    /// Tokens of the vocabulary starting with `prefix`, which have any postings
+    fn vocab_with_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+        hw_counter: &'a HardwareCounterCell,
+    ) -> Box<dyn Iterator<Item = (&'a str, TokenId)> + 'a> {
+        match self {
+            // Vocabulary of a mutable index keeps growing, so it is not kept sorted
+            Self::Mutable(index) => Box::new(
+                index
+                    .inverted_index
+                    .vocab_with_postings_len_iter()
+                    .filter(move |(token, postings_len)| {
+                        *postings_len > 0 && token.starts_with(prefix)
+                    })
+                    .filter_map(move |(token, _)| {
+                        let token_id = index.inverted_index.get_token_id(token, hw_counter)?;
+                        Some((token, token_id))
+                    }),
+            ),
+            Self::Immutable(index) => Box::new(
+                index
+                    .sorted_vocab
+                    .get_or_init(|| SortedVocab::from_index(&index.inverted_index, hw_counter))
+                    .with_prefix(prefix),
+            ),
+            Self::Mmap(index) => Box::new(
+                index
+                    .sorted_vocab
+                    .get_or_init(|| SortedVocab::from_index(&index.inverted_index, hw_counter))
+                    .with_prefix(prefix),
+            ),
+        }
+    }
+
     fn get_tokenizer(&self) -> &Tokenizer {
         match self {
             Self::Mutable(index) => &index.tokenizer,
@@ -292,35 +357,104 @@ impl FullTextIndex {
 
     /// Tries to parse a query. If there are any unseen tokens, returns `None`
     ///
-    /// Tokens are made unique
+    /// Tokens are made unique. Tokens with synonyms match any of them,
+    /// and are only considered unseen if none of the synonyms is seen either.
     pub fn parse_text_query(
         &self,
         text: &str,
         hw_counter: &HardwareCounterCell,
     ) -> Option<ParsedQuery> {
-        let mut tokens = AHashSet::new();
-        self.get_tokenizer().tokenize_query(text, |token| {
-            tokens.insert(self.get_token(token.as_ref(), hw_counter));
+        let tokenizer = self.get_tokenizer();
+        let mut query_tokens = AHashSet::new();
+        tokenizer.tokenize_query(text, |token| {
+            query_tokens.insert(token);
         });
-        let tokens = tokens.into_iter().collect::<Option<TokenSet>>()?;
-        Some(ParsedQuery::AllTokens(tokens))
+
+        let mut tokens = AHashSet::new();
+        let mut alternatives = Vec::new();
+        for token in &query_tokens {
+            let synonyms = tokenizer.synonyms(token);
+            if synonyms.is_empty() {
+                tokens.insert(self.get_token(token, hw_counter)?);
+                continue;
+            }
+
+            let token_alternatives = std::iter::once(token.as_ref())
+                .chain(synonyms.iter().map(String::as_str))
+                .filter_map(|token| self.get_token(token, hw_counter))
+                .collect::<TokenSet>();
+            if token_alternatives.is_empty() {
+                return None;
+            }
+            alternatives.push(token_alternatives);
+        }
+
+        if alternatives.is_empty() {
+            return Some(ParsedQuery::AllTokens(TokenSet::from(tokens)));
+        }
+
+        alternatives.extend(
+            tokens
+                .into_iter()
+                .map(|token_id| TokenSet::from_iter([token_id])),
+        );
+        Some(ParsedQuery::TokenAlternatives(alternatives))
     }
 
+    /// Parses a query, matching any of its tokens or their synonyms
     pub fn parse_text_any_query(
         &self,
         text: &str,
         hw_counter: &HardwareCounterCell,
     ) -> Option<ParsedQuery> {
+        let tokenizer = self.get_tokenizer();
         let mut tokens = AHashSet::new();
-        self.get_tokenizer().tokenize_query(text, |token| {
-            if let Some(token_id) = self.get_token(token.as_ref(), hw_counter) {
-                tokens.insert(token_id);
+        tokenizer.tokenize_query(text, |token| {
+            let synonyms = tokenizer.synonyms(&token);
+            let token_alternatives =
+                std::iter::once(token.as_ref()).chain(synonyms.iter().map(String::as_str));
+            for token in token_alternatives {
+                if let Some(token_id) = self.get_token(token, hw_counter) {
+                    tokens.insert(token_id);
+                }
             }
         });
         let tokens = tokens.into_iter().collect::<TokenSet>();
         Some(ParsedQuery::AnyTokens(tokens))
     }
 
+    /// Tries to parse a fuzzy query, each token is expanded to the similar tokens of the vocabulary.
+    /// If there are no similar tokens for any of the query tokens, returns `None`
+    ///
+    /// Only the vocabulary tokens starting with the prefix of a query token are checked,
+    /// so a longer `prefix_len` makes the lookup cheaper.
+    pub fn parse_fuzzy_query(
+        &self,
+        fuzzy: &MatchFuzzy,
+        hw_counter: &HardwareCounterCell,
+    ) -> Option<ParsedQuery> {
+        let MatchFuzzy {
+            text,
+            max_edits,
+            prefix_len,
+        } = fuzzy;
+
+        let mut query_tokens = AHashSet::new();
+        self.get_tokenizer().tokenize_query(text, |token| {
+            query_tokens.insert(token);
+        });
+
+        let token_sets = query_tokens
+            .iter()
+            .map(|token| {
+                let matcher = FuzzyMatcher::new(token, *max_edits, prefix_len.unwrap_or(0));
+                let similar = matcher.expand(self.vocab_with_prefix(matcher.prefix(), hw_counter));
+                (!similar.is_empty()).then(|| TokenSet::from_iter(similar))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(ParsedQuery::TokenAlternatives(token_sets))
+    }
+
     pub fn parse_tokenset(&self, text: &str, hw_counter: &HardwareCounterCell) -> TokenSet {
         let mut tokenset = AHashSet::new();
         self.get_tokenizer().tokenize_doc(text, |token| {
@@ -352,6 +486,92 @@ impl FullTextIndex {
         }
     }
 
+    /// Unique tokens of a relevance-ranked query, including the ones unseen by this index
+    pub fn parse_scored_query_tokens(&self, text: &str) -> Vec<String> {
+        let mut tokens = AHashSet::new();
+        self.get_tokenizer().tokenize_query(text, |token| {
+            tokens.insert(token.into_owned());
+        });
+        tokens.into_iter().collect()
+    }
+
+    /// Accumulate the statistics of this index, which are required for BM25 scoring of `tokens`.
+    ///
+    /// Points in `excluded_points` are not counted, even if they are still indexed.
+    pub fn fill_text_query_statistics(
+        &self,
+        tokens: &[String],
+        excluded_points: &[PointOffsetType],
+        statistics: &mut TextQueryStatistics,
+        hw_counter: &HardwareCounterCell,
+    ) {
+        let token_ids: Vec<_> = tokens
+            .iter()
+            .map(|token| self.get_token(token, hw_counter))
+            .collect();
+
+        let mut points_count = self.points_count();
+        let mut total_document_length = self.total_document_length();
+        // Posting lengths may include removed points, so only count the active ones
+        let mut document_frequencies: Vec<_> = token_ids
+            .iter()
+            .map(|token_id| {
+                token_id.map_or(0, |token_id| {
+                    self.term_frequencies(token_id, hw_counter).count()
+                })
+            })
+            .collect();
+
+        for &point_id in excluded_points {
+            if self.values_is_empty(point_id) {
+                continue;
+            }
+            points_count -= 1;
+            total_document_length -= self.document_length(point_id);
+            for (token_id, document_frequency) in token_ids.iter().zip(&mut document_frequencies) {
+                let Some(token_id) = *token_id else {
+                    continue;
+                };
+                let query = ParsedQuery::AnyTokens(TokenSet::from_iter([token_id]));
+                if self.check_match(&query, point_id) {
+                    *document_frequency -= 1;
+                }
+            }
+        }
+
+        statistics.points_count += points_count;
+        statistics.total_document_length += total_document_length;
+        for (token, document_frequency) in tokens.iter().zip(document_frequencies) {
+            *statistics
+                .document_frequencies
+                .entry(token.clone())
+                .or_default() += document_frequency;
+        }
+    }
+
+    /// BM25 scores of all points which contain at least one of the `tokens`
+    pub fn bm25_scores(
+        &self,
+        tokens: &[String],
+        statistics: &TextQueryStatistics,
+        hw_counter: &HardwareCounterCell,
+    ) -> AHashMap<PointOffsetType, ScoreType> {
+        let bm25 = statistics.bm25_params();
+        let mut scores = AHashMap::new();
+        for token in tokens {
+            let Some(token_id) = self.get_token(token, hw_counter) else {
+                continue;
+            };
+            let idf = statistics.idf(token);
+            for (point_id, frequency) in self.term_frequencies(token_id, hw_counter) {
+                let document_length = self.document_length(point_id) as ScoreType;
+                let term_weight = bm25.term_weight(frequency as ScoreType, document_length);
+                *scores.entry(point_id).or_insert(0.0) += idf * term_weight;
+            }
+        }
+        scores
+    }
+
     #[cfg(test)]
     pub fn query<'a>(
         &'a self,
@@ -381,9 +601,57 @@ impl FullTextIndex {
             return false;
         };
 
+        self.check_payload_query(payload_value, &query, hw_counter)
+    }
+
+    /// Checks the fuzzy text directly against the payload value
+    /// Matches the tokens of the payload value directly, without a lookup in the vocabulary
+    pub fn check_payload_fuzzy_match(
+        &self,
+        payload_value: &serde_json::Value,
+        fuzzy: &MatchFuzzy,
+    ) -> bool {
+        let MatchFuzzy {
+            text,
+            max_edits,
+            prefix_len,
+        } = fuzzy;
+
+        let tokenizer = self.get_tokenizer();
+        let mut matchers = Vec::new();
+        tokenizer.tokenize_query(text, |token| {
+            matchers.push(FuzzyMatcher::new(
+                &token,
+                *max_edits,
+                prefix_len.unwrap_or(0),
+            ));
+        });
+        if matchers.is_empty() {
+            return false;
+        }
+
         FullTextIndex::get_values(payload_value)
             .iter()
-            .any(|value| match &query {
+            .any(|value| {
+                let mut matched = vec![false; matchers.len()];
+                tokenizer.tokenize_doc(value, |token| {
+                    for (matcher, is_matched) in matchers.iter().zip(matched.iter_mut()) {
+                        *is_matched = *is_matched || matcher.is_match(&token);
+                    }
+                });
+                matched.into_iter().all(|is_matched| is_matched)
+            })
+    }
+
+    fn check_payload_query(
+        &self,
+        payload_value: &serde_json::Value,
+        query: &ParsedQuery,
+        hw_counter: &HardwareCounterCell,
+    ) -> bool {
+        FullTextIndex::get_values(payload_value)
+            .iter()
+            .any(|value| match query {
                 ParsedQuery::AllTokens(query) => {
                     let tokenset = self.parse_tokenset(value, hw_counter);
                     tokenset.has_subset(query)
@@ -396,6 +664,10 @@ impl FullTextIndex {
                     let tokenset = self.parse_tokenset(value, hw_counter);
                     tokenset.has_any(query)
                 }
+                ParsedQuery::TokenAlternatives(token_sets) => {
+                    let tokenset = self.parse_tokenset(value, hw_counter);
+                    !token_sets.is_empty() && token_sets.iter().all(|query| tokenset.has_any(query))
+                }
             })
     }
 
@@ -600,6 +872,7 @@ impl PayloadFieldIndex for FullTextIndex {
             Some(Match::Phrase(MatchPhrase { phrase })) => {
                 self.parse_phrase_query(phrase, hw_counter)
             }
+            Some(Match::Fuzzy(fuzzy)) => self.parse_fuzzy_query(fuzzy, hw_counter),
             _ => return None,
         };
 
@@ -620,6 +893,7 @@ impl PayloadFieldIndex for FullTextIndex {
             Some(Match::Phrase(MatchPhrase { phrase })) => {
                 self.parse_phrase_query(phrase, hw_counter)
             }
+            Some(Match::Fuzzy(fuzzy)) => self.parse_fuzzy_query(fuzzy, hw_counter),
             _ => return None,
         };
 
@@ -0,0 +1,240 @@
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::data_types::index::{BuiltinCharFilter, CharFilter, MappingCharFilter};
+
+/// Chain of character filters, applied to tokens in the configured order.
+#[derive(Debug, Clone, Default)]
+pub struct CharFilters {
+    filters: Vec<CompiledCharFilter>,
+}
+
+#[derive(Debug, Clone)]
+enum CompiledCharFilter {
+    RemoveDiacritics,
+    AsciiFolding,
+    /// Sorted by descending key length, so that the longest key matches first
+    Mapping(Vec<(String, String)>),
+}
+
+impl CharFilters {
+    pub fn new(char_filters: &[CharFilter]) -> Self {
+        let filters = char_filters
+            .iter()
+            .map(|filter| match filter {
+                CharFilter::Builtin(BuiltinCharFilter::RemoveDiacritics) => {
+                    CompiledCharFilter::RemoveDiacritics
+                }
+                CharFilter::Builtin(BuiltinCharFilter::AsciiFolding) => {
+                    CompiledCharFilter::AsciiFolding
+                }
+                CharFilter::Mapping(MappingCharFilter {
+                    r#type: _,
+                    mappings,
+                }) => {
+                    let mut mappings: Vec<_> = mappings
+                        .iter()
+                        .filter(|(from, _)| !from.is_empty())
+                        .map(|(from, to)| (from.clone(), to.clone()))
+                        .collect();
+                    mappings.sort_by_key(|(from, _)| std::cmp::Reverse(from.len()));
+                    CompiledCharFilter::Mapping(mappings)
+                }
+            })
+            .collect();
+
+        Self { filters }
+    }
+
+    pub fn apply<'a>(&self, token: Cow<'a, str>) -> Cow<'a, str> {
+        self.filters
+            .iter()
+            .fold(token, |token, filter| match filter {
+                CompiledCharFilter::RemoveDiacritics => remove_diacritics(token),
+                CompiledCharFilter::AsciiFolding => fold_to_ascii(token),
+                CompiledCharFilter::Mapping(mappings) => apply_mapping(token, mappings),
+            })
+    }
+}
+
+fn remove_diacritics(token: Cow<str>) -> Cow<str> {
+    if token.is_ascii() {
+        return token;
+    }
+
+    // Decompose, drop the combining marks, and compose the rest back
+    Cow::Owned(
+        token
+            .nfd()
+            .filter(|&c| !is_combining_mark(c))
+            .nfc()
+            .collect(),
+    )
+}
+
+fn fold_to_ascii(token: Cow<str>) -> Cow<str> {
+    if token.is_ascii() {
+        return token;
+    }
+
+    let mut folded = String::with_capacity(token.len());
+    for c in token.chars() {
+        if c.is_ascii() {
+            folded.push(c);
+        } else if let Some(replacement) = ascii_replacement(c) {
+            folded.push_str(replacement);
+        } else {
+            // Keep the base letter, if it is ASCII once the diacritics are removed
+            let base: String = std::iter::once(c)
+                .nfd()
+                .filter(|&c| !is_combining_mark(c))
+                .collect();
+            if base.is_ascii() && !base.is_empty() {
+                folded.push_str(&base);
+            } else {
+                folded.push(c);
+            }
+        }
+    }
+
+    Cow::Owned(folded)
+}
+
+/// ASCII equivalents of the characters, which are not decomposed into a base letter and diacritics
+fn ascii_replacement(c: char) -> Option<&'static str> {
+    let replacement = match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ø' => "o",
+        'Ø' => "O",
+        'đ' | 'ð' => "d",
+        'Đ' | 'Ð' => "D",
+        'ł' | 'ŀ' => "l",
+        'Ł' | 'Ŀ' => "L",
+        'ħ' => "h",
+        'Ħ' => "H",
+        'ı' => "i",
+        'ĳ' => "ij",
+        'Ĳ' => "IJ",
+        'ŋ' => "n",
+        'Ŋ' => "N",
+        'þ' => "th",
+        'Þ' => "TH",
+        'ſ' => "s",
+        'ﬀ' => "ff",
+        'ﬁ' => "fi",
+        'ﬂ' => "fl",
+        'ﬃ' => "ffi",
+        'ﬄ' => "ffl",
+        'ﬅ' | 'ﬆ' => "st",
+        '‘' | '’' | '‚' | '‛' => "'",
+        '“' | '”' | '„' | '‟' | '«' | '»' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' => "-",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+fn apply_mapping<'a>(token: Cow<'a, str>, mappings: &[(String, String)]) -> Cow<'a, str> {
+    if !mappings
+        .iter()
+        .any(|(from, _)| token.contains(from.as_str()))
+    {
+        return token;
+    }
+
+    let mut mapped = String::with_capacity(token.len());
+    let mut rest = token.as_ref();
+    'outer: while let Some(c) = rest.chars().next() {
+        for (from, to) in mappings {
+            if let Some(tail) = rest.strip_prefix(from.as_str()) {
+                mapped.push_str(to);
+                rest = tail;
+                continue 'outer;
+            }
+        }
+        mapped.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    Cow::Owned(mapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::data_types::index::Mapping;
+
+    fn apply(filters: &CharFilters, token: &str) -> String {
+        filters.apply(Cow::Borrowed(token)).into_owned()
+    }
+
+    #[test]
+    fn test_remove_diacritics() {
+        let filters = CharFilters::new(&[CharFilter::Builtin(BuiltinCharFilter::RemoveDiacritics)]);
+
+        assert_eq!(apply(&filters, "café"), "cafe");
+        assert_eq!(apply(&filters, "Ångström"), "Angstrom");
+        assert_eq!(apply(&filters, "crème brûlée"), "creme brulee");
+        // Letters without decomposition are kept
+        assert_eq!(apply(&filters, "straße"), "straße");
+        assert_eq!(apply(&filters, "한국어"), "한국어");
+        assert!(matches!(
+            filters.apply(Cow::Borrowed("plain")),
+            Cow::Borrowed("plain")
+        ));
+    }
+
+    #[test]
+    fn test_ascii_folding() {
+        let filters = CharFilters::new(&[CharFilter::Builtin(BuiltinCharFilter::AsciiFolding)]);
+
+        assert_eq!(apply(&filters, "café"), "cafe");
+        assert_eq!(apply(&filters, "straße"), "strasse");
+        assert_eq!(apply(&filters, "Æsir"), "AEsir");
+        assert_eq!(apply(&filters, "Łódź"), "Lodz");
+        assert_eq!(apply(&filters, "rock’n’roll"), "rock'n'roll");
+        // Non-latin scripts are kept
+        assert_eq!(apply(&filters, "привет"), "привет");
+    }
+
+    #[test]
+    fn test_mapping() {
+        let filters = CharFilters::new(&[CharFilter::Mapping(MappingCharFilter {
+            r#type: Mapping::Mapping,
+            mappings: BTreeMap::from([
+                ("&".to_string(), "and".to_string()),
+                ("ch".to_string(), "k".to_string()),
+                ("sch".to_string(), "sh".to_string()),
+                (String::new(), "ignored".to_string()),
+            ]),
+        })]);
+
+        assert_eq!(apply(&filters, "r&d"), "randd");
+        assert_eq!(apply(&filters, "chaos"), "kaos");
+        // Longest key is preferred
+        assert_eq!(apply(&filters, "schule"), "shule");
+        assert_eq!(apply(&filters, "other"), "other");
+    }
+
+    #[test]
+    fn test_filters_chain() {
+        let filters = CharFilters::new(&[
+            CharFilter::Builtin(BuiltinCharFilter::AsciiFolding),
+            CharFilter::Mapping(MappingCharFilter {
+                r#type: Mapping::Mapping,
+                mappings: BTreeMap::from([("ss".to_string(), "s".to_string())]),
+            }),
+        ]);
+
+        assert_eq!(apply(&filters, "straße"), "strase");
+    }
+}
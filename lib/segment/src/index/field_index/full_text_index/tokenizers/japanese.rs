@@ -60,12 +60,8 @@ impl JapaneseTokenizer {
                 continue;
             }
 
-            let surface = if tokens_processor.lowercase {
-                Cow::Owned(surface.to_lowercase())
-            } else {
-                Cow::Owned(surface.to_string())
-            };
-            cb(surface);
+            let surface = tokens_processor.normalize(Cow::Borrowed(surface));
+            cb(Cow::Owned(surface.into_owned()));
         }
     }
 }
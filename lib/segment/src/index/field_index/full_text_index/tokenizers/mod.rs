@@ -1,16 +1,19 @@
 use std::borrow::Cow;
 use std::sync::Arc;
+mod char_filters;
 mod japanese;
 mod multilingual;
 mod stemmer;
 pub mod tokens_processor;
 
+pub use char_filters::CharFilters;
 use multilingual::MultilingualTokenizer;
 pub use stemmer::Stemmer;
 pub use tokens_processor::TokensProcessor;
 
 use crate::data_types::index::{TextIndexParams, TokenizerType};
 use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
+use crate::index::field_index::full_text_index::synonyms::Synonyms;
 
 struct WhiteSpaceTokenizer;
 
@@ -54,12 +57,24 @@ impl PrefixTokenizer {
     fn tokenize<'a, C: FnMut(Cow<'a, str>)>(
         text: &'a str,
         tokens_processor: &TokensProcessor,
+        callback: C,
+    ) {
+        Self::tokenize_words(
+            text.split(|c| !char::is_alphanumeric(c)),
+            tokens_processor,
+            callback,
+        )
+    }
+
+    fn tokenize_words<'a, C: FnMut(Cow<'a, str>)>(
+        words: impl Iterator<Item = &'a str>,
+        tokens_processor: &TokensProcessor,
         mut callback: C,
     ) {
         let min_ngram = tokens_processor.min_token_len.unwrap_or(1);
         let max_ngram = tokens_processor.max_token_len.unwrap_or(usize::MAX);
 
-        text.split(|c| !char::is_alphanumeric(c)).for_each(|word| {
+        words.for_each(|word| {
             let Some(word_cow) = tokens_processor.process_token(word, false) else {
                 return;
             };
@@ -96,40 +111,170 @@ impl PrefixTokenizer {
     fn tokenize_query<'a, C: FnMut(Cow<'a, str>)>(
         text: &'a str,
         tokens_processor: &TokensProcessor,
+        callback: C,
+    ) {
+        Self::tokenize_query_words(
+            text.split(|c| !char::is_alphanumeric(c)),
+            tokens_processor,
+            callback,
+        )
+    }
+
+    fn tokenize_query_words<'a, C: FnMut(Cow<'a, str>)>(
+        words: impl Iterator<Item = &'a str>,
+        tokens_processor: &TokensProcessor,
         mut callback: C,
     ) {
         let max_ngram = tokens_processor.max_token_len.unwrap_or(usize::MAX);
 
-        text.split(|c| !char::is_alphanumeric(c))
-            .filter(|token| !token.is_empty())
-            .for_each(|word| {
-                let word_cow = if tokens_processor.lowercase {
-                    Cow::Owned(word.to_lowercase())
-                } else {
-                    Cow::Borrowed(word)
-                };
-
-                let word_cow = tokens_processor.stem_if_enabled(word_cow);
-
-                if tokens_processor
-                    .min_token_len
-                    .is_some_and(|min_len| word_cow.chars().count() < min_len)
-                {
-                    // Tokens shorter than min_token_len don't exist in the index
-                    return;
+        words.filter(|token| !token.is_empty()).for_each(|word| {
+            let word_cow = normalize_query_word(word, tokens_processor);
+
+            if tokens_processor
+                .min_token_len
+                .is_some_and(|min_len| word_cow.chars().count() < min_len)
+            {
+                // Tokens shorter than min_token_len don't exist in the index
+                return;
+            }
+
+            let ngram = word_cow.char_indices().map(|(i, _)| i).nth(max_ngram);
+            match ngram {
+                Some(end) => callback(truncate_cow(word_cow, end)),
+                None => {
+                    callback(word_cow);
                 }
+            }
+        });
+    }
+}
 
-                let ngram = word_cow.char_indices().map(|(i, _)| i).nth(max_ngram);
-                match ngram {
-                    Some(end) => callback(truncate_cow(word_cow, end)),
-                    None => {
-                        callback(word_cow);
-                    }
+/// Same as [`PrefixTokenizer`], but words are only separated by whitespace.
+///
+/// Punctuation stays inside of the words, so that prefixes of SKUs or code identifiers
+/// like `AL-76` or `snake_case` can be searched for.
+struct EdgeNgramTokenizer;
+
+impl EdgeNgramTokenizer {
+    fn tokenize<'a, C: FnMut(Cow<'a, str>)>(
+        text: &'a str,
+        tokens_processor: &TokensProcessor,
+        callback: C,
+    ) {
+        PrefixTokenizer::tokenize_words(text.split_whitespace(), tokens_processor, callback)
+    }
+
+    fn tokenize_query<'a, C: FnMut(Cow<'a, str>)>(
+        text: &'a str,
+        tokens_processor: &TokensProcessor,
+        callback: C,
+    ) {
+        PrefixTokenizer::tokenize_query_words(text.split_whitespace(), tokens_processor, callback)
+    }
+}
+
+/// Splits whitespace separated words into all substrings with `min..=max` characters,
+/// which allows substring matching.
+struct NgramTokenizer {
+    min: usize,
+    max: usize,
+}
+
+impl NgramTokenizer {
+    fn tokenize<'a, C: FnMut(Cow<'a, str>)>(
+        &self,
+        text: &'a str,
+        tokens_processor: &TokensProcessor,
+        mut callback: C,
+    ) {
+        let Self {
+            min: min_ngram,
+            max: max_ngram,
+        } = *self;
+
+        for word in text.split_whitespace() {
+            let Some(word_cow) = tokens_processor.process_token(word, false) else {
+                continue;
+            };
+
+            let boundaries = char_boundaries(&word_cow);
+            let chars_count = boundaries.len() - 1;
+
+            for start in 0..chars_count {
+                for n in min_ngram..=max_ngram.min(chars_count - start) {
+                    callback(slice_cow_ref(
+                        &word_cow,
+                        boundaries[start],
+                        boundaries[start + n],
+                    ));
                 }
-            });
+            }
+        }
+    }
+
+    /// Query words which fit into the n-gram range are looked up as is.
+    /// Longer words are split into n-grams of the maximal size, all of which must be present
+    /// in a document containing the word.
+    ///
+    /// Words shorter than the minimal n-gram size can't be found, so they are skipped.
+    ///
+    /// Same as with prefixes, stopwords are not applied, as the query may be a part of a word.
+    ///
+    /// Docs. tokens: `"hello"` (2..=3) -> `["he", "hel", "el", "ell", "ll", "llo", "lo"]`
+    /// Query tokens: `"ell"`   -> `["ell"]`
+    /// Query tokens: `"ello"`  -> `["ell", "llo"]`
+    fn tokenize_query<'a, C: FnMut(Cow<'a, str>)>(
+        &self,
+        text: &'a str,
+        tokens_processor: &TokensProcessor,
+        mut callback: C,
+    ) {
+        let Self {
+            min: min_ngram,
+            max: max_ngram,
+        } = *self;
+
+        for word in text.split_whitespace() {
+            let word_cow = normalize_query_word(word, tokens_processor);
+
+            let boundaries = char_boundaries(&word_cow);
+            let chars_count = boundaries.len() - 1;
+
+            if chars_count < min_ngram {
+                // Such n-grams don't exist in the index
+                continue;
+            }
+
+            if chars_count <= max_ngram {
+                callback(word_cow);
+                continue;
+            }
+
+            for start in 0..=chars_count - max_ngram {
+                callback(slice_cow_ref(
+                    &word_cow,
+                    boundaries[start],
+                    boundaries[start + max_ngram],
+                ));
+            }
+        }
     }
 }
 
+/// Applies char filters, lowercases and stems a query word, if enabled. Stopwords are not applied.
+fn normalize_query_word<'a>(word: &'a str, tokens_processor: &TokensProcessor) -> Cow<'a, str> {
+    let word_cow = tokens_processor.normalize(Cow::Borrowed(word));
+    tokens_processor.stem_if_enabled(word_cow)
+}
+
+/// Byte offsets of all chars in the string, followed by the length of the string.
+fn char_boundaries(inp: &str) -> Vec<usize> {
+    inp.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(inp.len()))
+        .collect()
+}
+
 /// Truncates a string inside a `Cow<str>` to the given `len` preserving the `Borrowed` and `Owned` state.
 fn truncate_cow<'a>(inp: Cow<'a, str>, len: usize) -> Cow<'a, str> {
     match inp {
@@ -144,9 +289,14 @@ fn truncate_cow<'a>(inp: Cow<'a, str>, len: usize) -> Cow<'a, str> {
 /// Truncates a string inside a `&Cow<str>` to the given `len` preserving the `Borrowed` and `Owned` state.
 /// `truncate_cow` should be preferred over this function if Cow doesn't need to be passed as reference.
 fn truncate_cow_ref<'a>(inp: &Cow<'a, str>, len: usize) -> Cow<'a, str> {
+    slice_cow_ref(inp, 0, len)
+}
+
+/// Takes a `start..end` slice of a string inside a `&Cow<str>` preserving the `Borrowed` and `Owned` state.
+fn slice_cow_ref<'a>(inp: &Cow<'a, str>, start: usize, end: usize) -> Cow<'a, str> {
     match inp {
-        Cow::Borrowed(b) => Cow::Borrowed(&b[..len]),
-        Cow::Owned(b) => Cow::Owned(b[..len].to_string()),
+        Cow::Borrowed(b) => Cow::Borrowed(&b[start..end]),
+        Cow::Owned(b) => Cow::Owned(b[start..end].to_string()),
     }
 }
 
@@ -168,18 +318,26 @@ impl Tokenizer {
             phrase_matching: _,
             stopwords,
             stemmer,
+            char_filters,
+            synonyms,
         } = params;
 
         let lowercase = lowercase.unwrap_or(true);
         let stopwords_filter = Arc::new(StopwordsFilter::new(stopwords, lowercase));
+        let char_filters = Arc::new(CharFilters::new(
+            char_filters.as_deref().unwrap_or_default(),
+        ));
 
-        let tokens_processor = TokensProcessor::new(
+        let mut tokens_processor = TokensProcessor::new(
             lowercase,
             stopwords_filter,
+            char_filters,
             stemmer.as_ref().map(Stemmer::from_algorithm),
             *min_token_len,
             *max_token_len,
         );
+        let synonyms = Synonyms::new(synonyms, &tokens_processor);
+        tokens_processor.set_synonyms(Arc::new(synonyms));
 
         Self::new(*tokenizer, tokens_processor)
     }
@@ -203,9 +361,41 @@ impl Tokenizer {
             TokenizerType::Prefix => {
                 PrefixTokenizer::tokenize(text, &self.tokens_processor, callback)
             }
+            TokenizerType::EdgeNgram => {
+                EdgeNgramTokenizer::tokenize(text, &self.tokens_processor, callback)
+            }
+            TokenizerType::Ngram { min, max } => {
+                NgramTokenizer { min, max }.tokenize(text, &self.tokens_processor, callback)
+            }
         }
     }
 
+    /// Words of the text with their byte offsets, as they are split by the tokenizer.
+    ///
+    /// Each word can be tokenized on its own, which allows to map the tokens back to the text.
+    /// Multilingual tokenizer segments the text by the script, words are approximated by
+    /// alphanumeric sequences for it.
+    pub fn split_words<'a>(&self, text: &'a str) -> Vec<(usize, &'a str)> {
+        let words: Box<dyn Iterator<Item = &'a str>> = match self.tokenizer_type {
+            TokenizerType::Whitespace | TokenizerType::EdgeNgram | TokenizerType::Ngram { .. } => {
+                Box::new(text.split_whitespace())
+            }
+            TokenizerType::Word | TokenizerType::Prefix | TokenizerType::Multilingual => {
+                Box::new(text.split(|c| !char::is_alphanumeric(c)))
+            }
+        };
+
+        words
+            .filter(|word| !word.is_empty())
+            .map(|word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+            .collect()
+    }
+
+    /// Synonyms of a query token, excluding the token itself
+    pub fn synonyms(&self, token: &str) -> &[String] {
+        self.tokens_processor.synonyms(token)
+    }
+
     pub fn tokenize_query<'a, C: FnMut(Cow<'a, str>)>(&'a self, text: &'a str, callback: C) {
         match self.tokenizer_type {
             TokenizerType::Whitespace => {
@@ -218,6 +408,12 @@ impl Tokenizer {
             TokenizerType::Prefix => {
                 PrefixTokenizer::tokenize_query(text, &self.tokens_processor, callback)
             }
+            TokenizerType::EdgeNgram => {
+                EdgeNgramTokenizer::tokenize_query(text, &self.tokens_processor, callback)
+            }
+            TokenizerType::Ngram { min, max } => {
+                NgramTokenizer { min, max }.tokenize_query(text, &self.tokens_processor, callback)
+            }
         }
     }
 }
@@ -277,8 +473,14 @@ mod tests {
     #[test]
     fn test_prefix_tokenizer() {
         let text = "hello, мир!";
-        let tokens_processor =
-            TokensProcessor::new(true, Default::default(), None, Some(1), Some(4));
+        let tokens_processor = TokensProcessor::new(
+            true,
+            Default::default(),
+            Default::default(),
+            None,
+            Some(1),
+            Some(4),
+        );
 
         let mut tokens = Vec::new();
         PrefixTokenizer::tokenize(text, &tokens_processor, |token| tokens.push(token));
@@ -296,7 +498,14 @@ mod tests {
     #[test]
     fn test_prefix_query_tokenizer() {
         let text = "hello, мир!";
-        let tokens_processor = TokensProcessor::new(true, Default::default(), None, None, Some(4));
+        let tokens_processor = TokensProcessor::new(
+            true,
+            Default::default(),
+            Default::default(),
+            None,
+            None,
+            Some(4),
+        );
 
         let mut tokens = Vec::new();
         PrefixTokenizer::tokenize_query(text, &tokens_processor, |token| tokens.push(token));
@@ -324,7 +533,8 @@ mod tests {
         // Test stopwords getting applied
         let filter =
             StopwordsFilter::new(&Some(StopwordsInterface::new_custom(&["の", "は"])), false);
-        let tokens_processor = TokensProcessor::new(true, Arc::new(filter), None, None, None);
+        let tokens_processor =
+            TokensProcessor::new(true, Arc::new(filter), Default::default(), None, None, None);
         MultilingualTokenizer::tokenize(text, &tokens_processor, |token| tokens.push(token));
         eprintln!("tokens = {tokens:#?}");
         assert_eq!(tokens.len(), 2);
@@ -349,7 +559,8 @@ mod tests {
 
         // Test stopwords getting applied
         let filter = StopwordsFilter::new(&Some(StopwordsInterface::new_custom(&["是"])), false);
-        let tokens_processor = TokensProcessor::new(true, Arc::new(filter), None, None, None);
+        let tokens_processor =
+            TokensProcessor::new(true, Arc::new(filter), Default::default(), None, None, None);
         MultilingualTokenizer::tokenize(text, &tokens_processor, |token| tokens.push(token));
         eprintln!("tokens = {tokens:#?}");
         assert_eq!(tokens.len(), 3);
@@ -387,6 +598,63 @@ mod tests {
         assert_eq!(tokens.get(4), Some(&Cow::Borrowed("for")));
     }
 
+    #[test]
+    fn test_edge_ngram_tokenizer() {
+        let text = "AL-76 мир";
+        let tokens_processor = TokensProcessor::new(
+            true,
+            Default::default(),
+            Default::default(),
+            None,
+            Some(2),
+            Some(4),
+        );
+
+        let mut tokens = Vec::new();
+        EdgeNgramTokenizer::tokenize(text, &tokens_processor, |token| tokens.push(token));
+        assert_eq!(tokens, vec!["al", "al-", "al-7", "ми", "мир"]);
+
+        tokens.clear();
+        EdgeNgramTokenizer::tokenize_query("Al-76 м", &tokens_processor, |token| {
+            tokens.push(token)
+        });
+        assert_eq!(tokens, vec!["al-7"]);
+    }
+
+    #[test]
+    fn test_ngram_tokenizer() {
+        let text = "Hello мир";
+        let tokens_processor = TokensProcessor::new(
+            true,
+            Default::default(),
+            Default::default(),
+            None,
+            None,
+            None,
+        );
+        let tokenizer = NgramTokenizer { min: 2, max: 3 };
+
+        let mut tokens = Vec::new();
+        tokenizer.tokenize(text, &tokens_processor, |token| tokens.push(token));
+        assert_eq!(
+            tokens,
+            vec![
+                "he", "hel", "el", "ell", "ll", "llo", "lo", "ми", "мир", "ир"
+            ],
+        );
+
+        tokens.clear();
+        tokenizer.tokenize_query("ELL ello l ир", &tokens_processor, |token| {
+            tokens.push(token)
+        });
+        assert_eq!(tokens, vec!["ell", "ell", "llo", "ир"]);
+
+        let tokenizer = NgramTokenizer { min: 3, max: 3 };
+        tokens.clear();
+        tokenizer.tokenize("sku-42", &tokens_processor, |token| tokens.push(token));
+        assert_eq!(tokens, vec!["sku", "ku-", "u-4", "-42"]);
+    }
+
     #[test]
     fn test_tokenizer() {
         let text = "Hello, Мир!";
@@ -401,6 +669,8 @@ mod tests {
             phrase_matching: None,
             stopwords: None,
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -432,6 +702,8 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::Language(Language::English)),
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -474,6 +746,8 @@ mod tests {
                 phrase_matching: None,
                 stopwords: Some(StopwordsInterface::Language(Language::English)),
                 stemmer: None,
+                char_filters: None,
+                synonyms: None,
             };
 
             let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -509,6 +783,8 @@ mod tests {
                 &["quick", "fox"],
             )),
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -544,6 +820,8 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::new_custom(&["as", "the", "a"])),
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -582,6 +860,8 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::Language(Language::English)),
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -620,6 +900,8 @@ mod tests {
                 &["I'd"],
             )),
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -661,6 +943,8 @@ mod tests {
             phrase_matching: None,
             stopwords: Some(StopwordsInterface::new_custom(&["the", "The", "LAZY"])),
             stemmer: None,
+            char_filters: None,
+            synonyms: None,
         };
 
         let tokenizer = Tokenizer::new_from_text_index_params(&params);
@@ -690,6 +974,7 @@ mod tests {
         let mut tokens_processor = TokensProcessor::new(
             true,
             Default::default(),
+            Default::default(),
             Some(make_stemmer(SnowballLanguage::English)),
             None,
             None,
@@ -182,6 +182,7 @@ mod test {
         let tokens_processor = TokensProcessor::new(
             true,
             Default::default(),
+            Default::default(),
             Some(Stemmer::from_algorithm(&StemmingAlgorithm::Snowball(
                 SnowballParams {
                     r#type: Default::default(),
@@ -1,14 +1,18 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
+use super::char_filters::CharFilters;
 use super::stemmer::Stemmer;
 use crate::index::field_index::full_text_index::stop_words::StopwordsFilter;
+use crate::index::field_index::full_text_index::synonyms::Synonyms;
 
 // TODO(rocksdb): Remove `Clone` once rocksdb has been removed!
 #[derive(Debug, Clone, Default)]
 pub struct TokensProcessor {
     pub lowercase: bool,
     stopwords_filter: Arc<StopwordsFilter>, // TDOO(rocksdb): Remove once rocksdb has been removed!
+    char_filters: Arc<CharFilters>,
+    synonyms: Arc<Synonyms>,
     stemmer: Option<Stemmer>,
     pub min_token_len: Option<usize>,
     pub max_token_len: Option<usize>,
@@ -18,6 +22,7 @@ impl TokensProcessor {
     pub fn new(
         lowercase: bool,
         stopwords_filter: Arc<StopwordsFilter>,
+        char_filters: Arc<CharFilters>,
         stemmer: Option<Stemmer>,
         min_token_len: Option<usize>,
         max_token_len: Option<usize>,
@@ -25,6 +30,8 @@ impl TokensProcessor {
         Self {
             lowercase,
             stopwords_filter,
+            char_filters,
+            synonyms: Arc::default(),
             stemmer,
             min_token_len,
             max_token_len,
@@ -36,6 +43,27 @@ impl TokensProcessor {
         self.stopwords_filter = stopwords_filter;
     }
 
+    /// Synonyms are set separately, as they are processed by this very processor.
+    pub fn set_synonyms(&mut self, synonyms: Arc<Synonyms>) {
+        self.synonyms = synonyms;
+    }
+
+    /// Synonyms of a processed token, excluding the token itself.
+    pub fn synonyms(&self, token: &str) -> &[String] {
+        self.synonyms.expand(token)
+    }
+
+    /// Applies the character filters and lowercasing, if enabled.
+    pub fn normalize<'a>(&self, token: Cow<'a, str>) -> Cow<'a, str> {
+        let token = self.char_filters.apply(token);
+
+        if self.lowercase {
+            Cow::Owned(token.to_lowercase())
+        } else {
+            token
+        }
+    }
+
     /// Applies stemming if enabled and applies the configured stemming algorithm. Does nothing if
     /// stemming is disabled.
     pub fn stem_if_enabled<'a>(&self, input: Cow<'a, str>) -> Cow<'a, str> {
@@ -56,24 +84,23 @@ impl TokensProcessor {
         check_max_len: bool,
     ) -> Option<Cow<'a, str>> {
         let Self {
-            lowercase,
+            lowercase: _,
             stopwords_filter,
+            char_filters: _,
+            synonyms: _,
             stemmer,
             min_token_len,
             max_token_len,
         } = self;
 
-        if token.is_empty() {
+        // Handle char filters and lowercase
+        let mut token_cow = self.normalize(token);
+
+        // Char filters may remove all characters of the token
+        if token_cow.is_empty() {
             return None;
         }
 
-        // Handle lowercase
-        let mut token_cow = if *lowercase {
-            Cow::Owned(token.to_lowercase())
-        } else {
-            token
-        };
-
         // Handle stopwords
         if stopwords_filter.is_stopword(&token_cow) {
             return None;
@@ -98,7 +125,7 @@ impl TokensProcessor {
     /// Processes a token for indexing. Applies all configured options to the token.
     ///
     /// Returns `None` if:
-    /// - The token is empty.
+    /// - The token is empty, or all of its characters are removed by the char filters.
     /// - The token is a stopword.
     /// - The token's chars length is outside of the `min_token_len` and (optionally) `max_token_len` range.
     pub fn process_token<'a>(&self, token: &'a str, check_max_len: bool) -> Option<Cow<'a, str>> {
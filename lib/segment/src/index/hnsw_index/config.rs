@@ -4,10 +4,11 @@ use io::file_operations::{atomic_save_json, read_json};
 use serde::{Deserialize, Serialize};
 
 use crate::common::operation_error::OperationResult;
+use crate::types::PayloadKeyType;
 
 pub const HNSW_INDEX_CONFIG_FILE: &str = "hnsw_config.json";
 
-#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
 pub struct HnswGraphConfig {
     pub m: usize,
     /// Requested M
@@ -29,6 +30,14 @@ pub struct HnswGraphConfig {
     pub payload_m0: Option<usize>,
     #[serde(default)]
     pub indexed_vector_count: Option<usize>,
+    /// Number of first dimensions the graph is built on
+    #[serde(default)]
+    pub matryoshka_dim: Option<usize>,
+    /// Payload fields, for which additional links were built into the graph
+    ///
+    /// `None` for graphs built before these fields were recorded.
+    #[serde(default)]
+    pub payload_linked_fields: Option<Vec<PayloadKeyType>>,
 }
 
 impl HnswGraphConfig {
@@ -50,6 +59,8 @@ impl HnswGraphConfig {
             payload_m,
             payload_m0: payload_m.map(|v| v * 2),
             indexed_vector_count: Some(indexed_vector_count),
+            matryoshka_dim: None,
+            payload_linked_fields: Some(Vec::new()),
         }
     }
 
@@ -7,10 +7,11 @@ use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
 use super::STORAGES_COUNT;
 use super::gpu_quantization::MAX_QUANTIZATION_BINDINGS;
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::index::hnsw_index::gpu::GPU_TIMEOUT;
 use crate::index::hnsw_index::gpu::shader_builder::ShaderBuilderParameters;
+use crate::types::{MultiVectorComparator, MultiVectorConfig};
 use crate::vector_storage::quantized::quantized_multivector_storage::{
     MultivectorOffsetsStorage, QuantizedMultivectorStorage,
 };
@@ -67,6 +68,7 @@ impl GpuMultivectors {
             TMultivectorOffsetsStorage,
         >,
     ) -> OperationResult<GpuMultivectors> {
+        Self::check_comparator(quantized_storage.multi_vector_config())?;
         Self::new_impl(
             device,
             (0..quantized_storage.vectors_count())
@@ -83,6 +85,7 @@ impl GpuMultivectors {
         device: Arc<gpu::Device>,
         vector_storage: &TVectorStorage,
     ) -> OperationResult<GpuMultivectors> {
+        Self::check_comparator(vector_storage.multi_vector_config())?;
         Self::new_impl(
             device,
             (0..vector_storage.total_vector_count())
@@ -120,6 +123,19 @@ impl GpuMultivectors {
         descriptor_set_layout_builder.add_storage_buffer(self.offsets_binding)
     }
 
+    /// Only MaxSim comparator is implemented in shaders.
+    fn check_comparator(multi_vector_config: &MultiVectorConfig) -> OperationResult<()> {
+        match multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim => Ok(()),
+            MultiVectorComparator::MaxSimNormalized | MultiVectorComparator::Chamfer => {
+                Err(OperationError::from(gpu::GpuError::NotSupported(format!(
+                    "Multivector comparator {:?} is not supported on GPU",
+                    multi_vector_config.comparator,
+                ))))
+            }
+        }
+    }
+
     fn new_impl(
         device: Arc<gpu::Device>,
         vector_offsets: impl Iterator<Item = GpuMultivectorOffset> + Clone,
@@ -3,10 +3,11 @@ use std::sync::Arc;
 
 use common::types::PointOffsetType;
 use quantization::encoded_vectors_binary::{BitsStoreType, EncodedVectorsBin};
+use quantization::encoded_vectors_u8::ScalarQuantizationMethod;
 use quantization::{EncodedStorage, EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
 
 use super::{GpuVectorStorage, STORAGES_COUNT};
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::hnsw_index::gpu::GPU_TIMEOUT;
 use crate::index::hnsw_index::gpu::shader_builder::ShaderBuilderParameters;
 
@@ -211,6 +212,11 @@ impl GpuScalarQuantization {
         device: Arc<gpu::Device>,
         quantized_storage: &EncodedVectorsU8<TStorage>,
     ) -> OperationResult<Self> {
+        if quantized_storage.get_method() != ScalarQuantizationMethod::Int8 {
+            return Err(OperationError::from(gpu::GpuError::NotSupported(
+                "Only int8 scalar quantization is supported on GPU".to_string(),
+            )));
+        }
         Ok(GpuScalarQuantization {
             multiplier: quantized_storage.get_multiplier(),
             diff: quantized_storage.get_diff(),
@@ -19,7 +19,9 @@ use zerocopy::IntoBytes;
 use super::shader_builder::ShaderBuilderParameters;
 use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorElementTypeHalf};
+use crate::data_types::vectors::{
+    VectorElementType, VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf,
+};
 use crate::index::hnsw_index::gpu::GPU_TIMEOUT;
 use crate::types::{Distance, VectorStorageDatatype};
 use crate::vector_storage::quantized::quantized_vectors::{
@@ -86,13 +88,14 @@ impl ShaderBuilderParameters for GpuVectorStorage {
     fn shader_defines(&self) -> HashMap<String, Option<String>> {
         let mut defines = HashMap::new();
         match self.element_type {
-            VectorStorageDatatype::Float32 => {
+            // BFloat16 vectors are uploaded to GPU as Float32
+            VectorStorageDatatype::Float32 | VectorStorageDatatype::BFloat16 => {
                 defines.insert("VECTOR_STORAGE_ELEMENT_FLOAT32".to_owned(), None);
             }
             VectorStorageDatatype::Float16 => {
                 defines.insert("VECTOR_STORAGE_ELEMENT_FLOAT16".to_owned(), None);
             }
-            VectorStorageDatatype::Uint8 => {
+            VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
                 defines.insert("VECTOR_STORAGE_ELEMENT_UINT8".to_owned(), None);
             }
         }
@@ -110,6 +113,8 @@ impl ShaderBuilderParameters for GpuVectorStorage {
             Distance::Manhattan => {
                 defines.insert("MANHATTAN_DISTANCE".to_owned(), None);
             }
+            // Rejected on storage creation
+            Distance::Hamming | Distance::Jaccard => {}
         }
 
         if let Some(quantization) = &self.quantization {
@@ -138,6 +143,12 @@ impl GpuVectorStorage {
         force_half_precision: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<Self> {
+        if vector_storage.distance().is_binary() {
+            return Err(OperationError::from(gpu::GpuError::NotSupported(
+                "Binary vectors are not supported on GPU".to_string(),
+            )));
+        }
+
         if let Some(quantized_storage) = quantized_storage {
             Self::new_quantized(
                 device,
@@ -301,6 +312,14 @@ impl GpuVectorStorage {
                 Some(GpuMultivectors::new_quantized(device, quantized_storage)?),
                 stopped,
             ),
+            QuantizedVectorStorage::RabitqRam(_)
+            | QuantizedVectorStorage::RabitqMmap(_)
+            | QuantizedVectorStorage::RabitqRamMulti(_)
+            | QuantizedVectorStorage::RabitqMmapMulti(_) => {
+                Err(OperationError::from(gpu::GpuError::NotSupported(
+                    "RaBitQ quantization is not supported on GPU".to_string(),
+                )))
+            }
         }
     }
 
@@ -421,6 +440,9 @@ impl GpuVectorStorage {
             VectorStorageEnum::DenseMemmapHalf(vector_storage) => {
                 Self::new_dense_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::DenseMemmapBFloat16(vector_storage) => {
+                Self::new_dense_bf16(device, vector_storage.as_ref(), stopped)
+            }
             VectorStorageEnum::DenseAppendableMemmap(vector_storage) => Self::new_dense_f32(
                 device,
                 vector_storage.as_ref(),
@@ -433,6 +455,9 @@ impl GpuVectorStorage {
             VectorStorageEnum::DenseAppendableMemmapHalf(vector_storage) => {
                 Self::new_dense_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(vector_storage) => {
+                Self::new_dense_bf16(device, vector_storage.as_ref(), stopped)
+            }
             VectorStorageEnum::DenseAppendableInRam(vector_storage) => Self::new_dense_f32(
                 device,
                 vector_storage.as_ref(),
@@ -445,6 +470,9 @@ impl GpuVectorStorage {
             VectorStorageEnum::DenseAppendableInRamHalf(vector_storage) => {
                 Self::new_dense_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::DenseAppendableInRamBFloat16(vector_storage) => {
+                Self::new_dense_bf16(device, vector_storage.as_ref(), stopped)
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::from(
                 gpu::GpuError::NotSupported("Sparse vectors are not supported on GPU".to_string()),
@@ -496,6 +524,9 @@ impl GpuVectorStorage {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(vector_storage) => {
                 Self::new_multi_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(vector_storage) => {
+                Self::new_multi_bf16(device, vector_storage.as_ref(), stopped)
+            }
             VectorStorageEnum::MultiDenseAppendableInRam(vector_storage) => Self::new_multi_f32(
                 device.clone(),
                 vector_storage.as_ref(),
@@ -508,6 +539,9 @@ impl GpuVectorStorage {
             VectorStorageEnum::MultiDenseAppendableInRamHalf(vector_storage) => {
                 Self::new_multi_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(vector_storage) => {
+                Self::new_multi_bf16(device, vector_storage.as_ref(), stopped)
+            }
         }
     }
 
@@ -564,6 +598,29 @@ impl GpuVectorStorage {
         }
     }
 
+    fn new_dense_bf16<TVectorStorage: DenseVectorStorage<VectorElementTypeBFloat16>>(
+        device: Arc<gpu::Device>,
+        vector_storage: &TVectorStorage,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        // Shaders have no bfloat16 support, upload vectors as Float32
+        Self::new_typed::<VectorElementType>(
+            device,
+            vector_storage.distance(),
+            vector_storage.total_vector_count(),
+            vector_storage.total_vector_count(),
+            vector_storage.vector_dim(),
+            (0..vector_storage.total_vector_count()).map(|id| {
+                VectorElementTypeBFloat16::slice_to_float_cow(Cow::Borrowed(
+                    vector_storage.get_dense::<Random>(id as PointOffsetType),
+                ))
+            }),
+            None,
+            None,
+            stopped,
+        )
+    }
+
     fn new_dense<TElement: PrimitiveVectorElement, TVectorStorage: DenseVectorStorage<TElement>>(
         device: Arc<gpu::Device>,
         vector_storage: &TVectorStorage,
@@ -644,6 +701,33 @@ impl GpuVectorStorage {
         }
     }
 
+    fn new_multi_bf16<TVectorStorage: MultiVectorStorage<VectorElementTypeBFloat16>>(
+        device: Arc<gpu::Device>,
+        vector_storage: &TVectorStorage,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        // Shaders have no bfloat16 support, upload vectors as Float32
+        Self::new_typed::<VectorElementType>(
+            device.clone(),
+            vector_storage.distance(),
+            (0..vector_storage.total_vector_count())
+                .map(|id| {
+                    vector_storage
+                        .get_multi::<Random>(id as PointOffsetType)
+                        .vectors_count()
+                })
+                .sum(),
+            vector_storage.total_vector_count(),
+            vector_storage.vector_dim(),
+            vector_storage
+                .iterate_inner_vectors()
+                .map(|vector| VectorElementTypeBFloat16::slice_to_float_cow(Cow::Borrowed(vector))),
+            None,
+            Some(GpuMultivectors::new_multidense(device, vector_storage)?),
+            stopped,
+        )
+    }
+
     fn new_multi<TElement: PrimitiveVectorElement, TVectorStorage: MultiVectorStorage<TElement>>(
         device: Arc<gpu::Device>,
         vector_storage: &TVectorStorage,
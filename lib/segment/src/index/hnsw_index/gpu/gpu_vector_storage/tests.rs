@@ -313,6 +313,7 @@ fn test_gpu_vector_storage_pq(
         product: ProductQuantizationConfig {
             always_ram: Some(true),
             compression: crate::types::CompressionRatio::X8,
+            opq: None,
         },
     });
 
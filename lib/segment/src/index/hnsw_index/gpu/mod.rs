@@ -106,6 +106,7 @@ mod tests {
     use crate::index::hnsw_index::graph_layers::GraphLayers;
     use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
     use crate::index::hnsw_index::graph_links::GraphLinksFormatParam;
+    use crate::index::hnsw_index::search_context::SearchTraversal;
     use crate::types::Distance;
     use crate::vector_storage::dense::volatile_dense_vector_storage::new_volatile_dense_vector_storage;
     use crate::vector_storage::{DEFAULT_STOPPED, Random, VectorStorage, VectorStorageEnum};
@@ -214,13 +215,27 @@ mod tests {
             let scorer = test.vector_holder.scorer(search_vector.clone());
 
             let search_result_gpu = graph
-                .search(top, ef, scorer, None, &DEFAULT_STOPPED)
+                .search(
+                    top,
+                    ef,
+                    SearchTraversal::Direct,
+                    scorer,
+                    None,
+                    &DEFAULT_STOPPED,
+                )
                 .unwrap();
 
             let scorer = test.vector_holder.scorer(search_vector.clone());
 
             let search_result_cpu = ref_graph
-                .search(top, ef, scorer, None, &DEFAULT_STOPPED)
+                .search(
+                    top,
+                    ef,
+                    SearchTraversal::Direct,
+                    scorer,
+                    None,
+                    &DEFAULT_STOPPED,
+                )
                 .unwrap();
 
             let mut gpu_set = HashSet::default();
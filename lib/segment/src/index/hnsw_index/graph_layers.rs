@@ -18,7 +18,7 @@ use crate::common::operation_error::{
 use crate::common::utils::rev_range;
 use crate::index::hnsw_index::graph_links::{GraphLinksFormatParam, serialize_graph_links};
 use crate::index::hnsw_index::point_scorer::{FilteredBytesScorer, FilteredScorer, ScorerFilters};
-use crate::index::hnsw_index::search_context::SearchContext;
+use crate::index::hnsw_index::search_context::{SearchContext, SearchTraversal};
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
 use crate::vector_storage::RawScorer;
 use crate::vector_storage::query_scorer::QueryScorerBytes;
@@ -119,6 +119,92 @@ pub trait GraphLayersBase {
         Ok(search_context.nearest)
     }
 
+    /// Similar to [`GraphLayersBase::_search_on_level`], but also follows links of the
+    /// neighbours which don't pass the filter, see [`SearchTraversal::TwoHop`].
+    fn _search_on_level_two_hop(
+        &self,
+        searcher: &mut SearchContext,
+        level: usize,
+        visited_list: &mut VisitedListHandle,
+        points_scorer: &mut FilteredScorer,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<()> {
+        let limit = self.get_m(level);
+        let mut points_ids: Vec<PointOffsetType> = Vec::with_capacity(limit);
+        let mut filtered_out_ids: Vec<PointOffsetType> = Vec::with_capacity(2 * limit);
+
+        while let Some(candidate) = searcher.candidates.pop() {
+            check_process_stopped(is_stopped)?;
+
+            if candidate.score < searcher.lower_bound() {
+                break;
+            }
+
+            points_ids.clear();
+            filtered_out_ids.clear();
+
+            let filters = points_scorer.filters();
+            self.for_each_link(candidate.idx, level, |link| {
+                if visited_list.check(link) {
+                    return;
+                }
+                if !filters.check_vector(link) {
+                    // Filtered-out points are never scored, only expanded once
+                    visited_list.check_and_update_visited(link);
+                    filtered_out_ids.push(link);
+                } else if points_ids.len() < limit {
+                    visited_list.check_and_update_visited(link);
+                    points_ids.push(link);
+                }
+            });
+
+            for &hop in &filtered_out_ids {
+                if points_ids.len() >= limit {
+                    break;
+                }
+                self.for_each_link(hop, level, |link| {
+                    if points_ids.len() < limit
+                        && !visited_list.check(link)
+                        && filters.check_vector(link)
+                    {
+                        visited_list.check_and_update_visited(link);
+                        points_ids.push(link);
+                    }
+                });
+            }
+
+            points_scorer
+                .score_checked_points(&points_ids)
+                .for_each(|score_point| searcher.process_candidate(score_point));
+        }
+
+        Ok(())
+    }
+
+    /// Similar to [`GraphLayersBase::search_on_level`], but uses [`SearchTraversal::TwoHop`].
+    fn search_on_level_two_hop(
+        &self,
+        level_entry: ScoredPointOffset,
+        level: usize,
+        ef: usize,
+        points_scorer: &mut FilteredScorer,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<FixedLengthPriorityQueue<ScoredPointOffset>> {
+        let mut visited_list = self.get_visited_list_from_pool();
+        visited_list.check_and_update_visited(level_entry.idx);
+        let mut search_context = SearchContext::new(ef);
+        search_context.process_candidate(level_entry);
+
+        self._search_on_level_two_hop(
+            &mut search_context,
+            level,
+            &mut visited_list,
+            points_scorer,
+            is_stopped,
+        )?;
+        Ok(search_context.nearest)
+    }
+
     /// Greedy searches for entry point of level `target_level`.
     /// Beam size is 1.
     fn search_entry(
@@ -408,10 +494,12 @@ impl GraphLayers {
             })
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn search(
         &self,
         top: usize,
         ef: usize,
+        traversal: SearchTraversal,
         mut points_scorer: FilteredScorer,
         custom_entry_points: Option<&[PointOffsetType]>,
         is_stopped: &AtomicBool,
@@ -428,13 +516,22 @@ impl GraphLayers {
             &mut points_scorer,
             is_stopped,
         )?;
-        let nearest = self.search_on_level(
-            zero_level_entry,
-            0,
-            max(top, ef),
-            &mut points_scorer,
-            is_stopped,
-        )?;
+        let nearest = match traversal {
+            SearchTraversal::Direct => self.search_on_level(
+                zero_level_entry,
+                0,
+                max(top, ef),
+                &mut points_scorer,
+                is_stopped,
+            )?,
+            SearchTraversal::TwoHop => self.search_on_level_two_hop(
+                zero_level_entry,
+                0,
+                max(top, ef),
+                &mut points_scorer,
+                is_stopped,
+            )?,
+        };
         Ok(nearest.into_iter_sorted().take(top).collect_vec())
     }
 
@@ -591,6 +688,8 @@ impl GraphLayers {
 
 #[cfg(test)]
 mod tests {
+    use bitvec::vec::BitVec;
+    use common::counter::hardware_counter::HardwareCounterCell;
     use rand::SeedableRng;
     use rand::rngs::StdRng;
     use rstest::rstest;
@@ -617,7 +716,14 @@ mod tests {
 
         let ef = 16;
         graph
-            .search(top, ef, scorer, None, &DEFAULT_STOPPED)
+            .search(
+                top,
+                ef,
+                SearchTraversal::Direct,
+                scorer,
+                None,
+                &DEFAULT_STOPPED,
+            )
             .unwrap()
     }
 
@@ -683,6 +789,76 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::direct(SearchTraversal::Direct, &[0])]
+    #[case::two_hop(SearchTraversal::TwoHop, &[0, 3, 4, 5])]
+    fn test_search_on_level_filtered(
+        #[case] traversal: SearchTraversal,
+        #[case] expected: &[PointOffsetType],
+    ) {
+        let dim = 8;
+        let hnsw_m = HnswM::new2(8);
+        let num_vectors = 10;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let vector_holder =
+            TestRawScorerProducer::new(dim, Distance::Dot, num_vectors, false, &mut rng);
+
+        // Points 1 and 2 are filtered out, points behind them are only reachable in two hops
+        let mut graph_links = vec![vec![Vec::new()]; num_vectors];
+        graph_links[0][0] = vec![1, 2];
+        graph_links[1][0] = vec![3, 4];
+        graph_links[2][0] = vec![5];
+        let mut filtered_out = BitVec::repeat(false, num_vectors);
+        filtered_out.set(1, true);
+        filtered_out.set(2, true);
+
+        let graph_layers = GraphLayers {
+            hnsw_m,
+            links: GraphLinks::new_from_edges(graph_links, GraphLinksFormatParam::Plain, hnsw_m)
+                .unwrap(),
+            entry_points: EntryPoints::new(1),
+            visited_pool: VisitedPool::new(),
+        };
+
+        let query = random_vector(&mut rng, dim);
+        let mut scorer = FilteredScorer::new(
+            query.into(),
+            vector_holder.storage(),
+            None,
+            None,
+            &filtered_out,
+            HardwareCounterCell::new(),
+        )
+        .unwrap();
+        let level_entry = ScoredPointOffset {
+            idx: 0,
+            score: scorer.score_point(0),
+        };
+
+        let nearest_on_level = match traversal {
+            SearchTraversal::Direct => {
+                graph_layers.search_on_level(level_entry, 0, 32, &mut scorer, &DEFAULT_STOPPED)
+            }
+            SearchTraversal::TwoHop => graph_layers.search_on_level_two_hop(
+                level_entry,
+                0,
+                32,
+                &mut scorer,
+                &DEFAULT_STOPPED,
+            ),
+        }
+        .unwrap();
+
+        let found: Vec<_> = nearest_on_level
+            .iter_unsorted()
+            .map(|point| point.idx)
+            .sorted()
+            .collect();
+        assert_eq!(found, expected);
+    }
+
     #[rstest]
     #[case::uncompressed((GraphLinksFormat::Plain, false))]
     #[case::converted((GraphLinksFormat::Plain, true))]
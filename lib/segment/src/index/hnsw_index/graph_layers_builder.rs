@@ -601,6 +601,7 @@ mod tests {
     use super::*;
     use crate::fixtures::index_fixtures::{TestRawScorerProducer, random_vector};
     use crate::index::hnsw_index::graph_links::{GraphLinksFormat, normalize_links};
+    use crate::index::hnsw_index::search_context::SearchTraversal;
     use crate::index::hnsw_index::tests::create_graph_layer_fixture;
     use crate::types::Distance;
     use crate::vector_storage::{DEFAULT_STOPPED, VectorStorage as _};
@@ -764,7 +765,14 @@ mod tests {
         let scorer = vector_holder.scorer(query);
         let ef = 16;
         let graph_search = graph
-            .search(top, ef, scorer, None, &DEFAULT_STOPPED)
+            .search(
+                top,
+                ef,
+                SearchTraversal::Direct,
+                scorer,
+                None,
+                &DEFAULT_STOPPED,
+            )
             .unwrap();
 
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
@@ -870,7 +878,14 @@ mod tests {
         let scorer = vector_holder.scorer(query);
         let ef = 16;
         let graph_search = graph
-            .search(top, ef, scorer, None, &DEFAULT_STOPPED)
+            .search(
+                top,
+                ef,
+                SearchTraversal::Direct,
+                scorer,
+                None,
+                &DEFAULT_STOPPED,
+            )
             .unwrap();
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
     }
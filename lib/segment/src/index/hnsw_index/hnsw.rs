@@ -36,6 +36,7 @@ use crate::common::operation_time_statistics::{
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
 use crate::id_tracker::IdTrackerSS;
+use crate::index::field_index::{CardinalityEstimation, PrimaryCondition};
 use crate::index::hnsw_index::HnswM;
 use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
 use crate::index::hnsw_index::config::HnswGraphConfig;
@@ -48,11 +49,12 @@ use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use crate::index::hnsw_index::graph_layers_healer::GraphLayersHealer;
 use crate::index::hnsw_index::graph_links::{GraphLinksFormatParam, StorageGraphLinksVectors};
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::hnsw_index::search_context::SearchTraversal;
 use crate::index::query_estimator::adjust_to_available_vectors;
 use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::vector_index_search_common::{
-    get_oversampled_top, is_quantized_search, postprocess_search_result,
+    get_oversampled_top, is_quantized_search, postprocess_search_result, rescore_search_result,
 };
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
 use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
@@ -79,6 +81,10 @@ pub const SINGLE_THREADED_HNSW_BUILD_THRESHOLD: usize = 256;
 
 const LINK_COMPRESSION_CONVERT_EXISTING: bool = false;
 
+/// Filtered searches, which are expected to match less than this fraction of vectors, use
+/// [`SearchTraversal::TwoHop`] unless there are payload links for the filtered fields.
+const TWO_HOP_MAX_SELECTIVITY: f64 = 0.1;
+
 #[derive(Debug)]
 pub struct HNSWIndex {
     id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
@@ -99,6 +105,7 @@ struct HNSWSearchesTelemetry {
     unfiltered_hnsw: Arc<Mutex<OperationDurationsAggregator>>,
     small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
     large_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    two_hop: Arc<Mutex<OperationDurationsAggregator>>,
     exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
     exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
 }
@@ -111,10 +118,22 @@ impl HNSWSearchesTelemetry {
             unfiltered_hnsw: OperationDurationsAggregator::new(),
             small_cardinality: OperationDurationsAggregator::new(),
             large_cardinality: OperationDurationsAggregator::new(),
+            two_hop: OperationDurationsAggregator::new(),
             exact_filtered: OperationDurationsAggregator::new(),
             exact_unfiltered: OperationDurationsAggregator::new(),
         }
     }
+
+    /// Aggregator of filtered graph searches with the given traversal
+    fn filtered_graph(
+        &self,
+        traversal: SearchTraversal,
+    ) -> &Arc<Mutex<OperationDurationsAggregator>> {
+        match traversal {
+            SearchTraversal::Direct => &self.large_cardinality,
+            SearchTraversal::TwoHop => &self.two_hop,
+        }
+    }
 }
 
 pub struct HnswIndexOpenArgs<'a> {
@@ -187,8 +206,8 @@ impl HNSWIndex {
         self.is_on_disk
     }
 
-    #[cfg(test)]
-    pub(super) fn graph(&self) -> &GraphLayers {
+    #[cfg(any(test, feature = "testing"))]
+    pub fn graph(&self) -> &GraphLayers {
         &self.graph
     }
 
@@ -257,6 +276,8 @@ impl HNSWIndex {
             hnsw_config.payload_m,
             total_vector_count,
         );
+        config.matryoshka_dim = hnsw_config.matryoshka_dim;
+        let build_dim = Self::matryoshka_dim(&config, quantized_vectors_ref.as_ref(), None);
 
         let old_index = old_indices
             .iter()
@@ -354,8 +375,9 @@ impl HNSWIndex {
 
         // Try to build the main graph on GPU if possible.
         // Store created gpu vectors to reuse them for payload links.
+        // GPU builds the graph with all dimensions
         #[cfg(feature = "gpu")]
-        let gpu_vectors = if build_main_graph {
+        let gpu_vectors = if build_main_graph && build_dim.is_none() {
             let timer = std::time::Instant::now();
             let gpu_vectors = Self::create_gpu_vectors(
                 gpu_device,
@@ -423,10 +445,11 @@ impl HNSWIndex {
                 // No need to accumulate hardware, since this is an internal operation
                 let internal_hardware_counter = HardwareCounterCell::disposable();
 
-                let points_scorer = FilteredScorer::new_internal(
+                let points_scorer = Self::construct_build_scorer(
                     vector_id,
                     vector_storage_ref.deref(),
                     quantized_vectors_ref.as_ref(),
+                    build_dim,
                     None,
                     id_tracker_ref.deleted_point_bitslice(),
                     internal_hardware_counter,
@@ -584,6 +607,7 @@ impl HNSWIndex {
                         id_tracker_ref.deref(),
                         &vector_storage_ref,
                         &quantized_vectors_ref,
+                        build_dim,
                         &mut gpu_insert_context,
                         &payload_index_ref,
                         &pool,
@@ -595,6 +619,11 @@ impl HNSWIndex {
                     )?;
                     graph_layers_builder.merge_from_other(additional_graph);
                 }
+
+                config
+                    .payload_linked_fields
+                    .get_or_insert_default()
+                    .push(field);
             }
 
             let indexed_payload_vectors = indexed_vectors_set.count_ones();
@@ -698,6 +727,7 @@ impl HNSWIndex {
         id_tracker: &IdTrackerSS,
         vector_storage: &VectorStorageEnum,
         quantized_vectors: &Option<QuantizedVectors>,
+        build_dim: Option<usize>,
         #[allow(unused_variables)] gpu_insert_context: &mut Option<GpuInsertContext<'_>>,
         payload_index: &StructPayloadIndex,
         pool: &ThreadPool,
@@ -741,10 +771,11 @@ impl HNSWIndex {
                 filter_list: block_filter_list,
                 current_point: block_point_id,
             };
-            let points_scorer = FilteredScorer::new_internal(
+            let points_scorer = Self::construct_build_scorer(
                 block_point_id,
                 vector_storage,
                 quantized_vectors.as_ref(),
+                build_dim,
                 Some(BoxCow::Borrowed(&block_condition_checker)),
                 id_tracker.deleted_point_bitslice(),
                 internal_hardware_counter,
@@ -946,6 +977,7 @@ impl HNSWIndex {
         top: usize,
         params: Option<&SearchParams>,
         custom_entry_points: Option<&[PointOffsetType]>,
+        traversal: SearchTraversal,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
         let ef = params
@@ -967,7 +999,10 @@ impl HNSWIndex {
         let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
 
         let search_with_vectors = || -> OperationResult<Option<Vec<ScoredPointOffset>>> {
-            if !self.graph.has_vectors() || !is_quantized_search(quantized_vectors.as_ref(), params)
+            // Graph with vectors only supports direct traversal
+            if !self.graph.has_vectors()
+                || !is_quantized_search(quantized_vectors.as_ref(), params)
+                || traversal != SearchTraversal::Direct
             {
                 return Ok(None);
             }
@@ -1009,6 +1044,40 @@ impl HNSWIndex {
             )?))
         };
 
+        let search_dim = Self::matryoshka_dim(&self.config, quantized_vectors.as_ref(), params);
+
+        let truncated_search = |dim: usize| -> OperationResult<Vec<ScoredPointOffset>> {
+            let filter_context = filter.map(|f| payload_index.filter_context(f, &hw_counter));
+            let points_scorer = FilteredScorer::new_truncated(
+                vector.to_owned(),
+                &vector_storage,
+                dim,
+                filter_context.map(BoxCow::Owned),
+                deleted_points,
+                vector_query_context.hardware_counter(),
+            )?;
+
+            // All candidates of the beam are rescored with all dimensions
+            let search_result = self.graph.search(
+                std::cmp::max(ef, top),
+                ef,
+                traversal,
+                points_scorer,
+                custom_entry_points,
+                &is_stopped,
+            )?;
+
+            let mut search_result = rescore_search_result(
+                &search_result,
+                id_tracker.deleted_point_bitslice(),
+                &vector_storage,
+                vector,
+                vector_query_context.hardware_counter(),
+            )?;
+            search_result.truncate(top);
+            Ok(search_result)
+        };
+
         let regular_search = || -> OperationResult<Vec<ScoredPointOffset>> {
             let filter_context = filter.map(|f| payload_index.filter_context(f, &hw_counter));
             let points_scorer = Self::construct_search_scorer(
@@ -1024,6 +1093,7 @@ impl HNSWIndex {
             let search_result = self.graph.search(
                 oversampled_top,
                 ef,
+                traversal,
                 points_scorer,
                 custom_entry_points,
                 &is_stopped,
@@ -1044,6 +1114,8 @@ impl HNSWIndex {
         // Try to use graph with vectors first.
         if let Some(search_result) = search_with_vectors()? {
             Ok(search_result)
+        } else if let Some(dim) = search_dim {
+            truncated_search(dim)
         } else {
             // Graph with vectors is not available, fallback to regular graph search.
             regular_search()
@@ -1056,6 +1128,7 @@ impl HNSWIndex {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        traversal: SearchTraversal,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
         vectors
@@ -1066,11 +1139,18 @@ impl HNSWIndex {
                     filter,
                     top,
                     params,
+                    traversal,
+                    vector_query_context,
+                ),
+                other => self.search_with_graph(
+                    other,
+                    filter,
+                    top,
+                    params,
+                    None,
+                    traversal,
                     vector_query_context,
                 ),
-                other => {
-                    self.search_with_graph(other, filter, top, params, None, vector_query_context)
-                }
             })
             .collect()
     }
@@ -1174,6 +1254,7 @@ impl HNSWIndex {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        traversal: SearchTraversal,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
         // Stage 1: Find best entry points using Context search
@@ -1188,6 +1269,7 @@ impl HNSWIndex {
                 DISCOVERY_ENTRY_POINT_COUNT,
                 params,
                 None,
+                traversal,
                 vector_query_context,
             )
             .map(|search_result| search_result.iter().map(|x| x.idx).collect())?;
@@ -1201,10 +1283,58 @@ impl HNSWIndex {
             top,
             params,
             Some(&custom_entry_points),
+            traversal,
             vector_query_context,
         )
     }
 
+    /// Choose how to traverse the graph in a filtered search
+    ///
+    /// A filter matching only a small fraction of vectors disconnects the main graph, unless
+    /// payload links were built for the filtered fields. Such searches use two-hop traversal,
+    /// which costs more filter checks but keeps the graph connected.
+    ///
+    /// Fields indexed after the graph was built have no payload links, so the graph's own
+    /// record of the linked fields is checked rather than the current payload index. Graphs
+    /// without such record are always traversed directly, as before.
+    fn filtered_traversal(
+        &self,
+        query_cardinality: &CardinalityEstimation,
+        available_vector_count: usize,
+    ) -> SearchTraversal {
+        if self.config.m == 0 {
+            return SearchTraversal::Direct;
+        }
+
+        // Unknown which fields have payload links
+        let Some(payload_linked_fields) = &self.config.payload_linked_fields else {
+            return SearchTraversal::Direct;
+        };
+
+        let is_selective = (query_cardinality.exp as f64)
+            < available_vector_count as f64 * TWO_HOP_MAX_SELECTIVITY;
+        if !is_selective {
+            return SearchTraversal::Direct;
+        }
+
+        let has_payload_links = self.config.payload_m.unwrap_or(self.config.m) > 0
+            && !query_cardinality.primary_clauses.is_empty()
+            && query_cardinality
+                .primary_clauses
+                .iter()
+                .all(|clause| match clause {
+                    PrimaryCondition::Condition(condition) => {
+                        payload_linked_fields.contains(&condition.key)
+                    }
+                    PrimaryCondition::Ids(_) | PrimaryCondition::HasVector(_) => false,
+                });
+        if has_payload_links {
+            SearchTraversal::Direct
+        } else {
+            SearchTraversal::TwoHop
+        }
+    }
+
     fn construct_search_scorer<'a>(
         vector: &QueryVector,
         vector_storage: &'a VectorStorageEnum,
@@ -1225,6 +1355,58 @@ impl HNSWIndex {
         )
     }
 
+    /// Number of first dimensions to score points with, if the graph is used with Matryoshka
+    /// embeddings
+    ///
+    /// Quantized vectors, and graphs built with all dimensions, are always scored with all
+    /// dimensions.
+    fn matryoshka_dim(
+        config: &HnswGraphConfig,
+        quantized_storage: Option<&QuantizedVectors>,
+        params: Option<&SearchParams>,
+    ) -> Option<usize> {
+        if is_quantized_search(quantized_storage, params) {
+            return None;
+        }
+        let build_dim = config.matryoshka_dim?;
+        Some(
+            params
+                .and_then(|params| params.matryoshka_dim)
+                .unwrap_or(build_dim),
+        )
+    }
+
+    /// Scorer to insert `point_id` into the graph, over the first `build_dim` dimensions if set
+    #[allow(clippy::too_many_arguments)]
+    fn construct_build_scorer<'a>(
+        point_id: PointOffsetType,
+        vector_storage: &'a VectorStorageEnum,
+        quantized_vectors: Option<&'a QuantizedVectors>,
+        build_dim: Option<usize>,
+        filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
+        deleted_points: &'a BitSlice,
+        hardware_counter: HardwareCounterCell,
+    ) -> OperationResult<FilteredScorer<'a>> {
+        match build_dim {
+            Some(dim) => FilteredScorer::new_internal_truncated(
+                point_id,
+                vector_storage,
+                dim,
+                filter_context,
+                deleted_points,
+                hardware_counter,
+            ),
+            None => FilteredScorer::new_internal(
+                point_id,
+                vector_storage,
+                quantized_vectors,
+                filter_context,
+                deleted_points,
+                hardware_counter,
+            ),
+        }
+    }
+
     /// Read underlying data from disk into disk cache.
     pub fn populate(&self) -> OperationResult<()> {
         self.graph.populate()
@@ -1299,7 +1481,14 @@ impl VectorIndex for HNSWIndex {
                 } else {
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_hnsw);
-                    self.search_vectors_with_graph(vectors, None, top, params, query_context)
+                    self.search_vectors_with_graph(
+                        vectors,
+                        None,
+                        top,
+                        params,
+                        SearchTraversal::Direct,
+                        query_context,
+                    )
                 }
             }
             Some(query_filter) => {
@@ -1354,15 +1543,19 @@ impl VectorIndex for HNSWIndex {
                     );
                 }
 
+                let traversal = self.filtered_traversal(&query_cardinality, available_vector_count);
+
                 if query_cardinality.min > self.config.full_scan_threshold {
                     // if cardinality is high enough - use HNSW index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
+                    let _timer = ScopeDurationMeasurer::new(
+                        self.searches_telemetry.filtered_graph(traversal),
+                    );
                     return self.search_vectors_with_graph(
                         vectors,
                         filter,
                         top,
                         params,
+                        traversal,
                         query_context,
                     );
                 }
@@ -1378,9 +1571,17 @@ impl VectorIndex for HNSWIndex {
                     available_vector_count, // Check cardinality among available vectors
                 ) {
                     // if cardinality is high enough - use HNSW index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    self.search_vectors_with_graph(vectors, filter, top, params, query_context)
+                    let _timer = ScopeDurationMeasurer::new(
+                        self.searches_telemetry.filtered_graph(traversal),
+                    );
+                    self.search_vectors_with_graph(
+                        vectors,
+                        filter,
+                        top,
+                        params,
+                        traversal,
+                        query_context,
+                    )
                 } else {
                     // if cardinality is small - use plain index
                     let _timer =
@@ -1400,6 +1601,7 @@ impl VectorIndex for HNSWIndex {
             unfiltered_hnsw: tm.unfiltered_hnsw.lock().get_statistics(detail),
             filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
             filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
+            filtered_two_hop: tm.two_hop.lock().get_statistics(detail),
             filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
             filtered_sparse: Default::default(),
             unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
@@ -1498,6 +1700,7 @@ impl<'a> OldIndexCandidate<'a> {
         let configuration_mismatch = config.m != old_index.config.m
             || config.m0 != old_index.config.m0
             || config.ef_construct != old_index.config.ef_construct
+            || config.matryoshka_dim != old_index.config.matryoshka_dim
             || new_quantization_config != old_quantization_config;
         if no_main_graph || configuration_mismatch {
             return None;
@@ -11,7 +11,7 @@ pub mod graph_links;
 pub mod hnsw;
 mod links_container;
 pub mod point_scorer;
-mod search_context;
+pub mod search_context;
 
 #[cfg(feature = "gpu")]
 pub mod gpu;
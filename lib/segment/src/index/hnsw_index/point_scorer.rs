@@ -15,6 +15,7 @@ use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query_scorer::QueryScorerBytes;
 use crate::vector_storage::{
     Random, RawScorer, VectorStorage, VectorStorageEnum, check_deleted_condition, new_raw_scorer,
+    new_truncated_raw_scorer,
 };
 
 /// Scorers composition:
@@ -162,6 +163,48 @@ impl<'a> FilteredScorer<'a> {
         })
     }
 
+    /// Create a new filtered scorer over the first `dim` dimensions of dense vectors.
+    ///
+    /// See [`new_truncated_raw_scorer`].
+    pub fn new_truncated(
+        query: QueryVector,
+        vectors: &'a VectorStorageEnum,
+        dim: usize,
+        filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
+        point_deleted: &'a BitSlice,
+        hardware_counter: HardwareCounterCell,
+    ) -> OperationResult<Self> {
+        Ok(FilteredScorer {
+            raw_scorer: new_truncated_raw_scorer(query, vectors, dim, hardware_counter)?,
+            filters: ScorerFilters {
+                filter_context,
+                point_deleted,
+                vec_deleted: vectors.deleted_vector_bitslice(),
+            },
+            scores_buffer: Vec::new(),
+        })
+    }
+
+    /// Same as [`FilteredScorer::new_truncated`], with the stored vector of `point_id` as query.
+    pub fn new_internal_truncated(
+        point_id: PointOffsetType,
+        vectors: &'a VectorStorageEnum,
+        dim: usize,
+        filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
+        point_deleted: &'a BitSlice,
+        hardware_counter: HardwareCounterCell,
+    ) -> OperationResult<Self> {
+        let query = vectors.get_vector::<Random>(point_id);
+        Self::new_truncated(
+            query.as_vec_ref().into(),
+            vectors,
+            dim,
+            filter_context,
+            point_deleted,
+            hardware_counter,
+        )
+    }
+
     /// Create a new filtered scorer for testing purposes.
     ///
     /// # Panics
@@ -220,6 +263,15 @@ impl<'a> FilteredScorer<'a> {
             point_ids.truncate(limit);
         }
 
+        self.score_checked_points(point_ids)
+    }
+
+    /// Calculates scores for the given points IDs, which are already checked with
+    /// [`ScorerFilters::check_vector`].
+    pub fn score_checked_points(
+        &mut self,
+        point_ids: &[PointOffsetType],
+    ) -> impl Iterator<Item = ScoredPointOffset> {
         if self.scores_buffer.len() < point_ids.len() {
             self.scores_buffer.resize(point_ids.len(), 0.0);
         }
@@ -227,7 +279,7 @@ impl<'a> FilteredScorer<'a> {
         self.raw_scorer
             .score_points(point_ids, &mut self.scores_buffer[..point_ids.len()]);
 
-        std::iter::zip(&*point_ids, &self.scores_buffer)
+        std::iter::zip(point_ids, &self.scores_buffer)
             .map(|(&idx, &score)| ScoredPointOffset { idx, score })
     }
 
@@ -4,6 +4,19 @@ use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
 use common::types::{ScoreType, ScoredPointOffset};
 use num_traits::float::FloatCore;
 
+/// How the graph is traversed around points, which don't pass the search filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchTraversal {
+    /// Follow links of the points which pass the filter only
+    #[default]
+    Direct,
+    /// Also follow links of the neighbours which don't pass the filter (ACORN-1)
+    ///
+    /// Restrictive filters break the graph into many disconnected islands. Expanding two-hop
+    /// neighbours lets the search walk over filtered-out points to the matching ones.
+    TwoHop,
+}
+
 /// Structure that holds context of the search
 pub struct SearchContext {
     /// Overall nearest points found so far
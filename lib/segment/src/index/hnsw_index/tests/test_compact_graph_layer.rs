@@ -11,6 +11,7 @@ use crate::index::hnsw_index::graph_layers::GraphLayersBase;
 use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use crate::index::hnsw_index::graph_links::GraphLinksFormat;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::hnsw_index::search_context::SearchTraversal;
 use crate::index::hnsw_index::tests::create_graph_layer_builder_fixture;
 use crate::types::Distance;
 use crate::vector_storage::DEFAULT_STOPPED;
@@ -96,7 +97,14 @@ fn test_compact_graph_layers(#[case] format: GraphLinksFormat) {
         .map(|query| {
             let scorer = vector_holder.scorer(query.clone());
             graph_layers
-                .search(top, ef, scorer, None, &DEFAULT_STOPPED)
+                .search(
+                    top,
+                    ef,
+                    SearchTraversal::Direct,
+                    scorer,
+                    None,
+                    &DEFAULT_STOPPED,
+                )
                 .unwrap()
         })
         .collect_vec();
@@ -0,0 +1,39 @@
+use std::path::{Path, PathBuf};
+
+use io::file_operations::{atomic_save_json, read_json};
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+use crate::types::IvfResiduals;
+
+pub const IVF_INDEX_CONFIG_FILE: &str = "ivf_config.json";
+
+/// Parameters the IVF index was built with
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub struct IvfListsConfig {
+    /// Dimension of centroids
+    pub dim: usize,
+    /// Number of inverted lists, `0` if there are no indexed vectors
+    pub nlist: usize,
+    /// Quantization of residuals, `None` if probed vectors are scored with original vectors
+    pub residuals: Option<IvfResiduals>,
+    /// Number of vector dimensions replaced by a single PQ centroid, only used with product
+    /// quantized residuals
+    pub pq_bucket_size: usize,
+    /// Number of vectors put into inverted lists
+    pub indexed_vector_count: usize,
+}
+
+impl IvfListsConfig {
+    pub fn get_config_path(path: &Path) -> PathBuf {
+        path.join(IVF_INDEX_CONFIG_FILE)
+    }
+
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        Ok(read_json(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_json(path, self)?)
+    }
+}
@@ -0,0 +1,141 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+use io::file_operations::atomic_save;
+use memmap2::Mmap;
+use memory::fadvise::clear_disk_cache;
+use memory::madvise::{Advice, AdviceSetting, Madviseable};
+use memory::mmap_ops::{open_read_mmap, transmute_from_u8_to_slice, transmute_to_u8_slice};
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::vectors::VectorElementType;
+
+pub const IVF_CENTROIDS_FILE: &str = "ivf_centroids.bin";
+pub const IVF_LISTS_FILE: &str = "ivf_lists.bin";
+
+const OFFSET_SIZE: usize = size_of::<u32>();
+
+/// Coarse centroids and inverted lists of the IVF index
+///
+/// Centroids are always kept in RAM. Inverted lists are stored in a single file: `nlist + 1`
+/// offsets of the lists followed by point offsets of all lists, list after list.
+#[derive(Debug)]
+pub struct InvertedLists {
+    dim: usize,
+    nlist: usize,
+    centroids: Vec<VectorElementType>,
+    lists: Mmap,
+}
+
+impl InvertedLists {
+    fn centroids_path(dir: &Path) -> PathBuf {
+        dir.join(IVF_CENTROIDS_FILE)
+    }
+
+    fn lists_path(dir: &Path) -> PathBuf {
+        dir.join(IVF_LISTS_FILE)
+    }
+
+    /// Write `centroids` and the inverted list of every centroid into `dir`
+    pub fn save(
+        dir: &Path,
+        centroids: &[VectorElementType],
+        lists: &[Vec<PointOffsetType>],
+    ) -> OperationResult<()> {
+        atomic_save(&Self::centroids_path(dir), |writer| {
+            writer.write_all(transmute_to_u8_slice(centroids))
+        })?;
+
+        atomic_save(&Self::lists_path(dir), |writer| {
+            let mut offset = 0u32;
+            writer.write_all(&offset.to_ne_bytes())?;
+            for list in lists {
+                offset += list.len() as u32;
+                writer.write_all(&offset.to_ne_bytes())?;
+            }
+            for list in lists {
+                writer.write_all(transmute_to_u8_slice(list))?;
+            }
+            Ok::<_, OperationError>(())
+        })
+    }
+
+    pub fn open(dir: &Path, dim: usize, nlist: usize, on_disk: bool) -> OperationResult<Self> {
+        let centroids_path = Self::centroids_path(dir);
+        let centroids_bytes = std::fs::read(&centroids_path)?;
+        if centroids_bytes.len() != nlist * dim * size_of::<VectorElementType>() {
+            return Err(OperationError::service_error(format!(
+                "IVF centroids file {} is corrupted, expected {nlist} centroids of dimension {dim}",
+                centroids_path.display(),
+            )));
+        }
+        let centroids = centroids_bytes
+            .chunks_exact(size_of::<VectorElementType>())
+            .map(|chunk| VectorElementType::from_ne_bytes(chunk.try_into().unwrap()))
+            .collect();
+
+        let lists_path = Self::lists_path(dir);
+        let populate = !on_disk;
+        let lists = open_read_mmap(&lists_path, AdviceSetting::Advice(Advice::Normal), populate)?;
+        let offsets_size = (nlist + 1) * OFFSET_SIZE;
+        let is_valid = lists.len() >= offsets_size && {
+            let offsets: &[u32] = transmute_from_u8_to_slice(&lists[..offsets_size]);
+            offsets.is_sorted()
+                && offsets_size + offsets[nlist] as usize * OFFSET_SIZE == lists.len()
+        };
+        if !is_valid {
+            return Err(OperationError::service_error(format!(
+                "IVF lists file {} is corrupted",
+                lists_path.display(),
+            )));
+        }
+
+        Ok(Self {
+            dim,
+            nlist,
+            centroids,
+            lists,
+        })
+    }
+
+    /// Number of inverted lists
+    pub fn nlist(&self) -> usize {
+        self.nlist
+    }
+
+    pub fn centroids(&self) -> &[VectorElementType] {
+        &self.centroids
+    }
+
+    pub fn centroid(&self, list_id: usize) -> &[VectorElementType] {
+        &self.centroids[list_id * self.dim..(list_id + 1) * self.dim]
+    }
+
+    fn offsets(&self) -> &[u32] {
+        transmute_from_u8_to_slice(&self.lists[..(self.nlist + 1) * OFFSET_SIZE])
+    }
+
+    /// Point offsets of the vectors assigned to the centroid `list_id`
+    pub fn list(&self, list_id: usize) -> &[PointOffsetType] {
+        let offsets = self.offsets();
+        let points: &[PointOffsetType] =
+            transmute_from_u8_to_slice(&self.lists[(self.nlist + 1) * OFFSET_SIZE..]);
+        &points[offsets[list_id] as usize..offsets[list_id + 1] as usize]
+    }
+
+    /// Read underlying data from disk into disk cache.
+    pub fn populate(&self) {
+        self.lists.populate();
+    }
+
+    /// Drop disk cache.
+    pub fn clear_cache(&self, dir: &Path) -> OperationResult<()> {
+        clear_disk_cache(&Self::lists_path(dir))?;
+        Ok(())
+    }
+
+    pub fn files(&self, dir: &Path) -> Vec<PathBuf> {
+        vec![Self::centroids_path(dir), Self::lists_path(dir)]
+    }
+}
@@ -0,0 +1,827 @@
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use atomic_refcell::AtomicRefCell;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
+use log::debug;
+use parking_lot::Mutex;
+use quantization::kmeans::kmeans;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use rayon::prelude::*;
+
+use super::config::IvfListsConfig;
+use super::inverted_lists::InvertedLists;
+use super::residual_vectors::ResidualVectors;
+use crate::common::BYTES_IN_KB;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use crate::data_types::named_vectors::CowVector;
+use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::vectors::{QueryVector, VectorElementType, VectorInternal, VectorRef};
+use crate::id_tracker::IdTrackerSS;
+use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::sample_estimation::sample_check_cardinality;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::{PayloadIndex, VectorIndex};
+use crate::segment_constructor::VectorIndexBuildArgs;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{DotProductMetric, EuclidMetric};
+use crate::telemetry::VectorIndexSearchesTelemetry;
+use crate::types::{DEFAULT_FULL_SCAN_THRESHOLD, Distance, Filter, IvfConfig, SearchParams};
+use crate::vector_storage::dense::volatile_dense_vector_storage::new_volatile_dense_vector_storage;
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use crate::vector_storage::{
+    AccessPattern, Random, Sequential, VectorStorage, VectorStorageEnum, new_raw_scorer,
+};
+
+/// Number of sampled vectors per inverted list used to train centroids
+const KMEANS_SAMPLES_PER_LIST: usize = 64;
+const KMEANS_MAX_ITERATIONS: usize = 20;
+const KMEANS_ACCURACY: f32 = 1e-5;
+
+#[derive(Debug)]
+struct IvfSearchesTelemetry {
+    unfiltered_plain: Arc<Mutex<OperationDurationsAggregator>>,
+    unfiltered_lists: Arc<Mutex<OperationDurationsAggregator>>,
+    small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    large_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl IvfSearchesTelemetry {
+    fn new() -> Self {
+        Self {
+            unfiltered_plain: OperationDurationsAggregator::new(),
+            unfiltered_lists: OperationDurationsAggregator::new(),
+            small_cardinality: OperationDurationsAggregator::new(),
+            large_cardinality: OperationDurationsAggregator::new(),
+            exact_filtered: OperationDurationsAggregator::new(),
+            exact_unfiltered: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+pub struct IvfIndexOpenArgs<'a> {
+    pub path: &'a Path,
+    pub id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    pub dimension: usize,
+    pub distance: Distance,
+    pub config: IvfConfig,
+}
+
+/// IVF (inverted file) vector index
+///
+/// Vectors are clustered with k-means, every vector is put into the inverted list of its closest
+/// centroid. Search ranks centroids by their score against the query and only scores vectors of
+/// the `nprobe` best lists. Building the index takes a single k-means run and one pass over the
+/// vectors, which is much cheaper than building a graph.
+///
+/// Optionally, residuals of vectors relative to their centroids are quantized, see
+/// [`ResidualVectors`]. Probed vectors are then scored by residuals and the best of them are
+/// rescored with original vectors.
+#[derive(Debug)]
+pub struct IvfIndex {
+    id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    distance: Distance,
+    config: IvfConfig,
+    lists_config: IvfListsConfig,
+    lists: InvertedLists,
+    /// Centroids in a vector storage, to rank lists against any kind of query
+    centroid_storage: VectorStorageEnum,
+    residual_vectors: Option<ResidualVectors>,
+    searches_telemetry: IvfSearchesTelemetry,
+    path: PathBuf,
+}
+
+impl IvfIndex {
+    pub fn open(args: IvfIndexOpenArgs<'_>) -> OperationResult<Self> {
+        Self::check_dense_vectors(&args.vector_storage.borrow())?;
+
+        let lists_config = IvfListsConfig::load(&IvfListsConfig::get_config_path(args.path))?;
+        let lists = InvertedLists::open(
+            args.path,
+            lists_config.dim,
+            lists_config.nlist,
+            args.config.on_disk.unwrap_or_default(),
+        )?;
+        let residual_vectors = lists_config
+            .residuals
+            .filter(|_| lists_config.nlist > 0)
+            .map(|residuals| {
+                ResidualVectors::load(
+                    args.path,
+                    residuals,
+                    lists_config.dim,
+                    args.distance,
+                    lists_config.pq_bucket_size,
+                )
+            })
+            .transpose()?;
+
+        Self::new(args, lists_config, lists, residual_vectors)
+    }
+
+    pub fn build<R: Rng + ?Sized>(
+        open_args: IvfIndexOpenArgs<'_>,
+        build_args: VectorIndexBuildArgs<'_, R>,
+    ) -> OperationResult<Self> {
+        let IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            payload_index: _,
+            dimension,
+            distance,
+            config,
+        } = &open_args;
+        let VectorIndexBuildArgs {
+            permit,
+            old_indices: _,
+            gpu_device: _,
+            rng,
+            stopped,
+            hnsw_global_config: _,
+            feature_flags: _,
+        } = build_args;
+
+        create_dir_all(path)?;
+
+        let id_tracker = id_tracker.borrow();
+        let vector_storage = vector_storage.borrow();
+        Self::check_dense_vectors(&vector_storage)?;
+
+        let dim = *dimension;
+        let total_vector_count = vector_storage.total_vector_count();
+        let points: Vec<_> = id_tracker
+            .iter_ids_excluding(vector_storage.deleted_vector_bitslice())
+            .collect();
+        let nlist = config
+            .nlist
+            .unwrap_or_else(|| default_nlist(points.len()))
+            .min(points.len());
+
+        debug!(
+            "building IVF with {nlist} lists for {} vectors with {} CPUs",
+            points.len(),
+            permit.num_cpus,
+        );
+
+        let max_threads = permit.num_cpus as usize;
+        let centroids = Self::train_centroids(
+            &vector_storage,
+            dim,
+            &points,
+            nlist,
+            max_threads,
+            rng,
+            stopped,
+        )?;
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|idx| format!("ivf-build-{idx}"))
+            .num_threads(max_threads)
+            .build()?;
+        let assignments = pool.install(|| {
+            points
+                .par_iter()
+                .map(|&point_id| {
+                    let vector = get_dense_vector::<Random>(&vector_storage, point_id, dim);
+                    nearest_centroid(&centroids, dim, &vector)
+                })
+                .collect::<Vec<_>>()
+        });
+        check_process_stopped(stopped)?;
+
+        let mut lists = vec![Vec::new(); nlist];
+        let mut point_lists = vec![None; total_vector_count];
+        for (&point_id, &list_id) in points.iter().zip(&assignments) {
+            lists[list_id].push(point_id);
+            point_lists[point_id as usize] = Some(list_id);
+        }
+        InvertedLists::save(path, &centroids, &lists)?;
+        drop(lists);
+
+        let on_disk = config.on_disk.unwrap_or_default();
+        let inverted_lists = InvertedLists::open(path, dim, nlist, on_disk)?;
+
+        let pq_bucket_size = QuantizedVectors::get_bucket_size(config.pq_compression);
+        let residual_vectors = config
+            .residuals
+            .filter(|_| nlist > 0)
+            .map(|residuals| {
+                // Vectors which are not in any list get zero residuals, they are never scored
+                let vectors = point_lists.iter().enumerate().map(|(point_id, list_id)| {
+                    let mut residual = vec![0.0; dim];
+                    if let Some(list_id) = *list_id {
+                        let vector = get_dense_vector::<Sequential>(
+                            &vector_storage,
+                            point_id as PointOffsetType,
+                            dim,
+                        );
+                        let centroid = inverted_lists.centroid(list_id);
+                        for ((residual, value), center) in
+                            residual.iter_mut().zip(vector.iter()).zip(centroid)
+                        {
+                            *residual = value - center;
+                        }
+                    }
+                    residual
+                });
+                ResidualVectors::encode(
+                    path,
+                    residuals,
+                    vectors,
+                    total_vector_count,
+                    dim,
+                    *distance,
+                    pq_bucket_size,
+                    max_threads,
+                    stopped,
+                )
+            })
+            .transpose()?;
+
+        let lists_config = IvfListsConfig {
+            dim,
+            nlist,
+            residuals: config.residuals,
+            pq_bucket_size,
+            indexed_vector_count: points.len(),
+        };
+        lists_config.save(&IvfListsConfig::get_config_path(path))?;
+
+        drop(vector_storage);
+        drop(id_tracker);
+        Self::new(open_args, lists_config, inverted_lists, residual_vectors)
+    }
+
+    fn new(
+        args: IvfIndexOpenArgs<'_>,
+        lists_config: IvfListsConfig,
+        lists: InvertedLists,
+        residual_vectors: Option<ResidualVectors>,
+    ) -> OperationResult<Self> {
+        let IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            payload_index,
+            dimension: _,
+            distance,
+            config,
+        } = args;
+
+        let mut centroid_storage = new_volatile_dense_vector_storage(lists_config.dim, distance);
+        let hw_counter = HardwareCounterCell::disposable();
+        for list_id in 0..lists_config.nlist {
+            centroid_storage.insert_vector(
+                list_id as PointOffsetType,
+                VectorRef::Dense(lists.centroid(list_id)),
+                &hw_counter,
+            )?;
+        }
+
+        Ok(Self {
+            id_tracker,
+            vector_storage,
+            payload_index,
+            distance,
+            config,
+            lists_config,
+            lists,
+            centroid_storage,
+            residual_vectors,
+            searches_telemetry: IvfSearchesTelemetry::new(),
+            path: path.to_path_buf(),
+        })
+    }
+
+    fn check_dense_vectors(vector_storage: &VectorStorageEnum) -> OperationResult<()> {
+        if vector_storage.try_multi_vector_config().is_some() {
+            return Err(OperationError::validation_error(
+                "IVF index does not support multi-vectors",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Train `nlist` centroids with k-means on a random sample of `points`
+    fn train_centroids<R: Rng + ?Sized>(
+        vector_storage: &VectorStorageEnum,
+        dim: usize,
+        points: &[PointOffsetType],
+        nlist: usize,
+        max_threads: usize,
+        rng: &mut R,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Vec<VectorElementType>> {
+        if nlist == 0 {
+            return Ok(Vec::new());
+        }
+
+        // k-means starts from the first `nlist` vectors, they have to be in random order
+        let sample_size = points.len().min(nlist * KMEANS_SAMPLES_PER_LIST);
+        let mut sample = points.to_vec();
+        let (sample, _) = sample.partial_shuffle(rng, sample_size);
+
+        let mut data = Vec::with_capacity(sample_size * dim);
+        for &point_id in sample.iter() {
+            check_process_stopped(stopped)?;
+            data.extend_from_slice(&get_dense_vector::<Random>(vector_storage, point_id, dim));
+        }
+
+        Ok(kmeans(
+            &data,
+            nlist,
+            dim,
+            KMEANS_MAX_ITERATIONS,
+            max_threads,
+            KMEANS_ACCURACY,
+            stopped,
+        )?)
+    }
+
+    pub fn config(&self) -> &IvfConfig {
+        &self.config
+    }
+
+    pub fn is_on_disk(&self) -> bool {
+        self.config.on_disk.unwrap_or_default()
+    }
+
+    /// Full scan threshold in number of vectors
+    fn full_scan_threshold(&self, vector_storage: &VectorStorageEnum) -> usize {
+        let full_scan_threshold_kb = self
+            .config
+            .full_scan_threshold
+            .unwrap_or(DEFAULT_FULL_SCAN_THRESHOLD);
+        vector_storage
+            .size_of_available_vectors_in_bytes()
+            .checked_div(vector_storage.available_vector_count())
+            .and_then(|avg_vector_size| {
+                full_scan_threshold_kb
+                    .saturating_mul(BYTES_IN_KB)
+                    .checked_div(avg_vector_size)
+            })
+            .unwrap_or(1)
+    }
+
+    /// Number of lists to probe at least
+    ///
+    /// A filter which accepts a fraction of vectors makes every list contribute the same fraction
+    /// of candidates, so proportionally more lists are probed to score as many accepted vectors
+    /// as an unfiltered search would.
+    fn nprobe(
+        &self,
+        params: Option<&SearchParams>,
+        filter_cardinality: Option<usize>,
+        available_vector_count: usize,
+    ) -> usize {
+        let nprobe = params
+            .and_then(|params| params.ivf_nprobe)
+            .unwrap_or(self.config.nprobe);
+        let nprobe = match filter_cardinality {
+            Some(cardinality) => {
+                nprobe.saturating_mul(available_vector_count.div_ceil(cardinality.max(1)))
+            }
+            None => nprobe,
+        };
+        nprobe.clamp(1, self.lists_config.nlist.max(1))
+    }
+
+    /// Score all given points with the vectors of the segment vector storage
+    fn search_plain(
+        &self,
+        vector: &QueryVector,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        top: usize,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let deleted_points = query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+        let scorer = FilteredScorer::new(
+            vector.to_owned(),
+            &vector_storage,
+            None,
+            None,
+            deleted_points,
+            query_context.hardware_counter(),
+        )?;
+        let is_stopped = query_context.is_stopped();
+        Ok(scorer.peek_top_iter(points, top, &is_stopped)?)
+    }
+
+    fn search_vectors_plain(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        match filter {
+            Some(filter) => {
+                let filtered_points = self
+                    .payload_index
+                    .borrow()
+                    .query_points(filter, &query_context.hardware_counter());
+                vectors
+                    .iter()
+                    .map(|vector| {
+                        self.search_plain(
+                            vector,
+                            &mut filtered_points.iter().copied(),
+                            top,
+                            query_context,
+                        )
+                    })
+                    .collect()
+            }
+            None => {
+                let id_tracker = self.id_tracker.borrow();
+                vectors
+                    .iter()
+                    .map(|vector| {
+                        self.search_plain(
+                            vector,
+                            &mut id_tracker.iter_internal(),
+                            top,
+                            query_context,
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn search_vectors_with_lists(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        nprobe: usize,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        vectors
+            .iter()
+            .map(|vector| {
+                self.search_with_lists(vector, filter, top, params, nprobe, query_context)
+            })
+            .collect()
+    }
+
+    /// Score vectors of the inverted lists closest to the query
+    ///
+    /// At least `nprobe` lists are probed. If they don't have enough points accepted by the
+    /// filter, the next closest lists are probed until there are enough of them.
+    fn search_with_lists(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        nprobe: usize,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let nlist = self.lists.nlist();
+        if nlist == 0 || top == 0 {
+            return Ok(Vec::new());
+        }
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let payload_index = self.payload_index.borrow();
+
+        let hw_counter = query_context.hardware_counter();
+        let is_stopped = query_context.is_stopped();
+        let deleted_points = query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+        let deleted_vectors = vector_storage.deleted_vector_bitslice();
+        let filter_context = filter.map(|filter| payload_index.filter_context(filter, &hw_counter));
+        let check = |point_id: PointOffsetType| {
+            let is_deleted = deleted_points
+                .get(point_id as usize)
+                .is_some_and(|deleted| *deleted)
+                || deleted_vectors
+                    .get(point_id as usize)
+                    .is_some_and(|deleted| *deleted);
+            !is_deleted
+                && filter_context
+                    .as_ref()
+                    .is_none_or(|filter_context| filter_context.check(point_id))
+        };
+
+        // Rank lists by the score of their centroids
+        let list_ids: Vec<_> = (0..nlist as PointOffsetType).collect();
+        let mut list_scores = vec![0.0; nlist];
+        new_raw_scorer(
+            vector.to_owned(),
+            &self.centroid_storage,
+            query_context.hardware_counter(),
+        )?
+        .score_points(&list_ids, &mut list_scores);
+        let mut list_order: Vec<_> = (0..nlist).collect();
+        list_order.sort_unstable_by(|&a, &b| list_scores[b].total_cmp(&list_scores[a]));
+
+        // Nearest queries are scored by quantized residuals if there are any, other queries are
+        // scored with the vectors of the segment vector storage
+        let quantization_params = params.and_then(|params| params.quantization);
+        let residual_query = match (self.residual_vectors.as_ref(), vector) {
+            (Some(residual_vectors), QueryVector::Nearest(VectorInternal::Dense(dense)))
+                if !quantization_params.is_some_and(|params| params.ignore) =>
+            {
+                let query = self
+                    .distance
+                    .preprocess_vector::<VectorElementType>(dense.clone());
+                Some((residual_vectors, query))
+            }
+            _ => None,
+        };
+        let candidates_count = match (&residual_query, quantization_params) {
+            (Some(_), Some(quantization_params)) => quantization_params
+                .oversampling
+                .map_or(top, |oversampling| {
+                    (oversampling * top as f64).ceil() as usize
+                })
+                .max(top),
+            _ => top,
+        };
+
+        let raw_scorer = new_raw_scorer(
+            vector.to_owned(),
+            &vector_storage,
+            query_context.hardware_counter(),
+        )?;
+        // Residual scores of Dot and Cosine are linear in the residual, the query is the same for
+        // all lists. Other distances encode the difference of the query and the centroid.
+        let shared_residual_query = residual_query.as_ref().and_then(|(residuals, query)| {
+            matches!(self.distance, Distance::Dot | Distance::Cosine)
+                .then(|| residuals.encode_query(query))
+        });
+
+        let mut candidates = FixedLengthPriorityQueue::new(candidates_count);
+        let mut accepted_count = 0;
+        let mut points = Vec::new();
+        let mut scores = Vec::new();
+        for (probed, &list_id) in list_order.iter().enumerate() {
+            if probed >= nprobe && accepted_count >= candidates_count {
+                break;
+            }
+            check_process_stopped(&is_stopped)?;
+
+            let list = self.lists.list(list_id);
+            hw_counter.vector_io_read().incr_delta(size_of_val(list));
+            points.clear();
+            points.extend(list.iter().copied().filter(|&point_id| check(point_id)));
+            accepted_count += points.len();
+
+            scores.clear();
+            match &residual_query {
+                Some((residual_vectors, query)) => {
+                    let centroid = self.lists.centroid(list_id);
+                    let list_query;
+                    let (centroid_score, encoded_query) = match &shared_residual_query {
+                        Some(encoded_query) => {
+                            hw_counter.cpu_counter().incr_delta(centroid.len());
+                            let centroid_score =
+                                <DotProductMetric as Metric<VectorElementType>>::similarity(
+                                    query, centroid,
+                                );
+                            (centroid_score, encoded_query)
+                        }
+                        None => {
+                            let difference: Vec<_> =
+                                query.iter().zip(centroid).map(|(q, c)| q - c).collect();
+                            list_query = residual_vectors.encode_query(&difference);
+                            (0.0, &list_query)
+                        }
+                    };
+                    scores.extend(points.iter().map(|&point_id| {
+                        centroid_score
+                            + residual_vectors.score_point(encoded_query, point_id, &hw_counter)
+                    }));
+                }
+                None => {
+                    scores.resize(points.len(), 0.0);
+                    raw_scorer.score_points(&points, &mut scores);
+                }
+            }
+            for (&idx, &score) in points.iter().zip(&scores) {
+                candidates.push(ScoredPointOffset { idx, score });
+            }
+        }
+
+        let mut found = candidates.into_sorted_vec();
+        let rescore = quantization_params
+            .and_then(|params| params.rescore)
+            .unwrap_or(true);
+        if residual_query.is_some() && rescore {
+            points.clear();
+            points.extend(found.iter().map(|point| point.idx));
+            scores.clear();
+            scores.resize(points.len(), 0.0);
+            raw_scorer.score_points(&points, &mut scores);
+            for (point, &score) in found.iter_mut().zip(&scores) {
+                point.score = score;
+            }
+            found.sort_unstable_by(|a, b| b.score.total_cmp(&a.score));
+        }
+        found.truncate(top);
+        Ok(found)
+    }
+
+    /// Read underlying data from disk into disk cache.
+    pub fn populate(&self) -> OperationResult<()> {
+        self.lists.populate();
+        Ok(())
+    }
+
+    /// Drop disk cache.
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.lists.clear_cache(&self.path)
+    }
+}
+
+/// Default number of lists, `4 * sqrt(N)` for `N` vectors
+fn default_nlist(vector_count: usize) -> usize {
+    ((vector_count as f64).sqrt() * 4.0).round().max(1.0) as usize
+}
+
+/// Dense vector of the point, or zeros if there is none
+fn get_dense_vector<P: AccessPattern>(
+    vector_storage: &VectorStorageEnum,
+    point_id: PointOffsetType,
+    dim: usize,
+) -> Vec<VectorElementType> {
+    match vector_storage.get_vector::<P>(point_id) {
+        CowVector::Dense(vector) => vector.into_owned(),
+        CowVector::Sparse(_) | CowVector::MultiDense(_) => vec![0.0; dim],
+    }
+}
+
+/// Index of the centroid closest to `vector` in euclidean distance, like in k-means
+fn nearest_centroid(
+    centroids: &[VectorElementType],
+    dim: usize,
+    vector: &[VectorElementType],
+) -> usize {
+    centroids
+        .chunks_exact(dim)
+        .map(|centroid| <EuclidMetric as Metric<VectorElementType>>::similarity(vector, centroid))
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(list_id, _)| list_id)
+}
+
+impl VectorIndex for IvfIndex {
+    fn search(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let exact = params.is_some_and(|params| params.exact);
+        let telemetry = &self.searches_telemetry;
+
+        let Some(query_filter) = filter else {
+            let vector_storage = self.vector_storage.borrow();
+            let available_vector_count = vector_storage.available_vector_count();
+            let plain_search =
+                exact || available_vector_count < self.full_scan_threshold(&vector_storage);
+            drop(vector_storage);
+            if plain_search {
+                let _timer = ScopeDurationMeasurer::new(if exact {
+                    &telemetry.exact_unfiltered
+                } else {
+                    &telemetry.unfiltered_plain
+                });
+                return self.search_vectors_plain(vectors, None, top, query_context);
+            }
+            let _timer = ScopeDurationMeasurer::new(&telemetry.unfiltered_lists);
+            let nprobe = self.nprobe(params, None, available_vector_count);
+            return self.search_vectors_with_lists(
+                vectors,
+                None,
+                top,
+                params,
+                nprobe,
+                query_context,
+            );
+        };
+
+        if exact {
+            let _timer = ScopeDurationMeasurer::new(&telemetry.exact_filtered);
+            return self.search_vectors_plain(vectors, filter, top, query_context);
+        }
+
+        // Like for HNSW, small cardinality filters are served by scoring the filtered points
+        // directly. Otherwise, the expected cardinality decides how many lists to probe.
+        let (use_lists, nprobe) = {
+            let id_tracker = self.id_tracker.borrow();
+            let vector_storage = self.vector_storage.borrow();
+            let payload_index = self.payload_index.borrow();
+            let hw_counter = query_context.hardware_counter();
+
+            let full_scan_threshold = self.full_scan_threshold(&vector_storage);
+            let available_vector_count = vector_storage.available_vector_count();
+            let query_cardinality = adjust_to_available_vectors(
+                payload_index.estimate_cardinality(query_filter, &hw_counter),
+                available_vector_count,
+                id_tracker.available_point_count(),
+            );
+
+            let use_lists = if query_cardinality.max < full_scan_threshold {
+                false
+            } else if query_cardinality.min > full_scan_threshold {
+                true
+            } else {
+                // Fast cardinality estimation is not enough, do sample estimation of cardinality
+                let filter_context = payload_index.filter_context(query_filter, &hw_counter);
+                sample_check_cardinality(
+                    id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
+                    |idx| filter_context.check(idx),
+                    full_scan_threshold,
+                    available_vector_count,
+                )
+            };
+            let nprobe = self.nprobe(params, Some(query_cardinality.exp), available_vector_count);
+            (use_lists, nprobe)
+        };
+
+        if use_lists {
+            let _timer = ScopeDurationMeasurer::new(&telemetry.large_cardinality);
+            self.search_vectors_with_lists(vectors, filter, top, params, nprobe, query_context)
+        } else {
+            let _timer = ScopeDurationMeasurer::new(&telemetry.small_cardinality);
+            self.search_vectors_plain(vectors, filter, top, query_context)
+        }
+    }
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
+        VectorIndexSearchesTelemetry {
+            index_name: None,
+            unfiltered_plain: tm.unfiltered_plain.lock().get_statistics(detail),
+            filtered_plain: Default::default(),
+            unfiltered_hnsw: tm.unfiltered_lists.lock().get_statistics(detail),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
+            filtered_two_hop: Default::default(),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
+            filtered_sparse: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
+            unfiltered_sparse: Default::default(),
+        }
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.lists.files(&self.path);
+        if let Some(residual_vectors) = &self.residual_vectors {
+            files.extend(residual_vectors.files());
+        }
+        files.push(IvfListsConfig::get_config_path(&self.path));
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        self.files()
+    }
+
+    fn indexed_vector_count(&self) -> usize {
+        self.lists_config.indexed_vector_count
+    }
+
+    fn size_of_searchable_vectors_in_bytes(&self) -> usize {
+        self.vector_storage
+            .borrow()
+            .size_of_available_vectors_in_bytes()
+    }
+
+    fn update_vector(
+        &mut self,
+        _id: PointOffsetType,
+        _vector: Option<VectorRef>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        Err(OperationError::service_error("Cannot update IVF index"))
+    }
+}
@@ -0,0 +1,6 @@
+pub mod config;
+pub mod inverted_lists;
+pub mod ivf_vector_index;
+mod residual_vectors;
+
+pub use ivf_vector_index::{IvfIndex, IvfIndexOpenArgs};
@@ -0,0 +1,184 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::{PointOffsetType, ScoreType};
+use quantization::{
+    EncodedQueryPQ, EncodedQueryU8, EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8,
+};
+
+use crate::common::operation_error::OperationResult;
+use crate::data_types::vectors::VectorElementType;
+use crate::types::{Distance, IvfResiduals};
+use crate::vector_storage::quantized::quantized_ram_storage::{
+    QuantizedRamStorage, QuantizedRamStorageBuilder,
+};
+use crate::vector_storage::quantized::quantized_vectors::{
+    QuantizedVectors, QuantizedVectorsStorageType,
+};
+
+const IVF_RESIDUALS_DATA_FILE: &str = "ivf_residuals.data";
+const IVF_RESIDUALS_META_FILE: &str = "ivf_residuals.json";
+
+/// Quantized differences between vectors and centroids of their inverted lists, kept in RAM
+///
+/// Residuals are much smaller than the vectors themselves, so they lose less precision on
+/// quantization.
+pub enum ResidualVectors {
+    Scalar(EncodedVectorsU8<QuantizedRamStorage>),
+    Product(EncodedVectorsPQ<QuantizedRamStorage>),
+}
+
+pub enum EncodedResidualQuery {
+    Scalar(EncodedQueryU8),
+    Product(EncodedQueryPQ),
+}
+
+impl fmt::Debug for ResidualVectors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            ResidualVectors::Scalar(_) => "Scalar",
+            ResidualVectors::Product(_) => "Product",
+        };
+        f.debug_tuple(kind).finish_non_exhaustive()
+    }
+}
+
+impl ResidualVectors {
+    #[allow(clippy::too_many_arguments)]
+    pub fn encode<'a>(
+        dir: &Path,
+        residuals: IvfResiduals,
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
+        count: usize,
+        dim: usize,
+        distance: Distance,
+        pq_bucket_size: usize,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let vector_parameters = Self::vector_parameters(dim, distance, count);
+        let data_path = dir.join(IVF_RESIDUALS_DATA_FILE);
+        let meta_path = dir.join(IVF_RESIDUALS_META_FILE);
+        match residuals {
+            IvfResiduals::Scalar => {
+                let quantized_vector_size =
+                    EncodedVectorsU8::<QuantizedRamStorage>::get_quantized_vector_size(
+                        &vector_parameters,
+                    );
+                let storage_builder =
+                    QuantizedRamStorageBuilder::new(&data_path, count, quantized_vector_size)?;
+                Ok(Self::Scalar(EncodedVectorsU8::encode(
+                    vectors,
+                    storage_builder,
+                    &vector_parameters,
+                    count,
+                    None,
+                    Some(&meta_path),
+                    stopped,
+                )?))
+            }
+            IvfResiduals::Product => {
+                let quantized_vector_size =
+                    EncodedVectorsPQ::<QuantizedRamStorage>::get_quantized_vector_size(
+                        &vector_parameters,
+                        pq_bucket_size,
+                    );
+                let storage_builder =
+                    QuantizedRamStorageBuilder::new(&data_path, count, quantized_vector_size)?;
+                Ok(Self::Product(EncodedVectorsPQ::encode(
+                    vectors,
+                    storage_builder,
+                    &vector_parameters,
+                    count,
+                    pq_bucket_size,
+                    max_threads,
+                    Some(&meta_path),
+                    stopped,
+                )?))
+            }
+        }
+    }
+
+    pub fn load(
+        dir: &Path,
+        residuals: IvfResiduals,
+        dim: usize,
+        distance: Distance,
+        pq_bucket_size: usize,
+    ) -> OperationResult<Self> {
+        let vector_parameters = Self::vector_parameters(dim, distance, 0);
+        let data_path = dir.join(IVF_RESIDUALS_DATA_FILE);
+        let meta_path = dir.join(IVF_RESIDUALS_META_FILE);
+        match residuals {
+            IvfResiduals::Scalar => {
+                let quantized_vector_size =
+                    EncodedVectorsU8::<QuantizedRamStorage>::get_quantized_vector_size(
+                        &vector_parameters,
+                    );
+                let storage = QuantizedRamStorage::from_file(&data_path, quantized_vector_size)?;
+                Ok(Self::Scalar(EncodedVectorsU8::load(storage, &meta_path)?))
+            }
+            IvfResiduals::Product => {
+                let quantized_vector_size =
+                    EncodedVectorsPQ::<QuantizedRamStorage>::get_quantized_vector_size(
+                        &vector_parameters,
+                        pq_bucket_size,
+                    );
+                let storage = QuantizedRamStorage::from_file(&data_path, quantized_vector_size)?;
+                Ok(Self::Product(EncodedVectorsPQ::load(storage, &meta_path)?))
+            }
+        }
+    }
+
+    fn vector_parameters(
+        dim: usize,
+        distance: Distance,
+        count: usize,
+    ) -> quantization::VectorParameters {
+        QuantizedVectors::construct_vector_parameters(
+            distance,
+            dim,
+            count,
+            QuantizedVectorsStorageType::Immutable,
+        )
+    }
+
+    /// Encode a preprocessed query, or its difference with a centroid for distances that are
+    /// not linear in the stored vector
+    pub fn encode_query(&self, query: &[VectorElementType]) -> EncodedResidualQuery {
+        match self {
+            ResidualVectors::Scalar(encoded) => {
+                EncodedResidualQuery::Scalar(encoded.encode_query(query))
+            }
+            ResidualVectors::Product(encoded) => {
+                EncodedResidualQuery::Product(encoded.encode_query(query))
+            }
+        }
+    }
+
+    pub fn score_point(
+        &self,
+        query: &EncodedResidualQuery,
+        point_id: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        match (self, query) {
+            (ResidualVectors::Scalar(encoded), EncodedResidualQuery::Scalar(query)) => {
+                encoded.score_point(query, point_id, hw_counter)
+            }
+            (ResidualVectors::Product(encoded), EncodedResidualQuery::Product(query)) => {
+                encoded.score_point(query, point_id, hw_counter)
+            }
+            _ => unreachable!("residual query is encoded by the same residual vectors"),
+        }
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        match self {
+            ResidualVectors::Scalar(encoded) => encoded.files(),
+            ResidualVectors::Product(encoded) => encoded.files(),
+        }
+    }
+}
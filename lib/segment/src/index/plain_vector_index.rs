@@ -208,6 +208,7 @@ impl VectorIndex for PlainVectorIndex {
             unfiltered_hnsw: OperationDurationStatistics::default(),
             filtered_small_cardinality: OperationDurationStatistics::default(),
             filtered_large_cardinality: OperationDurationStatistics::default(),
+            filtered_two_hop: OperationDurationStatistics::default(),
             filtered_exact: OperationDurationStatistics::default(),
             filtered_sparse: Default::default(),
             unfiltered_exact: OperationDurationStatistics::default(),
@@ -66,6 +66,7 @@ impl PayloadProvider {
                     .unwrap_or_else(|err| panic!("Payload storage is corrupted: {err}"));
                 Some(OwnedPayloadRef::from(payload))
             }
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => {
                 let payload = s
                     .get(point_id, hw_counter)
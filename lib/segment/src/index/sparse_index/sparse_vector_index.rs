@@ -16,6 +16,7 @@ use sparse::common::sparse_vector::SparseVector;
 use sparse::common::types::DimId;
 use sparse::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
 use sparse::index::inverted_index::{INDEX_FILE_NAME, InvertedIndex, OLD_INDEX_FILE_NAME};
+use sparse::index::posting_list_common::PostingListIter;
 use sparse::index::search_context::SearchContext;
 
 use super::indices_tracker::IndicesTracker;
@@ -34,7 +35,9 @@ use crate::index::sparse_index::sparse_search_telemetry::SparseSearchesTelemetry
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{PayloadIndex, VectorIndex};
 use crate::telemetry::VectorIndexSearchesTelemetry;
-use crate::types::{DEFAULT_SPARSE_FULL_SCAN_THRESHOLD, Filter, SearchParams};
+use crate::types::{
+    DEFAULT_SPARSE_FULL_SCAN_THRESHOLD, Filter, SearchParams, SparseSearchAlgorithm,
+};
 use crate::vector_storage::query::TransformInto;
 use crate::vector_storage::{Random, VectorStorage, VectorStorageEnum, check_deleted_condition};
 
@@ -380,6 +383,9 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             &is_stopped,
             &hw_counter,
         );
+        if let Some(bm25) = vector_query_context.bm25() {
+            search_context = search_context.with_bm25(bm25);
+        }
         let search_result = search_context.plain_search(&ids);
         Ok(search_result)
     }
@@ -390,6 +396,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         sparse_vector: &SparseVector,
         filter: Option<&Filter>,
         top: usize,
+        algorithm: SparseSearchAlgorithm,
         vector_query_context: &VectorQueryContext,
     ) -> Vec<ScoredPointOffset> {
         let vector_storage = self.vector_storage.borrow();
@@ -423,6 +430,9 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             &is_stopped,
             &hw_counter,
         );
+        if let Some(bm25) = vector_query_context.bm25() {
+            search_context = search_context.with_bm25(bm25);
+        }
 
         match filter {
             Some(filter) => {
@@ -431,9 +441,29 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 let matches_filter_condition = |idx: PointOffsetType| -> bool {
                     not_deleted_condition(idx) && filter_context.check(idx)
                 };
-                search_context.search(&matches_filter_condition)
+                Self::search_with_algorithm(
+                    &mut search_context,
+                    algorithm,
+                    &matches_filter_condition,
+                )
+            }
+            None => {
+                Self::search_with_algorithm(&mut search_context, algorithm, &not_deleted_condition)
             }
-            None => search_context.search(&not_deleted_condition),
+        }
+    }
+
+    fn search_with_algorithm<T: PostingListIter>(
+        search_context: &mut SearchContext<'_, '_, T>,
+        algorithm: SparseSearchAlgorithm,
+        filter_condition: &impl Fn(PointOffsetType) -> bool,
+    ) -> Vec<ScoredPointOffset> {
+        match algorithm {
+            SparseSearchAlgorithm::Batch => search_context.search(filter_condition),
+            SparseSearchAlgorithm::BlockMaxWand => {
+                search_context.search_block_max_wand(filter_condition)
+            }
+            SparseSearchAlgorithm::MaxScore => search_context.search_max_score(filter_condition),
         }
     }
 
@@ -442,6 +472,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         vector: &SparseVector,
         filter: Option<&Filter>,
         top: usize,
+        algorithm: SparseSearchAlgorithm,
         prefiltered_points: &mut Option<Vec<PointOffsetType>>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
@@ -471,12 +502,18 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 } else {
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_sparse);
-                    Ok(self.search_sparse(vector, Some(filter), top, vector_query_context))
+                    Ok(self.search_sparse(
+                        vector,
+                        Some(filter),
+                        top,
+                        algorithm,
+                        vector_query_context,
+                    ))
                 }
             }
             None => {
                 let _timer = ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_sparse);
-                Ok(self.search_sparse(vector, filter, top, vector_query_context))
+                Ok(self.search_sparse(vector, filter, top, algorithm, vector_query_context))
             }
         }
     }
@@ -486,6 +523,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         query_vector: &QueryVector,
         filter: Option<&Filter>,
         top: usize,
+        params: Option<&SearchParams>,
         prefiltered_points: &mut Option<Vec<PointOffsetType>>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
@@ -498,6 +536,9 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 vector.try_into()?,
                 filter,
                 top,
+                params
+                    .and_then(|params| params.sparse_algorithm)
+                    .unwrap_or_default(),
                 prefiltered_points,
                 vector_query_context,
             ),
@@ -545,7 +586,7 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
         vectors: &[&QueryVector],
         filter: Option<&Filter>,
         top: usize,
-        _params: Option<&SearchParams>,
+        params: Option<&SearchParams>,
         query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
         let mut results = Vec::with_capacity(vectors.len());
@@ -567,9 +608,23 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
                     Ok(vector)
                 })?;
 
-                self.search_query(&vector, filter, top, &mut prefiltered_points, query_context)?
+                self.search_query(
+                    &vector,
+                    filter,
+                    top,
+                    params,
+                    &mut prefiltered_points,
+                    query_context,
+                )?
             } else {
-                self.search_query(vector, filter, top, &mut prefiltered_points, query_context)?
+                self.search_query(
+                    vector,
+                    filter,
+                    top,
+                    params,
+                    &mut prefiltered_points,
+                    query_context,
+                )?
             };
 
             results.push(search_results);
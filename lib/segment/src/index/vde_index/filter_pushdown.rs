@@ -0,0 +1,322 @@
+use crate::types::{
+    Condition, FieldCondition, Filter, Match, MinShould, PayloadKeyType, RangeInterface,
+};
+
+/// Filter split into a part evaluated by VDE and a residual checked by Qdrant
+///
+/// VDE only understands a subset of Qdrant conditions: exact matches and numeric ranges on
+/// payload fields mirrored into the VDE collection. All other conditions are left in the
+/// residual, which is evaluated through the payload index of the segment.
+///
+/// A point matches the original filter if and only if it matches both parts.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct VdeFilterSplit {
+    /// Part of the filter which can be pushed down to VDE
+    pub pushdown: Option<Filter>,
+    /// Part of the filter which has to be checked by Qdrant
+    pub residual: Option<Filter>,
+}
+
+impl VdeFilterSplit {
+    /// Split `filter`, pushing down conditions on fields accepted by `is_pushable_field`
+    ///
+    /// `must` and `must_not` clauses are split condition by condition, as their conditions
+    /// are combined with AND. Clauses of `should` and `min_should` can only be pushed down
+    /// as a whole.
+    pub fn new(filter: &Filter, is_pushable_field: &impl Fn(&PayloadKeyType) -> bool) -> Self {
+        let Filter {
+            should,
+            min_should,
+            must,
+            must_not,
+        } = filter;
+
+        let mut pushdown = Filter::default();
+        let mut residual = Filter::default();
+
+        if let Some(should) = should {
+            let is_pushable = should
+                .iter()
+                .all(|condition| is_pushable_condition(condition, is_pushable_field));
+            let target = if is_pushable {
+                &mut pushdown
+            } else {
+                &mut residual
+            };
+            target.should = Some(should.clone());
+        }
+
+        if let Some(min_should) = min_should {
+            let MinShould {
+                conditions,
+                min_count: _,
+            } = min_should;
+            let is_pushable = conditions
+                .iter()
+                .all(|condition| is_pushable_condition(condition, is_pushable_field));
+            let target = if is_pushable {
+                &mut pushdown
+            } else {
+                &mut residual
+            };
+            target.min_should = Some(min_should.clone());
+        }
+
+        for condition in must.iter().flatten() {
+            match condition {
+                Condition::Filter(nested_filter) => {
+                    let nested = Self::new(nested_filter, is_pushable_field);
+                    if let Some(nested_pushdown) = nested.pushdown {
+                        add_condition(&mut pushdown.must, Condition::Filter(nested_pushdown));
+                    }
+                    if let Some(nested_residual) = nested.residual {
+                        add_condition(&mut residual.must, Condition::Filter(nested_residual));
+                    }
+                }
+                condition if is_pushable_condition(condition, is_pushable_field) => {
+                    add_condition(&mut pushdown.must, condition.clone());
+                }
+                condition => add_condition(&mut residual.must, condition.clone()),
+            }
+        }
+
+        for condition in must_not.iter().flatten() {
+            if is_pushable_condition(condition, is_pushable_field) {
+                add_condition(&mut pushdown.must_not, condition.clone());
+            } else {
+                add_condition(&mut residual.must_not, condition.clone());
+            }
+        }
+
+        Self {
+            pushdown: non_empty(pushdown),
+            residual: non_empty(residual),
+        }
+    }
+}
+
+fn add_condition(conditions: &mut Option<Vec<Condition>>, condition: Condition) {
+    conditions.get_or_insert_with(Vec::new).push(condition);
+}
+
+fn non_empty(filter: Filter) -> Option<Filter> {
+    let Filter {
+        should,
+        min_should,
+        must,
+        must_not,
+    } = &filter;
+    let is_empty = should.is_none() && min_should.is_none() && must.is_none() && must_not.is_none();
+    (!is_empty).then_some(filter)
+}
+
+fn is_pushable_condition(
+    condition: &Condition,
+    is_pushable_field: &impl Fn(&PayloadKeyType) -> bool,
+) -> bool {
+    match condition {
+        Condition::Field(field_condition) => {
+            is_pushable_field_condition(field_condition, is_pushable_field)
+        }
+        Condition::Filter(filter) => filter
+            .iter_conditions()
+            .all(|condition| is_pushable_condition(condition, is_pushable_field)),
+        Condition::IsEmpty(_)
+        | Condition::IsNull(_)
+        | Condition::HasId(_)
+        | Condition::HasVector(_)
+        | Condition::Nested(_)
+        | Condition::CustomIdChecker(_) => false,
+    }
+}
+
+fn is_pushable_field_condition(
+    field_condition: &FieldCondition,
+    is_pushable_field: &impl Fn(&PayloadKeyType) -> bool,
+) -> bool {
+    let FieldCondition {
+        key,
+        r#match,
+        range,
+        geo_bounding_box,
+        geo_radius,
+        geo_polygon,
+        values_count,
+        is_empty,
+        is_null,
+    } = field_condition;
+
+    let is_supported_match = match r#match {
+        None => true,
+        Some(Match::Value(_) | Match::Any(_) | Match::Except(_)) => true,
+        Some(Match::Text(_) | Match::TextAny(_) | Match::Phrase(_) | Match::Fuzzy(_)) => false,
+    };
+    let is_supported_range = match range {
+        None | Some(RangeInterface::Float(_)) => true,
+        Some(RangeInterface::DateTime(_)) => false,
+    };
+
+    is_pushable_field(key)
+        && (r#match.is_some() || range.is_some())
+        && is_supported_match
+        && is_supported_range
+        && geo_bounding_box.is_none()
+        && geo_radius.is_none()
+        && geo_polygon.is_none()
+        && values_count.is_none()
+        && is_empty.is_none()
+        && is_null.is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use ahash::AHashSet;
+
+    use super::*;
+    use crate::json_path::JsonPath;
+    use crate::types::{
+        GeoPoint, GeoRadius, HasIdCondition, IsEmptyCondition, PayloadField, Range,
+    };
+
+    fn is_pushable_field(key: &PayloadKeyType) -> bool {
+        key == &JsonPath::new("color") || key == &JsonPath::new("price")
+    }
+
+    fn match_color(color: &str) -> Condition {
+        Condition::Field(FieldCondition::new_match(
+            JsonPath::new("color"),
+            color.to_string().into(),
+        ))
+    }
+
+    fn price_range() -> Condition {
+        Condition::Field(FieldCondition::new_range(
+            JsonPath::new("price"),
+            Range {
+                lt: Some(100.0),
+                gt: None,
+                gte: Some(10.0),
+                lte: None,
+            },
+        ))
+    }
+
+    fn match_unindexed() -> Condition {
+        Condition::Field(FieldCondition::new_match(
+            JsonPath::new("size"),
+            "large".to_string().into(),
+        ))
+    }
+
+    fn near_origin() -> Condition {
+        Condition::Field(FieldCondition::new_geo_radius(
+            JsonPath::new("color"),
+            GeoRadius {
+                center: GeoPoint { lon: 0.0, lat: 0.0 },
+                radius: 1000.0,
+            },
+        ))
+    }
+
+    fn has_id() -> Condition {
+        Condition::HasId(HasIdCondition::from(AHashSet::from([1.into(), 2.into()])))
+    }
+
+    fn is_empty_color() -> Condition {
+        Condition::IsEmpty(IsEmptyCondition {
+            is_empty: PayloadField {
+                key: JsonPath::new("color"),
+            },
+        })
+    }
+
+    #[test]
+    fn test_fully_pushable_filter() {
+        let filter = Filter {
+            should: Some(vec![match_color("red"), match_color("blue")]),
+            min_should: None,
+            must: Some(vec![price_range()]),
+            must_not: Some(vec![match_color("green")]),
+        };
+        let split = VdeFilterSplit::new(&filter, &is_pushable_field);
+        assert_eq!(split.pushdown, Some(filter));
+        assert_eq!(split.residual, None);
+    }
+
+    #[test]
+    fn test_unsupported_filter() {
+        let filter = Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![match_unindexed(), near_origin(), is_empty_color()]),
+            must_not: Some(vec![has_id()]),
+        };
+        let split = VdeFilterSplit::new(&filter, &is_pushable_field);
+        assert_eq!(split.pushdown, None);
+        assert_eq!(split.residual, Some(filter));
+    }
+
+    #[test]
+    fn test_split_filter() {
+        let filter = Filter {
+            should: Some(vec![match_color("red"), near_origin()]),
+            min_should: None,
+            must: Some(vec![
+                price_range(),
+                match_unindexed(),
+                Condition::Filter(Filter {
+                    should: None,
+                    min_should: None,
+                    must: Some(vec![match_color("blue"), has_id()]),
+                    must_not: None,
+                }),
+            ]),
+            must_not: Some(vec![
+                match_color("green"),
+                Condition::Filter(Filter::new_must(is_empty_color())),
+            ]),
+        };
+
+        let split = VdeFilterSplit::new(&filter, &is_pushable_field);
+
+        let expected_pushdown = Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![
+                price_range(),
+                Condition::Filter(Filter::new_must(match_color("blue"))),
+            ]),
+            must_not: Some(vec![match_color("green")]),
+        };
+        let expected_residual = Filter {
+            should: Some(vec![match_color("red"), near_origin()]),
+            min_should: None,
+            must: Some(vec![
+                match_unindexed(),
+                Condition::Filter(Filter::new_must(has_id())),
+            ]),
+            must_not: Some(vec![Condition::Filter(Filter::new_must(is_empty_color()))]),
+        };
+        assert_eq!(split.pushdown, Some(expected_pushdown));
+        assert_eq!(split.residual, Some(expected_residual));
+    }
+
+    #[test]
+    fn test_min_should_pushed_as_whole() {
+        let pushable = Filter::new_min_should(MinShould {
+            conditions: vec![match_color("red"), price_range()],
+            min_count: 1,
+        });
+        let split = VdeFilterSplit::new(&pushable, &is_pushable_field);
+        assert_eq!(split.pushdown, Some(pushable));
+        assert_eq!(split.residual, None);
+
+        let not_pushable = Filter::new_min_should(MinShould {
+            conditions: vec![match_color("red"), match_unindexed()],
+            min_count: 1,
+        });
+        let split = VdeFilterSplit::new(&not_pushable, &is_pushable_field);
+        assert_eq!(split.pushdown, None);
+        assert_eq!(split.residual, Some(not_pushable));
+    }
+}
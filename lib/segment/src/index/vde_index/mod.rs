@@ -1,3 +1,5 @@
+pub mod filter_pushdown;
 pub mod vde_vector_index;
 
-pub use vde_vector_index::VDEVectorIndex;
+pub use filter_pushdown::VdeFilterSplit;
+pub use vde_vector_index::{VDEVectorIndex, VdeIndexOpenArgs};
@@ -1,155 +1,425 @@
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
+use atomic_refcell::AtomicRefCell;
+use bitvec::slice::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
-use vde_sys::*;
+use io::file_operations::{atomic_save_json, read_json};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 
-use crate::common::operation_error::{OperationError, OperationResult};
+use super::filter_pushdown::VdeFilterSplit;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use crate::common::{BYTES_IN_KB, Flusher};
+use crate::data_types::named_vectors::CowVector;
 use crate::data_types::query_context::VectorQueryContext;
-use crate::data_types::vectors::{QueryVector, VectorRef, VectorInternal};
-use crate::index::VectorIndex;
+use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
+use crate::id_tracker::IdTrackerSS;
+use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::{PayloadIndex, VectorIndex};
 use crate::telemetry::VectorIndexSearchesTelemetry;
-use crate::types::{Distance, Filter, SearchParams};
+use crate::types::{
+    DEFAULT_FULL_SCAN_THRESHOLD, Distance, Filter, Payload, PayloadKeyType, SearchParams,
+    VdeIndexConfig,
+};
+use crate::vde::{VdeBackend, VdeCollection, VdeCollectionConfig, VdeSearchParams, shared_engine};
+use crate::vector_storage::{Sequential, VectorStorage, VectorStorageEnum};
+
+/// Name of the VDE collection holding the index, within the vector index directory
+const VDE_INDEX_COLLECTION_NAME: &str = "vde_index";
+
+const VDE_INDEX_META_FILE: &str = "vde_index_meta.json";
+
+/// If the filter is expected to match at least this fraction of points, it is cheaper to check it
+/// on the found points than to make VDE evaluate it during the search
+const POST_FILTERING_MIN_SELECTIVITY: f64 = 0.5;
+
+/// State of the index kept next to the VDE collection
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct VdeIndexMeta {
+    /// Payload fields mirrored into the VDE collection
+    ///
+    /// Only indexed fields are mirrored: updating them moves a point out of a non-appendable
+    /// segment, so the mirrored values never get stale.
+    payload_fields: Vec<PayloadKeyType>,
+}
+
+impl VdeIndexMeta {
+    fn get_meta_path(path: &Path) -> PathBuf {
+        path.join(VDE_INDEX_META_FILE)
+    }
+
+    fn load(path: &Path) -> OperationResult<Self> {
+        let meta_path = Self::get_meta_path(path);
+        if !meta_path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(read_json(&meta_path)?)
+    }
+
+    fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_json(&Self::get_meta_path(path), self)?)
+    }
+
+    /// Serialize payload fields to mirror into the VDE collection
+    fn mirrored_payload(&self, payload: &Payload) -> OperationResult<Option<String>> {
+        if self.payload_fields.is_empty() {
+            return Ok(None);
+        }
+        let mirrored: serde_json::Map<_, _> = self
+            .payload_fields
+            .iter()
+            .filter_map(|field| {
+                let value = payload.0.get(&field.first_key)?;
+                Some((field.first_key.clone(), value.clone()))
+            })
+            .collect();
+        let json = serde_json::to_string(&mirrored).map_err(|err| {
+            OperationError::service_error(format!("Failed to serialize payload: {err}"))
+        })?;
+        Ok(Some(json))
+    }
+}
+
+#[derive(Debug)]
+struct VdeSearchesTelemetry {
+    unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+    small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    large_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl VdeSearchesTelemetry {
+    fn new() -> Self {
+        Self {
+            unfiltered: OperationDurationsAggregator::new(),
+            small_cardinality: OperationDurationsAggregator::new(),
+            large_cardinality: OperationDurationsAggregator::new(),
+            exact_filtered: OperationDurationsAggregator::new(),
+            exact_unfiltered: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+/// How a filtered search is executed
+enum FilteredSearchStrategy {
+    /// Score all points matching the filter, without VDE
+    Plain,
+    /// Search VDE without filter, check the whole filter on found points
+    PostFilter,
+    /// Let VDE evaluate a part of the filter, check the residual on found points
+    Pushdown(Box<VdeFilterSplit>),
+}
+
+pub struct VdeIndexOpenArgs<'a> {
+    pub path: &'a Path,
+    pub id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    pub dimension: usize,
+    pub distance: Distance,
+    pub config: VdeIndexConfig,
+}
 
 /// VDE-backed vector index implementation
 ///
-/// This wraps the VDE C++ engine and implements Qdrant's VectorIndex trait.
-/// VDE manages both the HNSW index (via vsag) and vector storage (via Btrieve2).
+/// This wraps a VDE collection and implements Qdrant's VectorIndex trait.
+/// VDE builds the index over a copy of the vectors kept in the segment vector storage, along with
+/// values of indexed payload fields. Filter conditions on these fields are pushed down to VDE,
+/// remaining conditions and deleted points are checked on the Qdrant side.
+///
+/// Only dense vectors are indexed in VDE. Multi-vectors have no approximate index: every search on
+/// them scores all points matching the filter exactly, with MaxSim over the vector storage. Sparse
+/// vectors are not supported.
 #[derive(Debug)]
 pub struct VDEVectorIndex {
-    /// VDE Collection handle (owned by VDE Engine)
-    collection: VDECollectionHandle,
-    
-    /// VDE Engine handle (shared ownership)
-    engine: Arc<RwLock<VDEEngineHandle>>,
-    
-    /// Collection name
-    name: String,
-    
-    /// Vector dimension
-    dimension: usize,
-    
-    /// Distance metric
-    #[allow(dead_code)]
-    distance: Distance,
-    
+    collection: Arc<dyn VdeCollection>,
+    id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    config: VdeIndexConfig,
+    meta: VdeIndexMeta,
+    searches_telemetry: VdeSearchesTelemetry,
+
     /// Base path for files
     path: PathBuf,
 }
 
 impl VDEVectorIndex {
-    /// Create a new VDE vector index
-    pub fn new(
-        path: &Path,
-        name: &str,
-        dimension: usize,
-        distance: Distance,
-        config_json: Option<&str>,
-    ) -> OperationResult<Self> {
-        unsafe {
-            // Create VDE engine
-            let work_dir = std::ffi::CString::new(path.to_str().unwrap())
-                .map_err(|e| OperationError::service_error(format!("Invalid path: {}", e)))?;
-            
-            let engine_handle = vde_engine_create(work_dir.as_ptr());
-            if engine_handle.is_null() {
-                return Err(OperationError::service_error("Failed to create VDE engine"));
-            }
-            
-            let engine = Arc::new(RwLock::new(engine_handle));
-            
-            // Prepare collection config
-            let collection_name = std::ffi::CString::new(name)
-                .map_err(|e| OperationError::service_error(format!("Invalid name: {}", e)))?;
-            
-            let index_type = std::ffi::CString::new("vsag_hnsw").unwrap();
-            let storage_type = std::ffi::CString::new("zendb").unwrap();
-            let distance_str = std::ffi::CString::new(match distance {
-                Distance::Cosine => "cosine",
-                Distance::Euclid => "euclidean",
-                Distance::Dot => "dot",
-                Distance::Manhattan => "manhattan",
-            }).unwrap();
-            
-            let config_json_cstr = config_json.map(|s| std::ffi::CString::new(s).unwrap());
-            
-            let config = VDECollectionConfig {
-                index_type: index_type.as_ptr(),
-                storage_type: storage_type.as_ptr(),
-                dimension: dimension as u32,
-                distance_metric: distance_str.as_ptr(),
-                config_json: config_json_cstr.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()),
-            };
-            
-            // Create collection
-            let collection = vde_collection_create(engine_handle, collection_name.as_ptr(), &config);
-            if collection.is_null() {
-                vde_engine_destroy(engine_handle);
-                return Err(OperationError::service_error("Failed to create VDE collection"));
-            }
-            
-            Ok(Self {
-                collection,
-                engine,
-                name: name.to_string(),
-                dimension,
-                distance,
-                path: path.to_path_buf(),
-            })
-        }
+    /// Open the VDE index of a segment, an empty index is created if there is none yet
+    pub fn open(args: VdeIndexOpenArgs) -> OperationResult<Self> {
+        let collection_config = Self::collection_config(&args);
+        let engine = shared_engine(VdeBackend::default(), args.path)?;
+        let collection =
+            engine.open_or_create_collection(VDE_INDEX_COLLECTION_NAME, &collection_config)?;
+        let meta = VdeIndexMeta::load(args.path)?;
+        Ok(Self::new(args, collection, meta))
     }
-    
-    /// Open an existing VDE collection
-    pub fn open(
-        path: &Path,
-        name: &str,
-        dimension: usize,
-        distance: Distance,
-    ) -> OperationResult<Self> {
-        unsafe {
-            let work_dir = std::ffi::CString::new(path.to_str().unwrap())
-                .map_err(|e| OperationError::service_error(format!("Invalid path: {}", e)))?;
-            
-            let engine_handle = vde_engine_create(work_dir.as_ptr());
-            if engine_handle.is_null() {
-                return Err(OperationError::service_error("Failed to create VDE engine"));
-            }
-            
-            let engine = Arc::new(RwLock::new(engine_handle));
-            
-            let collection_name = std::ffi::CString::new(name)
-                .map_err(|e| OperationError::service_error(format!("Invalid name: {}", e)))?;
-            
-            let collection = vde_collection_open(engine_handle, collection_name.as_ptr());
-            if collection.is_null() {
-                vde_engine_destroy(engine_handle);
-                return Err(OperationError::service_error("Failed to open VDE collection"));
+
+    /// Build a new VDE index over all available vectors in the vector storage
+    pub fn build(args: VdeIndexOpenArgs, stopped: &AtomicBool) -> OperationResult<Self> {
+        let collection_config = Self::collection_config(&args);
+        let engine = shared_engine(VdeBackend::default(), args.path)?;
+        let collection = engine.create_collection(VDE_INDEX_COLLECTION_NAME, &collection_config)?;
+
+        let hw_counter = HardwareCounterCell::disposable();
+        let meta = {
+            let id_tracker = args.id_tracker.borrow();
+            let vector_storage = args.vector_storage.borrow();
+            let payload_index = args.payload_index.borrow();
+
+            let mut payload_fields: Vec<_> = payload_index
+                .indexed_fields()
+                .into_keys()
+                .filter(|field| field.rest.is_empty())
+                .collect();
+            payload_fields.sort();
+            let meta = VdeIndexMeta { payload_fields };
+
+            // Multi-vectors are scored by Qdrant, there is nothing to index in VDE
+            let is_multi_vector = vector_storage.try_multi_vector_config().is_some();
+
+            if !is_multi_vector {
+                for id in id_tracker.iter_ids_excluding(vector_storage.deleted_vector_bitslice()) {
+                    check_process_stopped(stopped)?;
+                    let CowVector::Dense(vector) = vector_storage.get_vector::<Sequential>(id)
+                    else {
+                        return Err(OperationError::service_error(
+                            "VDE only supports dense vectors",
+                        ));
+                    };
+                    let payload = if meta.payload_fields.is_empty() {
+                        None
+                    } else {
+                        let payload = payload_index.get_payload_sequential(id, &hw_counter)?;
+                        meta.mirrored_payload(&payload)?
+                    };
+                    collection.upsert(id, Some(vector.as_ref()), payload.as_deref())?;
+                }
             }
-            
-            Ok(Self {
-                collection,
-                engine,
-                name: name.to_string(),
-                dimension,
-                distance,
-                path: path.to_path_buf(),
-            })
+            meta
+        };
+        collection.save_snapshot()?;
+        meta.save(args.path)?;
+
+        Ok(Self::new(args, collection, meta))
+    }
+
+    fn new(args: VdeIndexOpenArgs, collection: Arc<dyn VdeCollection>, meta: VdeIndexMeta) -> Self {
+        let VdeIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            payload_index,
+            dimension: _,
+            distance: _,
+            config,
+        } = args;
+        Self {
+            collection,
+            id_tracker,
+            vector_storage,
+            payload_index,
+            config,
+            meta,
+            searches_telemetry: VdeSearchesTelemetry::new(),
+            path: path.to_path_buf(),
         }
     }
-    
+
+    fn collection_config(args: &VdeIndexOpenArgs) -> VdeCollectionConfig {
+        VdeCollectionConfig::new(args.dimension, args.distance).with_index_config(&args.config)
+    }
+
+    pub fn config(&self) -> &VdeIndexConfig {
+        &self.config
+    }
+
     /// Save index snapshot
     pub fn save(&self) -> OperationResult<()> {
-        unsafe {
-            let ret = vde_save_snapshot(self.collection);
-            if ret != 0 {
-                return Err(OperationError::service_error(format!("Failed to save VDE snapshot: {}", ret)));
+        self.collection.save_snapshot()?;
+        Ok(())
+    }
+
+    /// Persist points updated since the last save, so that [`VectorIndex::files`] can be copied
+    pub fn flusher(&self) -> Flusher {
+        let collection = self.collection.clone();
+        Box::new(move || Ok(collection.save_snapshot()?))
+    }
+
+    /// Search `top` points accepted by `check` for each query, among points matching the pushed
+    /// down `filter`
+    ///
+    /// VDE doesn't know which points are deleted or match the residual filter, so the search is
+    /// repeated with a larger limit until enough points are accepted or the index is exhausted.
+    /// The limit of a repeated search is extrapolated from the share of accepted points, so a
+    /// single retry is usually enough.
+    ///
+    /// Every query is a separate VDE call, the VDE C API has no batch search.
+    fn search_checked(
+        &self,
+        queries: &[&[f32]],
+        top: usize,
+        params: &VdeSearchParams,
+        filter: Option<&str>,
+        check: impl Fn(PointOffsetType) -> bool,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let mut results = vec![Vec::new(); queries.len()];
+        if top == 0 {
+            return Ok(results);
+        }
+
+        let is_stopped = query_context.is_stopped();
+        let mut hw_counter = query_context.hardware_counter();
+        hw_counter.set_cpu_multiplier(self.collection.dimension() * size_of::<f32>());
+
+        let mut pending: Vec<usize> = (0..queries.len()).collect();
+        let mut limit = top;
+        while !pending.is_empty() {
+            check_process_stopped(&is_stopped)?;
+
+            let mut still_pending = Vec::new();
+            let mut min_accepted = top;
+            for idx in pending {
+                let found = self
+                    .collection
+                    .search(queries[idx], limit, params, filter)?;
+                hw_counter.cpu_counter().incr_delta(found.len());
+
+                let is_exhausted = found.len() < limit;
+                let accepted: Vec<_> = found
+                    .into_iter()
+                    .filter(|point| check(point.idx))
+                    .take(top)
+                    .collect();
+                if accepted.len() < top && !is_exhausted {
+                    still_pending.push(idx);
+                    min_accepted = min_accepted.min(accepted.len());
+                }
+                results[idx] = accepted;
             }
-            Ok(())
+            pending = still_pending;
+            limit = next_search_limit(limit, top, min_accepted);
         }
+
+        Ok(results)
+    }
+
+    /// Score all given points with the vectors of the segment vector storage
+    fn search_plain(
+        &self,
+        vector: &QueryVector,
+        points: &[PointOffsetType],
+        top: usize,
+        deleted_points: &BitSlice,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let vector_storage = self.vector_storage.borrow();
+        let scorer = FilteredScorer::new(
+            vector.to_owned(),
+            &vector_storage,
+            None,
+            None,
+            deleted_points,
+            query_context.hardware_counter(),
+        )?;
+        let is_stopped = query_context.is_stopped();
+        Ok(scorer.peek_top_iter(points.iter().copied(), top, &is_stopped)?)
+    }
+
+    /// Full scan threshold in number of vectors
+    fn full_scan_threshold(&self, vector_storage: &VectorStorageEnum) -> usize {
+        let full_scan_threshold_kb = self
+            .config
+            .full_scan_threshold
+            .unwrap_or(DEFAULT_FULL_SCAN_THRESHOLD);
+        vector_storage
+            .size_of_available_vectors_in_bytes()
+            .checked_div(vector_storage.available_vector_count())
+            .and_then(|avg_vector_size| {
+                full_scan_threshold_kb
+                    .saturating_mul(BYTES_IN_KB)
+                    .checked_div(avg_vector_size)
+            })
+            .unwrap_or(1)
+    }
+
+    /// Whether conditions on the payload `field` can be evaluated by VDE
+    fn is_pushable_field(&self, field: &PayloadKeyType, indexed_fields: &[PayloadKeyType]) -> bool {
+        // A field index could be dropped and recreated since the index was built, payload
+        // updates in between would not be mirrored
+        self.meta.payload_fields.contains(field) && indexed_fields.contains(field)
+    }
+
+    /// Whether vectors are multi-vectors, which are not indexed in VDE
+    fn is_multi_vector(&self) -> bool {
+        self.vector_storage
+            .borrow()
+            .try_multi_vector_config()
+            .is_some()
+    }
+
+    /// Choose how to execute a search with the given filter
+    ///
+    /// Like for HNSW, small cardinality filters are served by scoring the filtered points
+    /// directly. Otherwise VDE is queried, either evaluating the supported part of the filter
+    /// itself or, for weakly selective filters, with the filter checked on found points only.
+    fn filtered_search_strategy(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> FilteredSearchStrategy {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let payload_index = self.payload_index.borrow();
+
+        let available_vector_count = vector_storage.available_vector_count();
+        let cardinality = adjust_to_available_vectors(
+            payload_index.estimate_cardinality(filter, hw_counter),
+            available_vector_count,
+            id_tracker.available_point_count(),
+        );
+
+        if cardinality.max < self.full_scan_threshold(&vector_storage) {
+            return FilteredSearchStrategy::Plain;
+        }
+
+        let selectivity = cardinality.exp as f64 / available_vector_count.max(1) as f64;
+        if selectivity >= POST_FILTERING_MIN_SELECTIVITY {
+            return FilteredSearchStrategy::PostFilter;
+        }
+
+        let indexed_fields: Vec<_> = payload_index.indexed_fields().into_keys().collect();
+        let split = VdeFilterSplit::new(filter, &|field| {
+            self.is_pushable_field(field, &indexed_fields)
+        });
+        if split.pushdown.is_none() {
+            return FilteredSearchStrategy::PostFilter;
+        }
+        FilteredSearchStrategy::Pushdown(Box::new(split))
     }
 }
 
+/// Limit for repeating a search, which accepted only `accepted` out of `limit` found points
+///
+/// Assumes the share of accepted points stays the same, grows the limit at least twice.
+fn next_search_limit(limit: usize, top: usize, accepted: usize) -> usize {
+    let min_limit = limit.saturating_mul(2);
+    if accepted == 0 {
+        return min_limit.saturating_mul(2);
+    }
+    limit.saturating_mul(top).div_ceil(accepted).max(min_limit)
+}
+
 impl VectorIndex for VDEVectorIndex {
     fn search(
         &self,
@@ -157,178 +427,201 @@ impl VectorIndex for VDEVectorIndex {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
-        _query_context: &VectorQueryContext,
+        query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
-        let mut all_results = Vec::with_capacity(vectors.len());
-        
-        for query_vector in vectors {
-            // Extract dense vector
-            let dense = match query_vector {
-                QueryVector::Nearest(VectorInternal::Dense(v)) => v.as_slice(),
-                _ => return Err(OperationError::service_error("VDE only supports dense vectors")),
-            };
-            
-            if dense.len() != self.dimension {
-                return Err(OperationError::service_error(
-                    format!("Vector dimension mismatch: expected {}, got {}", self.dimension, dense.len())
-                ));
-            }
-            
-            unsafe {
-                let vde_query = VDEVector {
-                    data: dense.as_ptr() as *mut f32,
-                    dim: dense.len() as u32,
-                };
-                
-                // Convert SearchParams to VDESearchParams
-                let vde_params = params.map(|p| {
-                    let ef = p.hnsw_ef.unwrap_or(0) as u32;
-                    eprintln!("[VDE-INDEX] SearchParams: hnsw_ef={}, exact={}", ef, p.exact);
-                    VDESearchParams {
-                        hnsw_ef: ef,
-                        exact: if p.exact { 1 } else { 0 },
-                    }
-                });
-                
-                let mut results = vec![VDESearchResult { offset: 0, score: 0.0 }; top];
-                let mut result_count: u32 = 0;
-                
-                let ret = if let Some(filter) = filter {
-                    // Convert filter to JSON
-                    let filter_json = serde_json::to_string(filter)
-                        .map_err(|e| OperationError::service_error(format!("Failed to serialize filter: {}", e)))?;
-                    let filter_cstr = std::ffi::CString::new(filter_json).unwrap();
-                    
-                    vde_search_filtered_with_params(
-                        self.collection,
-                        &vde_query,
-                        top as u32,
-                        if vde_params.is_some() { &vde_params.unwrap() as *const VDESearchParams } else { std::ptr::null() },
-                        filter_cstr.as_ptr(),
-                        results.as_mut_ptr(),
-                        &mut result_count,
-                    )
-                } else {
-                    vde_search_with_params(
-                        self.collection,
-                        &vde_query,
-                        top as u32,
-                        if vde_params.is_some() { &vde_params.unwrap() as *const VDESearchParams } else { std::ptr::null() },
-                        results.as_mut_ptr(),
-                        &mut result_count,
-                    )
+        // VDE only serves nearest dense queries, recommend, discovery and context queries and
+        // multi-vectors are scored by Qdrant over the vectors in the storage
+        let dense_vectors = if self.is_multi_vector() {
+            None
+        } else {
+            vectors
+                .iter()
+                .map(|query_vector| match query_vector {
+                    QueryVector::Nearest(VectorInternal::Dense(dense)) => Some(dense.as_slice()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+        };
+
+        let vde_params = params.map(VdeSearchParams::from).unwrap_or_default();
+        let exact = params.is_some_and(|params| params.exact) || dense_vectors.is_none();
+        let hw_counter = query_context.hardware_counter();
+
+        let strategy = match filter {
+            _ if exact => FilteredSearchStrategy::Plain,
+            Some(filter) => self.filtered_search_strategy(filter, &hw_counter),
+            None => FilteredSearchStrategy::PostFilter,
+        };
+
+        let telemetry = &self.searches_telemetry;
+        let _timer = ScopeDurationMeasurer::new(match (filter, &strategy) {
+            (None, _) if exact => &telemetry.exact_unfiltered,
+            (Some(_), _) if exact => &telemetry.exact_filtered,
+            (None, _) => &telemetry.unfiltered,
+            (Some(_), FilteredSearchStrategy::Plain) => &telemetry.small_cardinality,
+            (Some(_), _) => &telemetry.large_cardinality,
+        });
+
+        let id_tracker = self.id_tracker.borrow();
+        let payload_index = self.payload_index.borrow();
+        let deleted_points = query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+
+        let (pushdown, residual) = match strategy {
+            FilteredSearchStrategy::Plain => {
+                let points = match filter {
+                    Some(filter) => payload_index.query_points(filter, &hw_counter),
+                    None => id_tracker.iter_internal().collect(),
                 };
-                
-                if ret != 0 {
-                    return Err(OperationError::service_error(format!("VDE search failed: {}", ret)));
-                }
-                
-                // Convert to Qdrant format
-                let scored_points: Vec<ScoredPointOffset> = results[..result_count as usize]
+                return vectors
                     .iter()
-                    .map(|r| ScoredPointOffset {
-                        idx: r.offset as PointOffsetType,
-                        score: r.score,
+                    .map(|vector| {
+                        self.search_plain(vector, &points, top, deleted_points, query_context)
                     })
                     .collect();
-                
-                all_results.push(scored_points);
             }
-        }
-        
-        Ok(all_results)
+            FilteredSearchStrategy::PostFilter => (None, filter.cloned()),
+            FilteredSearchStrategy::Pushdown(split) => {
+                let VdeFilterSplit { pushdown, residual } = *split;
+                (pushdown, residual)
+            }
+        };
+
+        let pushdown_json = pushdown
+            .map(|pushdown| serde_json::to_string(&pushdown))
+            .transpose()
+            .map_err(|err| {
+                OperationError::service_error(format!("Failed to serialize VDE filter: {err}"))
+            })?;
+        let residual_context = residual
+            .as_ref()
+            .map(|residual| payload_index.filter_context(residual, &hw_counter));
+
+        let check = |id: PointOffsetType| {
+            let is_deleted = deleted_points
+                .get(id as usize)
+                .is_some_and(|deleted| *deleted);
+            !is_deleted
+                && residual_context
+                    .as_ref()
+                    .is_none_or(|residual_context| residual_context.check(id))
+        };
+
+        self.search_checked(
+            &dense_vectors.unwrap_or_default(),
+            top,
+            &vde_params,
+            pushdown_json.as_deref(),
+            check,
+            query_context,
+        )
     }
-    
-    fn get_telemetry_data(&self, _detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
         VectorIndexSearchesTelemetry {
-            index_name: Some("vde_hnsw".to_string()),
+            index_name: None,
             unfiltered_plain: Default::default(),
             filtered_plain: Default::default(),
-            unfiltered_hnsw: Default::default(),
-            filtered_small_cardinality: Default::default(),
-            filtered_large_cardinality: Default::default(),
-            filtered_exact: Default::default(),
+            unfiltered_hnsw: tm.unfiltered.lock().get_statistics(detail),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
+            filtered_two_hop: Default::default(),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
             filtered_sparse: Default::default(),
             unfiltered_sparse: Default::default(),
-            unfiltered_exact: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
         }
     }
-    
+
     fn files(&self) -> Vec<PathBuf> {
-        vec![
-            self.path.join(format!("{}.vde", self.name)),
-        ]
+        let mut files = self.collection.files().unwrap_or_else(|err| {
+            log::error!(
+                "Failed to list files of VDE collection {}: {err}",
+                self.collection.name(),
+            );
+            Vec::new()
+        });
+        let meta_path = VdeIndexMeta::get_meta_path(&self.path);
+        if meta_path.exists() {
+            files.push(meta_path);
+        }
+        files.sort();
+        files.dedup();
+        files
     }
-    
+
     fn indexed_vector_count(&self) -> usize {
-        unsafe {
-            vde_get_vector_count(self.collection) as usize
+        if self.is_multi_vector() {
+            // Multi-vectors are searchable without being indexed in VDE
+            return self.vector_storage.borrow().available_vector_count();
         }
+        self.collection.vector_count()
     }
-    
+
     fn size_of_searchable_vectors_in_bytes(&self) -> usize {
         // Estimate: num_vectors * dimension * sizeof(f32) + HNSW overhead
+        if self.is_multi_vector() {
+            return self
+                .vector_storage
+                .borrow()
+                .size_of_available_vectors_in_bytes();
+        }
         let vector_count = self.indexed_vector_count();
-        vector_count * self.dimension * 4 * 2 // 2x for HNSW graph overhead
+        vector_count * self.collection.dimension() * size_of::<f32>() * 2 // 2x for HNSW graph overhead
     }
-    
+
     fn update_vector(
         &mut self,
         id: PointOffsetType,
         vector: Option<VectorRef>,
-        _hw_counter: &HardwareCounterCell,
+        hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        unsafe {
-            if let Some(vec_ref) = vector {
-                // Insert/update vector
-                let dense = match vec_ref {
-                    VectorRef::Dense(v) => v,
-                    _ => return Err(OperationError::service_error("VDE only supports dense vectors")),
-                };
-                
-                let vde_vector = VDEVector {
-                    data: dense.as_ptr() as *mut f32,
-                    dim: dense.len() as u32,
-                };
-                
-                let ret = vde_upsert_vector(
-                    self.collection,
-                    id as u64,
-                    &vde_vector,
-                    std::ptr::null(), // payload managed separately
-                );
-                
-                if ret != 0 {
-                    return Err(OperationError::service_error(format!("Failed to upsert vector: {}", ret)));
-                }
-            } else {
-                // Delete vector
-                let ret = vde_delete_vector(self.collection, id as u64);
-                if ret != 0 {
-                    return Err(OperationError::service_error(format!("Failed to delete vector: {}", ret)));
+        let mut vector_storage = self.vector_storage.borrow_mut();
+
+        match vector {
+            Some(VectorRef::Dense(dense)) => {
+                vector_storage.insert_vector(id, VectorRef::Dense(dense), hw_counter)?;
+                self.collection.upsert(id, Some(dense), None)?;
+                hw_counter
+                    .vector_io_write_counter()
+                    .incr_delta(size_of_val(dense));
+            }
+            Some(VectorRef::MultiDense(multi)) => {
+                vector_storage.insert_vector(id, VectorRef::MultiDense(multi), hw_counter)?;
+            }
+            Some(VectorRef::Sparse(_)) => {
+                return Err(OperationError::service_error(
+                    "VDE only supports dense and multi-dense vectors",
+                ));
+            }
+            None => {
+                if id as usize >= vector_storage.total_vector_count() {
+                    // Vector doesn't exist in the storage
+                    // Insert default vector to keep the sequence
+                    let default_vector = vector_storage.default_vector();
+                    vector_storage.insert_vector(
+                        id,
+                        VectorRef::from(&default_vector),
+                        hw_counter,
+                    )?;
                 }
+                vector_storage.delete_vector(id)?;
+                self.collection.delete(id)?;
             }
-            
-            Ok(())
         }
+        Ok(())
     }
 }
 
 impl Drop for VDEVectorIndex {
     fn drop(&mut self) {
-        // Save snapshot before dropping
-        let _ = self.save();
-        
-        // Engine will be destroyed when Arc refcount reaches 0
-        if let Ok(engine) = self.engine.read() {
-            unsafe {
-                if !(*engine).is_null() {
-                    vde_engine_destroy(*engine);
-                }
-            }
+        if let Err(err) = self.save() {
+            log::error!(
+                "Failed to save VDE snapshot of {}: {err}",
+                self.collection.name(),
+            );
         }
     }
 }
-
-unsafe impl Send for VDEVectorIndex {}
-unsafe impl Sync for VDEVectorIndex {}
@@ -12,10 +12,14 @@ use sparse::index::inverted_index::inverted_index_immutable_ram::InvertedIndexIm
 use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 
+use super::diskann_index::DiskAnnIndex;
 use super::hnsw_index::hnsw::HNSWIndex;
+use super::ivf_index::IvfIndex;
 use super::plain_vector_index::PlainVectorIndex;
 use super::sparse_index::sparse_vector_index::SparseVectorIndex;
+#[cfg(feature = "vde")]
 use super::vde_index::VDEVectorIndex;
+use crate::common::Flusher;
 use crate::common::operation_error::OperationResult;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
@@ -69,6 +73,9 @@ pub trait VectorIndex {
 pub enum VectorIndexEnum {
     Plain(PlainVectorIndex),
     Hnsw(HNSWIndex),
+    DiskAnn(DiskAnnIndex),
+    Ivf(IvfIndex),
+    #[cfg(feature = "vde")]
     Vde(VDEVectorIndex),
     SparseRam(SparseVectorIndex<InvertedIndexRam>),
     SparseImmutableRam(SparseVectorIndex<InvertedIndexImmutableRam>),
@@ -88,6 +95,9 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => false,
             Self::Hnsw(_) => true,
+            Self::DiskAnn(_) => true,
+            Self::Ivf(_) => true,
+            #[cfg(feature = "vde")]
             Self::Vde(_) => true,
             Self::SparseRam(_) => true,
             Self::SparseImmutableRam(_) => true,
@@ -107,6 +117,9 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => false,
             Self::Hnsw(index) => index.is_on_disk(),
+            Self::DiskAnn(index) => index.is_on_disk(),
+            Self::Ivf(index) => index.is_on_disk(),
+            #[cfg(feature = "vde")]
             Self::Vde(_) => true,
             Self::SparseRam(index) => index.inverted_index().is_on_disk(),
             Self::SparseImmutableRam(index) => index.inverted_index().is_on_disk(),
@@ -124,6 +137,9 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => {}
             Self::Hnsw(index) => index.populate()?,
+            Self::DiskAnn(index) => index.populate()?,
+            Self::Ivf(index) => index.populate()?,
+            #[cfg(feature = "vde")]
             Self::Vde(_) => {}
             Self::SparseRam(_) => {}
             Self::SparseImmutableRam(_) => {}
@@ -142,6 +158,9 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => {}
             Self::Hnsw(index) => index.clear_cache()?,
+            Self::DiskAnn(index) => index.clear_cache()?,
+            Self::Ivf(index) => index.clear_cache()?,
+            #[cfg(feature = "vde")]
             Self::Vde(_) => {}
             Self::SparseRam(_) => {}
             Self::SparseImmutableRam(_) => {}
@@ -156,13 +175,36 @@ impl VectorIndexEnum {
         Ok(())
     }
 
+    /// Flusher persisting updates of a mutable index, `None` if the index needs no flushing
+    pub fn flusher(&self) -> Option<Flusher> {
+        match self {
+            Self::Plain(_) => None,
+            Self::Hnsw(_) => None,
+            Self::DiskAnn(_) => None,
+            Self::Ivf(_) => None,
+            #[cfg(feature = "vde")]
+            Self::Vde(index) => Some(index.flusher()),
+            Self::SparseRam(_) => None,
+            Self::SparseImmutableRam(_) => None,
+            Self::SparseMmap(_) => None,
+            Self::SparseCompressedImmutableRamF32(_) => None,
+            Self::SparseCompressedImmutableRamF16(_) => None,
+            Self::SparseCompressedImmutableRamU8(_) => None,
+            Self::SparseCompressedMmapF32(_) => None,
+            Self::SparseCompressedMmapF16(_) => None,
+            Self::SparseCompressedMmapU8(_) => None,
+        }
+    }
+
     pub fn fill_idf_statistics(
         &self,
         idf: &mut HashMap<DimId, usize>,
         hw_counter: &HardwareCounterCell,
     ) {
         match self {
-            Self::Plain(_) | Self::Hnsw(_) | Self::Vde(_) => (),
+            Self::Plain(_) | Self::Hnsw(_) | Self::DiskAnn(_) | Self::Ivf(_) => (),
+            #[cfg(feature = "vde")]
+            Self::Vde(_) => (),
             Self::SparseRam(index) => index.fill_idf_statistics(idf, hw_counter),
             Self::SparseImmutableRam(index) => index.fill_idf_statistics(idf, hw_counter),
             Self::SparseMmap(index) => index.fill_idf_statistics(idf, hw_counter),
@@ -185,6 +227,9 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(index) => index.indexed_vector_count(),
             Self::Hnsw(index) => index.indexed_vector_count(),
+            Self::DiskAnn(index) => index.indexed_vector_count(),
+            Self::Ivf(index) => index.indexed_vector_count(),
+            #[cfg(feature = "vde")]
             Self::Vde(index) => index.indexed_vector_count(),
             Self::SparseRam(index) => index.inverted_index().vector_count(),
             Self::SparseImmutableRam(index) => index.inverted_index().vector_count(),
@@ -197,6 +242,36 @@ impl VectorIndexEnum {
             Self::SparseCompressedMmapU8(index) => index.inverted_index().vector_count(),
         }
     }
+
+    /// Total length of indexed sparse vectors, used for BM25 scoring
+    pub fn total_document_length(&self) -> f64 {
+        match self {
+            Self::Plain(_) | Self::Hnsw(_) | Self::DiskAnn(_) | Self::Ivf(_) => 0.0,
+            #[cfg(feature = "vde")]
+            Self::Vde(_) => 0.0,
+            Self::SparseRam(index) => index.inverted_index().document_lengths().total(),
+            Self::SparseImmutableRam(index) => index.inverted_index().document_lengths().total(),
+            Self::SparseMmap(index) => index.inverted_index().document_lengths().total(),
+            Self::SparseCompressedImmutableRamF32(index) => {
+                index.inverted_index().document_lengths().total()
+            }
+            Self::SparseCompressedImmutableRamF16(index) => {
+                index.inverted_index().document_lengths().total()
+            }
+            Self::SparseCompressedImmutableRamU8(index) => {
+                index.inverted_index().document_lengths().total()
+            }
+            Self::SparseCompressedMmapF32(index) => {
+                index.inverted_index().document_lengths().total()
+            }
+            Self::SparseCompressedMmapF16(index) => {
+                index.inverted_index().document_lengths().total()
+            }
+            Self::SparseCompressedMmapU8(index) => {
+                index.inverted_index().document_lengths().total()
+            }
+        }
+    }
 }
 
 impl VectorIndex for VectorIndexEnum {
@@ -215,6 +290,13 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::Hnsw(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
+            VectorIndexEnum::DiskAnn(index) => {
+                index.search(vectors, filter, top, params, query_context)
+            }
+            VectorIndexEnum::Ivf(index) => {
+                index.search(vectors, filter, top, params, query_context)
+            }
+            #[cfg(feature = "vde")]
             VectorIndexEnum::Vde(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
@@ -252,6 +334,9 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::Hnsw(index) => index.get_telemetry_data(detail),
+            VectorIndexEnum::DiskAnn(index) => index.get_telemetry_data(detail),
+            VectorIndexEnum::Ivf(index) => index.get_telemetry_data(detail),
+            #[cfg(feature = "vde")]
             VectorIndexEnum::Vde(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseRam(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseImmutableRam(index) => index.get_telemetry_data(detail),
@@ -275,6 +360,9 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(index) => index.files(),
             VectorIndexEnum::Hnsw(index) => index.files(),
+            VectorIndexEnum::DiskAnn(index) => index.files(),
+            VectorIndexEnum::Ivf(index) => index.files(),
+            #[cfg(feature = "vde")]
             VectorIndexEnum::Vde(index) => index.files(),
             VectorIndexEnum::SparseRam(index) => index.files(),
             VectorIndexEnum::SparseImmutableRam(index) => index.files(),
@@ -292,6 +380,9 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(index) => index.immutable_files(),
             VectorIndexEnum::Hnsw(index) => index.immutable_files(),
+            VectorIndexEnum::DiskAnn(index) => index.immutable_files(),
+            VectorIndexEnum::Ivf(index) => index.immutable_files(),
+            #[cfg(feature = "vde")]
             VectorIndexEnum::Vde(index) => index.immutable_files(),
             VectorIndexEnum::SparseRam(index) => index.immutable_files(),
             VectorIndexEnum::SparseImmutableRam(index) => index.immutable_files(),
@@ -309,6 +400,9 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.indexed_vector_count(),
             Self::Hnsw(index) => index.indexed_vector_count(),
+            Self::DiskAnn(index) => index.indexed_vector_count(),
+            Self::Ivf(index) => index.indexed_vector_count(),
+            #[cfg(feature = "vde")]
             Self::Vde(index) => index.indexed_vector_count(),
             Self::SparseRam(index) => index.indexed_vector_count(),
             Self::SparseImmutableRam(index) => index.indexed_vector_count(),
@@ -326,6 +420,9 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::Hnsw(index) => index.size_of_searchable_vectors_in_bytes(),
+            Self::DiskAnn(index) => index.size_of_searchable_vectors_in_bytes(),
+            Self::Ivf(index) => index.size_of_searchable_vectors_in_bytes(),
+            #[cfg(feature = "vde")]
             Self::Vde(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::SparseRam(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::SparseImmutableRam(index) => index.size_of_searchable_vectors_in_bytes(),
@@ -354,6 +451,9 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.update_vector(id, vector, hw_counter),
             Self::Hnsw(index) => index.update_vector(id, vector, hw_counter),
+            Self::DiskAnn(index) => index.update_vector(id, vector, hw_counter),
+            Self::Ivf(index) => index.update_vector(id, vector, hw_counter),
+            #[cfg(feature = "vde")]
             Self::Vde(index) => index.update_vector(id, vector, hw_counter),
             Self::SparseRam(index) => index.update_vector(id, vector, hw_counter),
             Self::SparseImmutableRam(index) => index.update_vector(id, vector, hw_counter),
@@ -1,6 +1,7 @@
 use bitvec::slice::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::ScoredPointOffset;
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use common::types::{ScoreType, ScoredPointOffset};
 use itertools::Itertools;
 
 use crate::common::operation_error::OperationResult;
@@ -24,6 +25,10 @@ pub fn is_quantized_search(
     quantized_storage.is_some() && !ignore_quantization && !exact
 }
 
+/// Candidates pool size factor for quantizations with error bounds, if oversampling is not set.
+/// Candidates are cheap to collect, error bounds define how many of them are actually rescored.
+pub const ERROR_BOUNDED_OVERSAMPLING: f64 = 4.0;
+
 pub fn get_oversampled_top(
     quantized_storage: Option<&QuantizedVectors>,
     params: Option<&SearchParams>,
@@ -31,10 +36,15 @@ pub fn get_oversampled_top(
 ) -> usize {
     let quantization_enabled = is_quantized_search(quantized_storage, params);
 
+    let default_oversampling = if quantized_storage.is_some_and(|q| q.has_error_bounds()) {
+        Some(ERROR_BOUNDED_OVERSAMPLING)
+    } else {
+        default_quantization_oversampling_value()
+    };
     let oversampling_value = params
         .and_then(|p| p.quantization)
-        .map(|q| q.oversampling)
-        .unwrap_or(default_quantization_oversampling_value());
+        .and_then(|q| q.oversampling)
+        .or(default_oversampling);
 
     match oversampling_value {
         Some(oversampling) if quantization_enabled && oversampling > 1.0 => {
@@ -67,21 +77,107 @@ pub fn postprocess_search_result(
             .and_then(|q| q.rescore)
             .unwrap_or(default_rescoring);
     if rescore {
-        let mut scorer = FilteredScorer::new(
-            vector.to_owned(),
-            vector_storage,
-            None,
-            None,
-            point_deleted,
-            hardware_counter,
-        )?;
-
-        search_result = scorer
-            .score_points(&mut search_result.iter().map(|x| x.idx).collect_vec(), 0)
-            .collect();
-        search_result.sort_unstable();
-        search_result.reverse();
+        let error_bounds = quantized_vectors.and_then(|q| {
+            let points = search_result.iter().map(|x| x.idx).collect_vec();
+            q.score_points_with_error_bounds(vector, &points, &hardware_counter)
+        });
+        search_result = match error_bounds {
+            Some(error_bounds) => rescore_search_result_with_error_bounds(
+                &search_result,
+                &error_bounds,
+                point_deleted,
+                vector_storage,
+                vector,
+                top,
+                hardware_counter,
+            )?,
+            None => rescore_search_result(
+                &search_result,
+                point_deleted,
+                vector_storage,
+                vector,
+                hardware_counter,
+            )?,
+        };
     }
     search_result.truncate(top);
     Ok(search_result)
 }
+
+/// Score found points again with the original vectors, best scores first.
+///
+/// Candidates are rescored in the order of their score upper bounds,
+/// until no remaining candidate can get into the `top`.
+pub fn rescore_search_result_with_error_bounds(
+    search_result: &[ScoredPointOffset],
+    error_bounds: &[(ScoreType, ScoreType)],
+    point_deleted: &BitSlice,
+    vector_storage: &VectorStorageEnum,
+    vector: &QueryVector,
+    top: usize,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Vec<ScoredPointOffset>> {
+    debug_assert_eq!(search_result.len(), error_bounds.len());
+    if top == 0 {
+        return Ok(vec![]);
+    }
+
+    let scorer = FilteredScorer::new(
+        vector.to_owned(),
+        vector_storage,
+        None,
+        None,
+        point_deleted,
+        hardware_counter,
+    )?;
+
+    let candidates = search_result
+        .iter()
+        .zip(error_bounds)
+        .map(|(point, (score, error))| (point.idx, score + error))
+        .sorted_unstable_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    let mut rescored = FixedLengthPriorityQueue::<ScoredPointOffset>::new(top);
+    for (idx, upper_bound) in candidates {
+        if rescored.is_full()
+            && rescored
+                .top()
+                .is_some_and(|worst| upper_bound < worst.score)
+        {
+            break;
+        }
+        if !scorer.filters().check_vector(idx) {
+            continue;
+        }
+        rescored.push(ScoredPointOffset {
+            idx,
+            score: scorer.score_point(idx),
+        });
+    }
+    Ok(rescored.into_sorted_vec())
+}
+
+/// Score found points again with the original vectors, best scores first
+pub fn rescore_search_result(
+    search_result: &[ScoredPointOffset],
+    point_deleted: &BitSlice,
+    vector_storage: &VectorStorageEnum,
+    vector: &QueryVector,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Vec<ScoredPointOffset>> {
+    let mut scorer = FilteredScorer::new(
+        vector.to_owned(),
+        vector_storage,
+        None,
+        None,
+        point_deleted,
+        hardware_counter,
+    )?;
+
+    let mut rescored: Vec<_> = scorer
+        .score_points(&mut search_result.iter().map(|x| x.idx).collect_vec(), 0)
+        .collect();
+    rescored.sort_unstable();
+    rescored.reverse();
+    Ok(rescored)
+}
@@ -17,6 +17,8 @@ pub mod data_types;
 pub mod json_path;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "vde")]
+pub mod vde;
 pub mod vector_storage;
 
 #[macro_use]
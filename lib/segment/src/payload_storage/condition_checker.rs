@@ -4,10 +4,11 @@ use std::str::FromStr;
 
 use serde_json::Value;
 
+use crate::index::field_index::full_text_index::fuzzy::FuzzyMatcher;
 use crate::types::{
     AnyVariants, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPoint,
-    GeoPolygon, GeoRadius, Match, MatchAny, MatchExcept, MatchPhrase, MatchText, MatchTextAny,
-    MatchValue, Range, RangeInterface, ValueVariants, ValuesCount,
+    GeoPolygon, GeoRadius, Match, MatchAny, MatchExcept, MatchFuzzy, MatchPhrase, MatchText,
+    MatchTextAny, MatchValue, Range, RangeInterface, ValueVariants, ValuesCount,
 };
 
 /// Threshold representing the point to which iterating through an IndexSet is more efficient than using hashing.
@@ -171,6 +172,32 @@ impl ValueChecker for Match {
                     .any(|token| stored.contains(token)),
                 _ => false,
             },
+            Match::Fuzzy(MatchFuzzy {
+                text,
+                max_edits,
+                prefix_len,
+            }) => match payload {
+                Value::String(stored) => {
+                    let split_words = |text: &str| {
+                        text.to_lowercase()
+                            .split(|c: char| !c.is_alphanumeric())
+                            .filter(|word| !word.is_empty())
+                            .map(str::to_string)
+                            .collect::<Vec<_>>()
+                    };
+                    let stored_words = split_words(stored);
+                    let query_words = split_words(text);
+
+                    // Each word of the query must be similar to some word of the payload
+                    !query_words.is_empty()
+                        && query_words.iter().all(|word| {
+                            let matcher =
+                                FuzzyMatcher::new(word, *max_edits, prefix_len.unwrap_or(0));
+                            stored_words.iter().any(|stored| matcher.is_match(stored))
+                        })
+                }
+                _ => false,
+            },
             Match::Any(MatchAny { any }) => match (payload, any) {
                 (Value::String(stored), AnyVariants::Strings(list)) => {
                     if list.len() < INDEXSET_ITER_THRESHOLD {
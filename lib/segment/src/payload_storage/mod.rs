@@ -15,6 +15,7 @@ pub mod simple_payload_storage;
 pub mod simple_payload_storage_impl;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "vde")]
 pub mod vde_storage;
 
 pub use payload_storage_base::*;
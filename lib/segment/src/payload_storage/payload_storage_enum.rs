@@ -15,6 +15,7 @@ use crate::payload_storage::mmap_payload_storage::MmapPayloadStorage;
 use crate::payload_storage::on_disk_payload_storage::OnDiskPayloadStorage;
 #[cfg(feature = "rocksdb")]
 use crate::payload_storage::simple_payload_storage::SimplePayloadStorage;
+#[cfg(feature = "vde")]
 use crate::payload_storage::vde_storage::vde_payload_storage::VDEPayloadStorage;
 use crate::types::Payload;
 
@@ -27,6 +28,7 @@ pub enum PayloadStorageEnum {
     #[cfg(feature = "rocksdb")]
     OnDiskPayloadStorage(OnDiskPayloadStorage),
     MmapPayloadStorage(MmapPayloadStorage),
+    #[cfg(feature = "vde")]
     Vde(VDEPayloadStorage),
 }
 
@@ -78,6 +80,7 @@ impl PayloadStorage for PayloadStorageEnum {
                 s.overwrite(point_id, payload, hw_counter)
             }
             PayloadStorageEnum::MmapPayloadStorage(s) => s.overwrite(point_id, payload, hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.overwrite(point_id, payload, hw_counter),
         }
     }
@@ -96,6 +99,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.set(point_id, payload, hw_counter),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.set(point_id, payload, hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.set(point_id, payload, hw_counter),
         }
     }
@@ -123,9 +127,8 @@ impl PayloadStorage for PayloadStorageEnum {
             PayloadStorageEnum::MmapPayloadStorage(s) => {
                 s.set_by_key(point_id, payload, key, hw_counter)
             }
-            PayloadStorageEnum::Vde(s) => {
-                s.set_by_key(point_id, payload, key, hw_counter)
-            }
+            #[cfg(feature = "vde")]
+            PayloadStorageEnum::Vde(s) => s.set_by_key(point_id, payload, key, hw_counter),
         }
     }
 
@@ -142,6 +145,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.get(point_id, hw_counter),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.get(point_id, hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.get(point_id, hw_counter),
         }
     }
@@ -159,6 +163,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.get_sequential(point_id, hw_counter),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.get_sequential(point_id, hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.get_sequential(point_id, hw_counter),
         }
     }
@@ -177,6 +182,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.delete(point_id, key, hw_counter),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.delete(point_id, key, hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.delete(point_id, key, hw_counter),
         }
     }
@@ -194,6 +200,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.clear(point_id, hw_counter),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.clear(point_id, hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.clear(point_id, hw_counter),
         }
     }
@@ -208,6 +215,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.clear_all(hw_counter),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.clear_all(hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.clear_all(hw_counter),
         }
     }
@@ -221,6 +229,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.flusher(),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.flusher(),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.flusher(),
         }
     }
@@ -237,6 +246,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.iter(callback, hw_counter),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.iter(callback, hw_counter),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.iter(callback, hw_counter),
         }
     }
@@ -250,6 +260,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.files(),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.files(),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.files(),
         }
     }
@@ -263,6 +274,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.immutable_files(),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.immutable_files(),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.immutable_files(),
         }
     }
@@ -276,6 +288,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.get_storage_size_bytes(),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.get_storage_size_bytes(),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.get_storage_size_bytes(),
         }
     }
@@ -289,6 +302,7 @@ impl PayloadStorage for PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(s) => s.is_on_disk(),
             PayloadStorageEnum::MmapPayloadStorage(s) => s.is_on_disk(),
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.is_on_disk(),
         }
     }
@@ -306,6 +320,7 @@ impl PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(_) => {}
             PayloadStorageEnum::MmapPayloadStorage(s) => s.populate()?,
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.populate()?,
         }
         Ok(())
@@ -321,6 +336,7 @@ impl PayloadStorageEnum {
             #[cfg(feature = "rocksdb")]
             PayloadStorageEnum::OnDiskPayloadStorage(_) => {}
             PayloadStorageEnum::MmapPayloadStorage(s) => s.clear_cache()?,
+            #[cfg(feature = "vde")]
             PayloadStorageEnum::Vde(s) => s.clear_cache()?,
         }
         Ok(())
@@ -306,6 +306,13 @@ impl ConditionChecker for SimpleConditionChecker {
                             });
                             Some(OwnedPayloadRef::from(payload))
                         }
+                        #[cfg(feature = "vde")]
+                        PayloadStorageEnum::Vde(s) => {
+                            let payload = s.get(point_id, &hw_counter).unwrap_or_else(|err| {
+                                panic!("Payload storage is corrupted: {err}")
+                            });
+                            Some(OwnedPayloadRef::from(payload))
+                        }
                     };
 
                     payload_ref_cell
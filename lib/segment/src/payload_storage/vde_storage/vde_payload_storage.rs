@@ -1,189 +1,111 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
 
+use atomic_refcell::AtomicRefCell;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
+use parking_lot::RwLock;
 use serde_json::Value;
-use vde_sys::*;
 
-use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::Flusher;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::id_tracker::IdTrackerSS;
 use crate::json_path::JsonPath;
 use crate::payload_storage::PayloadStorage;
-use crate::types::Payload;
+use crate::types::{Distance, Payload};
+use crate::vde::{VdeCollection, VdeCollectionConfig, VdeEngine};
 
 /// VDE-backed payload storage
 ///
-/// This implementation stores payload (metadata) in VDE's Btrieve2 backend.
+/// This implementation stores payload (metadata) in a VDE collection.
 /// Payloads are stored as JSON and indexed for filtering.
+///
+/// VDE can't enumerate the points of a collection, so whole-storage operations go over the points
+/// known to the segment's ID tracker.
 pub struct VDEPayloadStorage {
-    /// VDE Collection handle
-    collection: VDECollectionHandle,
-    
-    /// VDE Engine handle (shared)
-    engine: Arc<RwLock<VDEEngineHandle>>,
-    
-    /// Collection name
-    name: String,
-    
-    /// Base path
+    collection: Arc<dyn VdeCollection>,
     path: PathBuf,
-    
+    id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
     /// In-memory cache for payloads (optional optimization)
-    cache: Arc<RwLock<HashMap<PointOffsetType, Payload>>>,
+    cache: RwLock<HashMap<PointOffsetType, Payload>>,
 }
 
 impl std::fmt::Debug for VDEPayloadStorage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("VDEPayloadStorage")
-            .field("name", &self.name)
+            .field("name", &self.collection.name())
             .field("path", &self.path)
-            .field("cache_size", &self.cache.read().map(|c| c.len()))
+            .field("cache_size", &self.cache.read().len())
             .finish()
     }
 }
 
 impl VDEPayloadStorage {
-    pub fn new(
+    /// Open the VDE collection `name`, creating it if it does not exist yet
+    pub fn open(
+        engine: &dyn VdeEngine,
         path: &Path,
         name: &str,
         dimension: usize,
-        distance: crate::types::Distance,
+        distance: Distance,
+        id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
     ) -> OperationResult<Self> {
-        unsafe {
-            let work_dir = std::ffi::CString::new(path.to_str().unwrap())
-                .map_err(|e| OperationError::service_error(format!("Invalid path: {}", e)))?;
-            
-            let engine_handle = vde_engine_create(work_dir.as_ptr());
-            if engine_handle.is_null() {
-                return Err(OperationError::service_error("Failed to create VDE engine"));
-            }
-            
-            let engine = Arc::new(RwLock::new(engine_handle));
-            
-            let collection_name = std::ffi::CString::new(name)
-                .map_err(|e| OperationError::service_error(format!("Invalid name: {}", e)))?;
-            
-            // Try to open existing collection
-            let mut collection = vde_collection_open(engine_handle, collection_name.as_ptr());
-            
-            if collection.is_null() {
-                // Create new collection
-                let index_type = std::ffi::CString::new("vsag_hnsw").unwrap();
-                let storage_type = std::ffi::CString::new("zendb").unwrap();
-                let distance_str = std::ffi::CString::new(match distance {
-                    crate::types::Distance::Cosine => "cosine",
-                    crate::types::Distance::Euclid => "euclidean",
-                    crate::types::Distance::Dot => "dot",
-                    crate::types::Distance::Manhattan => "manhattan",
-                }).unwrap();
-                
-                let config = VDECollectionConfig {
-                    index_type: index_type.as_ptr(),
-                    storage_type: storage_type.as_ptr(),
-                    dimension: dimension as u32,
-                    distance_metric: distance_str.as_ptr(),
-                    config_json: std::ptr::null(),
-                };
-                
-                collection = vde_collection_create(engine_handle, collection_name.as_ptr(), &config);
-                if collection.is_null() {
-                    vde_engine_destroy(engine_handle);
-                    return Err(OperationError::service_error("Failed to create VDE collection"));
-                }
-            }
-            
-            Ok(Self {
-                collection,
-                engine,
-                name: name.to_string(),
-                path: path.to_path_buf(),
-                cache: Arc::new(RwLock::new(HashMap::new())),
-            })
-        }
+        let config = VdeCollectionConfig::new(dimension, distance);
+        let collection = engine.open_or_create_collection(name, &config)?;
+
+        Ok(Self {
+            collection,
+            path: path.to_path_buf(),
+            id_tracker,
+            cache: RwLock::new(HashMap::new()),
+        })
     }
-    
+
     /// Get payload from VDE
     fn get_payload_internal(&self, point_id: PointOffsetType) -> OperationResult<Payload> {
-        // Check cache first
-        {
-            let cache = self.cache.read().unwrap();
-            if let Some(payload) = cache.get(&point_id) {
-                return Ok(payload.clone());
-            }
-        }
-        
-        unsafe {
-            // Allocate buffer for payload JSON
-            let mut json_buffer = vec![0u8; 64 * 1024]; // 64KB max
-            let mut vde_payload = VDEPayload {
-                json: json_buffer.as_mut_ptr() as *const i8,
-                length: json_buffer.len() as u32,
-            };
-            
-            let ret = vde_get_vector(
-                self.collection,
-                point_id as u64,
-                std::ptr::null_mut(),
-                &mut vde_payload,
-            );
-            
-            if ret != 0 {
-                return Ok(Payload::default()); // Empty payload if not found
-            }
-            
-            // Parse JSON
-            let json_str = std::str::from_utf8(&json_buffer[..vde_payload.length as usize])
-                .map_err(|e| OperationError::service_error(format!("Invalid UTF-8 in payload: {}", e)))?;
-            
-            let payload: Payload = serde_json::from_str(json_str)
-                .map_err(|e| OperationError::service_error(format!("Failed to parse payload JSON: {}", e)))?;
-            
-            // Update cache
-            {
-                let mut cache = self.cache.write().unwrap();
-                cache.insert(point_id, payload.clone());
-            }
-            
-            Ok(payload)
+        if let Some(payload) = self.cache.read().get(&point_id) {
+            return Ok(payload.clone());
         }
+
+        let payload = self.read_payload(point_id)?;
+
+        self.cache.write().insert(point_id, payload.clone());
+
+        Ok(payload)
+    }
+
+    /// Read payload from VDE, bypassing the cache
+    fn read_payload(&self, point_id: PointOffsetType) -> OperationResult<Payload> {
+        let Some(json) = self.collection.get_payload(point_id)? else {
+            return Ok(Payload::default());
+        };
+
+        serde_json::from_str(&json).map_err(|err| {
+            OperationError::service_error(format!("Failed to parse payload JSON: {err}"))
+        })
+    }
+
+    /// Internal IDs of all points known to the segment
+    fn point_ids(&self) -> Vec<PointOffsetType> {
+        self.id_tracker.borrow().iter_internal().collect()
     }
-    
+
     /// Set payload in VDE
-    fn set_payload_internal(&mut self, point_id: PointOffsetType, payload: &Payload) -> OperationResult<()> {
-        unsafe {
-            // Serialize payload to JSON
-            let json = serde_json::to_string(payload)
-                .map_err(|e| OperationError::service_error(format!("Failed to serialize payload: {}", e)))?;
-            
-            let json_cstr = std::ffi::CString::new(json)
-                .map_err(|e| OperationError::service_error(format!("Invalid JSON string: {}", e)))?;
-            
-            let vde_payload = VDEPayload {
-                json: json_cstr.as_ptr(),
-                length: json_cstr.as_bytes().len() as u32,
-            };
-            
-            let ret = vde_upsert_vector(
-                self.collection,
-                point_id as u64,
-                std::ptr::null(), // vector handled separately
-                &vde_payload,
-            );
-            
-            if ret != 0 {
-                return Err(OperationError::service_error(format!("Failed to set payload: {}", ret)));
-            }
-            
-            // Update cache
-            {
-                let mut cache = self.cache.write().unwrap();
-                cache.insert(point_id, payload.clone());
-            }
-            
-            Ok(())
-        }
+    fn set_payload_internal(
+        &self,
+        point_id: PointOffsetType,
+        payload: &Payload,
+    ) -> OperationResult<()> {
+        let json = serde_json::to_string(payload).map_err(|err| {
+            OperationError::service_error(format!("Failed to serialize payload: {err}"))
+        })?;
+
+        self.collection.upsert(point_id, None, Some(&json))?;
+
+        self.cache.write().insert(point_id, payload.clone());
+
+        Ok(())
     }
 }
 
@@ -196,22 +118,18 @@ impl PayloadStorage for VDEPayloadStorage {
     ) -> OperationResult<()> {
         self.set_payload_internal(point_id, payload)
     }
-    
+
     fn set(
         &mut self,
         point_id: PointOffsetType,
         payload: &Payload,
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        // Get existing payload and merge
         let mut existing = self.get_payload_internal(point_id)?;
-        
-        // Merge payloads
         existing.merge(payload);
-        
         self.set_payload_internal(point_id, &existing)
     }
-    
+
     fn set_by_key(
         &mut self,
         point_id: PointOffsetType,
@@ -220,13 +138,10 @@ impl PayloadStorage for VDEPayloadStorage {
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         let mut existing = self.get_payload_internal(point_id)?;
-        
-        // Merge by key using JsonPath
         existing.merge_by_key(payload, key);
-        
         self.set_payload_internal(point_id, &existing)
     }
-    
+
     fn get(
         &self,
         point_id: PointOffsetType,
@@ -234,7 +149,7 @@ impl PayloadStorage for VDEPayloadStorage {
     ) -> OperationResult<Payload> {
         self.get_payload_internal(point_id)
     }
-    
+
     fn get_sequential(
         &self,
         point_id: PointOffsetType,
@@ -242,7 +157,7 @@ impl PayloadStorage for VDEPayloadStorage {
     ) -> OperationResult<Payload> {
         self.get(point_id, hw_counter)
     }
-    
+
     fn delete(
         &mut self,
         point_id: PointOffsetType,
@@ -250,84 +165,83 @@ impl PayloadStorage for VDEPayloadStorage {
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<Value>> {
         let mut payload = self.get_payload_internal(point_id)?;
-        
-        // Remove using JsonPath
         let deleted_values = payload.remove(key);
-        
         self.set_payload_internal(point_id, &payload)?;
         Ok(deleted_values)
     }
-    
+
     fn clear(
         &mut self,
         point_id: PointOffsetType,
         _hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<Payload>> {
         let existing = self.get_payload_internal(point_id).ok();
-        
-        // Set empty payload
-        let empty = Payload::default();
-        self.set_payload_internal(point_id, &empty)?;
-        
-        // Clear cache
-        {
-            let mut cache = self.cache.write().unwrap();
-            cache.remove(&point_id);
-        }
-        
+
+        self.set_payload_internal(point_id, &Payload::default())?;
+        self.cache.write().remove(&point_id);
+
         Ok(existing)
     }
-    
+
     #[cfg(test)]
     fn clear_all(&mut self, _hw_counter: &HardwareCounterCell) -> OperationResult<()> {
-        let mut cache = self.cache.write().unwrap();
-        cache.clear();
+        for point_id in self.point_ids() {
+            self.set_payload_internal(point_id, &Payload::default())?;
+        }
+        self.cache.write().clear();
         Ok(())
     }
-    
+
     fn flusher(&self) -> Flusher {
-        // Since VDECollection pointer can't be sent between threads,
-        // we return a no-op flusher. VDE flushes on Drop.
-        Box::new(|| Ok(()))
+        let collection = self.collection.clone();
+        Box::new(move || Ok(collection.flush()?))
     }
-    
+
     fn iter<F>(&self, mut callback: F, _hw_counter: &HardwareCounterCell) -> OperationResult<()>
     where
         F: FnMut(PointOffsetType, &Payload) -> OperationResult<bool>,
     {
-        // Iterate over cache (VDE doesn't expose iteration API yet)
-        let cache = self.cache.read().unwrap();
-        
-        for (point_id, payload) in cache.iter() {
-            let should_continue = callback(*point_id, payload)?;
-            if !should_continue {
+        for point_id in self.point_ids() {
+            let cached = self.cache.read().get(&point_id).cloned();
+            let payload = match cached {
+                Some(payload) => payload,
+                None => self.read_payload(point_id)?,
+            };
+            if payload.is_empty() {
+                continue;
+            }
+            if !callback(point_id, &payload)? {
                 break;
             }
         }
-        
+
         Ok(())
     }
-    
+
     fn files(&self) -> Vec<PathBuf> {
-        vec![
-            self.path.join(format!("{}_metadata.btr", self.name)),
-        ]
+        self.collection.files().unwrap_or_else(|err| {
+            log::error!(
+                "Failed to list files of VDE collection {}: {err}",
+                self.collection.name(),
+            );
+            Vec::new()
+        })
     }
-    
+
     fn get_storage_size_bytes(&self) -> OperationResult<usize> {
         // Estimate based on cache size
-        let cache = self.cache.read().unwrap();
-        let estimated_size: usize = cache.iter()
-            .map(|(_, payload)| {
-                serde_json::to_string(payload).unwrap_or_default().len()
-            })
+        let estimated_size = self
+            .cache
+            .read()
+            .values()
+            .map(|payload| serde_json::to_string(payload).map_or(0, |json| json.len()))
             .sum();
-        
+
         Ok(estimated_size)
     }
-    
+
     fn is_on_disk(&self) -> bool {
-        true // VDE uses Btrieve2 for persistent storage
+        true
     }
 }
 
@@ -336,32 +250,107 @@ impl VDEPayloadStorage {
     pub fn populate(&self) -> OperationResult<()> {
         Ok(())
     }
-    
+
     /// Clear cache
     pub fn clear_cache(&self) -> OperationResult<()> {
-        if let Ok(mut cache) = self.cache.write() {
-            cache.clear();
-        }
+        self.cache.write().clear();
         Ok(())
     }
 }
 
 impl Drop for VDEPayloadStorage {
     fn drop(&mut self) {
-        // Flush before dropping
-        unsafe {
-            vde_flush(self.collection);
+        if let Err(err) = self.collection.flush() {
+            log::error!(
+                "Failed to flush VDE collection {}: {err}",
+                self.collection.name(),
+            );
         }
-        
-        if let Ok(engine) = self.engine.read() {
-            unsafe {
-                if !(*engine).is_null() {
-                    vde_engine_destroy(*engine);
-                }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::fixtures::payload_context_fixture::FixtureIdTracker;
+    use crate::index::PayloadIndex;
+    use crate::index::struct_payload_index::StructPayloadIndex;
+    use crate::payload_json;
+    use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
+    use crate::types::{Condition, FieldCondition, Filter, PayloadSchemaType, Range};
+    use crate::vde::InProcessEngine;
+
+    const NUM_POINTS: usize = 100;
+
+    const COLLECTION_NAME: &str = "payload";
+
+    fn open_storage(
+        engine: &dyn VdeEngine,
+        path: &Path,
+        id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    ) -> VDEPayloadStorage {
+        VDEPayloadStorage::open(engine, path, COLLECTION_NAME, 1, Distance::Dot, id_tracker)
+            .unwrap()
+    }
+
+    /// Payload index built on a restored storage sees payloads that were never read after restore
+    #[test]
+    fn test_restored_payload_index() {
+        let dir = Builder::new().prefix("vde_payload").tempdir().unwrap();
+        let restored_dir = Builder::new().prefix("vde_payload").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::new();
+        let id_tracker: Arc<AtomicRefCell<IdTrackerSS>> =
+            Arc::new(AtomicRefCell::new(FixtureIdTracker::new(NUM_POINTS)));
+
+        {
+            let engine = InProcessEngine::open(dir.path()).unwrap();
+            let mut storage = open_storage(&engine, dir.path(), id_tracker.clone());
+            for point_id in 0..NUM_POINTS as PointOffsetType {
+                storage
+                    .set(point_id, &payload_json! {"num": point_id}, &hw_counter)
+                    .unwrap();
+            }
+            storage.flusher()().unwrap();
+
+            for file in storage.files() {
+                let file_name = file.strip_prefix(dir.path()).unwrap();
+                std::fs::copy(&file, restored_dir.path().join(file_name)).unwrap();
             }
         }
+
+        let engine = InProcessEngine::open(restored_dir.path()).unwrap();
+        let storage = open_storage(&engine, restored_dir.path(), id_tracker.clone());
+        let mut index = StructPayloadIndex::open(
+            Arc::new(AtomicRefCell::new(PayloadStorageEnum::Vde(storage))),
+            id_tracker,
+            HashMap::new(),
+            &restored_dir.path().join("payload_index"),
+            true,
+            true,
+        )
+        .unwrap();
+        index
+            .set_indexed(
+                &JsonPath::new("num"),
+                PayloadSchemaType::Integer,
+                &hw_counter,
+            )
+            .unwrap();
+        assert_eq!(index.indexed_points(&JsonPath::new("num")), NUM_POINTS);
+
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+            JsonPath::new("num"),
+            Range {
+                lt: Some(10.0),
+                gt: None,
+                gte: None,
+                lte: None,
+            },
+        )));
+        let mut found = index.query_points(&filter, &hw_counter);
+        found.sort_unstable();
+        assert_eq!(found, (0..10).collect::<Vec<_>>());
     }
 }
-
-unsafe impl Send for VDEPayloadStorage {}
-unsafe impl Sync for VDEPayloadStorage {}
@@ -5,7 +5,7 @@ use std::sync::atomic::AtomicBool;
 use std::{fs, thread};
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::TelemetryDetail;
+use common::types::{ScoreType, TelemetryDetail};
 
 use super::Segment;
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
@@ -17,6 +17,7 @@ use crate::data_types::order_by::{OrderBy, OrderValue};
 use crate::data_types::query_context::{
     FormulaContext, QueryContext, QueryIdfStats, SegmentQueryContext,
 };
+use crate::data_types::text_query::{TextQuery, TextQueryStatistics};
 use crate::data_types::vectors::{QueryVector, VectorInternal};
 use crate::entry::entry_point::SegmentEntry;
 use crate::index::field_index::{CardinalityEstimation, FieldIndex};
@@ -415,6 +416,28 @@ impl SegmentEntry for Segment {
         }
     }
 
+    fn fill_text_query_statistics(
+        &self,
+        query: &TextQuery,
+        excluded_points: &[PointIdType],
+        statistics: &mut TextQueryStatistics,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        self.fill_text_index_statistics(query, excluded_points, statistics, hw_counter)
+    }
+
+    fn read_text_scored_filtered<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        query: &'a TextQuery,
+        statistics: &TextQueryStatistics,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        self.filtered_read_by_text_score(limit, filter, query, statistics, is_stopped, hw_counter)
+    }
+
     fn read_random_filtered(
         &self,
         limit: usize,
@@ -645,6 +668,11 @@ impl SegmentEntry for Segment {
             .values()
             .filter_map(|v| v.quantized_vectors.borrow().as_ref().map(|q| q.flusher()))
             .collect();
+        let vector_index_flushers: Vec<_> = self
+            .vector_data
+            .values()
+            .filter_map(|v| v.vector_index.borrow().flusher())
+            .collect();
         let state = self.get_state();
         let current_path = self.current_path.clone();
         let id_tracker_mapping_flusher = self.id_tracker.borrow().mapping_flusher();
@@ -716,6 +744,11 @@ impl SegmentEntry for Segment {
                     ))
                 })?;
             }
+            for vector_index_flusher in vector_index_flushers {
+                vector_index_flusher().map_err(|err| {
+                    OperationError::service_error(format!("Failed to flush vector_index: {err}"))
+                })?;
+            }
             payload_index_flusher().map_err(|err| {
                 OperationError::service_error(format!("Failed to flush payload_index: {err}"))
             })?;
@@ -890,6 +923,7 @@ impl SegmentEntry for Segment {
         let QueryIdfStats {
             idf,
             indexed_vectors,
+            document_lengths,
         } = query_context.mut_idf_stats();
 
         for (vector_name, idf) in idf.iter_mut() {
@@ -907,5 +941,11 @@ impl SegmentEntry for Segment {
                 vector_index.fill_idf_statistics(idf, &hw_counter);
             }
         }
+
+        for (vector_name, total_length) in document_lengths.iter_mut() {
+            if let Some(vector_data) = self.vector_data.get(vector_name) {
+                *total_length += vector_data.vector_index.borrow().total_document_length();
+            }
+        }
     }
 }
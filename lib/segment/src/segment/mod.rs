@@ -6,6 +6,7 @@ mod sampling;
 mod scroll;
 mod search;
 mod segment_ops;
+mod text_query;
 mod version_tracker;
 
 pub mod snapshot;
@@ -88,6 +88,7 @@ impl Segment {
                     vector,
                     shard_key: None,
                     order_value: None,
+                    highlight: None,
                 })
             })
             .collect()
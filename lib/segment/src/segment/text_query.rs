@@ -0,0 +1,104 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::iterator_ext::IteratorExt;
+use common::types::ScoreType;
+use ordered_float::OrderedFloat;
+
+use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::text_query::{TextQuery, TextQueryStatistics};
+use crate::index::PayloadIndex;
+use crate::index::field_index::full_text_index::text_index::FullTextIndex;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::spaces::tools::peek_top_largest_iterable;
+use crate::types::{Filter, PointIdType};
+
+fn get_text_index<'a>(
+    payload_index: &'a StructPayloadIndex,
+    query: &TextQuery,
+) -> OperationResult<&'a FullTextIndex> {
+    payload_index
+        .field_indexes
+        .get(&query.key)
+        .and_then(|indexes| indexes.iter().find_map(|index| index.as_full_text()))
+        .ok_or_else(|| OperationError::MissingFullTextIndexForTextQuery {
+            key: query.key.to_string(),
+        })
+}
+
+impl Segment {
+    pub fn fill_text_index_statistics(
+        &self,
+        query: &TextQuery,
+        excluded_points: &[PointIdType],
+        statistics: &mut TextQueryStatistics,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let text_index = get_text_index(&payload_index, query)?;
+
+        let excluded_internal_ids: Vec<_> = excluded_points
+            .iter()
+            .filter_map(|&point_id| id_tracker.internal_id(point_id))
+            .collect();
+
+        let tokens = text_index.parse_scored_query_tokens(&query.query);
+        text_index.fill_text_query_statistics(
+            &tokens,
+            &excluded_internal_ids,
+            statistics,
+            hw_counter,
+        );
+        Ok(())
+    }
+
+    pub fn filtered_read_by_text_score(
+        &self,
+        limit: Option<usize>,
+        filter: Option<&Filter>,
+        query: &TextQuery,
+        statistics: &TextQueryStatistics,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let text_index = get_text_index(&payload_index, query)?;
+
+        let tokens = text_index.parse_scored_query_tokens(&query.query);
+        let scores = text_index.bm25_scores(&tokens, statistics, hw_counter);
+
+        let filter_context = filter.map(|filter| payload_index.filter_context(filter, hw_counter));
+
+        let scored_ids = scores
+            .into_iter()
+            .check_stop(|| is_stopped.load(Ordering::Relaxed))
+            .filter(|(internal_id, _)| !id_tracker.is_deleted_point(*internal_id))
+            .filter(|(internal_id, _)| {
+                filter_context
+                    .as_ref()
+                    .is_none_or(|filter_context| filter_context.check(*internal_id))
+            })
+            .filter_map(|(internal_id, score)| {
+                id_tracker
+                    .external_id(internal_id)
+                    .map(|external_id| (OrderedFloat(score), external_id))
+            });
+
+        let mut page = match limit {
+            Some(limit) => peek_top_largest_iterable(scored_ids, limit),
+            None => scored_ids.collect(),
+        };
+        // Highest score first, lowest id first on ties
+        page.sort_unstable_by(|(score_a, id_a), (score_b, id_b)| {
+            score_b.cmp(score_a).then_with(|| id_a.cmp(id_b))
+        });
+
+        Ok(page
+            .into_iter()
+            .map(|(score, external_id)| (score.into_inner(), external_id))
+            .collect())
+    }
+}
@@ -29,14 +29,18 @@ use crate::id_tracker::mutable_id_tracker::MutableIdTracker;
 use crate::id_tracker::simple_id_tracker::SimpleIdTracker;
 use crate::id_tracker::{IdTracker, IdTrackerEnum, IdTrackerSS};
 use crate::index::VectorIndexEnum;
+use crate::index::diskann_index::{DiskAnnIndex, DiskAnnIndexOpenArgs};
 use crate::index::hnsw_index::gpu::gpu_devices_manager::LockedGpuDevice;
 use crate::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use crate::index::ivf_index::{IvfIndex, IvfIndexOpenArgs};
 use crate::index::plain_vector_index::PlainVectorIndex;
 use crate::index::sparse_index::sparse_index_config::SparseIndexType;
 use crate::index::sparse_index::sparse_vector_index::{
     self, SparseVectorIndex, SparseVectorIndexOpenArgs,
 };
 use crate::index::struct_payload_index::StructPayloadIndex;
+#[cfg(feature = "vde")]
+use crate::index::vde_index::{VDEVectorIndex, VdeIndexOpenArgs};
 use crate::payload_storage::mmap_payload_storage::MmapPayloadStorage;
 #[cfg(feature = "rocksdb")]
 use crate::payload_storage::on_disk_payload_storage::OnDiskPayloadStorage;
@@ -46,17 +50,23 @@ use crate::payload_storage::simple_payload_storage::SimplePayloadStorage;
 use crate::segment::{SEGMENT_STATE_FILE, Segment, SegmentVersion, VectorData};
 #[cfg(feature = "rocksdb")]
 use crate::types::MultiVectorConfig;
+#[cfg(not(feature = "vde"))]
+use crate::types::VDE_NOT_SUPPORTED_MESSAGE;
 use crate::types::{
     Distance, HnswGlobalConfig, Indexes, PayloadStorageType, SegmentConfig, SegmentState,
     SegmentType, SeqNumberType, SparseVectorStorageType, VectorDataConfig, VectorName,
     VectorStorageDatatype, VectorStorageType,
 };
+#[cfg(feature = "vde")]
+use crate::vde::VdeBackend;
 use crate::vector_storage::dense::appendable_dense_vector_storage::{
     open_appendable_in_ram_vector_storage, open_appendable_memmap_vector_storage,
-    open_appendable_memmap_vector_storage_byte, open_appendable_memmap_vector_storage_half,
+    open_appendable_memmap_vector_storage_bf16, open_appendable_memmap_vector_storage_byte,
+    open_appendable_memmap_vector_storage_half,
 };
 use crate::vector_storage::dense::memmap_dense_vector_storage::{
-    open_memmap_vector_storage, open_memmap_vector_storage_byte, open_memmap_vector_storage_half,
+    open_memmap_vector_storage, open_memmap_vector_storage_bf16, open_memmap_vector_storage_byte,
+    open_memmap_vector_storage_half,
 };
 #[cfg(feature = "rocksdb")]
 use crate::vector_storage::dense::simple_dense_vector_storage::open_simple_dense_vector_storage;
@@ -158,12 +168,19 @@ pub(crate) fn open_vector_storage(
                         vector_config.size,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Uint8 => open_memmap_vector_storage_byte(
+                    VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
+                        open_memmap_vector_storage_byte(
+                            vector_storage_path,
+                            vector_config.size,
+                            vector_config.distance,
+                        )
+                    }
+                    VectorStorageDatatype::Float16 => open_memmap_vector_storage_half(
                         vector_storage_path,
                         vector_config.size,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Float16 => open_memmap_vector_storage_half(
+                    VectorStorageDatatype::BFloat16 => open_memmap_vector_storage_bf16(
                         vector_storage_path,
                         vector_config.size,
                         vector_config.distance,
@@ -188,12 +205,19 @@ pub(crate) fn open_vector_storage(
                         vector_config.size,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Uint8 => open_appendable_memmap_vector_storage_byte(
+                    VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
+                        open_appendable_memmap_vector_storage_byte(
+                            vector_storage_path,
+                            vector_config.size,
+                            vector_config.distance,
+                        )
+                    }
+                    VectorStorageDatatype::Float16 => open_appendable_memmap_vector_storage_half(
                         vector_storage_path,
                         vector_config.size,
                         vector_config.distance,
                     ),
-                    VectorStorageDatatype::Float16 => open_appendable_memmap_vector_storage_half(
+                    VectorStorageDatatype::BFloat16 => open_appendable_memmap_vector_storage_bf16(
                         vector_storage_path,
                         vector_config.size,
                         vector_config.distance,
@@ -220,29 +244,27 @@ pub(crate) fn open_vector_storage(
             }
         }
         // VDE storage
+        #[cfg(feature = "vde")]
         VectorStorageType::Vde => {
             use crate::vector_storage::vde_storage::VDEVectorStorage;
-            
-            if vector_config.multivector_config.is_some() {
-                return Err(OperationError::service_error(
-                    "VDE doesn't support multi-vector config"
-                ));
-            }
-            
+
             let collection_name = vector_storage_path
                 .file_name()
                 .and_then(|n| n.to_str())
                 .unwrap_or("vde_vectors");
-            
-            Ok(VectorStorageEnum::Vde(
-                VDEVectorStorage::new(
-                    vector_storage_path,
-                    collection_name,
-                    vector_config.size,
-                    vector_config.distance,
-                )?
-            ))
+
+            let engine = crate::vde::shared_engine(VdeBackend::default(), vector_storage_path)?;
+            Ok(VectorStorageEnum::Vde(VDEVectorStorage::open(
+                engine.as_ref(),
+                vector_storage_path,
+                collection_name,
+                vector_config.size,
+                vector_config.distance,
+                vector_config.multivector_config,
+            )?))
         }
+        #[cfg(not(feature = "vde"))]
+        VectorStorageType::Vde => Err(OperationError::validation_error(VDE_NOT_SUPPORTED_MESSAGE)),
     }
 }
 
@@ -338,6 +360,40 @@ pub(crate) fn open_vector_index(
             payload_index,
             hnsw_config: hnsw_config.clone(),
         })?),
+        Indexes::DiskAnn(diskann_config) => {
+            VectorIndexEnum::DiskAnn(DiskAnnIndex::open(DiskAnnIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                payload_index,
+                dimension: vector_config.size,
+                distance: vector_config.distance,
+                config: diskann_config.clone(),
+            })?)
+        }
+        Indexes::Ivf(ivf_config) => VectorIndexEnum::Ivf(IvfIndex::open(IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            payload_index,
+            dimension: vector_config.size,
+            distance: vector_config.distance,
+            config: ivf_config.clone(),
+        })?),
+        #[cfg(feature = "vde")]
+        Indexes::Vde(vde_config) => VectorIndexEnum::Vde(VDEVectorIndex::open(VdeIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            payload_index,
+            dimension: vector_config.size,
+            distance: vector_config.distance,
+            config: vde_config.clone(),
+        })?),
+        #[cfg(not(feature = "vde"))]
+        Indexes::Vde(_) => {
+            return Err(OperationError::validation_error(VDE_NOT_SUPPORTED_MESSAGE));
+        }
     })
 }
 
@@ -371,6 +427,47 @@ pub(crate) fn build_vector_index<R: Rng + ?Sized>(
             },
             build_args,
         )?),
+        Indexes::DiskAnn(diskann_config) => VectorIndexEnum::DiskAnn(DiskAnnIndex::build(
+            DiskAnnIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                payload_index,
+                dimension: vector_config.size,
+                distance: vector_config.distance,
+                config: diskann_config.clone(),
+            },
+            build_args,
+        )?),
+        Indexes::Ivf(ivf_config) => VectorIndexEnum::Ivf(IvfIndex::build(
+            IvfIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                payload_index,
+                dimension: vector_config.size,
+                distance: vector_config.distance,
+                config: ivf_config.clone(),
+            },
+            build_args,
+        )?),
+        #[cfg(feature = "vde")]
+        Indexes::Vde(vde_config) => VectorIndexEnum::Vde(VDEVectorIndex::build(
+            VdeIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                payload_index,
+                dimension: vector_config.size,
+                distance: vector_config.distance,
+                config: vde_config.clone(),
+            },
+            build_args.stopped,
+        )?),
+        #[cfg(not(feature = "vde"))]
+        Indexes::Vde(_) => {
+            return Err(OperationError::validation_error(VDE_NOT_SUPPORTED_MESSAGE));
+        }
     })
 }
 
@@ -389,6 +486,11 @@ pub(crate) fn create_sparse_vector_index(
         args.config.datatype.unwrap_or_default(),
         sparse_vector_index::USE_COMPRESSED,
     ) {
+        (_, a @ (VectorStorageDatatype::Binary | VectorStorageDatatype::BFloat16), _) => {
+            Err(OperationError::ValidationError {
+                description: format!("{a:?} datatype is not supported for sparse vectors"),
+            })?
+        }
         (_, a @ (VectorStorageDatatype::Float16 | VectorStorageDatatype::Uint8), false) => {
             Err(OperationError::ValidationError {
                 description: format!("{a:?} datatype is not supported"),
@@ -1414,6 +1516,7 @@ fn migrate_rocksdb_payload_storage(
         PayloadStorageEnum::InMemoryPayloadStorage(_) => {
             unreachable!("unexpected payload storage type")
         }
+        #[cfg(feature = "vde")]
         PayloadStorageEnum::Vde(_) => {
             unreachable!("unexpected payload storage type")
         }
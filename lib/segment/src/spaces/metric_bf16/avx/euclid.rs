@@ -0,0 +1,68 @@
+use std::arch::x86_64::*;
+
+use common::types::ScoreType;
+use half::bf16;
+
+use super::load_bf16_ps_avx;
+use crate::data_types::vectors::VectorElementTypeBFloat16;
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_euclid_similarity_bf16(
+    v1: &[VectorElementTypeBFloat16],
+    v2: &[VectorElementTypeBFloat16],
+) -> ScoreType {
+    unsafe {
+        let n = v1.len();
+        let m = n - (n % 16);
+        let ptr1 = v1.as_ptr();
+        let ptr2 = v2.as_ptr();
+        let mut sum256_1: __m256 = _mm256_setzero_ps();
+        let mut sum256_2: __m256 = _mm256_setzero_ps();
+
+        let mut i: usize = 0;
+        while i < m {
+            let sub256_1 =
+                _mm256_sub_ps(load_bf16_ps_avx(ptr1.add(i)), load_bf16_ps_avx(ptr2.add(i)));
+            sum256_1 = _mm256_fmadd_ps(sub256_1, sub256_1, sum256_1);
+
+            let sub256_2 = _mm256_sub_ps(
+                load_bf16_ps_avx(ptr1.add(i + 8)),
+                load_bf16_ps_avx(ptr2.add(i + 8)),
+            );
+            sum256_2 = _mm256_fmadd_ps(sub256_2, sub256_2, sum256_2);
+            i += 16;
+        }
+
+        let mut result = hsum256_ps_avx(sum256_1) + hsum256_ps_avx(sum256_2);
+        for i in m..n {
+            result +=
+                (bf16::to_f32(*v1.get_unchecked(i)) - bf16::to_f32(*v2.get_unchecked(i))).powi(2);
+        }
+        -result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_bf16::simple_euclid::euclid_similarity_bf16;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            let v1: Vec<bf16> = (0..101).map(|i| bf16::from_f32(i as f32 * 0.1)).collect();
+            let v2: Vec<bf16> = (0..101)
+                .map(|i| bf16::from_f32(1.0 - i as f32 * 0.05))
+                .collect();
+
+            let euclid_simd = unsafe { avx_euclid_similarity_bf16(&v1, &v2) };
+            let euclid = euclid_similarity_bf16(&v1, &v2);
+            assert!((euclid_simd - euclid).abs() / euclid.abs() < 1e-5);
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use std::arch::x86_64::*;
+
+use crate::data_types::vectors::VectorElementTypeBFloat16;
+
+pub mod dot;
+pub mod euclid;
+pub mod manhattan;
+
+/// Loads 8 bfloat16 values as `f32`
+///
+/// bfloat16 is the upper half of `f32`, so conversion is a shift of each value by 16 bits.
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn load_bf16_ps_avx(ptr: *const VectorElementTypeBFloat16) -> __m256 {
+    unsafe {
+        let values = _mm256_cvtepu16_epi32(_mm_loadu_si128(ptr.cast::<__m128i>()));
+        _mm256_castsi256_ps(_mm256_slli_epi32::<16>(values))
+    }
+}
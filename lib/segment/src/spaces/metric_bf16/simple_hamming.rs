@@ -0,0 +1,31 @@
+use common::types::ScoreType;
+use half::bf16;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeBFloat16};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::HammingMetric;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBFloat16> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeBFloat16], v2: &[VectorElementTypeBFloat16]) -> ScoreType {
+        hamming_similarity_bf16(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn hamming_similarity_bf16(
+    v1: &[VectorElementTypeBFloat16],
+    v2: &[VectorElementTypeBFloat16],
+) -> ScoreType {
+    -(v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (bf16::to_f32(*a) as u8 ^ bf16::to_f32(*b) as u8).count_ones())
+        .sum::<u32>() as ScoreType)
+}
@@ -0,0 +1,38 @@
+use common::types::ScoreType;
+use half::bf16;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeBFloat16};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{JaccardMetric, jaccard_score};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBFloat16> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeBFloat16], v2: &[VectorElementTypeBFloat16]) -> ScoreType {
+        jaccard_similarity_bf16(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn jaccard_similarity_bf16(
+    v1: &[VectorElementTypeBFloat16],
+    v2: &[VectorElementTypeBFloat16],
+) -> ScoreType {
+    let (intersection, union) = v1
+        .iter()
+        .zip(v2)
+        .fold((0, 0), |(intersection, union), (a, b)| {
+            let (a, b) = (bf16::to_f32(*a) as u8, bf16::to_f32(*b) as u8);
+            (
+                intersection + (a & b).count_ones(),
+                union + (a | b).count_ones(),
+            )
+        });
+    jaccard_score(intersection, union)
+}
@@ -0,0 +1,38 @@
+use common::types::ScoreType;
+use half::f16;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeHalf};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{JaccardMetric, jaccard_score};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeHalf> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        jaccard_similarity_half(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn jaccard_similarity_half(
+    v1: &[VectorElementTypeHalf],
+    v2: &[VectorElementTypeHalf],
+) -> ScoreType {
+    let (intersection, union) = v1
+        .iter()
+        .zip(v2)
+        .fold((0, 0), |(intersection, union), (a, b)| {
+            let (a, b) = (f16::to_f32(*a) as u8, f16::to_f32(*b) as u8);
+            (
+                intersection + (a & b).count_ones(),
+                union + (a | b).count_ones(),
+            )
+        });
+    jaccard_score(intersection, union)
+}
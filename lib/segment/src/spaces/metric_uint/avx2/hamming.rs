@@ -0,0 +1,86 @@
+use std::arch::x86_64::*;
+
+/// Number of bits set in each of 32 bytes, computed with a nibble lookup table
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub(super) fn popcount_epi8(v: __m256i) -> __m256i {
+    #[rustfmt::skip]
+    let lookup = _mm256_setr_epi8(
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+    );
+    let low_mask = _mm256_set1_epi8(0x0f);
+
+    let low = _mm256_and_si256(v, low_mask);
+    let high = _mm256_and_si256(_mm256_srli_epi16(v, 4), low_mask);
+    _mm256_add_epi8(
+        _mm256_shuffle_epi8(lookup, low),
+        _mm256_shuffle_epi8(lookup, high),
+    )
+}
+
+/// Sum of 4x64 bit integers
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+pub(super) fn hsum256_epi64(v: __m256i) -> u64 {
+    let sum = _mm_add_epi64(_mm256_castsi256_si128(v), _mm256_extracti128_si256(v, 1));
+    (_mm_cvtsi128_si64(sum) + _mm_extract_epi64(sum, 1)) as u64
+}
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_hamming_similarity_bytes(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    unsafe {
+        // bit count accumulator for 4x64 bit integers
+        let mut acc = _mm256_setzero_si256();
+        let len = v1.len();
+        for _ in 0..len / 32 {
+            // load 32 bytes
+            let p1 = _mm256_loadu_si256(ptr1.cast::<__m256i>());
+            let p2 = _mm256_loadu_si256(ptr2.cast::<__m256i>());
+            ptr1 = ptr1.add(32);
+            ptr2 = ptr2.add(32);
+
+            // count differing bits per byte and sum them horizontally into accumulator
+            let bits = popcount_epi8(_mm256_xor_si256(p1, p2));
+            acc = _mm256_add_epi64(acc, _mm256_sad_epu8(bits, _mm256_setzero_si256()));
+        }
+
+        let mut score = hsum256_epi64(acc) as u32;
+        for _ in 0..len % 32 {
+            score += (*ptr1 ^ *ptr2).count_ones();
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+        }
+
+        -(score as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_uint::simple_hamming::hamming_similarity_bytes;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx") && is_x86_feature_detected!("avx2") {
+            let v1: Vec<u8> = (0..=255).chain(0..=44).collect();
+            let v2: Vec<u8> = (0..=255).rev().chain(100..=144).collect();
+
+            let hamming_simd = unsafe { avx_hamming_similarity_bytes(&v1, &v2) };
+            let hamming = hamming_similarity_bytes(&v1, &v2);
+            assert_eq!(hamming_simd, hamming);
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
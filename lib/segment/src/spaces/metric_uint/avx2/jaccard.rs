@@ -0,0 +1,70 @@
+use std::arch::x86_64::*;
+
+use super::hamming::{hsum256_epi64, popcount_epi8};
+use crate::spaces::simple::jaccard_score;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_jaccard_similarity_bytes(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    unsafe {
+        // bit count accumulators for 4x64 bit integers
+        let mut intersection_acc = _mm256_setzero_si256();
+        let mut union_acc = _mm256_setzero_si256();
+        let len = v1.len();
+        for _ in 0..len / 32 {
+            // load 32 bytes
+            let p1 = _mm256_loadu_si256(ptr1.cast::<__m256i>());
+            let p2 = _mm256_loadu_si256(ptr2.cast::<__m256i>());
+            ptr1 = ptr1.add(32);
+            ptr2 = ptr2.add(32);
+
+            // count bits set in both and in any of bytes, sum them horizontally into accumulators
+            let intersection = popcount_epi8(_mm256_and_si256(p1, p2));
+            let union = popcount_epi8(_mm256_or_si256(p1, p2));
+            intersection_acc = _mm256_add_epi64(
+                intersection_acc,
+                _mm256_sad_epu8(intersection, _mm256_setzero_si256()),
+            );
+            union_acc = _mm256_add_epi64(union_acc, _mm256_sad_epu8(union, _mm256_setzero_si256()));
+        }
+
+        let mut intersection = hsum256_epi64(intersection_acc) as u32;
+        let mut union = hsum256_epi64(union_acc) as u32;
+        for _ in 0..len % 32 {
+            intersection += (*ptr1 & *ptr2).count_ones();
+            union += (*ptr1 | *ptr2).count_ones();
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+        }
+
+        jaccard_score(intersection, union)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_uint::simple_jaccard::jaccard_similarity_bytes;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx") && is_x86_feature_detected!("avx2") {
+            let v1: Vec<u8> = (0..=255).chain(0..=44).collect();
+            let v2: Vec<u8> = (0..=255).rev().chain(100..=144).collect();
+
+            let jaccard_simd = unsafe { avx_jaccard_similarity_bytes(&v1, &v2) };
+            let jaccard = jaccard_similarity_bytes(&v1, &v2);
+            assert_eq!(jaccard_simd, jaccard);
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::arch::aarch64::*;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_hamming_similarity_bytes(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    unsafe {
+        let mut score = 0u32;
+        let len = v1.len();
+        for _ in 0..len / 16 {
+            let p1 = vld1q_u8(ptr1);
+            let p2 = vld1q_u8(ptr2);
+            ptr1 = ptr1.add(16);
+            ptr2 = ptr2.add(16);
+
+            // count differing bits per byte and sum them horizontally
+            let bits = vcntq_u8(veorq_u8(p1, p2));
+            score += u32::from(vaddlvq_u8(bits));
+        }
+
+        for _ in 0..len % 16 {
+            score += (*ptr1 ^ *ptr2).count_ones();
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+        }
+
+        -(score as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_uint::simple_hamming::hamming_similarity_bytes;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let v1: Vec<u8> = (0..=255).chain(0..=44).collect();
+            let v2: Vec<u8> = (0..=255).rev().chain(100..=144).collect();
+
+            let hamming_simd = unsafe { neon_hamming_similarity_bytes(&v1, &v2) };
+            let hamming = hamming_similarity_bytes(&v1, &v2);
+            assert_eq!(hamming_simd, hamming);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
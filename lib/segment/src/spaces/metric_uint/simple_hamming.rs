@@ -0,0 +1,97 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeByte};
+use crate::spaces::metric::Metric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_uint::avx2::hamming::avx_hamming_similarity_bytes;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_uint::neon::hamming::neon_hamming_similarity_bytes;
+use crate::spaces::simple::HammingMetric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeByte> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("avx2")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { avx_hamming_similarity_bytes(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_hamming_similarity_bytes(v1, v2) };
+            }
+        }
+
+        hamming_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn hamming_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    -(count_bits_bytes(v1, v2, |a, b| a ^ b) as ScoreType)
+}
+
+/// Number of bits set in `op` of all pairs of bytes, processed by 8 bytes at once
+pub(super) fn count_bits_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+    op: impl Fn(u64, u64) -> u64,
+) -> u32 {
+    let chunks1 = v1.chunks_exact(size_of::<u64>());
+    let chunks2 = v2.chunks_exact(size_of::<u64>());
+    let remainder = std::iter::zip(chunks1.remainder(), chunks2.remainder())
+        .map(|(&a, &b)| op(u64::from(a), u64::from(b)).count_ones())
+        .sum::<u32>();
+
+    std::iter::zip(chunks1, chunks2)
+        .map(|(a, b)| {
+            let a = u64::from_le_bytes(a.try_into().unwrap());
+            let b = u64::from_le_bytes(b.try_into().unwrap());
+            op(a, b).count_ones()
+        })
+        .sum::<u32>()
+        + remainder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hamming_similarity_bytes() {
+        let v1: Vec<u8> = (0..=255).collect();
+        let v2: Vec<u8> = (0..=255).rev().collect();
+
+        let expected = v1
+            .iter()
+            .zip(&v2)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum::<u32>();
+        assert_eq!(hamming_similarity_bytes(&v1, &v2), -(expected as ScoreType));
+        assert_eq!(
+            <HammingMetric as Metric<VectorElementTypeByte>>::similarity(&v1[3..], &v2[3..]),
+            hamming_similarity_bytes(&v1[3..], &v2[3..]),
+        );
+        assert_eq!(hamming_similarity_bytes(&v1, &v1), 0.0);
+    }
+}
@@ -0,0 +1,81 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeByte};
+use crate::spaces::metric::Metric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_uint::avx2::jaccard::avx_jaccard_similarity_bytes;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_uint::neon::jaccard::neon_jaccard_similarity_bytes;
+use crate::spaces::metric_uint::simple_hamming::count_bits_bytes;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+use crate::spaces::simple::{JaccardMetric, jaccard_score};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeByte> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("avx2")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { avx_jaccard_similarity_bytes(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_jaccard_similarity_bytes(v1, v2) };
+            }
+        }
+
+        jaccard_similarity_bytes(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn jaccard_similarity_bytes(
+    v1: &[VectorElementTypeByte],
+    v2: &[VectorElementTypeByte],
+) -> ScoreType {
+    jaccard_score(
+        count_bits_bytes(v1, v2, |a, b| a & b),
+        count_bits_bytes(v1, v2, |a, b| a | b),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jaccard_similarity_bytes() {
+        let v1: Vec<u8> = (0..=255).collect();
+        let v2: Vec<u8> = (0..=255).rev().collect();
+
+        let (intersection, union) = v1.iter().zip(&v2).fold((0, 0), |(i, u), (a, b)| {
+            (i + (a & b).count_ones(), u + (a | b).count_ones())
+        });
+        assert_eq!(
+            jaccard_similarity_bytes(&v1, &v2),
+            intersection as ScoreType / union as ScoreType - 1.0,
+        );
+        assert_eq!(
+            <JaccardMetric as Metric<VectorElementTypeByte>>::similarity(&v1[3..], &v2[3..]),
+            jaccard_similarity_bytes(&v1[3..], &v2[3..]),
+        );
+        assert_eq!(jaccard_similarity_bytes(&v1, &v1), 0.0);
+        assert_eq!(jaccard_similarity_bytes(&[0; 4], &[0; 4]), 0.0);
+    }
+}
@@ -33,6 +33,14 @@ pub struct EuclidMetric;
 #[derive(Clone)]
 pub struct ManhattanMetric;
 
+/// Number of differing bits of bit-packed binary vectors
+#[derive(Clone)]
+pub struct HammingMetric;
+
+/// Share of differing bits among bits set in any of bit-packed binary vectors
+#[derive(Clone)]
+pub struct JaccardMetric;
+
 impl Metric<VectorElementType> for EuclidMetric {
     fn distance() -> Distance {
         Distance::Euclid
@@ -121,6 +129,46 @@ impl MetricPostProcessing for ManhattanMetric {
     }
 }
 
+impl Metric<VectorElementType> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        hamming_similarity(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for HammingMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+impl Metric<VectorElementType> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        jaccard_similarity(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for JaccardMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
 impl Metric<VectorElementType> for DotProductMetric {
     fn distance() -> Distance {
         Distance::Dot
@@ -225,6 +273,39 @@ pub fn manhattan_similarity(v1: &[VectorElementType], v2: &[VectorElementType])
         .sum::<ScoreType>()
 }
 
+/// Hamming similarity of binary vectors, each element of which holds a byte of packed bits
+pub fn hamming_similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+    -(v1.iter()
+        .zip(v2)
+        .map(|(&a, &b)| ((a as u8) ^ (b as u8)).count_ones())
+        .sum::<u32>() as ScoreType)
+}
+
+/// Jaccard similarity of binary vectors, each element of which holds a byte of packed bits
+pub fn jaccard_similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+    let (intersection, union) =
+        v1.iter()
+            .zip(v2)
+            .fold((0, 0), |(intersection, union), (&a, &b)| {
+                let (a, b) = (a as u8, b as u8);
+                (
+                    intersection + (a & b).count_ones(),
+                    union + (a | b).count_ones(),
+                )
+            });
+    jaccard_score(intersection, union)
+}
+
+/// Negated Jaccard distance for the number of bits set in both and in any of the vectors
+///
+/// Vectors without any bits set are considered identical.
+pub fn jaccard_score(intersection: u32, union: u32) -> ScoreType {
+    if union == 0 {
+        return 0.0;
+    }
+    intersection as ScoreType / union as ScoreType - 1.0
+}
+
 pub fn cosine_preprocess(vector: DenseVector) -> DenseVector {
     let mut length: f32 = vector.iter().map(|x| x * x).sum();
     if is_length_zero_or_normalized(length) {
@@ -275,4 +356,28 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_binary_similarity() {
+        let v1 = vec![
+            0b1010_1010 as VectorElementType,
+            0b0000_1111 as VectorElementType,
+        ];
+        let v2 = vec![
+            0b1010_0101 as VectorElementType,
+            0b0000_0011 as VectorElementType,
+        ];
+
+        // 4 bits differ in the first byte and 2 in the second one
+        let hamming = <HammingMetric as Metric<VectorElementType>>::similarity(&v1, &v2);
+        assert_eq!(HammingMetric::postprocess(hamming), 6.0);
+
+        // 4 bits of 10 set in any of vectors are set in both
+        let jaccard = <JaccardMetric as Metric<VectorElementType>>::similarity(&v1, &v2);
+        assert!((JaccardMetric::postprocess(jaccard) - 0.6).abs() < 1e-6);
+
+        let zeros = vec![0.0; 2];
+        assert_eq!(jaccard_similarity(&zeros, &zeros), 0.0);
+        assert_eq!(jaccard_similarity(&v1, &v1), 0.0);
+    }
 }
@@ -63,6 +63,9 @@ pub struct VectorIndexSearchesTelemetry {
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub filtered_large_cardinality: OperationDurationStatistics,
 
+    #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
+    pub filtered_two_hop: OperationDurationStatistics,
+
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub filtered_exact: OperationDurationStatistics,
 
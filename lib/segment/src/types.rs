@@ -30,6 +30,7 @@ use zerocopy::native_endian::U64;
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::{self, MaybeOneOrMany, MultiValue};
+use crate::data_types::highlight::PointHighlight;
 use crate::data_types::index::{
     BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams,
     KeywordIndexParams, TextIndexParams, UuidIndexParams,
@@ -38,10 +39,13 @@ use crate::data_types::order_by::OrderValue;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{DenseVector, VectorStructInternal};
 use crate::index::field_index::CardinalityEstimation;
+use crate::index::field_index::full_text_index::fuzzy::MAX_FUZZY_EDITS;
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
 use crate::json_path::JsonPath;
 use crate::spaces::metric::{Metric, MetricPostProcessing};
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::utils::unordered_hash_unique;
 use crate::utils::maybe_arc::MaybeArc;
 
@@ -297,6 +301,10 @@ pub enum Distance {
     Dot,
     // <https://simple.wikipedia.org/wiki/Manhattan_distance>
     Manhattan,
+    // <https://en.wikipedia.org/wiki/Hamming_distance>
+    Hamming,
+    // <https://en.wikipedia.org/wiki/Jaccard_index>
+    Jaccard,
 }
 
 impl Distance {
@@ -306,6 +314,8 @@ impl Distance {
             Distance::Euclid => EuclidMetric::postprocess(score),
             Distance::Dot => DotProductMetric::postprocess(score),
             Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Hamming => HammingMetric::postprocess(score),
+            Distance::Jaccard => JaccardMetric::postprocess(score),
         }
     }
 
@@ -315,19 +325,33 @@ impl Distance {
         EuclidMetric: Metric<T>,
         DotProductMetric: Metric<T>,
         ManhattanMetric: Metric<T>,
+        HammingMetric: Metric<T>,
+        JaccardMetric: Metric<T>,
     {
         match self {
             Distance::Cosine => CosineMetric::preprocess(vector),
             Distance::Euclid => EuclidMetric::preprocess(vector),
             Distance::Dot => DotProductMetric::preprocess(vector),
             Distance::Manhattan => ManhattanMetric::preprocess(vector),
+            Distance::Hamming => HammingMetric::preprocess(vector),
+            Distance::Jaccard => JaccardMetric::preprocess(vector),
         }
     }
 
     pub fn distance_order(&self) -> Order {
         match self {
             Distance::Cosine | Distance::Dot => Order::LargeBetter,
-            Distance::Euclid | Distance::Manhattan => Order::SmallBetter,
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming | Distance::Jaccard => {
+                Order::SmallBetter
+            }
+        }
+    }
+
+    /// Whether this distance compares bit-packed binary vectors
+    pub fn is_binary(&self) -> bool {
+        match self {
+            Distance::Hamming | Distance::Jaccard => true,
+            Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => false,
         }
     }
 
@@ -363,6 +387,8 @@ pub struct ScoredPoint {
     pub shard_key: Option<ShardKey>,
     /// Order-by value
     pub order_value: Option<OrderValue>,
+    /// Text matches of the point, if highlighting is requested
+    pub highlight: Option<PointHighlight>,
 }
 
 impl Eq for ScoredPoint {}
@@ -490,6 +516,9 @@ pub struct QuantizationSearchParams {
     ///
     /// For example, if `oversampling` is 2.4 and `limit` is 100, then 240 vectors will be pre-selected using quantized index,
     /// and then top-100 will be returned after re-scoring.
+    ///
+    /// For RaBitQ quantization, pre-selected vectors are re-scored only while their estimated error bounds
+    /// allow them to get into the top. Default for RaBitQ is 4.0.
     #[serde(default = "default_quantization_oversampling_value")]
     #[validate(range(min = 1.0))]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -543,6 +572,40 @@ pub struct SearchParams {
     /// guarantee that all uploaded vectors will be included in search results
     #[serde(default)]
     pub indexed_only: bool,
+
+    /// Params relevant to IVF index
+    /// Number of inverted lists probed during the search. Larger the value - more accurate the result, more time required for search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub ivf_nprobe: Option<usize>,
+
+    /// Params relevant to HNSW index
+    /// Number of first dimensions of dense vectors used to traverse the graph, for Matryoshka
+    /// embeddings. Found points are rescored with all dimensions. If not set, the dimensions the
+    /// graph was built with are used. Ignored for graphs built with all dimensions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub matryoshka_dim: Option<usize>,
+
+    /// Params relevant to sparse index
+    /// Algorithm used to traverse posting lists of the query. Default: batch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sparse_algorithm: Option<SparseSearchAlgorithm>,
+}
+
+/// Algorithm used to traverse posting lists of a sparse vector index
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SparseSearchAlgorithm {
+    /// Score all posting lists in batches of ids, pruning only the longest posting list
+    #[default]
+    Batch,
+    /// Block-Max WAND: skip ids and blocks of posting lists that can't make it to the top
+    /// results, according to upper bounds of the weights. Faster for long queries.
+    BlockMaxWand,
+    /// MaxScore: only ids from posting lists with large upper bounds of the weights are
+    /// considered as candidates. Faster for long queries.
+    MaxScore,
 }
 
 /// Configuration for vectors.
@@ -563,6 +626,18 @@ pub enum Indexes {
     /// Use filterable HNSW index for approximate search. Is very fast even on a very huge collections,
     /// but require additional space to store index and additional time to build it.
     Hnsw(HnswConfig),
+    /// Use index managed by VDE (Vector Data Engine).
+    /// Only available if Qdrant is built with the `vde` feature.
+    #[cfg_attr(not(feature = "vde"), schemars(skip))]
+    Vde(VdeIndexConfig),
+    /// Use DiskANN index: a single layer Vamana graph stored on disk along with full vectors.
+    /// Only product quantized vectors used to navigate the graph are kept in RAM, which allows to
+    /// search collections that don't fit into RAM.
+    DiskAnn(DiskAnnConfig),
+    /// Use IVF index: vectors are clustered around coarse centroids, search only scores vectors of
+    /// the few inverted lists closest to the query. Much cheaper to build than graph indexes,
+    /// which makes it suitable for huge segments with heavy write load.
+    Ivf(IvfConfig),
 }
 
 impl Indexes {
@@ -570,6 +645,9 @@ impl Indexes {
         match self {
             Indexes::Plain {} => false,
             Indexes::Hnsw(_) => true,
+            Indexes::Vde(_) => true,
+            Indexes::DiskAnn(_) => true,
+            Indexes::Ivf(_) => true,
         }
     }
 
@@ -577,10 +655,188 @@ impl Indexes {
         match self {
             Indexes::Plain {} => false,
             Indexes::Hnsw(config) => config.on_disk.unwrap_or_default(),
+            Indexes::Vde(config) => config.storage_type.is_on_disk(),
+            Indexes::DiskAnn(config) => config.on_disk.unwrap_or(true),
+            Indexes::Ivf(config) => config.on_disk.unwrap_or_default(),
         }
     }
 }
 
+/// Config of index managed by VDE
+#[derive(
+    Debug, Default, Deserialize, Serialize, JsonSchema, Anonymize, Clone, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+#[anonymize(false)]
+pub struct VdeIndexConfig {
+    /// Type of index built by VDE. Default: `vsag_hnsw`
+    #[serde(default)]
+    pub index_type: VdeIndexType,
+    /// Storage VDE keeps indexed vectors in. Default: `zendb`
+    #[serde(default)]
+    pub storage_type: VdeStorageType,
+    /// Engine specific tuning parameters, a JSON object passed to VDE as-is.
+    /// If not set, VDE defaults are used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_json: Option<String>,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over VDE search.
+    /// When the maximum estimated amount of points that a filter satisfies is smaller than
+    /// this threshold, the filtered points are scored directly instead of querying VDE.
+    /// If not set, `full_scan_threshold` of HNSW default config is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_scan_threshold: Option<usize>,
+}
+
+/// Type of index built by VDE
+#[derive(
+    Debug, Default, Deserialize, Serialize, JsonSchema, Anonymize, Clone, Copy, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VdeIndexType {
+    /// HNSW index of the VSAG library
+    #[default]
+    VsagHnsw,
+}
+
+impl VdeIndexType {
+    /// Name of the index type as understood by VDE
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VdeIndexType::VsagHnsw => "vsag_hnsw",
+        }
+    }
+}
+
+/// Storage VDE keeps indexed vectors in
+#[derive(
+    Debug, Default, Deserialize, Serialize, JsonSchema, Anonymize, Clone, Copy, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VdeStorageType {
+    /// Persistent ZenDB (Btrieve2) storage
+    #[default]
+    Zendb,
+    /// Storage in memory (RAM)
+    Memory,
+}
+
+impl VdeStorageType {
+    /// Name of the storage type as understood by VDE
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VdeStorageType::Zendb => "zendb",
+            VdeStorageType::Memory => "memory",
+        }
+    }
+
+    pub fn is_on_disk(&self) -> bool {
+        match self {
+            VdeStorageType::Zendb => true,
+            VdeStorageType::Memory => false,
+        }
+    }
+}
+
+/// Config of DiskANN index
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case", default)]
+#[anonymize(false)]
+pub struct DiskAnnConfig {
+    /// Maximal number of edges per node in the Vamana graph. Larger the value - more accurate the search, more space required. Default: 64
+    #[validate(range(min = 4))]
+    pub max_degree: usize,
+    /// Size of the candidate list used during the index building. Larger the value - more accurate the graph, more time required to build index. Default: 100
+    #[validate(range(min = 4))]
+    pub build_list_size: usize,
+    /// Size of the candidate list used during the search if `hnsw_ef` search param is not set. Default: 100
+    #[validate(range(min = 1))]
+    pub search_list_size: usize,
+    /// Number of graph nodes read from disk at once during the beam search. Default: 4
+    #[validate(range(min = 1, max = 16))]
+    pub beam_width: usize,
+    /// Compression ratio of product quantized vectors kept in RAM to navigate the graph. Default: x16
+    pub pq_compression: CompressionRatio,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over graph search.
+    /// If not set, `full_scan_threshold` of HNSW default config is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_scan_threshold: Option<usize>,
+    /// Store graph and full vectors on disk. If set to false, they are loaded into RAM. Default: true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_disk: Option<bool>,
+}
+
+impl Default for DiskAnnConfig {
+    fn default() -> Self {
+        Self {
+            max_degree: 64,
+            build_list_size: 100,
+            search_list_size: 100,
+            beam_width: 4,
+            pq_compression: CompressionRatio::X16,
+            full_scan_threshold: None,
+            on_disk: None,
+        }
+    }
+}
+
+/// Config of IVF index
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case", default)]
+#[anonymize(false)]
+pub struct IvfConfig {
+    /// Number of inverted lists, i.e. coarse centroids vectors are clustered around.
+    /// If not set, `4 * sqrt(N)` lists are built for `N` indexed vectors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub nlist: Option<usize>,
+    /// Number of inverted lists probed during the search if `ivf_nprobe` search param is not set. Default: 16
+    #[validate(range(min = 1))]
+    pub nprobe: usize,
+    /// Quantization of vector residuals relative to their centroids.
+    /// If set, probed vectors are scored by quantized residuals first and only the best of them are rescored with original vectors.
+    /// If not set, probed vectors are scored with original vectors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub residuals: Option<IvfResiduals>,
+    /// Compression ratio of product quantized residuals, only used with `product` residuals. Default: x16
+    pub pq_compression: CompressionRatio,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over IVF search.
+    /// If not set, `full_scan_threshold` of HNSW default config is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_scan_threshold: Option<usize>,
+    /// Store inverted lists on disk. If set to false, they are loaded into RAM. Default: false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_disk: Option<bool>,
+}
+
+impl Default for IvfConfig {
+    fn default() -> Self {
+        Self {
+            nlist: None,
+            nprobe: 16,
+            residuals: None,
+            pq_compression: CompressionRatio::X16,
+            full_scan_threshold: None,
+            on_disk: None,
+        }
+    }
+}
+
+/// Quantization of vector residuals in IVF index
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, Clone, Copy, PartialEq, Eq, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum IvfResiduals {
+    /// Scalar quantization of residuals into `int8`
+    Scalar,
+    /// Product quantization of residuals
+    Product,
+}
+
 /// Config of HNSW index
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -617,6 +873,12 @@ pub struct HnswConfig {
     /// Requires quantized vectors to be enabled. Multi-vectors are not supported.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub copy_vectors: Option<bool>,
+    /// Build the graph on the first N dimensions of dense vectors, for Matryoshka embeddings.
+    /// Searches traverse the graph with the same dimensions and rescore found points with all
+    /// dimensions. Ignored for quantized vectors. If not set, all dimensions are used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub matryoshka_dim: Option<usize>,
 }
 
 impl HnswConfig {
@@ -637,6 +899,7 @@ impl HnswConfig {
             payload_m,
             on_disk,
             copy_vectors,
+            matryoshka_dim,
         } = self.clone();
 
         m != other.m
@@ -648,6 +911,7 @@ impl HnswConfig {
             // to flip this flag
             || on_disk != other.on_disk
             || copy_vectors != other.copy_vectors
+            || matryoshka_dim != other.matryoshka_dim
     }
 }
 
@@ -688,6 +952,7 @@ pub enum CompressionRatio {
 pub enum ScalarType {
     #[default]
     Int8,
+    Int4,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
@@ -695,6 +960,7 @@ pub enum ScalarType {
 pub struct ScalarQuantizationConfig {
     /// Type of quantization to use
     /// If `int8` - 8 bit quantization will be used
+    /// If `int4` - 4 bit quantization will be used, halving memory at the cost of precision
     pub r#type: ScalarType,
     /// Quantile for quantization. Expected value range in [0.5, 1.0]. If not set - use the whole range of values
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -729,6 +995,12 @@ pub struct ProductQuantizationConfig {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub always_ram: Option<bool>,
+
+    /// If true - learn an orthogonal rotation (OPQ) together with the codebooks,
+    /// which improves accuracy for vectors with correlated dimensions.
+    /// Ignored for Manhattan distance. Default: false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opq: Option<bool>,
 }
 
 impl ProductQuantizationConfig {
@@ -794,6 +1066,21 @@ pub struct BinaryQuantization {
     pub binary: BinaryQuantizationConfig,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct RabitqQuantizationConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub always_ram: Option<bool>,
+}
+
+/// RaBitQ quantization: random rotation, one bit per dimension and per-vector correction factors.
+/// Estimated scores come with error bounds, which define how many candidates are rescored.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
+pub struct RabitqQuantization {
+    #[validate(nested)]
+    pub rabitq: RabitqQuantizationConfig,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Anonymize, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged, rename_all = "snake_case")]
 #[anonymize(false)]
@@ -801,6 +1088,7 @@ pub enum QuantizationConfig {
     Scalar(ScalarQuantization),
     Product(ProductQuantization),
     Binary(BinaryQuantization),
+    Rabitq(RabitqQuantization),
 }
 
 impl QuantizationConfig {
@@ -824,6 +1112,7 @@ impl Validate for QuantizationConfig {
             QuantizationConfig::Scalar(scalar) => scalar.validate(),
             QuantizationConfig::Product(product) => product.validate(),
             QuantizationConfig::Binary(binary) => binary.validate(),
+            QuantizationConfig::Rabitq(rabitq) => rabitq.validate(),
         }
     }
 }
@@ -859,6 +1148,12 @@ impl From<BinaryQuantizationConfig> for QuantizationConfig {
     }
 }
 
+impl From<RabitqQuantizationConfig> for QuantizationConfig {
+    fn from(config: RabitqQuantizationConfig) -> Self {
+        QuantizationConfig::Rabitq(RabitqQuantization { rabitq: config })
+    }
+}
+
 #[derive(
     Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Default, Merge, Hash,
 )]
@@ -1275,6 +1570,7 @@ impl Default for HnswConfig {
             on_disk: Some(false),
             payload_m: None,
             copy_vectors: None,
+            matryoshka_dim: None,
         }
     }
 }
@@ -1346,10 +1642,15 @@ impl SegmentConfig {
     /// This grabs the quantization config for the given vector name if it exists.
     ///
     /// If no quantization is configured, `None` is returned.
+    /// Binary vectors are never quantized, they are as compact as binary quantization already.
+    /// Vectors with Manhattan distance are not quantized with RaBitQ, which does not support it.
     pub fn quantization_config(&self, vector_name: &VectorName) -> Option<&QuantizationConfig> {
-        self.vector_data
-            .get(vector_name)
-            .and_then(|v| v.quantization_config.as_ref())
+        let vector_data = self.vector_data.get(vector_name)?;
+        let quantization_config = vector_data.quantization_config.as_ref()?;
+        let unsupported = vector_data.distance.is_binary()
+            || (vector_data.distance == Distance::Manhattan
+                && matches!(quantization_config, QuantizationConfig::Rabitq(_)));
+        (!unsupported).then_some(quantization_config)
     }
 
     /// Check if any vector storages are indexed
@@ -1401,7 +1702,9 @@ impl SegmentConfig {
 }
 
 /// Storage types for vectors
-#[derive(Debug, Deserialize, Serialize, JsonSchema, Anonymize, Eq, PartialEq, Copy, Clone, Hash)]
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, Eq, PartialEq, Copy, Clone, Hash,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum VectorStorageType {
     /// Storage in memory (RAM)
@@ -1423,10 +1726,16 @@ pub enum VectorStorageType {
     InRamChunkedMmap,
     /// VDE (Vector Data Engine) - uses VSAG HNSW index with Btrieve2/Memory storage
     ///
-    /// Experimental storage backend with integrated HNSW index
+    /// Experimental storage backend with integrated HNSW index.
+    /// Only available if Qdrant is built with the `vde` feature.
+    #[cfg_attr(not(feature = "vde"), schemars(skip))]
     Vde,
 }
 
+/// Error message for configs requesting VDE on a build without the `vde` feature
+pub const VDE_NOT_SUPPORTED_MESSAGE: &str =
+    "VDE is requested, but Qdrant was built without VDE support";
+
 #[cfg(any(test, feature = "testing"))]
 #[allow(clippy::derivable_impls)]
 impl Default for VectorStorageType {
@@ -1455,6 +1764,12 @@ pub enum VectorStorageDatatype {
     Float16,
     // Unsigned 8-bit integer
     Uint8,
+    // Bit-packed binary vector, each dimension is a byte holding 8 bits.
+    // Stored as `Uint8`, compared with `Hamming` or `Jaccard` distance only.
+    Binary,
+    // Brain floating point, 16-bit with the exponent range of `Float32`
+    #[serde(rename = "bfloat16")]
+    BFloat16,
 }
 
 #[derive(
@@ -1464,6 +1779,10 @@ pub enum VectorStorageDatatype {
 pub struct MultiVectorConfig {
     /// How to compare multivector points
     pub comparator: MultiVectorComparator,
+    /// Reduce count of vectors in each multivector at ingest time.
+    /// If not set - all vectors are stored as is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_pooling: Option<TokenPoolingConfig>,
 }
 
 #[derive(
@@ -1471,8 +1790,24 @@ pub struct MultiVectorConfig {
 )]
 #[serde(rename_all = "snake_case")]
 pub enum MultiVectorComparator {
+    /// Sum of max similarities of each query vector, as in ColBERT
     #[default]
     MaxSim,
+    /// `max_sim` divided by count of query vectors, comparable between queries of different length
+    MaxSimNormalized,
+    /// Symmetric Chamfer similarity: average of `max_sim_normalized` in both directions
+    Chamfer,
+}
+
+/// Token pooling config. Multivectors with more vectors than `target_count` are reduced
+/// by hierarchical clustering of their vectors, each cluster is replaced by its mean vector.
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, Eq, PartialEq, Copy, Clone, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub struct TokenPoolingConfig {
+    /// Max count of vectors stored for each multivector. Must be positive.
+    pub target_count: usize,
 }
 
 impl VectorStorageType {
@@ -1515,13 +1850,16 @@ impl VectorDataConfig {
         let is_index_appendable = match self.index {
             Indexes::Plain {} => true,
             Indexes::Hnsw(_) => false,
+            Indexes::Vde(_) => false,
+            Indexes::DiskAnn(_) => false,
+            Indexes::Ivf(_) => false,
         };
         let is_storage_appendable = match self.storage_type {
             VectorStorageType::Memory => true,
             VectorStorageType::Mmap => false,
             VectorStorageType::ChunkedMmap => true,
             VectorStorageType::InRamChunkedMmap => true,
-            VectorStorageType::Vde => false,  // VDE manages its own storage
+            VectorStorageType::Vde => false, // VDE manages its own storage
         };
         is_index_appendable && is_storage_appendable
     }
@@ -1983,7 +2321,7 @@ impl Validate for PayloadSchemaParams {
             PayloadSchemaParams::Integer(integer_index_params) => integer_index_params.validate(),
             PayloadSchemaParams::Float(_) => Ok(()),
             PayloadSchemaParams::Geo(_) => Ok(()),
-            PayloadSchemaParams::Text(_) => Ok(()),
+            PayloadSchemaParams::Text(text_index_params) => text_index_params.validate(),
             PayloadSchemaParams::Bool(_) => Ok(()),
             PayloadSchemaParams::Datetime(_) => Ok(()),
             PayloadSchemaParams::Uuid(_) => Ok(()),
@@ -2286,6 +2624,24 @@ impl<S: Into<String>> From<S> for MatchPhrase {
     }
 }
 
+/// Full-text match of the string, tolerating typos.
+///
+/// Each token of the text matches tokens within `max_edits` insertions, deletions or substitutions.
+/// All tokens within `max_edits` match, with and without a full-text index,
+/// so a short token with many edits may match a large part of the vocabulary.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchFuzzy {
+    pub text: String,
+
+    /// Maximal number of edits per token, at most 2.
+    pub max_edits: usize,
+
+    /// Number of leading characters of each token which must match exactly. Default: 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix_len: Option<usize>,
+}
+
 /// Exact match on any of the given values
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -2305,6 +2661,8 @@ pub struct MatchExcept {
 #[serde(untagged, rename_all = "snake_case")]
 pub enum MatchInterface {
     Value(MatchValue),
+    // Must go before `Text`, which would otherwise accept the same `text` field
+    Fuzzy(MatchFuzzy),
     Text(MatchText),
     TextAny(MatchTextAny),
     Phrase(MatchPhrase),
@@ -2317,6 +2675,7 @@ pub enum MatchInterface {
 #[serde(untagged, from = "MatchInterface")]
 pub enum Match {
     Value(MatchValue),
+    Fuzzy(MatchFuzzy),
     Text(MatchText),
     TextAny(MatchTextAny),
     Phrase(MatchPhrase),
@@ -2367,6 +2726,7 @@ impl From<MatchInterface> for Match {
                 except: except.except,
             }),
             MatchInterface::Phrase(MatchPhrase { phrase }) => Self::Phrase(MatchPhrase { phrase }),
+            MatchInterface::Fuzzy(fuzzy) => Self::Fuzzy(fuzzy),
         }
     }
 }
@@ -2917,18 +3277,27 @@ impl FieldCondition {
             Match::Text(_) => 0,
             Match::Phrase(_) => 0,
             Match::TextAny(_) => 0,
+            Match::Fuzzy(_) => 0,
         }
     }
 }
 
 pub fn validate_field_condition(field_condition: &FieldCondition) -> Result<(), ValidationError> {
     if field_condition.all_fields_none() {
-        Err(ValidationError::new(
+        return Err(ValidationError::new(
             "At least one field condition must be specified",
-        ))
-    } else {
-        Ok(())
+        ));
     }
+
+    if let Some(Match::Fuzzy(fuzzy)) = &field_condition.r#match
+        && fuzzy.max_edits > MAX_FUZZY_EDITS
+    {
+        return Err(ValidationError::new(
+            "Fuzzy match supports at most 2 edits per token",
+        ));
+    }
+
+    Ok(())
 }
 
 /// Payload field
@@ -0,0 +1,184 @@
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use common::types::{PointOffsetType, ScoredPointOffset};
+
+use super::error::{VdeError, VdeResult};
+use crate::types::{Distance, SearchParams, VdeIndexConfig};
+
+/// Index type VDE uses unless configured otherwise
+pub const DEFAULT_VDE_INDEX_TYPE: &str = "vsag_hnsw";
+
+/// Storage type VDE uses unless configured otherwise
+pub const DEFAULT_VDE_STORAGE_TYPE: &str = "zendb";
+
+/// Configuration a VDE collection is created with
+#[derive(Debug, Clone, PartialEq)]
+pub struct VdeCollectionConfig {
+    pub index_type: String,
+    pub storage_type: String,
+    pub dimension: usize,
+    pub distance: Distance,
+    /// Engine specific tuning parameters, passed to VDE as-is
+    pub config_json: Option<String>,
+}
+
+impl VdeCollectionConfig {
+    pub fn new(dimension: usize, distance: Distance) -> Self {
+        Self {
+            index_type: DEFAULT_VDE_INDEX_TYPE.to_string(),
+            storage_type: DEFAULT_VDE_STORAGE_TYPE.to_string(),
+            dimension,
+            distance,
+            config_json: None,
+        }
+    }
+
+    pub fn with_storage_type(mut self, storage_type: impl Into<String>) -> Self {
+        self.storage_type = storage_type.into();
+        self
+    }
+
+    pub fn with_config_json(mut self, config_json: Option<String>) -> Self {
+        self.config_json = config_json;
+        self
+    }
+
+    /// Use index type, storage type and tuning parameters of the given index config
+    pub fn with_index_config(self, index_config: &VdeIndexConfig) -> Self {
+        let VdeIndexConfig {
+            index_type,
+            storage_type,
+            config_json,
+            full_scan_threshold: _,
+        } = index_config;
+        Self {
+            index_type: index_type.as_str().to_string(),
+            storage_type: storage_type.as_str().to_string(),
+            config_json: config_json.clone(),
+            ..self
+        }
+    }
+}
+
+/// Name of the distance metric as understood by VDE
+pub fn distance_metric_name(distance: Distance) -> &'static str {
+    match distance {
+        Distance::Cosine => "cosine",
+        Distance::Euclid => "euclidean",
+        Distance::Dot => "dot",
+        Distance::Manhattan => "manhattan",
+        Distance::Hamming => "hamming",
+        Distance::Jaccard => "jaccard",
+    }
+}
+
+/// Search parameters forwarded to VDE
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VdeSearchParams {
+    /// Size of the beam in a HNSW search, engine default if `None`
+    pub hnsw_ef: Option<usize>,
+    /// Search without the approximate index
+    pub exact: bool,
+}
+
+impl From<&SearchParams> for VdeSearchParams {
+    fn from(params: &SearchParams) -> Self {
+        Self {
+            hnsw_ef: params.hnsw_ef,
+            exact: params.exact,
+        }
+    }
+}
+
+/// A VDE engine instance, owning all collections within one working directory
+pub trait VdeEngine: Debug + Send + Sync {
+    fn work_dir(&self) -> &Path;
+
+    /// Create a new collection, fails if a collection with the same name already exists
+    fn create_collection(
+        &self,
+        name: &str,
+        config: &VdeCollectionConfig,
+    ) -> VdeResult<Arc<dyn VdeCollection>>;
+
+    /// Open an existing collection, `None` if there is no collection with such name
+    fn open_collection(
+        &self,
+        name: &str,
+        config: &VdeCollectionConfig,
+    ) -> VdeResult<Option<Arc<dyn VdeCollection>>>;
+
+    fn open_or_create_collection(
+        &self,
+        name: &str,
+        config: &VdeCollectionConfig,
+    ) -> VdeResult<Arc<dyn VdeCollection>> {
+        match self.open_collection(name, config)? {
+            Some(collection) => Ok(collection),
+            None => self.create_collection(name, config),
+        }
+    }
+}
+
+/// Handle to a single VDE collection
+///
+/// Points are addressed by their internal offset within the segment.
+/// Scores are returned in Qdrant's internal convention, where a larger score is always better.
+pub trait VdeCollection: Debug + Send + Sync {
+    fn name(&self) -> &str;
+
+    fn dimension(&self) -> usize;
+
+    /// Insert or replace the vector and/or payload of a point
+    ///
+    /// A `None` vector or payload leaves the stored value untouched.
+    fn upsert(
+        &self,
+        id: PointOffsetType,
+        vector: Option<&[f32]>,
+        payload: Option<&str>,
+    ) -> VdeResult<()>;
+
+    fn delete(&self, id: PointOffsetType) -> VdeResult<()>;
+
+    fn get_vector(&self, id: PointOffsetType) -> VdeResult<Option<Vec<f32>>>;
+
+    /// Get payload of a point, serialized as JSON
+    fn get_payload(&self, id: PointOffsetType) -> VdeResult<Option<String>>;
+
+    /// Search `top` nearest points, optionally restricted by a JSON serialized filter
+    fn search(
+        &self,
+        query: &[f32],
+        top: usize,
+        params: &VdeSearchParams,
+        filter: Option<&str>,
+    ) -> VdeResult<Vec<ScoredPointOffset>>;
+
+    /// Number of points with a vector
+    fn vector_count(&self) -> usize;
+
+    /// Persist pending changes
+    fn flush(&self) -> VdeResult<()>;
+
+    /// Persist the collection including its index
+    fn save_snapshot(&self) -> VdeResult<()>;
+
+    /// All files of the collection within the engine work dir, as persisted by the last flush
+    ///
+    /// Copying these files into another work dir is enough to open the collection from there.
+    fn files(&self) -> VdeResult<Vec<PathBuf>>;
+
+    fn check_dimension(&self, received_dim: usize) -> VdeResult<()> {
+        let expected_dim = self.dimension();
+        if received_dim != expected_dim {
+            return Err(VdeError::WrongDimension {
+                expected_dim,
+                received_dim,
+            });
+        }
+        Ok(())
+    }
+}
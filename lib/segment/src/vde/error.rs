@@ -0,0 +1,136 @@
+use std::ffi::NulError;
+
+use io::file_operations::FileStorageError;
+use thiserror::Error;
+
+use crate::common::operation_error::OperationError;
+
+pub type VdeResult<T> = Result<T, VdeError>;
+
+/// Errors reported by the VDE engine, either translated from the C return codes or raised by the
+/// safe wrapper itself.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VdeError {
+    #[error("VDE {operation} failed: invalid argument")]
+    InvalidArgument { operation: &'static str },
+    #[error("VDE {operation} failed: not found")]
+    NotFound { operation: &'static str },
+    #[error("VDE {operation} failed: already exists")]
+    AlreadyExists { operation: &'static str },
+    #[error("VDE {operation} failed: out of memory")]
+    OutOfMemory { operation: &'static str },
+    #[error("VDE {operation} failed: I/O error")]
+    Io { operation: &'static str },
+    #[error("VDE {operation} failed: not supported")]
+    NotSupported { operation: &'static str },
+    #[error("VDE {operation} failed with code {code}")]
+    Other { operation: &'static str, code: i32 },
+    #[error("VDE returned a null handle for {operation}")]
+    NullHandle { operation: &'static str },
+    #[error("VDE vector dimension error: expected dim: {expected_dim}, got {received_dim}")]
+    WrongDimension {
+        expected_dim: usize,
+        received_dim: usize,
+    },
+    #[error("VDE filter is not valid: {0}")]
+    InvalidFilter(String),
+    #[error("VDE string argument contains an interior NUL byte: {0}")]
+    InvalidString(String),
+    #[error("VDE path is not valid UTF-8: {0}")]
+    InvalidPath(String),
+    #[error("VDE storage error: {0}")]
+    Storage(String),
+}
+
+/// Return codes of the VDE C API, as defined in `vde.h`
+#[cfg(feature = "vde-native")]
+pub mod code {
+    pub const OK: i32 = vde_sys::VDE_OK as i32;
+    pub const INVALID_ARGUMENT: i32 = vde_sys::VDE_ERR_INVALID_ARGUMENT as i32;
+    pub const NOT_FOUND: i32 = vde_sys::VDE_ERR_NOT_FOUND as i32;
+    pub const ALREADY_EXISTS: i32 = vde_sys::VDE_ERR_ALREADY_EXISTS as i32;
+    pub const OUT_OF_MEMORY: i32 = vde_sys::VDE_ERR_OUT_OF_MEMORY as i32;
+    pub const IO_ERROR: i32 = vde_sys::VDE_ERR_IO as i32;
+    pub const NOT_SUPPORTED: i32 = vde_sys::VDE_ERR_NOT_SUPPORTED as i32;
+}
+
+impl VdeError {
+    /// Convert a return code of a VDE C call into a result
+    #[cfg(feature = "vde-native")]
+    pub fn check(operation: &'static str, ret: i32) -> VdeResult<()> {
+        match ret {
+            code::OK => Ok(()),
+            code::INVALID_ARGUMENT => Err(VdeError::InvalidArgument { operation }),
+            code::NOT_FOUND => Err(VdeError::NotFound { operation }),
+            code::ALREADY_EXISTS => Err(VdeError::AlreadyExists { operation }),
+            code::OUT_OF_MEMORY => Err(VdeError::OutOfMemory { operation }),
+            code::IO_ERROR => Err(VdeError::Io { operation }),
+            code::NOT_SUPPORTED => Err(VdeError::NotSupported { operation }),
+            code => Err(VdeError::Other { operation, code }),
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, VdeError::NotFound { .. })
+    }
+}
+
+impl From<NulError> for VdeError {
+    fn from(err: NulError) -> Self {
+        VdeError::InvalidString(err.to_string())
+    }
+}
+
+impl From<FileStorageError> for VdeError {
+    fn from(err: FileStorageError) -> Self {
+        VdeError::Storage(err.to_string())
+    }
+}
+
+impl From<VdeError> for OperationError {
+    fn from(err: VdeError) -> Self {
+        match err {
+            VdeError::WrongDimension {
+                expected_dim,
+                received_dim,
+            } => OperationError::WrongVectorDimension {
+                expected_dim,
+                received_dim,
+            },
+            VdeError::InvalidArgument { .. }
+            | VdeError::InvalidFilter(_)
+            | VdeError::InvalidString(_)
+            | VdeError::InvalidPath(_) => OperationError::validation_error(err.to_string()),
+            VdeError::NotFound { .. }
+            | VdeError::AlreadyExists { .. }
+            | VdeError::OutOfMemory { .. }
+            | VdeError::Io { .. }
+            | VdeError::NotSupported { .. }
+            | VdeError::Other { .. }
+            | VdeError::NullHandle { .. }
+            | VdeError::Storage(_) => OperationError::service_error(err.to_string()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "vde-native"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_return_codes() {
+        assert_eq!(VdeError::check("search", code::OK), Ok(()));
+        assert!(
+            VdeError::check("get_vector", code::NOT_FOUND)
+                .unwrap_err()
+                .is_not_found()
+        );
+        assert_eq!(
+            VdeError::check("flush", 42),
+            Err(VdeError::Other {
+                operation: "flush",
+                code: 42,
+            }),
+        );
+    }
+}
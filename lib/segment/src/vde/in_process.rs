@@ -0,0 +1,446 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
+use io::file_operations::{atomic_save_bin, read_bin};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+
+use super::engine::{VdeCollection, VdeCollectionConfig, VdeEngine, VdeSearchParams};
+use super::error::{VdeError, VdeResult};
+use crate::common::utils::IndexesMap;
+use crate::data_types::vectors::VectorElementType;
+use crate::payload_storage::query_checker::check_payload;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
+use crate::types::{Distance, Filter, OwnedPayloadRef, Payload};
+
+/// Extension of files the in-process engine persists collections to
+pub const IN_PROCESS_COLLECTION_EXTENSION: &str = "vdemem";
+
+/// Pure-Rust stand-in for the native VDE engine
+///
+/// Implements the same contract as the C++ engine with exact search over in-memory vectors, and
+/// persists every collection into a single file on flush. Allows to run VDE backed segments on
+/// machines without the VDE library, primarily in tests.
+#[derive(Debug)]
+pub struct InProcessEngine {
+    work_dir: PathBuf,
+    collections: Mutex<HashMap<String, Arc<InProcessCollection>>>,
+}
+
+impl InProcessEngine {
+    pub fn open(work_dir: &Path) -> VdeResult<Self> {
+        std::fs::create_dir_all(work_dir).map_err(|err| {
+            VdeError::Storage(format!(
+                "failed to create VDE work dir {}: {err}",
+                work_dir.display(),
+            ))
+        })?;
+        Ok(Self {
+            work_dir: work_dir.to_path_buf(),
+            collections: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn collection_path(&self, name: &str) -> PathBuf {
+        self.work_dir
+            .join(format!("{name}.{IN_PROCESS_COLLECTION_EXTENSION}"))
+    }
+}
+
+impl VdeEngine for InProcessEngine {
+    fn work_dir(&self) -> &Path {
+        &self.work_dir
+    }
+
+    fn create_collection(
+        &self,
+        name: &str,
+        config: &VdeCollectionConfig,
+    ) -> VdeResult<Arc<dyn VdeCollection>> {
+        let mut collections = self.collections.lock();
+        let path = self.collection_path(name);
+        if collections.contains_key(name) || path.exists() {
+            return Err(VdeError::AlreadyExists {
+                operation: "collection_create",
+            });
+        }
+
+        let collection = Arc::new(InProcessCollection {
+            name: name.to_string(),
+            path,
+            data: RwLock::new(CollectionData {
+                dimension: config.dimension,
+                distance: config.distance,
+                points: BTreeMap::new(),
+            }),
+        });
+        collections.insert(name.to_string(), collection.clone());
+        Ok(collection)
+    }
+
+    fn open_collection(
+        &self,
+        name: &str,
+        config: &VdeCollectionConfig,
+    ) -> VdeResult<Option<Arc<dyn VdeCollection>>> {
+        let mut collections = self.collections.lock();
+        if let Some(collection) = collections.get(name) {
+            collection.check_dimension(config.dimension)?;
+            return Ok(Some(collection.clone()));
+        }
+
+        let path = self.collection_path(name);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let data: CollectionData = read_bin(&path)?;
+        let collection = Arc::new(InProcessCollection {
+            name: name.to_string(),
+            path,
+            data: RwLock::new(data),
+        });
+        collection.check_dimension(config.dimension)?;
+        collections.insert(name.to_string(), collection.clone());
+        Ok(Some(collection))
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct StoredPoint {
+    vector: Option<Vec<VectorElementType>>,
+    /// Payload serialized as JSON, the same way it crosses the C API
+    payload: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CollectionData {
+    dimension: usize,
+    distance: Distance,
+    points: BTreeMap<PointOffsetType, StoredPoint>,
+}
+
+#[derive(Debug)]
+pub struct InProcessCollection {
+    name: String,
+    path: PathBuf,
+    data: RwLock<CollectionData>,
+}
+
+impl InProcessCollection {
+    fn preprocess(distance: Distance, vector: &[VectorElementType]) -> Vec<VectorElementType> {
+        match distance {
+            Distance::Cosine => {
+                <CosineMetric as Metric<VectorElementType>>::preprocess(vector.to_vec())
+            }
+            Distance::Euclid
+            | Distance::Dot
+            | Distance::Manhattan
+            | Distance::Hamming
+            | Distance::Jaccard => vector.to_vec(),
+        }
+    }
+
+    fn similarity(
+        distance: Distance,
+        v1: &[VectorElementType],
+        v2: &[VectorElementType],
+    ) -> ScoreType {
+        match distance {
+            Distance::Cosine => CosineMetric::similarity(v1, v2),
+            Distance::Euclid => EuclidMetric::similarity(v1, v2),
+            Distance::Dot => DotProductMetric::similarity(v1, v2),
+            Distance::Manhattan => ManhattanMetric::similarity(v1, v2),
+            Distance::Hamming => HammingMetric::similarity(v1, v2),
+            Distance::Jaccard => JaccardMetric::similarity(v1, v2),
+        }
+    }
+
+    fn check_filter(id: PointOffsetType, point: &StoredPoint, filter: &Filter) -> bool {
+        let payload: Payload = point
+            .payload
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        let hw_counter = HardwareCounterCell::disposable();
+        check_payload(
+            Box::new(|| OwnedPayloadRef::from(&payload)),
+            None,
+            &HashMap::new(),
+            filter,
+            id,
+            &IndexesMap::new(),
+            &hw_counter,
+        )
+    }
+}
+
+impl VdeCollection for InProcessCollection {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.data.read().dimension
+    }
+
+    fn upsert(
+        &self,
+        id: PointOffsetType,
+        vector: Option<&[f32]>,
+        payload: Option<&str>,
+    ) -> VdeResult<()> {
+        let mut data = self.data.write();
+        let vector = match vector {
+            Some(vector) if vector.len() != data.dimension => {
+                return Err(VdeError::WrongDimension {
+                    expected_dim: data.dimension,
+                    received_dim: vector.len(),
+                });
+            }
+            Some(vector) => Some(Self::preprocess(data.distance, vector)),
+            None => None,
+        };
+
+        let point = data.points.entry(id).or_default();
+        if let Some(vector) = vector {
+            point.vector = Some(vector);
+        }
+        if let Some(payload) = payload {
+            point.payload = Some(payload.to_string());
+        }
+        Ok(())
+    }
+
+    fn delete(&self, id: PointOffsetType) -> VdeResult<()> {
+        self.data.write().points.remove(&id);
+        Ok(())
+    }
+
+    fn get_vector(&self, id: PointOffsetType) -> VdeResult<Option<Vec<f32>>> {
+        Ok(self
+            .data
+            .read()
+            .points
+            .get(&id)
+            .and_then(|point| point.vector.clone()))
+    }
+
+    fn get_payload(&self, id: PointOffsetType) -> VdeResult<Option<String>> {
+        Ok(self
+            .data
+            .read()
+            .points
+            .get(&id)
+            .and_then(|point| point.payload.clone()))
+    }
+
+    fn search(
+        &self,
+        query: &[f32],
+        top: usize,
+        _params: &VdeSearchParams,
+        filter: Option<&str>,
+    ) -> VdeResult<Vec<ScoredPointOffset>> {
+        self.check_dimension(query.len())?;
+        if top == 0 {
+            return Ok(Vec::new());
+        }
+
+        let filter: Option<Filter> = filter
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|err| VdeError::InvalidFilter(err.to_string()))?;
+
+        let data = self.data.read();
+        let query = Self::preprocess(data.distance, query);
+
+        // The stand-in engine has no approximate index, every search is exact
+        let mut queue = FixedLengthPriorityQueue::new(top);
+        for (&id, point) in &data.points {
+            let Some(vector) = &point.vector else {
+                continue;
+            };
+            if let Some(filter) = &filter
+                && !Self::check_filter(id, point, filter)
+            {
+                continue;
+            }
+            queue.push(ScoredPointOffset {
+                idx: id,
+                score: Self::similarity(data.distance, &query, vector),
+            });
+        }
+        Ok(queue.into_sorted_vec())
+    }
+
+    fn vector_count(&self) -> usize {
+        self.data
+            .read()
+            .points
+            .values()
+            .filter(|point| point.vector.is_some())
+            .count()
+    }
+
+    fn flush(&self) -> VdeResult<()> {
+        let data = self.data.read();
+        atomic_save_bin(&self.path, &*data)?;
+        Ok(())
+    }
+
+    fn save_snapshot(&self) -> VdeResult<()> {
+        self.flush()
+    }
+
+    fn files(&self) -> VdeResult<Vec<PathBuf>> {
+        // Nothing is written until the first flush
+        Ok(self
+            .path
+            .exists()
+            .then(|| self.path.clone())
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::json_path::JsonPath;
+    use crate::payload_json;
+    use crate::types::{Condition, FieldCondition, Match, ValueVariants};
+    use crate::vde::{VdeBackend, shared_engine};
+
+    fn config() -> VdeCollectionConfig {
+        VdeCollectionConfig::new(2, Distance::Dot)
+    }
+
+    #[test]
+    fn test_upsert_and_search() {
+        let dir = Builder::new().prefix("vde_engine").tempdir().unwrap();
+        let engine = InProcessEngine::open(dir.path()).unwrap();
+        let collection = engine.create_collection("test", &config()).unwrap();
+
+        for (id, vector) in [[1.0, 0.0], [0.0, 1.0], [0.5, 0.5]].iter().enumerate() {
+            let payload = serde_json::to_string(&payload_json! {"even": id % 2 == 0}).unwrap();
+            collection
+                .upsert(id as PointOffsetType, Some(vector), Some(&payload))
+                .unwrap();
+        }
+        collection.delete(1).unwrap();
+
+        assert_eq!(collection.vector_count(), 2);
+        assert_eq!(collection.get_vector(1).unwrap(), None);
+        assert_eq!(collection.get_vector(2).unwrap(), Some(vec![0.5, 0.5]));
+
+        let params = VdeSearchParams::default();
+        let result = collection.search(&[1.0, 0.0], 10, &params, None).unwrap();
+        let ids: Vec<_> = result.iter().map(|point| point.idx).collect();
+        assert_eq!(ids, vec![0, 2]);
+
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new("even"),
+            Match::new_value(ValueVariants::Bool(false)),
+        )));
+        // A deleted point can be inserted again, with a new payload
+        let payload = serde_json::to_string(&payload_json! {"even": false}).unwrap();
+        collection
+            .upsert(1, Some(&[0.0, 1.0]), Some(&payload))
+            .unwrap();
+        let filter_json = serde_json::to_string(&filter).unwrap();
+        let result = collection
+            .search(&[1.0, 0.0], 10, &params, Some(&filter_json))
+            .unwrap();
+        let ids: Vec<_> = result.iter().map(|point| point.idx).collect();
+        assert_eq!(ids, vec![1]);
+
+        assert!(matches!(
+            collection.upsert(0, Some(&[1.0]), None),
+            Err(VdeError::WrongDimension { .. }),
+        ));
+        assert!(matches!(
+            collection.search(&[1.0, 0.0], 10, &params, Some("{")),
+            Err(VdeError::InvalidFilter(_)),
+        ));
+    }
+
+    #[test]
+    fn test_persistence() {
+        let dir = Builder::new().prefix("vde_engine").tempdir().unwrap();
+
+        {
+            let engine = InProcessEngine::open(dir.path()).unwrap();
+            let collection = engine.create_collection("test", &config()).unwrap();
+            collection.upsert(7, Some(&[1.0, 2.0]), Some("{}")).unwrap();
+            collection.flush().unwrap();
+        }
+
+        let engine = InProcessEngine::open(dir.path()).unwrap();
+        assert!(matches!(
+            engine.create_collection("test", &config()),
+            Err(VdeError::AlreadyExists { .. }),
+        ));
+        assert!(
+            engine
+                .open_collection("other", &config())
+                .unwrap()
+                .is_none()
+        );
+
+        let collection = engine.open_collection("test", &config()).unwrap().unwrap();
+        assert_eq!(collection.get_vector(7).unwrap(), Some(vec![1.0, 2.0]));
+        assert_eq!(collection.get_payload(7).unwrap().as_deref(), Some("{}"));
+
+        let wrong_config = VdeCollectionConfig::new(3, Distance::Dot);
+        assert!(engine.open_collection("test", &wrong_config).is_err());
+    }
+
+    #[test]
+    fn test_copy_files() {
+        let dir = Builder::new().prefix("vde_engine").tempdir().unwrap();
+        let other_dir = Builder::new().prefix("vde_engine").tempdir().unwrap();
+
+        let engine = InProcessEngine::open(dir.path()).unwrap();
+        let collection = engine.create_collection("test", &config()).unwrap();
+        collection.upsert(7, Some(&[1.0, 2.0]), None).unwrap();
+        assert!(collection.files().unwrap().is_empty());
+
+        collection.flush().unwrap();
+        let files = collection.files().unwrap();
+        assert_eq!(files.len(), 1);
+        for file in files {
+            let file_name = file.strip_prefix(dir.path()).unwrap();
+            std::fs::copy(&file, other_dir.path().join(file_name)).unwrap();
+        }
+
+        let other_engine = InProcessEngine::open(other_dir.path()).unwrap();
+        let copied = other_engine
+            .open_collection("test", &config())
+            .unwrap()
+            .unwrap();
+        assert_eq!(copied.get_vector(7).unwrap(), Some(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_shared_engine() {
+        let dir = Builder::new().prefix("vde_engine").tempdir().unwrap();
+
+        let engine = shared_engine(VdeBackend::InProcess, dir.path()).unwrap();
+        let same_engine = shared_engine(VdeBackend::InProcess, dir.path()).unwrap();
+        assert!(Arc::ptr_eq(&engine, &same_engine));
+
+        let collection = engine.create_collection("test", &config()).unwrap();
+        let same_collection = same_engine.open_collection("test", &config()).unwrap();
+        assert!(Arc::ptr_eq(&collection, &same_collection.unwrap()));
+    }
+}
@@ -0,0 +1,80 @@
+//! Safe wrapper around the VDE (Vector Data Engine) C API
+//!
+//! All VDE backed segment components talk to the engine through the [`VdeEngine`] and
+//! [`VdeCollection`] traits. They are implemented by the native engine from `vde-sys` and by a
+//! pure-Rust [`InProcessEngine`], which can stand in for the native library in tests.
+//!
+//! The native engine is only linked with the `vde-native` feature, without it every VDE backed
+//! component runs on the in-process engine.
+
+pub mod engine;
+pub mod error;
+pub mod in_process;
+#[cfg(feature = "vde-native")]
+pub mod native;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Weak};
+
+use parking_lot::Mutex;
+
+pub use engine::{VdeCollection, VdeCollectionConfig, VdeEngine, VdeSearchParams};
+pub use error::{VdeError, VdeResult};
+pub use in_process::InProcessEngine;
+#[cfg(feature = "vde-native")]
+pub use native::NativeEngine;
+
+/// Implementation of the VDE engine to use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VdeBackend {
+    /// Native C++ engine, linked through `vde-sys`
+    #[cfg(feature = "vde-native")]
+    Native,
+    /// Pure-Rust in-process stand-in engine
+    InProcess,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for VdeBackend {
+    fn default() -> Self {
+        #[cfg(feature = "vde-native")]
+        {
+            VdeBackend::Native
+        }
+        #[cfg(not(feature = "vde-native"))]
+        {
+            VdeBackend::InProcess
+        }
+    }
+}
+
+type EngineKey = (VdeBackend, PathBuf);
+
+/// Engines currently in use, by working directory
+///
+/// Holds weak references only, an engine is destroyed as soon as the last component using it is dropped.
+static SHARED_ENGINES: LazyLock<Mutex<HashMap<EngineKey, Weak<dyn VdeEngine>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Get the engine working in the given directory
+///
+/// Components of the same segment share a single engine instance, which is created on first use.
+pub fn shared_engine(backend: VdeBackend, work_dir: &Path) -> VdeResult<Arc<dyn VdeEngine>> {
+    let mut engines = SHARED_ENGINES.lock();
+    let key = (backend, work_dir.to_path_buf());
+
+    if let Some(engine) = engines.get(&key).and_then(Weak::upgrade) {
+        return Ok(engine);
+    }
+
+    let engine: Arc<dyn VdeEngine> = match backend {
+        #[cfg(feature = "vde-native")]
+        VdeBackend::Native => Arc::new(NativeEngine::create(work_dir)?),
+        VdeBackend::InProcess => Arc::new(InProcessEngine::open(work_dir)?),
+    };
+
+    engines.retain(|_, engine| engine.strong_count() > 0);
+    engines.insert(key, Arc::downgrade(&engine));
+    Ok(engine)
+}
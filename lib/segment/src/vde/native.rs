@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+use std::ffi::{CString, c_char};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+use std::{fs, ptr};
+
+use common::types::{PointOffsetType, ScoredPointOffset};
+use parking_lot::{Mutex, RwLock};
+use vde_sys::{
+    VDECollectionConfig, VDECollectionHandle, VDEEngineHandle, VDEPayload, VDESearchParams,
+    VDESearchResult, VDEVector, vde_collection_create, vde_collection_open, vde_delete_vector,
+    vde_engine_create, vde_engine_destroy, vde_flush, vde_get_vector, vde_get_vector_count,
+    vde_save_snapshot, vde_search_filtered_with_params, vde_search_with_params, vde_upsert_vector,
+};
+
+use super::engine::{
+    VdeCollection, VdeCollectionConfig, VdeEngine, VdeSearchParams, distance_metric_name,
+};
+use super::error::{VdeError, VdeResult};
+
+/// Initial buffer size for reading back payloads, larger payloads are read with a second call
+const PAYLOAD_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Owned engine handle, destroys the engine once the last reference is dropped
+#[derive(Debug)]
+struct EngineHandle(VDEEngineHandle);
+
+// SAFETY: VDE engine handles may be used from any thread, the engine synchronizes internally.
+unsafe impl Send for EngineHandle {}
+unsafe impl Sync for EngineHandle {}
+
+impl Drop for EngineHandle {
+    fn drop(&mut self) {
+        // SAFETY: handle is non-null and not used after this point, all collections keep the
+        // engine handle alive while they exist.
+        unsafe { vde_engine_destroy(self.0) }
+    }
+}
+
+impl EngineHandle {
+    fn create(work_dir: &Path) -> VdeResult<Self> {
+        let c_work_dir = path_to_cstring(work_dir)?;
+
+        // SAFETY: `c_work_dir` is a valid NUL terminated string
+        let handle = unsafe { vde_engine_create(c_work_dir.as_ptr()) };
+        if handle.is_null() {
+            return Err(VdeError::NullHandle {
+                operation: "engine_create",
+            });
+        }
+        Ok(Self(handle))
+    }
+}
+
+/// VDE engine backed by the native C++ library
+///
+/// Every collection is served by its own native engine, working in a subdirectory named after
+/// the collection. This way all files within that subdirectory belong to the collection.
+#[derive(Debug)]
+pub struct NativeEngine {
+    work_dir: PathBuf,
+    /// Opened collections, so that components sharing a collection also share its handle
+    collections: Mutex<HashMap<String, Weak<NativeCollection>>>,
+}
+
+impl NativeEngine {
+    pub fn create(work_dir: &Path) -> VdeResult<Self> {
+        fs::create_dir_all(work_dir).map_err(|err| {
+            VdeError::Storage(format!(
+                "failed to create VDE work dir {}: {err}",
+                work_dir.display(),
+            ))
+        })?;
+        Ok(Self {
+            work_dir: work_dir.to_path_buf(),
+            collections: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn collection_dir(&self, name: &str) -> PathBuf {
+        self.work_dir.join(name)
+    }
+
+    fn register(
+        &self,
+        collections: &mut HashMap<String, Weak<NativeCollection>>,
+        name: &str,
+        engine: EngineHandle,
+        handle: VDECollectionHandle,
+        dimension: usize,
+    ) -> Arc<NativeCollection> {
+        let collection = Arc::new(NativeCollection {
+            handle,
+            _engine: engine,
+            dir: self.collection_dir(name),
+            name: name.to_string(),
+            dimension,
+            lock: RwLock::new(()),
+        });
+        collections.retain(|_, collection| collection.strong_count() > 0);
+        collections.insert(name.to_string(), Arc::downgrade(&collection));
+        collection
+    }
+}
+
+impl VdeEngine for NativeEngine {
+    fn work_dir(&self) -> &Path {
+        &self.work_dir
+    }
+
+    fn create_collection(
+        &self,
+        name: &str,
+        config: &VdeCollectionConfig,
+    ) -> VdeResult<Arc<dyn VdeCollection>> {
+        let mut collections = self.collections.lock();
+        if collections.get(name).is_some_and(|c| c.strong_count() > 0) {
+            return Err(VdeError::AlreadyExists {
+                operation: "collection_create",
+            });
+        }
+
+        let dir = self.collection_dir(name);
+        if dir.exists() {
+            return Err(VdeError::AlreadyExists {
+                operation: "collection_create",
+            });
+        }
+
+        let c_name = CString::new(name)?;
+        let index_type = CString::new(config.index_type.as_str())?;
+        let storage_type = CString::new(config.storage_type.as_str())?;
+        let distance_metric = CString::new(distance_metric_name(config.distance))?;
+        let config_json = config
+            .config_json
+            .as_deref()
+            .map(CString::new)
+            .transpose()?;
+
+        let vde_config = VDECollectionConfig {
+            index_type: index_type.as_ptr(),
+            storage_type: storage_type.as_ptr(),
+            dimension: config.dimension as u32,
+            distance_metric: distance_metric.as_ptr(),
+            config_json: config_json
+                .as_ref()
+                .map_or(ptr::null(), |json| json.as_ptr()),
+        };
+
+        fs::create_dir_all(&dir).map_err(|err| {
+            VdeError::Storage(format!(
+                "failed to create VDE collection dir {}: {err}",
+                dir.display(),
+            ))
+        })?;
+        let engine = EngineHandle::create(&dir)?;
+
+        // SAFETY: all strings referenced by `vde_config` outlive the call
+        let handle =
+            unsafe { vde_collection_create(engine.0, c_name.as_ptr(), &raw const vde_config) };
+        if handle.is_null() {
+            return Err(VdeError::NullHandle {
+                operation: "collection_create",
+            });
+        }
+
+        Ok(self.register(&mut collections, name, engine, handle, config.dimension))
+    }
+
+    fn open_collection(
+        &self,
+        name: &str,
+        config: &VdeCollectionConfig,
+    ) -> VdeResult<Option<Arc<dyn VdeCollection>>> {
+        let mut collections = self.collections.lock();
+        if let Some(collection) = collections.get(name).and_then(Weak::upgrade) {
+            collection.check_dimension(config.dimension)?;
+            return Ok(Some(collection));
+        }
+
+        let dir = self.collection_dir(name);
+        if !dir.is_dir() {
+            return Ok(None);
+        }
+
+        let c_name = CString::new(name)?;
+        let engine = EngineHandle::create(&dir)?;
+
+        // SAFETY: `c_name` is a valid NUL terminated string
+        let handle = unsafe { vde_collection_open(engine.0, c_name.as_ptr()) };
+        if handle.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(self.register(
+            &mut collections,
+            name,
+            engine,
+            handle,
+            config.dimension,
+        )))
+    }
+}
+
+/// Collection of the native VDE engine
+#[derive(Debug)]
+pub struct NativeCollection {
+    handle: VDECollectionHandle,
+    /// Collection handles are owned by the engine, it must outlive them
+    _engine: EngineHandle,
+    /// Work dir of the collection's engine, holding the files of this collection only
+    dir: PathBuf,
+    name: String,
+    dimension: usize,
+    /// VDE collections don't support writes concurrent to other operations
+    lock: RwLock<()>,
+}
+
+// SAFETY: all access to the collection handle is serialized by `lock`
+unsafe impl Send for NativeCollection {}
+unsafe impl Sync for NativeCollection {}
+
+impl VdeCollection for NativeCollection {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn upsert(
+        &self,
+        id: PointOffsetType,
+        vector: Option<&[f32]>,
+        payload: Option<&str>,
+    ) -> VdeResult<()> {
+        if let Some(vector) = vector {
+            self.check_dimension(vector.len())?;
+        }
+
+        let vde_vector = vector.map(|vector| VDEVector {
+            data: vector.as_ptr().cast_mut(),
+            dim: vector.len() as u32,
+        });
+        let payload = payload.map(CString::new).transpose()?;
+        let vde_payload = payload.as_ref().map(|payload| VDEPayload {
+            json: payload.as_ptr(),
+            length: payload.as_bytes().len() as u32,
+        });
+
+        let _guard = self.lock.write();
+        // SAFETY: vector and payload buffers outlive the call, VDE does not write into them
+        let ret = unsafe {
+            vde_upsert_vector(
+                self.handle,
+                u64::from(id),
+                vde_vector.as_ref().map_or(ptr::null(), ptr::from_ref),
+                vde_payload.as_ref().map_or(ptr::null(), ptr::from_ref),
+            )
+        };
+        VdeError::check("upsert", ret)
+    }
+
+    fn delete(&self, id: PointOffsetType) -> VdeResult<()> {
+        let _guard = self.lock.write();
+        // SAFETY: handle is valid for the lifetime of `self`
+        let ret = unsafe { vde_delete_vector(self.handle, u64::from(id)) };
+        VdeError::check("delete", ret)
+    }
+
+    fn get_vector(&self, id: PointOffsetType) -> VdeResult<Option<Vec<f32>>> {
+        let mut data = vec![0.0; self.dimension];
+        let mut vde_vector = VDEVector {
+            data: data.as_mut_ptr(),
+            dim: self.dimension as u32,
+        };
+
+        let _guard = self.lock.read();
+        // SAFETY: `data` has room for `dim` elements
+        let ret = unsafe {
+            vde_get_vector(
+                self.handle,
+                u64::from(id),
+                &raw mut vde_vector,
+                ptr::null_mut(),
+            )
+        };
+        match VdeError::check("get_vector", ret) {
+            Ok(()) => Ok(Some(data)),
+            Err(err) if err.is_not_found() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn get_payload(&self, id: PointOffsetType) -> VdeResult<Option<String>> {
+        let mut buffer = vec![0u8; PAYLOAD_BUFFER_SIZE];
+
+        let _guard = self.lock.read();
+        let length = loop {
+            let mut vde_payload = VDEPayload {
+                json: buffer.as_mut_ptr().cast::<c_char>().cast_const(),
+                length: buffer.len() as u32,
+            };
+            // SAFETY: `buffer` has room for `length` bytes, VDE writes back the actual length
+            let ret = unsafe {
+                vde_get_vector(
+                    self.handle,
+                    u64::from(id),
+                    ptr::null_mut(),
+                    &raw mut vde_payload,
+                )
+            };
+            match VdeError::check("get_payload", ret) {
+                Ok(()) => {}
+                Err(err) if err.is_not_found() => return Ok(None),
+                Err(err) => return Err(err),
+            }
+
+            let length = vde_payload.length as usize;
+            if length <= buffer.len() {
+                break length;
+            }
+            // Payload didn't fit, retry with the reported size. Size can only change by a write,
+            // which is excluded by the lock, so a second retry means a misbehaving engine.
+            if buffer.len() > PAYLOAD_BUFFER_SIZE {
+                return Err(VdeError::Storage(format!(
+                    "payload of point {id} is larger than reported: {length} > {} bytes",
+                    buffer.len(),
+                )));
+            }
+            buffer.resize(length, 0);
+        };
+
+        buffer.truncate(length);
+        String::from_utf8(buffer)
+            .map(Some)
+            .map_err(|err| VdeError::Storage(format!("invalid UTF-8 in payload: {err}")))
+    }
+
+    fn search(
+        &self,
+        query: &[f32],
+        top: usize,
+        params: &VdeSearchParams,
+        filter: Option<&str>,
+    ) -> VdeResult<Vec<ScoredPointOffset>> {
+        self.check_dimension(query.len())?;
+        if top == 0 {
+            return Ok(Vec::new());
+        }
+
+        let vde_query = VDEVector {
+            data: query.as_ptr().cast_mut(),
+            dim: query.len() as u32,
+        };
+        let vde_params = VDESearchParams {
+            hnsw_ef: params.hnsw_ef.unwrap_or(0) as u32,
+            exact: params.exact.into(),
+        };
+        let filter = filter.map(CString::new).transpose()?;
+
+        let mut results = vec![
+            VDESearchResult {
+                offset: 0,
+                score: 0.0,
+            };
+            top
+        ];
+        let mut result_count: u32 = 0;
+
+        let _guard = self.lock.read();
+        // SAFETY: `results` has room for `top` elements, all inputs outlive the call
+        let ret = unsafe {
+            match &filter {
+                Some(filter) => vde_search_filtered_with_params(
+                    self.handle,
+                    &raw const vde_query,
+                    top as u32,
+                    &raw const vde_params,
+                    filter.as_ptr(),
+                    results.as_mut_ptr(),
+                    &raw mut result_count,
+                ),
+                None => vde_search_with_params(
+                    self.handle,
+                    &raw const vde_query,
+                    top as u32,
+                    &raw const vde_params,
+                    results.as_mut_ptr(),
+                    &raw mut result_count,
+                ),
+            }
+        };
+        VdeError::check("search", ret)?;
+
+        results.truncate((result_count as usize).min(top));
+        Ok(results
+            .into_iter()
+            .map(|result| ScoredPointOffset {
+                idx: result.offset as PointOffsetType,
+                score: result.score,
+            })
+            .collect())
+    }
+
+    fn vector_count(&self) -> usize {
+        let _guard = self.lock.read();
+        // SAFETY: handle is valid for the lifetime of `self`
+        unsafe { vde_get_vector_count(self.handle) as usize }
+    }
+
+    fn flush(&self) -> VdeResult<()> {
+        let _guard = self.lock.write();
+        // SAFETY: handle is valid for the lifetime of `self`
+        let ret = unsafe { vde_flush(self.handle) };
+        VdeError::check("flush", ret)
+    }
+
+    fn save_snapshot(&self) -> VdeResult<()> {
+        let _guard = self.lock.write();
+        // SAFETY: handle is valid for the lifetime of `self`
+        let ret = unsafe { vde_save_snapshot(self.handle) };
+        VdeError::check("save_snapshot", ret)
+    }
+
+    fn files(&self) -> VdeResult<Vec<PathBuf>> {
+        let _guard = self.lock.read();
+        let mut files = common::disk::list_files(&self.dir).map_err(|err| {
+            VdeError::Storage(format!(
+                "failed to list VDE collection dir {}: {err}",
+                self.dir.display(),
+            ))
+        })?;
+        files.sort();
+        Ok(files)
+    }
+}
+
+fn path_to_cstring(path: &Path) -> VdeResult<CString> {
+    let path = path
+        .to_str()
+        .ok_or_else(|| VdeError::InvalidPath(path.display().to_string()))?;
+    Ok(CString::new(path)?)
+}
@@ -244,6 +244,18 @@ pub fn open_appendable_memmap_vector_storage_half(
     )))
 }
 
+pub fn open_appendable_memmap_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_appendable_memmap_vector_storage_impl(path, dim, distance)?;
+
+    Ok(VectorStorageEnum::DenseAppendableMemmapBFloat16(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_memmap_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
@@ -284,7 +296,10 @@ pub fn open_appendable_in_ram_vector_storage(
         VectorStorageDatatype::Float16 => {
             open_appendable_in_ram_vector_storage_half(path, dim, distance)
         }
-        VectorStorageDatatype::Uint8 => {
+        VectorStorageDatatype::BFloat16 => {
+            open_appendable_in_ram_vector_storage_bf16(path, dim, distance)
+        }
+        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
             open_appendable_in_ram_vector_storage_byte(path, dim, distance)
         }
     }
@@ -325,6 +340,18 @@ fn open_appendable_in_ram_vector_storage_half(
     )))
 }
 
+fn open_appendable_in_ram_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_appendable_in_ram_vector_storage_impl(path, dim, distance)?;
+
+    Ok(VectorStorageEnum::DenseAppendableInRamBFloat16(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_in_ram_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
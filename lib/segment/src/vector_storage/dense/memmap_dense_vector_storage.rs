@@ -91,6 +91,16 @@ pub fn open_memmap_vector_storage_half(
     Ok(VectorStorageEnum::DenseMemmapHalf(storage))
 }
 
+pub fn open_memmap_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_memmap_vector_storage_with_async_io_impl(path, dim, distance, get_async_scorer())?;
+    Ok(VectorStorageEnum::DenseMemmapBFloat16(storage))
+}
+
 pub fn open_memmap_vector_storage_with_async_io(
     path: &Path,
     dim: usize,
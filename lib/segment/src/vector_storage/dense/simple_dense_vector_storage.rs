@@ -112,13 +112,18 @@ pub fn open_simple_dense_vector_storage(
             distance,
             stopped,
         ),
-        VectorStorageDatatype::Uint8 => open_simple_dense_byte_vector_storage(
-            database,
-            database_column_name,
-            dim,
-            distance,
-            stopped,
-        ),
+        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
+            open_simple_dense_byte_vector_storage(
+                database,
+                database_column_name,
+                dim,
+                distance,
+                stopped,
+            )
+        }
+        VectorStorageDatatype::BFloat16 => Err(OperationError::service_error(
+            "BFloat16 datatype is not supported by the simple vector storage",
+        )),
     }
 }
 
@@ -10,7 +10,7 @@ mod vector_storage_base;
 mod tests;
 
 #[cfg(target_os = "linux")]
-mod async_io;
+pub(crate) mod async_io;
 mod async_io_mock;
 mod bitvec;
 pub mod chunked_vector_storage;
@@ -21,6 +21,7 @@ pub mod multi_dense;
 pub mod query;
 pub mod query_scorer;
 pub mod sparse;
+#[cfg(feature = "vde")]
 pub mod vde_storage;
 
 pub use raw_scorer::*;
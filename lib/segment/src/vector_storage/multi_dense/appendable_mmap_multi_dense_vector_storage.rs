@@ -329,13 +329,21 @@ pub fn open_appendable_memmap_multi_vector_storage(
             distance,
             multi_vector_config,
         ),
-        VectorStorageDatatype::Uint8 => open_appendable_memmap_multi_vector_storage_byte(
+        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
+            open_appendable_memmap_multi_vector_storage_byte(
+                path,
+                dim,
+                distance,
+                multi_vector_config,
+            )
+        }
+        VectorStorageDatatype::Float16 => open_appendable_memmap_multi_vector_storage_half(
             path,
             dim,
             distance,
             multi_vector_config,
         ),
-        VectorStorageDatatype::Float16 => open_appendable_memmap_multi_vector_storage_half(
+        VectorStorageDatatype::BFloat16 => open_appendable_memmap_multi_vector_storage_bf16(
             path,
             dim,
             distance,
@@ -390,6 +398,20 @@ pub fn open_appendable_memmap_multi_vector_storage_half(
     )))
 }
 
+pub fn open_appendable_memmap_multi_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_appendable_memmap_multi_vector_storage_impl(path, dim, distance, multi_vector_config)?;
+
+    Ok(VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(
+        Box::new(storage),
+    ))
+}
+
 pub fn open_appendable_memmap_multi_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
@@ -449,12 +471,20 @@ pub fn open_appendable_in_ram_multi_vector_storage(
             distance,
             multi_vector_config,
         ),
-        VectorStorageDatatype::Uint8 => open_appendable_in_ram_multi_vector_storage_byte(
+        VectorStorageDatatype::BFloat16 => open_appendable_in_ram_multi_vector_storage_bf16(
             path,
             dim,
             distance,
             multi_vector_config,
         ),
+        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
+            open_appendable_in_ram_multi_vector_storage_byte(
+                path,
+                dim,
+                distance,
+                multi_vector_config,
+            )
+        }
     }
 }
 
@@ -504,6 +534,20 @@ pub fn open_appendable_in_ram_multi_vector_storage_half(
     )))
 }
 
+pub fn open_appendable_in_ram_multi_vector_storage_bf16(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_appendable_in_ram_multi_vector_storage_impl(path, dim, distance, multi_vector_config)?;
+
+    Ok(VectorStorageEnum::MultiDenseAppendableInRamBFloat16(
+        Box::new(storage),
+    ))
+}
+
 pub fn open_appendable_in_ram_multi_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
@@ -83,14 +83,16 @@ pub fn open_simple_multi_dense_vector_storage(
             multi_vector_config,
             stopped,
         ),
-        VectorStorageDatatype::Uint8 => open_simple_multi_dense_vector_storage_byte(
-            database,
-            database_column_name,
-            dim,
-            distance,
-            multi_vector_config,
-            stopped,
-        ),
+        VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
+            open_simple_multi_dense_vector_storage_byte(
+                database,
+                database_column_name,
+                dim,
+                distance,
+                multi_vector_config,
+                stopped,
+            )
+        }
         VectorStorageDatatype::Float16 => open_simple_multi_dense_vector_storage_half(
             database,
             database_column_name,
@@ -99,6 +101,9 @@ pub fn open_simple_multi_dense_vector_storage(
             multi_vector_config,
             stopped,
         ),
+        VectorStorageDatatype::BFloat16 => Err(OperationError::service_error(
+            "BFloat16 datatype is not supported by the simple vector storage",
+        )),
     }
 }
 
@@ -0,0 +1,165 @@
+use crate::data_types::vectors::{DenseVector, MultiDenseVectorInternal, VectorElementType};
+
+/// Reduce count of vectors in a multivector to `target_count`.
+///
+/// Vectors are grouped by agglomerative hierarchical clustering with Ward linkage:
+/// on each step, the pair of clusters with the smallest increase of within-cluster variance
+/// is merged. Each resulting cluster is replaced by the mean of its vectors.
+///
+/// Returns `None` if the multivector has not more than `target_count` vectors.
+pub fn pool_tokens(
+    multi_vector: &MultiDenseVectorInternal,
+    target_count: usize,
+) -> Option<MultiDenseVectorInternal> {
+    let count = multi_vector.vectors_count();
+    let target_count = target_count.max(1);
+    if count <= target_count {
+        return None;
+    }
+
+    let mut clusters: Vec<Option<Cluster>> = multi_vector
+        .multi_vectors()
+        .map(|vector| {
+            Some(Cluster {
+                centroid: vector.to_vec(),
+                size: 1,
+            })
+        })
+        .collect();
+
+    // Cheapest merge for each cluster as `(cost, other cluster index)`
+    let mut nearest: Vec<(f32, usize)> = (0..count)
+        .map(|index| find_nearest(&clusters, index))
+        .collect();
+
+    for _ in target_count..count {
+        let Some((merged, &(_, absorbed))) = nearest
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| clusters[*index].is_some())
+            .min_by(|(_, (cost_a, _)), (_, (cost_b, _))| cost_a.total_cmp(cost_b))
+        else {
+            break;
+        };
+        debug_assert_ne!(merged, absorbed);
+
+        let Some(absorbed_cluster) = clusters[absorbed].take() else {
+            break;
+        };
+        if let Some(merged_cluster) = clusters[merged].as_mut() {
+            merged_cluster.merge(absorbed_cluster);
+        }
+
+        // Ward linkage is reducible: merged cluster is never closer to other clusters than
+        // its parts, so only clusters which pointed to the merged pair need an update
+        for index in 0..count {
+            if clusters[index].is_some()
+                && (index == merged || nearest[index].1 == merged || nearest[index].1 == absorbed)
+            {
+                nearest[index] = find_nearest(&clusters, index);
+            }
+        }
+    }
+
+    let pooled: DenseVector = clusters
+        .into_iter()
+        .flatten()
+        .flat_map(|cluster| cluster.centroid)
+        .collect();
+    Some(MultiDenseVectorInternal::new(pooled, multi_vector.dim))
+}
+
+struct Cluster {
+    centroid: DenseVector,
+    size: usize,
+}
+
+impl Cluster {
+    /// Increase of the total within-cluster variance if `self` and `other` are merged
+    fn ward_cost(&self, other: &Cluster) -> f32 {
+        let squared_distance: VectorElementType = self
+            .centroid
+            .iter()
+            .zip(&other.centroid)
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum();
+        let (size_a, size_b) = (self.size as f32, other.size as f32);
+        size_a * size_b / (size_a + size_b) * squared_distance
+    }
+
+    fn merge(&mut self, other: Cluster) {
+        let total = (self.size + other.size) as f32;
+        let (weight_a, weight_b) = (self.size as f32 / total, other.size as f32 / total);
+        for (a, b) in self.centroid.iter_mut().zip(&other.centroid) {
+            *a = *a * weight_a + b * weight_b;
+        }
+        self.size += other.size;
+    }
+}
+
+fn find_nearest(clusters: &[Option<Cluster>], index: usize) -> (f32, usize) {
+    let Some(cluster) = &clusters[index] else {
+        return (f32::INFINITY, index);
+    };
+    clusters
+        .iter()
+        .enumerate()
+        .filter(|(other_index, _)| *other_index != index)
+        .filter_map(|(other_index, other)| {
+            other
+                .as_ref()
+                .map(|other| (cluster.ward_cost(other), other_index))
+        })
+        .min_by(|(cost_a, _), (cost_b, _)| cost_a.total_cmp(cost_b))
+        .unwrap_or((f32::INFINITY, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: MultiDenseVectorInternal, expected: Vec<Vec<f32>>) {
+        let actual = actual.into_multi_vectors();
+        assert_eq!(actual.len(), expected.len(), "{actual:?}");
+        for (a, b) in actual.iter().flatten().zip(expected.iter().flatten()) {
+            assert!((a - b).abs() < 1e-4, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_pool_tokens_merges_closest() {
+        let multi_vector = MultiDenseVectorInternal::new_unchecked(vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.9, 0.1],
+            vec![0.1, 0.9],
+            vec![-1.0, -1.0],
+        ]);
+
+        let pooled = pool_tokens(&multi_vector, 3).unwrap();
+        assert_eq!(pooled.dim, 2);
+        assert_close(
+            pooled,
+            vec![vec![0.95, 0.05], vec![0.05, 0.95], vec![-1.0, -1.0]],
+        );
+    }
+
+    #[test]
+    fn test_pool_tokens_target_count() {
+        let multi_vector = MultiDenseVectorInternal::new_unchecked(
+            (0..10).map(|i| vec![i as f32, (i * i) as f32]).collect(),
+        );
+
+        assert!(pool_tokens(&multi_vector, 10).is_none());
+        assert!(pool_tokens(&multi_vector, 20).is_none());
+
+        for target_count in [1, 3, 9] {
+            let pooled = pool_tokens(&multi_vector, target_count).unwrap();
+            assert_eq!(pooled.vectors_count(), target_count);
+        }
+
+        // Single cluster is the mean of all vectors
+        let pooled = pool_tokens(&multi_vector, 1).unwrap();
+        assert_close(pooled, vec![vec![4.5, 28.5]]);
+    }
+}
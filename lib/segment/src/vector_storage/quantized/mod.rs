@@ -5,6 +5,6 @@ mod quantized_multi_custom_query_scorer;
 mod quantized_multi_query_scorer;
 pub mod quantized_multivector_storage;
 pub mod quantized_query_scorer;
-mod quantized_ram_storage;
+pub(crate) mod quantized_ram_storage;
 mod quantized_scorer_builder;
 pub mod quantized_vectors;
@@ -365,10 +365,77 @@ where
         sum
     }
 
+    /// Custom `score_chamfer_similarity` implementation for quantized vectors
+    fn score_point_chamfer_similarity(
+        &self,
+        query: &Vec<QuantizedStorage::EncodedQuery>,
+        vector_index: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        let offset = self.offsets.get_offset(vector_index);
+        let mut max_sims = vec![ScoreType::NEG_INFINITY; offset.count as usize];
+        let mut sum = 0.0;
+        for inner_query in query {
+            let mut max_sim = ScoreType::NEG_INFINITY;
+            for (i, max_sim_stored) in max_sims.iter_mut().enumerate() {
+                let sim = self.quantized_storage.score_point(
+                    inner_query,
+                    offset.start + i as PointOffsetType,
+                    hw_counter,
+                );
+                if sim > max_sim {
+                    max_sim = sim;
+                }
+                if sim > *max_sim_stored {
+                    *max_sim_stored = sim;
+                }
+            }
+            sum += max_sim;
+        }
+        let sum_stored: ScoreType = max_sims.iter().sum();
+        (sum / query.len() as ScoreType + sum_stored / offset.count as ScoreType) / 2.0
+    }
+
+    /// Custom `score_chamfer_similarity` implementation for quantized vectors
+    fn score_internal_chamfer_similarity(
+        &self,
+        vector_a_index: PointOffsetType,
+        vector_b_index: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        let offset_a = self.offsets.get_offset(vector_a_index);
+        let offset_b = self.offsets.get_offset(vector_b_index);
+        let mut max_sims_b = vec![ScoreType::NEG_INFINITY; offset_b.count as usize];
+        let mut sum_a = 0.0;
+        for a in 0..offset_a.count {
+            let mut max_sim = ScoreType::NEG_INFINITY;
+            for (b, max_sim_b) in max_sims_b.iter_mut().enumerate() {
+                let sim = self.quantized_storage.score_internal(
+                    offset_a.start + a,
+                    offset_b.start + b as PointOffsetType,
+                    hw_counter,
+                );
+                if sim > max_sim {
+                    max_sim = sim;
+                }
+                if sim > *max_sim_b {
+                    *max_sim_b = sim;
+                }
+            }
+            sum_a += max_sim;
+        }
+        let sum_b: ScoreType = max_sims_b.iter().sum();
+        (sum_a / offset_a.count as ScoreType + sum_b / offset_b.count as ScoreType) / 2.0
+    }
+
     pub fn inner_storage(&self) -> &QuantizedStorage {
         &self.quantized_storage
     }
 
+    pub fn multi_vector_config(&self) -> &MultiVectorConfig {
+        &self.multi_vector_config
+    }
+
     pub fn inner_vector_offset(&self, id: PointOffsetType) -> MultivectorOffset {
         self.offsets.get_offset(id)
     }
@@ -410,6 +477,12 @@ where
     ) -> ScoreType {
         match self.multi_vector_config.comparator {
             MultiVectorComparator::MaxSim => self.score_point_max_similarity(query, i, hw_counter),
+            MultiVectorComparator::MaxSimNormalized => {
+                self.score_point_max_similarity(query, i, hw_counter) / query.len() as ScoreType
+            }
+            MultiVectorComparator::Chamfer => {
+                self.score_point_chamfer_similarity(query, i, hw_counter)
+            }
         }
     }
 
@@ -421,6 +494,13 @@ where
     ) -> ScoreType {
         match self.multi_vector_config.comparator {
             MultiVectorComparator::MaxSim => self.score_internal_max_similarity(i, j, hw_counter),
+            MultiVectorComparator::MaxSimNormalized => {
+                self.score_internal_max_similarity(i, j, hw_counter)
+                    / self.offsets.get_offset(i).count as ScoreType
+            }
+            MultiVectorComparator::Chamfer => {
+                self.score_internal_chamfer_similarity(i, j, hw_counter)
+            }
         }
     }
 
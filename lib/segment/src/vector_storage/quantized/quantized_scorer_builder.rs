@@ -7,11 +7,13 @@ use super::quantized_vectors::QuantizedVectorStorage;
 use crate::common::operation_error::OperationResult;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf,
+    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType,
+    VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
 use crate::vector_storage::quantized::quantized_multi_custom_query_scorer::QuantizedMultiCustomQueryScorer;
 use crate::vector_storage::quantized::quantized_multi_query_scorer::QuantizedMultiQueryScorer;
@@ -61,8 +63,10 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementType, ManhattanMetric>()
                 }
+                Distance::Hamming => self.build_with_metric::<VectorElementType, HammingMetric>(),
+                Distance::Jaccard => self.build_with_metric::<VectorElementType, JaccardMetric>(),
             },
-            VectorStorageDatatype::Uint8 => match self.distance {
+            VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeByte, CosineMetric>(),
                 Distance::Euclid => self.build_with_metric::<VectorElementTypeByte, EuclidMetric>(),
                 Distance::Dot => {
@@ -71,6 +75,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeByte, ManhattanMetric>()
                 }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeByte, HammingMetric>()
+                }
+                Distance::Jaccard => {
+                    self.build_with_metric::<VectorElementTypeByte, JaccardMetric>()
+                }
             },
             VectorStorageDatatype::Float16 => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeHalf, CosineMetric>(),
@@ -81,6 +91,32 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeHalf, ManhattanMetric>()
                 }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeHalf, HammingMetric>()
+                }
+                Distance::Jaccard => {
+                    self.build_with_metric::<VectorElementTypeHalf, JaccardMetric>()
+                }
+            },
+            VectorStorageDatatype::BFloat16 => match self.distance {
+                Distance::Cosine => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, CosineMetric>()
+                }
+                Distance::Euclid => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, EuclidMetric>()
+                }
+                Distance::Dot => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, DotProductMetric>()
+                }
+                Distance::Manhattan => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, ManhattanMetric>()
+                }
+                Distance::Hamming => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, HammingMetric>()
+                }
+                Distance::Jaccard => {
+                    self.build_with_metric::<VectorElementTypeBFloat16, JaccardMetric>()
+                }
             },
         }
     }
@@ -118,6 +154,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::BinaryChunkedMmap(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric>(storage)
             }
+            QuantizedVectorStorage::RabitqRam(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric>(storage)
+            }
+            QuantizedVectorStorage::RabitqMmap(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric>(storage)
+            }
             QuantizedVectorStorage::ScalarRamMulti(storage) => {
                 self.new_multi_quantized_scorer::<TElement, TMetric>(storage)
             }
@@ -145,6 +187,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::BinaryChunkedMmapMulti(storage) => {
                 self.new_multi_quantized_scorer::<TElement, TMetric>(storage)
             }
+            QuantizedVectorStorage::RabitqRamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric>(storage)
+            }
+            QuantizedVectorStorage::RabitqMmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric>(storage)
+            }
         }
     }
 
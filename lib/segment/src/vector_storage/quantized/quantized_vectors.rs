@@ -1,14 +1,16 @@
 use std::alloc::Layout;
+use std::borrow::Cow;
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::PointOffsetType;
+use common::types::{PointOffsetType, ScoreType};
 use io::file_operations::{atomic_save_json, read_json};
 use memory::fadvise::clear_disk_cache;
 use quantization::encoded_vectors_binary::EncodedVectorsBin;
-use quantization::{EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
+use quantization::encoded_vectors_rabitq::DEFAULT_ERROR_BOUND_CONFIDENCE;
+use quantization::{EncodedVectors, EncodedVectorsPQ, EncodedVectorsRabitq, EncodedVectorsU8};
 use serde::{Deserialize, Serialize};
 
 use super::quantized_multivector_storage::{
@@ -18,12 +20,20 @@ use super::quantized_scorer_builder::QuantizedScorerBuilder;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::{QueryVector, VectorElementType, VectorRef};
+use crate::data_types::vectors::{
+    DenseVector, QueryVector, VectorElementType, VectorElementTypeBFloat16, VectorElementTypeByte,
+    VectorElementTypeHalf, VectorInternal, VectorRef,
+};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::{
     BinaryQuantization, BinaryQuantizationConfig, BinaryQuantizationEncoding,
-    BinaryQuantizationQueryEncoding, CompressionRatio, Distance, MultiVectorConfig,
-    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, ScalarQuantization,
-    ScalarQuantizationConfig, VectorStorageDatatype,
+    BinaryQuantizationQueryEncoding, CompressionRatio, Distance, MultiVectorConfig, Order,
+    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, RabitqQuantization,
+    RabitqQuantizationConfig, ScalarQuantization, ScalarQuantizationConfig, ScalarType,
+    VectorStorageDatatype,
 };
 use crate::vector_storage::quantized::quantized_chunked_mmap_storage::{
     QuantizedChunkedMmapStorage, QuantizedChunkedMmapStorageBuilder,
@@ -124,6 +134,15 @@ type BinaryChunkedMmapMulti = QuantizedMultivectorStorage<
     MultivectorOffsetsStorageChunkedMmap,
 >;
 
+type RabitqRamMulti = QuantizedMultivectorStorage<
+    EncodedVectorsRabitq<QuantizedRamStorage>,
+    MultivectorOffsetsStorageRam,
+>;
+type RabitqMmapMulti = QuantizedMultivectorStorage<
+    EncodedVectorsRabitq<QuantizedMmapStorage>,
+    MultivectorOffsetsStorageMmap,
+>;
+
 pub enum QuantizedVectorStorage {
     ScalarRam(EncodedVectorsU8<QuantizedRamStorage>),
     ScalarMmap(EncodedVectorsU8<QuantizedMmapStorage>),
@@ -134,6 +153,8 @@ pub enum QuantizedVectorStorage {
     BinaryRam(EncodedVectorsBin<u128, QuantizedRamStorage>),
     BinaryMmap(EncodedVectorsBin<u128, QuantizedMmapStorage>),
     BinaryChunkedMmap(EncodedVectorsBin<u128, QuantizedChunkedMmapStorage>),
+    RabitqRam(EncodedVectorsRabitq<QuantizedRamStorage>),
+    RabitqMmap(EncodedVectorsRabitq<QuantizedMmapStorage>),
     ScalarRamMulti(ScalarRamMulti),
     ScalarMmapMulti(ScalarMmapMulti),
     ScalarChunkedMmapMulti(ScalarChunkedMmapMulti),
@@ -143,6 +164,8 @@ pub enum QuantizedVectorStorage {
     BinaryRamMulti(BinaryRamMulti),
     BinaryMmapMulti(BinaryMmapMulti),
     BinaryChunkedMmapMulti(BinaryChunkedMmapMulti),
+    RabitqRamMulti(RabitqRamMulti),
+    RabitqMmapMulti(RabitqMmapMulti),
 }
 
 impl QuantizedVectorStorage {
@@ -157,6 +180,8 @@ impl QuantizedVectorStorage {
             QuantizedVectorStorage::BinaryRam(q) => q.is_on_disk(),
             QuantizedVectorStorage::BinaryMmap(q) => q.is_on_disk(),
             QuantizedVectorStorage::BinaryChunkedMmap(q) => q.is_on_disk(),
+            QuantizedVectorStorage::RabitqRam(q) => q.is_on_disk(),
+            QuantizedVectorStorage::RabitqMmap(q) => q.is_on_disk(),
             QuantizedVectorStorage::ScalarRamMulti(q) => q.is_on_disk(),
             QuantizedVectorStorage::ScalarMmapMulti(q) => q.is_on_disk(),
             QuantizedVectorStorage::ScalarChunkedMmapMulti(q) => q.is_on_disk(),
@@ -166,6 +191,8 @@ impl QuantizedVectorStorage {
             QuantizedVectorStorage::BinaryRamMulti(q) => q.is_on_disk(),
             QuantizedVectorStorage::BinaryMmapMulti(q) => q.is_on_disk(),
             QuantizedVectorStorage::BinaryChunkedMmapMulti(q) => q.is_on_disk(),
+            QuantizedVectorStorage::RabitqRamMulti(q) => q.is_on_disk(),
+            QuantizedVectorStorage::RabitqMmapMulti(q) => q.is_on_disk(),
         }
     }
 }
@@ -193,7 +220,10 @@ impl QuantizedVectors {
     pub fn default_rescoring(&self) -> bool {
         matches!(
             self.storage_impl,
-            QuantizedVectorStorage::BinaryRam(_) | QuantizedVectorStorage::BinaryMmap(_)
+            QuantizedVectorStorage::BinaryRam(_)
+                | QuantizedVectorStorage::BinaryMmap(_)
+                | QuantizedVectorStorage::RabitqRam(_)
+                | QuantizedVectorStorage::RabitqMmap(_)
         )
     }
 
@@ -208,6 +238,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRam(_) => false,
             QuantizedVectorStorage::BinaryMmap(_) => false,
             QuantizedVectorStorage::BinaryChunkedMmap(_) => false,
+            QuantizedVectorStorage::RabitqRam(_) => false,
+            QuantizedVectorStorage::RabitqMmap(_) => false,
             QuantizedVectorStorage::ScalarRamMulti(_) => true,
             QuantizedVectorStorage::ScalarMmapMulti(_) => true,
             QuantizedVectorStorage::ScalarChunkedMmapMulti(_) => true,
@@ -217,6 +249,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRamMulti(_) => true,
             QuantizedVectorStorage::BinaryMmapMulti(_) => true,
             QuantizedVectorStorage::BinaryChunkedMmapMulti(_) => true,
+            QuantizedVectorStorage::RabitqRamMulti(_) => true,
+            QuantizedVectorStorage::RabitqMmapMulti(_) => true,
         }
     }
 
@@ -234,6 +268,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRam(storage) => Ok(storage.layout()),
             QuantizedVectorStorage::BinaryMmap(storage) => Ok(storage.layout()),
             QuantizedVectorStorage::BinaryChunkedMmap(storage) => Ok(storage.layout()),
+            QuantizedVectorStorage::RabitqRam(storage) => Ok(storage.layout()),
+            QuantizedVectorStorage::RabitqMmap(storage) => Ok(storage.layout()),
             QuantizedVectorStorage::ScalarRamMulti(_)
             | QuantizedVectorStorage::ScalarMmapMulti(_)
             | QuantizedVectorStorage::ScalarChunkedMmapMulti(_)
@@ -242,6 +278,8 @@ impl QuantizedVectors {
             | QuantizedVectorStorage::PQChunkedMmapMulti(_)
             | QuantizedVectorStorage::BinaryRamMulti(_)
             | QuantizedVectorStorage::BinaryMmapMulti(_)
+            | QuantizedVectorStorage::RabitqRamMulti(_)
+            | QuantizedVectorStorage::RabitqMmapMulti(_)
             | QuantizedVectorStorage::BinaryChunkedMmapMulti(_) => {
                 Err(OperationError::service_error(
                     "Cannot get quantized vector layout from multivector storage",
@@ -261,6 +299,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRam(storage) => storage.get_quantized_vector(id),
             QuantizedVectorStorage::BinaryMmap(storage) => storage.get_quantized_vector(id),
             QuantizedVectorStorage::BinaryChunkedMmap(storage) => storage.get_quantized_vector(id),
+            QuantizedVectorStorage::RabitqRam(storage) => storage.get_quantized_vector(id),
+            QuantizedVectorStorage::RabitqMmap(storage) => storage.get_quantized_vector(id),
             QuantizedVectorStorage::ScalarRamMulti(_)
             | QuantizedVectorStorage::ScalarMmapMulti(_)
             | QuantizedVectorStorage::ScalarChunkedMmapMulti(_)
@@ -269,12 +309,108 @@ impl QuantizedVectors {
             | QuantizedVectorStorage::PQChunkedMmapMulti(_)
             | QuantizedVectorStorage::BinaryRamMulti(_)
             | QuantizedVectorStorage::BinaryMmapMulti(_)
+            | QuantizedVectorStorage::RabitqRamMulti(_)
+            | QuantizedVectorStorage::RabitqMmapMulti(_)
             | QuantizedVectorStorage::BinaryChunkedMmapMulti(_) => {
                 panic!("Cannot get quantized vector from multivector storage");
             }
         }
     }
 
+    /// Whether estimated scores of this quantization come with bounds of the estimation error.
+    ///
+    /// If so, the bounds define how many candidates are rescored with the original vectors,
+    /// see [`QuantizedVectors::score_points_with_error_bounds`].
+    pub fn has_error_bounds(&self) -> bool {
+        matches!(
+            self.storage_impl,
+            QuantizedVectorStorage::RabitqRam(_) | QuantizedVectorStorage::RabitqMmap(_)
+        )
+    }
+
+    /// Estimate scores of `points` for the nearest `query`, along with bounds of estimation errors.
+    ///
+    /// Returns `None` if the quantization or the query do not support error bounds.
+    pub fn score_points_with_error_bounds(
+        &self,
+        query: &QueryVector,
+        points: &[PointOffsetType],
+        hardware_counter: &HardwareCounterCell,
+    ) -> Option<Vec<(ScoreType, ScoreType)>> {
+        let QueryVector::Nearest(VectorInternal::Dense(query)) = query else {
+            return None;
+        };
+        let query = match self.datatype {
+            VectorStorageDatatype::Float32 => {
+                self.preprocess_query::<VectorElementType>(query.clone())
+            }
+            VectorStorageDatatype::Float16 => {
+                self.preprocess_query::<VectorElementTypeHalf>(query.clone())
+            }
+            VectorStorageDatatype::BFloat16 => {
+                self.preprocess_query::<VectorElementTypeBFloat16>(query.clone())
+            }
+            VectorStorageDatatype::Uint8 | VectorStorageDatatype::Binary => {
+                self.preprocess_query::<VectorElementTypeByte>(query.clone())
+            }
+        };
+
+        fn score<TStorage: quantization::EncodedStorage>(
+            quantized_data: &EncodedVectorsRabitq<TStorage>,
+            query: &[f32],
+            points: &[PointOffsetType],
+            hardware_counter: &HardwareCounterCell,
+        ) -> Vec<(ScoreType, ScoreType)> {
+            let query = quantized_data.encode_query(query);
+            points
+                .iter()
+                .map(|&point| {
+                    hardware_counter
+                        .vector_io_read()
+                        .incr_delta(quantized_data.quantized_vector_size());
+                    quantized_data.score_point_with_error_bound(
+                        &query,
+                        point,
+                        DEFAULT_ERROR_BOUND_CONFIDENCE,
+                        hardware_counter,
+                    )
+                })
+                .collect()
+        }
+
+        let mut hardware_counter = hardware_counter.fork();
+        hardware_counter.set_vector_io_read_multiplier(usize::from(self.storage_impl.is_on_disk()));
+        match &self.storage_impl {
+            QuantizedVectorStorage::RabitqRam(storage) => {
+                Some(score(storage, &query, points, &hardware_counter))
+            }
+            QuantizedVectorStorage::RabitqMmap(storage) => {
+                Some(score(storage, &query, points, &hardware_counter))
+            }
+            _ => None,
+        }
+    }
+
+    /// Apply the same preprocessing to the query, as [`QuantizedQueryScorer`] does.
+    fn preprocess_query<TElement: PrimitiveVectorElement>(&self, query: DenseVector) -> Vec<f32>
+    where
+        CosineMetric: Metric<TElement>,
+        EuclidMetric: Metric<TElement>,
+        DotProductMetric: Metric<TElement>,
+        ManhattanMetric: Metric<TElement>,
+        HammingMetric: Metric<TElement>,
+        JaccardMetric: Metric<TElement>,
+    {
+        let query = self.distance.preprocess_vector::<TElement>(query);
+        let query = TElement::slice_from_float_cow(Cow::Owned(query));
+        TElement::quantization_preprocess(
+            &self.config.quantization_config,
+            self.distance,
+            query.as_ref(),
+        )
+        .into_owned()
+    }
+
     pub fn raw_scorer<'a>(
         &'a self,
         query: QueryVector,
@@ -332,6 +468,12 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryChunkedMmap(storage) => {
                 build(point_id, storage, hardware_counter)
             }
+            QuantizedVectorStorage::RabitqRam(storage) => {
+                build(point_id, storage, hardware_counter)
+            }
+            QuantizedVectorStorage::RabitqMmap(storage) => {
+                build(point_id, storage, hardware_counter)
+            }
             QuantizedVectorStorage::ScalarRamMulti(storage) => {
                 build(point_id, storage, hardware_counter)
             }
@@ -359,6 +501,12 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryChunkedMmapMulti(storage) => {
                 build(point_id, storage, hardware_counter)
             }
+            QuantizedVectorStorage::RabitqRamMulti(storage) => {
+                build(point_id, storage, hardware_counter)
+            }
+            QuantizedVectorStorage::RabitqMmapMulti(storage) => {
+                build(point_id, storage, hardware_counter)
+            }
         }
     }
 
@@ -395,6 +543,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRam(q) => q.files(),
             QuantizedVectorStorage::BinaryMmap(q) => q.files(),
             QuantizedVectorStorage::BinaryChunkedMmap(q) => q.files(),
+            QuantizedVectorStorage::RabitqRam(q) => q.files(),
+            QuantizedVectorStorage::RabitqMmap(q) => q.files(),
             QuantizedVectorStorage::ScalarRamMulti(q) => q.files(),
             QuantizedVectorStorage::ScalarMmapMulti(q) => q.files(),
             QuantizedVectorStorage::ScalarChunkedMmapMulti(q) => q.files(),
@@ -404,6 +554,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRamMulti(q) => q.files(),
             QuantizedVectorStorage::BinaryMmapMulti(q) => q.files(),
             QuantizedVectorStorage::BinaryChunkedMmapMulti(q) => q.files(),
+            QuantizedVectorStorage::RabitqRamMulti(q) => q.files(),
+            QuantizedVectorStorage::RabitqMmapMulti(q) => q.files(),
         };
         files.push(self.path.join(QUANTIZED_CONFIG_PATH));
         files
@@ -420,6 +572,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRam(q) => q.immutable_files(),
             QuantizedVectorStorage::BinaryMmap(q) => q.immutable_files(),
             QuantizedVectorStorage::BinaryChunkedMmap(q) => q.immutable_files(),
+            QuantizedVectorStorage::RabitqRam(q) => q.immutable_files(),
+            QuantizedVectorStorage::RabitqMmap(q) => q.immutable_files(),
             QuantizedVectorStorage::ScalarRamMulti(q) => q.immutable_files(),
             QuantizedVectorStorage::ScalarMmapMulti(q) => q.immutable_files(),
             QuantizedVectorStorage::ScalarChunkedMmapMulti(q) => q.immutable_files(),
@@ -429,6 +583,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRamMulti(q) => q.immutable_files(),
             QuantizedVectorStorage::BinaryMmapMulti(q) => q.immutable_files(),
             QuantizedVectorStorage::BinaryChunkedMmapMulti(q) => q.immutable_files(),
+            QuantizedVectorStorage::RabitqRamMulti(q) => q.immutable_files(),
+            QuantizedVectorStorage::RabitqMmapMulti(q) => q.immutable_files(),
         };
         files.push(self.path.join(QUANTIZED_CONFIG_PATH));
         files
@@ -520,6 +676,14 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
             VectorStorageEnum::DenseAppendableMemmap(v) => Self::create_impl(
                 v.as_ref(),
                 quantization_config,
@@ -544,6 +708,14 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
             VectorStorageEnum::DenseAppendableInRam(v) => Self::create_impl(
                 v.as_ref(),
                 quantization_config,
@@ -568,6 +740,14 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => Self::create_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
             VectorStorageEnum::SparseVolatile(_) => Err(OperationError::WrongSparse),
@@ -649,6 +829,14 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => Self::create_multi_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => Self::create_multi_impl(
                 v.as_ref(),
                 quantization_config,
@@ -673,9 +861,20 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => Self::create_multi_impl(
+                v.as_ref(),
+                quantization_config,
+                storage_type,
+                path,
+                max_threads,
+                stopped,
+            ),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(_) => {
                 // VDE doesn't support quantization - return error
-                Err(OperationError::service_error("VDE doesn't support quantization"))
+                Err(OperationError::service_error(
+                    "VDE doesn't support quantization",
+                ))
             }
         }
     }
@@ -745,6 +944,18 @@ impl QuantizedVectors {
                 on_disk_vector_storage,
                 stopped,
             )?,
+            QuantizationConfig::Rabitq(RabitqQuantization {
+                rabitq: rabitq_config,
+            }) => Self::create_rabitq(
+                vectors,
+                &vector_parameters,
+                count,
+                rabitq_config,
+                storage_type,
+                path,
+                on_disk_vector_storage,
+                stopped,
+            )?,
         };
 
         let quantized_vectors_config = QuantizedVectorsConfig {
@@ -848,6 +1059,21 @@ impl QuantizedVectors {
                 on_disk_vector_storage,
                 stopped,
             )?,
+            QuantizationConfig::Rabitq(RabitqQuantization {
+                rabitq: rabitq_config,
+            }) => Self::create_rabitq_multi(
+                vectors,
+                offsets,
+                &vector_parameters,
+                vectors_count,
+                inner_vectors_count,
+                rabitq_config,
+                storage_type,
+                multi_vector_config,
+                path,
+                on_disk_vector_storage,
+                stopped,
+            )?,
         };
 
         let quantized_vectors_config = QuantizedVectorsConfig {
@@ -926,6 +1152,15 @@ impl QuantizedVectors {
                         multivector_config,
                     )?
                 }
+                QuantizationConfig::Rabitq(RabitqQuantization { rabitq }) => {
+                    Self::load_rabitq_multi(
+                        vector_storage,
+                        path,
+                        &config,
+                        rabitq,
+                        multivector_config,
+                    )?
+                }
             }
         } else {
             match &config.quantization_config {
@@ -938,6 +1173,9 @@ impl QuantizedVectors {
                 QuantizationConfig::Binary(BinaryQuantization { binary }) => {
                     Self::load_binary(vector_storage, path, &config, binary)?
                 }
+                QuantizationConfig::Rabitq(RabitqQuantization { rabitq }) => {
+                    Self::load_rabitq(vector_storage, path, &config, rabitq)?
+                }
             }
         };
 
@@ -969,8 +1207,9 @@ impl QuantizedVectors {
         let meta_path = Self::get_meta_path(path);
         if Self::is_ram(scalar_config.always_ram, on_disk_vector_storage) {
             let quantized_vector_size =
-                EncodedVectorsU8::<QuantizedRamStorage>::get_quantized_vector_size(
-                    &config.vector_parameters,
+                EncodedVectorsU8::<QuantizedRamStorage>::get_quantized_vector_size_from_params(
+                    config.vector_parameters.dim,
+                    Self::convert_scalar_type(&scalar_config.r#type),
                 );
             let quantized_vectors_storage =
                 QuantizedRamStorage::from_file(data_path.as_path(), quantized_vector_size)?;
@@ -980,8 +1219,9 @@ impl QuantizedVectors {
             )?))
         } else {
             let quantized_vector_size =
-                EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(
-                    &config.vector_parameters,
+                EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size_from_params(
+                    config.vector_parameters.dim,
+                    Self::convert_scalar_type(&scalar_config.r#type),
                 );
             let quantized_vectors_storage =
                 QuantizedMmapStorage::from_file(data_path.as_path(), quantized_vector_size)?;
@@ -1011,8 +1251,9 @@ impl QuantizedVectors {
         let offsets_path = Self::get_offsets_path(path, config.storage_type);
         if Self::is_ram(scalar_config.always_ram, on_disk_vector_storage) {
             let quantized_vector_size =
-                EncodedVectorsU8::<QuantizedRamStorage>::get_quantized_vector_size(
-                    &config.vector_parameters,
+                EncodedVectorsU8::<QuantizedRamStorage>::get_quantized_vector_size_from_params(
+                    config.vector_parameters.dim,
+                    Self::convert_scalar_type(&scalar_config.r#type),
                 );
             let inner_vectors_storage =
                 QuantizedRamStorage::from_file(data_path.as_path(), quantized_vector_size)?;
@@ -1028,8 +1269,9 @@ impl QuantizedVectors {
             ))
         } else {
             let quantized_vector_size =
-                EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(
-                    &config.vector_parameters,
+                EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size_from_params(
+                    config.vector_parameters.dim,
+                    Self::convert_scalar_type(&scalar_config.r#type),
                 );
             let inner_vectors_storage =
                 QuantizedMmapStorage::from_file(data_path.as_path(), quantized_vector_size)?;
@@ -1286,6 +1528,92 @@ impl QuantizedVectors {
         }
     }
 
+    fn load_rabitq(
+        vector_storage: &VectorStorageEnum,
+        path: &Path,
+        config: &QuantizedVectorsConfig,
+        rabitq_config: &RabitqQuantizationConfig,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        if !config.storage_type.is_immutable() {
+            return Err(OperationError::service_error(
+                "Mutable quantized storage is not supported for RaBitQ Quantization",
+            ));
+        }
+
+        let on_disk_vector_storage = vector_storage.is_on_disk();
+        let data_path = Self::get_data_path(path, config.storage_type);
+        let meta_path = Self::get_meta_path(path);
+        let quantized_vector_size =
+            EncodedVectorsRabitq::<QuantizedMmapStorage>::get_quantized_vector_size(
+                &config.vector_parameters,
+            );
+        if Self::is_ram(rabitq_config.always_ram, on_disk_vector_storage) {
+            let quantized_vectors_storage =
+                QuantizedRamStorage::from_file(data_path.as_path(), quantized_vector_size)?;
+            Ok(QuantizedVectorStorage::RabitqRam(
+                EncodedVectorsRabitq::load(quantized_vectors_storage, &meta_path)?,
+            ))
+        } else {
+            let quantized_vectors_storage =
+                QuantizedMmapStorage::from_file(data_path.as_path(), quantized_vector_size)?;
+            Ok(QuantizedVectorStorage::RabitqMmap(
+                EncodedVectorsRabitq::load(quantized_vectors_storage, &meta_path)?,
+            ))
+        }
+    }
+
+    fn load_rabitq_multi(
+        vector_storage: &VectorStorageEnum,
+        path: &Path,
+        config: &QuantizedVectorsConfig,
+        rabitq_config: &RabitqQuantizationConfig,
+        multivector_config: &MultiVectorConfig,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        if !config.storage_type.is_immutable() {
+            return Err(OperationError::service_error(
+                "Mutable quantized multivector storage is not supported for RaBitQ Quantization",
+            ));
+        }
+
+        let on_disk_vector_storage = vector_storage.is_on_disk();
+        let data_path = Self::get_data_path(path, config.storage_type);
+        let meta_path = Self::get_meta_path(path);
+        let offsets_path = Self::get_offsets_path(path, config.storage_type);
+        let quantized_vector_size =
+            EncodedVectorsRabitq::<QuantizedMmapStorage>::get_quantized_vector_size(
+                &config.vector_parameters,
+            );
+        if Self::is_ram(rabitq_config.always_ram, on_disk_vector_storage) {
+            let inner_vectors_storage =
+                QuantizedRamStorage::from_file(data_path.as_path(), quantized_vector_size)?;
+            let inner_vectors_storage =
+                EncodedVectorsRabitq::load(inner_vectors_storage, &meta_path)?;
+            let offsets = MultivectorOffsetsStorageRam::load(&offsets_path)?;
+            Ok(QuantizedVectorStorage::RabitqRamMulti(
+                QuantizedMultivectorStorage::new(
+                    config.vector_parameters.dim,
+                    inner_vectors_storage,
+                    offsets,
+                    *multivector_config,
+                ),
+            ))
+        } else {
+            let inner_vectors_storage =
+                QuantizedMmapStorage::from_file(data_path.as_path(), quantized_vector_size)?;
+            let inner_vectors_storage =
+                EncodedVectorsRabitq::load(inner_vectors_storage, &meta_path)?;
+            let offsets = MultivectorOffsetsStorageMmap::load(&offsets_path)?;
+            Ok(QuantizedVectorStorage::RabitqMmapMulti(
+                QuantizedMultivectorStorage::new(
+                    config.vector_parameters.dim,
+                    inner_vectors_storage,
+                    offsets,
+                    *multivector_config,
+                ),
+            ))
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn create_scalar<'a>(
         vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
@@ -1303,8 +1631,12 @@ impl QuantizedVectors {
             ));
         }
 
+        let method = Self::convert_scalar_type(&scalar_config.r#type);
         let quantized_vector_size =
-            EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
+            EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size_from_params(
+                vector_parameters.dim,
+                method,
+            );
         let meta_path = Self::get_meta_path(path);
         let data_path = Self::get_data_path(path, storage_type);
         let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
@@ -1314,15 +1646,18 @@ impl QuantizedVectors {
                 vectors_count,
                 quantized_vector_size,
             )?;
-            Ok(QuantizedVectorStorage::ScalarRam(EncodedVectorsU8::encode(
-                vectors,
-                storage_builder,
-                vector_parameters,
-                vectors_count,
-                scalar_config.quantile,
-                Some(meta_path.as_path()),
-                stopped,
-            )?))
+            Ok(QuantizedVectorStorage::ScalarRam(
+                EncodedVectorsU8::encode_with_method(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    method,
+                    vectors_count,
+                    scalar_config.quantile,
+                    Some(meta_path.as_path()),
+                    stopped,
+                )?,
+            ))
         } else {
             let storage_builder = QuantizedMmapStorageBuilder::new(
                 data_path.as_path(),
@@ -1330,10 +1665,11 @@ impl QuantizedVectors {
                 quantized_vector_size,
             )?;
             Ok(QuantizedVectorStorage::ScalarMmap(
-                EncodedVectorsU8::encode(
+                EncodedVectorsU8::encode_with_method(
                     vectors,
                     storage_builder,
                     vector_parameters,
+                    method,
                     vectors_count,
                     scalar_config.quantile,
                     Some(meta_path.as_path()),
@@ -1363,8 +1699,12 @@ impl QuantizedVectors {
             ));
         }
 
+        let method = Self::convert_scalar_type(&scalar_config.r#type);
         let quantized_vector_size =
-            EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
+            EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size_from_params(
+                vector_parameters.dim,
+                method,
+            );
         let meta_path = Self::get_meta_path(path);
         let data_path = Self::get_data_path(path, storage_type);
         let offsets_path = Self::get_offsets_path(path, storage_type);
@@ -1375,10 +1715,11 @@ impl QuantizedVectors {
                 inner_vectors_count,
                 quantized_vector_size,
             )?;
-            let quantized_storage = EncodedVectorsU8::encode(
+            let quantized_storage = EncodedVectorsU8::encode_with_method(
                 vectors,
                 storage_builder,
                 vector_parameters,
+                method,
                 inner_vectors_count,
                 scalar_config.quantile,
                 Some(meta_path.as_path()),
@@ -1399,10 +1740,11 @@ impl QuantizedVectors {
                 inner_vectors_count,
                 quantized_vector_size,
             )?;
-            let quantized_storage = EncodedVectorsU8::encode(
+            let quantized_storage = EncodedVectorsU8::encode_with_method(
                 vectors,
                 storage_builder,
                 vector_parameters,
+                method,
                 inner_vectors_count,
                 scalar_config.quantile,
                 Some(meta_path.as_path()),
@@ -1421,6 +1763,15 @@ impl QuantizedVectors {
         }
     }
 
+    /// OPQ rotation does not preserve L1 distances, so it is not applied for Manhattan distance
+    fn use_pq_rotation(
+        pq_config: &ProductQuantizationConfig,
+        vector_parameters: &quantization::VectorParameters,
+    ) -> bool {
+        pq_config.opq.unwrap_or_default()
+            && vector_parameters.distance_type != quantization::DistanceType::L1
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn create_pq<'a>(
         vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
@@ -1447,6 +1798,7 @@ impl QuantizedVectors {
             );
         let meta_path = Self::get_meta_path(path);
         let data_path = Self::get_data_path(path, storage_type);
+        let rotation = Self::use_pq_rotation(pq_config, vector_parameters);
         let in_ram = Self::is_ram(pq_config.always_ram, on_disk_vector_storage);
         if in_ram {
             let storage_builder = QuantizedRamStorageBuilder::new(
@@ -1454,32 +1806,38 @@ impl QuantizedVectors {
                 vectors_count,
                 quantized_vector_size,
             )?;
-            Ok(QuantizedVectorStorage::PQRam(EncodedVectorsPQ::encode(
-                vectors,
-                storage_builder,
-                vector_parameters,
-                vectors_count,
-                bucket_size,
-                max_threads,
-                Some(meta_path.as_path()),
-                stopped,
-            )?))
+            Ok(QuantizedVectorStorage::PQRam(
+                EncodedVectorsPQ::encode_with_rotation(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    vectors_count,
+                    bucket_size,
+                    rotation,
+                    max_threads,
+                    Some(meta_path.as_path()),
+                    stopped,
+                )?,
+            ))
         } else {
             let storage_builder = QuantizedMmapStorageBuilder::new(
                 data_path.as_path(),
                 vectors_count,
                 quantized_vector_size,
             )?;
-            Ok(QuantizedVectorStorage::PQMmap(EncodedVectorsPQ::encode(
-                vectors,
-                storage_builder,
-                vector_parameters,
-                vectors_count,
-                bucket_size,
-                max_threads,
-                Some(meta_path.as_path()),
-                stopped,
-            )?))
+            Ok(QuantizedVectorStorage::PQMmap(
+                EncodedVectorsPQ::encode_with_rotation(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    vectors_count,
+                    bucket_size,
+                    rotation,
+                    max_threads,
+                    Some(meta_path.as_path()),
+                    stopped,
+                )?,
+            ))
         }
     }
 
@@ -1513,6 +1871,7 @@ impl QuantizedVectors {
         let meta_path = Self::get_meta_path(path);
         let data_path = Self::get_data_path(path, storage_type);
         let offsets_path = Self::get_offsets_path(path, storage_type);
+        let rotation = Self::use_pq_rotation(pq_config, vector_parameters);
         let in_ram = Self::is_ram(pq_config.always_ram, on_disk_vector_storage);
         if in_ram {
             let storage_builder = QuantizedRamStorageBuilder::new(
@@ -1520,12 +1879,13 @@ impl QuantizedVectors {
                 inner_vectors_count,
                 quantized_vector_size,
             )?;
-            let quantized_storage = EncodedVectorsPQ::encode(
+            let quantized_storage = EncodedVectorsPQ::encode_with_rotation(
                 vectors,
                 storage_builder,
                 vector_parameters,
                 inner_vectors_count,
                 bucket_size,
+                rotation,
                 max_threads,
                 Some(meta_path.as_path()),
                 stopped,
@@ -1545,12 +1905,13 @@ impl QuantizedVectors {
                 inner_vectors_count,
                 quantized_vector_size,
             )?;
-            let quantized_storage = EncodedVectorsPQ::encode(
+            let quantized_storage = EncodedVectorsPQ::encode_with_rotation(
                 vectors,
                 storage_builder,
                 vector_parameters,
                 inner_vectors_count,
                 bucket_size,
+                rotation,
                 max_threads,
                 Some(meta_path.as_path()),
                 stopped,
@@ -1755,6 +2116,139 @@ impl QuantizedVectors {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn create_rabitq<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        vector_parameters: &quantization::VectorParameters,
+        vectors_count: usize,
+        rabitq_config: &RabitqQuantizationConfig,
+        storage_type: QuantizedVectorsStorageType,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        if !storage_type.is_immutable() {
+            return Err(OperationError::service_error(
+                "Mutable RaBitQ quantization is not supported",
+            ));
+        }
+
+        let quantized_vector_size =
+            EncodedVectorsRabitq::<QuantizedMmapStorage>::get_quantized_vector_size(
+                vector_parameters,
+            );
+        let meta_path = Self::get_meta_path(path);
+        let data_path = Self::get_data_path(path, storage_type);
+        let in_ram = Self::is_ram(rabitq_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let storage_builder = QuantizedRamStorageBuilder::new(
+                data_path.as_path(),
+                vectors_count,
+                quantized_vector_size,
+            )?;
+            Ok(QuantizedVectorStorage::RabitqRam(
+                EncodedVectorsRabitq::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    Some(meta_path.as_path()),
+                    stopped,
+                )?,
+            ))
+        } else {
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                data_path.as_path(),
+                vectors_count,
+                quantized_vector_size,
+            )?;
+            Ok(QuantizedVectorStorage::RabitqMmap(
+                EncodedVectorsRabitq::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    Some(meta_path.as_path()),
+                    stopped,
+                )?,
+            ))
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_rabitq_multi<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        offsets: impl Iterator<Item = MultivectorOffset>,
+        vector_parameters: &quantization::VectorParameters,
+        vectors_count: usize,
+        inner_vectors_count: usize,
+        rabitq_config: &RabitqQuantizationConfig,
+        storage_type: QuantizedVectorsStorageType,
+        multi_vector_config: MultiVectorConfig,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        if !storage_type.is_immutable() {
+            return Err(OperationError::service_error(
+                "Mutable RaBitQ quantization is not supported",
+            ));
+        }
+
+        let quantized_vector_size =
+            EncodedVectorsRabitq::<QuantizedMmapStorage>::get_quantized_vector_size(
+                vector_parameters,
+            );
+        let meta_path = Self::get_meta_path(path);
+        let data_path = Self::get_data_path(path, storage_type);
+        let offsets_path = Self::get_offsets_path(path, storage_type);
+        let in_ram = Self::is_ram(rabitq_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let storage_builder = QuantizedRamStorageBuilder::new(
+                data_path.as_path(),
+                inner_vectors_count,
+                quantized_vector_size,
+            )?;
+            let quantized_storage = EncodedVectorsRabitq::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                Some(meta_path.as_path()),
+                stopped,
+            )?;
+            let offsets = MultivectorOffsetsStorageRam::create(&offsets_path, offsets)?;
+            Ok(QuantizedVectorStorage::RabitqRamMulti(
+                QuantizedMultivectorStorage::new(
+                    vector_parameters.dim,
+                    quantized_storage,
+                    offsets,
+                    multi_vector_config,
+                ),
+            ))
+        } else {
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                data_path.as_path(),
+                inner_vectors_count,
+                quantized_vector_size,
+            )?;
+            let quantized_storage = EncodedVectorsRabitq::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                Some(meta_path.as_path()),
+                stopped,
+            )?;
+            let offsets =
+                MultivectorOffsetsStorageMmap::create(&offsets_path, offsets, vectors_count)?;
+            Ok(QuantizedVectorStorage::RabitqMmapMulti(
+                QuantizedMultivectorStorage::new(
+                    vector_parameters.dim,
+                    quantized_storage,
+                    offsets,
+                    multi_vector_config,
+                ),
+            ))
+        }
+    }
+
     fn is_ram(always_ram: Option<bool>, on_disk_vector_storage: bool) -> bool {
         !on_disk_vector_storage || always_ram == Some(true)
     }
@@ -1776,6 +2270,15 @@ impl QuantizedVectors {
         }
     }
 
+    fn convert_scalar_type(
+        scalar_type: &ScalarType,
+    ) -> quantization::encoded_vectors_u8::ScalarQuantizationMethod {
+        match scalar_type {
+            ScalarType::Int8 => quantization::encoded_vectors_u8::ScalarQuantizationMethod::Int8,
+            ScalarType::Int4 => quantization::encoded_vectors_u8::ScalarQuantizationMethod::Int4,
+        }
+    }
+
     fn convert_binary_query_encoding(
         query_encoding: Option<BinaryQuantizationQueryEncoding>,
     ) -> quantization::encoded_vectors_binary::QueryEncoding {
@@ -1796,7 +2299,7 @@ impl QuantizedVectors {
         }
     }
 
-    fn construct_vector_parameters(
+    pub(crate) fn construct_vector_parameters(
         distance: Distance,
         dim: usize,
         deprecated_count: usize,
@@ -1812,13 +2315,16 @@ impl QuantizedVectors {
                 Distance::Cosine => quantization::DistanceType::Dot,
                 Distance::Euclid => quantization::DistanceType::L2,
                 Distance::Dot => quantization::DistanceType::Dot,
-                Distance::Manhattan => quantization::DistanceType::L1,
+                // Binary vectors are never quantized
+                Distance::Manhattan | Distance::Hamming | Distance::Jaccard => {
+                    quantization::DistanceType::L1
+                }
             },
-            invert: distance == Distance::Euclid || distance == Distance::Manhattan,
+            invert: distance.distance_order() == Order::SmallBetter,
         }
     }
 
-    fn get_bucket_size(compression: CompressionRatio) -> usize {
+    pub(crate) fn get_bucket_size(compression: CompressionRatio) -> usize {
         match compression {
             CompressionRatio::X4 => 1,
             CompressionRatio::X8 => 2,
@@ -1843,6 +2349,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRam(_) => {}
             QuantizedVectorStorage::BinaryMmap(storage) => storage.storage().populate(),
             QuantizedVectorStorage::BinaryChunkedMmap(storage) => storage.storage().populate()?,
+            QuantizedVectorStorage::RabitqRam(_) => {}
+            QuantizedVectorStorage::RabitqMmap(storage) => storage.storage().populate(),
             QuantizedVectorStorage::ScalarRamMulti(_) => {}
             QuantizedVectorStorage::ScalarMmapMulti(storage) => {
                 storage.storage().storage().populate();
@@ -1870,6 +2378,11 @@ impl QuantizedVectors {
                 storage.storage().storage().populate()?;
                 storage.offsets_storage().populate()?;
             }
+            QuantizedVectorStorage::RabitqRamMulti(_) => {}
+            QuantizedVectorStorage::RabitqMmapMulti(storage) => {
+                storage.storage().storage().populate();
+                storage.offsets_storage().populate()?;
+            }
         }
         Ok(())
     }
@@ -1892,6 +2405,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRam(q) => q.flusher(),
             QuantizedVectorStorage::BinaryMmap(q) => q.flusher(),
             QuantizedVectorStorage::BinaryChunkedMmap(q) => q.flusher(),
+            QuantizedVectorStorage::RabitqRam(q) => q.flusher(),
+            QuantizedVectorStorage::RabitqMmap(q) => q.flusher(),
             QuantizedVectorStorage::ScalarRamMulti(q) => q.flusher(),
             QuantizedVectorStorage::ScalarMmapMulti(q) => q.flusher(),
             QuantizedVectorStorage::ScalarChunkedMmapMulti(q) => q.flusher(),
@@ -1901,6 +2416,8 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryRamMulti(q) => q.flusher(),
             QuantizedVectorStorage::BinaryMmapMulti(q) => q.flusher(),
             QuantizedVectorStorage::BinaryChunkedMmapMulti(q) => q.flusher(),
+            QuantizedVectorStorage::RabitqRamMulti(q) => q.flusher(),
+            QuantizedVectorStorage::RabitqMmapMulti(q) => q.flusher(),
         };
         Box::new(move || flusher().map_err(OperationError::from))
     }
@@ -1939,6 +2456,12 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryChunkedMmap(q) => {
                 Self::upsert_vector_dense(q, id, vector, hw_counter)
             }
+            QuantizedVectorStorage::RabitqRam(q) => {
+                Self::upsert_vector_dense(q, id, vector, hw_counter)
+            }
+            QuantizedVectorStorage::RabitqMmap(q) => {
+                Self::upsert_vector_dense(q, id, vector, hw_counter)
+            }
             QuantizedVectorStorage::ScalarRamMulti(q) => {
                 Self::upsert_vector_multi(q, id, vector, hw_counter)
             }
@@ -1966,6 +2489,12 @@ impl QuantizedVectors {
             QuantizedVectorStorage::BinaryChunkedMmapMulti(q) => {
                 Self::upsert_vector_multi(q, id, vector, hw_counter)
             }
+            QuantizedVectorStorage::RabitqRamMulti(q) => {
+                Self::upsert_vector_multi(q, id, vector, hw_counter)
+            }
+            QuantizedVectorStorage::RabitqMmapMulti(q) => {
+                Self::upsert_vector_multi(q, id, vector, hw_counter)
+            }
         }
     }
 
@@ -15,6 +15,11 @@ pub mod multi_custom_query_scorer;
 pub mod multi_metric_query_scorer;
 pub mod sparse_custom_query_scorer;
 pub mod sparse_metric_query_scorer;
+pub mod truncated_metric_query_scorer;
+#[cfg(feature = "vde")]
+pub mod vde_multi_query_scorer;
+#[cfg(feature = "vde")]
+pub mod vde_query_scorer;
 
 pub trait QueryScorer {
     type TVector: ?Sized;
@@ -93,6 +98,37 @@ pub fn score_max_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
     sum
 }
 
+/// Symmetric Chamfer similarity, metric for multi-dense vectors.
+/// Average of MaxSim normalized by the count of vectors, in both directions.
+/// This metric is also implemented in `QuantizedMultivectorStorage` structure for quantized data.
+pub fn score_chamfer_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    multi_dense_a: TypedMultiDenseVectorRef<'_, T>,
+    multi_dense_b: TypedMultiDenseVectorRef<'_, T>,
+) -> ScoreType {
+    debug_assert!(!multi_dense_a.is_empty());
+    debug_assert!(!multi_dense_b.is_empty());
+    // max similarity of each `b` vector, collected in the same pass
+    let mut max_sims_b = vec![ScoreType::NEG_INFINITY; multi_dense_b.vectors_count()];
+    let mut sum_a = 0.0;
+    for dense_a in multi_dense_a.multi_vectors() {
+        let mut max_sim = ScoreType::NEG_INFINITY;
+        for (dense_b, max_sim_b) in multi_dense_b.multi_vectors().zip(max_sims_b.iter_mut()) {
+            let sim = TMetric::similarity(dense_a, dense_b);
+            if sim > max_sim {
+                max_sim = sim;
+            }
+            if sim > *max_sim_b {
+                *max_sim_b = sim;
+            }
+        }
+        sum_a += max_sim;
+    }
+    let sum_b: ScoreType = max_sims_b.iter().sum();
+    (sum_a / multi_dense_a.vectors_count() as ScoreType
+        + sum_b / multi_dense_b.vectors_count() as ScoreType)
+        / 2.0
+}
+
 fn score_multi<T: PrimitiveVectorElement, TMetric: Metric<T>>(
     multi_vector_config: &MultiVectorConfig,
     multi_dense_a: TypedMultiDenseVectorRef<'_, T>,
@@ -102,6 +138,13 @@ fn score_multi<T: PrimitiveVectorElement, TMetric: Metric<T>>(
         MultiVectorComparator::MaxSim => {
             score_max_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
         }
+        MultiVectorComparator::MaxSimNormalized => {
+            score_max_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
+                / multi_dense_a.vectors_count() as ScoreType
+        }
+        MultiVectorComparator::Chamfer => {
+            score_chamfer_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
+        }
     }
 }
 
@@ -148,7 +191,7 @@ fn is_read_with_prefetch_efficient(ids: impl IntoIterator<Item = usize>) -> bool
 mod tests {
     use super::*;
     use crate::data_types::vectors::MultiDenseVectorInternal;
-    use crate::spaces::simple::EuclidMetric;
+    use crate::spaces::simple::{DotProductMetric, EuclidMetric};
 
     #[test]
     fn test_check_ids_rather_contiguous() {
@@ -186,4 +229,25 @@ mod tests {
         // proper value according to theory should be `5.9777255` but we do not apply post-processing step
         assert_eq!(score, -19.);
     }
+
+    #[test]
+    fn test_score_multi_comparators() {
+        let a = MultiDenseVectorInternal::try_from(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap();
+        let b = MultiDenseVectorInternal::try_from(vec![vec![1.0, 0.0]]).unwrap();
+        let score = |comparator| {
+            let config = MultiVectorConfig {
+                comparator,
+                token_pooling: None,
+            };
+            (
+                score_multi::<f32, DotProductMetric>(&config, (&a).into(), (&b).into()),
+                score_multi::<f32, DotProductMetric>(&config, (&b).into(), (&a).into()),
+            )
+        };
+
+        assert_eq!(score(MultiVectorComparator::MaxSim), (1.0, 1.0));
+        assert_eq!(score(MultiVectorComparator::MaxSimNormalized), (0.5, 1.0));
+        // symmetric
+        assert_eq!(score(MultiVectorComparator::Chamfer), (0.75, 0.75));
+    }
 }
@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::typelevel::True;
+use common::types::{PointOffsetType, ScoreType};
+use zerocopy::FromBytes;
+
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{TypedDenseVector, VectorElementType};
+use crate::spaces::metric::Metric;
+use crate::types::Distance;
+use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::{DenseVectorStorage, Random};
+
+/// Scores the first `dim` dimensions of the query against the first `dim` dimensions of
+/// stored vectors
+///
+/// Matryoshka embeddings keep most of their information in the leading dimensions, so the
+/// truncated score approximates the full one at a fraction of the cost. Stored vectors are
+/// preprocessed with all dimensions, so for cosine distance their first dimensions are normalized
+/// while scoring.
+pub struct TruncatedMetricQueryScorer<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TypedDenseVector<TElement>,
+    dim: usize,
+    normalize: bool,
+    metric: PhantomData<TMetric>,
+    hardware_counter: HardwareCounterCell,
+}
+
+impl<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> TruncatedMetricQueryScorer<'a, TElement, TMetric, TVectorStorage>
+{
+    /// `dim` must not exceed the dimensionality of `query` and of stored vectors
+    pub fn new(
+        mut query: TypedDenseVector<VectorElementType>,
+        dim: usize,
+        vector_storage: &'a TVectorStorage,
+        mut hardware_counter: HardwareCounterCell,
+    ) -> Self {
+        query.truncate(dim);
+        let preprocessed_vector = TMetric::preprocess(query);
+
+        hardware_counter.set_cpu_multiplier(dim * size_of::<TElement>());
+        if vector_storage.is_on_disk() {
+            hardware_counter.set_vector_io_read_multiplier(dim * size_of::<TElement>());
+        } else {
+            hardware_counter.set_vector_io_read_multiplier(0);
+        }
+
+        Self {
+            query: TypedDenseVector::from(TElement::slice_from_float_cow(Cow::from(
+                preprocessed_vector,
+            ))),
+            dim,
+            normalize: vector_storage.distance() == Distance::Cosine,
+            vector_storage,
+            metric: PhantomData,
+            hardware_counter,
+        }
+    }
+
+    /// Similarity of `v1` to the first `dim` dimensions of `v2`
+    ///
+    /// With cosine distance both are normalized, except for the query which is preprocessed with
+    /// its first dimensions already.
+    fn similarity(&self, v1: &[TElement], v1_is_query: bool, v2: &[TElement]) -> ScoreType {
+        let v2 = &v2[..self.dim];
+        let score = TMetric::similarity(v1, v2);
+        if !self.normalize {
+            return score;
+        }
+        let mut norm = TMetric::similarity(v2, v2);
+        if !v1_is_query {
+            norm *= TMetric::similarity(v1, v1);
+        }
+        if norm > 0.0 {
+            score / norm.sqrt()
+        } else {
+            score
+        }
+    }
+}
+
+impl<
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> QueryScorer for TruncatedMetricQueryScorer<'_, TElement, TMetric, TVectorStorage>
+{
+    type TVector = [TElement];
+
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        self.hardware_counter.cpu_counter().incr();
+        self.hardware_counter.vector_io_read().incr();
+        let stored = self.vector_storage.get_dense::<Random>(idx);
+        self.similarity(&self.query, true, &stored)
+    }
+
+    fn score_stored_batch(&self, ids: &[PointOffsetType], scores: &mut [ScoreType]) {
+        debug_assert!(ids.len() <= VECTOR_READ_BATCH_SIZE);
+        debug_assert_eq!(ids.len(), scores.len());
+
+        let mut vectors = [MaybeUninit::uninit(); VECTOR_READ_BATCH_SIZE];
+
+        let vectors = self
+            .vector_storage
+            .get_dense_batch(ids, &mut vectors[..ids.len()]);
+        self.hardware_counter.cpu_counter().incr_delta(ids.len());
+        self.hardware_counter.vector_io_read().incr_delta(ids.len());
+
+        for idx in 0..ids.len() {
+            scores[idx] = self.similarity(&self.query, true, &vectors[idx]);
+        }
+    }
+
+    #[inline]
+    fn score(&self, v2: &[TElement]) -> ScoreType {
+        self.hardware_counter.cpu_counter().incr();
+        self.similarity(&self.query, true, v2)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        self.hardware_counter.cpu_counter().incr();
+        let v1 = self.vector_storage.get_dense::<Random>(point_a);
+        let v2 = self.vector_storage.get_dense::<Random>(point_b);
+        self.similarity(&v1[..self.dim], false, &v2)
+    }
+
+    type SupportsBytes = True;
+    fn score_bytes(&self, _enabled: Self::SupportsBytes, bytes: &[u8]) -> ScoreType {
+        self.score(<[TElement]>::ref_from_bytes(bytes).unwrap())
+    }
+}
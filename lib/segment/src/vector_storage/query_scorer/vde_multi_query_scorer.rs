@@ -0,0 +1,195 @@
+use std::marker::PhantomData;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::typelevel::False;
+use common::types::{PointOffsetType, ScoreType};
+
+use super::score_multi;
+use crate::data_types::vectors::{
+    DenseVector, MultiDenseVectorInternal, TypedMultiDenseVectorRef, VectorElementType,
+};
+use crate::spaces::metric::Metric;
+use crate::types::MultiVectorConfig;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::vde_storage::VDEVectorStorage;
+
+fn preprocess_multi<TMetric: Metric<VectorElementType>>(
+    vector: MultiDenseVectorInternal,
+) -> MultiDenseVectorInternal {
+    let mut preprocessed = DenseVector::with_capacity(vector.flattened_len());
+    for slice in vector.multi_vectors() {
+        preprocessed.extend_from_slice(&TMetric::preprocess(slice.to_vec()));
+    }
+    MultiDenseVectorInternal::new(preprocessed, vector.dim)
+}
+
+/// Scores a multi-vector query against multi-vectors held by VDE
+pub struct VdeMultiMetricQueryScorer<'a, TMetric: Metric<VectorElementType>> {
+    vector_storage: &'a VDEVectorStorage,
+    multi_vector_config: MultiVectorConfig,
+    query: MultiDenseVectorInternal,
+    metric: PhantomData<TMetric>,
+    hardware_counter: HardwareCounterCell,
+}
+
+impl<'a, TMetric: Metric<VectorElementType>> VdeMultiMetricQueryScorer<'a, TMetric> {
+    pub fn new(
+        query: MultiDenseVectorInternal,
+        vector_storage: &'a VDEVectorStorage,
+        multi_vector_config: MultiVectorConfig,
+        mut hardware_counter: HardwareCounterCell,
+    ) -> Self {
+        hardware_counter.set_cpu_multiplier(query.dim * size_of::<VectorElementType>());
+        hardware_counter.set_vector_io_read_multiplier(query.dim * size_of::<VectorElementType>());
+
+        Self {
+            vector_storage,
+            multi_vector_config,
+            query: preprocess_multi::<TMetric>(query),
+            metric: PhantomData,
+            hardware_counter,
+        }
+    }
+
+    fn score_multi(
+        &self,
+        multi_dense_a: TypedMultiDenseVectorRef<VectorElementType>,
+        multi_dense_b: TypedMultiDenseVectorRef<VectorElementType>,
+    ) -> ScoreType {
+        self.hardware_counter
+            .cpu_counter()
+            .incr_delta(multi_dense_a.vectors_count() * multi_dense_b.vectors_count());
+
+        score_multi::<VectorElementType, TMetric>(
+            &self.multi_vector_config,
+            multi_dense_a,
+            multi_dense_b,
+        )
+    }
+}
+
+impl<TMetric: Metric<VectorElementType>> QueryScorer for VdeMultiMetricQueryScorer<'_, TMetric> {
+    type TVector = MultiDenseVectorInternal;
+
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_multi(idx);
+        self.hardware_counter
+            .vector_io_read()
+            .incr_delta(stored.vectors_count());
+
+        self.score(&stored)
+    }
+
+    #[inline]
+    fn score(&self, v2: &MultiDenseVectorInternal) -> ScoreType {
+        self.score_multi(
+            TypedMultiDenseVectorRef::from(&self.query),
+            TypedMultiDenseVectorRef::from(v2),
+        )
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        let v1 = self.vector_storage.get_multi(point_a);
+        let v2 = self.vector_storage.get_multi(point_b);
+        self.hardware_counter
+            .vector_io_read()
+            .incr_delta(v1.vectors_count() + v2.vectors_count());
+
+        self.score_multi(
+            TypedMultiDenseVectorRef::from(&v1),
+            TypedMultiDenseVectorRef::from(&v2),
+        )
+    }
+
+    type SupportsBytes = False;
+    fn score_bytes(&self, enabled: Self::SupportsBytes, _: &[u8]) -> ScoreType {
+        match enabled {}
+    }
+}
+
+/// Scores recommend, discovery and context queries against multi-vectors held by VDE
+pub struct VdeMultiCustomQueryScorer<
+    'a,
+    TMetric: Metric<VectorElementType>,
+    TQuery: Query<MultiDenseVectorInternal>,
+> {
+    vector_storage: &'a VDEVectorStorage,
+    multi_vector_config: MultiVectorConfig,
+    query: TQuery,
+    metric: PhantomData<TMetric>,
+    hardware_counter: HardwareCounterCell,
+}
+
+impl<
+    'a,
+    TMetric: Metric<VectorElementType>,
+    TQuery: Query<MultiDenseVectorInternal>
+        + TransformInto<TQuery, MultiDenseVectorInternal, MultiDenseVectorInternal>,
+> VdeMultiCustomQueryScorer<'a, TMetric, TQuery>
+{
+    pub fn new(
+        query: TQuery,
+        vector_storage: &'a VDEVectorStorage,
+        multi_vector_config: MultiVectorConfig,
+        mut hardware_counter: HardwareCounterCell,
+    ) -> Self {
+        let query: TQuery =
+            TransformInto::transform(query, |vector| Ok(preprocess_multi::<TMetric>(vector)))
+                .unwrap();
+
+        let dim = vector_storage.vector_dim();
+        hardware_counter.set_cpu_multiplier(dim * size_of::<VectorElementType>());
+        hardware_counter.set_vector_io_read_multiplier(dim * size_of::<VectorElementType>());
+
+        Self {
+            vector_storage,
+            multi_vector_config,
+            query,
+            metric: PhantomData,
+            hardware_counter,
+        }
+    }
+}
+
+impl<TMetric: Metric<VectorElementType>, TQuery: Query<MultiDenseVectorInternal>> QueryScorer
+    for VdeMultiCustomQueryScorer<'_, TMetric, TQuery>
+{
+    type TVector = MultiDenseVectorInternal;
+
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_multi(idx);
+        self.hardware_counter
+            .vector_io_read()
+            .incr_delta(stored.vectors_count());
+
+        self.score(&stored)
+    }
+
+    #[inline]
+    fn score(&self, against: &MultiDenseVectorInternal) -> ScoreType {
+        let cpu_counter = self.hardware_counter.cpu_counter();
+        let against = TypedMultiDenseVectorRef::from(against);
+
+        self.query.score_by(|example| {
+            cpu_counter.incr_delta(example.vectors_count() * against.vectors_count());
+
+            score_multi::<VectorElementType, TMetric>(
+                &self.multi_vector_config,
+                TypedMultiDenseVectorRef::from(example),
+                against,
+            )
+        })
+    }
+
+    fn score_internal(&self, _point_a: PointOffsetType, _point_b: PointOffsetType) -> ScoreType {
+        unimplemented!("Custom scorer can compare against multiple vectors, not just one")
+    }
+
+    type SupportsBytes = False;
+    fn score_bytes(&self, enabled: Self::SupportsBytes, _: &[u8]) -> ScoreType {
+        match enabled {}
+    }
+}
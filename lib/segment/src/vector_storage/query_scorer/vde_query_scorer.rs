@@ -0,0 +1,138 @@
+use std::marker::PhantomData;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::typelevel::False;
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::vectors::{DenseVector, VectorElementType};
+use crate::spaces::metric::Metric;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::vde_storage::VDEVectorStorage;
+
+/// Scores a dense query against dense vectors held by VDE
+///
+/// VDE only hands out owned vectors, so every scored point is read from VDE.
+pub struct VdeMetricQueryScorer<'a, TMetric: Metric<VectorElementType>> {
+    vector_storage: &'a VDEVectorStorage,
+    query: DenseVector,
+    metric: PhantomData<TMetric>,
+    hardware_counter: HardwareCounterCell,
+}
+
+impl<'a, TMetric: Metric<VectorElementType>> VdeMetricQueryScorer<'a, TMetric> {
+    pub fn new(
+        query: DenseVector,
+        vector_storage: &'a VDEVectorStorage,
+        mut hardware_counter: HardwareCounterCell,
+    ) -> Self {
+        let dim = query.len();
+        hardware_counter.set_cpu_multiplier(dim * size_of::<VectorElementType>());
+        hardware_counter.set_vector_io_read_multiplier(dim * size_of::<VectorElementType>());
+
+        Self {
+            vector_storage,
+            query: TMetric::preprocess(query),
+            metric: PhantomData,
+            hardware_counter,
+        }
+    }
+}
+
+impl<TMetric: Metric<VectorElementType>> QueryScorer for VdeMetricQueryScorer<'_, TMetric> {
+    type TVector = [VectorElementType];
+
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_dense(idx);
+        self.hardware_counter.vector_io_read().incr();
+        self.score(&stored)
+    }
+
+    #[inline]
+    fn score(&self, v2: &[VectorElementType]) -> ScoreType {
+        self.hardware_counter.cpu_counter().incr();
+        TMetric::similarity(&self.query, v2)
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        let v1 = self.vector_storage.get_dense(point_a);
+        let v2 = self.vector_storage.get_dense(point_b);
+        self.hardware_counter.vector_io_read().incr_delta(2);
+        self.hardware_counter.cpu_counter().incr();
+        TMetric::similarity(&v1, &v2)
+    }
+
+    type SupportsBytes = False;
+    fn score_bytes(&self, enabled: Self::SupportsBytes, _: &[u8]) -> ScoreType {
+        match enabled {}
+    }
+}
+
+/// Scores recommend, discovery and context queries against dense vectors held by VDE
+pub struct VdeCustomQueryScorer<'a, TMetric: Metric<VectorElementType>, TQuery: Query<DenseVector>>
+{
+    vector_storage: &'a VDEVectorStorage,
+    query: TQuery,
+    metric: PhantomData<TMetric>,
+    hardware_counter: HardwareCounterCell,
+}
+
+impl<
+    'a,
+    TMetric: Metric<VectorElementType>,
+    TQuery: Query<DenseVector> + TransformInto<TQuery, DenseVector, DenseVector>,
+> VdeCustomQueryScorer<'a, TMetric, TQuery>
+{
+    pub fn new(
+        query: TQuery,
+        vector_storage: &'a VDEVectorStorage,
+        mut hardware_counter: HardwareCounterCell,
+    ) -> Self {
+        let query: TQuery =
+            TransformInto::transform(query, |vector| Ok(TMetric::preprocess(vector))).unwrap();
+
+        let dim = vector_storage.vector_dim();
+        hardware_counter.set_cpu_multiplier(dim * size_of::<VectorElementType>());
+        hardware_counter.set_vector_io_read_multiplier(dim * size_of::<VectorElementType>());
+
+        Self {
+            vector_storage,
+            query,
+            metric: PhantomData,
+            hardware_counter,
+        }
+    }
+}
+
+impl<TMetric: Metric<VectorElementType>, TQuery: Query<DenseVector>> QueryScorer
+    for VdeCustomQueryScorer<'_, TMetric, TQuery>
+{
+    type TVector = [VectorElementType];
+
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        let stored = self.vector_storage.get_dense(idx);
+        self.hardware_counter.vector_io_read().incr();
+        self.score(&stored)
+    }
+
+    #[inline]
+    fn score(&self, against: &[VectorElementType]) -> ScoreType {
+        let cpu_counter = self.hardware_counter.cpu_counter();
+
+        self.query.score_by(|example| {
+            cpu_counter.incr();
+            TMetric::similarity(example, against)
+        })
+    }
+
+    fn score_internal(&self, _point_a: PointOffsetType, _point_b: PointOffsetType) -> ScoreType {
+        unimplemented!("Custom scorer can compare against multiple vectors, not just one")
+    }
+
+    type SupportsBytes = False;
+    fn score_bytes(&self, enabled: Self::SupportsBytes, _: &[u8]) -> ScoreType {
+        match enabled {}
+    }
+}
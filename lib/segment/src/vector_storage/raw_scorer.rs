@@ -6,27 +6,44 @@ use common::ext::BitSliceExt as _;
 use common::types::{PointOffsetType, ScoreType};
 use sparse::common::sparse_vector::SparseVector;
 
+#[cfg(feature = "vde")]
+use super::VectorStorage;
 use super::query::{
     ContextQuery, DiscoveryQuery, RecoBestScoreQuery, RecoQuery, RecoSumScoresQuery, TransformInto,
 };
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
 use super::query_scorer::multi_custom_query_scorer::MultiCustomQueryScorer;
 use super::query_scorer::sparse_custom_query_scorer::SparseCustomQueryScorer;
+#[cfg(feature = "vde")]
+use super::query_scorer::vde_multi_query_scorer::{
+    VdeMultiCustomQueryScorer, VdeMultiMetricQueryScorer,
+};
+#[cfg(feature = "vde")]
+use super::query_scorer::vde_query_scorer::{VdeCustomQueryScorer, VdeMetricQueryScorer};
 use super::query_scorer::{QueryScorerBytes, QueryScorerBytesImpl};
+#[cfg(feature = "vde")]
+use super::vde_storage::VDEVectorStorage;
 use super::{DenseVectorStorage, MultiVectorStorage, SparseVectorStorage, VectorStorageEnum};
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
+#[cfg(feature = "vde")]
+use crate::data_types::vectors::VectorElementType;
 use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, QueryVector, VectorInternal,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::Distance;
+#[cfg(feature = "vde")]
+use crate::types::MultiVectorConfig;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
 use crate::vector_storage::query_scorer::QueryScorer;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
 use crate::vector_storage::query_scorer::multi_metric_query_scorer::MultiMetricQueryScorer;
 use crate::vector_storage::query_scorer::sparse_metric_query_scorer::SparseMetricQueryScorer;
+use crate::vector_storage::query_scorer::truncated_metric_query_scorer::TruncatedMetricQueryScorer;
 use crate::vector_storage::sparse::volatile_sparse_vector_storage::VolatileSparseVectorStorage;
 
 pub trait RawScorer {
@@ -89,13 +106,20 @@ pub fn new_raw_scorer<'a>(
         // TODO(byte_storage): Implement async raw scorer for DenseMemmapByte and DenseMemmapHalf
         VectorStorageEnum::DenseMemmapByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseMemmapHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseMemmapBFloat16(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
 
         VectorStorageEnum::DenseAppendableMemmap(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableMemmapByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableMemmapHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseAppendableMemmapBFloat16(vs) => {
+            raw_scorer_impl(query, vs.as_ref(), hc)
+        }
         VectorStorageEnum::DenseAppendableInRam(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableInRamByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableInRamHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseAppendableInRamBFloat16(vs) => {
+            raw_scorer_impl(query, vs.as_ref(), hc)
+        }
         #[cfg(feature = "rocksdb")]
         VectorStorageEnum::SparseSimple(vs) => raw_sparse_scorer_impl(query, vs, hc),
         VectorStorageEnum::SparseVolatile(vs) => raw_sparse_scorer_volatile(query, vs, hc),
@@ -120,6 +144,9 @@ pub fn new_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => {
             raw_multi_scorer_impl(query, vs.as_ref(), hc)
         }
+        VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(vs) => {
+            raw_multi_scorer_impl(query, vs.as_ref(), hc)
+        }
         VectorStorageEnum::MultiDenseAppendableInRam(vs) => {
             raw_multi_scorer_impl(query, vs.as_ref(), hc)
         }
@@ -129,9 +156,160 @@ pub fn new_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseAppendableInRamHalf(vs) => {
             raw_multi_scorer_impl(query, vs.as_ref(), hc)
         }
-        VectorStorageEnum::Vde(_vs) => {
-            // VDE doesn't support raw scoring (uses its own native search)
-            Err(OperationError::service_error("VDE uses native search, raw scoring not supported"))
+        VectorStorageEnum::MultiDenseAppendableInRamBFloat16(vs) => {
+            raw_multi_scorer_impl(query, vs.as_ref(), hc)
+        }
+        #[cfg(feature = "vde")]
+        VectorStorageEnum::Vde(vs) => raw_vde_scorer_impl(query, vs, hc),
+    }
+}
+
+/// Create a raw scorer over the first `dim` dimensions of dense vectors
+///
+/// Used to search graphs built on truncated Matryoshka embeddings. Only nearest queries against
+/// dense storages with more than `dim` dimensions are truncated, other queries and storages are
+/// scored with all dimensions.
+pub fn new_truncated_raw_scorer<'a>(
+    query: QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    dim: usize,
+    hc: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage {
+        #[cfg(feature = "rocksdb")]
+        VectorStorageEnum::DenseSimple(vs) => truncated_raw_scorer_impl(query, vs, dim, hc),
+        #[cfg(feature = "rocksdb")]
+        VectorStorageEnum::DenseSimpleByte(vs) => truncated_raw_scorer_impl(query, vs, dim, hc),
+        #[cfg(feature = "rocksdb")]
+        VectorStorageEnum::DenseSimpleHalf(vs) => truncated_raw_scorer_impl(query, vs, dim, hc),
+        VectorStorageEnum::DenseVolatile(vs) => truncated_raw_scorer_impl(query, vs, dim, hc),
+        #[cfg(test)]
+        VectorStorageEnum::DenseVolatileByte(vs) => truncated_raw_scorer_impl(query, vs, dim, hc),
+        #[cfg(test)]
+        VectorStorageEnum::DenseVolatileHalf(vs) => truncated_raw_scorer_impl(query, vs, dim, hc),
+        VectorStorageEnum::DenseMemmap(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseMemmapByte(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseMemmapHalf(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseMemmapBFloat16(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableMemmap(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableMemmapByte(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableMemmapHalf(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableMemmapBFloat16(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableInRam(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableInRamByte(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableInRamHalf(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        VectorStorageEnum::DenseAppendableInRamBFloat16(vs) => {
+            truncated_raw_scorer_impl(query, vs.as_ref(), dim, hc)
+        }
+        _ => new_raw_scorer(query, vector_storage, hc),
+    }
+}
+
+fn truncated_raw_scorer_impl<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TVectorStorage: DenseVectorStorage<TElement>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    dim: usize,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>>
+where
+    CosineMetric: Metric<TElement>,
+    EuclidMetric: Metric<TElement>,
+    DotProductMetric: Metric<TElement>,
+    ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
+{
+    let QueryVector::Nearest(VectorInternal::Dense(vector)) = query else {
+        return raw_scorer_impl(query, vector_storage, hardware_counter);
+    };
+    if dim >= vector_storage.vector_dim() {
+        return raw_scorer_impl(
+            QueryVector::Nearest(VectorInternal::Dense(vector)),
+            vector_storage,
+            hardware_counter,
+        );
+    }
+    if vector.len() < dim {
+        return Err(OperationError::WrongVectorDimension {
+            expected_dim: vector_storage.vector_dim(),
+            received_dim: vector.len(),
+        });
+    }
+
+    match vector_storage.distance() {
+        Distance::Cosine => {
+            raw_scorer_from_query_scorer(TruncatedMetricQueryScorer::<_, CosineMetric, _>::new(
+                vector,
+                dim,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Euclid => {
+            raw_scorer_from_query_scorer(TruncatedMetricQueryScorer::<_, EuclidMetric, _>::new(
+                vector,
+                dim,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Dot => {
+            raw_scorer_from_query_scorer(TruncatedMetricQueryScorer::<_, DotProductMetric, _>::new(
+                vector,
+                dim,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Manhattan => {
+            raw_scorer_from_query_scorer(TruncatedMetricQueryScorer::<_, ManhattanMetric, _>::new(
+                vector,
+                dim,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Hamming => {
+            raw_scorer_from_query_scorer(TruncatedMetricQueryScorer::<_, HammingMetric, _>::new(
+                vector,
+                dim,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Jaccard => {
+            raw_scorer_from_query_scorer(TruncatedMetricQueryScorer::<_, JaccardMetric, _>::new(
+                vector,
+                dim,
+                vector_storage,
+                hardware_counter,
+            ))
         }
     }
 }
@@ -228,6 +406,8 @@ where
     EuclidMetric: Metric<TElement>,
     DotProductMetric: Metric<TElement>,
     ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
 {
     match vector_storage.distance() {
         Distance::Cosine => new_scorer_with_metric::<TElement, CosineMetric, _>(
@@ -250,6 +430,16 @@ where
             vector_storage,
             hardware_counter,
         ),
+        Distance::Hamming => new_scorer_with_metric::<TElement, HammingMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
+        Distance::Jaccard => new_scorer_with_metric::<TElement, JaccardMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
     }
 }
 
@@ -331,6 +521,8 @@ where
     EuclidMetric: Metric<TElement>,
     DotProductMetric: Metric<TElement>,
     ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
 {
     match vector_storage.distance() {
         Distance::Cosine => new_multi_scorer_with_metric::<_, CosineMetric, _>(
@@ -353,6 +545,16 @@ where
             vector_storage,
             hardware_counter,
         ),
+        Distance::Hamming => new_multi_scorer_with_metric::<_, HammingMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
+        Distance::Jaccard => new_multi_scorer_with_metric::<_, JaccardMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
     }
 }
 
@@ -416,6 +618,149 @@ fn new_multi_scorer_with_metric<
     }
 }
 
+#[cfg(feature = "vde")]
+pub fn raw_vde_scorer_impl<'a>(
+    query: QueryVector,
+    vector_storage: &'a VDEVectorStorage,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => {
+            new_vde_scorer_with_metric::<CosineMetric>(query, vector_storage, hardware_counter)
+        }
+        Distance::Euclid => {
+            new_vde_scorer_with_metric::<EuclidMetric>(query, vector_storage, hardware_counter)
+        }
+        Distance::Dot => {
+            new_vde_scorer_with_metric::<DotProductMetric>(query, vector_storage, hardware_counter)
+        }
+        Distance::Manhattan => {
+            new_vde_scorer_with_metric::<ManhattanMetric>(query, vector_storage, hardware_counter)
+        }
+        Distance::Hamming => {
+            new_vde_scorer_with_metric::<HammingMetric>(query, vector_storage, hardware_counter)
+        }
+        Distance::Jaccard => {
+            new_vde_scorer_with_metric::<JaccardMetric>(query, vector_storage, hardware_counter)
+        }
+    }
+}
+
+#[cfg(feature = "vde")]
+fn new_vde_scorer_with_metric<'a, TMetric: Metric<VectorElementType> + 'a>(
+    query: QueryVector,
+    vector_storage: &'a VDEVectorStorage,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    if let Some(multi_vector_config) = vector_storage.multi_vector_config().copied() {
+        return new_vde_multi_scorer_with_metric::<TMetric>(
+            query,
+            vector_storage,
+            multi_vector_config,
+            hardware_counter,
+        );
+    }
+
+    match query {
+        QueryVector::Nearest(vector) => {
+            raw_scorer_from_query_scorer(VdeMetricQueryScorer::<TMetric>::new(
+                vector.try_into()?,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        QueryVector::RecommendBestScore(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeCustomQueryScorer::<TMetric, _>::new(
+                RecoBestScoreQuery::from(reco_query),
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeCustomQueryScorer::<TMetric, _>::new(
+                RecoSumScoresQuery::from(reco_query),
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeCustomQueryScorer::<TMetric, _>::new(
+                discovery_query,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeCustomQueryScorer::<TMetric, _>::new(
+                context_query,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+    }
+}
+
+#[cfg(feature = "vde")]
+fn new_vde_multi_scorer_with_metric<'a, TMetric: Metric<VectorElementType> + 'a>(
+    query: QueryVector,
+    vector_storage: &'a VDEVectorStorage,
+    multi_vector_config: MultiVectorConfig,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match query {
+        QueryVector::Nearest(vector) => {
+            raw_scorer_from_query_scorer(VdeMultiMetricQueryScorer::<TMetric>::new(
+                vector.try_into()?,
+                vector_storage,
+                multi_vector_config,
+                hardware_counter,
+            ))
+        }
+        QueryVector::RecommendBestScore(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeMultiCustomQueryScorer::<TMetric, _>::new(
+                RecoBestScoreQuery::from(reco_query),
+                vector_storage,
+                multi_vector_config,
+                hardware_counter,
+            ))
+        }
+        QueryVector::RecommendSumScores(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeMultiCustomQueryScorer::<TMetric, _>::new(
+                RecoSumScoresQuery::from(reco_query),
+                vector_storage,
+                multi_vector_config,
+                hardware_counter,
+            ))
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVectorInternal> =
+                discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeMultiCustomQueryScorer::<TMetric, _>::new(
+                discovery_query,
+                vector_storage,
+                multi_vector_config,
+                hardware_counter,
+            ))
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVectorInternal> =
+                context_query.transform_into()?;
+            raw_scorer_from_query_scorer(VdeMultiCustomQueryScorer::<TMetric, _>::new(
+                context_query,
+                vector_storage,
+                multi_vector_config,
+                hardware_counter,
+            ))
+        }
+    }
+}
+
 impl<TQueryScorer: QueryScorer> RawScorer for RawScorerImpl<TQueryScorer> {
     fn score_points(&self, points: &[PointOffsetType], scores: &mut [ScoreType]) {
         assert_eq!(points.len(), scores.len());
@@ -83,6 +83,7 @@ fn product_x4() -> WithQuantization {
     let config = ProductQuantizationConfig {
         compression: crate::types::CompressionRatio::X4,
         always_ram: Some(true),
+        opq: None,
     }
     .into();
 
@@ -85,10 +85,12 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
             | VectorStorageEnum::DenseVolatileHalf(_) => unreachable!(),
             VectorStorageEnum::DenseMemmap(_)
             | VectorStorageEnum::DenseMemmapByte(_)
-            | VectorStorageEnum::DenseMemmapHalf(_) => unreachable!(),
+            | VectorStorageEnum::DenseMemmapHalf(_)
+            | VectorStorageEnum::DenseMemmapBFloat16(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableMemmap(_)
             | VectorStorageEnum::DenseAppendableMemmapByte(_)
-            | VectorStorageEnum::DenseAppendableMemmapHalf(_) => unreachable!(),
+            | VectorStorageEnum::DenseAppendableMemmapHalf(_)
+            | VectorStorageEnum::DenseAppendableMemmapBFloat16(_) => unreachable!(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => unreachable!(),
             VectorStorageEnum::SparseMmap(_) => unreachable!(),
@@ -116,13 +118,18 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
                 }
             }
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_)
-            | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => unreachable!(),
+            | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_)
+            | VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableInRam(_)
             | VectorStorageEnum::DenseAppendableInRamByte(_)
-            | VectorStorageEnum::DenseAppendableInRamHalf(_) => unreachable!(),
+            | VectorStorageEnum::DenseAppendableInRamHalf(_)
+            | VectorStorageEnum::DenseAppendableInRamBFloat16(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableInRam(_)
             | VectorStorageEnum::MultiDenseAppendableInRamByte(_)
-            | VectorStorageEnum::MultiDenseAppendableInRamHalf(_) => unreachable!(),
+            | VectorStorageEnum::MultiDenseAppendableInRamHalf(_)
+            | VectorStorageEnum::MultiDenseAppendableInRamBFloat16(_) => unreachable!(),
+            #[cfg(feature = "vde")]
+            VectorStorageEnum::Vde(_) => unreachable!(),
         };
     }
 
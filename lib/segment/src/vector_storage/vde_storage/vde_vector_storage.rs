@@ -1,358 +1,597 @@
 use std::borrow::Cow;
 use std::ops::Range;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::AtomicBool;
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use bitvec::slice::BitSlice;
+use bitvec::prelude::BitSlice;
+use bitvec::vec::BitVec;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
-use vde_sys::*;
+use io::file_operations::{atomic_save_bin, read_bin};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
 
-use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::Flusher;
-use crate::data_types::named_vectors::CowVector;
-use crate::data_types::vectors::VectorRef;
-use crate::types::{Distance, VectorStorageDatatype};
-use crate::vector_storage::VectorStorage;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::named_vectors::{CowMultiVector, CowVector};
+use crate::data_types::vectors::{DenseVector, MultiDenseVectorInternal, VectorRef};
+use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
+use crate::vde::{VdeCollection, VdeCollectionConfig, VdeEngine};
+use crate::vector_storage::{AccessPattern, Random, VectorStorage};
+
+/// VDE storage type used for vectors
+const VDE_VECTOR_STORAGE_TYPE: &str = "memory";
+
+/// Deleted flags of a VDE vector storage, as persisted on flush
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct DeletedFlags {
+    /// Number of vector slots, including deleted ones
+    total: usize,
+    deleted: Vec<PointOffsetType>,
+}
+
+/// Inner vectors of a single multi-vector within the VDE collection
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+struct MultiVectorOffset {
+    /// VDE id of the first inner vector
+    start: PointOffsetType,
+    /// Number of inner vectors, zero if there is no multi-vector
+    count: u32,
+}
+
+impl MultiVectorOffset {
+    fn ids(self) -> Range<PointOffsetType> {
+        self.start..self.start + self.count
+    }
+}
+
+/// Layout of multi-vectors within the VDE collection, as persisted on flush
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct MultiVectorOffsets {
+    /// VDE id of the next inner vector, ids of replaced inner vectors are never reused
+    next_id: PointOffsetType,
+    offsets: Vec<MultiVectorOffset>,
+}
 
 /// VDE-backed vector storage
 ///
-/// This implementation delegates vector storage to VDE's Btrieve2 backend.
+/// This implementation delegates vector storage to a VDE collection.
 /// VDE manages the raw vector data while Qdrant manages deleted flags.
+///
+/// Multi-vectors are stored as one VDE vector per inner vector, Qdrant keeps track of which VDE
+/// vectors belong to each point.
+/// Sparse vectors are not supported.
+#[derive(Debug)]
 pub struct VDEVectorStorage {
-    /// VDE Collection handle
-    collection: VDECollectionHandle,
-    
-    /// VDE Engine handle (shared)
-    engine: Arc<RwLock<VDEEngineHandle>>,
-    
-    /// Collection name
-    name: String,
-    
-    /// Vector dimension
-    dimension: usize,
-    
-    /// Distance metric
+    collection: Arc<dyn VdeCollection>,
     distance: Distance,
-    
-    /// Data type
+    multi_vector_config: Option<MultiVectorConfig>,
+    /// Inner vectors of each point, only used for multi-vectors
+    multi_vector_offsets: Arc<RwLock<MultiVectorOffsets>>,
+    /// Whether multi-vector offsets changed since the last flush
+    offsets_changed: Arc<AtomicBool>,
     datatype: VectorStorageDatatype,
-    
-    /// Base path
     path: PathBuf,
-    
-    /// Deleted vector tracking (VDE uses internal bitset, but we maintain this for compatibility)
-    deleted: Arc<RwLock<Vec<bool>>>,
-}
-
-impl std::fmt::Debug for VDEVectorStorage {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("VDEVectorStorage")
-            .field("name", &self.name)
-            .field("dimension", &self.dimension)
-            .field("distance", &self.distance)
-            .field("datatype", &self.datatype)
-            .field("path", &self.path)
-            .field("deleted_count", &self.deleted.read().map(|d| d.iter().filter(|&&x| x).count()))
-            .finish()
-    }
+    /// Deleted flags, VDE removes deleted vectors completely
+    deleted: BitVec,
+    deleted_count: usize,
+    /// Whether deleted flags changed since the last flusher was created
+    flags_changed: AtomicBool,
+    /// Deleted flags not yet persisted, `None` if nothing changed since the last flush
+    pending_flags: Arc<Mutex<Option<DeletedFlags>>>,
 }
 
 impl VDEVectorStorage {
-    pub fn new(
+    /// Open the VDE collection `name`, creating it if it does not exist yet
+    ///
+    /// With `multi_vector_config`, the storage holds multi-vectors of `dimension` sized vectors.
+    pub fn open(
+        engine: &dyn VdeEngine,
         path: &Path,
         name: &str,
         dimension: usize,
         distance: Distance,
+        multi_vector_config: Option<MultiVectorConfig>,
     ) -> OperationResult<Self> {
-        unsafe {
-            let work_dir = std::ffi::CString::new(path.to_str().unwrap())
-                .map_err(|e| OperationError::service_error(format!("Invalid path: {}", e)))?;
-            
-            let engine_handle = vde_engine_create(work_dir.as_ptr());
-            if engine_handle.is_null() {
-                return Err(OperationError::service_error("Failed to create VDE engine"));
-            }
-            
-            let engine = Arc::new(RwLock::new(engine_handle));
-            
-            let collection_name = std::ffi::CString::new(name)
-                .map_err(|e| OperationError::service_error(format!("Invalid name: {}", e)))?;
-            
-            // Try to open existing collection first
-            let mut collection = vde_collection_open(engine_handle, collection_name.as_ptr());
-            
-            if collection.is_null() {
-                // Create new collection
-                let index_type = std::ffi::CString::new("vsag_hnsw").unwrap();
-                let storage_type = std::ffi::CString::new("memory").unwrap();
-                let distance_str = std::ffi::CString::new(match distance {
-                    Distance::Cosine => "cosine",
-                    Distance::Euclid => "euclidean",
-                    Distance::Dot => "dot",
-                    Distance::Manhattan => "manhattan",
-                }).unwrap();
-                
-                let config = VDECollectionConfig {
-                    index_type: index_type.as_ptr(),
-                    storage_type: storage_type.as_ptr(),
-                    dimension: dimension as u32,
-                    distance_metric: distance_str.as_ptr(),
-                    config_json: std::ptr::null(),
+        let config = VdeCollectionConfig::new(dimension, distance)
+            .with_storage_type(VDE_VECTOR_STORAGE_TYPE);
+        let collection = engine.open_or_create_collection(name, &config)?;
+
+        let flags_path = Self::deleted_flags_path(path, name);
+        let flags: DeletedFlags = if flags_path.exists() {
+            read_bin(&flags_path)?
+        } else {
+            DeletedFlags::default()
+        };
+
+        let offsets_path = Self::multi_vector_offsets_path(path, name);
+        let multi_vector_offsets: MultiVectorOffsets = if offsets_path.exists() {
+            read_bin(&offsets_path)?
+        } else {
+            MultiVectorOffsets::default()
+        };
+
+        let mut storage = Self {
+            collection,
+            distance,
+            multi_vector_config,
+            multi_vector_offsets: Arc::new(RwLock::new(multi_vector_offsets)),
+            offsets_changed: Arc::new(AtomicBool::new(false)),
+            datatype: VectorStorageDatatype::Float32,
+            path: path.to_path_buf(),
+            deleted: BitVec::repeat(false, flags.total),
+            deleted_count: 0,
+            flags_changed: AtomicBool::new(false),
+            pending_flags: Arc::new(Mutex::new(None)),
+        };
+        for key in flags.deleted {
+            storage.set_deleted(key, true);
+        }
+        *storage.flags_changed.get_mut() = false;
+        Ok(storage)
+    }
+
+    fn deleted_flags_path(path: &Path, name: &str) -> PathBuf {
+        path.join(format!("{name}_deleted.dat"))
+    }
+
+    fn multi_vector_offsets_path(path: &Path, name: &str) -> PathBuf {
+        path.join(format!("{name}_offsets.dat"))
+    }
+
+    fn set_deleted(&mut self, key: PointOffsetType, deleted: bool) -> bool {
+        let key = key as usize;
+        if self.deleted.len() <= key {
+            self.deleted.resize(key + 1, false);
+            *self.flags_changed.get_mut() = true;
+        }
+        let was_deleted = self.deleted.replace(key, deleted);
+        match (was_deleted, deleted) {
+            (false, true) => self.deleted_count += 1,
+            (true, false) => self.deleted_count -= 1,
+            (false, false) | (true, true) => return was_deleted,
+        }
+        *self.flags_changed.get_mut() = true;
+
+        was_deleted
+    }
+
+    /// Upsert a vector into the VDE collection and mark it as present
+    fn upsert_vector(&mut self, key: PointOffsetType, vector: &CowVector) -> OperationResult<()> {
+        match (vector.as_vec_ref(), &self.multi_vector_config) {
+            (VectorRef::Dense(dense), None) => self.collection.upsert(key, Some(dense), None)?,
+            (VectorRef::MultiDense(multi), Some(_)) => {
+                let count = multi.vectors_count() as u32;
+                let replaced = {
+                    let mut layout = self.multi_vector_offsets.write();
+                    let offset = MultiVectorOffset {
+                        start: layout.next_id,
+                        count,
+                    };
+                    layout.next_id += count;
+
+                    let key = key as usize;
+                    if layout.offsets.len() <= key {
+                        layout.offsets.resize(key + 1, MultiVectorOffset::default());
+                    }
+                    let replaced = std::mem::replace(&mut layout.offsets[key], offset);
+                    for (id, inner) in offset.ids().zip(multi.multi_vectors()) {
+                        self.collection.upsert(id, Some(inner), None)?;
+                    }
+                    replaced
                 };
-                
-                collection = vde_collection_create(engine_handle, collection_name.as_ptr(), &config);
-                if collection.is_null() {
-                    vde_engine_destroy(engine_handle);
-                    return Err(OperationError::service_error("Failed to create VDE collection"));
+                for id in replaced.ids() {
+                    self.collection.delete(id)?;
                 }
+                self.offsets_changed.store(true, Ordering::Relaxed);
+            }
+            (VectorRef::Sparse(_), _) => {
+                return Err(OperationError::service_error(
+                    "VDE only supports dense and multi-dense vectors",
+                ));
+            }
+            (VectorRef::Dense(_), Some(_)) | (VectorRef::MultiDense(_), None) => {
+                return Err(OperationError::WrongMulti);
             }
-            
-            Ok(Self {
-                collection,
-                engine,
-                name: name.to_string(),
-                dimension,
-                distance,
-                datatype: VectorStorageDatatype::Float32,
-                path: path.to_path_buf(),
-                deleted: Arc::new(RwLock::new(Vec::new())),
-            })
         }
+        self.set_deleted(key, false);
+        Ok(())
     }
-    
-    /// Get vector from VDE
-    fn get_vector_internal(&self, key: PointOffsetType) -> OperationResult<Vec<f32>> {
-        unsafe {
-            let mut vector_data = vec![0.0f32; self.dimension];
-            let mut vde_vector = VDEVector {
-                data: vector_data.as_mut_ptr(),
-                dim: self.dimension as u32,
+
+    /// Get a dense vector, a zero vector is returned if it doesn't exist
+    pub fn get_dense(&self, key: PointOffsetType) -> DenseVector {
+        match self.get_vector_opt::<Random>(key) {
+            Some(CowVector::Dense(dense)) => dense.into_owned(),
+            _ => vec![0.0; self.vector_dim()],
+        }
+    }
+
+    /// Get a multi-vector, a placeholder is returned if it doesn't exist
+    pub fn get_multi(&self, key: PointOffsetType) -> MultiDenseVectorInternal {
+        match self.get_vector_opt::<Random>(key) {
+            Some(CowVector::MultiDense(multi)) => multi.to_owned(),
+            _ => MultiDenseVectorInternal::placeholder(self.vector_dim()),
+        }
+    }
+
+    /// Read the inner vectors of a multi-vector from VDE
+    fn read_multi(
+        &self,
+        key: PointOffsetType,
+    ) -> OperationResult<Option<MultiDenseVectorInternal>> {
+        let offset = self
+            .multi_vector_offsets
+            .read()
+            .offsets
+            .get(key as usize)
+            .copied()
+            .unwrap_or_default();
+        if offset.count == 0 {
+            return Ok(None);
+        }
+
+        let dim = self.vector_dim();
+        let mut flattened = Vec::with_capacity(offset.count as usize * dim);
+        for id in offset.ids() {
+            let Some(vector) = self.collection.get_vector(id)? else {
+                return Err(OperationError::service_error(format!(
+                    "Inner vector {id} of multi-vector {key} is missing in VDE",
+                )));
             };
-            
-            let ret = vde_get_vector(
-                self.collection,
-                key as u64,
-                &mut vde_vector,
-                std::ptr::null_mut(),
-            );
-            
-            if ret != 0 {
-                return Err(OperationError::service_error(format!("Failed to get vector {}: {}", key, ret)));
-            }
-            
-            Ok(vector_data)
+            flattened.extend(vector);
+        }
+        Ok(Some(MultiDenseVectorInternal::new(flattened, dim)))
+    }
+
+    /// Get vector dimension
+    pub fn vector_dim(&self) -> usize {
+        self.collection.dimension()
+    }
+
+    /// Get multi-vector config, `None` if the storage holds dense vectors
+    pub fn multi_vector_config(&self) -> Option<&MultiVectorConfig> {
+        self.multi_vector_config.as_ref()
+    }
+
+    /// Get size of available vectors in bytes
+    pub fn size_of_available_vectors_in_bytes(&self) -> usize {
+        let vector_size = self.vector_dim() * size_of::<f32>();
+        if self.multi_vector_config.is_some() {
+            let layout = self.multi_vector_offsets.read();
+            layout
+                .offsets
+                .iter()
+                .enumerate()
+                .filter(|(key, _)| !self.is_deleted_vector(*key as PointOffsetType))
+                .map(|(_, offset)| offset.count as usize * vector_size)
+                .sum()
+        } else {
+            self.available_vector_count() * vector_size
         }
     }
+
+    /// Populate index (no-op for VDE as it manages its own index)
+    pub fn populate(&self) -> OperationResult<()> {
+        Ok(())
+    }
+
+    /// Clear cache (no-op for VDE)
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        Ok(())
+    }
 }
 
 impl VectorStorage for VDEVectorStorage {
     fn distance(&self) -> Distance {
         self.distance
     }
-    
+
     fn datatype(&self) -> VectorStorageDatatype {
         self.datatype
     }
-    
+
     fn is_on_disk(&self) -> bool {
-        true // VDE uses Btrieve2 for persistent storage
+        true
     }
-    
+
     fn total_vector_count(&self) -> usize {
-        unsafe {
-            vde_get_vector_count(self.collection) as usize
+        if self.multi_vector_config.is_some() {
+            // VDE counts inner vectors of multi-vectors
+            self.deleted.len()
+        } else {
+            self.deleted.len().max(self.collection.vector_count())
         }
     }
-    
-    fn get_vector<P: crate::vector_storage::AccessPattern>(&self, key: PointOffsetType) -> CowVector<'_> {
-        let vector = self.get_vector_internal(key).unwrap_or_default();
-        CowVector::Dense(Cow::Owned(vector))
+
+    fn get_vector<P: AccessPattern>(&self, key: PointOffsetType) -> CowVector<'_> {
+        self.get_vector_opt::<P>(key).unwrap_or_else(|| {
+            if self.multi_vector_config.is_some() {
+                CowVector::MultiDense(CowMultiVector::Owned(
+                    MultiDenseVectorInternal::placeholder(self.vector_dim()),
+                ))
+            } else {
+                CowVector::Dense(Cow::Owned(vec![0.0; self.vector_dim()]))
+            }
+        })
     }
-    
-    fn get_vector_opt<P: crate::vector_storage::AccessPattern>(&self, key: PointOffsetType) -> Option<CowVector<'_>> {
-        self.get_vector_internal(key).ok().map(|v| CowVector::Dense(Cow::Owned(v)))
+
+    fn get_vector_opt<P: AccessPattern>(&self, key: PointOffsetType) -> Option<CowVector<'_>> {
+        let vector = if self.multi_vector_config.is_some() {
+            self.read_multi(key)
+                .map(|multi| multi.map(|multi| CowVector::MultiDense(CowMultiVector::Owned(multi))))
+        } else {
+            self.collection
+                .get_vector(key)
+                .map(|vector| vector.map(|vector| CowVector::Dense(Cow::Owned(vector))))
+                .map_err(OperationError::from)
+        };
+        vector.unwrap_or_else(|err| {
+            log::error!("Failed to read vector {key} from VDE: {err}");
+            None
+        })
     }
-    
+
     fn insert_vector(
         &mut self,
         key: PointOffsetType,
         vector: VectorRef,
-        _hw_counter: &HardwareCounterCell,
+        hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
-        unsafe {
-            let dense = match vector {
-                VectorRef::Dense(v) => v,
-                _ => return Err(OperationError::service_error("VDE only supports dense vectors")),
-            };
-            
-            if dense.len() != self.dimension {
-                return Err(OperationError::service_error(
-                    format!("Vector dimension mismatch: expected {}, got {}", self.dimension, dense.len())
-                ));
-            }
-            
-            let vde_vector = VDEVector {
-                data: dense.as_ptr() as *mut f32,
-                dim: dense.len() as u32,
-            };
-            
-            let ret = vde_upsert_vector(
-                self.collection,
-                key as u64,
-                &vde_vector,
-                std::ptr::null(),
-            );
-            
-            if ret != 0 {
-                return Err(OperationError::service_error(format!("Failed to insert vector: {}", ret)));
-            }
-            
-            // Ensure deleted tracking is large enough
-            let mut deleted = self.deleted.write().unwrap();
-            if deleted.len() <= key as usize {
-                deleted.resize(key as usize + 1, false);
-            }
-            deleted[key as usize] = false;
-            
-            Ok(())
-        }
+        let vector = CowVector::from(vector);
+        let size = vector.estimate_size_in_bytes();
+        self.upsert_vector(key, &vector)?;
+        hw_counter.vector_io_write_counter().incr_delta(size);
+        Ok(())
     }
-    
+
     fn update_from<'a>(
         &mut self,
         other_vectors: &'a mut impl Iterator<Item = (CowVector<'a>, bool)>,
         stopped: &AtomicBool,
     ) -> OperationResult<Range<PointOffsetType>> {
-        let mut start: Option<PointOffsetType> = None;
-        let mut end: PointOffsetType = 0;
-        
-        for (idx, (vector, deleted)) in other_vectors.enumerate() {
-            if stopped.load(std::sync::atomic::Ordering::Relaxed) {
+        let start = self.total_vector_count() as PointOffsetType;
+        let mut end = start;
+
+        for (vector, deleted) in other_vectors {
+            if stopped.load(Ordering::Relaxed) {
                 return Err(OperationError::Cancelled {
                     description: "Update cancelled".to_string(),
                 });
             }
-            
-            let key = idx as PointOffsetType;
-            if start.is_none() {
-                start = Some(key);
-            }
-            end = key + 1;
-            
-            if !deleted {
-                let vec_ref = match &vector {
-                    CowVector::Dense(Cow::Owned(v)) => VectorRef::Dense(v.as_slice()),
-                    CowVector::Dense(Cow::Borrowed(v)) => VectorRef::Dense(v),
-                    _ => return Err(OperationError::service_error("VDE only supports dense vectors")),
-                };
-                self.insert_vector(key, vec_ref, &HardwareCounterCell::disposable())?;
-            } else {
-                self.delete_vector(key)?;
+
+            let key = end;
+            end += 1;
+
+            if deleted {
+                self.set_deleted(key, true);
+                continue;
             }
+
+            self.upsert_vector(key, &vector)?;
         }
-        
-        Ok(start.unwrap_or(0)..end)
+
+        Ok(start..end)
     }
-    
+
     fn flusher(&self) -> Flusher {
-        // Since VDECollection pointer can't be sent between threads,
-        // we return a no-op flusher. VDE flushes on Drop.
-        Box::new(|| Ok(()))
+        let collection = self.collection.clone();
+        // Serialize the flags once per flush, rather than on every change
+        let pending_flags = self.pending_flags.clone();
+        if self.flags_changed.swap(false, Ordering::Relaxed) {
+            *pending_flags.lock() = Some(DeletedFlags {
+                total: self.deleted.len(),
+                deleted: self
+                    .deleted
+                    .iter_ones()
+                    .map(|key| key as PointOffsetType)
+                    .collect(),
+            });
+        }
+        let flags_path = Self::deleted_flags_path(&self.path, collection.name());
+        let multi_vector_offsets = self.multi_vector_offsets.clone();
+        let offsets_changed = self.offsets_changed.clone();
+        let offsets_path = Self::multi_vector_offsets_path(&self.path, collection.name());
+        Box::new(move || {
+            collection.flush()?;
+            if offsets_changed.swap(false, Ordering::Relaxed) {
+                let layout = multi_vector_offsets.read();
+                if let Err(err) = atomic_save_bin(&offsets_path, &*layout) {
+                    offsets_changed.store(true, Ordering::Relaxed);
+                    return Err(err.into());
+                }
+            }
+            let mut pending_flags = pending_flags.lock();
+            if let Some(flags) = pending_flags.as_ref() {
+                atomic_save_bin(&flags_path, flags)?;
+                *pending_flags = None;
+            }
+            Ok(())
+        })
     }
-    
+
     fn files(&self) -> Vec<PathBuf> {
-        vec![
-            self.path.join(format!("{}_vectors.btr", self.name)),
-            self.path.join(format!("{}_index.snapshot", self.name)),
-        ]
+        let name = self.collection.name();
+        let mut files = self.collection.files().unwrap_or_else(|err| {
+            log::error!("Failed to list files of VDE collection {name}: {err}");
+            Vec::new()
+        });
+        // Deleted flags are only written once the storage was changed
+        let flags_path = Self::deleted_flags_path(&self.path, name);
+        if flags_path.exists() {
+            files.push(flags_path);
+        }
+        let offsets_path = Self::multi_vector_offsets_path(&self.path, name);
+        if offsets_path.exists() {
+            files.push(offsets_path);
+        }
+        files.sort();
+        files.dedup();
+        files
     }
-    
+
     fn delete_vector(&mut self, key: PointOffsetType) -> OperationResult<bool> {
-        unsafe {
-            let ret = vde_delete_vector(self.collection, key as u64);
-            if ret != 0 {
-                return Err(OperationError::service_error(format!("Failed to delete vector: {}", ret)));
+        if self.multi_vector_config.is_some() {
+            let removed = self
+                .multi_vector_offsets
+                .write()
+                .offsets
+                .get_mut(key as usize)
+                .map(std::mem::take)
+                .unwrap_or_default();
+            for id in removed.ids() {
+                self.collection.delete(id)?;
             }
-            
-            let mut deleted = self.deleted.write().unwrap();
-            if deleted.len() <= key as usize {
-                deleted.resize(key as usize + 1, false);
-            }
-            
-            let was_not_deleted = !deleted[key as usize];
-            deleted[key as usize] = true;
-            
-            Ok(was_not_deleted)
+            self.offsets_changed.store(true, Ordering::Relaxed);
+        } else {
+            self.collection.delete(key)?;
         }
+        Ok(!self.set_deleted(key, true))
     }
-    
+
     fn is_deleted_vector(&self, key: PointOffsetType) -> bool {
-        let deleted = self.deleted.read().unwrap();
-        if key as usize >= deleted.len() {
-            return false;
-        }
-        deleted[key as usize]
+        self.deleted
+            .get(key as usize)
+            .is_some_and(|deleted| *deleted)
     }
-    
+
     fn deleted_vector_count(&self) -> usize {
-        let deleted = self.deleted.read().unwrap();
-        deleted.iter().filter(|&&d| d).count()
+        self.deleted_count
     }
-    
+
     fn deleted_vector_bitslice(&self) -> &BitSlice {
-        // This is unsafe but required by trait
-        // In practice, VDE manages deletions internally
-        BitSlice::empty()
+        &self.deleted
     }
 }
 
-impl VDEVectorStorage {
-    /// Get vector dimension
-    pub fn vector_dim(&self) -> usize {
-        self.dimension
-    }
-    
-    /// Get multi-vector config (VDE doesn't support multi-vectors)
-    pub fn multi_vector_config(&self) -> &crate::types::MultiVectorConfig {
-        // VDE doesn't support multi-vectors, this shouldn't be called
-        unimplemented!("VDE doesn't support multi-vectors")
-    }
-    
-    /// Get size of available vectors in bytes
-    pub fn size_of_available_vectors_in_bytes(&self) -> usize {
-        // Estimate: dimension * 4 bytes per f32 * vector count
-        self.dimension * 4 * self.total_vector_count()
-    }
-    
-    /// Populate index (no-op for VDE as it manages its own index)
-    pub fn populate(&self) -> OperationResult<()> {
-        Ok(())
-    }
-    
-    /// Clear cache (no-op for VDE)
-    pub fn clear_cache(&self) -> OperationResult<()> {
-        Ok(())
+impl Drop for VDEVectorStorage {
+    fn drop(&mut self) {
+        if let Err(err) = self.flusher()() {
+            log::error!(
+                "Failed to flush VDE collection {}: {err}",
+                self.collection.name(),
+            );
+        }
     }
 }
 
-impl Drop for VDEVectorStorage {
-    fn drop(&mut self) {
-        // Flush before dropping
-        unsafe {
-            vde_flush(self.collection);
+#[cfg(test)]
+mod tests {
+    use common::counter::hardware_counter::HardwareCounterCell;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::vde::{VdeBackend, shared_engine};
+
+    #[test]
+    fn test_vde_vector_storage() {
+        let dir = Builder::new().prefix("vde_storage").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::disposable();
+
+        {
+            let engine = shared_engine(VdeBackend::InProcess, dir.path()).unwrap();
+            let mut storage = VDEVectorStorage::open(
+                engine.as_ref(),
+                dir.path(),
+                "vectors",
+                2,
+                Distance::Dot,
+                None,
+            )
+            .unwrap();
+
+            for id in 0..4 {
+                let vector = [id as f32, 1.0];
+                storage
+                    .insert_vector(id, VectorRef::from(&vector[..]), &hw_counter)
+                    .unwrap();
+            }
+            assert!(storage.delete_vector(2).unwrap());
+            assert!(!storage.delete_vector(2).unwrap());
+
+            assert_eq!(storage.total_vector_count(), 4);
+            assert_eq!(storage.deleted_vector_count(), 1);
+            assert!(storage.is_deleted_vector(2));
+            assert!(storage.get_vector_opt::<Random>(2).is_none());
+
+            storage.flusher()().unwrap();
         }
-        
-        if let Ok(engine) = self.engine.read() {
-            unsafe {
-                if !(*engine).is_null() {
-                    vde_engine_destroy(*engine);
-                }
+
+        let engine = shared_engine(VdeBackend::InProcess, dir.path()).unwrap();
+        let storage = VDEVectorStorage::open(
+            engine.as_ref(),
+            dir.path(),
+            "vectors",
+            2,
+            Distance::Dot,
+            None,
+        )
+        .unwrap();
+        let vector = storage.get_vector::<Random>(3);
+        assert_eq!(vector, CowVector::from(&[3.0, 1.0][..]));
+        assert_eq!(storage.total_vector_count(), 4);
+        assert!(storage.is_deleted_vector(2));
+    }
+
+    #[test]
+    fn test_vde_multi_vector_storage() {
+        let dir = Builder::new().prefix("vde_storage").tempdir().unwrap();
+        let hw_counter = HardwareCounterCell::disposable();
+        let multi = |vectors: &[[f32; 2]]| {
+            MultiDenseVectorInternal::new(vectors.iter().flatten().copied().collect(), 2)
+        };
+        let open = || {
+            let engine = shared_engine(VdeBackend::InProcess, dir.path()).unwrap();
+            VDEVectorStorage::open(
+                engine.as_ref(),
+                dir.path(),
+                "vectors",
+                2,
+                Distance::Dot,
+                Some(MultiVectorConfig::default()),
+            )
+            .unwrap()
+        };
+
+        {
+            let mut storage = open();
+            for id in 0..3 {
+                let vector = multi(&[[id as f32, 1.0], [id as f32, 2.0]]);
+                storage
+                    .insert_vector(id, VectorRef::from(&vector), &hw_counter)
+                    .unwrap();
             }
+            let replaced = multi(&[[5.0, 5.0]]);
+            storage
+                .insert_vector(1, VectorRef::from(&replaced), &hw_counter)
+                .unwrap();
+            assert!(storage.delete_vector(2).unwrap());
+
+            let dense = [1.0, 1.0];
+            assert!(
+                storage
+                    .insert_vector(3, VectorRef::from(&dense[..]), &hw_counter)
+                    .is_err()
+            );
+
+            assert_eq!(storage.total_vector_count(), 3);
+            // Inner vectors of replaced and deleted multi-vectors are removed from VDE
+            assert_eq!(storage.collection.vector_count(), 3);
+            assert!(storage.get_vector_opt::<Random>(2).is_none());
+
+            storage.flusher()().unwrap();
         }
+
+        let storage = open();
+        assert_eq!(
+            storage.get_vector::<Random>(0),
+            CowVector::from(multi(&[[0.0, 1.0], [0.0, 2.0]])),
+        );
+        assert_eq!(storage.get_multi(1), multi(&[[5.0, 5.0]]));
+        assert!(storage.is_deleted_vector(2));
+        assert_eq!(
+            storage.size_of_available_vectors_in_bytes(),
+            3 * 2 * size_of::<f32>(),
+        );
     }
 }
-
-unsafe impl Send for VDEVectorStorage {}
-unsafe impl Sync for VDEVectorStorage {}
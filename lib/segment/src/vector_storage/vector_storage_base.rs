@@ -23,14 +23,16 @@ use super::multi_dense::simple_multi_dense_vector_storage::SimpleMultiDenseVecto
 use super::multi_dense::volatile_multi_dense_vector_storage::VolatileMultiDenseVectorStorage;
 use super::sparse::mmap_sparse_vector_storage::MmapSparseVectorStorage;
 use super::sparse::volatile_sparse_vector_storage::VolatileSparseVectorStorage;
+#[cfg(feature = "vde")]
 use super::vde_storage::VDEVectorStorage;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    MultiDenseVectorInternal, TypedMultiDenseVectorRef, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf, VectorInternal, VectorRef,
+    MultiDenseVectorInternal, TypedMultiDenseVectorRef, VectorElementType,
+    VectorElementTypeBFloat16, VectorElementTypeByte, VectorElementTypeHalf, VectorInternal,
+    VectorRef,
 };
 use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
 use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
@@ -230,6 +232,7 @@ pub enum VectorStorageEnum {
     DenseMemmap(Box<MemmapDenseVectorStorage<VectorElementType>>),
     DenseMemmapByte(Box<MemmapDenseVectorStorage<VectorElementTypeByte>>),
     DenseMemmapHalf(Box<MemmapDenseVectorStorage<VectorElementTypeHalf>>),
+    DenseMemmapBFloat16(Box<MemmapDenseVectorStorage<VectorElementTypeBFloat16>>),
     DenseAppendableMemmap(
         Box<
             AppendableMmapDenseVectorStorage<
@@ -254,6 +257,14 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    DenseAppendableMemmapBFloat16(
+        Box<
+            AppendableMmapDenseVectorStorage<
+                VectorElementTypeBFloat16,
+                ChunkedMmapVectors<VectorElementTypeBFloat16>,
+            >,
+        >,
+    ),
     DenseAppendableInRam(
         Box<
             AppendableMmapDenseVectorStorage<
@@ -278,6 +289,14 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    DenseAppendableInRamBFloat16(
+        Box<
+            AppendableMmapDenseVectorStorage<
+                VectorElementTypeBFloat16,
+                InRamPersistedVectors<VectorElementTypeBFloat16>,
+            >,
+        >,
+    ),
     #[cfg(feature = "rocksdb")]
     SparseSimple(SimpleSparseVectorStorage),
     SparseVolatile(VolatileSparseVectorStorage),
@@ -320,6 +339,15 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    MultiDenseAppendableMemmapBFloat16(
+        Box<
+            AppendableMmapMultiDenseVectorStorage<
+                VectorElementTypeBFloat16,
+                ChunkedMmapVectors<VectorElementTypeBFloat16>,
+                ChunkedMmapVectors<MultivectorMmapOffset>,
+            >,
+        >,
+    ),
     MultiDenseAppendableInRam(
         Box<
             AppendableMmapMultiDenseVectorStorage<
@@ -347,6 +375,16 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    MultiDenseAppendableInRamBFloat16(
+        Box<
+            AppendableMmapMultiDenseVectorStorage<
+                VectorElementTypeBFloat16,
+                InRamPersistedVectors<VectorElementTypeBFloat16>,
+                InRamPersistedVectors<MultivectorMmapOffset>,
+            >,
+        >,
+    ),
+    #[cfg(feature = "vde")]
     Vde(VDEVectorStorage),
 }
 
@@ -367,12 +405,15 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(_) => None,
             VectorStorageEnum::DenseMemmapByte(_) => None,
             VectorStorageEnum::DenseMemmapHalf(_) => None,
+            VectorStorageEnum::DenseMemmapBFloat16(_) => None,
             VectorStorageEnum::DenseAppendableMemmap(_) => None,
             VectorStorageEnum::DenseAppendableMemmapByte(_) => None,
             VectorStorageEnum::DenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(_) => None,
             VectorStorageEnum::DenseAppendableInRam(_) => None,
             VectorStorageEnum::DenseAppendableInRamByte(_) => None,
             VectorStorageEnum::DenseAppendableInRamHalf(_) => None,
+            VectorStorageEnum::DenseAppendableInRamBFloat16(_) => None,
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => None,
             VectorStorageEnum::SparseVolatile(_) => None,
@@ -391,10 +432,17 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(s) => {
+                Some(s.multi_vector_config())
+            }
             VectorStorageEnum::MultiDenseAppendableInRam(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableInRamByte(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(s) => Some(s.multi_vector_config()),
-            VectorStorageEnum::Vde(_) => None,
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(s) => {
+                Some(s.multi_vector_config())
+            }
+            #[cfg(feature = "vde")]
+            VectorStorageEnum::Vde(s) => s.multi_vector_config(),
         }
     }
 
@@ -426,6 +474,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmapHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseMemmapBFloat16(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             VectorStorageEnum::DenseAppendableMemmap(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
@@ -435,6 +486,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             VectorStorageEnum::DenseAppendableInRam(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
@@ -444,6 +498,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableInRamHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => VectorInternal::from(SparseVector::default()),
             VectorStorageEnum::SparseVolatile(_) => VectorInternal::from(SparseVector::default()),
@@ -480,6 +537,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
             VectorStorageEnum::MultiDenseAppendableInRam(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
@@ -489,7 +549,17 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
-            VectorStorageEnum::Vde(v) => VectorInternal::from(vec![1.0; v.vector_dim()]),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
+            #[cfg(feature = "vde")]
+            VectorStorageEnum::Vde(v) => {
+                if v.multi_vector_config().is_some() {
+                    VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+                } else {
+                    VectorInternal::from(vec![1.0; v.vector_dim()])
+                }
+            }
         }
     }
 
@@ -509,6 +579,7 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseMemmapByte(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 v.size_of_available_vectors_in_bytes()
@@ -516,6 +587,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::DenseAppendableInRam(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => {
                 v.size_of_available_vectors_in_bytes()
@@ -523,6 +597,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableInRamHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::SparseVolatile(v) => v.size_of_available_vectors_in_bytes(),
@@ -551,6 +628,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::MultiDenseAppendableInRam(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
@@ -560,6 +640,10 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.size_of_available_vectors_in_bytes(),
         }
     }
@@ -580,12 +664,15 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(vs) => vs.populate()?,
             VectorStorageEnum::DenseMemmapByte(vs) => vs.populate()?,
             VectorStorageEnum::DenseMemmapHalf(vs) => vs.populate()?,
+            VectorStorageEnum::DenseMemmapBFloat16(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableMemmap(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableMemmapByte(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableMemmapHalf(vs) => vs.populate()?,
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableInRam(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableInRamByte(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableInRamHalf(vs) => vs.populate()?,
+            VectorStorageEnum::DenseAppendableInRamBFloat16(vs) => vs.populate()?,
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => {} // Can't populate as it is not mmap
             VectorStorageEnum::SparseVolatile(_) => {} // Can't populate as it is not mmap
@@ -604,9 +691,12 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => vs.populate()?,
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseAppendableInRam(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseAppendableInRamByte(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseAppendableInRamHalf(vs) => vs.populate()?,
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(vs) => vs.populate()?,
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(vs) => vs.populate()?,
         }
         Ok(())
@@ -628,12 +718,15 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseMemmapByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseMemmapHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseMemmapBFloat16(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableMemmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableMemmapByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableMemmapHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableInRam(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableInRamByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableInRamHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseAppendableInRamBFloat16(vs) => vs.clear_cache()?,
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => {} // Can't populate as it is not mmap
             VectorStorageEnum::SparseVolatile(_) => {} // Can't populate as it is not mmap
@@ -652,9 +745,12 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseAppendableInRam(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseAppendableInRamByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseAppendableInRamHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(vs) => vs.clear_cache()?,
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(vs) => vs.clear_cache()?,
         }
         Ok(())
@@ -677,12 +773,15 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_dense_bytes_opt::<P>(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_dense_bytes_opt::<P>(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.get_dense_bytes_opt::<P>(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.get_dense_bytes_opt::<P>(key),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.get_dense_bytes_opt::<P>(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => None,
             VectorStorageEnum::SparseVolatile(_) => None,
@@ -701,9 +800,12 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(_) => None,
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => None,
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(_) => None,
             VectorStorageEnum::MultiDenseAppendableInRam(_) => None,
             VectorStorageEnum::MultiDenseAppendableInRamByte(_) => None,
             VectorStorageEnum::MultiDenseAppendableInRamHalf(_) => None,
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(_) => None,
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(_) => None,
         }
     }
@@ -725,12 +827,19 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseMemmapByte(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseMemmapHalf(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableMemmap(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                return v.get_dense_vector_layout();
+            }
             VectorStorageEnum::DenseAppendableInRam(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => {
+                return v.get_dense_vector_layout();
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(_) => {}
             VectorStorageEnum::SparseVolatile(_) => {}
@@ -749,9 +858,12 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(_) => {}
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => {}
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => {}
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(_) => {}
             VectorStorageEnum::MultiDenseAppendableInRam(_) => {}
             VectorStorageEnum::MultiDenseAppendableInRamByte(_) => {}
             VectorStorageEnum::MultiDenseAppendableInRamHalf(_) => {}
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(_) => {}
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(_) => {}
         }
         Err(OperationError::service_error(
@@ -777,12 +889,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.distance(),
             VectorStorageEnum::DenseMemmapByte(v) => v.distance(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.distance(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.distance(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.distance(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.distance(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.distance(),
             VectorStorageEnum::SparseVolatile(v) => v.distance(),
@@ -801,9 +916,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.distance(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.distance(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.distance(),
         }
     }
@@ -824,12 +942,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapByte(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.datatype(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.datatype(),
             VectorStorageEnum::SparseVolatile(v) => v.datatype(),
@@ -848,9 +969,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.datatype(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.datatype(),
         }
     }
@@ -873,12 +997,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.is_on_disk(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.is_on_disk(),
             VectorStorageEnum::SparseVolatile(v) => v.is_on_disk(),
@@ -897,9 +1024,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.is_on_disk(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.is_on_disk(),
         }
     }
@@ -920,12 +1050,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.total_vector_count(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.total_vector_count(),
             VectorStorageEnum::SparseVolatile(v) => v.total_vector_count(),
@@ -944,9 +1077,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.total_vector_count(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.total_vector_count(),
         }
     }
@@ -967,12 +1103,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.get_vector::<P>(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.get_vector::<P>(key),
             VectorStorageEnum::SparseVolatile(v) => v.get_vector::<P>(key),
@@ -991,9 +1130,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.get_vector::<P>(key),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.get_vector::<P>(key),
         }
     }
@@ -1014,12 +1156,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.get_vector_opt::<P>(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::SparseVolatile(v) => v.get_vector_opt::<P>(key),
@@ -1038,9 +1183,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.get_vector_opt::<P>(key),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.get_vector_opt::<P>(key),
         }
     }
@@ -1066,6 +1214,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseMemmapByte(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseMemmapHalf(v) => v.insert_vector(key, vector, hw_counter),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 v.insert_vector(key, vector, hw_counter)
@@ -1073,6 +1222,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                v.insert_vector(key, vector, hw_counter)
+            }
             VectorStorageEnum::DenseAppendableInRam(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseAppendableInRamByte(v) => {
                 v.insert_vector(key, vector, hw_counter)
@@ -1080,6 +1232,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableInRamHalf(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => {
+                v.insert_vector(key, vector, hw_counter)
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::SparseVolatile(v) => v.insert_vector(key, vector, hw_counter),
@@ -1108,6 +1263,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                v.insert_vector(key, vector, hw_counter)
+            }
             VectorStorageEnum::MultiDenseAppendableInRam(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
@@ -1117,6 +1275,10 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => {
+                v.insert_vector(key, vector, hw_counter)
+            }
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.insert_vector(key, vector, hw_counter),
         }
     }
@@ -1141,6 +1303,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseMemmapByte(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseMemmapHalf(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 v.update_from(other_vectors, stopped)
@@ -1148,9 +1311,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => {
+                v.update_from(other_vectors, stopped)
+            }
             VectorStorageEnum::DenseAppendableInRam(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => {
+                v.update_from(other_vectors, stopped)
+            }
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::SparseVolatile(v) => v.update_from(other_vectors, stopped),
@@ -1175,6 +1344,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => {
+                v.update_from(other_vectors, stopped)
+            }
             VectorStorageEnum::MultiDenseAppendableInRam(v) => {
                 v.update_from(other_vectors, stopped)
             }
@@ -1184,6 +1356,10 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => {
+                v.update_from(other_vectors, stopped)
+            }
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.update_from(other_vectors, stopped),
         }
     }
@@ -1204,12 +1380,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapByte(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.flusher(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.flusher(),
             VectorStorageEnum::SparseVolatile(v) => v.flusher(),
@@ -1228,9 +1407,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.flusher(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.flusher(),
         }
     }
@@ -1251,12 +1433,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.files(),
             VectorStorageEnum::DenseMemmapByte(v) => v.files(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.files(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.files(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.files(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.files(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.files(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.files(),
             VectorStorageEnum::SparseVolatile(v) => v.files(),
@@ -1275,9 +1460,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.files(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.files(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.files(),
         }
     }
@@ -1298,12 +1486,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.immutable_files(),
             VectorStorageEnum::DenseMemmapByte(v) => v.immutable_files(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.immutable_files(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.immutable_files(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.immutable_files(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.immutable_files(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.immutable_files(),
             VectorStorageEnum::SparseVolatile(v) => v.immutable_files(),
@@ -1322,9 +1513,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.immutable_files(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.immutable_files(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.immutable_files(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.immutable_files(),
         }
     }
@@ -1345,12 +1539,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.delete_vector(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.delete_vector(key),
             VectorStorageEnum::SparseVolatile(v) => v.delete_vector(key),
@@ -1369,9 +1566,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.delete_vector(key),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.delete_vector(key),
         }
     }
@@ -1392,12 +1592,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.is_deleted_vector(key),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseVolatile(v) => v.is_deleted_vector(key),
@@ -1416,9 +1619,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.is_deleted_vector(key),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.is_deleted_vector(key),
         }
     }
@@ -1439,12 +1645,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.deleted_vector_count(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseVolatile(v) => v.deleted_vector_count(),
@@ -1463,9 +1672,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.deleted_vector_count(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.deleted_vector_count(),
         }
     }
@@ -1486,12 +1698,15 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseMemmapBFloat16(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableMemmapBFloat16(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableInRamBFloat16(v) => v.deleted_vector_bitslice(),
             #[cfg(feature = "rocksdb")]
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseVolatile(v) => v.deleted_vector_bitslice(),
@@ -1510,9 +1725,12 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseAppendableMemmapBFloat16(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseAppendableInRamBFloat16(v) => v.deleted_vector_bitslice(),
+            #[cfg(feature = "vde")]
             VectorStorageEnum::Vde(v) => v.deleted_vector_bitslice(),
         }
     }
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use rand::SeedableRng;
+use rand::prelude::StdRng;
+use rstest::rstest;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_dense_byte_vector;
+use segment::index::VectorIndex;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::segment_constructor::VectorIndexBuildArgs;
+use segment::segment_constructor::build_segment;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::types::{
+    Distance, HnswConfig, HnswGlobalConfig, Indexes, SearchParams, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+};
+use segment::vector_storage::VectorStorageEnum;
+use tempfile::Builder;
+
+/// Binary vectors are stored as bytes, scored exactly as the same bytes stored as floats, and
+/// HNSW over them finds nearly all exact nearest points
+#[rstest]
+#[case::hamming(Distance::Hamming)]
+#[case::jaccard(Distance::Jaccard)]
+fn test_binary_storage_hnsw(#[case] distance: Distance) {
+    let stopped = AtomicBool::new(false);
+
+    // 256 bits
+    let dim = 32;
+    let num_vectors: u64 = 2_000;
+    let top = 10;
+    let attempts = 50;
+    let min_recall = 0.9;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir_float = Builder::new()
+        .prefix("segment_dir_float")
+        .tempdir()
+        .unwrap();
+    let dir_binary = Builder::new()
+        .prefix("segment_dir_binary")
+        .tempdir()
+        .unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir_binary").tempdir().unwrap();
+
+    let config_binary = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: Some(VectorStorageDatatype::Binary),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment_float = build_simple_segment(dir_float.path(), dim, distance).unwrap();
+    let mut segment_binary = build_segment(dir_binary.path(), &config_binary, true).unwrap();
+    // check that `segment_binary` uses byte storage
+    {
+        let borrowed_storage = segment_binary.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .borrow();
+        let raw_storage: &VectorStorageEnum = &borrowed_storage;
+        #[cfg(feature = "rocksdb")]
+        assert!(matches!(
+            raw_storage,
+            &VectorStorageEnum::DenseSimpleByte(_)
+        ));
+        #[cfg(not(feature = "rocksdb"))]
+        assert!(matches!(
+            raw_storage,
+            &VectorStorageEnum::DenseAppendableInRamByte(_),
+        ));
+    }
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut vectors = Vec::new();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_dense_byte_vector(&mut rng, dim);
+
+        segment_float
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment_binary
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        vectors.push(vector);
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 64,
+        full_scan_threshold: 0,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+        copy_vectors: None,
+        matryoshka_dim: None,
+    };
+
+    let permit = Arc::new(ResourcePermit::dummy(1));
+    let hnsw_index = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir.path(),
+            id_tracker: segment_binary.id_tracker.clone(),
+            vector_storage: segment_binary.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            quantized_vectors: segment_binary.vector_data[DEFAULT_VECTOR_NAME]
+                .quantized_vectors
+                .clone(),
+            payload_index: segment_binary.payload_index.clone(),
+            hnsw_config,
+        },
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+        },
+    )
+    .unwrap();
+
+    let mut total_recall = 0.0;
+    for i in 0..attempts {
+        // Stored vectors are their own nearest points, at zero distance
+        let query: QueryVector = vectors[i * 7].clone().into();
+
+        let plain_result_float = segment_float.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let plain_result_binary = segment_binary.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        assert_eq!(plain_result_binary[0][0].idx as usize, i * 7);
+        assert_eq!(plain_result_binary[0][0].score, 0.0);
+        // Ties are ordered the same way, both storages compute the same bit counts
+        assert_eq!(plain_result_float, plain_result_binary);
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(64),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        // Points tied with the last exact result are as good as any other
+        let worst_score = plain_result_binary[0].last().unwrap().score;
+        let hits = index_result[0]
+            .iter()
+            .filter(|point| point.score >= worst_score)
+            .count();
+        total_recall += hits as f64 / top as f64;
+    }
+
+    let recall = total_recall / attempts as f64;
+    assert!(recall >= min_recall, "recall@{top} is too low: {recall}");
+}
@@ -43,6 +43,7 @@ fn compare_search_result(result_a: &[Vec<ScoredPointOffset>], result_b: &[Vec<Sc
 #[rstest]
 #[case::nearest(QueryVariant::Nearest, VectorStorageDatatype::Uint8, 32, 10)]
 #[case::nearest(QueryVariant::Nearest, VectorStorageDatatype::Float16, 32, 10)]
+#[case::nearest(QueryVariant::Nearest, VectorStorageDatatype::BFloat16, 32, 10)]
 #[case::discovery(QueryVariant::Discovery, VectorStorageDatatype::Uint8, 128, 20)]
 #[case::reco_best_score(QueryVariant::RecoBestScore, VectorStorageDatatype::Float16, 64, 20)]
 #[case::reco_sum_scores(QueryVariant::RecoSumScores, VectorStorageDatatype::Float16, 64, 20)]
@@ -83,7 +84,11 @@ fn test_byte_storage_hnsw(
             VectorDataConfig {
                 size: dim,
                 distance,
-                storage_type: VectorStorageType::default(),
+                // BFloat16 has no RocksDB-backed storage, keep it in RAM over mmap
+                storage_type: match storage_data_type {
+                    VectorStorageDatatype::BFloat16 => VectorStorageType::InRamChunkedMmap,
+                    _ => VectorStorageType::default(),
+                },
                 index: Indexes::Plain {},
                 quantization_config: None,
                 multivector_config: None,
@@ -98,7 +103,7 @@ fn test_byte_storage_hnsw(
 
     let mut segment_float = build_simple_segment(dir_float.path(), dim, distance).unwrap();
     let mut segment_byte = build_segment(dir_byte.path(), &config_byte, true).unwrap();
-    // check that `segment_byte` uses byte or half storage
+    // check that `segment_byte` uses byte, half or bfloat16 storage
     {
         let borrowed_storage = segment_byte.vector_data[DEFAULT_VECTOR_NAME]
             .vector_storage
@@ -107,13 +112,16 @@ fn test_byte_storage_hnsw(
         #[cfg(feature = "rocksdb")]
         assert!(matches!(
             raw_storage,
-            &VectorStorageEnum::DenseSimpleByte(_) | &VectorStorageEnum::DenseSimpleHalf(_),
+            &VectorStorageEnum::DenseSimpleByte(_)
+                | &VectorStorageEnum::DenseSimpleHalf(_)
+                | &VectorStorageEnum::DenseAppendableInRamBFloat16(_),
         ));
         #[cfg(not(feature = "rocksdb"))]
         assert!(matches!(
             raw_storage,
             &VectorStorageEnum::DenseAppendableInRamByte(_)
-                | &VectorStorageEnum::DenseAppendableInRamHalf(_),
+                | &VectorStorageEnum::DenseAppendableInRamHalf(_)
+                | &VectorStorageEnum::DenseAppendableInRamBFloat16(_),
         ));
     }
 
@@ -179,6 +187,7 @@ fn test_byte_storage_hnsw(
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
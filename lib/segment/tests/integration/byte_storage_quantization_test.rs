@@ -21,8 +21,8 @@ use segment::segment_constructor::build_segment;
 use segment::types::{
     BinaryQuantizationConfig, CompressionRatio, Condition, Distance, FieldCondition, Filter,
     HnswConfig, HnswGlobalConfig, Indexes, PayloadSchemaType, ProductQuantizationConfig,
-    QuantizationSearchParams, Range, ScalarQuantizationConfig, SearchParams, SegmentConfig,
-    SeqNumberType, VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+    QuantizationSearchParams, Range, ScalarQuantizationConfig, ScalarType, SearchParams,
+    SegmentConfig, SeqNumberType, VectorDataConfig, VectorStorageDatatype, VectorStorageType,
 };
 use segment::vector_storage::VectorStorageEnum;
 use segment::vector_storage::quantized::quantized_vectors::{
@@ -32,6 +32,7 @@ use tempfile::Builder;
 
 enum QuantizationVariant {
     Scalar,
+    ScalarInt4,
     PQ,
     Binary,
 }
@@ -41,8 +42,8 @@ where
     R: Rng + ?Sized,
 {
     match data_type {
-        VectorStorageDatatype::Float32 => unreachable!(),
-        VectorStorageDatatype::Float16 => {
+        VectorStorageDatatype::Float32 | VectorStorageDatatype::Binary => unreachable!(),
+        VectorStorageDatatype::Float16 | VectorStorageDatatype::BFloat16 => {
             let mut vector = segment::fixtures::payload_fixtures::random_vector(rnd_gen, dim);
             vector.iter_mut().for_each(|x| *x -= 0.5);
             vector
@@ -170,6 +171,24 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     32, // ef
     80., // min_acc out of 100
 )]
+#[case::nearest_scalar_dot(
+    QueryVariant::Nearest,
+    VectorStorageDatatype::BFloat16,
+    QuantizationVariant::Scalar,
+    Distance::Dot,
+    32, // dim
+    32, // ef
+    80., // min_acc out of 100
+)]
+#[case::nearest_scalar_int4_dot(
+    QueryVariant::Nearest,
+    VectorStorageDatatype::Float16,
+    QuantizationVariant::ScalarInt4,
+    Distance::Dot,
+    64, // dim
+    32, // ef
+    60., // min_acc out of 100
+)]
 #[case::nearest_scalar_cosine(
     QueryVariant::Nearest,
     VectorStorageDatatype::Uint8,
@@ -222,7 +241,11 @@ fn test_byte_storage_binary_quantization_hnsw(
             VectorDataConfig {
                 size: dim,
                 distance,
-                storage_type: VectorStorageType::default(),
+                // BFloat16 has no RocksDB-backed storage, keep it in RAM over mmap
+                storage_type: match storage_data_type {
+                    VectorStorageDatatype::BFloat16 => VectorStorageType::InRamChunkedMmap,
+                    _ => VectorStorageType::default(),
+                },
                 index: Indexes::Plain {},
                 quantization_config: None,
                 multivector_config: None,
@@ -236,7 +259,7 @@ fn test_byte_storage_binary_quantization_hnsw(
     let int_key = "int";
 
     let mut segment_byte = build_segment(dir_byte.path(), &config_byte, true).unwrap();
-    // check that `segment_byte` uses byte or half storage
+    // check that `segment_byte` uses byte, half or bfloat16 storage
     {
         let borrowed_storage = segment_byte.vector_data[DEFAULT_VECTOR_NAME]
             .vector_storage
@@ -245,13 +268,16 @@ fn test_byte_storage_binary_quantization_hnsw(
         #[cfg(feature = "rocksdb")]
         assert!(matches!(
             raw_storage,
-            &VectorStorageEnum::DenseSimpleByte(_) | &VectorStorageEnum::DenseSimpleHalf(_),
+            &VectorStorageEnum::DenseSimpleByte(_)
+                | &VectorStorageEnum::DenseSimpleHalf(_)
+                | &VectorStorageEnum::DenseAppendableInRamBFloat16(_),
         ));
         #[cfg(not(feature = "rocksdb"))]
         assert!(matches!(
             raw_storage,
             &VectorStorageEnum::DenseAppendableInRamByte(_)
-                | &VectorStorageEnum::DenseAppendableInRamHalf(_),
+                | &VectorStorageEnum::DenseAppendableInRamHalf(_)
+                | &VectorStorageEnum::DenseAppendableInRamBFloat16(_),
         ));
     }
 
@@ -294,9 +320,16 @@ fn test_byte_storage_binary_quantization_hnsw(
             always_ram: None,
         }
         .into(),
+        QuantizationVariant::ScalarInt4 => ScalarQuantizationConfig {
+            r#type: ScalarType::Int4,
+            quantile: None,
+            always_ram: None,
+        }
+        .into(),
         QuantizationVariant::PQ => ProductQuantizationConfig {
             compression: CompressionRatio::X8,
             always_ram: None,
+            opq: None,
         }
         .into(),
         QuantizationVariant::Binary => BinaryQuantizationConfig {
@@ -332,6 +365,7 @@ fn test_byte_storage_binary_quantization_hnsw(
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -0,0 +1,210 @@
+use std::collections::{HashMap, HashSet};
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::{random_int_payload, random_vector};
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment::Segment;
+use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::segment_constructor::{build_segment, load_segment};
+use segment::types::{
+    CompressionRatio, Condition, DiskAnnConfig, Distance, FieldCondition, Filter, HnswGlobalConfig,
+    Indexes, PayloadSchemaType, PointIdType, Range, SearchParams, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+const INT_KEY: &str = "int";
+const DIM: usize = 16;
+const NUM_POINTS: u64 = 2_000;
+const TOP: usize = 10;
+const ATTEMPTS: usize = 30;
+
+/// Minimal average recall@k accepted from the DiskANN index
+const MIN_RECALL: f64 = 0.9;
+
+fn segment_config(index: Indexes, distance: Distance) -> SegmentConfig {
+    SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: DIM,
+                distance,
+                storage_type: VectorStorageType::default(),
+                index,
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    }
+}
+
+fn search(
+    segment: &Segment,
+    query: &QueryVector,
+    filter: Option<&Filter>,
+    params: &SearchParams,
+) -> Vec<PointIdType> {
+    segment
+        .search(
+            DEFAULT_VECTOR_NAME,
+            query,
+            &WithPayload::default(),
+            &false.into(),
+            filter,
+            TOP,
+            Some(params),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|point| point.id)
+        .collect()
+}
+
+/// DiskANN index finds nearly the same points as exact search, with and without filters, and
+/// serves the same results after the segment is reopened
+#[rstest]
+#[case::cosine_on_disk(Distance::Cosine, true)]
+#[case::euclid_in_ram(Distance::Euclid, false)]
+#[case::dot_on_disk(Distance::Dot, true)]
+fn test_diskann_recall(#[case] distance: Distance, #[case] on_disk: bool) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let hw_counter = HardwareCounterCell::new();
+    let dir = Builder::new().prefix("diskann_plain").tempdir().unwrap();
+
+    let mut plain_segment = build_segment(
+        dir.path(),
+        &segment_config(Indexes::Plain {}, distance),
+        true,
+    )
+    .unwrap();
+    for n in 0..NUM_POINTS {
+        let idx = n.into();
+        let vector = random_vector(&mut rng, DIM);
+        let payload = payload_json! {INT_KEY: random_int_payload(&mut rng, 1..=1)};
+        plain_segment
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        plain_segment
+            .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+    for n in 0..50 {
+        let idx = rng.random_range(0..NUM_POINTS).into();
+        plain_segment
+            .delete_point(NUM_POINTS + n, idx, &hw_counter)
+            .unwrap();
+    }
+    plain_segment
+        .create_field_index(
+            2 * NUM_POINTS,
+            &JsonPath::new(INT_KEY),
+            Some(&PayloadSchemaType::Integer.into()),
+            &hw_counter,
+        )
+        .unwrap();
+
+    let diskann_index = Indexes::DiskAnn(DiskAnnConfig {
+        max_degree: 16,
+        build_list_size: 50,
+        search_list_size: 50,
+        // Vectors are short, keep enough of them in PQ codes to navigate the graph
+        pq_compression: CompressionRatio::X4,
+        full_scan_threshold: Some(0),
+        on_disk: Some(on_disk),
+        ..Default::default()
+    });
+    let segment_base_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let segment_builder_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment_builder = SegmentBuilder::new(
+        segment_base_dir.path(),
+        segment_builder_dir.path(),
+        &segment_config(diskann_index, distance),
+        &HnswGlobalConfig::default(),
+    )
+    .unwrap();
+    segment_builder
+        .update(&[&plain_segment], &false.into())
+        .unwrap();
+    let diskann_segment = segment_builder
+        .build(
+            ResourcePermit::dummy(num_rayon_threads(0) as u32),
+            &false.into(),
+            &mut rng,
+            &hw_counter,
+        )
+        .unwrap();
+    assert_eq!(
+        plain_segment.available_point_count(),
+        diskann_segment.available_point_count(),
+    );
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+        JsonPath::new(INT_KEY),
+        Range {
+            lt: None,
+            gt: None,
+            gte: Some(100.0),
+            lte: Some(300.0),
+        },
+    )));
+    let exact_params = SearchParams {
+        exact: true,
+        ..Default::default()
+    };
+    let params = SearchParams::default();
+
+    let queries: Vec<QueryVector> = (0..ATTEMPTS)
+        .map(|_| QueryVector::Nearest(random_vector(&mut rng, DIM).into()))
+        .collect();
+
+    for filter in [None, Some(&filter)] {
+        let mut total_recall = 0.0;
+        for query in &queries {
+            let expected = search(&plain_segment, query, filter, &exact_params);
+            let found = search(&diskann_segment, query, filter, &params);
+            assert_eq!(expected.len(), TOP);
+            assert_eq!(found.len(), TOP);
+
+            let expected: HashSet<_> = expected.into_iter().collect();
+            let hits = found.iter().filter(|id| expected.contains(id)).count();
+            total_recall += hits as f64 / TOP as f64;
+        }
+        let recall = total_recall / ATTEMPTS as f64;
+        assert!(
+            recall >= MIN_RECALL,
+            "DiskANN recall@{TOP} is too low: {recall}, filtered: {}",
+            filter.is_some(),
+        );
+    }
+
+    let segment_path = diskann_segment.current_path.clone();
+    let found_before: Vec<_> = queries
+        .iter()
+        .map(|query| search(&diskann_segment, query, Some(&filter), &params))
+        .collect();
+    drop(diskann_segment);
+
+    let reopened_segment = load_segment(&segment_path, &false.into()).unwrap().unwrap();
+    for (query, found_before) in queries.iter().zip(found_before) {
+        assert_eq!(
+            search(&reopened_segment, query, Some(&filter), &params),
+            found_before,
+        );
+    }
+}
@@ -79,6 +79,7 @@ fn exact_search_test() {
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     payload_index_ptr
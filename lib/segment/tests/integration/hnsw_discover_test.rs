@@ -95,6 +95,7 @@ fn hnsw_discover_precision() {
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -222,6 +223,7 @@ fn filtered_hnsw_discover_precision() {
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{
+    DEFAULT_VECTOR_NAME, DenseVector, QueryVector, only_default_vector,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::index::VectorIndex;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::segment_constructor::VectorIndexBuildArgs;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::types::{Distance, HnswConfig, HnswGlobalConfig, SearchParams, SeqNumberType};
+use tempfile::Builder;
+
+/// Imitates a Matryoshka embedding: leading dimensions carry most of the information
+fn matryoshka_vector(rng: &mut StdRng, dim: usize, head_dim: usize) -> DenseVector {
+    (0..dim)
+        .map(|i| {
+            let value: f32 = rng.random_range(-1.0..1.0);
+            if i < head_dim { value } else { value * 0.1 }
+        })
+        .collect()
+}
+
+/// A graph built on the first dimensions finds nearly all exact nearest points, and found
+/// points are scored with all dimensions
+///
+/// Graphs built with all dimensions ignore the search dimensions.
+#[rstest]
+#[case::built_truncated(Distance::Dot, Some(16), None)]
+#[case::built_truncated_cosine(Distance::Cosine, Some(16), None)]
+#[case::searched_truncated_full_graph(Distance::Dot, None, Some(16))]
+#[case::searched_full(Distance::Dot, Some(16), Some(64))]
+fn test_hnsw_matryoshka_search(
+    #[case] distance: Distance,
+    #[case] build_dim: Option<usize>,
+    #[case] search_dim: Option<usize>,
+) {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 64;
+    let head_dim = 16;
+    let num_vectors: u64 = 2_000;
+    let top = 10;
+    let ef = 64;
+    let attempts: usize = 50;
+    let min_recall = 0.9;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut segment = build_simple_segment(dir.path(), dim, distance).unwrap();
+    for n in 0..num_vectors {
+        let vector = matryoshka_vector(&mut rng, dim, head_dim);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 64,
+        full_scan_threshold: 0,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+        copy_vectors: None,
+        matryoshka_dim: build_dim,
+    };
+
+    let permit = Arc::new(ResourcePermit::dummy(1));
+    let hnsw_index = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir.path(),
+            id_tracker: segment.id_tracker.clone(),
+            vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+                .quantized_vectors
+                .clone(),
+            payload_index: segment.payload_index.clone(),
+            hnsw_config,
+        },
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+        },
+    )
+    .unwrap();
+
+    let search_params = SearchParams {
+        hnsw_ef: Some(ef),
+        matryoshka_dim: search_dim,
+        ..Default::default()
+    };
+
+    let mut total_recall = 0.0;
+    for _ in 0..attempts {
+        let query: QueryVector = matryoshka_vector(&mut rng, dim, head_dim).into();
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&search_params),
+                &Default::default(),
+            )
+            .unwrap();
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+
+        assert_eq!(index_result[0].len(), top);
+        if build_dim.is_none() {
+            let full_result = hnsw_index
+                .search(
+                    &[&query],
+                    None,
+                    top,
+                    Some(&SearchParams {
+                        hnsw_ef: Some(ef),
+                        ..Default::default()
+                    }),
+                    &Default::default(),
+                )
+                .unwrap();
+            assert_eq!(index_result, full_result);
+        }
+
+        let expected: HashMap<_, _> = plain_result[0]
+            .iter()
+            .map(|point| (point.idx, point.score))
+            .collect();
+        let mut hits: usize = 0;
+        for point in &index_result[0] {
+            if let Some(&score) = expected.get(&point.idx) {
+                assert!((point.score - score).abs() < 1e-4);
+                hits += 1;
+            }
+        }
+        total_recall += hits as f64 / top as f64;
+    }
+
+    let recall = total_recall / attempts as f64;
+    assert!(recall >= min_recall, "recall@{top} is too low: {recall}");
+}
@@ -26,7 +26,7 @@ use segment::types::PayloadSchemaType::Keyword;
 use segment::types::{
     CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswConfig, HnswGlobalConfig,
     Indexes, ProductQuantizationConfig, QuantizationConfig, QuantizationSearchParams,
-    ScalarQuantizationConfig, SearchParams,
+    RabitqQuantizationConfig, ScalarQuantizationConfig, SearchParams,
 };
 use segment::vector_storage::quantized::quantized_vectors::{
     QuantizedVectors, QuantizedVectorsStorageType,
@@ -117,6 +117,7 @@ fn hnsw_quantized_search_test(
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -366,6 +367,7 @@ fn hnsw_product_quantization_cosine_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
         }
         .into(),
     );
@@ -379,6 +381,7 @@ fn hnsw_product_quantization_euclid_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
         }
         .into(),
     );
@@ -392,6 +395,42 @@ fn hnsw_product_quantization_manhattan_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn hnsw_product_quantization_opq_cosine_test() {
+    hnsw_quantized_search_test(
+        Distance::Cosine,
+        1003,
+        ProductQuantizationConfig {
+            compression: CompressionRatio::X4,
+            always_ram: Some(true),
+            opq: Some(true),
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn hnsw_rabitq_quantization_cosine_test() {
+    hnsw_quantized_search_test(
+        Distance::Cosine,
+        5003,
+        RabitqQuantizationConfig { always_ram: None }.into(),
+    );
+}
+
+#[test]
+fn hnsw_rabitq_quantization_euclid_test() {
+    hnsw_quantized_search_test(
+        Distance::Euclid,
+        5003,
+        RabitqQuantizationConfig {
+            always_ram: Some(true),
         }
         .into(),
     );
@@ -424,6 +463,7 @@ fn test_build_hnsw_using_quantization() {
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     });
 
     let permit_cpu_count = num_rayon_threads(0);
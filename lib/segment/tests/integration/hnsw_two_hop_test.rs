@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::types::TelemetryDetail;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::{random_int_payload, random_vector};
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::{PayloadIndex, VectorIndex};
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment_constructor::VectorIndexBuildArgs;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, HnswConfig, HnswGlobalConfig, PayloadSchemaType,
+    Range, SearchParams, SeqNumberType,
+};
+use tempfile::Builder;
+
+/// Filters matching a few percent of points, without payload links for the filtered field, are
+/// searched with two-hop traversal and still find nearly all expected points
+///
+/// Filters with payload links, or matching a large fraction of points, keep direct traversal.
+/// Fields indexed only after the graph was built have no payload links. Graphs without record of
+/// their payload linked fields keep direct traversal.
+#[rstest]
+#[case::selective_without_payload_links(Some(0), false, false, 15, true)]
+#[case::selective_with_payload_links(None, false, false, 15, false)]
+#[case::selective_indexed_after_build(None, true, false, 15, true)]
+#[case::selective_unknown_payload_links(None, false, true, 15, false)]
+#[case::wide_without_payload_links(Some(0), false, false, 150, false)]
+fn test_hnsw_two_hop_traversal(
+    #[case] payload_m: Option<usize>,
+    #[case] index_after_build: bool,
+    #[case] unknown_payload_links: bool,
+    #[case] range_size: i64,
+    #[case] expect_two_hop: bool,
+) {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 16;
+    let m = 16;
+    let num_vectors: u64 = 10_000;
+    let ef_construct = 64;
+    let ef = 64;
+    let top = 10;
+    let distance = Distance::Cosine;
+    let full_scan_threshold = 1; // KB
+    let min_recall = 0.9;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let int_key = "int";
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut segment = build_simple_segment(dir.path(), dim, distance).unwrap();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rng, dim);
+        let payload = payload_json! {int_key: random_int_payload(&mut rng, 1..=1)};
+
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+
+    let payload_index_ptr = segment.payload_index.clone();
+    let set_indexed = || {
+        payload_index_ptr
+            .borrow_mut()
+            .set_indexed(
+                &JsonPath::new(int_key),
+                PayloadSchemaType::Integer,
+                &hw_counter,
+            )
+            .unwrap();
+    };
+    if !index_after_build {
+        set_indexed();
+    }
+
+    let hnsw_config = HnswConfig {
+        m,
+        ef_construct,
+        full_scan_threshold,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m,
+        copy_vectors: None,
+        matryoshka_dim: None,
+    };
+
+    let open_args = || HnswIndexOpenArgs {
+        path: hnsw_dir.path(),
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .clone(),
+        payload_index: payload_index_ptr.clone(),
+        hnsw_config: hnsw_config.clone(),
+    };
+
+    let permit = Arc::new(ResourcePermit::dummy(1));
+    let mut hnsw_index = HNSWIndex::build(
+        open_args(),
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+        },
+    )
+    .unwrap();
+
+    if index_after_build {
+        set_indexed();
+    }
+
+    if unknown_payload_links {
+        // Graphs built by older versions have no record of the payload linked fields
+        let config_path = hnsw_dir.path().join("hnsw_config.json");
+        let mut graph_config: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&config_path).unwrap()).unwrap();
+        graph_config
+            .as_object_mut()
+            .unwrap()
+            .remove("payload_linked_fields")
+            .unwrap();
+        std::fs::write(&config_path, graph_config.to_string()).unwrap();
+
+        drop(hnsw_index);
+        hnsw_index = HNSWIndex::open(open_args()).unwrap();
+    }
+
+    let attempts = 50;
+    let mut total_recall = 0.0;
+    for _ in 0..attempts {
+        let query: QueryVector = random_vector(&mut rng, dim).into();
+
+        let left_range = rng.random_range(0..500 - range_size);
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+            JsonPath::new(int_key),
+            Range {
+                lt: None,
+                gt: None,
+                gte: Some(left_range as f64),
+                lte: Some((left_range + range_size - 1) as f64),
+            },
+        )));
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                Some(&filter),
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(ef),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], Some(&filter), top, None, &Default::default())
+            .unwrap();
+
+        let expected: HashSet<_> = plain_result[0].iter().map(|point| point.idx).collect();
+        let hits = index_result[0]
+            .iter()
+            .filter(|point| expected.contains(&point.idx))
+            .count();
+        total_recall += hits as f64 / expected.len() as f64;
+    }
+
+    // Check that the search was performed using the HNSW graph with expected traversal
+    let telemetry = hnsw_index.get_telemetry_data(TelemetryDetail::default());
+    let (two_hop_count, direct_count) = if expect_two_hop {
+        (attempts, 0)
+    } else {
+        (0, attempts)
+    };
+    assert_eq!(telemetry.filtered_two_hop.count, two_hop_count);
+    assert_eq!(telemetry.filtered_large_cardinality.count, direct_count);
+
+    let recall = total_recall / attempts as f64;
+    assert!(recall >= min_recall, "recall@{top} is too low: {recall}");
+}
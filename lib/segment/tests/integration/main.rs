@@ -1,7 +1,9 @@
 mod batch_search_test;
+mod binary_storage_hnsw_test;
 mod byte_storage_hnsw_test;
 mod byte_storage_quantization_test;
 mod disbalanced_vectors_test;
+mod diskann_test;
 mod exact_search_test;
 mod fail_recovery_test;
 mod filtering_context_check;
@@ -11,10 +13,14 @@ mod fixtures;
 mod gpu_hnsw_test;
 mod hnsw_discover_test;
 mod hnsw_incremental_build;
+mod hnsw_matryoshka_test;
 mod hnsw_quantized_search_test;
+mod hnsw_two_hop_test;
+mod ivf_test;
 mod multivector_filtrable_hnsw_test;
 mod multivector_hnsw_test;
 mod multivector_quantization_test;
+mod multivector_token_pooling_test;
 mod nested_filtering_test;
 mod payload_index_test;
 mod scroll_filtering_test;
@@ -23,3 +29,11 @@ mod segment_on_disk_snapshot;
 mod segment_tests;
 mod sparse_discover_test;
 mod sparse_vector_index_search_tests;
+#[cfg(feature = "vde")]
+mod vde_custom_query_test;
+#[cfg(feature = "vde")]
+mod vde_index_test;
+#[cfg(feature = "vde")]
+mod vde_recall_test;
+#[cfg(feature = "vde")]
+mod vde_segment_snapshot_test;
@@ -125,6 +125,7 @@ fn test_multi_filterable_hnsw(
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     let permit_cpu_count = 1; // single-threaded for deterministic build
@@ -110,6 +110,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
         on_disk: Some(false),
         payload_m: None,
         copy_vectors: None,
+        matryoshka_dim: None,
     };
 
     // single threaded mode to guarantee equivalency between single and multi hnsw
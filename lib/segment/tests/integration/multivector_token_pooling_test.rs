@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use rand::SeedableRng;
+use rand::prelude::StdRng;
+use rstest::rstest;
+use segment::data_types::vectors::{
+    DEFAULT_VECTOR_NAME, MultiDenseVectorInternal, QueryVector, VectorInternal,
+    only_default_multi_vector,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_multi_vector;
+use segment::segment_constructor::build_segment;
+use segment::spaces::simple::DotProductMetric;
+use segment::types::{
+    Distance, Indexes, MultiVectorComparator, MultiVectorConfig, SearchParams, SegmentConfig,
+    SeqNumberType, TokenPoolingConfig, VectorDataConfig, VectorStorageType,
+};
+use segment::vector_storage::query_scorer::{score_chamfer_similarity, score_max_similarity};
+use tempfile::Builder;
+
+const TOKENS_COUNT: usize = 10;
+const TARGET_COUNT: usize = 4;
+
+fn expected_score(
+    comparator: MultiVectorComparator,
+    query: &MultiDenseVectorInternal,
+    stored: &MultiDenseVectorInternal,
+) -> f32 {
+    match comparator {
+        MultiVectorComparator::MaxSim => {
+            score_max_similarity::<f32, DotProductMetric>(query.into(), stored.into())
+        }
+        MultiVectorComparator::MaxSimNormalized => {
+            score_max_similarity::<f32, DotProductMetric>(query.into(), stored.into())
+                / query.vectors_count() as f32
+        }
+        MultiVectorComparator::Chamfer => {
+            score_chamfer_similarity::<f32, DotProductMetric>(query.into(), stored.into())
+        }
+    }
+}
+
+#[rstest]
+#[case::max_sim(MultiVectorComparator::MaxSim)]
+#[case::max_sim_normalized(MultiVectorComparator::MaxSimNormalized)]
+#[case::chamfer(MultiVectorComparator::Chamfer)]
+fn test_multivector_token_pooling(#[case] comparator: MultiVectorComparator) {
+    let num_points: u64 = 50;
+    let dim = 8;
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::InRamChunkedMmap,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: Some(MultiVectorConfig {
+                    comparator,
+                    token_pooling: Some(TokenPoolingConfig {
+                        target_count: TARGET_COUNT,
+                    }),
+                }),
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    for n in 0..num_points {
+        // short multivectors are stored as is
+        let tokens_count = if n % 2 == 0 { TOKENS_COUNT } else { 2 };
+        let vector = random_multi_vector(&mut rng, dim, tokens_count);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_multi_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    let mut stored_vectors = HashMap::new();
+    for n in 0..num_points {
+        let stored = segment
+            .vector(DEFAULT_VECTOR_NAME, n.into(), &hw_counter)
+            .unwrap()
+            .unwrap();
+        let VectorInternal::MultiDense(stored) = stored else {
+            panic!("multivector expected");
+        };
+        let expected_count = if n % 2 == 0 { TARGET_COUNT } else { 2 };
+        assert_eq!(stored.vectors_count(), expected_count);
+        stored_vectors.insert(n, stored);
+    }
+
+    let query = random_multi_vector(&mut rng, dim, 3);
+    let result = segment
+        .search(
+            DEFAULT_VECTOR_NAME,
+            &QueryVector::from(query.clone()),
+            &Default::default(),
+            &false.into(),
+            None,
+            num_points as usize,
+            Some(&SearchParams {
+                exact: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+    assert_eq!(result.len(), num_points as usize);
+
+    for scored_point in result {
+        let segment::types::ExtendedPointId::NumId(n) = scored_point.id else {
+            panic!("numeric id expected");
+        };
+        let expected = expected_score(comparator, &query, &stored_vectors[&n]);
+        assert!(
+            (scored_point.score - expected).abs() < 1e-4,
+            "{} != {expected}",
+            scored_point.score,
+        );
+    }
+}
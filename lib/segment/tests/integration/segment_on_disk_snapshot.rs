@@ -117,6 +117,7 @@ fn test_on_disk_segment_snapshot(#[case] format: SnapshotFormat) {
                     on_disk: Some(true), // mmap index
                     payload_m: None,
                     copy_vectors: None,
+                    matryoshka_dim: None,
                 }),
                 quantization_config: None,
                 multivector_config: None,
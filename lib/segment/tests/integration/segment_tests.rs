@@ -275,6 +275,9 @@ fn test_update_named_vector() {
         exact: true,
         quantization: None,
         indexed_only: false,
+        ivf_nprobe: None,
+        matryoshka_dim: None,
+        sparse_algorithm: None,
     };
     let nearest_upsert = segment
         .search(
@@ -27,13 +27,15 @@ use segment::types::PayloadFieldSchema::FieldType;
 use segment::types::PayloadSchemaType::Keyword;
 use segment::types::{
     Condition, DEFAULT_SPARSE_FULL_SCAN_THRESHOLD, FieldCondition, Filter, ScoredPoint,
-    SegmentConfig, SeqNumberType, SparseVectorDataConfig, SparseVectorStorageType, VectorName,
-    VectorStorageDatatype,
+    SearchParams, SegmentConfig, SeqNumberType, SparseSearchAlgorithm, SparseVectorDataConfig,
+    SparseVectorStorageType, VectorName, VectorStorageDatatype,
 };
 use segment::vector_storage::{Random, VectorStorage};
 use segment::{fixture_for_all_indices, payload_json};
 use sparse::common::sparse_vector::SparseVector;
-use sparse::common::sparse_vector_fixture::{random_full_sparse_vector, random_sparse_vector};
+use sparse::common::sparse_vector_fixture::{
+    random_full_sparse_vector, random_positive_sparse_vector, random_sparse_vector,
+};
 use sparse::common::types::DimId;
 use sparse::index::inverted_index::InvertedIndex;
 use sparse::index::inverted_index::inverted_index_compressed_immutable_ram::InvertedIndexCompressedImmutableRam;
@@ -726,6 +728,61 @@ fn check_persistence<TInvertedIndex: InvertedIndex>(
     check_search(&sparse_vector_index);
 }
 
+#[test]
+fn sparse_vector_index_search_algorithms() {
+    fixture_for_all_indices!(check_search_algorithms::<_>());
+}
+
+fn check_search_algorithms<I: InvertedIndex>() {
+    let mut rnd = StdRng::seed_from_u64(42);
+    let data_dir = Builder::new().prefix("data_dir").tempdir().unwrap();
+    let sparse_vector_index = fixture_sparse_index::<I, _>(
+        &mut rnd,
+        NUM_VECTORS,
+        MAX_SPARSE_DIM,
+        LOW_FULL_SCAN_THRESHOLD,
+        data_dir.path(),
+    );
+
+    let top = 10;
+    for _ in 0..100 {
+        let query_vector: QueryVector =
+            random_positive_sparse_vector(&mut rnd, MAX_SPARSE_DIM).into();
+        let search = |sparse_algorithm| {
+            let params = SearchParams {
+                sparse_algorithm,
+                ..Default::default()
+            };
+            sparse_vector_index
+                .search(
+                    &[&query_vector],
+                    None,
+                    top,
+                    Some(&params),
+                    &Default::default(),
+                )
+                .unwrap()
+                .remove(0)
+        };
+
+        let expected = search(None);
+        for algorithm in [
+            SparseSearchAlgorithm::Batch,
+            SparseSearchAlgorithm::BlockMaxWand,
+            SparseSearchAlgorithm::MaxScore,
+        ] {
+            let result = search(Some(algorithm));
+            assert_eq!(result.len(), expected.len(), "{algorithm:?}");
+            for (result, expected) in result.iter().zip(&expected) {
+                assert!(
+                    (result.score - expected.score).abs() <= 1e-3 * expected.score.abs().max(1.0),
+                    "{algorithm:?}: {result:?} != {expected:?}",
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn sparse_vector_index_files() {
     fixture_for_all_indices!(check_sparse_vector_index_files::<_>());
@@ -742,8 +799,9 @@ fn check_sparse_vector_index_files<I: InvertedIndex>() {
     );
 
     let files = index.files();
-    // sparse index config + version + inverted index config + inverted index data + tracker
-    assert_eq!(files.len(), 5);
+    // sparse index config + version + inverted index config + inverted index data
+    // + document lengths + tracker
+    assert_eq!(files.len(), 6);
     for file in files.iter() {
         assert!(file.exists(), "file {file:?} does not exist");
     }
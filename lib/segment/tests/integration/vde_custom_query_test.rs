@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{
+    DEFAULT_VECTOR_NAME, QueryVector, VectorInternal, only_default_multi_vector,
+    only_default_vector,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::{random_int_payload, random_multi_vector, random_vector};
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment::Segment;
+use segment::segment_constructor::build_segment;
+use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, HnswGlobalConfig, Indexes, MultiVectorConfig,
+    PayloadSchemaType, Range, SegmentConfig, SeqNumberType, VdeIndexConfig, VdeStorageType,
+    VectorDataConfig, VectorStorageType, WithPayload,
+};
+use segment::vector_storage::query::{ContextPair, ContextQuery, DiscoveryQuery, RecoQuery};
+use tempfile::Builder;
+
+const INT_KEY: &str = "int";
+const DIM: usize = 8;
+const NUM_POINTS: u64 = 300;
+const TOP: usize = 10;
+
+fn segment_config(
+    storage_type: VectorStorageType,
+    index: Indexes,
+    distance: Distance,
+    multivector_config: Option<MultiVectorConfig>,
+) -> SegmentConfig {
+    SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: DIM,
+                distance,
+                storage_type,
+                index,
+                quantization_config: None,
+                multivector_config,
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    }
+}
+
+fn random_query_vector<R: Rng + ?Sized>(rng: &mut R, is_multi: bool) -> VectorInternal {
+    if is_multi {
+        let num_vectors = rng.random_range(1..4);
+        random_multi_vector(rng, DIM, num_vectors).into()
+    } else {
+        random_vector(rng, DIM).into()
+    }
+}
+
+fn random_queries<R: Rng + ?Sized>(rng: &mut R, is_multi: bool) -> Vec<QueryVector> {
+    let mut vector = || random_query_vector(rng, is_multi);
+    vec![
+        QueryVector::Nearest(vector()),
+        QueryVector::RecommendBestScore(RecoQuery::new(vec![vector(), vector()], vec![vector()])),
+        QueryVector::RecommendSumScores(RecoQuery::new(vec![vector(), vector()], vec![vector()])),
+        QueryVector::Discovery(DiscoveryQuery::new(
+            vector(),
+            vec![ContextPair {
+                positive: vector(),
+                negative: vector(),
+            }],
+        )),
+        QueryVector::Context(ContextQuery::new(vec![
+            ContextPair {
+                positive: vector(),
+                negative: vector(),
+            },
+            ContextPair {
+                positive: vector(),
+                negative: vector(),
+            },
+        ])),
+    ]
+}
+
+/// Recommend, discovery and context queries and multi-vectors on VDE storage and index score the
+/// same as on a plain segment
+#[rstest]
+#[case::dense_dot(Distance::Dot, false)]
+#[case::dense_cosine(Distance::Cosine, false)]
+#[case::multi_cosine(Distance::Cosine, true)]
+#[case::multi_euclid(Distance::Euclid, true)]
+fn test_vde_custom_queries(#[case] distance: Distance, #[case] is_multi: bool) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let hw_counter = HardwareCounterCell::new();
+    let dir = Builder::new().prefix("vde_custom_query").tempdir().unwrap();
+
+    let multivector_config = is_multi.then(MultiVectorConfig::default);
+    let mut plain_segment = build_segment(
+        dir.path(),
+        &segment_config(
+            VectorStorageType::default(),
+            Indexes::Plain {},
+            distance,
+            multivector_config,
+        ),
+        true,
+    )
+    .unwrap();
+    for n in 0..NUM_POINTS {
+        let idx = n.into();
+        let vector = random_query_vector(&mut rng, is_multi);
+        let vectors = match &vector {
+            VectorInternal::Dense(dense) => only_default_vector(dense),
+            VectorInternal::MultiDense(multi) => only_default_multi_vector(multi),
+            VectorInternal::Sparse(_) => unreachable!(),
+        };
+        let payload = payload_json! {INT_KEY: random_int_payload(&mut rng, 1..=1)};
+        plain_segment
+            .upsert_point(n as SeqNumberType, idx, vectors, &hw_counter)
+            .unwrap();
+        plain_segment
+            .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+    for n in 0..20 {
+        let idx = rng.random_range(0..NUM_POINTS).into();
+        plain_segment
+            .delete_point(NUM_POINTS + n, idx, &hw_counter)
+            .unwrap();
+    }
+    plain_segment
+        .create_field_index(
+            2 * NUM_POINTS,
+            &JsonPath::new(INT_KEY),
+            Some(&PayloadSchemaType::Integer.into()),
+            &hw_counter,
+        )
+        .unwrap();
+
+    let vde_index = Indexes::Vde(VdeIndexConfig {
+        storage_type: VdeStorageType::Memory,
+        ..Default::default()
+    });
+    let segment_base_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let segment_builder_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment_builder = SegmentBuilder::new(
+        segment_base_dir.path(),
+        segment_builder_dir.path(),
+        &segment_config(
+            VectorStorageType::Vde,
+            vde_index,
+            distance,
+            multivector_config,
+        ),
+        &HnswGlobalConfig::default(),
+    )
+    .unwrap();
+    segment_builder
+        .update(&[&plain_segment], &false.into())
+        .unwrap();
+    let vde_segment = segment_builder
+        .build(
+            ResourcePermit::dummy(num_rayon_threads(0) as u32),
+            &false.into(),
+            &mut rng,
+            &hw_counter,
+        )
+        .unwrap();
+
+    assert_eq!(
+        plain_segment.available_point_count(),
+        vde_segment.available_point_count(),
+    );
+    for id in plain_segment.iter_points() {
+        assert_eq!(
+            plain_segment.all_vectors(id, &hw_counter).unwrap(),
+            vde_segment.all_vectors(id, &hw_counter).unwrap(),
+        );
+    }
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+        JsonPath::new(INT_KEY),
+        Range {
+            lt: None,
+            gt: None,
+            gte: Some(100.0),
+            lte: Some(400.0),
+        },
+    )));
+    for query in random_queries(&mut rng, is_multi) {
+        for filter in [None, Some(&filter)] {
+            let search = |segment: &Segment| {
+                segment
+                    .search(
+                        DEFAULT_VECTOR_NAME,
+                        &query,
+                        &WithPayload::default(),
+                        &false.into(),
+                        filter,
+                        TOP,
+                        None,
+                    )
+                    .unwrap()
+            };
+            let expected = search(&plain_segment);
+            let found = search(&vde_segment);
+            assert_eq!(expected.len(), TOP);
+            assert_eq!(expected.len(), found.len());
+            for (expected, found) in expected.iter().zip(&found) {
+                assert!(
+                    (expected.score - found.score).abs() < 1e-4,
+                    "{query:?}: expected {expected:?}, found {found:?}",
+                );
+            }
+        }
+    }
+}
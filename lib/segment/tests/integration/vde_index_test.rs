@@ -0,0 +1,282 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use ahash::AHashSet;
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::types::TelemetryDetail;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, only_default_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::{random_adj, random_int_payload, random_vector};
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::vde_index::{VDEVectorIndex, VdeIndexOpenArgs};
+use segment::index::{PayloadIndex, VectorIndex};
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment_constructor::VectorIndexBuildArgs;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, HasIdCondition, HnswConfig, HnswGlobalConfig,
+    IsEmptyCondition, PayloadSchemaType, Range, SearchParams, SeqNumberType, VdeIndexConfig,
+    VdeStorageType,
+};
+use tempfile::Builder;
+
+const INT_KEY: &str = "int";
+const COLOR_KEY: &str = "color";
+const UNINDEXED_KEY: &str = "unindexed";
+
+fn int_range(key: &str, left: i64, size: i64) -> Condition {
+    Condition::Field(FieldCondition::new_range(
+        JsonPath::new(key),
+        Range {
+            lt: None,
+            gt: None,
+            gte: Some(left as f64),
+            lte: Some((left + size) as f64),
+        },
+    ))
+}
+
+fn random_filter<R: Rng + ?Sized>(rng: &mut R, num_vectors: u64) -> Filter {
+    let color = Condition::Field(FieldCondition::new_match(
+        JsonPath::new(COLOR_KEY),
+        random_adj(rng).into(),
+    ));
+    let has_id = Condition::HasId(HasIdCondition::from(
+        (0..50)
+            .map(|_| rng.random_range(0..num_vectors).into())
+            .collect::<AHashSet<_>>(),
+    ));
+
+    match rng.random_range(0..5) {
+        // Fully pushed down
+        0 => Filter::new_must(int_range(INT_KEY, rng.random_range(0..400), 100)),
+        // Pushed down with residual
+        1 => Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![color, int_range(UNINDEXED_KEY, 0, 300)]),
+            must_not: None,
+        },
+        // Residual only, `should` can't be split
+        2 => Filter {
+            should: Some(vec![color, has_id]),
+            min_should: None,
+            must: None,
+            must_not: None,
+        },
+        // Nested filter with pushed down and residual parts
+        3 => Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![Condition::Filter(Filter {
+                should: None,
+                min_should: None,
+                must: Some(vec![int_range(INT_KEY, rng.random_range(0..400), 200)]),
+                must_not: Some(vec![Condition::IsEmpty(IsEmptyCondition::from(
+                    JsonPath::new(UNINDEXED_KEY),
+                ))]),
+            })]),
+            must_not: Some(vec![color]),
+        },
+        // Weakly selective
+        _ => Filter::new_must_not(color),
+    }
+}
+
+#[rstest]
+#[case::pushdown(Some(0))]
+#[case::small_threshold(Some(1))]
+#[case::default_threshold(None)]
+fn vde_index_search_test(#[case] full_scan_threshold: Option<usize>) {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 8;
+    let num_vectors: u64 = 2_000;
+    let num_deleted: u64 = 100;
+    let distance = Distance::Dot;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+    let vde_dir = Builder::new().prefix("vde_dir").tempdir().unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_simple_segment(dir.path(), dim, distance).unwrap();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rng, dim);
+
+        let mut payload = payload_json! {
+            INT_KEY: random_int_payload(&mut rng, 1..=1),
+            COLOR_KEY: random_adj(&mut rng),
+        };
+        if rng.random_bool(0.8) {
+            payload.merge(&payload_json! {UNINDEXED_KEY: random_int_payload(&mut rng, 1..=2)});
+        }
+
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+
+    for n in 0..num_deleted {
+        let idx = rng.random_range(0..num_vectors).into();
+        segment
+            .delete_point(num_vectors + n, idx, &hw_counter)
+            .unwrap();
+    }
+
+    let payload_index = segment.payload_index.clone();
+    payload_index
+        .borrow_mut()
+        .set_indexed(
+            &JsonPath::new(INT_KEY),
+            PayloadSchemaType::Integer,
+            &hw_counter,
+        )
+        .unwrap();
+    payload_index
+        .borrow_mut()
+        .set_indexed(
+            &JsonPath::new(COLOR_KEY),
+            PayloadSchemaType::Keyword,
+            &hw_counter,
+        )
+        .unwrap();
+
+    let vector_storage = &segment.vector_data[DEFAULT_VECTOR_NAME].vector_storage;
+    let quantized_vectors = &segment.vector_data[DEFAULT_VECTOR_NAME].quantized_vectors;
+
+    let permit = Arc::new(ResourcePermit::dummy(1));
+    let hnsw_index = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir.path(),
+            id_tracker: segment.id_tracker.clone(),
+            vector_storage: vector_storage.clone(),
+            quantized_vectors: quantized_vectors.clone(),
+            payload_index: payload_index.clone(),
+            hnsw_config: HnswConfig {
+                m: 16,
+                ef_construct: 64,
+                full_scan_threshold: 1,
+                max_indexing_threads: 1,
+                on_disk: Some(false),
+                payload_m: None,
+                copy_vectors: None,
+                matryoshka_dim: None,
+            },
+        },
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+        },
+    )
+    .unwrap();
+
+    let open_args = || VdeIndexOpenArgs {
+        path: vde_dir.path(),
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: vector_storage.clone(),
+        payload_index: payload_index.clone(),
+        dimension: dim,
+        distance,
+        config: VdeIndexConfig {
+            storage_type: VdeStorageType::Memory,
+            full_scan_threshold,
+            ..Default::default()
+        },
+    };
+
+    let vde_index = VDEVectorIndex::build(open_args(), &stopped).unwrap();
+    assert_eq!(
+        vde_index.indexed_vector_count(),
+        segment.available_point_count(),
+    );
+
+    let exact_params = SearchParams {
+        exact: true,
+        ..Default::default()
+    };
+
+    let top = 10;
+    let attempts = 50;
+    for _ in 0..attempts {
+        let query = random_vector(&mut rng, dim).into();
+        let filter = random_filter(&mut rng, num_vectors);
+
+        for filter in [None, Some(&filter)] {
+            let vde_result = vde_index
+                .search(&[&query], filter, top, None, &Default::default())
+                .unwrap();
+            let hnsw_result = hnsw_index
+                .search(
+                    &[&query],
+                    filter,
+                    top,
+                    Some(&exact_params),
+                    &Default::default(),
+                )
+                .unwrap();
+
+            assert_eq!(
+                vde_result, hnsw_result,
+                "VDE search is not equal to exact HNSW search, filter: {filter:?}",
+            );
+        }
+    }
+
+    let telemetry = vde_index.get_telemetry_data(TelemetryDetail::default());
+    assert_eq!(telemetry.unfiltered_hnsw.count, attempts);
+    assert_eq!(
+        telemetry.filtered_small_cardinality.count + telemetry.filtered_large_cardinality.count,
+        attempts,
+    );
+    if full_scan_threshold == Some(0) {
+        assert_eq!(telemetry.filtered_small_cardinality.count, 0);
+    }
+
+    // Index is persisted and opened again
+    drop(vde_index);
+    let vde_index = VDEVectorIndex::open(open_args()).unwrap();
+    assert_eq!(
+        vde_index.indexed_vector_count(),
+        segment.available_point_count(),
+    );
+
+    let query = random_vector(&mut rng, dim).into();
+    let filter = Filter::new_must(int_range(INT_KEY, 100, 200));
+    let vde_result = vde_index
+        .search(&[&query], Some(&filter), top, None, &Default::default())
+        .unwrap();
+    let hnsw_result = hnsw_index
+        .search(
+            &[&query],
+            Some(&filter),
+            top,
+            Some(&exact_params),
+            &Default::default(),
+        )
+        .unwrap();
+    assert_eq!(vde_result, hnsw_result);
+}
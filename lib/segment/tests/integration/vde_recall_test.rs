@@ -0,0 +1,113 @@
+use std::time::{Duration, Instant};
+
+use rand::SeedableRng;
+use rand::prelude::StdRng;
+use rstest::rstest;
+use segment::fixtures::index_fixtures::random_vector;
+use segment::fixtures::vde_fixtures::{VdeHnswFixture, random_vde_filter, recall};
+use segment::index::VectorIndex;
+use segment::types::{Distance, HnswConfig, SearchParams};
+use tempfile::Builder;
+
+const NUM_VECTORS: u64 = 3_000;
+const DIM: usize = 16;
+const TOP: usize = 10;
+const ATTEMPTS: usize = 50;
+const EF: usize = 64;
+
+/// Minimal recall@k accepted from either index
+const MIN_RECALL: f64 = 0.9;
+
+#[derive(Default)]
+struct Stats {
+    recall: f64,
+    elapsed: Duration,
+}
+
+impl Stats {
+    fn add(&mut self, recall: f64, elapsed: Duration) {
+        self.recall += recall / ATTEMPTS as f64;
+        self.elapsed += elapsed;
+    }
+}
+
+/// Compare recall@k and latency of the VDE index against native HNSW on the same data
+#[rstest]
+#[case::unfiltered(false, Distance::Cosine)]
+#[case::filtered(true, Distance::Cosine)]
+#[case::filtered_euclid(true, Distance::Euclid)]
+fn vde_vs_hnsw_recall_test(#[case] filtered: bool, #[case] distance: Distance) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("vde_recall").tempdir().unwrap();
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 100,
+        full_scan_threshold: 1,
+        max_indexing_threads: 1,
+        on_disk: Some(false),
+        payload_m: None,
+        copy_vectors: None,
+        matryoshka_dim: None,
+    };
+    let fixture = VdeHnswFixture::new(
+        dir.path(),
+        NUM_VECTORS,
+        DIM,
+        distance,
+        hnsw_config,
+        &mut rng,
+    );
+
+    let params = SearchParams {
+        hnsw_ef: Some(EF),
+        ..Default::default()
+    };
+
+    let mut hnsw_stats = Stats::default();
+    let mut vde_stats = Stats::default();
+    for _ in 0..ATTEMPTS {
+        let query = random_vector(&mut rng, DIM).into();
+        let filter = filtered.then(|| random_vde_filter(&mut rng));
+
+        let exact = fixture.search_exact(&query, filter.as_ref(), TOP);
+
+        for (index, stats) in [
+            (&fixture.hnsw_index as &dyn VectorIndex, &mut hnsw_stats),
+            (&fixture.vde_index, &mut vde_stats),
+        ] {
+            let timer = Instant::now();
+            let found = index
+                .search(
+                    &[&query],
+                    filter.as_ref(),
+                    TOP,
+                    Some(&params),
+                    &Default::default(),
+                )
+                .unwrap();
+            stats.add(recall(&exact, &found[0]), timer.elapsed());
+        }
+    }
+
+    eprintln!(
+        "filtered: {filtered}, distance: {distance:?}, \
+         HNSW recall@{TOP}: {:.3}, latency: {:?}, \
+         VDE recall@{TOP}: {:.3}, latency: {:?}",
+        hnsw_stats.recall,
+        hnsw_stats.elapsed / ATTEMPTS as u32,
+        vde_stats.recall,
+        vde_stats.elapsed / ATTEMPTS as u32,
+    );
+
+    assert!(
+        hnsw_stats.recall >= MIN_RECALL,
+        "HNSW recall@{TOP} is too low: {}",
+        hnsw_stats.recall,
+    );
+    assert!(
+        vde_stats.recall >= MIN_RECALL,
+        "VDE recall@{TOP} is too low: {}",
+        vde_stats.recall,
+    );
+}
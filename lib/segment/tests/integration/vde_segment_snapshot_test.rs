@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::tar_ext;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, only_default_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::entry::snapshot_entry::SnapshotEntry as _;
+use segment::fixtures::payload_fixtures::{random_int_payload, random_vector};
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment::Segment;
+use segment::segment_constructor::load_segment;
+use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, HnswGlobalConfig, Indexes, PayloadSchemaType,
+    PayloadStorageType, Range, SegmentConfig, SnapshotFormat, VdeIndexConfig, VdeStorageType,
+    VectorDataConfig, VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+const INT_KEY: &str = "int";
+
+/// Segment with VDE vector storage and VDE index is snapshotted and restored at another path
+#[rstest]
+#[case::regular(SnapshotFormat::Regular)]
+#[case::streamable(SnapshotFormat::Streamable)]
+fn test_vde_segment_snapshot(#[case] format: SnapshotFormat) {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dim = 4;
+    let num_points = 200;
+    let distance = Distance::Dot;
+    let mut rng = StdRng::seed_from_u64(42);
+    let hw_counter = HardwareCounterCell::new();
+
+    let source_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut source_segment = build_simple_segment(source_dir.path(), dim, distance).unwrap();
+    for n in 0..num_points {
+        let idx = n.into();
+        let vector = random_vector(&mut rng, dim);
+        let payload = payload_json! {INT_KEY: random_int_payload(&mut rng, 1..=1)};
+        source_segment
+            .upsert_point(n, idx, only_default_vector(&vector), &hw_counter)
+            .unwrap();
+        source_segment
+            .set_full_payload(n, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+    for n in 0..20 {
+        let idx = rng.random_range(0..num_points).into();
+        source_segment
+            .delete_point(num_points + n, idx, &hw_counter)
+            .unwrap();
+    }
+    source_segment
+        .create_field_index(
+            2 * num_points,
+            &JsonPath::new(INT_KEY),
+            Some(&PayloadSchemaType::Integer.into()),
+            &hw_counter,
+        )
+        .unwrap();
+
+    let segment_config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type: VectorStorageType::Vde,
+                index: Indexes::Vde(VdeIndexConfig {
+                    storage_type: VdeStorageType::Memory,
+                    ..Default::default()
+                }),
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: PayloadStorageType::Mmap,
+    };
+
+    let segment_base_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let segment_builder_dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let mut segment_builder = SegmentBuilder::new(
+        segment_base_dir.path(),
+        segment_builder_dir.path(),
+        &segment_config,
+        &HnswGlobalConfig::default(),
+    )
+    .unwrap();
+    segment_builder
+        .update(&[&source_segment], &false.into())
+        .unwrap();
+    let segment = segment_builder
+        .build(
+            ResourcePermit::dummy(num_rayon_threads(0) as u32),
+            &false.into(),
+            &mut rng,
+            &hw_counter,
+        )
+        .unwrap();
+
+    let temp_dir = Builder::new().prefix("temp_dir").tempdir().unwrap();
+    let parent_snapshot_tar = Builder::new()
+        .prefix("parent_snapshot")
+        .suffix(".tar")
+        .tempfile()
+        .unwrap();
+
+    let tar = tar_ext::BuilderExt::new_seekable_owned(File::create(&parent_snapshot_tar).unwrap());
+    segment
+        .take_snapshot(temp_dir.path(), &tar, format, None, &mut HashSet::new())
+        .unwrap();
+    tar.blocking_finish().unwrap();
+
+    let parent_snapshot_unpacked = Builder::new().prefix("parent_snapshot").tempdir().unwrap();
+    tar::Archive::new(File::open(&parent_snapshot_tar).unwrap())
+        .unpack(parent_snapshot_unpacked.path())
+        .unwrap();
+
+    let mut entries = parent_snapshot_unpacked.path().read_dir().unwrap();
+    let entry = entries.next().unwrap().unwrap();
+    assert!(entries.next().is_none());
+
+    Segment::restore_snapshot_in_place(&entry.path()).unwrap();
+
+    let mut entries = parent_snapshot_unpacked.path().read_dir().unwrap();
+    let entry = entries.next().unwrap().unwrap();
+    assert!(entry.path().is_dir());
+
+    let restored_segment = load_segment(&entry.path(), &AtomicBool::new(false))
+        .unwrap()
+        .unwrap();
+    assert_ne!(restored_segment.current_path, segment.current_path);
+
+    assert_eq!(
+        segment.available_point_count(),
+        restored_segment.available_point_count(),
+    );
+    assert_eq!(
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .indexed_vectors(),
+        restored_segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .indexed_vectors(),
+    );
+
+    for id in segment.iter_points() {
+        let vectors = segment.all_vectors(id, &hw_counter).unwrap();
+        let restored_vectors = restored_segment.all_vectors(id, &hw_counter).unwrap();
+        assert_eq!(vectors, restored_vectors);
+    }
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_range(
+        JsonPath::new(INT_KEY),
+        Range {
+            lt: None,
+            gt: None,
+            gte: Some(100.0),
+            lte: Some(400.0),
+        },
+    )));
+    for filter in [None, Some(&filter)] {
+        let query = random_vector(&mut rng, dim).into();
+        let search = |segment: &Segment| {
+            segment
+                .search(
+                    DEFAULT_VECTOR_NAME,
+                    &query,
+                    &WithPayload::default(),
+                    &false.into(),
+                    filter,
+                    10,
+                    None,
+                )
+                .unwrap()
+        };
+        let result = search(&segment);
+        assert!(!result.is_empty());
+        assert_eq!(result, search(&restored_segment));
+    }
+}
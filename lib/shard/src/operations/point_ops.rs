@@ -547,6 +547,7 @@ impl TryFrom<api::rest::schema::Record> for PointStructPersisted {
             vector,
             shard_key: _,
             order_value: _,
+            highlight: _,
         } = record;
 
         if vector.is_none() {
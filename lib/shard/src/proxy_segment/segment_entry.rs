@@ -5,13 +5,14 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::TelemetryDetail;
+use common::types::{ScoreType, TelemetryDetail};
 use segment::common::operation_error::{OperationResult, SegmentFailedState};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
 use segment::data_types::facets::{FacetParams, FacetValue};
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::order_by::OrderValue;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
+use segment::data_types::text_query::{TextQuery, TextQueryStatistics};
 use segment::data_types::vectors::{QueryVector, VectorInternal};
 use segment::entry::entry_point::SegmentEntry;
 use segment::index::field_index::{CardinalityEstimation, FieldIndex};
@@ -493,6 +494,87 @@ impl SegmentEntry for ProxySegment {
         Ok(read_points)
     }
 
+    fn fill_text_query_statistics(
+        &self,
+        query: &TextQuery,
+        excluded_points: &[PointIdType],
+        statistics: &mut TextQueryStatistics,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        {
+            // Points deleted through the proxy are still indexed in the wrapped segment
+            let deleted_points = self.deleted_points.read();
+            let wrapped_excluded_points: Vec<_> = excluded_points
+                .iter()
+                .copied()
+                .chain(deleted_points.keys().copied())
+                .collect();
+            self.wrapped_segment
+                .get()
+                .read()
+                .fill_text_query_statistics(
+                    query,
+                    &wrapped_excluded_points,
+                    statistics,
+                    hw_counter,
+                )?;
+        }
+        self.write_segment.get().read().fill_text_query_statistics(
+            query,
+            excluded_points,
+            statistics,
+            hw_counter,
+        )
+    }
+
+    fn read_text_scored_filtered<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        query: &'a TextQuery,
+        statistics: &TextQueryStatistics,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        let deleted_points = self.deleted_points.read();
+        let mut read_points = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_text_scored_filtered(
+                    limit, filter, query, statistics, is_stopped, hw_counter,
+                )?
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                filter,
+                deleted_points.keys().copied(),
+            );
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_text_scored_filtered(
+                    limit,
+                    Some(&wrapped_filter),
+                    query,
+                    statistics,
+                    is_stopped,
+                    hw_counter,
+                )?
+        };
+        let mut write_segment_points =
+            self.write_segment.get().read().read_text_scored_filtered(
+                limit, filter, query, statistics, is_stopped, hw_counter,
+            )?;
+        read_points.append(&mut write_segment_points);
+        read_points.sort_unstable_by(|(score_a, id_a), (score_b, id_b)| {
+            score_b.total_cmp(score_a).then_with(|| id_a.cmp(id_b))
+        });
+        if let Some(limit) = limit {
+            read_points.truncate(limit);
+        }
+        Ok(read_points)
+    }
+
     fn read_random_filtered<'a>(
         &'a self,
         limit: usize,
@@ -56,6 +56,9 @@ pub struct CompressedPostingChunk<W> {
     /// An offset within id_data
     offset: u32,
 
+    /// Max weight value in the chunk. Used as an upper bound to skip whole chunks during search.
+    max_weight: W,
+
     /// Weight values for the chunk.
     weights: [W; CHUNK_SIZE],
 }
@@ -326,6 +329,46 @@ impl<'a, W: Weight> CompressedPostingListView<'a, W> {
         chunk.weights[start..start + count].as_ref()
     }
 
+    #[inline]
+    fn chunk_max_weight(&self, chunk_index: usize) -> DimWeight {
+        self.hw_counter.vector_io_read().incr_delta(size_of::<W>());
+        self.chunks[chunk_index].max_weight.to_f32(self.multiplier)
+    }
+
+    fn remainders_max_weight(&self, index: usize) -> Option<DimWeight> {
+        self.iter_remainder_from(index)
+            .map(|e| e.weight.to_f32(self.multiplier))
+            .reduce(DimWeight::max)
+    }
+
+    /// Max weight of the elements starting from the position `pos`.
+    /// The whole chunk of the position is taken into account.
+    fn max_weight_from(&self, pos: usize) -> Option<DimWeight> {
+        let chunks_max = (pos / CHUNK_SIZE..self.chunks_len())
+            .map(|chunk_index| self.chunk_max_weight(chunk_index))
+            .reduce(DimWeight::max);
+        let remainders_max = self.remainders_max_weight(
+            pos.saturating_sub(self.chunks_len() * CHUNK_SIZE)
+                .min(self.remainder_len()),
+        );
+        match (chunks_max, remainders_max) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Last id that can be stored in the chunk, which is right before the start of the next
+    /// chunk or the remainders.
+    fn chunk_last_id(&self, chunk_index: usize) -> Option<PointOffsetType> {
+        if let Some(next_chunk) = self.chunks.get(chunk_index + 1) {
+            Some(next_chunk.initial - 1)
+        } else if let Some(first_remainder) = self.remainders.first() {
+            Some(first_remainder.record_id - 1)
+        } else {
+            self.last_id
+        }
+    }
+
     pub fn iter(&self) -> CompressedPostingListIterator<'a, W> {
         CompressedPostingListIterator::new(self)
     }
@@ -375,9 +418,14 @@ impl CompressedPostingBuilder {
                 let chunk_bits =
                     bitpacker.num_bits_strictly_sorted(initial.checked_sub(1), &this_chunk);
                 let chunk_size = BitPackerImpl::compressed_block_size(chunk_bits);
+                let max_weight = chunk
+                    .iter()
+                    .map(|e| e.weight)
+                    .fold(DimWeight::NEG_INFINITY, DimWeight::max);
                 chunks.push(CompressedPostingChunk {
                     initial,
                     offset: data_size as u32,
+                    max_weight: Weight::from_f32(quantization_params, max_weight),
                     weights: chunk
                         .iter()
                         .map(|e| Weight::from_f32(quantization_params, e.weight))
@@ -650,6 +698,33 @@ impl<W: Weight> PostingListIter for CompressedPostingListIterator<'_, W> {
         false
     }
 
+    fn max_weight(&mut self) -> Option<DimWeight> {
+        self.list.max_weight_from(self.pos.0)
+    }
+
+    fn block_max_weight(
+        &mut self,
+        record_id: PointOffsetType,
+    ) -> Option<(PointOffsetType, DimWeight)> {
+        let last_id = self.list.last_id?;
+        if record_id > last_id {
+            return None;
+        }
+        match self.list.chunk_id_by_id(record_id) {
+            IdChunkPosition::Chunk(chunk_index) => Some((
+                self.list.chunk_last_id(chunk_index)?,
+                self.list.chunk_max_weight(chunk_index),
+            )),
+            // Nothing is stored before the first chunk, so the first chunk is the block
+            IdChunkPosition::Before if self.list.chunks_len() > 0 => {
+                Some((self.list.chunk_last_id(0)?, self.list.chunk_max_weight(0)))
+            }
+            IdChunkPosition::Before | IdChunkPosition::After => {
+                Some((last_id, self.list.remainders_max_weight(0)?))
+            }
+        }
+    }
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement> {
         CompressedPostingListStdIterator(self)
     }
@@ -761,6 +836,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_block_max_weight() {
+        let hw_counter = HardwareCounterCell::new();
+        for count in CASES {
+            // weights are not monotonic, so block bounds differ from the list bound
+            let case: Vec<_> = (0..count)
+                .map(|i| (i as u32 * 2 + 10000, ((i * 7) % 100) as DimWeight))
+                .collect();
+            let list = CompressedPostingList::<f32>::from(case.clone());
+            let mut iter = list.iter(&hw_counter);
+
+            assert_eq!(
+                iter.max_weight(),
+                case.iter()
+                    .map(|(_, weight)| *weight)
+                    .reduce(DimWeight::max),
+            );
+            assert_eq!(iter.block_max_weight(PointOffsetType::MAX), None);
+
+            for &(id, _) in &case {
+                for record_id in [id - 1, id] {
+                    let (last_id, max_weight) = iter.block_max_weight(record_id).unwrap();
+                    assert!(last_id >= record_id);
+                    // the bound holds for all elements covered by the block
+                    for &(other_id, other_weight) in &case {
+                        if (record_id..=last_id).contains(&other_id) {
+                            assert!(max_weight >= other_weight);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_count_le_sorted() {
         let data = [1, 2, 4, 5];
@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+
+use common::types::PointOffsetType;
+use io::file_operations::{atomic_save_bin, read_bin};
+
+use crate::common::types::DimWeight;
+
+const DOCUMENT_LENGTHS_FILE_NAME: &str = "document_lengths.bin";
+
+/// Per-point lengths of indexed sparse vectors.
+///
+/// Length of a vector is the sum of its weights, which is the number of tokens in a document
+/// if the weights are term frequencies. Used for BM25 length normalization at search time.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentLengths {
+    lengths: Vec<DimWeight>,
+    /// Sum of all lengths, pre-computed to avoid traversing all points on each search
+    total: f64,
+}
+
+impl DocumentLengths {
+    pub fn file_path(path: &Path) -> PathBuf {
+        path.join(DOCUMENT_LENGTHS_FILE_NAME)
+    }
+
+    /// Length of the vector with the given weights
+    pub fn vector_length(weights: &[DimWeight]) -> DimWeight {
+        weights.iter().sum()
+    }
+
+    /// Length of the point, zero if the point is not indexed
+    #[inline]
+    pub fn get(&self, id: PointOffsetType) -> DimWeight {
+        self.lengths.get(id as usize).copied().unwrap_or(0.0)
+    }
+
+    /// Sum of lengths of all indexed points
+    pub fn total(&self) -> f64 {
+        self.total
+    }
+
+    /// Set length of the point, replacing the previous one
+    pub fn set(&mut self, id: PointOffsetType, length: DimWeight) {
+        let id = id as usize;
+        if id >= self.lengths.len() {
+            self.lengths.resize(id + 1, 0.0);
+        }
+        self.total += f64::from(length) - f64::from(self.lengths[id]);
+        self.lengths[id] = length;
+    }
+
+    /// Add weight of a single posting element to the length of the point
+    pub fn add(&mut self, id: PointOffsetType, weight: DimWeight) {
+        let length = self.get(id) + weight;
+        self.set(id, length);
+    }
+
+    pub fn remove(&mut self, id: PointOffsetType) {
+        self.set(id, 0.0);
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        atomic_save_bin(&Self::file_path(path), &self.lengths)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let lengths: Vec<DimWeight> = read_bin(&Self::file_path(path))?;
+        let total = lengths.iter().map(|&length| f64::from(length)).sum();
+        Ok(Self { lengths, total })
+    }
+
+    /// Load lengths of an index, or compute them with `compute` if the index was created
+    /// before lengths were stored, and persist them for the next load
+    pub fn load_or_compute(path: &Path, compute: impl FnOnce() -> Self) -> std::io::Result<Self> {
+        if Self::file_path(path).exists() {
+            return Self::load(path);
+        }
+        let document_lengths = compute();
+        document_lengths.save(path)?;
+        Ok(document_lengths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+
+    #[test]
+    fn test_document_lengths() {
+        let mut lengths = DocumentLengths::default();
+        lengths.set(3, 4.0);
+        lengths.add(1, 1.0);
+        lengths.add(1, 2.0);
+        assert_eq!(lengths.get(0), 0.0);
+        assert_eq!(lengths.get(1), 3.0);
+        assert_eq!(lengths.get(3), 4.0);
+        assert_eq!(lengths.get(100), 0.0);
+        assert_eq!(lengths.total(), 7.0);
+
+        lengths.set(3, 1.0);
+        lengths.remove(1);
+        assert_eq!(lengths.total(), 1.0);
+
+        let dir = Builder::new().prefix("document_lengths").tempdir().unwrap();
+        lengths.save(dir.path()).unwrap();
+        assert_eq!(DocumentLengths::load(dir.path()).unwrap(), lengths);
+    }
+}
@@ -12,6 +12,7 @@ use crate::common::types::{DimId, DimOffset, Weight};
 use crate::index::compressed_posting_list::{
     CompressedPostingBuilder, CompressedPostingList, CompressedPostingListIterator,
 };
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::posting_list_common::PostingListIter as _;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +20,7 @@ pub struct InvertedIndexCompressedImmutableRam<W: Weight> {
     pub(super) postings: Vec<CompressedPostingList<W>>,
     pub(super) vector_count: usize,
     pub(super) total_sparse_size: usize,
+    pub(super) document_lengths: DocumentLengths,
 }
 
 impl<W: Weight> InvertedIndexCompressedImmutableRam<W> {
@@ -43,6 +45,7 @@ impl<W: Weight> InvertedIndex for InvertedIndexCompressedImmutableRam<W> {
             postings: Vec::with_capacity(mmap_inverted_index.file_header.posting_count),
             vector_count: mmap_inverted_index.file_header.vector_count,
             total_sparse_size: mmap_inverted_index.total_sparse_vectors_size(),
+            document_lengths: mmap_inverted_index.document_lengths().clone(),
         };
 
         let hw_counter = HardwareCounterCell::disposable();
@@ -130,6 +133,7 @@ impl<W: Weight> InvertedIndex for InvertedIndexCompressedImmutableRam<W> {
             postings,
             vector_count: ram_index.vector_count,
             total_sparse_size,
+            document_lengths: ram_index.document_lengths.clone(),
         })
     }
 
@@ -141,6 +145,10 @@ impl<W: Weight> InvertedIndex for InvertedIndexCompressedImmutableRam<W> {
         self.total_sparse_size
     }
 
+    fn document_lengths(&self) -> &DocumentLengths {
+        &self.document_lengths
+    }
+
     fn max_index(&self) -> Option<DimOffset> {
         self.postings
             .len()
@@ -25,9 +25,10 @@ use crate::common::types::{DimId, DimOffset, Weight};
 use crate::index::compressed_posting_list::{
     CompressedPostingChunk, CompressedPostingListIterator, CompressedPostingListView,
 };
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::inverted_index::InvertedIndex;
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
-use crate::index::posting_list_common::GenericPostingElement;
+use crate::index::posting_list_common::{GenericPostingElement, PostingListIter as _};
 
 const INDEX_CONFIG_FILE_NAME: &str = "inverted_index_config.json";
 
@@ -35,7 +36,7 @@ pub struct Version;
 
 impl StorageVersion for Version {
     fn current_raw() -> &'static str {
-        "0.2.0"
+        "0.3.0"
     }
 }
 
@@ -58,6 +59,7 @@ pub struct InvertedIndexCompressedMmap<W> {
     path: PathBuf,
     mmap: Arc<Mmap>,
     pub file_header: InvertedIndexFileHeader,
+    document_lengths: DocumentLengths,
     _phantom: PhantomData<W>,
 }
 
@@ -122,6 +124,7 @@ impl<W: Weight> InvertedIndex for InvertedIndexCompressedMmap<W> {
         vec![
             Self::index_file_path(path),
             Self::index_config_file_path(path),
+            DocumentLengths::file_path(path),
         ]
     }
 
@@ -163,6 +166,10 @@ impl<W: Weight> InvertedIndex for InvertedIndexCompressedMmap<W> {
         self.file_header.total_sparse_size.unwrap_or(0)
     }
 
+    fn document_lengths(&self) -> &DocumentLengths {
+        &self.document_lengths
+    }
+
     fn max_index(&self) -> Option<DimId> {
         match self.file_header.posting_count {
             0 => None,
@@ -301,6 +308,8 @@ impl<W: Weight> InvertedIndexCompressedMmap<W> {
 
         atomic_save_json(&Self::index_config_file_path(path.as_ref()), &file_header)?;
 
+        index.document_lengths.save(path.as_ref())?;
+
         Ok(Self {
             path: path.as_ref().to_owned(),
             mmap: Arc::new(open_read_mmap(
@@ -309,6 +318,7 @@ impl<W: Weight> InvertedIndexCompressedMmap<W> {
                 false,
             )?),
             file_header,
+            document_lengths: index.document_lengths.clone(),
             _phantom: PhantomData,
         })
     }
@@ -330,6 +340,7 @@ impl<W: Weight> InvertedIndexCompressedMmap<W> {
             path: path.as_ref().to_owned(),
             mmap: Arc::new(mmap),
             file_header,
+            document_lengths: DocumentLengths::default(),
             _phantom: PhantomData,
         };
 
@@ -341,9 +352,26 @@ impl<W: Weight> InvertedIndexCompressedMmap<W> {
             atomic_save_json(&config_file_path, &index.file_header)?;
         }
 
+        index.document_lengths = DocumentLengths::load_or_compute(path.as_ref(), || {
+            index.calculate_document_lengths(&hw_counter)
+        })?;
+
         Ok(index)
     }
 
+    fn calculate_document_lengths(&self, hw_counter: &HardwareCounterCell) -> DocumentLengths {
+        let mut document_lengths = DocumentLengths::default();
+        for id in 0..self.file_header.posting_count as DimId {
+            let Some(posting) = self.get(id, hw_counter) else {
+                continue;
+            };
+            for element in posting.iter().into_std_iter() {
+                document_lengths.add(element.record_id, element.weight);
+            }
+        }
+        document_lengths
+    }
+
     fn calculate_total_sparse_size(&self, hw_counter: &HardwareCounterCell) -> usize {
         (0..self.file_header.posting_count as DimId)
             .filter_map(|id| {
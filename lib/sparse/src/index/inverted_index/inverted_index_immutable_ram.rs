@@ -9,6 +9,7 @@ use super::inverted_index_mmap::InvertedIndexMmap;
 use super::inverted_index_ram::InvertedIndexRam;
 use crate::common::sparse_vector::RemappedSparseVector;
 use crate::common::types::{DimId, DimOffset};
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::posting_list::{PostingList, PostingListIterator};
 
 /// A wrapper around [`InvertedIndexRam`].
@@ -35,6 +36,7 @@ impl InvertedIndex for InvertedIndexImmutableRam {
             vector_count: mmap_inverted_index.file_header.vector_count,
             // Calculated after reading mmap
             total_sparse_size: 0,
+            document_lengths: mmap_inverted_index.document_lengths().clone(),
         };
 
         for i in 0..mmap_inverted_index.file_header.posting_count as DimId {
@@ -116,6 +118,10 @@ impl InvertedIndex for InvertedIndexImmutableRam {
         self.inner.total_sparse_vectors_size()
     }
 
+    fn document_lengths(&self) -> &DocumentLengths {
+        &self.inner.document_lengths
+    }
+
     fn max_index(&self) -> Option<DimOffset> {
         self.inner.max_index()
     }
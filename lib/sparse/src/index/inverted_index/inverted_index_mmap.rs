@@ -19,6 +19,7 @@ use serde::{Deserialize, Serialize};
 use super::INDEX_FILE_NAME;
 use crate::common::sparse_vector::RemappedSparseVector;
 use crate::common::types::{DimId, DimOffset};
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::inverted_index::InvertedIndex;
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use crate::index::posting_list::PostingListIterator;
@@ -49,6 +50,7 @@ pub struct InvertedIndexMmap {
     path: PathBuf,
     mmap: Arc<Mmap>,
     pub file_header: InvertedIndexFileHeader,
+    document_lengths: DocumentLengths,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -109,6 +111,7 @@ impl InvertedIndex for InvertedIndexMmap {
         vec![
             Self::index_file_path(path),
             Self::index_config_file_path(path),
+            DocumentLengths::file_path(path),
         ]
     }
 
@@ -149,6 +152,10 @@ impl InvertedIndex for InvertedIndexMmap {
         0
     }
 
+    fn document_lengths(&self) -> &DocumentLengths {
+        &self.document_lengths
+    }
+
     fn max_index(&self) -> Option<DimId> {
         match self.file_header.posting_count {
             0 => None,
@@ -216,10 +223,13 @@ impl InvertedIndexMmap {
         let config_file_path = Self::index_config_file_path(path.as_ref());
         atomic_save_json(&config_file_path, &file_header)?;
 
+        inverted_index_ram.document_lengths.save(path.as_ref())?;
+
         Ok(Self {
             path: path.as_ref().to_owned(),
             mmap: Arc::new(mmap.make_read_only()?),
             file_header,
+            document_lengths: inverted_index_ram.document_lengths.clone(),
         })
     }
 
@@ -235,11 +245,25 @@ impl InvertedIndexMmap {
             AdviceSetting::from(Advice::Normal),
             false,
         )?;
-        Ok(Self {
+        let mut index = Self {
             path: path.as_ref().to_owned(),
             mmap: Arc::new(mmap),
             file_header,
-        })
+            document_lengths: DocumentLengths::default(),
+        };
+        index.document_lengths =
+            DocumentLengths::load_or_compute(path.as_ref(), || index.calculate_document_lengths())?;
+        Ok(index)
+    }
+
+    fn calculate_document_lengths(&self) -> DocumentLengths {
+        let mut document_lengths = DocumentLengths::default();
+        for id in 0..self.file_header.posting_count as DimId {
+            for element in self.get(&id).unwrap_or_default() {
+                document_lengths.add(element.record_id, element.weight);
+            }
+        }
+        document_lengths
     }
 
     fn total_posting_headers_size(inverted_index_ram: &InvertedIndexRam) -> usize {
@@ -7,6 +7,7 @@ use io::storage_version::StorageVersion;
 
 use crate::common::sparse_vector::RemappedSparseVector;
 use crate::common::types::{DimId, DimOffset};
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::inverted_index::InvertedIndex;
 use crate::index::posting_list::{PostingList, PostingListIterator};
 use crate::index::posting_list_common::PostingElementEx;
@@ -30,6 +31,8 @@ pub struct InvertedIndexRam {
     pub vector_count: usize,
     /// Total size of all searchable sparse vectors in bytes
     pub total_sparse_size: usize,
+    /// Lengths of indexed vectors
+    pub document_lengths: DocumentLengths,
 }
 
 impl InvertedIndex for InvertedIndexRam {
@@ -86,6 +89,7 @@ impl InvertedIndex for InvertedIndexRam {
 
         self.total_sparse_size = self.total_sparse_size.saturating_sub(old_vector_size);
         self.vector_count = self.vector_count.saturating_sub(1);
+        self.document_lengths.remove(id);
     }
 
     fn upsert(
@@ -112,6 +116,10 @@ impl InvertedIndex for InvertedIndexRam {
         self.total_sparse_size
     }
 
+    fn document_lengths(&self) -> &DocumentLengths {
+        &self.document_lengths
+    }
+
     fn max_index(&self) -> Option<DimId> {
         match self.postings.len() {
             0 => None,
@@ -127,6 +135,7 @@ impl InvertedIndexRam {
             postings: Vec::new(),
             vector_count: 0,
             total_sparse_size: 0,
+            document_lengths: DocumentLengths::default(),
         }
     }
 
@@ -159,6 +168,8 @@ impl InvertedIndexRam {
         }
 
         let new_vector_size = vector.len() * size_of::<PostingElementEx>();
+        self.document_lengths
+            .set(id, DocumentLengths::vector_length(&vector.values));
 
         for (dim_id, weight) in vector.indices.into_iter().zip(vector.values.into_iter()) {
             let dim_id = dim_id as usize;
@@ -4,6 +4,7 @@ use common::types::PointOffsetType;
 use log::debug;
 
 use crate::common::sparse_vector::RemappedSparseVector;
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 use crate::index::posting_list::PostingBuilder;
 use crate::index::posting_list_common::PostingElementEx;
@@ -13,6 +14,7 @@ pub struct InvertedIndexBuilder {
     pub posting_builders: Vec<PostingBuilder>,
     pub vector_count: usize,
     pub total_sparse_size: usize,
+    pub document_lengths: DocumentLengths,
 }
 
 impl Default for InvertedIndexBuilder {
@@ -27,12 +29,15 @@ impl InvertedIndexBuilder {
             posting_builders: Vec::new(),
             vector_count: 0,
             total_sparse_size: 0,
+            document_lengths: DocumentLengths::default(),
         }
     }
 
     /// Add a vector to the inverted index builder
     pub fn add(&mut self, id: PointOffsetType, vector: RemappedSparseVector) {
         let sparse_size = vector.len() * size_of::<PostingElementEx>();
+        self.document_lengths
+            .set(id, DocumentLengths::vector_length(&vector.values));
         for (dim_id, weight) in vector.indices.into_iter().zip(vector.values.into_iter()) {
             let dim_id = dim_id as usize;
             self.posting_builders.resize_with(
@@ -52,6 +57,7 @@ impl InvertedIndexBuilder {
                 postings: vec![],
                 total_sparse_size: self.total_sparse_size,
                 vector_count: self.vector_count,
+                document_lengths: self.document_lengths,
             };
         }
 
@@ -72,6 +78,7 @@ impl InvertedIndexBuilder {
             postings,
             vector_count,
             total_sparse_size,
+            document_lengths: self.document_lengths,
         }
     }
 
@@ -9,6 +9,7 @@ use io::storage_version::StorageVersion;
 use super::posting_list_common::PostingListIter;
 use crate::common::sparse_vector::RemappedSparseVector;
 use crate::common::types::DimOffset;
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 
 pub mod inverted_index_compressed_immutable_ram;
@@ -81,6 +82,9 @@ pub trait InvertedIndex: Sized + Debug + 'static {
     /// Total size of all the sparse vectors in bytes
     fn total_sparse_vectors_size(&self) -> usize;
 
+    /// Lengths of indexed vectors, used for BM25 scoring
+    fn document_lengths(&self) -> &DocumentLengths;
+
     /// Get max existed index
     fn max_index(&self) -> Option<DimOffset>;
 }
@@ -1,4 +1,5 @@
 pub mod compressed_posting_list;
+pub mod document_lengths;
 pub mod inverted_index;
 pub mod loaders;
 pub mod posting_list;
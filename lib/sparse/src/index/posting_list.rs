@@ -229,6 +229,23 @@ impl PostingListIter for PostingListIterator<'_> {
         true
     }
 
+    fn max_weight(&mut self) -> Option<DimWeight> {
+        self.peek()
+            .map(|element| element.weight.max(element.max_next_weight))
+    }
+
+    fn block_max_weight(
+        &mut self,
+        record_id: PointOffsetType,
+    ) -> Option<(PointOffsetType, DimWeight)> {
+        // The list is not split into blocks, but `max_next_weight` bounds the rest of the list
+        let remaining = &self.elements[self.current_index..];
+        let offset = remaining.partition_point(|e| e.record_id < record_id);
+        let element = remaining.get(offset)?;
+        let last_id = remaining.last()?.record_id;
+        Some((last_id, element.weight.max(element.max_next_weight)))
+    }
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement> {
         self.elements.iter().cloned().map(PostingElement::from)
     }
@@ -82,5 +82,19 @@ pub trait PostingListIter {
     /// Whether the max_next_weight is reliable.
     fn reliable_max_next_weight() -> bool;
 
+    /// Upper bound of the weights of the remaining elements.
+    /// Returns None if the iterator is exhausted.
+    fn max_weight(&mut self) -> Option<DimWeight>;
+
+    /// Upper bound of the weights in the block of elements which may contain `record_id`.
+    /// Returns the last id covered by the block (`>= record_id`) along with the bound,
+    /// or None if there are no elements with ID >= `record_id`.
+    ///
+    /// Unlike [`Self::skip_to`], does not advance the iterator.
+    fn block_max_weight(
+        &mut self,
+        record_id: PointOffsetType,
+    ) -> Option<(PointOffsetType, DimWeight)>;
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement>;
 }
@@ -6,10 +6,11 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::top_k::TopK;
 use common::types::{PointOffsetType, ScoredPointOffset};
 
-use super::posting_list_common::PostingListIter;
+use super::posting_list_common::{PostingElementEx, PostingListIter};
 use crate::common::scores_memory_pool::PooledScoresHandle;
 use crate::common::sparse_vector::{RemappedSparseVector, score_vectors};
 use crate::common::types::{DimId, DimWeight};
+use crate::index::document_lengths::DocumentLengths;
 use crate::index::inverted_index::InvertedIndex;
 use crate::index::posting_list::PostingListIterator;
 
@@ -20,9 +21,91 @@ pub struct IndexedPostingListIterator<T: PostingListIter> {
     query_weight: DimWeight,
 }
 
+/// Posting list iterator along with an upper bound of its score contribution,
+/// used by the dynamic pruning algorithms
+struct PruningPostingListIterator<T: PostingListIter> {
+    posting: IndexedPostingListIterator<T>,
+    /// Element at the current position, None if the iterator is exhausted
+    current: Option<PostingElementEx>,
+    /// Upper bound of the score contribution of the remaining elements
+    max_score: f32,
+}
+
+impl<T: PostingListIter> PruningPostingListIterator<T> {
+    #[inline]
+    fn current_id(&self) -> Option<PointOffsetType> {
+        self.current.as_ref().map(|element| element.record_id)
+    }
+
+    /// Advance the iterator to the first element with ID >= `record_id`
+    #[inline]
+    fn seek(&mut self, record_id: PointOffsetType) {
+        if self
+            .current_id()
+            .is_some_and(|current_id| current_id < record_id)
+        {
+            self.posting.posting_list_iterator.skip_to(record_id);
+            self.current = self.posting.posting_list_iterator.peek();
+        }
+    }
+
+    /// Advance the iterator past the element with ID `record_id`
+    #[inline]
+    fn seek_past(&mut self, record_id: PointOffsetType) {
+        match record_id.checked_add(1) {
+            Some(next_id) => self.seek(next_id),
+            None => {
+                self.posting.posting_list_iterator.skip_to_end();
+                self.current = None;
+            }
+        }
+    }
+}
+
 /// Making this larger makes the search faster but uses more (pooled) memory
 const ADVANCE_BATCH_SIZE: usize = 10_000;
 
+/// BM25 term frequency saturation, applied to document weights at search time.
+///
+/// Document weights are treated as term frequencies, and the length of a document
+/// is the sum of its weights (see [`DocumentLengths`]).
+/// Negative weights are treated as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Bm25Params {
+    /// Term frequency saturation
+    pub k1: DimWeight,
+    /// Document length normalization, in range `[0, 1]`
+    pub b: DimWeight,
+    /// Average document length across all searched segments
+    pub avg_doc_length: DimWeight,
+}
+
+impl Bm25Params {
+    /// Saturated weight of a term with frequency `tf` in a document of length `doc_length`
+    #[inline]
+    pub fn term_weight(&self, tf: DimWeight, doc_length: DimWeight) -> DimWeight {
+        if tf <= 0.0 {
+            return 0.0;
+        }
+        let length_norm = if self.avg_doc_length > 0.0 {
+            1.0 - self.b + self.b * doc_length / self.avg_doc_length
+        } else {
+            1.0
+        };
+        tf * (self.k1 + 1.0) / (tf + self.k1 * length_norm)
+    }
+
+    /// Upper bound of [`Self::term_weight`] over documents of any length, for a term frequency
+    /// of at most `max_tf`. Used for pruning.
+    #[inline]
+    pub fn max_term_weight(&self, max_tf: DimWeight) -> DimWeight {
+        if max_tf <= 0.0 {
+            return 0.0;
+        }
+        max_tf * (self.k1 + 1.0) / (max_tf + self.k1 * (1.0 - self.b))
+    }
+}
+
 pub struct SearchContext<'a, 'b, T: PostingListIter = PostingListIterator<'a>> {
     postings_iterators: Vec<IndexedPostingListIterator<T>>,
     query: RemappedSparseVector,
@@ -34,6 +117,8 @@ pub struct SearchContext<'a, 'b, T: PostingListIter = PostingListIterator<'a>> {
     pooled: PooledScoresHandle<'b>,         // handle to pooled scores
     use_pruning: bool,
     hardware_counter: &'a HardwareCounterCell,
+    document_lengths: &'a DocumentLengths,
+    bm25: Option<Bm25Params>,
 }
 
 impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
@@ -90,9 +175,17 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
             pooled,
             use_pruning,
             hardware_counter,
+            document_lengths: inverted_index.document_lengths(),
+            bm25: None,
         }
     }
 
+    /// Apply BM25 saturation to document weights
+    pub fn with_bm25(mut self, bm25: Bm25Params) -> Self {
+        self.bm25 = Some(bm25);
+        self
+    }
+
     const DEFAULT_SCORE: f32 = 0.0;
 
     /// Plain search against the given ids without any pruning
@@ -105,6 +198,8 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
 
         let mut indices = Vec::with_capacity(self.query.indices.len());
         let mut values = Vec::with_capacity(self.query.values.len());
+        let bm25 = self.bm25;
+        let document_lengths = self.document_lengths;
         for id in sorted_ids {
             // check for cancellation
             if self.is_stopped.load(Relaxed) {
@@ -121,7 +216,12 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                     Some(element) => {
                         // match for posting list
                         indices.push(posting_iterator.query_index);
-                        values.push(element.weight);
+                        values.push(match bm25 {
+                            None => element.weight,
+                            Some(bm25) => {
+                                bm25.term_weight(element.weight, document_lengths.get(id))
+                            }
+                        });
                     }
                 }
             }
@@ -161,19 +261,36 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
         self.pooled.scores.clear(); // keep underlying allocated memory
         self.pooled.scores.resize(batch_len as usize, 0.0);
 
+        let document_lengths = self.document_lengths;
         for posting in self.postings_iterators.iter_mut() {
-            posting.posting_list_iterator.for_each_till_id(
-                batch_last_id,
-                self.pooled.scores.as_mut_slice(),
-                #[inline(always)]
-                |scores, id, weight| {
-                    let element_score = weight * posting.query_weight;
-                    let local_id = (id - batch_start_id) as usize;
-                    // SAFETY: `id` is within `batch_start_id..=batch_last_id`
-                    // Thus, `local_id` is within `0..batch_len`.
-                    *unsafe { scores.get_unchecked_mut(local_id) } += element_score;
-                },
-            );
+            let query_weight = posting.query_weight;
+            match self.bm25 {
+                None => posting.posting_list_iterator.for_each_till_id(
+                    batch_last_id,
+                    self.pooled.scores.as_mut_slice(),
+                    #[inline(always)]
+                    |scores, id, weight| {
+                        let element_score = weight * query_weight;
+                        let local_id = (id - batch_start_id) as usize;
+                        // SAFETY: `id` is within `batch_start_id..=batch_last_id`
+                        // Thus, `local_id` is within `0..batch_len`.
+                        *unsafe { scores.get_unchecked_mut(local_id) } += element_score;
+                    },
+                ),
+                Some(bm25) => posting.posting_list_iterator.for_each_till_id(
+                    batch_last_id,
+                    self.pooled.scores.as_mut_slice(),
+                    #[inline(always)]
+                    |scores, id, weight| {
+                        let element_score =
+                            bm25.term_weight(weight, document_lengths.get(id)) * query_weight;
+                        let local_id = (id - batch_start_id) as usize;
+                        // SAFETY: `id` is within `batch_start_id..=batch_last_id`
+                        // Thus, `local_id` is within `0..batch_len`.
+                        *unsafe { scores.get_unchecked_mut(local_id) } += element_score;
+                    },
+                ),
+            }
         }
 
         for (local_index, &score) in self.pooled.scores.iter().enumerate() {
@@ -197,6 +314,8 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
     fn process_last_posting_list<F: Fn(PointOffsetType) -> bool>(&mut self, filter_condition: &F) {
         debug_assert_eq!(self.postings_iterators.len(), 1);
         let posting = &mut self.postings_iterators[0];
+        let bm25 = self.bm25;
+        let document_lengths = self.document_lengths;
         posting.posting_list_iterator.for_each_till_id(
             PointOffsetType::MAX,
             &mut (),
@@ -205,12 +324,51 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                 if !filter_condition(id) {
                     return;
                 }
+                let weight = match bm25 {
+                    None => weight,
+                    Some(bm25) => bm25.term_weight(weight, document_lengths.get(id)),
+                };
                 let score = weight * posting.query_weight;
                 self.top_results.push(ScoredPointOffset { score, idx: id });
             },
         );
     }
 
+    /// Upper bound of the document weight used for scoring, given the max raw weight in a posting list
+    fn max_document_weight(bm25: Option<Bm25Params>, max_weight: DimWeight) -> DimWeight {
+        match bm25 {
+            None => max_weight,
+            Some(bm25) => bm25.max_term_weight(max_weight),
+        }
+    }
+
+    /// Weight of the document used for scoring
+    #[inline]
+    fn document_weight(
+        bm25: Option<Bm25Params>,
+        document_lengths: &DocumentLengths,
+        id: PointOffsetType,
+        weight: DimWeight,
+    ) -> DimWeight {
+        match bm25 {
+            None => weight,
+            Some(bm25) => bm25.term_weight(weight, document_lengths.get(id)),
+        }
+    }
+
+    /// Measure CPU usage of indexed sparse search.
+    /// Assume the complexity of the search as total volume of the posting lists
+    /// that are traversed in the batched search.
+    fn measure_cpu_usage(&self) {
+        let mut cpu_cost = 0;
+
+        for posting in self.postings_iterators.iter() {
+            cpu_cost += posting.posting_list_iterator.len_to_end()
+                * posting.posting_list_iterator.element_size();
+        }
+        self.hardware_counter.cpu_counter().incr_delta(cpu_cost);
+    }
+
     /// Returns the next min record id from all posting list iterators
     ///
     /// returns None if all posting list iterators are exhausted
@@ -275,18 +433,7 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
             return Vec::new();
         }
 
-        {
-            // Measure CPU usage of indexed sparse search.
-            // Assume the complexity of the search as total volume of the posting lists
-            // that are traversed in the batched search.
-            let mut cpu_cost = 0;
-
-            for posting in self.postings_iterators.iter() {
-                cpu_cost += posting.posting_list_iterator.len_to_end()
-                    * posting.posting_list_iterator.element_size();
-            }
-            self.hardware_counter.cpu_counter().incr_delta(cpu_cost);
-        }
+        self.measure_cpu_usage();
 
         let mut best_min_score = f32::MIN;
         loop {
@@ -354,6 +501,270 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
         queue.into_vec()
     }
 
+    /// Dynamic pruning relies on upper bounds of the score contributions,
+    /// which can't be computed for negative query weights
+    fn supports_dynamic_pruning(&self) -> bool {
+        self.query.values.iter().all(|v| *v >= 0.0)
+    }
+
+    /// Upper bound of the score contribution of a posting list, given the max raw weight in it.
+    ///
+    /// Never negative: an id may be missing from posting lists with negative weights, so their
+    /// bounds can't lower the bound of the total score.
+    fn max_score_contribution(
+        bm25: Option<Bm25Params>,
+        max_weight: DimWeight,
+        posting: &IndexedPostingListIterator<T>,
+    ) -> f32 {
+        (Self::max_document_weight(bm25, max_weight) * posting.query_weight).max(0.0)
+    }
+
+    /// Take posting lists out of the context, along with upper bounds of their score contributions
+    fn take_pruning_posting_lists(&mut self) -> Vec<PruningPostingListIterator<T>> {
+        let bm25 = self.bm25;
+        std::mem::take(&mut self.postings_iterators)
+            .into_iter()
+            .filter_map(|mut posting| {
+                let current = posting.posting_list_iterator.peek()?;
+                let max_weight = posting.posting_list_iterator.max_weight()?;
+                let max_score = Self::max_score_contribution(bm25, max_weight, &posting);
+                Some(PruningPostingListIterator {
+                    posting,
+                    current: Some(current),
+                    max_score,
+                })
+            })
+            .collect()
+    }
+
+    /// Search for the top k results that satisfy the filter condition, using Block-Max WAND.
+    ///
+    /// Documents are visited in order of ids, skipping ids and whole blocks of posting lists
+    /// that can't make it to the top results according to upper bounds of the weights.
+    /// Falls back to [`Self::search`] for queries with negative weights.
+    pub fn search_block_max_wand<F: Fn(PointOffsetType) -> bool>(
+        &mut self,
+        filter_condition: &F,
+    ) -> Vec<ScoredPointOffset> {
+        if !self.supports_dynamic_pruning() {
+            return self.search(filter_condition);
+        }
+
+        self.measure_cpu_usage();
+
+        let bm25 = self.bm25;
+        let document_lengths = self.document_lengths;
+        let mut postings = self.take_pruning_posting_lists();
+
+        while !postings.is_empty() {
+            // check for cancellation
+            if self.is_stopped.load(Relaxed) {
+                break;
+            }
+
+            postings.sort_by_key(|posting| posting.current_id());
+            let threshold = self.top_results.threshold();
+
+            // find the pivot: the first posting list at which the sum of the upper bounds
+            // is enough to make it to the top results
+            let mut max_score = 0.0;
+            let Some(mut pivot) = postings.iter().position(|posting| {
+                max_score += posting.max_score;
+                max_score > threshold
+            }) else {
+                break;
+            };
+            let Some(pivot_id) = postings[pivot].current_id() else {
+                break;
+            };
+            while postings
+                .get(pivot + 1)
+                .is_some_and(|posting| posting.current_id() == Some(pivot_id))
+            {
+                pivot += 1;
+            }
+
+            // refine the upper bound with the blocks containing the pivot id
+            let mut block_max_score = 0.0;
+            let mut block_last_id = PointOffsetType::MAX;
+            for posting in &mut postings[..=pivot] {
+                if let Some((last_id, max_weight)) = posting
+                    .posting
+                    .posting_list_iterator
+                    .block_max_weight(pivot_id)
+                {
+                    block_max_score +=
+                        Self::max_score_contribution(bm25, max_weight, &posting.posting);
+                    block_last_id = min(block_last_id, last_id);
+                }
+            }
+
+            if block_max_score > threshold {
+                if postings[0].current_id() == Some(pivot_id) {
+                    // all posting lists up to the pivot point to the pivot id, score it
+                    let mut score = 0.0;
+                    for posting in &mut postings[..=pivot] {
+                        if let Some(element) = &posting.current {
+                            score += Self::document_weight(
+                                bm25,
+                                document_lengths,
+                                pivot_id,
+                                element.weight,
+                            ) * posting.posting.query_weight;
+                        }
+                        posting.seek_past(pivot_id);
+                    }
+                    if score != 0.0 && score > threshold && filter_condition(pivot_id) {
+                        self.top_results.push(ScoredPointOffset {
+                            score,
+                            idx: pivot_id,
+                        });
+                    }
+                } else {
+                    // ids before the pivot id can't make it to the top results
+                    for posting in &mut postings[..pivot] {
+                        posting.seek(pivot_id);
+                    }
+                }
+            } else {
+                // no id in the blocks can make it to the top results, skip them
+                let next_posting_id = postings
+                    .get(pivot + 1)
+                    .and_then(|posting| posting.current_id());
+                let next_id = match (block_last_id.checked_add(1), next_posting_id) {
+                    (Some(block_next_id), Some(posting_id)) => Some(min(block_next_id, posting_id)),
+                    (block_next_id, posting_id) => block_next_id.or(posting_id),
+                };
+                for posting in &mut postings[..=pivot] {
+                    match next_id {
+                        Some(next_id) => posting.seek(next_id),
+                        None => posting.seek_past(PointOffsetType::MAX),
+                    }
+                }
+            }
+
+            postings.retain(|posting| posting.current.is_some());
+        }
+
+        let queue = std::mem::take(&mut self.top_results);
+        queue.into_vec()
+    }
+
+    /// Search for the top k results that satisfy the filter condition, using MaxScore.
+    ///
+    /// Posting lists are split into essential and non-essential ones by the upper bounds of their
+    /// score contributions. Only ids from the essential posting lists are considered as candidates,
+    /// and non-essential posting lists are only used to complete the scores of the candidates.
+    /// Falls back to [`Self::search`] for queries with negative weights.
+    pub fn search_max_score<F: Fn(PointOffsetType) -> bool>(
+        &mut self,
+        filter_condition: &F,
+    ) -> Vec<ScoredPointOffset> {
+        if !self.supports_dynamic_pruning() {
+            return self.search(filter_condition);
+        }
+
+        self.measure_cpu_usage();
+
+        let bm25 = self.bm25;
+        let document_lengths = self.document_lengths;
+        let mut postings = self.take_pruning_posting_lists();
+        postings.sort_by(|a, b| a.max_score.total_cmp(&b.max_score));
+
+        // sum of the upper bounds of the posting lists up to the index, inclusive
+        let cumulative_max_scores: Vec<f32> = postings
+            .iter()
+            .scan(0.0, |sum, posting| {
+                *sum += posting.max_score;
+                Some(*sum)
+            })
+            .collect();
+
+        // posting lists before this index are non-essential
+        let mut essential_start = 0;
+        loop {
+            // check for cancellation
+            if self.is_stopped.load(Relaxed) {
+                break;
+            }
+
+            let threshold = self.top_results.threshold();
+            while cumulative_max_scores
+                .get(essential_start)
+                .is_some_and(|&max_score| max_score <= threshold)
+            {
+                essential_start += 1;
+            }
+
+            let (non_essential, essential) = postings.split_at_mut(essential_start);
+
+            // the next candidate is the min id among the essential posting lists
+            let Some(candidate_id) = essential
+                .iter()
+                .filter_map(|posting| posting.current_id())
+                .min()
+            else {
+                break;
+            };
+
+            let mut score = 0.0;
+            for posting in essential.iter_mut() {
+                if let Some(element) = &posting.current
+                    && element.record_id == candidate_id
+                {
+                    score +=
+                        Self::document_weight(bm25, document_lengths, candidate_id, element.weight)
+                            * posting.posting.query_weight;
+                    posting.seek_past(candidate_id);
+                }
+            }
+
+            // complete the score with the non-essential posting lists, largest bounds first,
+            // until the candidate can't make it to the top results
+            let mut competitive = true;
+            for (index, posting) in non_essential.iter_mut().enumerate().rev() {
+                let rest_max_score = index
+                    .checked_sub(1)
+                    .map_or(0.0, |prev| cumulative_max_scores[prev]);
+                if score + posting.max_score + rest_max_score <= threshold {
+                    competitive = false;
+                    break;
+                }
+                let Some((_, max_weight)) = posting
+                    .posting
+                    .posting_list_iterator
+                    .block_max_weight(candidate_id)
+                else {
+                    continue;
+                };
+                let block_max_score =
+                    Self::max_score_contribution(bm25, max_weight, &posting.posting);
+                if score + block_max_score + rest_max_score <= threshold {
+                    competitive = false;
+                    break;
+                }
+                posting.seek(candidate_id);
+                if let Some(element) = &posting.current
+                    && element.record_id == candidate_id
+                {
+                    score +=
+                        Self::document_weight(bm25, document_lengths, candidate_id, element.weight)
+                            * posting.posting.query_weight;
+                }
+            }
+
+            if competitive && score != 0.0 && score > threshold && filter_condition(candidate_id) {
+                self.top_results.push(ScoredPointOffset {
+                    score,
+                    idx: candidate_id,
+                });
+            }
+        }
+
+        let queue = std::mem::take(&mut self.top_results);
+        queue.into_vec()
+    }
+
     /// Prune posting lists that cannot possibly contribute to the top results
     /// Assumes longest posting list is at the head of the posting list iterators
     /// Returns true if the longest posting list was pruned
@@ -361,6 +772,7 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
         if self.postings_iterators.is_empty() {
             return false;
         }
+        let bm25 = self.bm25;
         // peek first element of longest posting list
         let (longest_posting_iterator, rest_iterators) = self.postings_iterators.split_at_mut(1);
         let longest_posting_iterator = &mut longest_posting_iterator[0];
@@ -383,7 +795,10 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                             // check against the max possible score using the `max_next_weight`
                             // we can under prune as we should actually check the best score up to `next_min_id` - 1 only
                             // instead of the max possible score but it is not possible to know the best score up to `next_min_id` - 1
-                            let max_weight_from_list = element.weight.max(element.max_next_weight);
+                            let max_weight_from_list = Self::max_document_weight(
+                                bm25,
+                                element.weight.max(element.max_next_weight),
+                            );
                             let max_score_contribution =
                                 max_weight_from_list * longest_posting_iterator.query_weight;
                             if max_score_contribution <= min_score {
@@ -404,7 +819,10 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                 None => {
                     // the current posting list is the only one left, we can potentially skip it to the end
                     // check against the max possible score using the `max_next_weight`
-                    let max_weight_from_list = element.weight.max(element.max_next_weight);
+                    let max_weight_from_list = Self::max_document_weight(
+                        bm25,
+                        element.weight.max(element.max_next_weight),
+                    );
                     let max_score_contribution =
                         max_weight_from_list * longest_posting_iterator.query_weight;
                     if max_score_contribution <= min_score {
@@ -0,0 +1,123 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::{PointOffsetType, ScoredPointOffset};
+use half::f16;
+use rand::SeedableRng;
+
+use crate::common::sparse_vector::RemappedSparseVector;
+use crate::common::types::{QuantizedU8, Weight};
+use crate::index::inverted_index::InvertedIndex;
+use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
+use crate::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
+use crate::index::search_context::{Bm25Params, SearchContext};
+use crate::index::tests::common::{
+    build_index, get_pooled_scores, match_all, random_sparse_vector,
+};
+
+const COUNT: usize = 2_000;
+const DENSITY: usize = 32;
+const VOCAB1: usize = 32;
+const VOCAB2: usize = 512;
+
+fn assert_same_scores(expected: &[ScoredPointOffset], actual: &[ScoredPointOffset]) {
+    assert_eq!(expected.len(), actual.len());
+    for (expected, actual) in expected.iter().zip(actual) {
+        assert!(
+            (expected.score - actual.score).abs() <= 1e-4 * expected.score.abs().max(1.0),
+            "{expected:?} != {actual:?}",
+        );
+    }
+}
+
+/// Compare Block-Max WAND and MaxScore against the exhaustive batched search
+fn check_dynamic_pruning<I: InvertedIndex>(
+    index: &I,
+    query: &RemappedSparseVector,
+    top: usize,
+    bm25: Option<Bm25Params>,
+    filter: &impl Fn(PointOffsetType) -> bool,
+) {
+    let is_stopped = AtomicBool::new(false);
+    let hardware_counter = HardwareCounterCell::disposable();
+    let search_context = || {
+        let search_context = SearchContext::new(
+            query.clone(),
+            top,
+            index,
+            get_pooled_scores(),
+            &is_stopped,
+            &hardware_counter,
+        );
+        match bm25 {
+            None => search_context,
+            Some(bm25) => search_context.with_bm25(bm25),
+        }
+    };
+
+    let expected = search_context().search(filter);
+    assert!(!expected.is_empty());
+
+    let block_max_wand = search_context().search_block_max_wand(filter);
+    assert_same_scores(&expected, &block_max_wand);
+
+    let max_score = search_context().search_max_score(filter);
+    assert_same_scores(&expected, &max_score);
+}
+
+fn check_queries<I: InvertedIndex>(index: &I) {
+    let mut rnd = rand::rngs::StdRng::seed_from_u64(43);
+    let bm25 = Bm25Params {
+        k1: 1.2,
+        b: 0.75,
+        avg_doc_length: (index.document_lengths().total() / COUNT as f64) as f32,
+    };
+    let even = |id: PointOffsetType| id.is_multiple_of(2);
+
+    for density in [1, 4, 16, 64] {
+        let query = random_sparse_vector(&mut rnd, density, VOCAB1, VOCAB2);
+        for top in [1, 10, 100] {
+            check_dynamic_pruning(index, &query, top, None, &match_all);
+            check_dynamic_pruning(index, &query, top, None, &even);
+            check_dynamic_pruning(index, &query, top, Some(bm25), &match_all);
+        }
+    }
+}
+
+fn check_compressed<W: Weight + 'static>() {
+    let index = build_index::<W>(COUNT, DENSITY, VOCAB1, VOCAB2);
+    check_queries(&index.index);
+}
+
+#[test]
+fn test_dynamic_pruning_compressed() {
+    check_compressed::<f32>();
+    check_compressed::<f16>();
+    check_compressed::<u8>();
+    check_compressed::<QuantizedU8>();
+}
+
+#[test]
+fn test_dynamic_pruning_ram() {
+    let mut rnd = rand::rngs::StdRng::seed_from_u64(42);
+    let mut builder = InvertedIndexBuilder::new();
+    for id in 0..COUNT {
+        let mut vector = random_sparse_vector(&mut rnd, DENSITY, VOCAB1, VOCAB2);
+        // some of the weights are negative
+        vector.values.iter_mut().for_each(|value| *value -= 0.5);
+        builder.add(id as PointOffsetType, vector);
+    }
+    let index: InvertedIndexRam = builder.build();
+    check_queries(&index);
+}
+
+#[test]
+fn test_dynamic_pruning_negative_query() {
+    let index = build_index::<f32>(COUNT, DENSITY, VOCAB1, VOCAB2);
+    let query = RemappedSparseVector {
+        indices: vec![0, 1, 100],
+        values: vec![1.0, -1.0, 0.5],
+    };
+    // falls back to the exhaustive search
+    check_dynamic_pruning(&index.index, &query, 10, None, &match_all);
+}
@@ -1,4 +1,5 @@
 mod common;
+mod dynamic_pruning_test;
 mod hw_counter_test;
 mod indexed_vs_plain_test;
 mod search_context_tests;
@@ -16,6 +16,7 @@ mod tests {
     use crate::common::sparse_vector::{RemappedSparseVector, SparseVector};
     use crate::common::sparse_vector_fixture::random_sparse_vector;
     use crate::common::types::QuantizedU8;
+    use crate::index::document_lengths::DocumentLengths;
     use crate::index::inverted_index::InvertedIndex;
     use crate::index::inverted_index::inverted_index_compressed_immutable_ram::InvertedIndexCompressedImmutableRam;
     use crate::index::inverted_index::inverted_index_compressed_mmap::InvertedIndexCompressedMmap;
@@ -24,7 +25,7 @@ mod tests {
     use crate::index::inverted_index::inverted_index_ram::InvertedIndexRam;
     use crate::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
     use crate::index::posting_list_common::PostingListIter;
-    use crate::index::search_context::SearchContext;
+    use crate::index::search_context::{Bm25Params, SearchContext};
     // ---- Test instantiations ----
 
     #[instantiate_tests(<InvertedIndexRam>)]
@@ -78,7 +79,7 @@ mod tests {
     /// Helper struct to store both an index and a temporary directory
     struct TestIndex<I: InvertedIndex> {
         index: I,
-        _temp_dir: TempDir,
+        temp_dir: TempDir,
     }
 
     impl<I: InvertedIndex> TestIndex<I> {
@@ -89,7 +90,7 @@ mod tests {
                 .unwrap();
             TestIndex {
                 index: I::from_ram_index(Cow::Owned(ram_index), &temp_dir).unwrap(),
-                _temp_dir: temp_dir,
+                temp_dir,
             }
         }
     }
@@ -642,4 +643,127 @@ mod tests {
             assert!(accumulator.get_vector_io_read() > 0);
         }
     }
+
+    /// Term frequencies of the documents used in BM25 tests
+    fn bm25_documents() -> Vec<(PointOffsetType, RemappedSparseVector)> {
+        (1..=50)
+            .map(|id: PointOffsetType| {
+                let vector: RemappedSparseVector = (1..=8)
+                    .filter(|dim| !(id + dim).is_multiple_of(3))
+                    .map(|dim| (dim, ((id * dim) % 5 + 1) as f32))
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+                (id, vector)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bm25_search_test<I: InvertedIndex>() {
+        let documents = bm25_documents();
+        let index = TestIndex::<I>::from_ram(InvertedIndexBuilder::build_from_iterator(
+            documents.iter().cloned(),
+        ));
+
+        let document_lengths = index.index.document_lengths();
+        let bm25 = Bm25Params {
+            k1: 1.2,
+            b: 0.75,
+            avg_doc_length: (document_lengths.total() / documents.len() as f64) as f32,
+        };
+        let query = RemappedSparseVector {
+            indices: vec![1, 4, 6],
+            values: vec![1.0, 0.5, 2.0],
+        };
+
+        let mut expected: Vec<_> = documents
+            .iter()
+            .map(|(id, vector)| {
+                let length = vector.values.iter().sum::<f32>();
+                assert_eq!(document_lengths.get(*id), length);
+                let score = query
+                    .indices
+                    .iter()
+                    .zip(&query.values)
+                    .filter_map(|(dim, query_weight)| {
+                        let position = vector.indices.iter().position(|i| i == dim)?;
+                        Some(bm25.term_weight(vector.values[position], length) * query_weight)
+                    })
+                    .sum::<f32>();
+                ScoredPointOffset { idx: *id, score }
+            })
+            .collect();
+        expected.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let tolerance = if TypeId::of::<I>()
+            == TypeId::of::<InvertedIndexCompressedMmap<QuantizedU8>>()
+            || TypeId::of::<I>() == TypeId::of::<InvertedIndexCompressedImmutableRam<QuantizedU8>>()
+        {
+            0.1
+        } else {
+            1e-5
+        };
+        let assert_top = |actual: Vec<ScoredPointOffset>, top: usize| {
+            assert_eq!(actual.len(), top);
+            for (actual, expected) in actual.iter().zip(&expected) {
+                assert!(
+                    (actual.score - expected.score).abs() < tolerance,
+                    "{actual:?} != {expected:?}",
+                );
+            }
+        };
+
+        let is_stopped = AtomicBool::new(false);
+        let hardware_counter = HardwareCounterCell::disposable();
+        for top in [1, 5, 50] {
+            let mut search_context = SearchContext::new(
+                query.clone(),
+                top,
+                &index.index,
+                get_pooled_scores(),
+                &is_stopped,
+                &hardware_counter,
+            )
+            .with_bm25(bm25);
+            assert_top(search_context.search(&match_all), top);
+
+            let mut search_context = SearchContext::new(
+                query.clone(),
+                top,
+                &index.index,
+                get_pooled_scores(),
+                &is_stopped,
+                &hardware_counter,
+            )
+            .with_bm25(bm25);
+            let ids: Vec<_> = documents.iter().map(|(id, _)| *id).collect();
+            assert_top(search_context.plain_search(&ids), top);
+        }
+    }
+
+    #[test]
+    fn bm25_document_lengths_reload_test<I: InvertedIndex + 'static>() {
+        if TypeId::of::<I>() == TypeId::of::<InvertedIndexRam>() {
+            // InvertedIndexRam is not persisted
+            return;
+        }
+
+        let index = TestIndex::<I>::from_ram(InvertedIndexBuilder::build_from_iterator(
+            bm25_documents().into_iter(),
+        ));
+        index.index.save(index.temp_dir.path()).unwrap();
+        let reloaded = I::open(index.temp_dir.path()).unwrap();
+        assert_eq!(reloaded.document_lengths(), index.index.document_lengths());
+
+        // Indexes created before document lengths were stored compute them on load
+        std::fs::remove_file(DocumentLengths::file_path(index.temp_dir.path())).unwrap();
+        let reloaded = I::open(index.temp_dir.path()).unwrap();
+        let document_lengths = reloaded.document_lengths();
+        for (id, vector) in bm25_documents() {
+            let length = vector.values.iter().sum::<f32>();
+            assert!((document_lengths.get(id) - length).abs() < 0.5);
+        }
+        assert!(DocumentLengths::file_path(index.temp_dir.path()).exists());
+    }
 }
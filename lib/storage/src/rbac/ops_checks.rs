@@ -1112,6 +1112,7 @@ mod tests_ops {
             with_payload: Some(WithPayloadInterface::Bool(true)),
             with_vector: WithVector::Bool(true),
             order_by: Some(OrderByInterface::Key("path".parse().unwrap())),
+            highlight: None,
         };
 
         assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
@@ -6,7 +6,7 @@
 //! Low-level FFI bindings to VDE (Vector Data Engine)
 //!
 //! This crate provides raw, unsafe Rust bindings to the VDE C API.
-//! For safe, idiomatic Rust wrappers, see the vde_index module in segment.
+//! For safe, idiomatic Rust wrappers, see the `vde` module in segment.
 
 // Include the auto-generated bindings
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
@@ -126,6 +126,7 @@ impl AuthKeys {
             with_payload: Some(WithPayloadInterface::Bool(false)),
             with_vector: WithVector::Bool(false),
             order_by: None,
+            highlight: None,
         };
 
         let res = self
@@ -102,7 +102,8 @@ fn collect_query(query: &Query, batch: &mut BatchAccum) {
         | Query::Fusion(_)
         | Query::Rrf(_)
         | Query::Formula(_)
-        | Query::Sample(_) => {}
+        | Query::Sample(_)
+        | Query::Text(_) => {}
     }
 }
 
@@ -180,6 +181,7 @@ pub fn collect_query_request(request: &QueryRequestInternal) -> BatchAccum {
         with_vector: _,
         with_payload: _,
         lookup_from: _,
+        highlight: _,
     } = request;
 
     if let Some(query) = query {
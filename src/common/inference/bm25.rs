@@ -9,8 +9,9 @@ use itertools::Itertools;
 use murmur3::murmur3_32_of_slice;
 use segment::data_types::index::{Language, StopwordsInterface};
 use segment::index::field_index::full_text_index::stop_words::StopwordsFilter;
+use segment::index::field_index::full_text_index::synonyms::Synonyms;
 use segment::index::field_index::full_text_index::tokenizers::{
-    Stemmer, Tokenizer, TokensProcessor,
+    CharFilters, Stemmer, Tokenizer, TokensProcessor,
 };
 
 const DEFAULT_LANGUAGE: &str = "english";
@@ -38,6 +39,8 @@ impl Bm25 {
     }
 
     /// Embeds the given input using the Bm25 algorithm and configured options/hyperparameters.
+    ///
+    /// Query tokens are expanded with their synonyms.
     pub fn search_embed(&self, input: &str) -> VectorPersisted {
         let tokens = self.tokenize(input);
 
@@ -46,8 +49,12 @@ impl Bm25 {
         }
 
         let indices: Vec<u32> = tokens
-            .into_iter()
-            .map(|token| Self::compute_token_id(&token))
+            .iter()
+            .flat_map(|token| {
+                std::iter::once(token.as_ref())
+                    .chain(self.tokenizer.synonyms(token).iter().map(String::as_str))
+            })
+            .map(Self::compute_token_id)
             .unique()
             .collect();
 
@@ -106,6 +113,8 @@ fn new_token_processor_from_config(value: TextPreprocessingConfig) -> TokensProc
         stemmer,
         min_token_len,
         max_token_len,
+        char_filters,
+        synonyms,
     } = value;
 
     let lowercase = lowercase.unwrap_or(true);
@@ -127,11 +136,18 @@ fn new_token_processor_from_config(value: TextPreprocessingConfig) -> TokensProc
         Some(stopwords_interface) => Some(stopwords_interface),
     };
 
-    TokensProcessor::new(
+    let char_filters = CharFilters::new(char_filters.as_deref().unwrap_or_default());
+
+    let mut tokens_processor = TokensProcessor::new(
         lowercase,
         Arc::new(StopwordsFilter::new(&stopwords_config, lowercase)),
+        Arc::new(char_filters),
         stemmer,
         min_token_len,
         max_token_len,
-    )
+    );
+    let synonyms = Synonyms::new(&synonyms, &tokens_processor);
+    tokens_processor.set_synonyms(Arc::new(synonyms));
+
+    tokens_processor
 }
@@ -11,6 +11,7 @@ use collection::operations::universal_query::formula::FormulaInternal;
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
 use ordered_float::OrderedFloat;
 use segment::data_types::order_by::OrderBy;
+use segment::data_types::text_query::TextQuery;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, MultiDenseVectorInternal, VectorInternal};
 use segment::types::{Filter, PointIdType, SearchParams};
 use segment::vector_storage::query::{ContextPair, ContextQuery, DiscoveryQuery, RecoQuery};
@@ -97,7 +98,7 @@ pub async fn convert_query_point_groups_from_grpc(
         limit: limit
             .map(|l| l as usize)
             .unwrap_or(CollectionQueryRequest::DEFAULT_LIMIT),
-        params: params.map(From::from),
+        params: params.map(TryFrom::try_from).transpose()?,
         with_lookup: with_lookup.map(TryFrom::try_from).transpose()?,
     };
 
@@ -125,6 +126,7 @@ pub async fn convert_query_points_from_grpc(
         shard_key_selector: _,
         lookup_from,
         timeout: _,
+        highlight,
     } = query;
 
     let mut batch = BatchAccumGrpc::new();
@@ -166,7 +168,7 @@ pub async fn convert_query_points_from_grpc(
             offset: offset
                 .map(|o| o as usize)
                 .unwrap_or(CollectionQueryRequest::DEFAULT_OFFSET),
-            params: params.map(From::from),
+            params: params.map(TryFrom::try_from).transpose()?,
             with_vector: with_vectors
                 .map(From::from)
                 .unwrap_or(CollectionQueryRequest::DEFAULT_WITH_VECTOR),
@@ -175,6 +177,7 @@ pub async fn convert_query_points_from_grpc(
                 .transpose()?
                 .unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PAYLOAD),
             lookup_from: lookup_from.map(From::from),
+            highlight: highlight.map(From::from),
         },
         usage.unwrap_or_default().into(),
     ))
@@ -213,7 +216,7 @@ fn convert_prefetch_with_inferred(
         limit: limit
             .map(|l| l as usize)
             .unwrap_or(CollectionQueryRequest::DEFAULT_LIMIT),
-        params: params.map(SearchParams::from),
+        params: params.map(SearchParams::try_from).transpose()?,
         lookup_from: lookup_from.map(LookupLocation::from),
     })
 }
@@ -294,6 +297,7 @@ fn convert_query_with_inferred(
         Variant::Rrf(rrf) => Query::Fusion(FusionInternal::try_from(rrf)?),
         Variant::Formula(formula) => Query::Formula(FormulaInternal::try_from(formula)?),
         Variant::Sample(sample) => Query::Sample(SampleInternal::try_from(sample)?),
+        Variant::Text(text) => Query::Text(TextQuery::try_from(text)?),
         Variant::NearestWithMmr(grpc::NearestInputWithMmr { nearest, mmr }) => {
             let nearest =
                 nearest.ok_or_else(|| Status::invalid_argument("nearest vector is missing"))?;
@@ -481,6 +485,7 @@ mod tests {
         let vector = grpc::VectorInput {
             variant: Some(Variant::Dense(grpc::DenseVector {
                 data: vec![1.0, 2.0, 3.0],
+                binary: None,
             })),
         };
 
@@ -527,6 +532,7 @@ mod tests {
             positive: Some(grpc::VectorInput {
                 variant: Some(Variant::Dense(grpc::DenseVector {
                     data: vec![1.0, 2.0, 3.0],
+                    binary: None,
                 })),
             }),
             negative: Some(grpc::VectorInput {
@@ -93,7 +93,7 @@ pub async fn search(
     let search_request = CoreSearchRequest {
         query: QueryEnum::Nearest(NamedQuery::from(vector_struct)),
         filter: filter.map(|f| f.try_into()).transpose()?,
-        params: params.map(|p| p.into()),
+        params: params.map(|p| p.try_into()).transpose()?,
         limit: limit as usize,
         offset: offset.unwrap_or_default() as usize,
         with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
@@ -588,6 +588,7 @@ pub async fn scroll(
         shard_key_selector,
         order_by,
         timeout,
+        highlight,
     } = scroll_points;
 
     let scroll_request = ScrollRequestInternal {
@@ -602,6 +603,7 @@ pub async fn scroll(
             .map(OrderBy::try_from)
             .transpose()?
             .map(OrderByInterface::Struct),
+        highlight: highlight.map(From::from),
     };
 
     let toc = toc_provider